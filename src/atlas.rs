@@ -0,0 +1,319 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{RowStride, YuvBiPlanarImage, YuvPlanarImage};
+use crate::yuv_error::MismatchedSize;
+use crate::yuv_nv_to_rgba::yuv_nv_to_rgbx_rows;
+use crate::yuv_support::{
+    YuvChromaSample, YuvNVOrder, YuvRange, YuvSourceChannels, YuvStandardMatrix,
+};
+use crate::yuv_to_rgba::yuv_to_rgbx_rows;
+use crate::YuvError;
+
+/// Part of `dst` covering `width` x `height` pixels with the top left one at `x`, `y`.
+///
+/// Region starts at its first pixel and ends right after its last one,
+/// rows keep stride of the whole destination.
+fn destination_region(
+    dst: &mut [u8],
+    dst_stride: u32,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    channels: usize,
+) -> Result<&mut [u8], YuvError> {
+    if width == 0 || height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    let stride = dst_stride as usize;
+    let row_end = (x as usize)
+        .checked_add(width as usize)
+        .and_then(|v| v.checked_mul(channels))
+        .ok_or(YuvError::PointerOverflow)?;
+    if row_end > stride {
+        return Err(YuvError::MinimumDestinationSizeMismatch(MismatchedSize {
+            expected: row_end,
+            received: stride,
+        }));
+    }
+    let last_row = (y as usize)
+        .checked_add(height as usize - 1)
+        .ok_or(YuvError::PointerOverflow)?;
+    let end = last_row
+        .checked_mul(stride)
+        .and_then(|v| v.checked_add(row_end))
+        .ok_or(YuvError::PointerOverflow)?;
+    if end > dst.len() {
+        return Err(YuvError::DestinationSizeMismatch(MismatchedSize {
+            expected: end,
+            received: dst.len(),
+        }));
+    }
+    let start = y as usize * stride + x as usize * channels;
+    Ok(&mut dst[start..end])
+}
+
+fn yuv_to_rgbx_at_offset<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    x: u32,
+    y: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    image.check_constraints(sampling)?;
+    let width = image.size.width;
+    let height = image.size.height;
+    let region = destination_region(
+        rgba,
+        rgba_stride,
+        x,
+        y,
+        width,
+        height,
+        dst_chans.get_channels_count(),
+    )?;
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => {
+            yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+        }
+        YuvChromaSample::YUV422 => {
+            yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+        }
+        YuvChromaSample::YUV444 => {
+            yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+        }
+    };
+    handler(
+        image.y_plane,
+        image.y_stride.get(),
+        image.u_plane,
+        image.u_stride.get(),
+        image.v_plane,
+        image.v_stride.get(),
+        region,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        255,
+    );
+    Ok(())
+}
+
+fn yuv_nv12_to_rgbx_at_offset<const DESTINATION_CHANNELS: u8>(
+    image: &YuvBiPlanarImage<u8>,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    x: u32,
+    y: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    image.check_constraints(YuvChromaSample::YUV420)?;
+    let width = image.size.width;
+    let height = image.size.height;
+    let region = destination_region(
+        rgba,
+        rgba_stride,
+        x,
+        y,
+        width,
+        height,
+        dst_chans.get_channels_count(),
+    )?;
+    yuv_nv_to_rgbx_rows::<
+        { YuvNVOrder::UV as u8 },
+        DESTINATION_CHANNELS,
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        image.y_plane,
+        image.y_stride.get(),
+        image.uv_plane,
+        image.uv_stride.get(),
+        region,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    );
+    Ok(())
+}
+
+/// Convert planar YUV image to RGBA written at `x`, `y` of a larger RGBA image such as a sprite atlas.
+///
+/// Only pixels covered by the image are written, the rest of the destination is left untouched.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `rgba` - A mutable slice of the whole destination RGBA image.
+/// * `rgba_stride` - The stride (bytes per row) for the whole destination RGBA image.
+/// * `x` - Column of the destination where the image starts.
+/// * `y` - Row of the destination where the image starts.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_to_rgba_at_offset(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: RowStride,
+    x: u32,
+    y: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let rgba_stride = rgba_stride.get();
+    yuv_to_rgbx_at_offset::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        sampling,
+        rgba,
+        rgba_stride,
+        x,
+        y,
+        range,
+        matrix,
+    )
+}
+
+/// Convert planar YUV image to BGRA written at `x`, `y` of a larger BGRA image such as a sprite atlas.
+///
+/// Only pixels covered by the image are written, the rest of the destination is left untouched.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `bgra` - A mutable slice of the whole destination BGRA image.
+/// * `bgra_stride` - The stride (bytes per row) for the whole destination BGRA image.
+/// * `x` - Column of the destination where the image starts.
+/// * `y` - Row of the destination where the image starts.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_to_bgra_at_offset(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    bgra: &mut [u8],
+    bgra_stride: RowStride,
+    x: u32,
+    y: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let bgra_stride = bgra_stride.get();
+    yuv_to_rgbx_at_offset::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        sampling,
+        bgra,
+        bgra_stride,
+        x,
+        y,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV12 image to RGBA written at `x`, `y` of a larger RGBA image such as a sprite atlas.
+///
+/// Only pixels covered by the image are written, the rest of the destination is left untouched.
+///
+/// # Arguments
+///
+/// * `image` - Source NV12 image.
+/// * `rgba` - A mutable slice of the whole destination RGBA image.
+/// * `rgba_stride` - The stride (bytes per row) for the whole destination RGBA image.
+/// * `x` - Column of the destination where the image starts.
+/// * `y` - Row of the destination where the image starts.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv12_to_rgba_at_offset(
+    image: &YuvBiPlanarImage<u8>,
+    rgba: &mut [u8],
+    rgba_stride: RowStride,
+    x: u32,
+    y: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let rgba_stride = rgba_stride.get();
+    yuv_nv12_to_rgbx_at_offset::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        rgba,
+        rgba_stride,
+        x,
+        y,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV12 image to BGRA written at `x`, `y` of a larger BGRA image such as a sprite atlas.
+///
+/// Only pixels covered by the image are written, the rest of the destination is left untouched.
+///
+/// # Arguments
+///
+/// * `image` - Source NV12 image.
+/// * `bgra` - A mutable slice of the whole destination BGRA image.
+/// * `bgra_stride` - The stride (bytes per row) for the whole destination BGRA image.
+/// * `x` - Column of the destination where the image starts.
+/// * `y` - Row of the destination where the image starts.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv12_to_bgra_at_offset(
+    image: &YuvBiPlanarImage<u8>,
+    bgra: &mut [u8],
+    bgra_stride: RowStride,
+    x: u32,
+    y: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let bgra_stride = bgra_stride.get();
+    yuv_nv12_to_rgbx_at_offset::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        bgra,
+        bgra_stride,
+        x,
+        y,
+        range,
+        matrix,
+    )
+}
@@ -133,6 +133,34 @@ pub unsafe fn _mm256_store_interleaved_epi8(
     _mm256_storeu_si256(ptr.add(96) as *mut __m256i, rgba3);
 }
 
+/// Stores 32 RGBX pixels, the 4th byte of every destination pixel is masked out and never written
+#[inline]
+pub unsafe fn _mm256_store_interleaved_rgbx_epi8(ptr: *mut u8, r: __m256i, g: __m256i, b: __m256i) {
+    let zeros = _mm256_setzero_si256();
+    let bg0 = _mm256_unpacklo_epi8(r, g);
+    let bg1 = _mm256_unpackhi_epi8(r, g);
+    let ra0 = _mm256_unpacklo_epi8(b, zeros);
+    let ra1 = _mm256_unpackhi_epi8(b, zeros);
+
+    let rgba0_ = _mm256_unpacklo_epi16(bg0, ra0);
+    let rgba1_ = _mm256_unpackhi_epi16(bg0, ra0);
+    let rgba2_ = _mm256_unpacklo_epi16(bg1, ra1);
+    let rgba3_ = _mm256_unpackhi_epi16(bg1, ra1);
+
+    let rgba0 = _mm256_permute2x128_si256::<32>(rgba0_, rgba1_);
+    let rgba2 = _mm256_permute2x128_si256::<49>(rgba0_, rgba1_);
+    let rgba1 = _mm256_permute2x128_si256::<32>(rgba2_, rgba3_);
+    let rgba3 = _mm256_permute2x128_si256::<49>(rgba2_, rgba3_);
+
+    // AVX2 has no byte granular masked store, so each half goes through `maskmovdqu`
+    let mask = _mm_set1_epi32(0x00ff_ffff);
+    for (i, rgba) in [rgba0, rgba1, rgba2, rgba3].into_iter().enumerate() {
+        let dst = ptr.add(i * 32) as *mut i8;
+        _mm_maskmoveu_si128(_mm256_castsi256_si128(rgba), mask, dst);
+        _mm_maskmoveu_si128(_mm256_extracti128_si256::<1>(rgba), mask, dst.add(16));
+    }
+}
+
 #[inline]
 #[target_feature(enable = "avx2")]
 pub unsafe fn avx2_interleave_odd(x: __m256i) -> __m256i {
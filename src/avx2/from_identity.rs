@@ -74,7 +74,7 @@ pub unsafe fn gbr_to_image_avx<const DESTINATION_CHANNELS: u8>(
                 _mm256_storeu_si256(rgb_start_ptr.add(32) as *mut __m256i, new_pixel.1);
                 _mm256_storeu_si256(rgb_start_ptr.add(64) as *mut __m256i, new_pixel.2);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 _mm256_store_interleaved_epi8(
                     rgb_start_ptr,
                     r_pixel,
@@ -83,7 +83,7 @@ pub unsafe fn gbr_to_image_avx<const DESTINATION_CHANNELS: u8>(
                     default_alpha,
                 );
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 _mm256_store_interleaved_epi8(
                     rgb_start_ptr,
                     b_pixel,
@@ -105,7 +105,10 @@ pub unsafe fn avx2_rgba_to_yuv<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
                     b_values = it1;
                 }
             }
-            YuvSourceChannels::Rgba | YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Rgba
+            | YuvSourceChannels::Bgra
+            | YuvSourceChannels::Rgbx
+            | YuvSourceChannels::Bgrx => {
                 let source_ptr = rgba_ptr.add(px);
                 let row_1 = _mm256_loadu_si256(source_ptr as *const __m256i);
                 let row_2 = _mm256_loadu_si256(source_ptr.add(32) as *const __m256i);
@@ -113,7 +116,10 @@ pub unsafe fn avx2_rgba_to_yuv<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
                 let row_4 = _mm256_loadu_si256(source_ptr.add(96) as *const __m256i);
 
                 let (it1, it2, it3, _) = _mm256_deinterleave_rgba_epi8(row_1, row_2, row_3, row_4);
-                if source_channels == YuvSourceChannels::Rgba {
+                if matches!(
+                    source_channels,
+                    YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx
+                ) {
                     r_values = it1;
                     g_values = it2;
                     b_values = it3;
@@ -70,14 +70,14 @@ pub unsafe fn image_to_gbr_avx<const SOURCE_CHANNELS: u8>(
                 g_pixel = rgb_pixel.1;
                 b_pixel = rgb_pixel.2;
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let row3 = _mm256_loadu_si256(rgb_start_ptr.add(96) as *const __m256i);
                 let rgb_pixel = _mm256_deinterleave_rgba_epi8(row0, row1, row2, row3);
                 r_pixel = rgb_pixel.0;
                 g_pixel = rgb_pixel.1;
                 b_pixel = rgb_pixel.2;
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let row3 = _mm256_loadu_si256(rgb_start_ptr.add(96) as *const __m256i);
                 let rgb_pixel = _mm256_deinterleave_rgba_epi8(row0, row1, row2, row3);
                 r_pixel = rgb_pixel.2;
@@ -187,7 +187,7 @@ pub unsafe fn avx2_ycgco_to_rgb_row<const DESTINATION_CHANNELS: u8, const SAMPLI
                 let ptr = rgba_ptr.add(dst_shift);
                 avx2_store_u8_rgb(ptr, b_values, g_values, r_values);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 _mm256_store_interleaved_epi8(
                     rgba_ptr.add(dst_shift),
                     r_values,
@@ -196,7 +196,7 @@ pub unsafe fn avx2_ycgco_to_rgb_row<const DESTINATION_CHANNELS: u8, const SAMPLI
                     v_alpha,
                 );
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 _mm256_store_interleaved_epi8(
                     rgba_ptr.add(dst_shift),
                     b_values,
@@ -212,7 +212,7 @@ pub unsafe fn avx2_ycgco_to_rgba_alpha<const DESTINATION_CHANNELS: u8, const SAM
                 let ptr = rgba_ptr.add(dst_shift);
                 avx2_store_u8_rgb(ptr, b_values, g_values, r_values);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 _mm256_store_interleaved_epi8(
                     rgba_ptr.add(dst_shift),
                     r_values,
@@ -221,7 +221,7 @@ pub unsafe fn avx2_ycgco_to_rgba_alpha<const DESTINATION_CHANNELS: u8, const SAM
                     v_alpha,
                 );
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 _mm256_store_interleaved_epi8(
                     rgba_ptr.add(dst_shift),
                     b_values,
@@ -54,6 +54,7 @@ pub unsafe fn avx2_yuv_nv_to_rgba_row<
     uv_offset: usize,
     rgba_offset: usize,
     width: usize,
+    alpha: u8,
 ) -> ProcessedOffset {
     let order: YuvNVOrder = UV_ORDER.into();
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
@@ -74,7 +75,7 @@ pub unsafe fn avx2_yuv_nv_to_rgba_row<
     let v_min_values = _mm256_setzero_si256();
     let v_g_coeff_1 = _mm256_set1_epi16(-(transform.g_coeff_1 as i16));
     let v_g_coeff_2 = _mm256_set1_epi16(-(transform.g_coeff_2 as i16));
-    let v_alpha = _mm256_set1_epi8(255u8 as i8);
+    let v_alpha = _mm256_set1_epi8(alpha as i8);
     let rounding_const = _mm256_set1_epi16(1 << 5);
 
     while cx + 32 < width {
@@ -235,6 +236,22 @@ pub unsafe fn avx2_yuv_nv_to_rgba_row<
                     v_alpha,
                 );
             }
+            YuvSourceChannels::Rgbx => {
+                _mm256_store_interleaved_rgbx_epi8(
+                    rgba_ptr.add(dst_shift),
+                    r_values,
+                    g_values,
+                    b_values,
+                );
+            }
+            YuvSourceChannels::Bgrx => {
+                _mm256_store_interleaved_rgbx_epi8(
+                    rgba_ptr.add(dst_shift),
+                    b_values,
+                    g_values,
+                    r_values,
+                );
+            }
         }
 
         cx += 32;
@@ -249,5 +266,13 @@ pub unsafe fn avx2_yuv_nv_to_rgba_row<
         }
     }
 
+    if matches!(
+        destination_channels,
+        YuvSourceChannels::Rgbx | YuvSourceChannels::Bgrx
+    ) {
+        // Masked stores are non temporal, order them before the caller hands rows out
+        _mm_sfence();
+    }
+
     ProcessedOffset { cx, ux: uv_x }
 }
@@ -52,6 +52,7 @@ pub unsafe fn avx2_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLIN
     v_offset: usize,
     rgba_offset: usize,
     width: usize,
+    alpha: u8,
 ) -> ProcessedOffset {
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
@@ -72,10 +73,10 @@ pub unsafe fn avx2_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLIN
     let v_min_values = _mm256_setzero_si256();
     let v_g_coeff_1 = _mm256_set1_epi16(-(transform.g_coeff_1 as i16));
     let v_g_coeff_2 = _mm256_set1_epi16(-(transform.g_coeff_2 as i16));
-    let v_alpha = _mm256_set1_epi8(255u8 as i8);
+    let v_alpha = _mm256_set1_epi8(alpha as i8);
     let rounding_const = _mm256_set1_epi16(1 << 5);
 
-    while cx + 32 < width {
+    while cx + 32 <= width {
         let y_values = _mm256_subs_epu8(
             _mm256_loadu_si256(y_ptr.add(y_offset + cx) as *const __m256i),
             y_corr,
@@ -207,6 +208,22 @@ pub unsafe fn avx2_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLIN
                     v_alpha,
                 );
             }
+            YuvSourceChannels::Rgbx => {
+                _mm256_store_interleaved_rgbx_epi8(
+                    rgba_ptr.add(dst_shift),
+                    r_values,
+                    g_values,
+                    b_values,
+                );
+            }
+            YuvSourceChannels::Bgrx => {
+                _mm256_store_interleaved_rgbx_epi8(
+                    rgba_ptr.add(dst_shift),
+                    b_values,
+                    g_values,
+                    r_values,
+                );
+            }
         }
 
         cx += 32;
@@ -221,5 +238,13 @@ pub unsafe fn avx2_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLIN
         }
     }
 
+    if matches!(
+        destination_channels,
+        YuvSourceChannels::Rgbx | YuvSourceChannels::Bgrx
+    ) {
+        // Masked stores are non temporal, order them before the caller hands rows out
+        _mm_sfence();
+    }
+
     ProcessedOffset { cx, ux: uv_x }
 }
@@ -233,10 +233,10 @@ unsafe fn yuy2_to_rgb_avx_impl<const DST_CHANNELS: u8, const YUY2_TARGET: usize>
                     _mm256_storeu_si256(dst_ptr.add(32) as *mut __m256i, packed.1);
                     _mm256_storeu_si256(dst_ptr.add(64) as *mut __m256i, packed.2);
                 }
-                YuvSourceChannels::Rgba => {
+                YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                     _mm256_store_interleaved_epi8(dst_ptr, r_l, g_l, b_l, v_alpha);
                 }
-                YuvSourceChannels::Bgra => {
+                YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                     _mm256_store_interleaved_epi8(dst_ptr, b_l, g_l, r_l, v_alpha);
                 }
                 YuvSourceChannels::Bgr => {
@@ -341,11 +341,11 @@ unsafe fn yuy2_to_rgb_avx_impl<const DST_CHANNELS: u8, const YUY2_TARGET: usize>
                     _mm256_storeu_si256(v_dst.add(32) as *mut __m256i, packed.1);
                     _mm256_storeu_si256(v_dst.add(64) as *mut __m256i, packed.2);
                 }
-                YuvSourceChannels::Rgba => {
+                YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                     let v_dst = dst_ptr.add(32 * dst_chans.get_channels_count());
                     _mm256_store_interleaved_epi8(v_dst, r_h, g_h, b_h, v_alpha);
                 }
-                YuvSourceChannels::Bgra => {
+                YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                     let v_dst = dst_ptr.add(32 * dst_chans.get_channels_count());
                     _mm256_store_interleaved_epi8(v_dst, b_h, g_h, r_h, v_alpha);
                 }
@@ -35,6 +35,7 @@ use std::arch::x86::*;
 use std::arch::x86_64::*;
 
 use crate::avx512bw::avx512_setr::{_v512_set_epu16, _v512_set_epu32};
+use crate::yuv_support::Yuy2Description;
 
 #[inline]
 pub unsafe fn avx512_pack_u16(lo: __m512i, hi: __m512i) -> __m512i {
@@ -78,12 +79,53 @@ pub unsafe fn avx512_interleave_rgb(
     (bgr0, bgr1, bgr2)
 }
 
+/// Loads 64 bytes, with `ALIGNED` `src` must be 64-byte aligned
+#[inline(always)]
+pub unsafe fn avx512_load_si512<const ALIGNED: bool>(src: *const u8) -> __m512i {
+    if ALIGNED {
+        _mm512_load_si512(src as *const _)
+    } else {
+        _mm512_loadu_si512(src as *const _)
+    }
+}
+
+/// Loads 32 bytes, with `ALIGNED` `src` must be 32-byte aligned
+#[inline(always)]
+pub unsafe fn avx2_load_si256<const ALIGNED: bool>(src: *const u8) -> __m256i {
+    if ALIGNED {
+        _mm256_load_si256(src as *const __m256i)
+    } else {
+        _mm256_loadu_si256(src as *const __m256i)
+    }
+}
+
+/// Stores 64 bytes, with `ALIGNED` `dst` must be 64-byte aligned
+#[inline(always)]
+pub unsafe fn avx512_store_si512<const ALIGNED: bool>(dst: *mut u8, v: __m512i) {
+    if ALIGNED {
+        _mm512_store_si512(dst as *mut _, v);
+    } else {
+        _mm512_storeu_si512(dst as *mut _, v);
+    }
+}
+
+/// Stores 64 RGB pixels, with `ALIGNED` `dst` must be 64-byte aligned
 #[inline]
-pub unsafe fn avx512_rgb_u8(dst: *mut u8, a: __m512i, b: __m512i, c: __m512i) {
+pub unsafe fn avx512_store_rgb_u8<const ALIGNED: bool>(
+    dst: *mut u8,
+    a: __m512i,
+    b: __m512i,
+    c: __m512i,
+) {
     let (rgb0, rgb1, rgb2) = avx512_interleave_rgb(a, b, c);
-    _mm512_storeu_si512(dst as *mut i32, rgb0);
-    _mm512_storeu_si512(dst.add(64) as *mut i32, rgb1);
-    _mm512_storeu_si512(dst.add(128) as *mut i32, rgb2);
+    avx512_store_si512::<ALIGNED>(dst, rgb0);
+    avx512_store_si512::<ALIGNED>(dst.add(64), rgb1);
+    avx512_store_si512::<ALIGNED>(dst.add(128), rgb2);
+}
+
+#[inline]
+pub unsafe fn avx512_rgb_u8(dst: *mut u8, a: __m512i, b: __m512i, c: __m512i) {
+    avx512_store_rgb_u8::<false>(dst, a, b, c);
 }
 
 #[inline]
@@ -109,13 +151,36 @@ pub unsafe fn avx512_interleave_rgba(
     (bgra0, bgra1, bgra2, bgra3)
 }
 
+/// Stores 64 RGBA pixels, with `ALIGNED` `dst` must be 64-byte aligned
 #[inline]
-pub unsafe fn avx512_rgba_u8(dst: *mut u8, a: __m512i, b: __m512i, c: __m512i, d: __m512i) {
+pub unsafe fn avx512_store_rgba_u8<const ALIGNED: bool>(
+    dst: *mut u8,
+    a: __m512i,
+    b: __m512i,
+    c: __m512i,
+    d: __m512i,
+) {
     let (rgb0, rgb1, rgb2, rgb3) = avx512_interleave_rgba(a, b, c, d);
-    _mm512_storeu_si512(dst as *mut i32, rgb0);
-    _mm512_storeu_si512(dst.add(64) as *mut i32, rgb1);
-    _mm512_storeu_si512(dst.add(128) as *mut i32, rgb2);
-    _mm512_storeu_si512(dst.add(128 + 64) as *mut i32, rgb3);
+    avx512_store_si512::<ALIGNED>(dst, rgb0);
+    avx512_store_si512::<ALIGNED>(dst.add(64), rgb1);
+    avx512_store_si512::<ALIGNED>(dst.add(128), rgb2);
+    avx512_store_si512::<ALIGNED>(dst.add(128 + 64), rgb3);
+}
+
+#[inline]
+pub unsafe fn avx512_rgba_u8(dst: *mut u8, a: __m512i, b: __m512i, c: __m512i, d: __m512i) {
+    avx512_store_rgba_u8::<false>(dst, a, b, c, d);
+}
+
+/// Stores 64 RGBX pixels, the 4th byte of every destination pixel is masked out and never written
+#[inline]
+pub unsafe fn avx512_rgbx_u8(dst: *mut u8, a: __m512i, b: __m512i, c: __m512i) {
+    let (rgb0, rgb1, rgb2, rgb3) = avx512_interleave_rgba(a, b, c, _mm512_setzero_si512());
+    const MASK: __mmask64 = 0x7777_7777_7777_7777;
+    _mm512_mask_storeu_epi8(dst as *mut i8, MASK, rgb0);
+    _mm512_mask_storeu_epi8(dst.add(64) as *mut i8, MASK, rgb1);
+    _mm512_mask_storeu_epi8(dst.add(128) as *mut i8, MASK, rgb2);
+    _mm512_mask_storeu_epi8(dst.add(128 + 64) as *mut i8, MASK, rgb3);
 }
 
 #[inline]
@@ -272,3 +337,33 @@ pub unsafe fn avx2_zip(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
     let b1 = _mm256_unpackhi_epi8(v0, v1);
     (b0, b1)
 }
+
+/// Splits 128 interleaved bytes into even and odd bytes, 64 each
+#[inline]
+pub unsafe fn avx512_deinterleave_x2_epi8(a: __m512i, b: __m512i) -> (__m512i, __m512i) {
+    let low_mask = _mm512_set1_epi16(0x00ff);
+    let even = avx512_pack_u16(_mm512_and_si512(a, low_mask), _mm512_and_si512(b, low_mask));
+    let odd = avx512_pack_u16(_mm512_srli_epi16::<8>(a), _mm512_srli_epi16::<8>(b));
+    (even, odd)
+}
+
+/// Splits 32 packed 4:2:2 macro-pixels into 64 luma values and 32 values for each chroma
+#[inline]
+pub unsafe fn avx512_deinterleave_yuy2(
+    yuy2_source: Yuy2Description,
+    j0: __m512i,
+    j1: __m512i,
+) -> (__m512i, __m256i, __m256i) {
+    let (even, odd) = avx512_deinterleave_x2_epi8(j0, j1);
+    let (y_values, chroma) = match yuy2_source {
+        Yuy2Description::YUYV | Yuy2Description::YVYU => (even, odd),
+        Yuy2Description::UYVY | Yuy2Description::VYUY => (odd, even),
+    };
+    let (c_even, c_odd) = avx512_deinterleave_x2_epi8(chroma, _mm512_setzero_si512());
+    let c_even = _mm512_castsi512_si256(c_even);
+    let c_odd = _mm512_castsi512_si256(c_odd);
+    match yuy2_source {
+        Yuy2Description::YUYV | Yuy2Description::UYVY => (y_values, c_even, c_odd),
+        Yuy2Description::YVYU | Yuy2Description::VYUY => (y_values, c_odd, c_even),
+    }
+}
@@ -39,6 +39,8 @@ mod ycgco_to_rgba_alpha;
 mod yuv_nv_to_rgba;
 mod yuv_to_rgba;
 mod yuv_to_rgba_alpha;
+mod yuy2_to_rgb;
+mod yuy2_to_yuv;
 
 pub use rgb_to_y::avx512_row_rgb_to_y;
 pub use rgb_to_ycgco::avx512_rgb_to_ycgco_row;
@@ -49,3 +51,5 @@ pub use ycgco_to_rgba_alpha::avx512_ycgco_to_rgba_alpha;
 pub use yuv_nv_to_rgba::avx512_yuv_nv_to_rgba;
 pub use yuv_to_rgba::avx512_yuv_to_rgba;
 pub use yuv_to_rgba_alpha::avx512_yuv_to_rgba_alpha;
+pub use yuy2_to_rgb::yuy2_to_rgb_avx512;
+pub use yuy2_to_yuv::yuy2_to_yuv_avx512;
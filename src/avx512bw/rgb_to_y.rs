@@ -86,7 +86,10 @@ pub unsafe fn avx512_row_rgb_to_y<const ORIGIN_CHANNELS: u8>(
                     b_values = it1;
                 }
             }
-            YuvSourceChannels::Rgba | YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Rgba
+            | YuvSourceChannels::Bgra
+            | YuvSourceChannels::Rgbx
+            | YuvSourceChannels::Bgrx => {
                 let source_ptr = rgba_ptr.add(px);
                 let row_1 = _mm512_loadu_si512(source_ptr as *const i32);
                 let row_2 = _mm512_loadu_si512(source_ptr.add(64) as *const i32);
@@ -94,7 +97,10 @@ pub unsafe fn avx512_row_rgb_to_y<const ORIGIN_CHANNELS: u8>(
                 let row_4 = _mm512_loadu_si512(source_ptr.add(128 + 64) as *const i32);
 
                 let (it1, it2, it3, _) = avx512_deinterleave_rgba(row_1, row_2, row_3, row_4);
-                if source_channels == YuvSourceChannels::Rgba {
+                if matches!(
+                    source_channels,
+                    YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx
+                ) {
                     r_values = it1;
                     g_values = it2;
                     b_values = it3;
@@ -90,7 +90,7 @@ pub unsafe fn avx512_y_to_rgb_row<const DESTINATION_CHANNELS: u8>(
                 let ptr = rgba_ptr.add(dst_shift);
                 avx512_rgb_u8(ptr, r_values, r_values, r_values);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 avx512_rgba_u8(
                     rgba_ptr.add(dst_shift),
                     r_values,
@@ -99,7 +99,7 @@ pub unsafe fn avx512_y_to_rgb_row<const DESTINATION_CHANNELS: u8>(
                     v_alpha,
                 );
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 avx512_rgba_u8(
                     rgba_ptr.add(dst_shift),
                     r_values,
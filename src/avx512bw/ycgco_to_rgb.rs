@@ -191,7 +191,7 @@ pub unsafe fn avx512_ycgco_to_rgb_row<const DESTINATION_CHANNELS: u8, const SAMP
                 let ptr = rgba_ptr.add(dst_shift);
                 avx512_rgb_u8(ptr, b_values, g_values, r_values);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 avx512_rgba_u8(
                     rgba_ptr.add(dst_shift),
                     r_values,
@@ -200,7 +200,7 @@ pub unsafe fn avx512_ycgco_to_rgb_row<const DESTINATION_CHANNELS: u8, const SAMP
                     v_alpha,
                 );
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 avx512_rgba_u8(
                     rgba_ptr.add(dst_shift),
                     b_values,
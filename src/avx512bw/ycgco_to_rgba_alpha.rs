@@ -214,7 +214,7 @@ pub unsafe fn avx512_ycgco_to_rgba_alpha<const DESTINATION_CHANNELS: u8, const S
                 let ptr = rgba_ptr.add(dst_shift);
                 avx512_rgb_u8(ptr, b_values, g_values, r_values);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 avx512_rgba_u8(
                     rgba_ptr.add(dst_shift),
                     r_values,
@@ -223,7 +223,7 @@ pub unsafe fn avx512_ycgco_to_rgba_alpha<const DESTINATION_CHANNELS: u8, const S
                     v_alpha,
                 );
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 avx512_rgba_u8(
                     rgba_ptr.add(dst_shift),
                     b_values,
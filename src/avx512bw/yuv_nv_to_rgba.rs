@@ -29,7 +29,7 @@
 
 use crate::avx512bw::avx512_utils::{
     avx512_interleave_even_epi8, avx512_interleave_odd_epi8, avx512_pack_u16, avx512_rgb_u8,
-    avx512_rgba_u8,
+    avx512_rgba_u8, avx512_rgbx_u8,
 };
 use crate::internals::ProcessedOffset;
 use crate::yuv_support::{
@@ -57,6 +57,7 @@ pub unsafe fn avx512_yuv_nv_to_rgba<
     uv_offset: usize,
     rgba_offset: usize,
     width: usize,
+    alpha: u8,
 ) -> ProcessedOffset {
     let order: YuvNVOrder = UV_ORDER.into();
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
@@ -77,7 +78,7 @@ pub unsafe fn avx512_yuv_nv_to_rgba<
     let v_min_values = _mm512_setzero_si512();
     let v_g_coeff_1 = _mm512_set1_epi16(-(transform.g_coeff_1 as i16));
     let v_g_coeff_2 = _mm512_set1_epi16(-(transform.g_coeff_2 as i16));
-    let v_alpha = _mm512_set1_epi8(255u8 as i8);
+    let v_alpha = _mm512_set1_epi8(alpha as i8);
     let rounding_const = _mm512_set1_epi16(1 << 5);
 
     while cx + 32 < width {
@@ -239,6 +240,12 @@ pub unsafe fn avx512_yuv_nv_to_rgba<
                     v_alpha,
                 );
             }
+            YuvSourceChannels::Rgbx => {
+                avx512_rgbx_u8(rgba_ptr.add(dst_shift), r_values, g_values, b_values);
+            }
+            YuvSourceChannels::Bgrx => {
+                avx512_rgbx_u8(rgba_ptr.add(dst_shift), b_values, g_values, r_values);
+            }
         }
 
         cx += 64;
@@ -27,7 +27,10 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
-use crate::avx512bw::avx512_utils::{avx512_pack_u16, avx512_rgb_u8, avx512_rgba_u8, shuffle};
+use crate::avx512bw::avx512_utils::{
+    avx2_load_si256, avx512_load_si512, avx512_pack_u16, avx512_rgbx_u8, avx512_store_rgb_u8,
+    avx512_store_rgba_u8, shuffle,
+};
 use crate::internals::ProcessedOffset;
 use crate::yuv_support::{
     CbCrInverseTransform, YuvChromaRange, YuvChromaSample, YuvSourceChannels,
@@ -37,8 +40,14 @@ use std::arch::x86::*;
 #[cfg(target_arch = "x86_64")]
 use std::arch::x86_64::*;
 
+/// With `ALIGNED` rows of every plane must start at 64-byte boundary and `start_cx` must be
+/// a multiple of 64, so all full width loads and stores are aligned.
 #[target_feature(enable = "avx512bw")]
-pub unsafe fn avx512_yuv_to_rgba<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
+pub unsafe fn avx512_yuv_to_rgba<
+    const DESTINATION_CHANNELS: u8,
+    const SAMPLING: u8,
+    const ALIGNED: bool,
+>(
     range: &YuvChromaRange,
     transform: &CbCrInverseTransform<i32>,
     y_plane: &[u8],
@@ -52,6 +61,7 @@ pub unsafe fn avx512_yuv_to_rgba<const DESTINATION_CHANNELS: u8, const SAMPLING:
     v_offset: usize,
     rgba_offset: usize,
     width: usize,
+    alpha: u8,
 ) -> ProcessedOffset {
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
@@ -72,12 +82,12 @@ pub unsafe fn avx512_yuv_to_rgba<const DESTINATION_CHANNELS: u8, const SAMPLING:
     let v_min_values = _mm512_setzero_si512();
     let v_g_coeff_1 = _mm512_set1_epi16(-(transform.g_coeff_1 as i16));
     let v_g_coeff_2 = _mm512_set1_epi16(-(transform.g_coeff_2 as i16));
-    let v_alpha = _mm512_set1_epi8(255u8 as i8);
+    let v_alpha = _mm512_set1_epi8(alpha as i8);
     let rounding_const = _mm512_set1_epi16(1 << 5);
 
-    while cx + 64 < width {
+    while cx + 64 <= width {
         let y_values = _mm512_subs_epu8(
-            _mm512_loadu_si512(y_ptr.add(y_offset + cx) as *const i32),
+            avx512_load_si512::<ALIGNED>(y_ptr.add(y_offset + cx)),
             y_corr,
         );
 
@@ -85,8 +95,8 @@ pub unsafe fn avx512_yuv_to_rgba<const DESTINATION_CHANNELS: u8, const SAMPLING:
 
         match chroma_subsampling {
             YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => {
-                let u_values = _mm256_loadu_si256(u_ptr.add(u_offset + uv_x) as *const __m256i);
-                let v_values = _mm256_loadu_si256(v_ptr.add(v_offset + uv_x) as *const __m256i);
+                let u_values = avx2_load_si256::<ALIGNED>(u_ptr.add(u_offset + uv_x));
+                let v_values = avx2_load_si256::<ALIGNED>(v_ptr.add(v_offset + uv_x));
 
                 const MASK: i32 = shuffle(3, 1, 2, 0);
                 u_high_u8 =
@@ -99,8 +109,8 @@ pub unsafe fn avx512_yuv_to_rgba<const DESTINATION_CHANNELS: u8, const SAMPLING:
                     _mm256_permute4x64_epi64::<MASK>(_mm256_unpacklo_epi8(v_values, v_values));
             }
             YuvChromaSample::YUV444 => {
-                let u_values = _mm512_loadu_si512(u_ptr.add(u_offset + uv_x) as *const i32);
-                let v_values = _mm512_loadu_si512(v_ptr.add(v_offset + uv_x) as *const i32);
+                let u_values = avx512_load_si512::<ALIGNED>(u_ptr.add(u_offset + uv_x));
+                let v_values = avx512_load_si512::<ALIGNED>(v_ptr.add(v_offset + uv_x));
 
                 u_high_u8 = _mm512_extracti64x4_epi64::<1>(u_values);
                 v_high_u8 = _mm512_extracti64x4_epi64::<1>(v_values);
@@ -188,14 +198,14 @@ pub unsafe fn avx512_yuv_to_rgba<const DESTINATION_CHANNELS: u8, const SAMPLING:
         match destination_channels {
             YuvSourceChannels::Rgb => {
                 let ptr = rgba_ptr.add(dst_shift);
-                avx512_rgb_u8(ptr, r_values, g_values, b_values);
+                avx512_store_rgb_u8::<ALIGNED>(ptr, r_values, g_values, b_values);
             }
             YuvSourceChannels::Bgr => {
                 let ptr = rgba_ptr.add(dst_shift);
-                avx512_rgb_u8(ptr, b_values, g_values, r_values);
+                avx512_store_rgb_u8::<ALIGNED>(ptr, b_values, g_values, r_values);
             }
             YuvSourceChannels::Rgba => {
-                avx512_rgba_u8(
+                avx512_store_rgba_u8::<ALIGNED>(
                     rgba_ptr.add(dst_shift),
                     r_values,
                     g_values,
@@ -204,7 +214,7 @@ pub unsafe fn avx512_yuv_to_rgba<const DESTINATION_CHANNELS: u8, const SAMPLING:
                 );
             }
             YuvSourceChannels::Bgra => {
-                avx512_rgba_u8(
+                avx512_store_rgba_u8::<ALIGNED>(
                     rgba_ptr.add(dst_shift),
                     b_values,
                     g_values,
@@ -212,6 +222,12 @@ pub unsafe fn avx512_yuv_to_rgba<const DESTINATION_CHANNELS: u8, const SAMPLING:
                     v_alpha,
                 );
             }
+            YuvSourceChannels::Rgbx => {
+                avx512_rgbx_u8(rgba_ptr.add(dst_shift), r_values, g_values, b_values);
+            }
+            YuvSourceChannels::Bgrx => {
+                avx512_rgbx_u8(rgba_ptr.add(dst_shift), b_values, g_values, r_values);
+            }
         }
 
         cx += 64;
@@ -0,0 +1,225 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::avx512bw::avx512_utils::{
+    avx512_deinterleave_yuy2, avx512_pack_u16, avx512_rgb_u8, avx512_rgba_u8, avx512_rgbx_u8,
+};
+use crate::yuv_support::{
+    CbCrInverseTransform, YuvChromaRange, YuvSourceChannels, Yuy2Description,
+};
+use crate::yuv_to_yuy2::YuvToYuy2Navigation;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+pub fn yuy2_to_rgb_avx512<const DST_CHANNELS: u8, const YUY2_TARGET: usize>(
+    range: &YuvChromaRange,
+    transform: &CbCrInverseTransform<i32>,
+    yuy2_store: &[u8],
+    yuy2_offset: usize,
+    rgb: &mut [u8],
+    rgb_offset: usize,
+    width: u32,
+    nav: YuvToYuy2Navigation,
+) -> YuvToYuy2Navigation {
+    unsafe {
+        yuy2_to_rgb_avx512_impl::<DST_CHANNELS, YUY2_TARGET>(
+            range,
+            transform,
+            yuy2_store,
+            yuy2_offset,
+            rgb,
+            rgb_offset,
+            width,
+            nav,
+        )
+    }
+}
+
+#[target_feature(enable = "avx512bw")]
+unsafe fn yuy2_to_rgb_avx512_impl<const DST_CHANNELS: u8, const YUY2_TARGET: usize>(
+    range: &YuvChromaRange,
+    transform: &CbCrInverseTransform<i32>,
+    yuy2_store: &[u8],
+    yuy2_offset: usize,
+    rgb: &mut [u8],
+    rgb_offset: usize,
+    width: u32,
+    nav: YuvToYuy2Navigation,
+) -> YuvToYuy2Navigation {
+    let yuy2_source: Yuy2Description = YUY2_TARGET.into();
+    let dst_chans: YuvSourceChannels = DST_CHANNELS.into();
+
+    let mut _cx = nav.cx;
+    let mut _yuy2_x = nav.x;
+
+    let max_x_32 = (width as usize / 2).saturating_sub(32);
+
+    let y_corr = _mm512_set1_epi8(range.bias_y as i8);
+    let uv_corr = _mm512_set1_epi16(range.bias_uv as i16);
+    let v_luma_coeff = _mm512_set1_epi16(transform.y_coef as i16);
+    let v_cr_coeff = _mm512_set1_epi16(transform.cr_coef as i16);
+    let v_cb_coeff = _mm512_set1_epi16(transform.cb_coef as i16);
+    let v_min_values = _mm512_setzero_si512();
+    let v_g_coeff_1 = _mm512_set1_epi16(-(transform.g_coeff_1 as i16));
+    let v_g_coeff_2 = _mm512_set1_epi16(-(transform.g_coeff_2 as i16));
+    let v_alpha = _mm512_set1_epi8(255u8 as i8);
+    let rounding_const = _mm512_set1_epi16(1 << 5);
+
+    for x in (_yuy2_x..max_x_32).step_by(32) {
+        let yuy2_offset = yuy2_offset + x * 4;
+        let dst_pos = rgb_offset + _cx * dst_chans.get_channels_count();
+        let dst_ptr = rgb.as_mut_ptr().add(dst_pos);
+
+        let yuy2_ptr = yuy2_store.as_ptr().add(yuy2_offset);
+
+        let j0 = _mm512_loadu_si512(yuy2_ptr as *const i32);
+        let j1 = _mm512_loadu_si512(yuy2_ptr.add(64) as *const i32);
+
+        let (y_values, u_values, v_values) = avx512_deinterleave_yuy2(yuy2_source, j0, j1);
+
+        let y_values = _mm512_subs_epu8(y_values, y_corr);
+
+        let u_wide = _mm512_cvtepu8_epi16(u_values);
+        let v_wide = _mm512_cvtepu8_epi16(v_values);
+        let u_doubled = _mm512_or_si512(u_wide, _mm512_slli_epi16::<8>(u_wide));
+        let v_doubled = _mm512_or_si512(v_wide, _mm512_slli_epi16::<8>(v_wide));
+
+        let u_high_u8 = _mm512_extracti64x4_epi64::<1>(u_doubled);
+        let v_high_u8 = _mm512_extracti64x4_epi64::<1>(v_doubled);
+        let u_low_u8 = _mm512_castsi512_si256(u_doubled);
+        let v_low_u8 = _mm512_castsi512_si256(v_doubled);
+
+        let u_high = _mm512_subs_epi16(_mm512_cvtepu8_epi16(u_high_u8), uv_corr);
+        let v_high = _mm512_subs_epi16(_mm512_cvtepu8_epi16(v_high_u8), uv_corr);
+        let y_high = _mm512_mullo_epi16(
+            _mm512_cvtepu8_epi16(_mm512_extracti64x4_epi64::<1>(y_values)),
+            v_luma_coeff,
+        );
+
+        let r_high = _mm512_srli_epi16::<6>(_mm512_adds_epi16(
+            _mm512_max_epi16(
+                _mm512_adds_epi16(y_high, _mm512_mullo_epi16(v_high, v_cr_coeff)),
+                v_min_values,
+            ),
+            rounding_const,
+        ));
+        let b_high = _mm512_srli_epi16::<6>(_mm512_adds_epi16(
+            _mm512_max_epi16(
+                _mm512_adds_epi16(y_high, _mm512_mullo_epi16(u_high, v_cb_coeff)),
+                v_min_values,
+            ),
+            rounding_const,
+        ));
+        let g_high = _mm512_srli_epi16::<6>(_mm512_adds_epi16(
+            _mm512_max_epi16(
+                _mm512_adds_epi16(
+                    y_high,
+                    _mm512_adds_epi16(
+                        _mm512_mullo_epi16(v_high, v_g_coeff_1),
+                        _mm512_mullo_epi16(u_high, v_g_coeff_2),
+                    ),
+                ),
+                v_min_values,
+            ),
+            rounding_const,
+        ));
+
+        let u_low = _mm512_subs_epi16(_mm512_cvtepu8_epi16(u_low_u8), uv_corr);
+        let v_low = _mm512_subs_epi16(_mm512_cvtepu8_epi16(v_low_u8), uv_corr);
+        let y_low = _mm512_mullo_epi16(
+            _mm512_cvtepu8_epi16(_mm512_castsi512_si256(y_values)),
+            v_luma_coeff,
+        );
+
+        let r_low = _mm512_srli_epi16::<6>(_mm512_adds_epi16(
+            _mm512_max_epi16(
+                _mm512_adds_epi16(y_low, _mm512_mullo_epi16(v_low, v_cr_coeff)),
+                v_min_values,
+            ),
+            rounding_const,
+        ));
+        let b_low = _mm512_srli_epi16::<6>(_mm512_adds_epi16(
+            _mm512_max_epi16(
+                _mm512_adds_epi16(y_low, _mm512_mullo_epi16(u_low, v_cb_coeff)),
+                v_min_values,
+            ),
+            rounding_const,
+        ));
+        let g_low = _mm512_srli_epi16::<6>(_mm512_adds_epi16(
+            _mm512_max_epi16(
+                _mm512_adds_epi16(
+                    y_low,
+                    _mm512_adds_epi16(
+                        _mm512_mullo_epi16(v_low, v_g_coeff_1),
+                        _mm512_mullo_epi16(u_low, v_g_coeff_2),
+                    ),
+                ),
+                v_min_values,
+            ),
+            rounding_const,
+        ));
+
+        let r_values = avx512_pack_u16(r_low, r_high);
+        let g_values = avx512_pack_u16(g_low, g_high);
+        let b_values = avx512_pack_u16(b_low, b_high);
+
+        match dst_chans {
+            YuvSourceChannels::Rgb => {
+                avx512_rgb_u8(dst_ptr, r_values, g_values, b_values);
+            }
+            YuvSourceChannels::Bgr => {
+                avx512_rgb_u8(dst_ptr, b_values, g_values, r_values);
+            }
+            YuvSourceChannels::Rgba => {
+                avx512_rgba_u8(dst_ptr, r_values, g_values, b_values, v_alpha);
+            }
+            YuvSourceChannels::Bgra => {
+                avx512_rgba_u8(dst_ptr, b_values, g_values, r_values, v_alpha);
+            }
+            YuvSourceChannels::Rgbx => {
+                avx512_rgbx_u8(dst_ptr, r_values, g_values, b_values);
+            }
+            YuvSourceChannels::Bgrx => {
+                avx512_rgbx_u8(dst_ptr, b_values, g_values, r_values);
+            }
+        }
+
+        _yuy2_x = x;
+        if x + 32 < max_x_32 {
+            _cx += 64;
+        }
+    }
+
+    YuvToYuy2Navigation {
+        cx: _cx,
+        uv_x: 0,
+        x: _yuy2_x,
+    }
+}
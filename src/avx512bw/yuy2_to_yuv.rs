@@ -0,0 +1,102 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::avx512bw::avx512_utils::avx512_deinterleave_yuy2;
+use crate::yuv_support::{YuvChromaSample, Yuy2Description};
+use crate::yuv_to_yuy2::YuvToYuy2Navigation;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[target_feature(enable = "avx512bw")]
+pub unsafe fn yuy2_to_yuv_avx512<const SAMPLING: u8, const YUY2_TARGET: usize>(
+    y_plane: &mut [u8],
+    y_offset: usize,
+    u_plane: &mut [u8],
+    u_offset: usize,
+    v_plane: &mut [u8],
+    v_offset: usize,
+    yuy2_store: &[u8],
+    yuy2_offset: usize,
+    width: u32,
+    nav: YuvToYuy2Navigation,
+) -> YuvToYuy2Navigation {
+    let yuy2_source: Yuy2Description = YUY2_TARGET.into();
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+
+    let mut _cx = nav.cx;
+    let mut _uv_x = nav.uv_x;
+    let mut _yuy2_x = nav.x;
+
+    let max_x_32 = (width as usize / 2).saturating_sub(32);
+
+    for x in (_yuy2_x..max_x_32).step_by(32) {
+        let src_offset = yuy2_offset + x * 4;
+        let u_pos = u_offset + _uv_x;
+        let v_pos = v_offset + _uv_x;
+        let y_pos = y_offset + _cx;
+
+        let yuy2_ptr = yuy2_store.as_ptr().add(src_offset);
+
+        let j0 = _mm512_loadu_si512(yuy2_ptr as *const i32);
+        let j1 = _mm512_loadu_si512(yuy2_ptr.add(64) as *const i32);
+
+        let (y_values, u_value, v_value) = avx512_deinterleave_yuy2(yuy2_source, j0, j1);
+
+        if chroma_subsampling == YuvChromaSample::YUV444 {
+            let u_wide = _mm512_cvtepu8_epi16(u_value);
+            let v_wide = _mm512_cvtepu8_epi16(v_value);
+            let u_doubled = _mm512_or_si512(u_wide, _mm512_slli_epi16::<8>(u_wide));
+            let v_doubled = _mm512_or_si512(v_wide, _mm512_slli_epi16::<8>(v_wide));
+
+            _mm512_storeu_si512(u_plane.as_mut_ptr().add(u_pos) as *mut i32, u_doubled);
+            _mm512_storeu_si512(v_plane.as_mut_ptr().add(v_pos) as *mut i32, v_doubled);
+        } else {
+            _mm256_storeu_si256(u_plane.as_mut_ptr().add(u_pos) as *mut __m256i, u_value);
+            _mm256_storeu_si256(v_plane.as_mut_ptr().add(v_pos) as *mut __m256i, v_value);
+        }
+
+        _mm512_storeu_si512(y_plane.as_mut_ptr().add(y_pos) as *mut i32, y_values);
+
+        _yuy2_x = x;
+        if x + 32 < max_x_32 {
+            _uv_x += match chroma_subsampling {
+                YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => 32,
+                YuvChromaSample::YUV444 => 64,
+            };
+            _cx += 64;
+        }
+    }
+
+    YuvToYuy2Navigation {
+        cx: _cx,
+        uv_x: _uv_x,
+        x: _yuy2_x,
+    }
+}
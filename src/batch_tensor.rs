@@ -0,0 +1,320 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_vec, ImageSize, YuvPlanarImage};
+use crate::yuv_error::MismatchedSize;
+use crate::yuv_planar_generic::yuv_planar_to_rgb_f32;
+use crate::yuv_support::p16_dispatch;
+use crate::yuv_support::{
+    YuvBytesPacking, YuvChromaSample, YuvEndianness, YuvRange, YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+/// Memory order of a single frame inside a float tensor
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum TensorLayout {
+    /// Planar, all R samples, then all G, then all B
+    #[default]
+    Chw,
+    /// Interleaved, RGB triplets row by row
+    Hwc,
+}
+
+/// Per channel normalization applied as `(value / max_value - mean) / std`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TensorNormalization {
+    pub mean: [f32; 3],
+    pub std: [f32; 3],
+}
+
+impl Default for TensorNormalization {
+    /// Plain `[0, 1]` scaling
+    fn default() -> Self {
+        TensorNormalization {
+            mean: [0.; 3],
+            std: [1.; 3],
+        }
+    }
+}
+
+/// Builds per channel tables mapping every code value at `bit_depth` to its normalized float
+fn normalization_luts(
+    normalization: TensorNormalization,
+    bit_depth: usize,
+) -> Result<[Vec<f32>; 3], YuvError> {
+    if normalization.std.iter().any(|&x| !x.is_finite() || x == 0.) {
+        return Err(YuvError::InvalidTensorNormalization);
+    }
+    let max_value = (1usize << bit_depth) - 1;
+    let mut luts = [
+        try_vec(0f32, max_value + 1)?,
+        try_vec(0f32, max_value + 1)?,
+        try_vec(0f32, max_value + 1)?,
+    ];
+    for ((table, &mean), &std) in luts
+        .iter_mut()
+        .zip(normalization.mean.iter())
+        .zip(normalization.std.iter())
+    {
+        let scale = 1. / (max_value as f32 * std);
+        let bias = -mean / std;
+        for (code, dst) in table.iter_mut().enumerate() {
+            *dst = code as f32 * scale + bias;
+        }
+    }
+    Ok(luts)
+}
+
+/// Converts `n` 8-bit planar YUV frames stored back to back into a RGB float tensor.
+///
+/// Every frame is tightly packed, Y plane of `width` x `height` followed by U and V planes
+/// of chroma size, rows have no padding. Output frames follow each other in `tensor` as well,
+/// so with [TensorLayout::Chw] the result is a NCHW tensor and with [TensorLayout::Hwc] NHWC one.
+///
+/// Normalization is fused into the conversion through per channel lookup tables,
+/// with `rayon` feature frames are converted in parallel.
+///
+/// # Arguments
+///
+/// * `frames` - Source buffer holding exactly `n` frames.
+/// * `n` - Number of frames.
+/// * `size` - The size of the image.
+/// * `sampling` - Chroma subsampling of the frames.
+/// * `tensor` - Destination tensor of `n * 3 * width * height` values.
+/// * `layout` - Memory order of each output frame.
+/// * `normalization` - Mean and standard deviation per RGB channel.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_batch_to_rgb_tensor(
+    frames: &[u8],
+    n: usize,
+    size: ImageSize,
+    sampling: YuvChromaSample,
+    tensor: &mut [f32],
+    layout: TensorLayout,
+    normalization: TensorNormalization,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    if n == 0 || width == 0 || height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    let w = width as usize;
+    let h = height as usize;
+    let (chroma_width, chroma_height) = match sampling {
+        YuvChromaSample::YUV420 => (w.div_ceil(2), h.div_ceil(2)),
+        YuvChromaSample::YUV422 => (w.div_ceil(2), h),
+        YuvChromaSample::YUV444 => (w, h),
+    };
+    let luma_size = w * h;
+    let chroma_size = chroma_width * chroma_height;
+    let frame_size = luma_size + 2 * chroma_size;
+    let tensor_frame_size = luma_size * 3;
+
+    let expected_frames = frame_size.checked_mul(n).ok_or(YuvError::PointerOverflow)?;
+    if frames.len() != expected_frames {
+        return Err(YuvError::PackedFrameSizeMismatch(MismatchedSize {
+            expected: expected_frames,
+            received: frames.len(),
+        }));
+    }
+    let expected_tensor = tensor_frame_size
+        .checked_mul(n)
+        .ok_or(YuvError::PointerOverflow)?;
+    if tensor.len() != expected_tensor {
+        return Err(YuvError::DestinationSizeMismatch(MismatchedSize {
+            expected: expected_tensor,
+            received: tensor.len(),
+        }));
+    }
+
+    let luts = normalization_luts(normalization, 8)?;
+
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => {
+            yuv_planar_to_rgb_f32::<
+                u8,
+                { YuvChromaSample::YUV420 as u8 },
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+            >
+        }
+        YuvChromaSample::YUV422 => {
+            yuv_planar_to_rgb_f32::<
+                u8,
+                { YuvChromaSample::YUV422 as u8 },
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+            >
+        }
+        YuvChromaSample::YUV444 => {
+            yuv_planar_to_rgb_f32::<
+                u8,
+                { YuvChromaSample::YUV444 as u8 },
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+            >
+        }
+    };
+
+    let convert_frame = |(frame, dst): (&[u8], &mut [f32])| -> Result<(), YuvError> {
+        let (y_plane, chroma) = frame.split_at(luma_size);
+        let (u_plane, v_plane) = chroma.split_at(chroma_size);
+        handler(
+            y_plane,
+            width,
+            u_plane,
+            chroma_width as u32,
+            v_plane,
+            chroma_width as u32,
+            dst,
+            layout,
+            width,
+            height,
+            range,
+            matrix,
+            8,
+            &luts,
+        )
+    };
+
+    #[cfg(feature = "rayon")]
+    {
+        frames
+            .par_chunks_exact(frame_size)
+            .zip(tensor.par_chunks_exact_mut(tensor_frame_size))
+            .try_for_each(convert_frame)
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        frames
+            .chunks_exact(frame_size)
+            .zip(tensor.chunks_exact_mut(tensor_frame_size))
+            .try_for_each(convert_frame)
+    }
+}
+
+/// Converts 8-bit YUV 4:2:0 image into planar RGB float, as expected by inference engines.
+///
+/// Conversion and normalization are fused, `dst` receives R plane, then G and B planes,
+/// each of `width` x `height` values without padding.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `dst` - Destination of `3 * width * height` values.
+/// * `normalization` - Mean and standard deviation per RGB channel.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv420_to_rgb_f32_chw(
+    image: &YuvPlanarImage<u8>,
+    dst: &mut [f32],
+    normalization: TensorNormalization,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let luts = normalization_luts(normalization, 8)?;
+    yuv_planar_to_rgb_f32::<
+        u8,
+        { YuvChromaSample::YUV420 as u8 },
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::LeastSignificantBytes as u8 },
+    >(
+        image.y_plane,
+        image.y_stride.get(),
+        image.u_plane,
+        image.u_stride.get(),
+        image.v_plane,
+        image.v_stride.get(),
+        dst,
+        TensorLayout::Chw,
+        image.size.width,
+        image.size.height,
+        range,
+        matrix,
+        8,
+        &luts,
+    )
+}
+
+/// Converts 10-bit YUV 4:2:0 image into planar RGB float, as expected by inference engines.
+///
+/// Conversion and normalization are fused, `dst` receives R plane, then G and B planes,
+/// each of `width` x `height` values without padding. Values are normalized against 1023.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image, strides are in bytes.
+/// * `dst` - Destination of `3 * width * height` values.
+/// * `normalization` - Mean and standard deviation per RGB channel.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes.
+/// * `bytes_packing` - Position of significant bytes ( most significant or least significant ).
+///
+pub fn yuv420_p10_to_rgb_f32_chw(
+    image: &YuvPlanarImage<u16>,
+    dst: &mut [f32],
+    normalization: TensorNormalization,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let luts = normalization_luts(normalization, 10)?;
+    let handler = p16_dispatch!(
+        yuv_planar_to_rgb_f32,
+        [u16, { YuvChromaSample::YUV420 as u8 }],
+        endianness,
+        bytes_packing
+    );
+    handler(
+        image.y_plane,
+        image.y_stride.get(),
+        image.u_plane,
+        image.u_stride.get(),
+        image.v_plane,
+        image.v_stride.get(),
+        dst,
+        TensorLayout::Chw,
+        image.size.width,
+        image.size.height,
+        range,
+        matrix,
+        10,
+        &luts,
+    )
+}
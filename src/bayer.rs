@@ -0,0 +1,549 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{RowStride, YuvBiPlanarImageMut, YuvPlanarImageMut};
+use crate::yuv_error::check_y8_channel;
+use crate::yuv_planar_generic::Pixel;
+use crate::yuv_support::{
+    get_forward_transform, get_yuv_range, CbCrForwardTransform, ToIntegerTransform,
+    YuvBytesPacking, YuvChromaSample, YuvEndianness, YuvRange, YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+/// Color filter array layout, named after the top left 2x2 block
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum BayerPattern {
+    Rggb,
+    Bggr,
+    Grbg,
+    Gbrg,
+}
+
+impl BayerPattern {
+    /// Position of the red site inside the top left 2x2 block
+    const fn red_site(self) -> (usize, usize) {
+        match self {
+            BayerPattern::Rggb => (0, 0),
+            BayerPattern::Grbg => (1, 0),
+            BayerPattern::Gbrg => (0, 1),
+            BayerPattern::Bggr => (1, 1),
+        }
+    }
+}
+
+/// Interpolation used to reconstruct missing color samples
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum BayerDemosaic {
+    /// Average of nearest samples of the same color, 3x3 neighbourhood
+    Bilinear,
+    /// Malvar-He-Cutler gradient corrected linear interpolation, 5x5 neighbourhood
+    #[default]
+    Malvar,
+}
+
+/// Color filter site of a mosaic sample
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum BayerSite {
+    Red,
+    /// Green with red horizontal neighbours
+    GreenRed,
+    /// Green with blue horizontal neighbours
+    GreenBlue,
+    Blue,
+}
+
+/// Mirrors coordinate around image edges without repeating the edge sample,
+/// so the color filter parity is kept.
+#[inline(always)]
+fn reflect(i: isize, n: usize) -> usize {
+    let last = n as isize - 1;
+    let i = if i < 0 { -i } else { i };
+    let i = if i > last { 2 * last - i } else { i };
+    i.clamp(0, last) as usize
+}
+
+struct BayerToYuv<'a, S: Pixel> {
+    bayer: &'a [S],
+    /// Stride in elements
+    bayer_stride: usize,
+    width: usize,
+    height: usize,
+    bit_depth: usize,
+    red_site: (usize, usize),
+    demosaic: BayerDemosaic,
+    transform: CbCrForwardTransform<i32>,
+    bias_y: i32,
+    bias_uv: i32,
+    min_value: i32,
+    cap_y: i32,
+    min_uv: i32,
+    cap_uv: i32,
+}
+
+impl<S: Pixel> BayerToYuv<'_, S> {
+    const PRECISION: i32 = 16;
+
+    #[inline(always)]
+    fn site(&self, x: usize, y: usize) -> BayerSite {
+        match ((x + self.red_site.0) & 1, (y + self.red_site.1) & 1) {
+            (0, 0) => BayerSite::Red,
+            (1, 0) => BayerSite::GreenRed,
+            (0, _) => BayerSite::GreenBlue,
+            _ => BayerSite::Blue,
+        }
+    }
+
+    #[inline(always)]
+    fn at(&self, x: usize, y: usize, dx: isize, dy: isize) -> i32 {
+        let x = reflect(x as isize + dx, self.width);
+        let y = reflect(y as isize + dy, self.height);
+        self.bayer[y * self.bayer_stride + x].load::<
+            { YuvEndianness::LittleEndian as u8 },
+            { YuvBytesPacking::LeastSignificantBytes as u8 },
+        >(self.bit_depth)
+    }
+
+    /// Reconstructs RGB at source bit depth
+    #[inline(always)]
+    fn rgb(&self, x: usize, y: usize) -> (i32, i32, i32) {
+        let site = self.site(x, y);
+        let c = self.at(x, y, 0, 0);
+        let horizontal = self.at(x, y, -1, 0) + self.at(x, y, 1, 0);
+        let vertical = self.at(x, y, 0, -1) + self.at(x, y, 0, 1);
+        let cross = horizontal + vertical;
+        let diagonal = self.at(x, y, -1, -1)
+            + self.at(x, y, 1, -1)
+            + self.at(x, y, -1, 1)
+            + self.at(x, y, 1, 1);
+
+        match self.demosaic {
+            BayerDemosaic::Bilinear => match site {
+                BayerSite::Red => (c, (cross + 2) >> 2, (diagonal + 2) >> 2),
+                BayerSite::GreenRed => ((horizontal + 1) >> 1, c, (vertical + 1) >> 1),
+                BayerSite::GreenBlue => ((vertical + 1) >> 1, c, (horizontal + 1) >> 1),
+                BayerSite::Blue => ((diagonal + 2) >> 2, (cross + 2) >> 2, c),
+            },
+            BayerDemosaic::Malvar => {
+                let max_value = (1i32 << self.bit_depth) - 1;
+                let horizontal2 = self.at(x, y, -2, 0) + self.at(x, y, 2, 0);
+                let vertical2 = self.at(x, y, 0, -2) + self.at(x, y, 0, 2);
+                let cross2 = horizontal2 + vertical2;
+                // Filter weights are doubled to keep half taps integer, sum of weights is 16
+                let filter = |v: i32| ((v + 8) >> 4).clamp(0, max_value);
+                let green_at_rb = filter(8 * c + 4 * cross - 2 * cross2);
+                let rb_at_opposite = filter(12 * c + 4 * diagonal - 3 * cross2);
+                let along_row =
+                    filter(10 * c + 8 * horizontal - 2 * diagonal - 2 * horizontal2 + vertical2);
+                let along_column =
+                    filter(10 * c + 8 * vertical - 2 * diagonal - 2 * vertical2 + horizontal2);
+                match site {
+                    BayerSite::Red => (c, green_at_rb, rb_at_opposite),
+                    BayerSite::GreenRed => (along_row, c, along_column),
+                    BayerSite::GreenBlue => (along_column, c, along_row),
+                    BayerSite::Blue => (rb_at_opposite, green_at_rb, c),
+                }
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn luma(&self, r: i32, g: i32, b: i32) -> u8 {
+        let t = &self.transform;
+        ((r * t.yr + g * t.yg + b * t.yb + self.bias_y) >> Self::PRECISION)
+            .clamp(self.min_value, self.cap_y) as u8
+    }
+
+    #[inline(always)]
+    fn chroma(&self, r: i32, g: i32, b: i32) -> (u8, u8) {
+        let t = &self.transform;
+        let cb = (r * t.cb_r + g * t.cb_g + b * t.cb_b + self.bias_uv) >> Self::PRECISION;
+        let cr = (r * t.cr_r + g * t.cr_g + b * t.cr_b + self.bias_uv) >> Self::PRECISION;
+        (
+            cb.clamp(self.min_uv, self.cap_uv) as u8,
+            cr.clamp(self.min_uv, self.cap_uv) as u8,
+        )
+    }
+
+    /// Demosaics luma rows `2 * chroma_y` and `2 * chroma_y + 1`, `store_chroma` receives
+    /// chroma column with Cb, Cr of the averaged 2x2 block.
+    fn convert_rows(
+        &self,
+        chroma_y: usize,
+        y_rows: &mut [u8],
+        y_stride: usize,
+        mut store_chroma: impl FnMut(usize, u8, u8),
+    ) {
+        let y0 = chroma_y * 2;
+        let rows = (self.height - y0).min(2);
+        for cx in 0..self.width.div_ceil(2) {
+            let x0 = cx * 2;
+            let columns = (self.width - x0).min(2);
+            let (mut r_sum, mut g_sum, mut b_sum) = (0i32, 0i32, 0i32);
+            for dy in 0..rows {
+                for dx in 0..columns {
+                    let (r, g, b) = self.rgb(x0 + dx, y0 + dy);
+                    y_rows[dy * y_stride + x0 + dx] = self.luma(r, g, b);
+                    r_sum += r;
+                    g_sum += g;
+                    b_sum += b;
+                }
+            }
+            let count = (rows * columns) as i32;
+            let (cb, cr) = self.chroma(
+                (r_sum + count / 2) / count,
+                (g_sum + count / 2) / count,
+                (b_sum + count / 2) / count,
+            );
+            store_chroma(cx, cb, cr);
+        }
+    }
+}
+
+fn bayer_context<S: Pixel>(
+    bayer: &[S],
+    bayer_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    pattern: BayerPattern,
+    demosaic: BayerDemosaic,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<BayerToYuv<'_, S>, YuvError> {
+    check_y8_channel(bayer, bayer_stride, width, height)?;
+    let range = get_yuv_range(8, range);
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
+    let transform = get_forward_transform(
+        (1u32 << bit_depth) - 1,
+        range.range_y,
+        range.range_uv,
+        kr_kb.kr,
+        kr_kb.kb,
+    )
+    .to_integers(BayerToYuv::<S>::PRECISION as u32);
+    let rounding = 1i32 << (BayerToYuv::<S>::PRECISION - 1);
+    Ok(BayerToYuv {
+        bayer,
+        bayer_stride: bayer_stride as usize / size_of::<S>(),
+        width: width as usize,
+        height: height as usize,
+        bit_depth: bit_depth as usize,
+        red_site: pattern.red_site(),
+        demosaic,
+        transform,
+        bias_y: range.bias_y as i32 * (1 << BayerToYuv::<S>::PRECISION) + rounding,
+        bias_uv: range.bias_uv as i32 * (1 << BayerToYuv::<S>::PRECISION) + rounding,
+        min_value: range.bias_y as i32,
+        cap_y: (range.bias_y + range.range_y) as i32,
+        min_uv: range.min_uv() as i32,
+        cap_uv: range.max_uv() as i32,
+    })
+}
+
+fn bayer_to_yuv420_impl<S: Pixel>(
+    bayer: &[S],
+    bayer_stride: u32,
+    bit_depth: u32,
+    pattern: BayerPattern,
+    demosaic: BayerDemosaic,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    planar_image.check_constraints(YuvChromaSample::YUV420)?;
+    let width = planar_image.size.width;
+    let height = planar_image.size.height;
+    let context = bayer_context(
+        bayer,
+        bayer_stride,
+        bit_depth,
+        width,
+        height,
+        pattern,
+        demosaic,
+        range,
+        matrix,
+    )?;
+
+    let y_stride = planar_image.y_stride.get() as usize;
+    let u_stride = planar_image.u_stride.get() as usize;
+    let v_stride = planar_image.v_stride.get() as usize;
+    let chroma_height = (height as usize).div_ceil(2);
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = planar_image
+            .y_plane
+            .borrow_mut()
+            .par_chunks_mut(y_stride * 2)
+            .zip(planar_image.u_plane.borrow_mut().par_chunks_mut(u_stride))
+            .zip(planar_image.v_plane.borrow_mut().par_chunks_mut(v_stride))
+            .take(chroma_height);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = planar_image
+            .y_plane
+            .borrow_mut()
+            .chunks_mut(y_stride * 2)
+            .zip(planar_image.u_plane.borrow_mut().chunks_mut(u_stride))
+            .zip(planar_image.v_plane.borrow_mut().chunks_mut(v_stride))
+            .take(chroma_height);
+    }
+    iter.enumerate()
+        .for_each(|(chroma_y, ((y_rows, u_row), v_row))| {
+            context.convert_rows(chroma_y, y_rows, y_stride, |cx, cb, cr| {
+                u_row[cx] = cb;
+                v_row[cx] = cr;
+            });
+        });
+
+    Ok(())
+}
+
+fn bayer_to_yuv_nv12_impl<S: Pixel>(
+    bayer: &[S],
+    bayer_stride: u32,
+    bit_depth: u32,
+    pattern: BayerPattern,
+    demosaic: BayerDemosaic,
+    bi_planar_image: &mut YuvBiPlanarImageMut<u8>,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    bi_planar_image.check_constraints(YuvChromaSample::YUV420)?;
+    let width = bi_planar_image.size.width;
+    let height = bi_planar_image.size.height;
+    let context = bayer_context(
+        bayer,
+        bayer_stride,
+        bit_depth,
+        width,
+        height,
+        pattern,
+        demosaic,
+        range,
+        matrix,
+    )?;
+
+    let y_stride = bi_planar_image.y_stride.get() as usize;
+    let uv_stride = bi_planar_image.uv_stride.get() as usize;
+    let chroma_height = (height as usize).div_ceil(2);
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = bi_planar_image
+            .y_plane
+            .borrow_mut()
+            .par_chunks_mut(y_stride * 2)
+            .zip(
+                bi_planar_image
+                    .uv_plane
+                    .borrow_mut()
+                    .par_chunks_mut(uv_stride),
+            )
+            .take(chroma_height);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = bi_planar_image
+            .y_plane
+            .borrow_mut()
+            .chunks_mut(y_stride * 2)
+            .zip(bi_planar_image.uv_plane.borrow_mut().chunks_mut(uv_stride))
+            .take(chroma_height);
+    }
+    iter.enumerate().for_each(|(chroma_y, (y_rows, uv_row))| {
+        context.convert_rows(chroma_y, y_rows, y_stride, |cx, cb, cr| {
+            uv_row[cx * 2] = cb;
+            uv_row[cx * 2 + 1] = cr;
+        });
+    });
+
+    Ok(())
+}
+
+/// Demosaics 8-bit Bayer mosaic straight into YUV 4:2:0 planar image.
+///
+/// Demosaicing is fused with RGB to YUV conversion, no intermediate RGB image is allocated.
+/// Chroma is taken from the average RGB of each 2x2 block, edges are mirrored.
+///
+/// # Arguments
+///
+/// * `bayer` - Source mosaic, one sample per pixel.
+/// * `bayer_stride` - Stride of the mosaic in bytes.
+/// * `pattern` - Color filter array layout of the mosaic.
+/// * `demosaic` - Interpolation of missing color samples.
+/// * `planar_image` - Destination image, its size defines the size of the mosaic.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bayer_to_yuv420(
+    bayer: &[u8],
+    bayer_stride: RowStride,
+    pattern: BayerPattern,
+    demosaic: BayerDemosaic,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let bayer_stride = bayer_stride.get();
+    bayer_to_yuv420_impl(
+        bayer,
+        bayer_stride,
+        8,
+        pattern,
+        demosaic,
+        planar_image,
+        range,
+        matrix,
+    )
+}
+
+/// Demosaics 8-bit Bayer mosaic straight into YUV NV12 image.
+///
+/// Demosaicing is fused with RGB to YUV conversion, no intermediate RGB image is allocated.
+/// Chroma is taken from the average RGB of each 2x2 block, edges are mirrored.
+///
+/// # Arguments
+///
+/// * `bayer` - Source mosaic, one sample per pixel.
+/// * `bayer_stride` - Stride of the mosaic in bytes.
+/// * `pattern` - Color filter array layout of the mosaic.
+/// * `demosaic` - Interpolation of missing color samples.
+/// * `bi_planar_image` - Destination image, its size defines the size of the mosaic.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bayer_to_yuv_nv12(
+    bayer: &[u8],
+    bayer_stride: RowStride,
+    pattern: BayerPattern,
+    demosaic: BayerDemosaic,
+    bi_planar_image: &mut YuvBiPlanarImageMut<u8>,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let bayer_stride = bayer_stride.get();
+    bayer_to_yuv_nv12_impl(
+        bayer,
+        bayer_stride,
+        8,
+        pattern,
+        demosaic,
+        bi_planar_image,
+        range,
+        matrix,
+    )
+}
+
+/// Demosaics high bit depth Bayer mosaic straight into 8-bit YUV 4:2:0 planar image.
+///
+/// Samples are native endian and stored in lower `bit_depth` bits, as produced by
+/// 10 and 12 bit sensors after unpacking.
+///
+/// # Arguments
+///
+/// * `bayer` - Source mosaic, one sample per pixel.
+/// * `bayer_stride` - Stride of the mosaic in bytes.
+/// * `bit_depth` - Bit depth of the mosaic, 8 to 16.
+/// * `pattern` - Color filter array layout of the mosaic.
+/// * `demosaic` - Interpolation of missing color samples.
+/// * `planar_image` - Destination image, its size defines the size of the mosaic.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bayer16_to_yuv420(
+    bayer: &[u16],
+    bayer_stride: RowStride,
+    bit_depth: u32,
+    pattern: BayerPattern,
+    demosaic: BayerDemosaic,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let bayer_stride = bayer_stride.get();
+    bayer_to_yuv420_impl(
+        bayer,
+        bayer_stride,
+        bit_depth.clamp(8, 16),
+        pattern,
+        demosaic,
+        planar_image,
+        range,
+        matrix,
+    )
+}
+
+/// Demosaics high bit depth Bayer mosaic straight into 8-bit YUV NV12 image.
+///
+/// Samples are native endian and stored in lower `bit_depth` bits, as produced by
+/// 10 and 12 bit sensors after unpacking.
+///
+/// # Arguments
+///
+/// * `bayer` - Source mosaic, one sample per pixel.
+/// * `bayer_stride` - Stride of the mosaic in bytes.
+/// * `bit_depth` - Bit depth of the mosaic, 8 to 16.
+/// * `pattern` - Color filter array layout of the mosaic.
+/// * `demosaic` - Interpolation of missing color samples.
+/// * `bi_planar_image` - Destination image, its size defines the size of the mosaic.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bayer16_to_yuv_nv12(
+    bayer: &[u16],
+    bayer_stride: RowStride,
+    bit_depth: u32,
+    pattern: BayerPattern,
+    demosaic: BayerDemosaic,
+    bi_planar_image: &mut YuvBiPlanarImageMut<u8>,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let bayer_stride = bayer_stride.get();
+    bayer_to_yuv_nv12_impl(
+        bayer,
+        bayer_stride,
+        bit_depth.clamp(8, 16),
+        pattern,
+        demosaic,
+        bi_planar_image,
+        range,
+        matrix,
+    )
+}
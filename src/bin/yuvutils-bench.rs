@@ -0,0 +1,373 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use yuvutils_rs::*;
+
+/// Backend reported by the last instrumented conversion
+static LAST_BACKEND: Mutex<Option<YuvDispatchBackend>> = Mutex::new(None);
+
+fn record_backend(profile: &YuvConversionProfile) {
+    if let Ok(mut lock) = LAST_BACKEND.lock() {
+        *lock = Some(profile.backend);
+    }
+}
+
+struct Frames {
+    width: u32,
+    height: u32,
+    rgba: Vec<u8>,
+    gray: YuvGrayImageMut<'static, u8>,
+    yuv420: YuvPlanarImageMut<'static, u8>,
+    yuv422: YuvPlanarImageMut<'static, u8>,
+    yuv444: YuvPlanarImageMut<'static, u8>,
+    nv12: YuvBiPlanarImageMut<'static, u8>,
+    yuyv: Vec<u8>,
+}
+
+impl Frames {
+    fn new(width: u32, height: u32) -> Frames {
+        let size = ImageSize::new(width, height);
+        let rgba = (0..width as usize * height as usize * 4)
+            .map(|i| (i * 7 % 251) as u8)
+            .collect();
+        Frames {
+            width,
+            height,
+            rgba,
+            gray: YuvGrayImageMut::alloc(size),
+            yuv420: YuvPlanarImageMut::alloc(size, YuvChromaSample::YUV420),
+            yuv422: YuvPlanarImageMut::alloc(size, YuvChromaSample::YUV422),
+            yuv444: YuvPlanarImageMut::alloc(size, YuvChromaSample::YUV444),
+            nv12: YuvBiPlanarImageMut::alloc(size, YuvChromaSample::YUV420),
+            yuyv: vec![0u8; width.div_ceil(2) as usize * 4 * height as usize],
+        }
+    }
+
+    fn yuyv_stride(&self) -> u32 {
+        self.width.div_ceil(2) * 4
+    }
+}
+
+const RANGE: YuvRange = YuvRange::TV;
+const MATRIX: YuvStandardMatrix = YuvStandardMatrix::Bt709;
+
+fn rgba_to_planar(f: &mut Frames, sampling: YuvChromaSample) {
+    let (width, height) = (f.width, f.height);
+    let image = match sampling {
+        YuvChromaSample::YUV420 => &mut f.yuv420,
+        YuvChromaSample::YUV422 => &mut f.yuv422,
+        YuvChromaSample::YUV444 => &mut f.yuv444,
+    };
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => rgba_to_yuv420,
+        YuvChromaSample::YUV422 => rgba_to_yuv422,
+        YuvChromaSample::YUV444 => rgba_to_yuv444,
+    };
+    handler(
+        image.y_plane.borrow_mut(),
+        image.y_stride.get(),
+        image.u_plane.borrow_mut(),
+        image.u_stride.get(),
+        image.v_plane.borrow_mut(),
+        image.v_stride.get(),
+        &f.rgba,
+        width * 4,
+        width,
+        height,
+        RANGE,
+        MATRIX,
+    )
+    .unwrap();
+}
+
+fn planar_to_rgba(f: &mut Frames, sampling: YuvChromaSample) {
+    let (width, height) = (f.width, f.height);
+    let image = match sampling {
+        YuvChromaSample::YUV420 => f.yuv420.to_fixed(),
+        YuvChromaSample::YUV422 => f.yuv422.to_fixed(),
+        YuvChromaSample::YUV444 => f.yuv444.to_fixed(),
+    };
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => yuv420_to_rgba,
+        YuvChromaSample::YUV422 => yuv422_to_rgba,
+        YuvChromaSample::YUV444 => yuv444_to_rgba,
+    };
+    handler(
+        image.y_plane,
+        image.y_stride.get(),
+        image.u_plane,
+        image.u_stride.get(),
+        image.v_plane,
+        image.v_stride.get(),
+        &mut f.rgba,
+        width * 4,
+        width,
+        height,
+        RANGE,
+        MATRIX,
+    )
+    .unwrap();
+}
+
+type BenchCase = (&'static str, YuvFormatPair, fn(&mut Frames));
+
+const CASES: [BenchCase; 15] = [
+    ("rgba_to_yuv420", YuvFormatPair::RgbaToYuv420, |f| {
+        rgba_to_planar(f, YuvChromaSample::YUV420)
+    }),
+    ("rgba_to_yuv422", YuvFormatPair::RgbaToYuv422, |f| {
+        rgba_to_planar(f, YuvChromaSample::YUV422)
+    }),
+    ("rgba_to_yuv444", YuvFormatPair::RgbaToYuv444, |f| {
+        rgba_to_planar(f, YuvChromaSample::YUV444)
+    }),
+    ("yuv420_to_rgba", YuvFormatPair::Yuv420ToRgba, |f| {
+        planar_to_rgba(f, YuvChromaSample::YUV420)
+    }),
+    ("yuv422_to_rgba", YuvFormatPair::Yuv422ToRgba, |f| {
+        planar_to_rgba(f, YuvChromaSample::YUV422)
+    }),
+    ("yuv444_to_rgba", YuvFormatPair::Yuv444ToRgba, |f| {
+        planar_to_rgba(f, YuvChromaSample::YUV444)
+    }),
+    ("rgba_to_yuv_nv12", YuvFormatPair::RgbaToYuvNv12, |f| {
+        rgba_to_yuv_nv12(
+            f.nv12.y_plane.borrow_mut(),
+            f.nv12.y_stride.get(),
+            f.nv12.uv_plane.borrow_mut(),
+            f.nv12.uv_stride.get(),
+            &f.rgba,
+            f.width * 4,
+            f.width,
+            f.height,
+            RANGE,
+            MATRIX,
+        )
+        .unwrap()
+    }),
+    ("yuv_nv12_to_rgba", YuvFormatPair::YuvNv12ToRgba, |f| {
+        yuv_nv12_to_rgba(
+            f.nv12.y_plane.borrow(),
+            f.nv12.y_stride.get(),
+            f.nv12.uv_plane.borrow(),
+            f.nv12.uv_stride.get(),
+            &mut f.rgba,
+            f.width * 4,
+            f.width,
+            f.height,
+            RANGE,
+            MATRIX,
+        )
+        .unwrap()
+    }),
+    ("rgba_to_yuv400", YuvFormatPair::RgbaToYuv400, |f| {
+        rgba_to_yuv400(
+            f.gray.y_plane.borrow_mut(),
+            f.gray.y_stride.get(),
+            &f.rgba,
+            f.width * 4,
+            f.width,
+            f.height,
+            RANGE,
+            MATRIX,
+        )
+        .unwrap()
+    }),
+    ("yuv400_to_rgba", YuvFormatPair::Yuv400ToRgba, |f| {
+        yuv400_to_rgba(
+            f.gray.y_plane.borrow(),
+            f.gray.y_stride.get(),
+            &mut f.rgba,
+            f.width * 4,
+            f.width,
+            f.height,
+            RANGE,
+            MATRIX,
+        )
+        .unwrap()
+    }),
+    ("yuv420_to_yuyv422", YuvFormatPair::Yuv420ToYuyv422, |f| {
+        let stride = f.yuyv_stride();
+        let image = f.yuv420.to_fixed();
+        yuv420_to_yuyv422(
+            image.y_plane,
+            image.y_stride.get(),
+            image.u_plane,
+            image.u_stride.get(),
+            image.v_plane,
+            image.v_stride.get(),
+            &mut f.yuyv,
+            stride,
+            f.width,
+            f.height,
+        )
+        .unwrap()
+    }),
+    ("yuyv422_to_yuv420", YuvFormatPair::Yuyv422ToYuv420, |f| {
+        let stride = f.yuyv_stride();
+        let image = &mut f.yuv420;
+        yuyv422_to_yuv420(
+            image.y_plane.borrow_mut(),
+            image.y_stride.get(),
+            image.u_plane.borrow_mut(),
+            image.u_stride.get(),
+            image.v_plane.borrow_mut(),
+            image.v_stride.get(),
+            &f.yuyv,
+            stride,
+            f.width,
+            f.height,
+        )
+        .unwrap()
+    }),
+    ("yuyv422_to_rgba", YuvFormatPair::Yuyv422ToRgba, |f| {
+        let stride = f.yuyv_stride();
+        yuyv422_to_rgba(
+            &f.yuyv,
+            stride,
+            &mut f.rgba,
+            f.width * 4,
+            f.width,
+            f.height,
+            RANGE,
+            MATRIX,
+        )
+        .unwrap()
+    }),
+    ("yuv420_to_yuv_nv12", YuvFormatPair::Yuv420ToYuvNv12, |f| {
+        yuv420_to_yuv_nv12(&f.yuv420.to_fixed(), &mut f.nv12).unwrap()
+    }),
+    ("yuv_nv12_to_yuv420", YuvFormatPair::YuvNv12ToYuv420, |f| {
+        yuv_nv12_to_yuv420(&f.nv12.to_fixed(), &mut f.yuv420).unwrap()
+    }),
+];
+
+/// Backends the current machine can run, capped dispatch is measured for each of them
+fn available_backends() -> Vec<Option<YuvDispatchBackend>> {
+    #[allow(unused_mut)]
+    let mut backends = vec![Some(YuvDispatchBackend::Scalar)];
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            backends.push(Some(YuvDispatchBackend::Sse41));
+        }
+        if std::arch::is_x86_feature_detected!("avx2") {
+            backends.push(Some(YuvDispatchBackend::Avx2));
+        }
+        if std::arch::is_x86_feature_detected!("avx512bw") {
+            backends.push(Some(YuvDispatchBackend::Avx512Bw));
+        }
+        backends
+    }
+    #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+    {
+        // Backend is chosen at compile time, only the dispatched one can be measured
+        vec![None]
+    }
+}
+
+fn parse_arg(args: &[String], index: usize, default: u32) -> u32 {
+    match args.get(index) {
+        Some(value) => value.parse().unwrap_or_else(|_| {
+            eprintln!("Invalid argument {value}");
+            eprintln!("Usage: yuvutils-bench [--calibrate] [width] [height] [iterations]");
+            std::process::exit(2);
+        }),
+        None => default,
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().collect();
+    // Prints baseline rows of `query_expected_throughput` instead of the table
+    let calibrate = args.iter().any(|x| x == "--calibrate");
+    let args: Vec<String> = args.into_iter().filter(|x| x != "--calibrate").collect();
+    let width = parse_arg(&args, 1, 1920).max(1);
+    let height = parse_arg(&args, 2, 1080).max(1);
+    let iterations = parse_arg(&args, 3, 50).max(1);
+
+    set_profiling_callback(Some(record_backend));
+    let mut frames = Frames::new(width, height);
+    let megapixels = width as f64 * height as f64 / 1_000_000.0;
+
+    println!(
+        "{width}x{height}, {iterations} iterations, rayon {}",
+        if cfg!(feature = "rayon") { "on" } else { "off" }
+    );
+    if !calibrate {
+        println!(
+            "{:<20} {:<10} {:<10} {:>10} {:>10} {:>8}",
+            "conversion", "limit", "picked", "ms", "MPix/s", "GB/s"
+        );
+    }
+
+    let mut calibrated = Vec::new();
+
+    for (name, pair, run) in CASES.iter() {
+        for limit in available_backends() {
+            set_backend_limit(limit);
+            if let Ok(mut lock) = LAST_BACKEND.lock() {
+                *lock = None;
+            }
+            run(&mut frames);
+
+            let mut elapsed = Duration::ZERO;
+            for _ in 0..iterations {
+                let start = Instant::now();
+                run(&mut frames);
+                elapsed += start.elapsed();
+            }
+
+            let picked = LAST_BACKEND.lock().ok().and_then(|x| *x);
+            let per_call = elapsed.as_secs_f64() / iterations as f64;
+            let gbps = pair.frame_bytes(width, height) as f64 / per_call / 1e9;
+            if calibrate {
+                // Conversions without SIMD kernels resolve to the same backend under every cap
+                let backend = pair.backend();
+                if !calibrated.contains(&(*pair, backend)) {
+                    calibrated.push((*pair, backend));
+                    println!("    (YuvFormatPair::{pair:?}, YuvDispatchBackend::{backend:?}, {gbps:.2}),");
+                }
+                continue;
+            }
+            println!(
+                "{:<20} {:<10} {:<10} {:>10.3} {:>10.1} {:>8.2}",
+                name,
+                limit.map_or("none".to_string(), |x| format!("{x:?}")),
+                picked.map_or("-".to_string(), |x| format!("{x:?}")),
+                per_call * 1000.0,
+                megapixels / per_call,
+                gbps
+            );
+        }
+    }
+
+    set_backend_limit(None);
+}
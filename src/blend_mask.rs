@@ -0,0 +1,502 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::blend_nv::div_by_255;
+use crate::images::{ImageSize, YuvBiPlanarImageMut, YuvPlanarImageMut};
+use crate::yuv_error::check_y8_channel;
+use crate::yuv_support::{YuvChromaSample, YuvNVOrder};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+/// Solid color given directly in YUV components, range and matrix of the frame it is drawn on
+pub struct YuvColor {
+    pub y: u8,
+    pub u: u8,
+    pub v: u8,
+}
+
+impl YuvColor {
+    #[inline]
+    pub const fn new(y: u8, u: u8, v: u8) -> YuvColor {
+        YuvColor { y, u, v }
+    }
+}
+
+/// A8 mask clipped against the frame
+#[derive(Copy, Clone)]
+struct MaskPlacement<'a> {
+    mask: &'a [u8],
+    stride: usize,
+    x: i64,
+    y: i64,
+    /// Covered frame region, end exclusive
+    x0: usize,
+    x1: usize,
+    y0: usize,
+    y1: usize,
+}
+
+impl MaskPlacement<'_> {
+    #[inline(always)]
+    fn alpha(&self, fx: usize, fy: usize) -> i32 {
+        if fx >= self.x0 && fx < self.x1 && fy >= self.y0 && fy < self.y1 {
+            let mx = (fx as i64 - self.x) as usize;
+            let my = (fy as i64 - self.y) as usize;
+            self.mask[my * self.stride + mx] as i32
+        } else {
+            0
+        }
+    }
+}
+
+/// Layout of one component inside a plane, samples are `step` apart starting at `offset`,
+/// each sample covers `1 << shift_x` by `1 << shift_y` frame pixels
+#[derive(Copy, Clone)]
+struct ComponentLayout {
+    stride: usize,
+    step: usize,
+    offset: usize,
+    shift_x: u32,
+    shift_y: u32,
+}
+
+/// Blends `value` into every sample of the component touched by the mask,
+/// subsampled components take the average of their blended pixels
+fn blend_mask_component(
+    plane: &mut [u8],
+    layout: ComponentLayout,
+    frame_size: ImageSize,
+    placement: MaskPlacement,
+    value: i32,
+) {
+    let frame_width = frame_size.width as usize;
+    let frame_height = frame_size.height as usize;
+    let ComponentLayout {
+        stride,
+        step,
+        offset,
+        shift_x,
+        shift_y,
+    } = layout;
+    let sx0 = placement.x0 >> shift_x;
+    let sx1 = (placement.x1 + (1 << shift_x) - 1) >> shift_x;
+    let sy0 = placement.y0 >> shift_y;
+    let sy1 = (placement.y1 + (1 << shift_y) - 1) >> shift_y;
+    let shift = shift_x + shift_y;
+    let rounding = (1 << shift) >> 1;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = plane.par_chunks_mut(stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = plane.chunks_mut(stride);
+    }
+
+    iter.enumerate()
+        .skip(sy0)
+        .take(sy1 - sy0)
+        .for_each(|(sy, row)| {
+            for sx in sx0..sx1 {
+                let dst = &mut row[sx * step + offset];
+                let current = *dst as i32;
+                let mut sum = 0;
+                // Pixels past the frame edge replicate their neighbour
+                for dy in 0..1usize << shift_y {
+                    let fy = ((sy << shift_y) + dy).min(frame_height - 1);
+                    for dx in 0..1usize << shift_x {
+                        let fx = ((sx << shift_x) + dx).min(frame_width - 1);
+                        let a = placement.alpha(fx, fy);
+                        sum += div_by_255(current * (255 - a) + value * a);
+                    }
+                }
+                *dst = ((sum + rounding) >> shift) as u8;
+            }
+        });
+}
+
+fn chroma_shifts(sampling: YuvChromaSample) -> (u32, u32) {
+    match sampling {
+        YuvChromaSample::YUV420 => (1, 1),
+        YuvChromaSample::YUV422 => (1, 0),
+        YuvChromaSample::YUV444 => (0, 0),
+    }
+}
+
+fn place_mask<'a>(
+    frame_size: ImageSize,
+    mask: &'a [u8],
+    mask_stride: u32,
+    mask_size: ImageSize,
+    x: i32,
+    y: i32,
+) -> Result<Option<MaskPlacement<'a>>, YuvError> {
+    check_y8_channel(mask, mask_stride, mask_size.width, mask_size.height)?;
+    let frame_width = frame_size.width as i64;
+    let frame_height = frame_size.height as i64;
+    let placement = MaskPlacement {
+        mask,
+        stride: mask_stride as usize,
+        x: x as i64,
+        y: y as i64,
+        x0: (x as i64).clamp(0, frame_width) as usize,
+        x1: (x as i64 + mask_size.width as i64).clamp(0, frame_width) as usize,
+        y0: (y as i64).clamp(0, frame_height) as usize,
+        y1: (y as i64 + mask_size.height as i64).clamp(0, frame_height) as usize,
+    };
+    if placement.x0 >= placement.x1 || placement.y0 >= placement.y1 {
+        return Ok(None);
+    }
+    Ok(Some(placement))
+}
+
+/// Blends solid color through A8 mask onto planar YUV frame in place.
+///
+/// Each pixel is blended as `frame * (255 - alpha) / 255 + color * alpha / 255`,
+/// subsampled chroma takes the average of blended pixels of its block so glyph edges stay smooth.
+/// Mask may be placed partially or entirely outside of the frame, only the overlap is blended.
+///
+/// # Arguments
+///
+/// * `frame` - Planar YUV frame to draw onto.
+/// * `sampling` - Chroma subsampling of the frame.
+/// * `mask` - A8 coverage mask, 255 is fully opaque.
+/// * `mask_stride` - The stride (bytes per row) for the mask.
+/// * `mask_size` - The size of the mask in pixels.
+/// * `color` - Color to draw, in range and matrix of the frame.
+/// * `x` - Horizontal position of the mask top left corner in the frame.
+/// * `y` - Vertical position of the mask top left corner in the frame.
+///
+pub fn blend_mask_yuv(
+    frame: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    mask: &[u8],
+    mask_stride: u32,
+    mask_size: ImageSize,
+    color: YuvColor,
+    x: i32,
+    y: i32,
+) -> Result<(), YuvError> {
+    frame.check_constraints(sampling)?;
+    let placement = match place_mask(frame.size, mask, mask_stride, mask_size, x, y)? {
+        Some(placement) => placement,
+        None => return Ok(()),
+    };
+    let (shift_x, shift_y) = chroma_shifts(sampling);
+    let plane = |stride: u32, shift_x: u32, shift_y: u32| ComponentLayout {
+        stride: stride as usize,
+        step: 1,
+        offset: 0,
+        shift_x,
+        shift_y,
+    };
+    blend_mask_component(
+        frame.y_plane.borrow_mut(),
+        plane(frame.y_stride.get(), 0, 0),
+        frame.size,
+        placement,
+        color.y as i32,
+    );
+    blend_mask_component(
+        frame.u_plane.borrow_mut(),
+        plane(frame.u_stride.get(), shift_x, shift_y),
+        frame.size,
+        placement,
+        color.u as i32,
+    );
+    blend_mask_component(
+        frame.v_plane.borrow_mut(),
+        plane(frame.v_stride.get(), shift_x, shift_y),
+        frame.size,
+        placement,
+        color.v as i32,
+    );
+    Ok(())
+}
+
+fn blend_mask_yuv_nv_impl<const UV_ORDER: u8, const SAMPLING: u8>(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    mask: &[u8],
+    mask_stride: u32,
+    mask_size: ImageSize,
+    color: YuvColor,
+    x: i32,
+    y: i32,
+) -> Result<(), YuvError> {
+    let order: YuvNVOrder = UV_ORDER.into();
+    let sampling: YuvChromaSample = SAMPLING.into();
+    frame.check_constraints(sampling)?;
+    let placement = match place_mask(frame.size, mask, mask_stride, mask_size, x, y)? {
+        Some(placement) => placement,
+        None => return Ok(()),
+    };
+    let (shift_x, shift_y) = chroma_shifts(sampling);
+    blend_mask_component(
+        frame.y_plane.borrow_mut(),
+        ComponentLayout {
+            stride: frame.y_stride.get() as usize,
+            step: 1,
+            offset: 0,
+            shift_x: 0,
+            shift_y: 0,
+        },
+        frame.size,
+        placement,
+        color.y as i32,
+    );
+    let uv_stride = frame.uv_stride.get() as usize;
+    for (offset, value) in [
+        (order.get_u_position(), color.u),
+        (order.get_v_position(), color.v),
+    ] {
+        blend_mask_component(
+            frame.uv_plane.borrow_mut(),
+            ComponentLayout {
+                stride: uv_stride,
+                step: 2,
+                offset,
+                shift_x,
+                shift_y,
+            },
+            frame.size,
+            placement,
+            value as i32,
+        );
+    }
+    Ok(())
+}
+
+/// Blends solid color through A8 mask onto NV12 frame in place.
+///
+/// Blending follows [blend_mask_yuv], only the overlap of the mask and the frame is blended.
+///
+/// # Arguments
+///
+/// * `frame` - NV12 frame to draw onto.
+/// * `mask` - A8 coverage mask, 255 is fully opaque.
+/// * `mask_stride` - The stride (bytes per row) for the mask.
+/// * `mask_size` - The size of the mask in pixels.
+/// * `color` - Color to draw, in range and matrix of the frame.
+/// * `x` - Horizontal position of the mask top left corner in the frame.
+/// * `y` - Vertical position of the mask top left corner in the frame.
+///
+pub fn blend_mask_yuv_nv12(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    mask: &[u8],
+    mask_stride: u32,
+    mask_size: ImageSize,
+    color: YuvColor,
+    x: i32,
+    y: i32,
+) -> Result<(), YuvError> {
+    blend_mask_yuv_nv_impl::<{ YuvNVOrder::UV as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        frame,
+        mask,
+        mask_stride,
+        mask_size,
+        color,
+        x,
+        y,
+    )
+}
+
+/// Blends solid color through A8 mask onto NV21 frame in place.
+///
+/// Blending follows [blend_mask_yuv], only the overlap of the mask and the frame is blended.
+///
+/// # Arguments
+///
+/// * `frame` - NV21 frame to draw onto.
+/// * `mask` - A8 coverage mask, 255 is fully opaque.
+/// * `mask_stride` - The stride (bytes per row) for the mask.
+/// * `mask_size` - The size of the mask in pixels.
+/// * `color` - Color to draw, in range and matrix of the frame.
+/// * `x` - Horizontal position of the mask top left corner in the frame.
+/// * `y` - Vertical position of the mask top left corner in the frame.
+///
+pub fn blend_mask_yuv_nv21(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    mask: &[u8],
+    mask_stride: u32,
+    mask_size: ImageSize,
+    color: YuvColor,
+    x: i32,
+    y: i32,
+) -> Result<(), YuvError> {
+    blend_mask_yuv_nv_impl::<{ YuvNVOrder::VU as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        frame,
+        mask,
+        mask_stride,
+        mask_size,
+        color,
+        x,
+        y,
+    )
+}
+
+/// Blends solid color through A8 mask onto NV16 frame in place.
+///
+/// Blending follows [blend_mask_yuv], only the overlap of the mask and the frame is blended.
+///
+/// # Arguments
+///
+/// * `frame` - NV16 frame to draw onto.
+/// * `mask` - A8 coverage mask, 255 is fully opaque.
+/// * `mask_stride` - The stride (bytes per row) for the mask.
+/// * `mask_size` - The size of the mask in pixels.
+/// * `color` - Color to draw, in range and matrix of the frame.
+/// * `x` - Horizontal position of the mask top left corner in the frame.
+/// * `y` - Vertical position of the mask top left corner in the frame.
+///
+pub fn blend_mask_yuv_nv16(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    mask: &[u8],
+    mask_stride: u32,
+    mask_size: ImageSize,
+    color: YuvColor,
+    x: i32,
+    y: i32,
+) -> Result<(), YuvError> {
+    blend_mask_yuv_nv_impl::<{ YuvNVOrder::UV as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        frame,
+        mask,
+        mask_stride,
+        mask_size,
+        color,
+        x,
+        y,
+    )
+}
+
+/// Blends solid color through A8 mask onto NV61 frame in place.
+///
+/// Blending follows [blend_mask_yuv], only the overlap of the mask and the frame is blended.
+///
+/// # Arguments
+///
+/// * `frame` - NV61 frame to draw onto.
+/// * `mask` - A8 coverage mask, 255 is fully opaque.
+/// * `mask_stride` - The stride (bytes per row) for the mask.
+/// * `mask_size` - The size of the mask in pixels.
+/// * `color` - Color to draw, in range and matrix of the frame.
+/// * `x` - Horizontal position of the mask top left corner in the frame.
+/// * `y` - Vertical position of the mask top left corner in the frame.
+///
+pub fn blend_mask_yuv_nv61(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    mask: &[u8],
+    mask_stride: u32,
+    mask_size: ImageSize,
+    color: YuvColor,
+    x: i32,
+    y: i32,
+) -> Result<(), YuvError> {
+    blend_mask_yuv_nv_impl::<{ YuvNVOrder::VU as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        frame,
+        mask,
+        mask_stride,
+        mask_size,
+        color,
+        x,
+        y,
+    )
+}
+
+/// Blends solid color through A8 mask onto NV24 frame in place.
+///
+/// Blending follows [blend_mask_yuv], only the overlap of the mask and the frame is blended.
+///
+/// # Arguments
+///
+/// * `frame` - NV24 frame to draw onto.
+/// * `mask` - A8 coverage mask, 255 is fully opaque.
+/// * `mask_stride` - The stride (bytes per row) for the mask.
+/// * `mask_size` - The size of the mask in pixels.
+/// * `color` - Color to draw, in range and matrix of the frame.
+/// * `x` - Horizontal position of the mask top left corner in the frame.
+/// * `y` - Vertical position of the mask top left corner in the frame.
+///
+pub fn blend_mask_yuv_nv24(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    mask: &[u8],
+    mask_stride: u32,
+    mask_size: ImageSize,
+    color: YuvColor,
+    x: i32,
+    y: i32,
+) -> Result<(), YuvError> {
+    blend_mask_yuv_nv_impl::<{ YuvNVOrder::UV as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        frame,
+        mask,
+        mask_stride,
+        mask_size,
+        color,
+        x,
+        y,
+    )
+}
+
+/// Blends solid color through A8 mask onto NV42 frame in place.
+///
+/// Blending follows [blend_mask_yuv], only the overlap of the mask and the frame is blended.
+///
+/// # Arguments
+///
+/// * `frame` - NV42 frame to draw onto.
+/// * `mask` - A8 coverage mask, 255 is fully opaque.
+/// * `mask_stride` - The stride (bytes per row) for the mask.
+/// * `mask_size` - The size of the mask in pixels.
+/// * `color` - Color to draw, in range and matrix of the frame.
+/// * `x` - Horizontal position of the mask top left corner in the frame.
+/// * `y` - Vertical position of the mask top left corner in the frame.
+///
+pub fn blend_mask_yuv_nv42(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    mask: &[u8],
+    mask_stride: u32,
+    mask_size: ImageSize,
+    color: YuvColor,
+    x: i32,
+    y: i32,
+) -> Result<(), YuvError> {
+    blend_mask_yuv_nv_impl::<{ YuvNVOrder::VU as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        frame,
+        mask,
+        mask_stride,
+        mask_size,
+        color,
+        x,
+        y,
+    )
+}
@@ -0,0 +1,472 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, YuvBiPlanarImageMut};
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::neon::neon_blend_rgba_nv_row;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::sse::sse_blend_rgba_nv_row;
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_support::{
+    get_forward_transform, get_yuv_range, CbCrForwardTransform, ToIntegerTransform, YuvChromaRange,
+    YuvChromaSample, YuvNVOrder, YuvRange, YuvSourceChannels, YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+/// Rounded division by 255 exact for the whole `0..=255 * 255` range
+#[inline(always)]
+pub(crate) fn div_by_255(v: i32) -> i32 {
+    let v = v + 128;
+    (v + (v >> 8)) >> 8
+}
+
+struct BlendCoefficients {
+    transform: CbCrForwardTransform<i32>,
+    bias_y: i32,
+    bias_uv: i32,
+    min: i32,
+    cap_y: i32,
+    min_uv: i32,
+    cap_uv: i32,
+}
+
+impl BlendCoefficients {
+    fn new(range: &YuvChromaRange, matrix: YuvStandardMatrix) -> BlendCoefficients {
+        const PRECISION: i32 = 8;
+        const ROUNDING_CONST_BIAS: i32 = 1 << (PRECISION - 1);
+        let kr_kb = matrix.get_kr_kb();
+        let transform = get_forward_transform(
+            (1 << 8) - 1,
+            range.range_y,
+            range.range_uv,
+            kr_kb.kr,
+            kr_kb.kb,
+        )
+        .to_integers(PRECISION as u32);
+        let min = range.bias_y as i32;
+        BlendCoefficients {
+            transform,
+            bias_y: range.bias_y as i32 * (1 << PRECISION) + ROUNDING_CONST_BIAS,
+            bias_uv: range.bias_uv as i32 * (1 << PRECISION) + ROUNDING_CONST_BIAS,
+            min,
+            cap_y: min + range.range_y as i32,
+            min_uv: range.min_uv() as i32,
+            cap_uv: range.max_uv() as i32,
+        }
+    }
+
+    /// Blends sprite pixel over luma in place, returns blended chroma of the pixel
+    #[inline(always)]
+    fn blend<const ORIGIN_CHANNELS: u8>(
+        &self,
+        px: &[u8],
+        y: &mut u8,
+        u: i32,
+        v: i32,
+    ) -> (i32, i32) {
+        let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+        let r = px[src_chans.get_r_channel_offset()] as i32;
+        let g = px[src_chans.get_g_channel_offset()] as i32;
+        let b = px[src_chans.get_b_channel_offset()] as i32;
+        let a = px[src_chans.get_a_channel_offset()] as i32;
+        let t = &self.transform;
+        let ys = ((r * t.yr + g * t.yg + b * t.yb + self.bias_y) >> 8).clamp(self.min, self.cap_y);
+        let cb = ((r * t.cb_r + g * t.cb_g + b * t.cb_b + self.bias_uv) >> 8)
+            .clamp(self.min_uv, self.cap_uv);
+        let cr = ((r * t.cr_r + g * t.cr_g + b * t.cr_b + self.bias_uv) >> 8)
+            .clamp(self.min_uv, self.cap_uv);
+        let inv_a = 255 - a;
+        *y = div_by_255(*y as i32 * inv_a + ys * a) as u8;
+        (
+            div_by_255(u * inv_a + cb * a),
+            div_by_255(v * inv_a + cr * a),
+        )
+    }
+}
+
+fn blend_rgba_over_nv_impl<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8>(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    sprite: &[u8],
+    sprite_stride: u32,
+    sprite_size: ImageSize,
+    x: i32,
+    y: i32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let order: YuvNVOrder = UV_ORDER.into();
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let channels = src_chans.get_channels_count();
+
+    frame.check_constraints(YuvChromaSample::YUV420)?;
+    check_rgba_destination(
+        sprite,
+        sprite_stride,
+        sprite_size.width,
+        sprite_size.height,
+        channels,
+    )?;
+
+    let frame_width = frame.size.width as i64;
+    let frame_height = frame.size.height as i64;
+    // Covered frame region, end exclusive
+    let x0 = (x as i64).clamp(0, frame_width) as usize;
+    let x1 = (x as i64 + sprite_size.width as i64).clamp(0, frame_width) as usize;
+    let y0 = (y as i64).clamp(0, frame_height) as usize;
+    let y1 = (y as i64 + sprite_size.height as i64).clamp(0, frame_height) as usize;
+    if x0 >= x1 || y0 >= y1 {
+        return Ok(());
+    }
+
+    let chroma_range = get_yuv_range(8, range);
+    let coeffs = BlendCoefficients::new(
+        &chroma_range,
+        matrix.resolve(frame.size.width, frame.size.height),
+    );
+
+    let frame_width = frame.size.width as usize;
+    let frame_height = frame.size.height as usize;
+    let y_stride = frame.y_stride.get() as usize;
+    let uv_stride = frame.uv_stride.get() as usize;
+    let sprite_stride = sprite_stride as usize;
+    let u_pos = order.get_u_position();
+    let v_pos = order.get_v_position();
+
+    // Sprite column and row for frame coordinates known to be covered
+    let sprite_x = |fx: usize| (fx as i64 - x as i64) as usize;
+    let sprite_y = |fy: usize| (fy as i64 - y as i64) as usize;
+
+    let cy0 = y0 / 2;
+    let cy1 = y1.div_ceil(2);
+    let cx0 = x0 / 2;
+    let cx1 = x1.div_ceil(2);
+    // Chroma columns where both luma columns are covered
+    let full_cx0 = x0.div_ceil(2);
+    let full_cx1 = (x1 / 2).max(full_cx0);
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    let y_plane = frame.y_plane.borrow_mut();
+    let uv_plane = frame.uv_plane.borrow_mut();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = y_plane
+            .par_chunks_mut(y_stride * 2)
+            .zip(uv_plane.par_chunks_mut(uv_stride));
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = y_plane
+            .chunks_mut(y_stride * 2)
+            .zip(uv_plane.chunks_mut(uv_stride));
+    }
+
+    iter.enumerate()
+        .skip(cy0)
+        .take(cy1 - cy0)
+        .for_each(|(cy, (y_rows, uv_row))| {
+            let fy_top = cy * 2;
+            let has_bottom = fy_top + 1 < frame_height;
+            let (y_top, y_bottom) = y_rows.split_at_mut(y_stride.min(y_rows.len()));
+            let covered = |fy: usize| fy >= y0 && fy < y1;
+            let top_covered = covered(fy_top);
+            let bottom_covered = has_bottom && covered(fy_top + 1);
+            let top_sprite = if top_covered {
+                &sprite[sprite_y(fy_top) * sprite_stride..]
+            } else {
+                &[]
+            };
+            let bottom_sprite = if bottom_covered {
+                &sprite[sprite_y(fy_top + 1) * sprite_stride..]
+            } else {
+                &[]
+            };
+
+            let mut _simd_cx = full_cx0..full_cx0;
+
+            if top_covered && bottom_covered {
+                let _sprite_offset = sprite_x(full_cx0 * 2) * channels;
+                let _pairs = full_cx1 - full_cx0;
+
+                #[cfg(all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    not(feature = "safe-only")
+                ))]
+                unsafe {
+                    if _use_sse {
+                        let processed = sse_blend_rgba_nv_row::<ORIGIN_CHANNELS, UV_ORDER>(
+                            &coeffs.transform,
+                            &chroma_range,
+                            &top_sprite[_sprite_offset..],
+                            &bottom_sprite[_sprite_offset..],
+                            &mut y_top[full_cx0 * 2..],
+                            &mut y_bottom[full_cx0 * 2..],
+                            &mut uv_row[full_cx0 * 2..],
+                            _pairs,
+                        );
+                        _simd_cx = full_cx0..full_cx0 + processed;
+                    }
+                }
+
+                #[cfg(all(
+                    target_arch = "aarch64",
+                    target_feature = "neon",
+                    not(feature = "safe-only")
+                ))]
+                unsafe {
+                    let processed = neon_blend_rgba_nv_row::<ORIGIN_CHANNELS, UV_ORDER>(
+                        &coeffs.transform,
+                        &chroma_range,
+                        &top_sprite[_sprite_offset..],
+                        &bottom_sprite[_sprite_offset..],
+                        &mut y_top[full_cx0 * 2..],
+                        &mut y_bottom[full_cx0 * 2..],
+                        &mut uv_row[full_cx0 * 2..],
+                        _pairs,
+                    );
+                    _simd_cx = full_cx0..full_cx0 + processed;
+                }
+            }
+
+            for cx in (cx0..cx1).filter(|cx| !_simd_cx.contains(cx)) {
+                let u = uv_row[cx * 2 + u_pos] as i32;
+                let v = uv_row[cx * 2 + v_pos] as i32;
+                let fx_left = cx * 2;
+                let has_right = fx_left + 1 < frame_width;
+
+                // Uncovered pixels keep frame chroma, pixels past the frame edge
+                // replicate their neighbour so edge blocks are averaged over existing pixels
+                let blend_row = |row: &mut [u8], src: &[u8], row_covered: bool| {
+                    let mut blend_at = |fx: usize| {
+                        if row_covered && fx >= x0 && fx < x1 {
+                            let px = &src[sprite_x(fx) * channels..];
+                            coeffs.blend::<ORIGIN_CHANNELS>(px, &mut row[fx], u, v)
+                        } else {
+                            (u, v)
+                        }
+                    };
+                    let left = blend_at(fx_left);
+                    let right = if has_right {
+                        blend_at(fx_left + 1)
+                    } else {
+                        left
+                    };
+                    (left.0 + right.0, left.1 + right.1)
+                };
+
+                let top = blend_row(y_top, top_sprite, top_covered);
+                let bottom = if has_bottom {
+                    blend_row(y_bottom, bottom_sprite, bottom_covered)
+                } else {
+                    top
+                };
+
+                uv_row[cx * 2 + u_pos] = ((top.0 + bottom.0 + 2) >> 2) as u8;
+                uv_row[cx * 2 + v_pos] = ((top.1 + bottom.1 + 2) >> 2) as u8;
+            }
+        });
+
+    Ok(())
+}
+
+/// Alpha blends RGBA sprite over NV12 frame in place.
+///
+/// Only the region covered by the sprite is converted, sprite pixels are transformed to YUV
+/// and blended with straight (not premultiplied) alpha. Chroma of each 2x2 block is the average
+/// of its blended pixels, so partially covered blocks at sprite edges are blended proportionally.
+/// Sprite may be placed partially or entirely outside of the frame, only the overlap is blended.
+///
+/// # Arguments
+///
+/// * `frame` - NV12 frame to blend onto.
+/// * `sprite` - The input RGBA sprite data slice.
+/// * `sprite_stride` - The stride (bytes per row) for the RGBA sprite data.
+/// * `sprite_size` - The size of the sprite in pixels.
+/// * `x` - Horizontal position of the sprite top left corner in the frame.
+/// * `y` - Vertical position of the sprite top left corner in the frame.
+/// * `range` - The YUV range (limited or full) of the frame.
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other) of the frame.
+///
+pub fn blend_rgba_over_nv12(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    sprite: &[u8],
+    sprite_stride: u32,
+    sprite_size: ImageSize,
+    x: i32,
+    y: i32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    blend_rgba_over_nv_impl::<{ YuvSourceChannels::Rgba as u8 }, { YuvNVOrder::UV as u8 }>(
+        frame,
+        sprite,
+        sprite_stride,
+        sprite_size,
+        x,
+        y,
+        range,
+        matrix,
+    )
+}
+
+/// Alpha blends RGBA sprite over NV21 frame in place.
+///
+/// Only the region covered by the sprite is converted, sprite pixels are transformed to YUV
+/// and blended with straight (not premultiplied) alpha. Chroma of each 2x2 block is the average
+/// of its blended pixels, so partially covered blocks at sprite edges are blended proportionally.
+/// Sprite may be placed partially or entirely outside of the frame, only the overlap is blended.
+///
+/// # Arguments
+///
+/// * `frame` - NV21 frame to blend onto.
+/// * `sprite` - The input RGBA sprite data slice.
+/// * `sprite_stride` - The stride (bytes per row) for the RGBA sprite data.
+/// * `sprite_size` - The size of the sprite in pixels.
+/// * `x` - Horizontal position of the sprite top left corner in the frame.
+/// * `y` - Vertical position of the sprite top left corner in the frame.
+/// * `range` - The YUV range (limited or full) of the frame.
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other) of the frame.
+///
+pub fn blend_rgba_over_nv21(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    sprite: &[u8],
+    sprite_stride: u32,
+    sprite_size: ImageSize,
+    x: i32,
+    y: i32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    blend_rgba_over_nv_impl::<{ YuvSourceChannels::Rgba as u8 }, { YuvNVOrder::VU as u8 }>(
+        frame,
+        sprite,
+        sprite_stride,
+        sprite_size,
+        x,
+        y,
+        range,
+        matrix,
+    )
+}
+
+/// Alpha blends BGRA sprite over NV12 frame in place.
+///
+/// Only the region covered by the sprite is converted, sprite pixels are transformed to YUV
+/// and blended with straight (not premultiplied) alpha. Chroma of each 2x2 block is the average
+/// of its blended pixels, so partially covered blocks at sprite edges are blended proportionally.
+/// Sprite may be placed partially or entirely outside of the frame, only the overlap is blended.
+///
+/// # Arguments
+///
+/// * `frame` - NV12 frame to blend onto.
+/// * `sprite` - The input BGRA sprite data slice.
+/// * `sprite_stride` - The stride (bytes per row) for the BGRA sprite data.
+/// * `sprite_size` - The size of the sprite in pixels.
+/// * `x` - Horizontal position of the sprite top left corner in the frame.
+/// * `y` - Vertical position of the sprite top left corner in the frame.
+/// * `range` - The YUV range (limited or full) of the frame.
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other) of the frame.
+///
+pub fn blend_bgra_over_nv12(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    sprite: &[u8],
+    sprite_stride: u32,
+    sprite_size: ImageSize,
+    x: i32,
+    y: i32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    blend_rgba_over_nv_impl::<{ YuvSourceChannels::Bgra as u8 }, { YuvNVOrder::UV as u8 }>(
+        frame,
+        sprite,
+        sprite_stride,
+        sprite_size,
+        x,
+        y,
+        range,
+        matrix,
+    )
+}
+
+/// Alpha blends BGRA sprite over NV21 frame in place.
+///
+/// Only the region covered by the sprite is converted, sprite pixels are transformed to YUV
+/// and blended with straight (not premultiplied) alpha. Chroma of each 2x2 block is the average
+/// of its blended pixels, so partially covered blocks at sprite edges are blended proportionally.
+/// Sprite may be placed partially or entirely outside of the frame, only the overlap is blended.
+///
+/// # Arguments
+///
+/// * `frame` - NV21 frame to blend onto.
+/// * `sprite` - The input BGRA sprite data slice.
+/// * `sprite_stride` - The stride (bytes per row) for the BGRA sprite data.
+/// * `sprite_size` - The size of the sprite in pixels.
+/// * `x` - Horizontal position of the sprite top left corner in the frame.
+/// * `y` - Vertical position of the sprite top left corner in the frame.
+/// * `range` - The YUV range (limited or full) of the frame.
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other) of the frame.
+///
+pub fn blend_bgra_over_nv21(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    sprite: &[u8],
+    sprite_stride: u32,
+    sprite_size: ImageSize,
+    x: i32,
+    y: i32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    blend_rgba_over_nv_impl::<{ YuvSourceChannels::Bgra as u8 }, { YuvNVOrder::VU as u8 }>(
+        frame,
+        sprite,
+        sprite_stride,
+        sprite_size,
+        x,
+        y,
+        range,
+        matrix,
+    )
+}
@@ -0,0 +1,375 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::blend_mask::YuvColor;
+use crate::images::{try_vec, YuvPlanarImage};
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::neon::neon_chroma_key_alpha_row;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::sse::sse_chroma_key_alpha_row;
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_support::{YuvChromaSample, YuvRange, YuvSourceChannels, YuvStandardMatrix};
+use crate::yuv_to_rgba::yuv_to_rgbx;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+/// Key color and alpha ramp shared by scalar and SIMD paths
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ChromaKeyParams {
+    pub(crate) key_u: i16,
+    pub(crate) key_v: i16,
+    pub(crate) tolerance: f32,
+    /// Alpha gained per chroma unit of distance past tolerance
+    pub(crate) scale: f32,
+}
+
+impl ChromaKeyParams {
+    fn new(key_color: YuvColor, tolerance: u8, softness: u8) -> ChromaKeyParams {
+        ChromaKeyParams {
+            key_u: key_color.u as i16,
+            key_v: key_color.v as i16,
+            tolerance: tolerance as f32,
+            scale: 255. / softness.max(1) as f32,
+        }
+    }
+
+    /// Alpha of a single chroma sample, SIMD kernels must follow the same operation order
+    #[inline(always)]
+    pub(crate) fn alpha(&self, u: u8, v: u8) -> u8 {
+        let du = u as i32 - self.key_u as i32;
+        let dv = v as i32 - self.key_v as i32;
+        let distance = ((du * du + dv * dv) as f32).sqrt();
+        let alpha = ((distance - self.tolerance) * self.scale).max(0.).min(255.);
+        (alpha + 0.5) as u8
+    }
+}
+
+/// Removes component of chroma pointing towards key color so keyed edges
+/// and reflections lose their tint
+#[inline]
+fn suppress_spill(u: u8, v: u8, key_u: i32, key_v: i32) -> (u8, u8) {
+    let cu = u as i32 - 128;
+    let cv = v as i32 - 128;
+    let key_norm = key_u * key_u + key_v * key_v;
+    let dot = cu * key_u + cv * key_v;
+    if dot <= 0 || key_norm == 0 {
+        return (u, v);
+    }
+    let projection = dot as f32 / key_norm as f32;
+    let new_u = (cu as f32 - projection * key_u as f32).round() as i32 + 128;
+    let new_v = (cv as f32 - projection * key_v as f32).round() as i32 + 128;
+    (new_u.clamp(0, 255) as u8, new_v.clamp(0, 255) as u8)
+}
+
+fn chroma_key_yuv_to_rgbx<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    key_color: YuvColor,
+    tolerance: u8,
+    softness: u8,
+    spill_suppression: bool,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+    let width = image.size.width;
+    let height = image.size.height;
+
+    image.check_constraints(sampling)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+
+    let (shift_x, shift_y) = match sampling {
+        YuvChromaSample::YUV420 => (1, 1),
+        YuvChromaSample::YUV422 => (1, 0),
+        YuvChromaSample::YUV444 => (0, 0),
+    };
+    let chroma_width = (width as usize).div_ceil(1 << shift_x);
+    let chroma_height = (height as usize).div_ceil(1 << shift_y);
+    let u_stride = image.u_stride.get() as usize;
+    let v_stride = image.v_stride.get() as usize;
+
+    let params = ChromaKeyParams::new(key_color, tolerance, softness);
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    // Alpha is decided on subsampled chroma, pixels sharing a chroma sample share alpha
+    let mut alpha = try_vec(0u8, chroma_width * chroma_height)?;
+    let alpha_iter;
+    #[cfg(feature = "rayon")]
+    {
+        alpha_iter = alpha.par_chunks_mut(chroma_width);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        alpha_iter = alpha.chunks_mut(chroma_width);
+    }
+    alpha_iter.enumerate().for_each(|(cy, alpha_row)| {
+        let u_row = &image.u_plane[cy * u_stride..];
+        let v_row = &image.v_plane[cy * v_stride..];
+
+        #[allow(unused_mut)]
+        let mut cx = 0usize;
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            if _use_sse {
+                cx = sse_chroma_key_alpha_row(&params, u_row, v_row, alpha_row, chroma_width);
+            }
+        }
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            cx = neon_chroma_key_alpha_row(&params, u_row, v_row, alpha_row, chroma_width);
+        }
+
+        for ((dst, &u), &v) in alpha_row
+            .iter_mut()
+            .zip(u_row.iter())
+            .zip(v_row.iter())
+            .skip(cx)
+        {
+            *dst = params.alpha(u, v);
+        }
+    });
+
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => {
+            yuv_to_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+        }
+        YuvChromaSample::YUV422 => {
+            yuv_to_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+        }
+        YuvChromaSample::YUV444 => {
+            yuv_to_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+        }
+    };
+
+    if spill_suppression {
+        let key_u = key_color.u as i32 - 128;
+        let key_v = key_color.v as i32 - 128;
+        let mut u_plane = try_vec(0u8, chroma_width * chroma_height)?;
+        let mut v_plane = try_vec(0u8, chroma_width * chroma_height)?;
+        let spill_iter;
+        #[cfg(feature = "rayon")]
+        {
+            spill_iter = u_plane
+                .par_chunks_mut(chroma_width)
+                .zip(v_plane.par_chunks_mut(chroma_width));
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            spill_iter = u_plane
+                .chunks_mut(chroma_width)
+                .zip(v_plane.chunks_mut(chroma_width));
+        }
+        spill_iter.enumerate().for_each(|(cy, (u_dst, v_dst))| {
+            let u_row = &image.u_plane[cy * u_stride..];
+            let v_row = &image.v_plane[cy * v_stride..];
+            for (((u_dst, v_dst), &u), &v) in u_dst
+                .iter_mut()
+                .zip(v_dst.iter_mut())
+                .zip(u_row.iter())
+                .zip(v_row.iter())
+            {
+                (*u_dst, *v_dst) = suppress_spill(u, v, key_u, key_v);
+            }
+        });
+        handler(
+            image.y_plane,
+            image.y_stride.get(),
+            &u_plane,
+            chroma_width as u32,
+            &v_plane,
+            chroma_width as u32,
+            rgba,
+            rgba_stride,
+            width,
+            height,
+            range,
+            matrix,
+        )?;
+    } else {
+        handler(
+            image.y_plane,
+            image.y_stride.get(),
+            image.u_plane,
+            u_stride as u32,
+            image.v_plane,
+            v_stride as u32,
+            rgba,
+            rgba_stride,
+            width,
+            height,
+            range,
+            matrix,
+        )?;
+    }
+
+    let a_offset = dst_chans.get_a_channel_offset();
+    let rgba_iter;
+    #[cfg(feature = "rayon")]
+    {
+        rgba_iter = rgba
+            .par_chunks_mut(rgba_stride as usize)
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        rgba_iter = rgba.chunks_mut(rgba_stride as usize).take(height as usize);
+    }
+    rgba_iter.enumerate().for_each(|(y, rgba_row)| {
+        let alpha_row = &alpha[(y >> shift_y) * chroma_width..];
+        for (x, dst) in rgba_row
+            .chunks_exact_mut(channels)
+            .take(width as usize)
+            .enumerate()
+        {
+            dst[a_offset] = alpha_row[x >> shift_x];
+        }
+    });
+
+    Ok(())
+}
+
+/// Convert planar YUV image to RGBA, alpha is keyed out from chroma distance to `key_color`.
+///
+/// Keying is done on subsampled chroma before any conversion: samples within `tolerance`
+/// of the key chroma become transparent, alpha then rises linearly over `softness` chroma
+/// units until fully opaque. Luma of `key_color` is not used. With `spill_suppression`
+/// chroma is additionally stripped of its component towards the key before conversion,
+/// which removes key tint from edges and reflections.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `key_color` - Color to key out, only `u` and `v` are used.
+/// * `tolerance` - Chroma distance fully keyed out.
+/// * `softness` - Width of the alpha ramp past `tolerance`, 0 is treated as 1.
+/// * `spill_suppression` - Strip key tint from remaining chroma.
+///
+pub fn chroma_key_yuv_to_rgba(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    key_color: YuvColor,
+    tolerance: u8,
+    softness: u8,
+    spill_suppression: bool,
+) -> Result<(), YuvError> {
+    chroma_key_yuv_to_rgbx::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        sampling,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+        key_color,
+        tolerance,
+        softness,
+        spill_suppression,
+    )
+}
+
+/// Convert planar YUV image to BGRA, alpha is keyed out from chroma distance to `key_color`.
+///
+/// Keying is done on subsampled chroma before any conversion: samples within `tolerance`
+/// of the key chroma become transparent, alpha then rises linearly over `softness` chroma
+/// units until fully opaque. Luma of `key_color` is not used. With `spill_suppression`
+/// chroma is additionally stripped of its component towards the key before conversion,
+/// which removes key tint from edges and reflections.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `key_color` - Color to key out, only `u` and `v` are used.
+/// * `tolerance` - Chroma distance fully keyed out.
+/// * `softness` - Width of the alpha ramp past `tolerance`, 0 is treated as 1.
+/// * `spill_suppression` - Strip key tint from remaining chroma.
+///
+pub fn chroma_key_yuv_to_bgra(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    key_color: YuvColor,
+    tolerance: u8,
+    softness: u8,
+    spill_suppression: bool,
+) -> Result<(), YuvError> {
+    chroma_key_yuv_to_rgbx::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        sampling,
+        bgra,
+        bgra_stride,
+        range,
+        matrix,
+        key_color,
+        tolerance,
+        softness,
+        spill_suppression,
+    )
+}
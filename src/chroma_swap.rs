@@ -0,0 +1,78 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::check_chroma_channel;
+use crate::yuv_support::YuvChromaSample;
+use crate::YuvError;
+
+/// Swaps U and V planes contents in place.
+///
+/// Useful when planar image was mislabeled, e.g. YV12 was received where I420 was expected,
+/// and planes are owned by separate buffers so just swapping references is not an option.
+///
+/// # Arguments
+///
+/// * `u_plane` - A mutable slice containing U plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice containing V plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `sampling` - Chroma subsampling of the image.
+///
+pub fn swap_chroma_planes<V: Copy>(
+    u_plane: &mut [V],
+    u_stride: u32,
+    v_plane: &mut [V],
+    v_stride: u32,
+    width: u32,
+    height: u32,
+    sampling: YuvChromaSample,
+) -> Result<(), YuvError> {
+    check_chroma_channel(u_plane, u_stride, width, height, sampling)?;
+    check_chroma_channel(v_plane, v_stride, width, height, sampling)?;
+
+    if u_stride == v_stride && u_plane.len() == v_plane.len() {
+        u_plane.swap_with_slice(v_plane);
+        return Ok(());
+    }
+
+    let chroma_width = match sampling {
+        YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => width.div_ceil(2),
+        YuvChromaSample::YUV444 => width,
+    } as usize;
+    let element_size = std::mem::size_of::<V>();
+
+    for (u_row, v_row) in u_plane
+        .chunks_mut(u_stride as usize / element_size)
+        .zip(v_plane.chunks_mut(v_stride as usize / element_size))
+    {
+        u_row[..chroma_width].swap_with_slice(&mut v_row[..chroma_width]);
+    }
+    Ok(())
+}
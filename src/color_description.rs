@@ -0,0 +1,831 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{YuvPlanarImage, YuvPlanarImageMut};
+use crate::rgb_to_ycgco::rgbx_to_ycgco;
+use crate::rgba_to_yuv::rgbx_to_yuv8;
+use crate::ycgco_to_rgb::ycgco_ro_rgbx;
+use crate::yuv_support::{YuvChromaSample, YuvRange, YuvSourceChannels, YuvStandardMatrix};
+use crate::yuv_to_rgba::yuv_to_rgbx;
+use crate::YuvError;
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Matrix coefficients as signalled by ITU-T H.273 `MatrixCoefficients`
+pub enum YuvMatrixCoefficients {
+    Identity = 0,
+    Bt709 = 1,
+    Unspecified = 2,
+    Fcc = 4,
+    Bt470Bg = 5,
+    Smpte170m = 6,
+    Smpte240m = 7,
+    YCgCo = 8,
+    Bt2020Ncl = 9,
+    Bt2020Cl = 10,
+    Smpte2085 = 11,
+    ChromaticityDerivedNcl = 12,
+    ChromaticityDerivedCl = 13,
+    ICtCp = 14,
+    IptC2 = 15,
+    YCgCoRe = 16,
+    YCgCoRo = 17,
+}
+
+impl From<u8> for YuvMatrixCoefficients {
+    /// Reserved code points are mapped to [YuvMatrixCoefficients::Unspecified]
+    fn from(value: u8) -> Self {
+        match value {
+            0 => YuvMatrixCoefficients::Identity,
+            1 => YuvMatrixCoefficients::Bt709,
+            4 => YuvMatrixCoefficients::Fcc,
+            5 => YuvMatrixCoefficients::Bt470Bg,
+            6 => YuvMatrixCoefficients::Smpte170m,
+            7 => YuvMatrixCoefficients::Smpte240m,
+            8 => YuvMatrixCoefficients::YCgCo,
+            9 => YuvMatrixCoefficients::Bt2020Ncl,
+            10 => YuvMatrixCoefficients::Bt2020Cl,
+            11 => YuvMatrixCoefficients::Smpte2085,
+            12 => YuvMatrixCoefficients::ChromaticityDerivedNcl,
+            13 => YuvMatrixCoefficients::ChromaticityDerivedCl,
+            14 => YuvMatrixCoefficients::ICtCp,
+            15 => YuvMatrixCoefficients::IptC2,
+            16 => YuvMatrixCoefficients::YCgCoRe,
+            17 => YuvMatrixCoefficients::YCgCoRo,
+            _ => YuvMatrixCoefficients::Unspecified,
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Transfer characteristics as signalled by ITU-T H.273 `TransferCharacteristics`
+pub enum YuvTransferCharacteristics {
+    Bt709 = 1,
+    Unspecified = 2,
+    Bt470M = 4,
+    Bt470Bg = 5,
+    Smpte170m = 6,
+    Smpte240m = 7,
+    Linear = 8,
+    Log100 = 9,
+    Log100Sqrt10 = 10,
+    Iec61966 = 11,
+    Bt1361 = 12,
+    Srgb = 13,
+    Bt2020_10bit = 14,
+    Bt2020_12bit = 15,
+    Smpte2084 = 16,
+    Smpte428 = 17,
+    Hlg = 18,
+}
+
+impl From<u8> for YuvTransferCharacteristics {
+    /// Reserved code points are mapped to [YuvTransferCharacteristics::Unspecified]
+    fn from(value: u8) -> Self {
+        match value {
+            1 => YuvTransferCharacteristics::Bt709,
+            4 => YuvTransferCharacteristics::Bt470M,
+            5 => YuvTransferCharacteristics::Bt470Bg,
+            6 => YuvTransferCharacteristics::Smpte170m,
+            7 => YuvTransferCharacteristics::Smpte240m,
+            8 => YuvTransferCharacteristics::Linear,
+            9 => YuvTransferCharacteristics::Log100,
+            10 => YuvTransferCharacteristics::Log100Sqrt10,
+            11 => YuvTransferCharacteristics::Iec61966,
+            12 => YuvTransferCharacteristics::Bt1361,
+            13 => YuvTransferCharacteristics::Srgb,
+            14 => YuvTransferCharacteristics::Bt2020_10bit,
+            15 => YuvTransferCharacteristics::Bt2020_12bit,
+            16 => YuvTransferCharacteristics::Smpte2084,
+            17 => YuvTransferCharacteristics::Smpte428,
+            18 => YuvTransferCharacteristics::Hlg,
+            _ => YuvTransferCharacteristics::Unspecified,
+        }
+    }
+}
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Colour primaries as signalled by ITU-T H.273 `ColourPrimaries`
+pub enum YuvColorPrimaries {
+    Bt709 = 1,
+    Unspecified = 2,
+    Bt470M = 4,
+    Bt470Bg = 5,
+    Smpte170m = 6,
+    Smpte240m = 7,
+    Film = 8,
+    Bt2020 = 9,
+    Smpte428 = 10,
+    Smpte431 = 11,
+    Smpte432 = 12,
+    Ebu3213 = 22,
+}
+
+impl From<u8> for YuvColorPrimaries {
+    /// Reserved code points are mapped to [YuvColorPrimaries::Unspecified]
+    fn from(value: u8) -> Self {
+        match value {
+            1 => YuvColorPrimaries::Bt709,
+            4 => YuvColorPrimaries::Bt470M,
+            5 => YuvColorPrimaries::Bt470Bg,
+            6 => YuvColorPrimaries::Smpte170m,
+            7 => YuvColorPrimaries::Smpte240m,
+            8 => YuvColorPrimaries::Film,
+            9 => YuvColorPrimaries::Bt2020,
+            10 => YuvColorPrimaries::Smpte428,
+            11 => YuvColorPrimaries::Smpte431,
+            12 => YuvColorPrimaries::Smpte432,
+            22 => YuvColorPrimaries::Ebu3213,
+            _ => YuvColorPrimaries::Unspecified,
+        }
+    }
+}
+
+impl YuvColorPrimaries {
+    /// Returns CIE 1931 xy chromaticities of red, green, blue and white point
+    pub const fn chromaticities(self) -> Option<[(f64, f64); 4]> {
+        const D65: (f64, f64) = (0.3127, 0.3290);
+        const ILLUMINANT_C: (f64, f64) = (0.310, 0.316);
+        match self {
+            YuvColorPrimaries::Bt709 => Some([(0.64, 0.33), (0.30, 0.60), (0.15, 0.06), D65]),
+            YuvColorPrimaries::Unspecified => None,
+            YuvColorPrimaries::Bt470M => {
+                Some([(0.67, 0.33), (0.21, 0.71), (0.14, 0.08), ILLUMINANT_C])
+            }
+            YuvColorPrimaries::Bt470Bg => Some([(0.64, 0.33), (0.29, 0.60), (0.15, 0.06), D65]),
+            YuvColorPrimaries::Smpte170m | YuvColorPrimaries::Smpte240m => {
+                Some([(0.630, 0.340), (0.310, 0.595), (0.155, 0.070), D65])
+            }
+            YuvColorPrimaries::Film => {
+                Some([(0.681, 0.319), (0.243, 0.692), (0.145, 0.049), ILLUMINANT_C])
+            }
+            YuvColorPrimaries::Bt2020 => {
+                Some([(0.708, 0.292), (0.170, 0.797), (0.131, 0.046), D65])
+            }
+            YuvColorPrimaries::Smpte428 => {
+                Some([(1.0, 0.0), (0.0, 1.0), (0.0, 0.0), (1.0 / 3.0, 1.0 / 3.0)])
+            }
+            YuvColorPrimaries::Smpte431 => Some([
+                (0.680, 0.320),
+                (0.265, 0.690),
+                (0.150, 0.060),
+                (0.314, 0.351),
+            ]),
+            YuvColorPrimaries::Smpte432 => {
+                Some([(0.680, 0.320), (0.265, 0.690), (0.150, 0.060), D65])
+            }
+            YuvColorPrimaries::Ebu3213 => {
+                Some([(0.630, 0.340), (0.295, 0.605), (0.155, 0.077), D65])
+            }
+        }
+    }
+
+    /// Derives luma coefficients Kr and Kb from the primaries, as H.273 does
+    /// for [YuvMatrixCoefficients::ChromaticityDerivedNcl]
+    fn derive_kr_kb(self) -> Option<(f32, f32)> {
+        let [r, g, b, w] = self.chromaticities()?;
+        let z = |(x, y): (f64, f64)| 1.0 - (x + y);
+        let (xr, yr, zr) = (r.0, r.1, z(r));
+        let (xg, yg, zg) = (g.0, g.1, z(g));
+        let (xb, yb, zb) = (b.0, b.1, z(b));
+        let (xw, yw, zw) = (w.0, w.1, z(w));
+        let denominator =
+            yw * (xr * (yg * zb - yb * zg) + xg * (yb * zr - yr * zb) + xb * (yr * zg - yg * zr));
+        if denominator == 0.0 {
+            return None;
+        }
+        let kr = yr
+            * (xw * (yg * zb - yb * zg) + yw * (xb * zg - xg * zb) + zw * (xg * yb - xb * yg))
+            / denominator;
+        let kb = yb
+            * (xw * (yr * zg - yg * zr) + yw * (xg * zr - xr * zg) + zw * (xr * yg - xg * yr))
+            / denominator;
+        Some((kr as f32, kb as f32))
+    }
+
+    /// Derives linear RGB to CIE XYZ matrix and XYZ of the white point with `Y = 1`,
+    /// as in SMPTE RP 177
+    pub(crate) fn rgb_to_xyz(self) -> Option<([[f32; 3]; 3], [f32; 3])> {
+        let chromaticities = self.chromaticities()?;
+        if chromaticities.iter().any(|&(_, y)| y == 0.0) {
+            return None;
+        }
+        let xyz = |(x, y): (f64, f64)| [x / y, 1.0, (1.0 - x - y) / y];
+        let [r, g, b, w] = chromaticities.map(xyz);
+        let m = [[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]];
+        let determinant = m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0]);
+        if determinant == 0.0 {
+            return None;
+        }
+        // Scales of each primary so that R = G = B = 1 lands on the white point, by Cramer's rule
+        let solve = |column: usize| {
+            let mut c = m;
+            for (row, value) in c.iter_mut().zip(w.iter()) {
+                row[column] = *value;
+            }
+            (c[0][0] * (c[1][1] * c[2][2] - c[1][2] * c[2][1])
+                - c[0][1] * (c[1][0] * c[2][2] - c[1][2] * c[2][0])
+                + c[0][2] * (c[1][0] * c[2][1] - c[1][1] * c[2][0]))
+                / determinant
+        };
+        let scales = [solve(0), solve(1), solve(2)];
+        let matrix = m.map(|row| {
+            [
+                (row[0] * scales[0]) as f32,
+                (row[1] * scales[1]) as f32,
+                (row[2] * scales[2]) as f32,
+            ]
+        });
+        Some((matrix, w.map(|v| v as f32)))
+    }
+}
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Chroma sample location relative to luma, as signalled by `chroma_sample_loc_type`
+/// in H.264 and H.265 VUI
+pub enum YuvChromaSiting {
+    /// Co-sited horizontally with the left luma sample, vertically centered
+    Left = 0,
+    /// Centered between luma samples in both directions
+    Center = 1,
+    /// Co-sited with the top left luma sample
+    TopLeft = 2,
+    /// Horizontally centered, co-sited with the top luma row
+    Top = 3,
+    /// Co-sited with the bottom left luma sample
+    BottomLeft = 4,
+    /// Horizontally centered, co-sited with the bottom luma row
+    Bottom = 5,
+}
+
+impl From<u8> for YuvChromaSiting {
+    /// Out of range values are mapped to [YuvChromaSiting::Left], the VUI default
+    fn from(value: u8) -> Self {
+        match value {
+            1 => YuvChromaSiting::Center,
+            2 => YuvChromaSiting::TopLeft,
+            3 => YuvChromaSiting::Top,
+            4 => YuvChromaSiting::BottomLeft,
+            5 => YuvChromaSiting::Bottom,
+            _ => YuvChromaSiting::Left,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// Per-frame colour metadata as carried by decoders, i.e. H.273 code points
+/// from the bitstream VUI or container
+pub struct ColorDescription {
+    pub matrix: YuvMatrixCoefficients,
+    pub range: YuvRange,
+    /// Conversions keep samples in this transfer, it is carried for callers only
+    pub transfer: YuvTransferCharacteristics,
+    /// Used only to derive coefficients for [YuvMatrixCoefficients::ChromaticityDerivedNcl]
+    pub primaries: YuvColorPrimaries,
+    pub siting: YuvChromaSiting,
+}
+
+impl ColorDescription {
+    /// Builds description from raw code points as they appear in the bitstream
+    pub fn from_codes(
+        matrix: u8,
+        full_range: bool,
+        transfer: u8,
+        primaries: u8,
+        siting: u8,
+    ) -> ColorDescription {
+        ColorDescription {
+            matrix: matrix.into(),
+            range: if full_range {
+                YuvRange::Full
+            } else {
+                YuvRange::TV
+            },
+            transfer: transfer.into(),
+            primaries: primaries.into(),
+            siting: siting.into(),
+        }
+    }
+
+    /// Resolves matrix coefficients into one of the prebuilt YCbCr matrices.
+    ///
+    /// Non YCbCr matrices, constant luminance variants and unspecified coefficients
+    /// are reported as [YuvError::UnsupportedMatrixCoefficients].
+    pub fn standard_matrix(&self) -> Result<YuvStandardMatrix, YuvError> {
+        match self.matrix {
+            YuvMatrixCoefficients::Bt709 => Ok(YuvStandardMatrix::Bt709),
+            YuvMatrixCoefficients::Bt470Bg | YuvMatrixCoefficients::Smpte170m => {
+                Ok(YuvStandardMatrix::Bt601)
+            }
+            YuvMatrixCoefficients::Smpte240m => Ok(YuvStandardMatrix::Smpte240),
+            YuvMatrixCoefficients::Bt2020Ncl => Ok(YuvStandardMatrix::Bt2020),
+            YuvMatrixCoefficients::Fcc => Ok(YuvStandardMatrix::Custom(0.30, 0.11)),
+            YuvMatrixCoefficients::ChromaticityDerivedNcl => {
+                let (kr, kb) = self
+                    .primaries
+                    .derive_kr_kb()
+                    .ok_or(YuvError::UnsupportedColorPrimaries(self.primaries))?;
+                Ok(YuvStandardMatrix::Custom(kr, kb))
+            }
+            YuvMatrixCoefficients::Identity
+            | YuvMatrixCoefficients::Unspecified
+            | YuvMatrixCoefficients::YCgCo
+            | YuvMatrixCoefficients::Bt2020Cl
+            | YuvMatrixCoefficients::Smpte2085
+            | YuvMatrixCoefficients::ChromaticityDerivedCl
+            | YuvMatrixCoefficients::ICtCp
+            | YuvMatrixCoefficients::IptC2
+            | YuvMatrixCoefficients::YCgCoRe
+            | YuvMatrixCoefficients::YCgCoRo => {
+                Err(YuvError::UnsupportedMatrixCoefficients(self.matrix))
+            }
+        }
+    }
+
+    /// Encoders keep chroma of the left and right pixel averaged, and on 4:2:0
+    /// take it from the top row of each pair
+    fn check_encoder_siting(&self, sampling: YuvChromaSample) -> Result<(), YuvError> {
+        let supported = match sampling {
+            YuvChromaSample::YUV444 => true,
+            YuvChromaSample::YUV422 => matches!(
+                self.siting,
+                YuvChromaSiting::Center | YuvChromaSiting::Top | YuvChromaSiting::Bottom
+            ),
+            YuvChromaSample::YUV420 => self.siting == YuvChromaSiting::Top,
+        };
+        if !supported {
+            return Err(YuvError::UnsupportedChromaSiting(self.siting));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// Coding-independent code points as stored in PNG `cICP` chunk, AVIF `colr` box
+/// and other ITU-T H.273 containers
+pub struct CicpTag {
+    pub primaries: YuvColorPrimaries,
+    pub transfer: YuvTransferCharacteristics,
+    pub matrix: YuvMatrixCoefficients,
+    pub full_range: bool,
+}
+
+impl CicpTag {
+    /// Payload of PNG `cICP` chunk: primaries, transfer, matrix and full range flag
+    pub const fn to_bytes(self) -> [u8; 4] {
+        [
+            self.primaries as u8,
+            self.transfer as u8,
+            self.matrix as u8,
+            self.full_range as u8,
+        ]
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// Colour tags of RGB produced by conversion, PNG fallback chunks for readers without `cICP` support
+pub struct RgbExportTags {
+    /// Always has [YuvMatrixCoefficients::Identity] and full range, as conversions output full range RGB
+    pub cicp: CicpTag,
+    /// Output may be tagged with PNG `sRGB` chunk, primaries are BT.709 and transfer is sRGB
+    pub srgb: bool,
+    /// PNG `cHRM` payload: white point, red, green and blue xy chromaticities scaled by 100000
+    pub chrm: Option<[u32; 8]>,
+    /// PNG `gAMA` payload: encoding gamma scaled by 100000, set only for pure power
+    /// and sRGB transfers
+    pub gama: Option<u32>,
+}
+
+impl ColorDescription {
+    /// Returns tags to store with RGB converted from this description.
+    ///
+    /// Conversions keep primaries and transfer untouched, so exported images must be tagged
+    /// with the source ones instead of the commonly assumed sRGB.
+    pub fn rgb_export_tags(&self) -> RgbExportTags {
+        let chrm = self.primaries.chromaticities().map(|[r, g, b, w]| {
+            let scale = |v: f64| (v * 100000.0).round() as u32;
+            [
+                scale(w.0),
+                scale(w.1),
+                scale(r.0),
+                scale(r.1),
+                scale(g.0),
+                scale(g.1),
+                scale(b.0),
+                scale(b.1),
+            ]
+        });
+        let gama = match self.transfer {
+            YuvTransferCharacteristics::Srgb | YuvTransferCharacteristics::Bt470M => Some(45455),
+            YuvTransferCharacteristics::Bt470Bg => Some(35714),
+            YuvTransferCharacteristics::Linear => Some(100000),
+            _ => None,
+        };
+        RgbExportTags {
+            cicp: CicpTag {
+                primaries: self.primaries,
+                transfer: self.transfer,
+                matrix: YuvMatrixCoefficients::Identity,
+                full_range: true,
+            },
+            srgb: self.primaries == YuvColorPrimaries::Bt709
+                && self.transfer == YuvTransferCharacteristics::Srgb,
+            chrm,
+            gama,
+        }
+    }
+}
+
+fn described_yuv_to_rgbx<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    description: ColorDescription,
+) -> Result<(), YuvError> {
+    if description.matrix == YuvMatrixCoefficients::YCgCo {
+        let handler = match sampling {
+            YuvChromaSample::YUV420 => {
+                ycgco_ro_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+            }
+            YuvChromaSample::YUV422 => {
+                ycgco_ro_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+            }
+            YuvChromaSample::YUV444 => {
+                ycgco_ro_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+            }
+        };
+        return handler(
+            image.y_plane,
+            image.y_stride.get(),
+            image.u_plane,
+            image.u_stride.get(),
+            image.v_plane,
+            image.v_stride.get(),
+            rgba,
+            rgba_stride,
+            image.size.width,
+            image.size.height,
+            description.range,
+        );
+    }
+    let matrix = description.standard_matrix()?;
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => {
+            yuv_to_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+        }
+        YuvChromaSample::YUV422 => {
+            yuv_to_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+        }
+        YuvChromaSample::YUV444 => {
+            yuv_to_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+        }
+    };
+    handler(
+        image.y_plane,
+        image.y_stride.get(),
+        image.u_plane,
+        image.u_stride.get(),
+        image.v_plane,
+        image.v_stride.get(),
+        rgba,
+        rgba_stride,
+        image.size.width,
+        image.size.height,
+        description.range,
+        matrix,
+    )
+}
+
+fn described_rgbx_to_yuv<const ORIGIN_CHANNELS: u8>(
+    image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    rgba: &[u8],
+    rgba_stride: u32,
+    description: ColorDescription,
+) -> Result<(), YuvError> {
+    description.check_encoder_siting(sampling)?;
+    let width = image.size.width;
+    let height = image.size.height;
+    let y_stride = image.y_stride.get();
+    let u_stride = image.u_stride.get();
+    let v_stride = image.v_stride.get();
+    if description.matrix == YuvMatrixCoefficients::YCgCo {
+        let handler = match sampling {
+            YuvChromaSample::YUV420 => {
+                rgbx_to_ycgco::<ORIGIN_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+            }
+            YuvChromaSample::YUV422 => {
+                rgbx_to_ycgco::<ORIGIN_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+            }
+            YuvChromaSample::YUV444 => {
+                rgbx_to_ycgco::<ORIGIN_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+            }
+        };
+        return handler(
+            image.y_plane.borrow_mut(),
+            y_stride,
+            image.u_plane.borrow_mut(),
+            u_stride,
+            image.v_plane.borrow_mut(),
+            v_stride,
+            rgba,
+            rgba_stride,
+            width,
+            height,
+            description.range,
+        );
+    }
+    let matrix = description.standard_matrix()?;
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => {
+            rgbx_to_yuv8::<ORIGIN_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+        }
+        YuvChromaSample::YUV422 => {
+            rgbx_to_yuv8::<ORIGIN_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+        }
+        YuvChromaSample::YUV444 => {
+            rgbx_to_yuv8::<ORIGIN_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+        }
+    };
+    handler(
+        image.y_plane.borrow_mut(),
+        y_stride,
+        image.u_plane.borrow_mut(),
+        u_stride,
+        image.v_plane.borrow_mut(),
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        description.range,
+        matrix,
+    )
+}
+
+/// Convert planar YUV image to RGB using colour metadata reported by a decoder.
+///
+/// Matrix and range are taken from `description`, [YuvMatrixCoefficients::YCgCo] is decoded
+/// with YCgCo conversion. Chroma is upsampled by replication so any siting is accepted.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image with 8-bit samples.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `description` - Colour metadata of the frame.
+///
+pub fn yuv_to_rgb_with_description(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgb: &mut [u8],
+    rgb_stride: u32,
+    description: ColorDescription,
+) -> Result<(), YuvError> {
+    described_yuv_to_rgbx::<{ YuvSourceChannels::Rgb as u8 }>(
+        image,
+        sampling,
+        rgb,
+        rgb_stride,
+        description,
+    )
+}
+
+/// Convert planar YUV image to BGR using colour metadata reported by a decoder.
+///
+/// Matrix and range are taken from `description`, [YuvMatrixCoefficients::YCgCo] is decoded
+/// with YCgCo conversion. Chroma is upsampled by replication so any siting is accepted.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image with 8-bit samples.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `bgr` - A mutable slice to store the converted BGR data.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `description` - Colour metadata of the frame.
+///
+pub fn yuv_to_bgr_with_description(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    bgr: &mut [u8],
+    bgr_stride: u32,
+    description: ColorDescription,
+) -> Result<(), YuvError> {
+    described_yuv_to_rgbx::<{ YuvSourceChannels::Bgr as u8 }>(
+        image,
+        sampling,
+        bgr,
+        bgr_stride,
+        description,
+    )
+}
+
+/// Convert planar YUV image to RGBA using colour metadata reported by a decoder.
+///
+/// Matrix and range are taken from `description`, [YuvMatrixCoefficients::YCgCo] is decoded
+/// with YCgCo conversion. Chroma is upsampled by replication so any siting is accepted.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image with 8-bit samples.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `description` - Colour metadata of the frame.
+///
+pub fn yuv_to_rgba_with_description(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    description: ColorDescription,
+) -> Result<(), YuvError> {
+    described_yuv_to_rgbx::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        sampling,
+        rgba,
+        rgba_stride,
+        description,
+    )
+}
+
+/// Convert planar YUV image to BGRA using colour metadata reported by a decoder.
+///
+/// Matrix and range are taken from `description`, [YuvMatrixCoefficients::YCgCo] is decoded
+/// with YCgCo conversion. Chroma is upsampled by replication so any siting is accepted.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image with 8-bit samples.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `description` - Colour metadata of the frame.
+///
+pub fn yuv_to_bgra_with_description(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    description: ColorDescription,
+) -> Result<(), YuvError> {
+    described_yuv_to_rgbx::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        sampling,
+        bgra,
+        bgra_stride,
+        description,
+    )
+}
+
+/// Convert RGB image data to planar YUV using colour metadata that will be signalled with it.
+///
+/// Matrix and range are taken from `description`, [YuvMatrixCoefficients::YCgCo] is encoded
+/// with YCgCo conversion. Subsampled chroma is horizontally centered and on 4:2:0 sited at the top
+/// row, other sitings are reported as [YuvError::UnsupportedChromaSiting].
+///
+/// # Arguments
+///
+/// * `image` - Destination planar YUV image with 8-bit samples.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `rgb` - The input RGB image data slice.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `description` - Colour metadata of the frame.
+///
+pub fn rgb_to_yuv_with_description(
+    image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    rgb: &[u8],
+    rgb_stride: u32,
+    description: ColorDescription,
+) -> Result<(), YuvError> {
+    described_rgbx_to_yuv::<{ YuvSourceChannels::Rgb as u8 }>(
+        image,
+        sampling,
+        rgb,
+        rgb_stride,
+        description,
+    )
+}
+
+/// Convert BGR image data to planar YUV using colour metadata that will be signalled with it.
+///
+/// Matrix and range are taken from `description`, [YuvMatrixCoefficients::YCgCo] is encoded
+/// with YCgCo conversion. Subsampled chroma is horizontally centered and on 4:2:0 sited at the top
+/// row, other sitings are reported as [YuvError::UnsupportedChromaSiting].
+///
+/// # Arguments
+///
+/// * `image` - Destination planar YUV image with 8-bit samples.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `bgr` - The input BGR image data slice.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `description` - Colour metadata of the frame.
+///
+pub fn bgr_to_yuv_with_description(
+    image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    bgr: &[u8],
+    bgr_stride: u32,
+    description: ColorDescription,
+) -> Result<(), YuvError> {
+    described_rgbx_to_yuv::<{ YuvSourceChannels::Bgr as u8 }>(
+        image,
+        sampling,
+        bgr,
+        bgr_stride,
+        description,
+    )
+}
+
+/// Convert RGBA image data to planar YUV using colour metadata that will be signalled with it.
+///
+/// Matrix and range are taken from `description`, [YuvMatrixCoefficients::YCgCo] is encoded
+/// with YCgCo conversion. Subsampled chroma is horizontally centered and on 4:2:0 sited at the top
+/// row, other sitings are reported as [YuvError::UnsupportedChromaSiting].
+///
+/// # Arguments
+///
+/// * `image` - Destination planar YUV image with 8-bit samples.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `description` - Colour metadata of the frame.
+///
+pub fn rgba_to_yuv_with_description(
+    image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    rgba: &[u8],
+    rgba_stride: u32,
+    description: ColorDescription,
+) -> Result<(), YuvError> {
+    described_rgbx_to_yuv::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        sampling,
+        rgba,
+        rgba_stride,
+        description,
+    )
+}
+
+/// Convert BGRA image data to planar YUV using colour metadata that will be signalled with it.
+///
+/// Matrix and range are taken from `description`, [YuvMatrixCoefficients::YCgCo] is encoded
+/// with YCgCo conversion. Subsampled chroma is horizontally centered and on 4:2:0 sited at the top
+/// row, other sitings are reported as [YuvError::UnsupportedChromaSiting].
+///
+/// # Arguments
+///
+/// * `image` - Destination planar YUV image with 8-bit samples.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `description` - Colour metadata of the frame.
+///
+pub fn bgra_to_yuv_with_description(
+    image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    bgra: &[u8],
+    bgra_stride: u32,
+    description: ColorDescription,
+) -> Result<(), YuvError> {
+    described_rgbx_to_yuv::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        sampling,
+        bgra,
+        bgra_stride,
+        description,
+    )
+}
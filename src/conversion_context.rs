@@ -0,0 +1,709 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::fast_approx::{
+    rgbx_to_yuv_fastest, yuv_to_rgbx_fastest, yuv_to_rgbx_fastest_rows, YuvConversionMode,
+};
+use crate::frame_pool::YuvFramePoolLayout;
+use crate::images::{
+    ImageSize, YuvBiPlanarImage, YuvBiPlanarImageMut, YuvGrayAlphaImage, YuvGrayAlphaImageMut,
+    YuvGrayImage, YuvGrayImageMut, YuvPlanarImage, YuvPlanarImageMut,
+};
+use crate::lut3d::Lut3d;
+use crate::rgba_to_yuv::rgbx_to_yuv8_broadcast_safe;
+use crate::scratch::YuvScratchArena;
+use crate::yuv_error::{check_rgba_destination, with_stride_mode, YuvStrideMode};
+use crate::yuv_lut::{yuv_to_rgbx_banded, YuvRgbLut};
+use crate::yuv_support::{
+    YuvBroadcastSafe, YuvChromaSample, YuvRange, YuvSourceChannels, YuvStandardMatrix,
+};
+use crate::yuv_to_rgba::yuv_to_rgbx_rows;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+use std::ops::Range;
+
+/// Converts `rows` of an image into a band holding only these rows, `rows.start` is even
+type YuvBandHandler =
+    fn(&YuvConversionContext, &YuvPlanarImage<u8>, &mut [u8], u32, Range<u32>, YuvStandardMatrix);
+
+/// Rows of a frame that finished conversion, handed out by
+/// [YuvConversionContext::convert_many_rows_into_iter].
+#[derive(Debug)]
+pub struct YuvRowBand<'a> {
+    rows: Range<u32>,
+    data: &'a mut [u8],
+}
+
+impl YuvRowBand<'_> {
+    /// Rows of the frame this band holds
+    pub fn rows(&self) -> Range<u32> {
+        self.rows.clone()
+    }
+
+    /// Converted pixels of the band, rows are destination stride bytes apart
+    pub fn data(&self) -> &[u8] {
+        self.data
+    }
+
+    /// Converted pixels of the band for in place post-processing, e.g. drawing overlays
+    /// before the band is sent, rows are destination stride bytes apart
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        self.data
+    }
+}
+
+/// Iterator converting a frame band after band as it is advanced,
+/// see [YuvConversionContext::convert_many_rows_into_iter].
+#[derive(Debug)]
+pub struct YuvConvertedRows<'a> {
+    context: YuvConversionContext,
+    image: &'a YuvPlanarImage<'a, u8>,
+    handler: YuvBandHandler,
+    matrix: YuvStandardMatrix,
+    remaining: &'a mut [u8],
+    stride: u32,
+    band_rows: u32,
+    next_row: u32,
+}
+
+impl<'a> Iterator for YuvConvertedRows<'a> {
+    type Item = YuvRowBand<'a>;
+
+    fn next(&mut self) -> Option<YuvRowBand<'a>> {
+        let height = self.image.size.height;
+        if self.next_row >= height {
+            return None;
+        }
+        let rows = self.next_row..(self.next_row + self.band_rows).min(height);
+        let band_len = (rows.len() * self.stride as usize).min(self.remaining.len());
+        let (data, remaining) = std::mem::take(&mut self.remaining).split_at_mut(band_len);
+        self.remaining = remaining;
+        (self.handler)(
+            &self.context,
+            self.image,
+            data,
+            self.stride,
+            rows.clone(),
+            self.matrix,
+        );
+        self.next_row = rows.end;
+        Some(YuvRowBand { rows, data })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self
+            .image
+            .size
+            .height
+            .saturating_sub(self.next_row)
+            .div_ceil(self.band_rows) as usize;
+        (left, Some(left))
+    }
+}
+
+/// Conversion parameters shared by every frame of a stream.
+///
+/// Context is immutable and holds no caches, the only shared state conversions touch is
+/// process wide dispatch configuration kept in atomics. So a single context can be handed
+/// to any number of per-stream worker threads and used from all of them at once.
+///
+/// `*_rows` methods convert only a part of the frame, e.g. to spread conversion of a large frame
+/// over several rendering ticks on low-power devices.
+/// [YuvConversionContext::convert_many_rows_into_iter] hands out bands of rows as soon as they are
+/// converted, so pipelined consumers e.g. network senders overlap their work with conversion.
+///
+/// [YuvConversionMode::Fastest] mode, selected with [YuvConversionContext::with_mode],
+/// trades accuracy for speed e.g. for thumbnails.
+/// Buffers are validated in [YuvStrideMode::Strict] unless
+/// [YuvConversionContext::with_stride_mode] says otherwise, regardless of the caller's scope.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct YuvConversionContext {
+    pub sampling: YuvChromaSample,
+    pub range: YuvRange,
+    pub matrix: YuvStandardMatrix,
+    pub mode: YuvConversionMode,
+    pub stride_mode: YuvStrideMode,
+}
+
+impl YuvConversionContext {
+    pub const fn new(
+        sampling: YuvChromaSample,
+        range: YuvRange,
+        matrix: YuvStandardMatrix,
+    ) -> YuvConversionContext {
+        YuvConversionContext {
+            sampling,
+            range,
+            matrix,
+            mode: YuvConversionMode::Exact,
+            stride_mode: YuvStrideMode::Strict,
+        }
+    }
+
+    /// Returns the same context converting in `mode`
+    pub const fn with_mode(self, mode: YuvConversionMode) -> YuvConversionContext {
+        YuvConversionContext { mode, ..self }
+    }
+
+    /// Returns the same context validating buffers of its conversions in `stride_mode`
+    pub const fn with_stride_mode(self, stride_mode: YuvStrideMode) -> YuvConversionContext {
+        YuvConversionContext {
+            stride_mode,
+            ..self
+        }
+    }
+
+    fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8>(
+        &self,
+        image: &YuvPlanarImage<u8>,
+        rgba: &mut [u8],
+        rgba_stride: u32,
+    ) -> Result<(), YuvError> {
+        with_stride_mode(self.stride_mode, || {
+            if self.mode == YuvConversionMode::Fastest {
+                return yuv_to_rgbx_fastest::<DESTINATION_CHANNELS>(
+                    image,
+                    self.sampling,
+                    rgba,
+                    rgba_stride,
+                    self.range,
+                    self.matrix,
+                );
+            }
+            yuv_to_rgbx_banded::<DESTINATION_CHANNELS>(
+                image,
+                self.sampling,
+                rgba,
+                rgba_stride,
+                self.range,
+                self.matrix,
+                |_| {},
+            )
+        })
+    }
+
+    fn yuv_to_rgbx_rows<const DESTINATION_CHANNELS: u8>(
+        &self,
+        image: &YuvPlanarImage<u8>,
+        rgba: &mut [u8],
+        rgba_stride: u32,
+        rows: Range<u32>,
+    ) -> Result<(), YuvError> {
+        with_stride_mode(self.stride_mode, || {
+            let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+            let width = image.size.width;
+            let height = image.size.height;
+
+            image.check_constraints(self.sampling)?;
+            check_rgba_destination(
+                rgba,
+                rgba_stride,
+                width,
+                height,
+                dst_chans.get_channels_count(),
+            )?;
+            if rows.start > rows.end || rows.end > height {
+                return Err(YuvError::InvalidRowRange(rows.start, rows.end));
+            }
+            if self.mode == YuvConversionMode::Fastest {
+                yuv_to_rgbx_fastest_rows::<DESTINATION_CHANNELS>(
+                    image,
+                    self.sampling,
+                    rgba,
+                    rgba_stride,
+                    self.range,
+                    self.matrix,
+                    rows.start as usize..rows.end as usize,
+                );
+                return Ok(());
+            }
+
+            let handler = match self.sampling {
+                YuvChromaSample::YUV420 => {
+                    yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+                }
+                YuvChromaSample::YUV422 => {
+                    yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+                }
+                YuvChromaSample::YUV444 => {
+                    yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+                }
+            };
+
+            // Automatic matrix follows the whole frame, not the converted range
+            let matrix = self.matrix.resolve(width, height);
+            let mut start = rows.start;
+            while start < rows.end {
+                // 4:2:0 kernels read chroma row `y / 2` counting from the first row they get,
+                // so an odd first row sharing chroma with the row above is converted on its own
+                let (end, chroma_row) = match self.sampling {
+                    YuvChromaSample::YUV420 if start & 1 == 1 => (start + 1, start / 2),
+                    YuvChromaSample::YUV420 => (rows.end, start / 2),
+                    YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => (rows.end, start),
+                };
+                let dst_start = start as usize * rgba_stride as usize;
+                let dst_end = (end as usize * rgba_stride as usize).min(rgba.len());
+                handler(
+                    &image.y_plane[start as usize * image.y_stride.get() as usize..],
+                    image.y_stride.get(),
+                    &image.u_plane[chroma_row as usize * image.u_stride.get() as usize..],
+                    image.u_stride.get(),
+                    &image.v_plane[chroma_row as usize * image.v_stride.get() as usize..],
+                    image.v_stride.get(),
+                    &mut rgba[dst_start..dst_end],
+                    rgba_stride,
+                    width,
+                    end - start,
+                    self.range,
+                    matrix,
+                    255,
+                );
+                start = end;
+            }
+
+            Ok(())
+        })
+    }
+
+    fn yuv_to_rgbx_band<const DESTINATION_CHANNELS: u8>(
+        &self,
+        image: &YuvPlanarImage<u8>,
+        band: &mut [u8],
+        band_stride: u32,
+        rows: Range<u32>,
+        matrix: YuvStandardMatrix,
+    ) {
+        let start = rows.start as usize;
+        let chroma_row = match self.sampling {
+            YuvChromaSample::YUV420 => start / 2,
+            YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => start,
+        };
+        let band_image = YuvPlanarImage {
+            y_plane: &image.y_plane[start * image.y_stride.get() as usize..],
+            y_stride: image.y_stride,
+            u_plane: &image.u_plane[chroma_row * image.u_stride.get() as usize..],
+            u_stride: image.u_stride,
+            v_plane: &image.v_plane[chroma_row * image.v_stride.get() as usize..],
+            v_stride: image.v_stride,
+            size: ImageSize::new(image.size.width, rows.end - rows.start),
+        };
+        if self.mode == YuvConversionMode::Fastest {
+            yuv_to_rgbx_fastest_rows::<DESTINATION_CHANNELS>(
+                &band_image,
+                self.sampling,
+                band,
+                band_stride,
+                self.range,
+                matrix,
+                0..rows.len(),
+            );
+            return;
+        }
+        let handler = match self.sampling {
+            YuvChromaSample::YUV420 => {
+                yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+            }
+            YuvChromaSample::YUV422 => {
+                yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+            }
+            YuvChromaSample::YUV444 => {
+                yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+            }
+        };
+        handler(
+            band_image.y_plane,
+            band_image.y_stride.get(),
+            band_image.u_plane,
+            band_image.u_stride.get(),
+            band_image.v_plane,
+            band_image.v_stride.get(),
+            band,
+            band_stride,
+            band_image.size.width,
+            band_image.size.height,
+            self.range,
+            matrix,
+            255,
+        );
+    }
+
+    /// Validates frame and destination, returns band handler, matrix of the whole frame,
+    /// rows per band and part of destination covering the frame
+    fn prepare_row_bands<'a>(
+        &self,
+        image: &YuvPlanarImage<u8>,
+        layout: YuvFramePoolLayout,
+        dst: &'a mut [u8],
+        dst_stride: u32,
+        band_rows: u32,
+    ) -> Result<(YuvBandHandler, YuvStandardMatrix, u32, &'a mut [u8]), YuvError> {
+        with_stride_mode(self.stride_mode, || {
+            let width = image.size.width;
+            let height = image.size.height;
+            image.check_constraints(self.sampling)?;
+            check_rgba_destination(dst, dst_stride, width, height, layout.bytes_per_pixel())?;
+
+            let handler: YuvBandHandler = match layout {
+                YuvFramePoolLayout::Rgb => {
+                    YuvConversionContext::yuv_to_rgbx_band::<{ YuvSourceChannels::Rgb as u8 }>
+                }
+                YuvFramePoolLayout::Rgba => {
+                    YuvConversionContext::yuv_to_rgbx_band::<{ YuvSourceChannels::Rgba as u8 }>
+                }
+                YuvFramePoolLayout::Bgra => {
+                    YuvConversionContext::yuv_to_rgbx_band::<{ YuvSourceChannels::Bgra as u8 }>
+                }
+            };
+            // Bands start on even rows so 4:2:0 chroma row is never shared by two bands
+            let band_rows = band_rows.clamp(2, height.max(2));
+            let band_rows = band_rows + (band_rows & 1);
+            let frame_len = (height as usize * dst_stride as usize).min(dst.len());
+            Ok((
+                handler,
+                self.matrix.resolve(width, height),
+                band_rows,
+                &mut dst[..frame_len],
+            ))
+        })
+    }
+
+    fn rgbx_to_yuv<const ORIGIN_CHANNELS: u8>(
+        &self,
+        rgba: &[u8],
+        rgba_stride: u32,
+        planar_image: &mut YuvPlanarImageMut<u8>,
+    ) -> Result<(), YuvError> {
+        with_stride_mode(self.stride_mode, || {
+            if self.mode == YuvConversionMode::Fastest {
+                return rgbx_to_yuv_fastest::<ORIGIN_CHANNELS>(
+                    rgba,
+                    rgba_stride,
+                    planar_image,
+                    self.sampling,
+                    self.range,
+                    self.matrix,
+                );
+            }
+            rgbx_to_yuv8_broadcast_safe::<ORIGIN_CHANNELS>(
+                rgba,
+                rgba_stride,
+                planar_image,
+                self.sampling,
+                self.range,
+                self.matrix,
+                YuvBroadcastSafe::default(),
+            )
+            .map(|_| ())
+        })
+    }
+
+    /// Converts planar YUV image to RGB.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Source planar image, subsampled as the context says.
+    /// * `rgb` - A mutable slice to store the converted RGB data.
+    /// * `rgb_stride` - The stride (bytes per row) for RGB data.
+    ///
+    pub fn yuv_to_rgb(
+        &self,
+        image: &YuvPlanarImage<u8>,
+        rgb: &mut [u8],
+        rgb_stride: u32,
+    ) -> Result<(), YuvError> {
+        self.yuv_to_rgbx::<{ YuvSourceChannels::Rgb as u8 }>(image, rgb, rgb_stride)
+    }
+
+    /// Converts rows `rows` of planar YUV image to RGB, rows outside of the range are left untouched.
+    ///
+    /// Output is identical to converting the whole frame at once, so a frame may be converted
+    /// piece by piece over several calls in any order.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Whole source planar image, subsampled as the context says.
+    /// * `rgb` - A mutable slice of the whole destination RGB image.
+    /// * `rgb_stride` - The stride (bytes per row) for RGB data.
+    /// * `rows` - Range of image rows to convert.
+    ///
+    pub fn yuv_to_rgb_rows(
+        &self,
+        image: &YuvPlanarImage<u8>,
+        rgb: &mut [u8],
+        rgb_stride: u32,
+        rows: Range<u32>,
+    ) -> Result<(), YuvError> {
+        self.yuv_to_rgbx_rows::<{ YuvSourceChannels::Rgb as u8 }>(image, rgb, rgb_stride, rows)
+    }
+
+    /// Converts planar YUV image to RGBA, alpha is opaque.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Source planar image, subsampled as the context says.
+    /// * `rgba` - A mutable slice to store the converted RGBA data.
+    /// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+    ///
+    pub fn yuv_to_rgba(
+        &self,
+        image: &YuvPlanarImage<u8>,
+        rgba: &mut [u8],
+        rgba_stride: u32,
+    ) -> Result<(), YuvError> {
+        self.yuv_to_rgbx::<{ YuvSourceChannels::Rgba as u8 }>(image, rgba, rgba_stride)
+    }
+
+    /// Converts rows `rows` of planar YUV image to RGBA, rows outside of the range are left untouched.
+    ///
+    /// Output is identical to converting the whole frame at once, so a frame may be converted
+    /// piece by piece over several calls in any order.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Whole source planar image, subsampled as the context says.
+    /// * `rgba` - A mutable slice of the whole destination RGBA image.
+    /// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+    /// * `rows` - Range of image rows to convert.
+    ///
+    pub fn yuv_to_rgba_rows(
+        &self,
+        image: &YuvPlanarImage<u8>,
+        rgba: &mut [u8],
+        rgba_stride: u32,
+        rows: Range<u32>,
+    ) -> Result<(), YuvError> {
+        self.yuv_to_rgbx_rows::<{ YuvSourceChannels::Rgba as u8 }>(image, rgba, rgba_stride, rows)
+    }
+
+    /// Converts planar YUV image to BGRA, alpha is opaque.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Source planar image, subsampled as the context says.
+    /// * `bgra` - A mutable slice to store the converted BGRA data.
+    /// * `bgra_stride` - The stride (bytes per row) for BGRA data.
+    ///
+    pub fn yuv_to_bgra(
+        &self,
+        image: &YuvPlanarImage<u8>,
+        bgra: &mut [u8],
+        bgra_stride: u32,
+    ) -> Result<(), YuvError> {
+        self.yuv_to_rgbx::<{ YuvSourceChannels::Bgra as u8 }>(image, bgra, bgra_stride)
+    }
+
+    /// Converts rows `rows` of planar YUV image to BGRA, rows outside of the range are left untouched.
+    ///
+    /// Output is identical to converting the whole frame at once, so a frame may be converted
+    /// piece by piece over several calls in any order.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Whole source planar image, subsampled as the context says.
+    /// * `bgra` - A mutable slice of the whole destination BGRA image.
+    /// * `bgra_stride` - The stride (bytes per row) for BGRA data.
+    /// * `rows` - Range of image rows to convert.
+    ///
+    pub fn yuv_to_bgra_rows(
+        &self,
+        image: &YuvPlanarImage<u8>,
+        bgra: &mut [u8],
+        bgra_stride: u32,
+        rows: Range<u32>,
+    ) -> Result<(), YuvError> {
+        self.yuv_to_rgbx_rows::<{ YuvSourceChannels::Bgra as u8 }>(image, bgra, bgra_stride, rows)
+    }
+
+    /// Converts planar YUV image in bands of rows, one band each time the returned
+    /// iterator is advanced.
+    ///
+    /// Finished bands can be sent or encoded right away, overlapping their transmission
+    /// with conversion of the rest of the frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Source planar image, subsampled as the context says.
+    /// * `layout` - Interleaved layout of converted pixels.
+    /// * `dst` - A mutable slice to store the converted data, bands borrow from it.
+    /// * `dst_stride` - The stride (bytes per row) for converted data.
+    /// * `band_rows` - Rows in a band, rounded up to an even count.
+    ///
+    pub fn convert_many_rows_into_iter<'a>(
+        &self,
+        image: &'a YuvPlanarImage<'a, u8>,
+        layout: YuvFramePoolLayout,
+        dst: &'a mut [u8],
+        dst_stride: u32,
+        band_rows: u32,
+    ) -> Result<YuvConvertedRows<'a>, YuvError> {
+        let (handler, matrix, band_rows, dst) =
+            self.prepare_row_bands(image, layout, dst, dst_stride, band_rows)?;
+        Ok(YuvConvertedRows {
+            context: *self,
+            image,
+            handler,
+            matrix,
+            remaining: dst,
+            stride: dst_stride,
+            band_rows,
+            next_row: 0,
+        })
+    }
+
+    /// Converts planar YUV image in bands of rows on rayon pool and hands bands to `consume`
+    /// over a bounded channel as soon as each one is finished.
+    ///
+    /// Bands arrive in order they are finished, not in order of rows. `consume` runs
+    /// on the calling thread while workers convert, so calling it from the only thread
+    /// of a rayon pool deadlocks.
+    /// Workers wait when `capacity` bands are queued, and stop converting once `consume`
+    /// drops the iterator. Returns what `consume` returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Source planar image, subsampled as the context says.
+    /// * `layout` - Interleaved layout of converted pixels.
+    /// * `dst` - A mutable slice to store the converted data, bands borrow from it.
+    /// * `dst_stride` - The stride (bytes per row) for converted data.
+    /// * `band_rows` - Rows in a band, rounded up to an even count.
+    /// * `capacity` - Finished bands that may wait for `consume`.
+    /// * `consume` - Receives the iterator of finished bands.
+    ///
+    #[cfg(feature = "rayon")]
+    pub fn convert_many_rows_into_channel<R>(
+        &self,
+        image: &YuvPlanarImage<u8>,
+        layout: YuvFramePoolLayout,
+        dst: &mut [u8],
+        dst_stride: u32,
+        band_rows: u32,
+        capacity: usize,
+        consume: impl FnOnce(std::sync::mpsc::IntoIter<YuvRowBand<'_>>) -> R,
+    ) -> Result<R, YuvError> {
+        let (handler, matrix, band_rows, dst) =
+            self.prepare_row_bands(image, layout, dst, dst_stride, band_rows)?;
+        let height = image.size.height;
+        let band_stride = band_rows as usize * dst_stride as usize;
+        let (sender, receiver) = std::sync::mpsc::sync_channel(capacity);
+
+        Ok(std::thread::scope(|scope| {
+            scope.spawn(move || {
+                dst.par_chunks_mut(band_stride)
+                    .enumerate()
+                    .try_for_each_with(sender, |sender, (band, data)| {
+                        let start = band as u32 * band_rows;
+                        let rows = start..(start + band_rows).min(height);
+                        handler(self, image, data, dst_stride, rows.clone(), matrix);
+                        sender.send(YuvRowBand { rows, data })
+                    })
+            });
+            consume(receiver.into_iter())
+        }))
+    }
+
+    /// Converts RGB to planar YUV image.
+    ///
+    /// # Arguments
+    ///
+    /// * `rgb` - The input RGB image data slice.
+    /// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+    /// * `planar_image` - Target planar image, subsampled as the context says.
+    ///
+    pub fn rgb_to_yuv(
+        &self,
+        rgb: &[u8],
+        rgb_stride: u32,
+        planar_image: &mut YuvPlanarImageMut<u8>,
+    ) -> Result<(), YuvError> {
+        self.rgbx_to_yuv::<{ YuvSourceChannels::Rgb as u8 }>(rgb, rgb_stride, planar_image)
+    }
+
+    /// Converts RGBA to planar YUV image, alpha is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `rgba` - The input RGBA image data slice.
+    /// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+    /// * `planar_image` - Target planar image, subsampled as the context says.
+    ///
+    pub fn rgba_to_yuv(
+        &self,
+        rgba: &[u8],
+        rgba_stride: u32,
+        planar_image: &mut YuvPlanarImageMut<u8>,
+    ) -> Result<(), YuvError> {
+        self.rgbx_to_yuv::<{ YuvSourceChannels::Rgba as u8 }>(rgba, rgba_stride, planar_image)
+    }
+
+    /// Converts BGRA to planar YUV image, alpha is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `bgra` - The input BGRA image data slice.
+    /// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+    /// * `planar_image` - Target planar image, subsampled as the context says.
+    ///
+    pub fn bgra_to_yuv(
+        &self,
+        bgra: &[u8],
+        bgra_stride: u32,
+        planar_image: &mut YuvPlanarImageMut<u8>,
+    ) -> Result<(), YuvError> {
+        self.rgbx_to_yuv::<{ YuvSourceChannels::Bgra as u8 }>(bgra, bgra_stride, planar_image)
+    }
+}
+
+/// Public types carrying state between calls must stay shareable between worker threads,
+/// adding a non thread safe member to any of them fails the build here.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<YuvConversionContext>();
+    assert_send_sync::<YuvError>();
+    assert_send_sync::<Lut3d>();
+    assert_send_sync::<crate::frame_pool::YuvFramePool>();
+    assert_send_sync::<crate::frame_pool::YuvConvertedFrame>();
+    assert_send_sync::<YuvConvertedRows<'static>>();
+    assert_send_sync::<YuvRowBand<'static>>();
+    assert_send_sync::<YuvRgbLut<'static, u8>>();
+    assert_send_sync::<YuvRgbLut<'static, u16>>();
+    assert_send_sync::<YuvScratchArena<'static>>();
+    assert_send_sync::<YuvGrayImage<'static, u8>>();
+    assert_send_sync::<YuvGrayImageMut<'static, u16>>();
+    assert_send_sync::<YuvGrayAlphaImage<'static, u8>>();
+    assert_send_sync::<YuvGrayAlphaImageMut<'static, u16>>();
+    assert_send_sync::<YuvPlanarImage<'static, u8>>();
+    assert_send_sync::<YuvPlanarImageMut<'static, u16>>();
+    assert_send_sync::<YuvBiPlanarImage<'static, u8>>();
+    assert_send_sync::<YuvBiPlanarImageMut<'static, u16>>();
+    #[cfg(feature = "frame-hash")]
+    assert_send_sync::<crate::frame_reuse::YuvFrameReuse>();
+};
@@ -0,0 +1,180 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// SIMD backend picked by runtime dispatch for the conversion
+pub enum YuvDispatchBackend {
+    Scalar,
+    Sse41,
+    Avx2,
+    Avx512Bw,
+    Neon,
+    WasmSimd128,
+}
+
+impl YuvDispatchBackend {
+    /// Rank of x86 runtime dispatched backend, other backends are chosen at compile time
+    #[allow(dead_code)]
+    pub(crate) const fn x86_rank(self) -> Option<u8> {
+        match self {
+            YuvDispatchBackend::Scalar => Some(0),
+            YuvDispatchBackend::Sse41 => Some(1),
+            YuvDispatchBackend::Avx2 => Some(2),
+            YuvDispatchBackend::Avx512Bw => Some(3),
+            YuvDispatchBackend::Neon | YuvDispatchBackend::WasmSimd128 => None,
+        }
+    }
+
+    /// Next slower backend whose baseline throughput stands in for this one when it was
+    /// not measured, 128-bit NEON and wasm kernels fall back to scalar
+    pub(crate) const fn measured_fallback(self) -> Option<YuvDispatchBackend> {
+        match self {
+            YuvDispatchBackend::Scalar => None,
+            YuvDispatchBackend::Sse41
+            | YuvDispatchBackend::Neon
+            | YuvDispatchBackend::WasmSimd128 => Some(YuvDispatchBackend::Scalar),
+            YuvDispatchBackend::Avx2 => Some(YuvDispatchBackend::Sse41),
+            YuvDispatchBackend::Avx512Bw => Some(YuvDispatchBackend::Avx2),
+        }
+    }
+}
+
+#[cfg(feature = "bench")]
+static BACKEND_LIMIT: AtomicU8 = AtomicU8::new(u8::MAX);
+
+/// Temporary cap used while backends are verified, see [crate::verify_backends]
+static VERIFICATION_LIMIT: AtomicU8 = AtomicU8::new(u8::MAX);
+
+/// Bit per backend rank that failed verification and must not be dispatched to
+static DISABLED_BACKENDS: AtomicU8 = AtomicU8::new(0);
+
+/// Caps x86 runtime dispatch at the given backend, `None` removes the cap.
+///
+/// Conversions never pick a backend faster than the cap, it lets benchmarks
+/// compare backends on a single machine. NEON and wasm backends are selected
+/// at compile time and are not affected.
+#[cfg(feature = "bench")]
+pub fn set_backend_limit(limit: Option<YuvDispatchBackend>) {
+    let rank = limit.and_then(|x| x.x86_rank()).unwrap_or(u8::MAX);
+    BACKEND_LIMIT.store(rank, Ordering::Relaxed);
+}
+
+#[allow(dead_code)]
+pub(crate) fn set_verification_limit(rank: u8) {
+    VERIFICATION_LIMIT.store(rank, Ordering::Relaxed);
+}
+
+#[allow(dead_code)]
+pub(crate) fn disable_backend(rank: u8) {
+    DISABLED_BACKENDS.fetch_or(1 << rank, Ordering::Relaxed);
+}
+
+#[inline(always)]
+#[allow(dead_code)]
+fn backend_allowed(rank: u8) -> bool {
+    #[cfg(feature = "bench")]
+    if BACKEND_LIMIT.load(Ordering::Relaxed) < rank {
+        return false;
+    }
+    VERIFICATION_LIMIT.load(Ordering::Relaxed) >= rank
+        && DISABLED_BACKENDS.load(Ordering::Relaxed) & (1 << rank) == 0
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+#[inline]
+pub(crate) fn has_sse41() -> bool {
+    backend_allowed(1) && std::arch::is_x86_feature_detected!("sse4.1")
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+#[inline]
+pub(crate) fn has_avx2() -> bool {
+    backend_allowed(2) && std::arch::is_x86_feature_detected!("avx2")
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+#[inline]
+#[allow(dead_code)]
+pub(crate) fn has_avx512bw() -> bool {
+    backend_allowed(3) && std::arch::is_x86_feature_detected!("avx512bw")
+}
+
+/// Resolves backend the same way as conversion dispatchers do,
+/// `has_avx512` and `has_wasm` tell if the conversion has such kernels at all
+#[allow(unused_variables)]
+pub(crate) fn dispatch_backend(has_avx512: bool, has_wasm: bool) -> YuvDispatchBackend {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly_avx512",
+        not(feature = "safe-only")
+    ))]
+    if has_avx512 && has_avx512bw() {
+        return YuvDispatchBackend::Avx512Bw;
+    }
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    {
+        if has_avx2() {
+            return YuvDispatchBackend::Avx2;
+        }
+        if has_sse41() {
+            return YuvDispatchBackend::Sse41;
+        }
+    }
+    #[cfg(all(
+        target_arch = "aarch64",
+        target_feature = "neon",
+        not(feature = "safe-only")
+    ))]
+    {
+        return YuvDispatchBackend::Neon;
+    }
+    #[cfg(all(
+        target_arch = "wasm32",
+        target_feature = "simd128",
+        not(feature = "safe-only")
+    ))]
+    if has_wasm {
+        return YuvDispatchBackend::WasmSimd128;
+    }
+    #[allow(unreachable_code)]
+    YuvDispatchBackend::Scalar
+}
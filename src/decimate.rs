@@ -0,0 +1,499 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{
+    ImageSize, YuvBiPlanarImage, YuvBiPlanarImageMut, YuvPlanarImage, YuvPlanarImageMut,
+};
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::neon::{neon_decimate_row, neon_decimate_row_u16};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::sse::{sse_decimate_row, sse_decimate_row_u16};
+use crate::yuv_support::YuvChromaSample;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+/// Downscale ratio applied to each dimension by box filter decimation
+pub enum YuvDecimation {
+    /// Average of 2x2 block
+    #[default]
+    Half,
+    /// Average of 4x4 block
+    Quarter,
+}
+
+impl YuvDecimation {
+    #[inline]
+    const fn factor(self) -> u32 {
+        match self {
+            YuvDecimation::Half => 2,
+            YuvDecimation::Quarter => 4,
+        }
+    }
+
+    /// Size of the image decimated from `size`, odd remainders are rounded up
+    #[inline]
+    pub const fn decimated_size(self, size: ImageSize) -> ImageSize {
+        let factor = self.factor();
+        ImageSize::new(size.width.div_ceil(factor), size.height.div_ceil(factor))
+    }
+}
+
+/// Plane to decimate, stride is in elements and width counts pixels, not samples
+#[derive(Copy, Clone)]
+struct DecimatedPlane<'a, T> {
+    data: &'a [T],
+    stride: usize,
+    width: usize,
+    height: usize,
+}
+
+/// Box filters `FACTOR`x`FACTOR` blocks of `CHANNELS` interleaved samples,
+/// edge pixels are replicated when dimensions are not multiple of `FACTOR`
+fn decimate_plane<T: Copy + Send + Sync, const FACTOR: usize, const CHANNELS: usize>(
+    src: DecimatedPlane<T>,
+    dst: &mut [T],
+    dst_stride: usize,
+    simd_row: impl Fn(&[&[T]; FACTOR], &mut [T], usize) -> usize + Send + Sync,
+    load: impl Fn(T) -> u32 + Send + Sync,
+    store: impl Fn(u32) -> T + Send + Sync,
+) {
+    let src_length = src.width * CHANNELS;
+    let dst_length = src.width.div_ceil(FACTOR) * CHANNELS;
+    let dst_height = src.height.div_ceil(FACTOR);
+    let shift = 2 * FACTOR.trailing_zeros();
+    let rounding = 1u32 << (shift - 1);
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst.par_chunks_mut(dst_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst.chunks_mut(dst_stride);
+    }
+
+    iter.take(dst_height).enumerate().for_each(|(y, dst_row)| {
+        let rows: [&[T]; FACTOR] = std::array::from_fn(|i| {
+            let src_y = (y * FACTOR + i).min(src.height - 1);
+            &src.data[src_y * src.stride..][..src_length]
+        });
+
+        let cx = simd_row(&rows, dst_row, src_length);
+
+        for (x, dst) in dst_row.iter_mut().enumerate().take(dst_length).skip(cx) {
+            let px = x / CHANNELS;
+            let channel = x % CHANNELS;
+            let mut sum = 0u32;
+            for row in rows.iter() {
+                for k in 0..FACTOR {
+                    let src_x = (px * FACTOR + k).min(src.width - 1);
+                    sum += load(row[src_x * CHANNELS + channel]);
+                }
+            }
+            *dst = store((sum + rounding) >> shift);
+        }
+    });
+}
+
+fn decimate_plane_u8<const FACTOR: usize, const CHANNELS: usize>(
+    src: DecimatedPlane<u8>,
+    dst: &mut [u8],
+    dst_stride: usize,
+) {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    decimate_plane::<u8, FACTOR, CHANNELS>(
+        src,
+        dst,
+        dst_stride,
+        |_rows, _dst_row, _length| {
+            let mut _cx = 0usize;
+
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "safe-only")
+            ))]
+            unsafe {
+                if _use_sse {
+                    _cx = sse_decimate_row::<FACTOR, CHANNELS>(_rows, _dst_row, _length);
+                }
+            }
+
+            #[cfg(all(
+                target_arch = "aarch64",
+                target_feature = "neon",
+                not(feature = "safe-only")
+            ))]
+            unsafe {
+                _cx = neon_decimate_row::<FACTOR, CHANNELS>(_rows, _dst_row, _length);
+            }
+
+            _cx
+        },
+        |v| v as u32,
+        |v| v as u8,
+    );
+}
+
+/// Same as [decimate_plane_u8] for 16-bit samples, `shift` drops padding bits
+/// of MSB aligned samples before summing.
+///
+/// LSB aligned samples are native endian, MSB aligned ones are little endian as P010 is.
+fn decimate_plane_u16<const FACTOR: usize, const CHANNELS: usize>(
+    src: DecimatedPlane<u16>,
+    dst: &mut [u16],
+    dst_stride: usize,
+    shift: u32,
+) {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    decimate_plane::<u16, FACTOR, CHANNELS>(
+        src,
+        dst,
+        dst_stride,
+        |_rows, _dst_row, _length| {
+            let mut _cx = 0usize;
+
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "safe-only")
+            ))]
+            unsafe {
+                if _use_sse {
+                    _cx = sse_decimate_row_u16::<FACTOR, CHANNELS>(_rows, _dst_row, _length, shift);
+                }
+            }
+
+            #[cfg(all(
+                target_arch = "aarch64",
+                target_feature = "neon",
+                not(feature = "safe-only")
+            ))]
+            unsafe {
+                _cx = neon_decimate_row_u16::<FACTOR, CHANNELS>(_rows, _dst_row, _length, shift);
+            }
+
+            _cx
+        },
+        |v| {
+            if shift != 0 {
+                (u16::from_le(v) >> shift) as u32
+            } else {
+                v as u32
+            }
+        },
+        |v| {
+            if shift != 0 {
+                ((v as u16) << shift).to_le()
+            } else {
+                v as u16
+            }
+        },
+    );
+}
+
+fn decimate_u8<const CHANNELS: usize>(
+    src: DecimatedPlane<u8>,
+    dst: &mut [u8],
+    dst_stride: usize,
+    decimation: YuvDecimation,
+) {
+    match decimation {
+        YuvDecimation::Half => decimate_plane_u8::<2, CHANNELS>(src, dst, dst_stride),
+        YuvDecimation::Quarter => decimate_plane_u8::<4, CHANNELS>(src, dst, dst_stride),
+    }
+}
+
+fn decimate_u16<const CHANNELS: usize>(
+    src: DecimatedPlane<u16>,
+    dst: &mut [u16],
+    dst_stride: usize,
+    decimation: YuvDecimation,
+    shift: u32,
+) {
+    match decimation {
+        YuvDecimation::Half => decimate_plane_u16::<2, CHANNELS>(src, dst, dst_stride, shift),
+        YuvDecimation::Quarter => decimate_plane_u16::<4, CHANNELS>(src, dst, dst_stride, shift),
+    }
+}
+
+fn chroma_size(size: ImageSize, sampling: YuvChromaSample) -> (usize, usize) {
+    match sampling {
+        YuvChromaSample::YUV420 => (
+            size.width.div_ceil(2) as usize,
+            size.height.div_ceil(2) as usize,
+        ),
+        YuvChromaSample::YUV422 => (size.width.div_ceil(2) as usize, size.height as usize),
+        YuvChromaSample::YUV444 => (size.width as usize, size.height as usize),
+    }
+}
+
+fn check_decimation(
+    src_size: ImageSize,
+    dst_size: ImageSize,
+    decimation: YuvDecimation,
+) -> Result<(), YuvError> {
+    if src_size.width == 0 || src_size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    let expected = decimation.decimated_size(src_size);
+    if dst_size != expected {
+        return Err(YuvError::ImageSizeMismatch(expected, dst_size));
+    }
+    Ok(())
+}
+
+fn decimate_planar_impl<T: Copy + std::fmt::Debug>(
+    src: &YuvPlanarImage<T>,
+    dst: &mut YuvPlanarImageMut<T>,
+    sampling: YuvChromaSample,
+    decimation: YuvDecimation,
+    decimate: impl Fn(DecimatedPlane<T>, &mut [T], usize, YuvDecimation),
+) -> Result<(), YuvError> {
+    check_decimation(src.size, dst.size, decimation)?;
+    src.check_constraints(sampling)?;
+    dst.check_constraints(sampling)?;
+
+    let element_size = std::mem::size_of::<T>();
+    let (chroma_width, chroma_height) = chroma_size(src.size, sampling);
+
+    let y_stride = dst.y_stride.get() as usize / element_size;
+    decimate(
+        DecimatedPlane {
+            data: src.y_plane,
+            stride: src.y_stride.get() as usize / element_size,
+            width: src.size.width as usize,
+            height: src.size.height as usize,
+        },
+        dst.y_plane.borrow_mut(),
+        y_stride,
+        decimation,
+    );
+    let u_stride = dst.u_stride.get() as usize / element_size;
+    decimate(
+        DecimatedPlane {
+            data: src.u_plane,
+            stride: src.u_stride.get() as usize / element_size,
+            width: chroma_width,
+            height: chroma_height,
+        },
+        dst.u_plane.borrow_mut(),
+        u_stride,
+        decimation,
+    );
+    let v_stride = dst.v_stride.get() as usize / element_size;
+    decimate(
+        DecimatedPlane {
+            data: src.v_plane,
+            stride: src.v_stride.get() as usize / element_size,
+            width: chroma_width,
+            height: chroma_height,
+        },
+        dst.v_plane.borrow_mut(),
+        v_stride,
+        decimation,
+    );
+    Ok(())
+}
+
+fn decimate_bi_planar_impl<T: Copy + std::fmt::Debug>(
+    src: &YuvBiPlanarImage<T>,
+    dst: &mut YuvBiPlanarImageMut<T>,
+    sampling: YuvChromaSample,
+    decimation: YuvDecimation,
+    decimate_luma: impl Fn(DecimatedPlane<T>, &mut [T], usize, YuvDecimation),
+    decimate_chroma: impl Fn(DecimatedPlane<T>, &mut [T], usize, YuvDecimation),
+) -> Result<(), YuvError> {
+    check_decimation(src.size, dst.size, decimation)?;
+    src.check_constraints(sampling)?;
+    dst.check_constraints(sampling)?;
+
+    let element_size = std::mem::size_of::<T>();
+    let (chroma_width, chroma_height) = chroma_size(src.size, sampling);
+
+    let y_stride = dst.y_stride.get() as usize / element_size;
+    decimate_luma(
+        DecimatedPlane {
+            data: src.y_plane,
+            stride: src.y_stride.get() as usize / element_size,
+            width: src.size.width as usize,
+            height: src.size.height as usize,
+        },
+        dst.y_plane.borrow_mut(),
+        y_stride,
+        decimation,
+    );
+    let uv_stride = dst.uv_stride.get() as usize / element_size;
+    decimate_chroma(
+        DecimatedPlane {
+            data: src.uv_plane,
+            stride: src.uv_stride.get() as usize / element_size,
+            width: chroma_width,
+            height: chroma_height,
+        },
+        dst.uv_plane.borrow_mut(),
+        uv_stride,
+        decimation,
+    );
+    Ok(())
+}
+
+/// Downscales planar YUV image by 2 or 4 in each dimension with box filter.
+///
+/// Every plane is decimated on its own grid, so chroma subsampling of the source is kept
+/// and chroma stays aligned with luma. Odd dimensions are rounded up, edge pixels are replicated.
+///
+/// # Arguments
+///
+/// * `planar_image` - Source planar image.
+/// * `target_image` - Target planar image of [YuvDecimation::decimated_size] of the source.
+/// * `sampling` - Chroma subsampling of both images.
+/// * `decimation` - Downscale ratio, see [YuvDecimation].
+///
+pub fn decimate_yuv(
+    planar_image: &YuvPlanarImage<u8>,
+    target_image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    decimation: YuvDecimation,
+) -> Result<(), YuvError> {
+    decimate_planar_impl(
+        planar_image,
+        target_image,
+        sampling,
+        decimation,
+        decimate_u8::<1>,
+    )
+}
+
+/// Downscales planar YUV image with 8+ bit pixel format by 2 or 4 in each dimension with box filter.
+///
+/// Samples are native endian and stored in the least significant bits, bit depth up to 12 is supported.
+/// See [decimate_yuv] for how planes are handled.
+///
+/// # Arguments
+///
+/// * `planar_image` - Source planar image.
+/// * `target_image` - Target planar image of [YuvDecimation::decimated_size] of the source.
+/// * `sampling` - Chroma subsampling of both images.
+/// * `decimation` - Downscale ratio, see [YuvDecimation].
+/// * `bit_depth` - Bit depth of the samples, from 8 to 12.
+///
+pub fn decimate_yuv_p16(
+    planar_image: &YuvPlanarImage<u16>,
+    target_image: &mut YuvPlanarImageMut<u16>,
+    sampling: YuvChromaSample,
+    decimation: YuvDecimation,
+    bit_depth: u32,
+) -> Result<(), YuvError> {
+    if !(8..=12).contains(&bit_depth) {
+        return Err(YuvError::UnsupportedBitDepth(bit_depth));
+    }
+    decimate_planar_impl(
+        planar_image,
+        target_image,
+        sampling,
+        decimation,
+        |src, dst, dst_stride, decimation| decimate_u16::<1>(src, dst, dst_stride, decimation, 0),
+    )
+}
+
+/// Downscales NV12 or NV16 image by 2 or 4 in each dimension with box filter.
+///
+/// Chroma channels of the interleaved plane are averaged separately and their order is kept,
+/// so the same call decimates NV21 and NV61. Odd dimensions are rounded up, edge pixels are replicated.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source bi-planar image.
+/// * `target_image` - Target bi-planar image of [YuvDecimation::decimated_size] of the source.
+/// * `sampling` - Chroma subsampling of both images.
+/// * `decimation` - Downscale ratio, see [YuvDecimation].
+///
+pub fn decimate_yuv_nv12(
+    bi_planar_image: &YuvBiPlanarImage<u8>,
+    target_image: &mut YuvBiPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    decimation: YuvDecimation,
+) -> Result<(), YuvError> {
+    decimate_bi_planar_impl(
+        bi_planar_image,
+        target_image,
+        sampling,
+        decimation,
+        decimate_u8::<1>,
+        decimate_u8::<2>,
+    )
+}
+
+/// Downscales P010 or P210 image by 2 or 4 in each dimension with box filter.
+///
+/// Both keep 10-bit samples in the most significant bits of little endian 16-bit words,
+/// averages are rounded back to 10 bits. See [decimate_yuv_nv12] for how chroma is handled.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source P010 image.
+/// * `target_image` - Target P010 image of [YuvDecimation::decimated_size] of the source.
+/// * `sampling` - Chroma subsampling of both images.
+/// * `decimation` - Downscale ratio, see [YuvDecimation].
+///
+pub fn decimate_yuv_p010(
+    bi_planar_image: &YuvBiPlanarImage<u16>,
+    target_image: &mut YuvBiPlanarImageMut<u16>,
+    sampling: YuvChromaSample,
+    decimation: YuvDecimation,
+) -> Result<(), YuvError> {
+    decimate_bi_planar_impl(
+        bi_planar_image,
+        target_image,
+        sampling,
+        decimation,
+        |src, dst, dst_stride, decimation| decimate_u16::<1>(src, dst, dst_stride, decimation, 6),
+        |src, dst, dst_stride, decimation| decimate_u16::<2>(src, dst, dst_stride, decimation, 6),
+    )
+}
@@ -0,0 +1,549 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{YuvPlanarImage, YuvPlanarImageMut};
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_support::{
+    get_forward_transform, get_inverse_transform, get_yuv_range, YuvChromaSample, YuvRange,
+    YuvSourceChannels, YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+use std::ops::Range;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, Default)]
+/// Trade-off between accuracy and speed of 8-bit conversions
+pub enum YuvConversionMode {
+    /// Fixed point matrix as specified by the standard, SIMD accelerated where available
+    #[default]
+    Exact,
+    /// Every coefficient is approximated by at most four powers of two, so pixels are converted
+    /// with shifts and additions only, chroma is taken from the nearest sample and averaged over
+    /// the subsampled block with shifts. Meant for thumbnails and previews on scalar-only targets.
+    ///
+    /// For Bt.601, Bt.709 and Bt.2020 in either range and codes inside the nominal range
+    /// every channel stays within ±3 codes of [YuvConversionMode::Exact] output.
+    Fastest,
+}
+
+/// Fraction bits kept while coefficient terms are accumulated
+const FASTEST_PRECISION: u32 = 10;
+/// Input is pre-scaled by this many bits so terms up to 4x are plain right shifts
+const FASTEST_HEADROOM: u32 = 2;
+const FASTEST_ROUNDING: i32 = 1 << (FASTEST_PRECISION - 1);
+/// Smallest term worth keeping, finer terms change no output code
+const FASTEST_MAX_SHIFT: u32 = FASTEST_PRECISION + FASTEST_HEADROOM;
+
+/// Coefficient approximated as a sum of signed powers of two,
+/// term with shift `s` weights input by `2^(FASTEST_HEADROOM - s)`
+#[derive(Debug, Copy, Clone, Default)]
+struct ShiftCoefficient {
+    terms: [(i32, u32); 4],
+    len: usize,
+}
+
+impl ShiftCoefficient {
+    /// Greedy approximation, every term takes the power of two nearest to what is left
+    fn new(coefficient: f32) -> ShiftCoefficient {
+        let mut approximation = ShiftCoefficient::default();
+        let mut residual = coefficient;
+        while approximation.len < approximation.terms.len() {
+            if residual.abs() < 1f32 / (1 << FASTEST_PRECISION) as f32 {
+                break;
+            }
+            let shift = (FASTEST_HEADROOM as f32 - residual.abs().log2())
+                .round()
+                .clamp(0f32, FASTEST_MAX_SHIFT as f32) as u32;
+            let sign = if residual < 0f32 { -1 } else { 1 };
+            approximation.terms[approximation.len] = (sign, shift);
+            approximation.len += 1;
+            residual -= sign as f32 * 2f32.powi(FASTEST_HEADROOM as i32 - shift as i32);
+        }
+        approximation
+    }
+
+    /// Weighted input with [FASTEST_PRECISION] fraction bits
+    #[inline(always)]
+    fn apply(&self, v: i32) -> i32 {
+        let scaled = v << (FASTEST_PRECISION + FASTEST_HEADROOM);
+        let mut acc = 0;
+        for &(sign, shift) in self.terms[..self.len].iter() {
+            let term = scaled >> shift;
+            acc += if sign < 0 { -term } else { term };
+        }
+        acc
+    }
+}
+
+struct FastestInverse {
+    y: ShiftCoefficient,
+    cr: ShiftCoefficient,
+    cb: ShiftCoefficient,
+    g_cr: ShiftCoefficient,
+    g_cb: ShiftCoefficient,
+    bias_y: i32,
+    bias_uv: i32,
+}
+
+impl FastestInverse {
+    fn new(range: YuvRange, matrix: YuvStandardMatrix, width: u32, height: u32) -> Self {
+        let range = get_yuv_range(8, range);
+        let kr_kb = matrix.resolve(width, height).get_kr_kb();
+        let transform =
+            get_inverse_transform(255, range.range_y, range.range_uv, kr_kb.kr, kr_kb.kb);
+        FastestInverse {
+            y: ShiftCoefficient::new(transform.y_coef),
+            cr: ShiftCoefficient::new(transform.cr_coef),
+            cb: ShiftCoefficient::new(transform.cb_coef),
+            g_cr: ShiftCoefficient::new(transform.g_coeff_1),
+            g_cb: ShiftCoefficient::new(transform.g_coeff_2),
+            bias_y: range.bias_y as i32,
+            bias_uv: range.bias_uv as i32,
+        }
+    }
+}
+
+struct FastestForward {
+    yr: ShiftCoefficient,
+    yg: ShiftCoefficient,
+    yb: ShiftCoefficient,
+    cb_r: ShiftCoefficient,
+    cb_g: ShiftCoefficient,
+    cb_b: ShiftCoefficient,
+    cr_r: ShiftCoefficient,
+    cr_g: ShiftCoefficient,
+    cr_b: ShiftCoefficient,
+    bias_y: i32,
+    bias_uv: i32,
+    max_y: i32,
+    min_uv: i32,
+    max_uv: i32,
+}
+
+impl FastestForward {
+    fn new(range: YuvRange, matrix: YuvStandardMatrix, width: u32, height: u32) -> Self {
+        let range = get_yuv_range(8, range);
+        let kr_kb = matrix.resolve(width, height).get_kr_kb();
+        let transform =
+            get_forward_transform(255, range.range_y, range.range_uv, kr_kb.kr, kr_kb.kb);
+        FastestForward {
+            yr: ShiftCoefficient::new(transform.yr),
+            yg: ShiftCoefficient::new(transform.yg),
+            yb: ShiftCoefficient::new(transform.yb),
+            cb_r: ShiftCoefficient::new(transform.cb_r),
+            cb_g: ShiftCoefficient::new(transform.cb_g),
+            cb_b: ShiftCoefficient::new(transform.cb_b),
+            cr_r: ShiftCoefficient::new(transform.cr_r),
+            cr_g: ShiftCoefficient::new(transform.cr_g),
+            cr_b: ShiftCoefficient::new(transform.cr_b),
+            bias_y: range.bias_y as i32,
+            bias_uv: range.bias_uv as i32,
+            max_y: (range.bias_y + range.range_y) as i32,
+            min_uv: range.min_uv() as i32,
+            max_uv: range.max_uv() as i32,
+        }
+    }
+
+    #[inline(always)]
+    fn luma(&self, r: i32, g: i32, b: i32) -> u8 {
+        let y = (self.yr.apply(r) + self.yg.apply(g) + self.yb.apply(b) + FASTEST_ROUNDING)
+            >> FASTEST_PRECISION;
+        (y + self.bias_y).clamp(self.bias_y, self.max_y) as u8
+    }
+
+    #[inline(always)]
+    fn chroma(&self, r: i32, g: i32, b: i32) -> (u8, u8) {
+        let cb = (self.cb_r.apply(r) + self.cb_g.apply(g) + self.cb_b.apply(b) + FASTEST_ROUNDING)
+            >> FASTEST_PRECISION;
+        let cr = (self.cr_r.apply(r) + self.cr_g.apply(g) + self.cr_b.apply(b) + FASTEST_ROUNDING)
+            >> FASTEST_PRECISION;
+        (
+            (cb + self.bias_uv).clamp(self.min_uv, self.max_uv) as u8,
+            (cr + self.bias_uv).clamp(self.min_uv, self.max_uv) as u8,
+        )
+    }
+}
+
+#[inline]
+fn chroma_shifts(sampling: YuvChromaSample) -> (usize, usize) {
+    match sampling {
+        YuvChromaSample::YUV420 => (1, 1),
+        YuvChromaSample::YUV422 => (1, 0),
+        YuvChromaSample::YUV444 => (0, 0),
+    }
+}
+
+/// Converts rows `rows` of a validated image, `rgba` holds the whole destination
+pub(crate) fn yuv_to_rgbx_fastest_rows<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    rows: Range<usize>,
+) {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+    let width = image.size.width as usize;
+    let transform = FastestInverse::new(range, matrix, image.size.width, image.size.height);
+    let (shift_x, shift_y) = chroma_shifts(sampling);
+    let y_stride = image.y_stride.get() as usize;
+    let u_stride = image.u_stride.get() as usize;
+    let v_stride = image.v_stride.get() as usize;
+    let rgba_stride = rgba_stride as usize;
+
+    let rgba = &mut rgba[rows.start * rgba_stride..];
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba.par_chunks_mut(rgba_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba.chunks_mut(rgba_stride);
+    }
+    iter.take(rows.len()).enumerate().for_each(|(i, rgba_row)| {
+        let y = rows.start + i;
+        let y_row = &image.y_plane[y * y_stride..];
+        let u_row = &image.u_plane[(y >> shift_y) * u_stride..];
+        let v_row = &image.v_plane[(y >> shift_y) * v_stride..];
+        for (x, (dst, &y_src)) in rgba_row
+            .chunks_exact_mut(channels)
+            .zip(y_row.iter())
+            .take(width)
+            .enumerate()
+        {
+            let y_value = transform.y.apply(y_src as i32 - transform.bias_y);
+            let cb = u_row[x >> shift_x] as i32 - transform.bias_uv;
+            let cr = v_row[x >> shift_x] as i32 - transform.bias_uv;
+            let r = (y_value + transform.cr.apply(cr) + FASTEST_ROUNDING) >> FASTEST_PRECISION;
+            let g = (y_value - transform.g_cr.apply(cr) - transform.g_cb.apply(cb)
+                + FASTEST_ROUNDING)
+                >> FASTEST_PRECISION;
+            let b = (y_value + transform.cb.apply(cb) + FASTEST_ROUNDING) >> FASTEST_PRECISION;
+            dst[dst_chans.get_r_channel_offset()] = r.clamp(0, 255) as u8;
+            dst[dst_chans.get_g_channel_offset()] = g.clamp(0, 255) as u8;
+            dst[dst_chans.get_b_channel_offset()] = b.clamp(0, 255) as u8;
+            if dst_chans.has_alpha() {
+                dst[dst_chans.get_a_channel_offset()] = 255;
+            }
+        }
+    });
+}
+
+pub(crate) fn yuv_to_rgbx_fastest<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    image.check_constraints(sampling)?;
+    check_rgba_destination(
+        rgba,
+        rgba_stride,
+        image.size.width,
+        image.size.height,
+        dst_chans.get_channels_count(),
+    )?;
+    yuv_to_rgbx_fastest_rows::<DESTINATION_CHANNELS>(
+        image,
+        sampling,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+        0..image.size.height as usize,
+    );
+    Ok(())
+}
+
+pub(crate) fn rgbx_to_yuv_fastest<const ORIGIN_CHANNELS: u8>(
+    rgba: &[u8],
+    rgba_stride: u32,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let channels = src_chans.get_channels_count();
+    planar_image.check_constraints(sampling)?;
+    let width = planar_image.size.width as usize;
+    let height = planar_image.size.height as usize;
+    check_rgba_destination(rgba, rgba_stride, width as u32, height as u32, channels)?;
+
+    let transform = FastestForward::new(range, matrix, width as u32, height as u32);
+    let (shift_x, shift_y) = chroma_shifts(sampling);
+    let rgba_stride = rgba_stride as usize;
+    let y_stride = planar_image.y_stride.get() as usize;
+    let u_stride = planar_image.u_stride.get() as usize;
+    let v_stride = planar_image.v_stride.get() as usize;
+    let y_plane = planar_image.y_plane.borrow_mut();
+    let u_plane = planar_image.u_plane.borrow_mut();
+    let v_plane = planar_image.v_plane.borrow_mut();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = y_plane
+            .par_chunks_mut(y_stride << shift_y)
+            .zip(u_plane.par_chunks_mut(u_stride))
+            .zip(v_plane.par_chunks_mut(v_stride));
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = y_plane
+            .chunks_mut(y_stride << shift_y)
+            .zip(u_plane.chunks_mut(u_stride))
+            .zip(v_plane.chunks_mut(v_stride));
+    }
+    iter.take(height.div_ceil(1 << shift_y))
+        .enumerate()
+        .for_each(|(cy, ((y_rows, u_row), v_row))| {
+            let first_row = cy << shift_y;
+            let block_rows = (1 << shift_y).min(height - first_row);
+            for (cx, (u_dst, v_dst)) in u_row
+                .iter_mut()
+                .zip(v_row.iter_mut())
+                .take(width.div_ceil(1 << shift_x))
+                .enumerate()
+            {
+                let first_column = cx << shift_x;
+                let block_columns = (1 << shift_x).min(width - first_column);
+                let (mut r_sum, mut g_sum, mut b_sum) = (0i32, 0i32, 0i32);
+                for row in 0..block_rows {
+                    let src_row = &rgba[(first_row + row) * rgba_stride..];
+                    let y_row = &mut y_rows[row * y_stride..];
+                    for x in first_column..first_column + block_columns {
+                        let px = &src_row[x * channels..];
+                        let r = px[src_chans.get_r_channel_offset()] as i32;
+                        let g = px[src_chans.get_g_channel_offset()] as i32;
+                        let b = px[src_chans.get_b_channel_offset()] as i32;
+                        y_row[x] = transform.luma(r, g, b);
+                        r_sum += r;
+                        g_sum += g;
+                        b_sum += b;
+                    }
+                }
+                // Blocks hold 1, 2 or 4 pixels, so the average is a rounded shift
+                let count_shift = (block_rows >> 1) + (block_columns >> 1);
+                let rounding = (1 << count_shift) >> 1;
+                let (cb, cr) = transform.chroma(
+                    (r_sum + rounding) >> count_shift,
+                    (g_sum + rounding) >> count_shift,
+                    (b_sum + rounding) >> count_shift,
+                );
+                *u_dst = cb;
+                *v_dst = cr;
+            }
+        });
+
+    Ok(())
+}
+
+/// Convert planar YUV image to RGB in [YuvConversionMode::Fastest] mode.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_to_rgb_fastest(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgb: &mut [u8],
+    rgb_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_fastest::<{ YuvSourceChannels::Rgb as u8 }>(
+        image, sampling, rgb, rgb_stride, range, matrix,
+    )
+}
+
+/// Convert planar YUV image to RGBA in [YuvConversionMode::Fastest] mode, alpha is opaque.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_to_rgba_fastest(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_fastest::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        sampling,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+    )
+}
+
+/// Convert planar YUV image to BGRA in [YuvConversionMode::Fastest] mode, alpha is opaque.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_to_bgra_fastest(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_fastest::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        sampling,
+        bgra,
+        bgra_stride,
+        range,
+        matrix,
+    )
+}
+
+/// Convert RGB to planar YUV image in [YuvConversionMode::Fastest] mode.
+///
+/// Chroma of every subsampled block is computed once from its averaged RGB.
+///
+/// # Arguments
+///
+/// * `rgb` - The input RGB image data slice.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `planar_image` - Target planar image.
+/// * `sampling` - Chroma subsampling of the target image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgb_to_yuv_fastest(
+    rgb: &[u8],
+    rgb_stride: u32,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv_fastest::<{ YuvSourceChannels::Rgb as u8 }>(
+        rgb,
+        rgb_stride,
+        planar_image,
+        sampling,
+        range,
+        matrix,
+    )
+}
+
+/// Convert RGBA to planar YUV image in [YuvConversionMode::Fastest] mode, alpha is ignored.
+///
+/// Chroma of every subsampled block is computed once from its averaged RGB.
+///
+/// # Arguments
+///
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `planar_image` - Target planar image.
+/// * `sampling` - Chroma subsampling of the target image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgba_to_yuv_fastest(
+    rgba: &[u8],
+    rgba_stride: u32,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv_fastest::<{ YuvSourceChannels::Rgba as u8 }>(
+        rgba,
+        rgba_stride,
+        planar_image,
+        sampling,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA to planar YUV image in [YuvConversionMode::Fastest] mode, alpha is ignored.
+///
+/// Chroma of every subsampled block is computed once from its averaged RGB.
+///
+/// # Arguments
+///
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `planar_image` - Target planar image.
+/// * `sampling` - Chroma subsampling of the target image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgra_to_yuv_fastest(
+    bgra: &[u8],
+    bgra_stride: u32,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv_fastest::<{ YuvSourceChannels::Bgra as u8 }>(
+        bgra,
+        bgra_stride,
+        planar_image,
+        sampling,
+        range,
+        matrix,
+    )
+}
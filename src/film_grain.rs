@@ -0,0 +1,288 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_vec, YuvPlanarImage};
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_rotate::chroma_rows;
+use crate::yuv_support::{YuvChromaSample, YuvRange, YuvSourceChannels, YuvStandardMatrix};
+use crate::yuv_to_rgba::yuv_to_rgbx_rows;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+/// Plane of the source image grain hook is invoked for
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum YuvGrainPlane {
+    Y,
+    U,
+    V,
+}
+
+/// Rows copied, grained and converted at once, small enough to stay in cache
+const GRAIN_BAND_ROWS: usize = 16;
+
+/// Band sized copies of the source planes grain is applied onto
+struct GrainBands {
+    y: Vec<u8>,
+    u: Vec<u8>,
+    v: Vec<u8>,
+}
+
+fn yuv_to_rgbx_with_grain<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    grain: impl Fn(YuvGrainPlane, usize, &mut [u8]) + Send + Sync,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+    let width = image.size.width;
+    let height = image.size.height;
+
+    image.check_constraints(sampling)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    let matrix = matrix.resolve(width, height);
+
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => {
+            yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+        }
+        YuvChromaSample::YUV422 => {
+            yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+        }
+        YuvChromaSample::YUV444 => {
+            yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+        }
+    };
+
+    let luma_width = width as usize;
+    let chroma_width = match sampling {
+        YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => luma_width.div_ceil(2),
+        YuvChromaSample::YUV444 => luma_width,
+    };
+    let y_stride = image.y_stride.get() as usize;
+    let u_stride = image.u_stride.get() as usize;
+    let v_stride = image.v_stride.get() as usize;
+    let band_stride = rgba_stride as usize * GRAIN_BAND_ROWS;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba.par_chunks_mut(band_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba.chunks_mut(band_stride);
+    }
+
+    let band_rows = GRAIN_BAND_ROWS.min(height as usize);
+    let chroma_band_rows = chroma_rows(sampling, 0, band_rows).1;
+
+    let convert_band = |bands: &mut Option<GrainBands>,
+                        (band, rgba_band): (usize, &mut [u8])|
+     -> Result<(), YuvError> {
+        // Source planes are borrowed immutably, so grain goes onto band sized copies
+        let bands = match bands {
+            Some(bands) => bands,
+            slot @ None => slot.insert(GrainBands {
+                y: try_vec(0u8, luma_width * band_rows)?,
+                u: try_vec(0u8, chroma_width * chroma_band_rows)?,
+                v: try_vec(0u8, chroma_width * chroma_band_rows)?,
+            }),
+        };
+        let start_row = band * GRAIN_BAND_ROWS;
+        let rows = GRAIN_BAND_ROWS.min(height as usize - start_row);
+        let (chroma_start, chroma_rows) = chroma_rows(sampling, start_row, rows);
+        let y_band = &mut bands.y[..luma_width * rows];
+        let u_band = &mut bands.u[..chroma_width * chroma_rows];
+        let v_band = &mut bands.v[..chroma_width * chroma_rows];
+
+        for (row, dst) in y_band.chunks_exact_mut(luma_width).enumerate() {
+            let src_row = start_row + row;
+            let src = &image.y_plane[src_row * y_stride..src_row * y_stride + luma_width];
+            dst.copy_from_slice(src);
+            grain(YuvGrainPlane::Y, src_row, dst);
+        }
+        for (row, (u_dst, v_dst)) in u_band
+            .chunks_exact_mut(chroma_width)
+            .zip(v_band.chunks_exact_mut(chroma_width))
+            .enumerate()
+        {
+            let src_row = chroma_start + row;
+            u_dst.copy_from_slice(
+                &image.u_plane[src_row * u_stride..src_row * u_stride + chroma_width],
+            );
+            v_dst.copy_from_slice(
+                &image.v_plane[src_row * v_stride..src_row * v_stride + chroma_width],
+            );
+            grain(YuvGrainPlane::U, src_row, u_dst);
+            grain(YuvGrainPlane::V, src_row, v_dst);
+        }
+
+        handler(
+            y_band,
+            luma_width as u32,
+            u_band,
+            chroma_width as u32,
+            v_band,
+            chroma_width as u32,
+            rgba_band,
+            rgba_stride,
+            width,
+            rows as u32,
+            range,
+            matrix,
+            255,
+        );
+        Ok(())
+    };
+
+    let bands = iter
+        .take((height as usize).div_ceil(GRAIN_BAND_ROWS))
+        .enumerate();
+    // Band buffers are allocated once per worker and reused for every band it converts
+    #[cfg(feature = "rayon")]
+    {
+        bands.try_for_each_init(|| None, convert_band)
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        let mut buffers = None;
+        for band in bands {
+            convert_band(&mut buffers, band)?;
+        }
+        Ok(())
+    }
+}
+
+/// Convert YUV planar format with 8-bit pixel format to RGB format applying film grain to YUV first.
+///
+/// Source is processed in small bands of rows, each band is copied into a band sized buffer,
+/// handed to `grain` and converted right away, so grain is synthesized without an extra
+/// full frame YUV pass and the source image stays untouched.
+///
+/// `grain` is called with the plane, the absolute row index inside that plane and the visible
+/// samples of the row, chroma rows are numbered in chroma plane coordinates.
+/// Rows of different bands may be grained concurrently with `rayon` feature.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `grain` - Grain synthesis hook adding noise to a row in place.
+///
+pub fn yuv_to_rgb_with_grain(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgb: &mut [u8],
+    rgb_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    grain: impl Fn(YuvGrainPlane, usize, &mut [u8]) + Send + Sync,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_with_grain::<{ YuvSourceChannels::Rgb as u8 }>(
+        image, sampling, rgb, rgb_stride, range, matrix, grain,
+    )
+}
+
+/// Convert YUV planar format with 8-bit pixel format to RGBA format applying film grain to YUV first.
+///
+/// See [yuv_to_rgb_with_grain] for how `grain` is invoked. Alpha is always opaque.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `grain` - Grain synthesis hook adding noise to a row in place.
+///
+pub fn yuv_to_rgba_with_grain(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    grain: impl Fn(YuvGrainPlane, usize, &mut [u8]) + Send + Sync,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_with_grain::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        sampling,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+        grain,
+    )
+}
+
+/// Convert YUV planar format with 8-bit pixel format to BGRA format applying film grain to YUV first.
+///
+/// See [yuv_to_rgb_with_grain] for how `grain` is invoked. Alpha is always opaque.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `grain` - Grain synthesis hook adding noise to a row in place.
+///
+pub fn yuv_to_bgra_with_grain(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    grain: impl Fn(YuvGrainPlane, usize, &mut [u8]) + Send + Sync,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_with_grain::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        sampling,
+        bgra,
+        bgra_stride,
+        range,
+        matrix,
+        grain,
+    )
+}
@@ -0,0 +1,526 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+//! Conversions named after industry FourCC codes.
+//!
+//! The crate names its functions after the layout ( `yuv420_to_rgba`, `yuv_nv12_p10_to_rgba`,
+//! ... ), this module provides the same conversions under their common FourCC names
+//! ( `i420_to_rgba`, `p010_to_rgba`, ... ) and a [FourCC] lookup describing each format.
+//!
+//! High bit depth aliases fix the storage: `I010`, `I210` and `I410` keep samples in the
+//! least significant bits, `P010`, `P210` and `P410` in the most significant bits, both
+//! little endian.
+use crate::images::{ImageSize, RowStride};
+use crate::yuv_support::{
+    YuvBytesPacking, YuvChromaSample, YuvEndianness, YuvRange, YuvStandardMatrix,
+};
+use crate::YuvError;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Formats known by their FourCC code
+pub enum FourCC {
+    /// 8-bit planar 4:2:0, also known as `IYUV`
+    I420,
+    /// 8-bit planar 4:2:2
+    I422,
+    /// 8-bit planar 4:4:4
+    I444,
+    /// 10-bit planar 4:2:0, samples in the least significant bits
+    I010,
+    /// 10-bit planar 4:2:2, samples in the least significant bits
+    I210,
+    /// 10-bit planar 4:4:4, samples in the least significant bits
+    I410,
+    /// 8-bit bi-planar 4:2:0, Cb before Cr
+    Nv12,
+    /// 8-bit bi-planar 4:2:0, Cr before Cb
+    Nv21,
+    /// 8-bit bi-planar 4:2:2, Cb before Cr
+    Nv16,
+    /// 8-bit bi-planar 4:2:2, Cr before Cb
+    Nv61,
+    /// 8-bit bi-planar 4:4:4, Cb before Cr
+    Nv24,
+    /// 8-bit bi-planar 4:4:4, Cr before Cb
+    Nv42,
+    /// 10-bit bi-planar 4:2:0, samples in the most significant bits
+    P010,
+    /// 10-bit bi-planar 4:2:2, samples in the most significant bits
+    P210,
+    /// 10-bit bi-planar 4:4:4, samples in the most significant bits
+    P410,
+    /// 16-bit bi-planar 4:2:0
+    P016,
+    /// 8-bit packed 4:2:2 in `Y0 Cb Y1 Cr` order, also known as `YUYV`
+    Yuy2,
+    /// 8-bit packed 4:2:2 in `Y0 Cr Y1 Cb` order
+    Yvyu,
+    /// 8-bit packed 4:2:2 in `Cb Y0 Cr Y1` order
+    Uyvy,
+    /// 8-bit packed 4:2:2 in `Cr Y0 Cb Y1` order
+    Vyuy,
+}
+
+impl FourCC {
+    /// Finds format by its FourCC code, common aliases such as `IYUV` or `YUYV` are accepted.
+    pub const fn from_code(code: [u8; 4]) -> Option<FourCC> {
+        match &code {
+            b"I420" | b"IYUV" => Some(FourCC::I420),
+            b"I422" => Some(FourCC::I422),
+            b"I444" => Some(FourCC::I444),
+            b"I010" => Some(FourCC::I010),
+            b"I210" => Some(FourCC::I210),
+            b"I410" => Some(FourCC::I410),
+            b"NV12" => Some(FourCC::Nv12),
+            b"NV21" => Some(FourCC::Nv21),
+            b"NV16" => Some(FourCC::Nv16),
+            b"NV61" => Some(FourCC::Nv61),
+            b"NV24" => Some(FourCC::Nv24),
+            b"NV42" => Some(FourCC::Nv42),
+            b"P010" => Some(FourCC::P010),
+            b"P210" => Some(FourCC::P210),
+            b"P410" => Some(FourCC::P410),
+            b"P016" => Some(FourCC::P016),
+            b"YUY2" | b"YUYV" => Some(FourCC::Yuy2),
+            b"YVYU" => Some(FourCC::Yvyu),
+            b"UYVY" => Some(FourCC::Uyvy),
+            b"VYUY" => Some(FourCC::Vyuy),
+            _ => None,
+        }
+    }
+
+    /// Canonical FourCC code of the format
+    pub const fn code(self) -> [u8; 4] {
+        match self {
+            FourCC::I420 => *b"I420",
+            FourCC::I422 => *b"I422",
+            FourCC::I444 => *b"I444",
+            FourCC::I010 => *b"I010",
+            FourCC::I210 => *b"I210",
+            FourCC::I410 => *b"I410",
+            FourCC::Nv12 => *b"NV12",
+            FourCC::Nv21 => *b"NV21",
+            FourCC::Nv16 => *b"NV16",
+            FourCC::Nv61 => *b"NV61",
+            FourCC::Nv24 => *b"NV24",
+            FourCC::Nv42 => *b"NV42",
+            FourCC::P010 => *b"P010",
+            FourCC::P210 => *b"P210",
+            FourCC::P410 => *b"P410",
+            FourCC::P016 => *b"P016",
+            FourCC::Yuy2 => *b"YUY2",
+            FourCC::Yvyu => *b"YVYU",
+            FourCC::Uyvy => *b"UYVY",
+            FourCC::Vyuy => *b"VYUY",
+        }
+    }
+
+    /// Chroma subsampling of the format
+    pub const fn chroma_sample(self) -> YuvChromaSample {
+        match self {
+            FourCC::I420
+            | FourCC::I010
+            | FourCC::Nv12
+            | FourCC::Nv21
+            | FourCC::P010
+            | FourCC::P016 => YuvChromaSample::YUV420,
+            FourCC::I444 | FourCC::I410 | FourCC::Nv24 | FourCC::Nv42 | FourCC::P410 => {
+                YuvChromaSample::YUV444
+            }
+            _ => YuvChromaSample::YUV422,
+        }
+    }
+
+    /// Significant bits of each sample
+    pub const fn bit_depth(self) -> u32 {
+        match self {
+            FourCC::I010
+            | FourCC::I210
+            | FourCC::I410
+            | FourCC::P010
+            | FourCC::P210
+            | FourCC::P410 => 10,
+            FourCC::P016 => 16,
+            _ => 8,
+        }
+    }
+}
+
+pub use crate::{
+    bgr_to_yuv420 as bgr_to_i420, bgr_to_yuv422 as bgr_to_i422, bgr_to_yuv444 as bgr_to_i444,
+    bgr_to_yuv_nv12 as bgr_to_nv12, bgr_to_yuv_nv21 as bgr_to_nv21, bgra_to_yuv420 as bgra_to_i420,
+    bgra_to_yuv422 as bgra_to_i422, bgra_to_yuv444 as bgra_to_i444,
+    bgra_to_yuv_nv12 as bgra_to_nv12, bgra_to_yuv_nv21 as bgra_to_nv21,
+    rgb_to_yuv420 as rgb_to_i420, rgb_to_yuv422 as rgb_to_i422, rgb_to_yuv444 as rgb_to_i444,
+    rgb_to_yuv_nv12 as rgb_to_nv12, rgb_to_yuv_nv21 as rgb_to_nv21, rgba_to_yuv420 as rgba_to_i420,
+    rgba_to_yuv422 as rgba_to_i422, rgba_to_yuv444 as rgba_to_i444,
+    rgba_to_yuv_nv12 as rgba_to_nv12, rgba_to_yuv_nv21 as rgba_to_nv21,
+    uyvy422_to_bgr as uyvy_to_bgr, uyvy422_to_bgra as uyvy_to_bgra, uyvy422_to_rgb as uyvy_to_rgb,
+    uyvy422_to_rgba as uyvy_to_rgba, vyuy422_to_bgr as vyuy_to_bgr,
+    vyuy422_to_bgra as vyuy_to_bgra, vyuy422_to_rgb as vyuy_to_rgb,
+    vyuy422_to_rgba as vyuy_to_rgba, yuv420_to_bgr as i420_to_bgr, yuv420_to_bgra as i420_to_bgra,
+    yuv420_to_rgb as i420_to_rgb, yuv420_to_rgba as i420_to_rgba, yuv422_to_bgr as i422_to_bgr,
+    yuv422_to_bgra as i422_to_bgra, yuv422_to_rgb as i422_to_rgb, yuv422_to_rgba as i422_to_rgba,
+    yuv444_to_bgr as i444_to_bgr, yuv444_to_bgra as i444_to_bgra, yuv444_to_rgb as i444_to_rgb,
+    yuv444_to_rgba as i444_to_rgba, yuv_nv12_to_bgr as nv12_to_bgr,
+    yuv_nv12_to_bgra as nv12_to_bgra, yuv_nv12_to_rgb as nv12_to_rgb,
+    yuv_nv12_to_rgba as nv12_to_rgba, yuv_nv21_to_bgr as nv21_to_bgr,
+    yuv_nv21_to_bgra as nv21_to_bgra, yuv_nv21_to_rgb as nv21_to_rgb,
+    yuv_nv21_to_rgba as nv21_to_rgba, yuv_p010_to_rgba16 as p010_to_rgba16,
+    yuv_p016_to_rgba16 as p016_to_rgba16, yuyv422_to_bgr as yuy2_to_bgr,
+    yuyv422_to_bgra as yuy2_to_bgra, yuyv422_to_rgb as yuy2_to_rgb,
+    yuyv422_to_rgba as yuy2_to_rgba, yvyu422_to_bgr as yvyu_to_bgr,
+    yvyu422_to_bgra as yvyu_to_bgra, yvyu422_to_rgb as yvyu_to_rgb,
+    yvyu422_to_rgba as yvyu_to_rgba,
+};
+
+/// Declares a planar high bit depth alias with storage fixed by its FourCC
+macro_rules! planar_p16_alias {
+    ($name:ident, $target:ident, $dst:ty, $fourcc:literal, $packing:ident) => {
+        #[doc = concat!("Convert `", $fourcc, "` to the destination layout, same as [crate::", stringify!($target), "].")]
+        ///
+        /// # Arguments
+        ///
+        /// * `y_plane` - A slice to load the Y (luminance) plane.
+        /// * `y_stride` - The stride (bytes per row) for the Y plane.
+        /// * `u_plane` - A slice to load the U (chrominance) plane.
+        /// * `u_stride` - The stride (bytes per row) for the U plane.
+        /// * `v_plane` - A slice to load the V (chrominance) plane.
+        /// * `v_stride` - The stride (bytes per row) for the V plane.
+        /// * `dst` - A mutable slice to store the converted data.
+        /// * `dst_stride` - The stride (bytes per row) for the destination.
+        /// * `size` - The size of the image.
+        /// * `range` - The YUV range (limited or full).
+        /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+        ///
+        pub fn $name(
+            y_plane: &[u16],
+            y_stride: RowStride,
+            u_plane: &[u16],
+            u_stride: RowStride,
+            v_plane: &[u16],
+            v_stride: RowStride,
+            dst: &mut [$dst],
+            dst_stride: RowStride,
+            size: ImageSize,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            crate::$target(
+                y_plane,
+                y_stride.get(),
+                u_plane,
+                u_stride.get(),
+                v_plane,
+                v_stride.get(),
+                dst,
+                dst_stride.get(),
+                size.width,
+                size.height,
+                range,
+                matrix,
+                YuvEndianness::LittleEndian,
+                YuvBytesPacking::$packing,
+            )
+        }
+    };
+}
+
+/// Declares a planar 10 bit to `AR30` alias with storage fixed by its FourCC, target takes
+/// typed strides and size
+macro_rules! planar_ar30_alias {
+    ($name:ident, $target:ident, $fourcc:literal, $packing:ident) => {
+        #[doc = concat!("Convert `", $fourcc, "` to the destination layout, same as [crate::", stringify!($target), "].")]
+        ///
+        /// # Arguments
+        ///
+        /// * `y_plane` - A slice to load the Y (luminance) plane.
+        /// * `y_stride` - The stride (bytes per row) for the Y plane.
+        /// * `u_plane` - A slice to load the U (chrominance) plane.
+        /// * `u_stride` - The stride (bytes per row) for the U plane.
+        /// * `v_plane` - A slice to load the V (chrominance) plane.
+        /// * `v_stride` - The stride (bytes per row) for the V plane.
+        /// * `dst` - A mutable slice to store the converted data.
+        /// * `dst_stride` - The stride (bytes per row) for the destination.
+        /// * `size` - The size of the image.
+        /// * `range` - The YUV range (limited or full).
+        /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+        ///
+        pub fn $name(
+            y_plane: &[u16],
+            y_stride: RowStride,
+            u_plane: &[u16],
+            u_stride: RowStride,
+            v_plane: &[u16],
+            v_stride: RowStride,
+            dst: &mut [u32],
+            dst_stride: RowStride,
+            size: ImageSize,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            crate::$target(
+                y_plane,
+                y_stride,
+                u_plane,
+                u_stride,
+                v_plane,
+                v_stride,
+                dst,
+                dst_stride,
+                size,
+                range,
+                matrix,
+                YuvEndianness::LittleEndian,
+                YuvBytesPacking::$packing,
+            )
+        }
+    };
+}
+
+/// Declares a bi-planar high bit depth alias with storage fixed by its FourCC
+macro_rules! biplanar_p16_alias {
+    ($name:ident, $target:ident, $fourcc:literal, $packing:ident) => {
+        #[doc = concat!("Convert `", $fourcc, "` to the destination layout, same as [crate::", stringify!($target), "].")]
+        ///
+        /// # Arguments
+        ///
+        /// * `y_plane` - A slice to load the Y (luminance) plane.
+        /// * `y_stride` - The stride (bytes per row) for the Y plane.
+        /// * `uv_plane` - A slice to load the interleaved UV (chrominance) plane.
+        /// * `uv_stride` - The stride (bytes per row) for the UV plane.
+        /// * `dst` - A mutable slice to store the converted data.
+        /// * `dst_stride` - The stride (bytes per row) for the destination.
+        /// * `size` - The size of the image.
+        /// * `range` - The YUV range (limited or full).
+        /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+        ///
+        pub fn $name(
+            y_plane: &[u16],
+            y_stride: RowStride,
+            uv_plane: &[u16],
+            uv_stride: RowStride,
+            dst: &mut [u8],
+            dst_stride: RowStride,
+            size: ImageSize,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            crate::$target(
+                y_plane,
+                y_stride.get(),
+                uv_plane,
+                uv_stride.get(),
+                dst,
+                dst_stride.get(),
+                size.width,
+                size.height,
+                range,
+                matrix,
+                YuvEndianness::LittleEndian,
+                YuvBytesPacking::$packing,
+            )
+        }
+    };
+}
+
+planar_p16_alias!(
+    i010_to_rgba,
+    yuv420_p10_to_rgba,
+    u8,
+    "I010",
+    LeastSignificantBytes
+);
+planar_p16_alias!(
+    i010_to_rgb,
+    yuv420_p10_to_rgb,
+    u8,
+    "I010",
+    LeastSignificantBytes
+);
+planar_p16_alias!(
+    i010_to_bgra,
+    yuv420_p10_to_bgra,
+    u8,
+    "I010",
+    LeastSignificantBytes
+);
+planar_p16_alias!(
+    i010_to_bgr,
+    yuv420_p10_to_bgr,
+    u8,
+    "I010",
+    LeastSignificantBytes
+);
+planar_ar30_alias!(
+    i010_to_ar30,
+    yuv420_p10_to_ar30,
+    "I010",
+    LeastSignificantBytes
+);
+planar_p16_alias!(
+    i210_to_rgba,
+    yuv422_p10_to_rgba,
+    u8,
+    "I210",
+    LeastSignificantBytes
+);
+planar_p16_alias!(
+    i210_to_rgb,
+    yuv422_p10_to_rgb,
+    u8,
+    "I210",
+    LeastSignificantBytes
+);
+planar_p16_alias!(
+    i210_to_bgra,
+    yuv422_p10_to_bgra,
+    u8,
+    "I210",
+    LeastSignificantBytes
+);
+planar_p16_alias!(
+    i210_to_bgr,
+    yuv422_p10_to_bgr,
+    u8,
+    "I210",
+    LeastSignificantBytes
+);
+planar_ar30_alias!(
+    i210_to_ar30,
+    yuv422_p10_to_ar30,
+    "I210",
+    LeastSignificantBytes
+);
+planar_p16_alias!(
+    i410_to_rgba,
+    yuv444_p10_to_rgba,
+    u8,
+    "I410",
+    LeastSignificantBytes
+);
+planar_p16_alias!(
+    i410_to_rgb,
+    yuv444_p10_to_rgb,
+    u8,
+    "I410",
+    LeastSignificantBytes
+);
+planar_p16_alias!(
+    i410_to_bgra,
+    yuv444_p10_to_bgra,
+    u8,
+    "I410",
+    LeastSignificantBytes
+);
+planar_p16_alias!(
+    i410_to_bgr,
+    yuv444_p10_to_bgr,
+    u8,
+    "I410",
+    LeastSignificantBytes
+);
+planar_ar30_alias!(
+    i410_to_ar30,
+    yuv444_p10_to_ar30,
+    "I410",
+    LeastSignificantBytes
+);
+
+biplanar_p16_alias!(
+    p010_to_rgba,
+    yuv_nv12_p10_to_rgba,
+    "P010",
+    MostSignificantBytes
+);
+biplanar_p16_alias!(
+    p010_to_rgb,
+    yuv_nv12_p10_to_rgb,
+    "P010",
+    MostSignificantBytes
+);
+biplanar_p16_alias!(
+    p010_to_bgra,
+    yuv_nv12_p10_to_bgra,
+    "P010",
+    MostSignificantBytes
+);
+biplanar_p16_alias!(
+    p010_to_bgr,
+    yuv_nv12_p10_to_bgr,
+    "P010",
+    MostSignificantBytes
+);
+biplanar_p16_alias!(
+    p210_to_rgba,
+    yuv_nv16_p10_to_rgba,
+    "P210",
+    MostSignificantBytes
+);
+biplanar_p16_alias!(
+    p210_to_rgb,
+    yuv_nv16_p10_to_rgb,
+    "P210",
+    MostSignificantBytes
+);
+biplanar_p16_alias!(
+    p210_to_bgra,
+    yuv_nv16_p10_to_bgra,
+    "P210",
+    MostSignificantBytes
+);
+biplanar_p16_alias!(
+    p210_to_bgr,
+    yuv_nv16_p10_to_bgr,
+    "P210",
+    MostSignificantBytes
+);
+biplanar_p16_alias!(
+    p410_to_rgba,
+    yuv_nv24_p10_to_rgba,
+    "P410",
+    MostSignificantBytes
+);
+biplanar_p16_alias!(
+    p410_to_rgb,
+    yuv_nv24_p10_to_rgb,
+    "P410",
+    MostSignificantBytes
+);
+biplanar_p16_alias!(
+    p410_to_bgra,
+    yuv_nv24_p10_to_bgra,
+    "P410",
+    MostSignificantBytes
+);
+biplanar_p16_alias!(
+    p410_to_bgr,
+    yuv_nv24_p10_to_bgr,
+    "P410",
+    MostSignificantBytes
+);
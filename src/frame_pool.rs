@@ -0,0 +1,216 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::conversion_context::YuvConversionContext;
+use crate::images::{try_vec, try_with_capacity, ImageSize, YuvPlanarImage};
+use crate::YuvError;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// Interleaved layout of frames produced by [YuvFramePool] and of bands produced by
+/// [YuvConversionContext::convert_many_rows_into_iter]
+pub enum YuvFramePoolLayout {
+    Rgb,
+    Rgba,
+    Bgra,
+}
+
+impl YuvFramePoolLayout {
+    /// Bytes taken by one pixel
+    pub const fn bytes_per_pixel(self) -> usize {
+        match self {
+            YuvFramePoolLayout::Rgb => 3,
+            YuvFramePoolLayout::Rgba | YuvFramePoolLayout::Bgra => 4,
+        }
+    }
+}
+
+/// Converted frame lent out by [YuvFramePool].
+///
+/// Frame owns its buffer until it is handed back with [YuvFramePool::recycle],
+/// so it can be moved to a render or encoder thread meanwhile.
+#[derive(Debug)]
+pub struct YuvConvertedFrame {
+    data: Vec<u8>,
+    stride: u32,
+    size: ImageSize,
+    layout: YuvFramePoolLayout,
+    sequence: u64,
+}
+
+impl YuvConvertedFrame {
+    /// Pixel data, rows are `stride` bytes apart
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Stride in bytes
+    pub fn stride(&self) -> u32 {
+        self.stride
+    }
+
+    pub fn size(&self) -> ImageSize {
+        self.size
+    }
+
+    pub fn layout(&self) -> YuvFramePoolLayout {
+        self.layout
+    }
+
+    /// Number of frames converted by the pool before this one
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+}
+
+/// Ring of preallocated output buffers paired with a [YuvConversionContext],
+/// so real-time streams convert frames without allocating.
+///
+/// With capacity of 2 the pool is double buffered: one frame is displayed or encoded
+/// while the next one is converted. [YuvFramePool::convert] takes a free buffer,
+/// [YuvFramePool::recycle] gives it back. When every buffer is still lent out
+/// the conversion is skipped, so a slow consumer drops frames instead of growing memory.
+#[derive(Debug)]
+pub struct YuvFramePool {
+    context: YuvConversionContext,
+    size: ImageSize,
+    layout: YuvFramePoolLayout,
+    stride: u32,
+    capacity: usize,
+    free: Vec<Vec<u8>>,
+    sequence: u64,
+}
+
+impl YuvFramePool {
+    /// Preallocates `capacity` tightly packed frames, at least one.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - Conversion parameters applied to every frame.
+    /// * `size` - Dimensions of the source frames.
+    /// * `layout` - Interleaved layout of converted frames.
+    /// * `capacity` - Number of frames in the ring, 2 for double buffering.
+    ///
+    pub fn new(
+        context: YuvConversionContext,
+        size: ImageSize,
+        layout: YuvFramePoolLayout,
+        capacity: usize,
+    ) -> Result<YuvFramePool, YuvError> {
+        if size.width == 0 || size.height == 0 {
+            return Err(YuvError::ZeroBaseSize);
+        }
+        let row_bytes = (size.width as usize)
+            .checked_mul(layout.bytes_per_pixel())
+            .ok_or(YuvError::ImageTooLarge(size))?;
+        let stride = u32::try_from(row_bytes).map_err(|_| YuvError::ImageTooLarge(size))?;
+        let frame_bytes = row_bytes
+            .checked_mul(size.height as usize)
+            .ok_or(YuvError::ImageTooLarge(size))?;
+        let capacity = capacity.max(1);
+        let mut free = try_with_capacity(capacity)?;
+        for _ in 0..capacity {
+            free.push(try_vec(0u8, frame_bytes)?);
+        }
+        Ok(YuvFramePool {
+            context,
+            size,
+            layout,
+            stride,
+            capacity,
+            free,
+            sequence: 0,
+        })
+    }
+
+    /// Number of frames in the ring
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Number of frames ready to be converted into
+    pub fn available(&self) -> usize {
+        self.free.len()
+    }
+
+    /// Bytes taken by one converted frame
+    pub fn frame_bytes(&self) -> usize {
+        self.stride as usize * self.size.height as usize
+    }
+
+    /// Converts planar frame into the next free buffer.
+    ///
+    /// Returns `None` and leaves the pool untouched when every frame is lent out.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Source planar image of the pool size, subsampled as the context says.
+    ///
+    pub fn convert(
+        &mut self,
+        image: &YuvPlanarImage<u8>,
+    ) -> Result<Option<YuvConvertedFrame>, YuvError> {
+        if image.size != self.size {
+            return Err(YuvError::ImageSizeMismatch(image.size, self.size));
+        }
+        let mut data = match self.free.pop() {
+            Some(data) => data,
+            None => return Ok(None),
+        };
+        let converted = match self.layout {
+            YuvFramePoolLayout::Rgb => self.context.yuv_to_rgb(image, &mut data, self.stride),
+            YuvFramePoolLayout::Rgba => self.context.yuv_to_rgba(image, &mut data, self.stride),
+            YuvFramePoolLayout::Bgra => self.context.yuv_to_bgra(image, &mut data, self.stride),
+        };
+        if let Err(err) = converted {
+            self.free.push(data);
+            return Err(err);
+        }
+        let sequence = self.sequence;
+        self.sequence += 1;
+        Ok(Some(YuvConvertedFrame {
+            data,
+            stride: self.stride,
+            size: self.size,
+            layout: self.layout,
+            sequence,
+        }))
+    }
+
+    /// Hands frame buffer back to the ring.
+    ///
+    /// Frames of another pool shape are dropped, as are extra frames once the ring is full.
+    pub fn recycle(&mut self, frame: YuvConvertedFrame) {
+        if frame.size == self.size
+            && frame.layout == self.layout
+            && frame.data.len() == self.frame_bytes()
+            && self.free.len() < self.capacity
+        {
+            self.free.push(frame.data);
+        }
+    }
+}
@@ -0,0 +1,273 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_vec, YuvGrayImage, YuvPlanarImage};
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_support::{YuvChromaSample, YuvRange, YuvSourceChannels, YuvStandardMatrix};
+use crate::yuv_to_rgba::yuv_to_rgbx_rows;
+use crate::{ImageSize, YuvError};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+use xxhash_rust::xxh3::{xxh3_64, xxh3_64_with_seed};
+
+/// Computes xxh3 hash of every row of Y plane, only `width` samples of each row are hashed
+/// so stride padding does not affect result.
+///
+/// Two frames with equal hashes on the same row almost certainly have this row unchanged,
+/// this is cheap enough to be used for screen content change detection.
+pub fn luma_row_hashes(image: &YuvGrayImage<u8>) -> Result<Vec<u64>, YuvError> {
+    image.check_constraints()?;
+    let width = image.size.width as usize;
+    let stride = image.y_stride.get() as usize;
+    let mut hashes = try_vec(0u64, image.size.height as usize)?;
+    for (y, hash) in hashes.iter_mut().enumerate() {
+        let row = &image.y_plane[y * stride..y * stride + width];
+        *hash = xxh3_64(row);
+    }
+    Ok(hashes)
+}
+
+/// Parameters of the previous call, any change invalidates stored hashes
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct FrameReuseKey {
+    size: ImageSize,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    channels: YuvSourceChannels,
+    rgba_stride: u32,
+}
+
+/// Converts successive frames of a stream to RGBA/BGRA and skips rows
+/// that did not change since the previous call.
+///
+/// Rows are tracked in groups sharing one chroma row ( 2 rows for 4:2:0, 1 otherwise ),
+/// each group is identified by xxh3 of its Y, U and V samples.
+/// Caller must keep passing the same destination buffer holding previous output,
+/// otherwise [YuvFrameReuse::invalidate] has to be called before the next frame.
+#[derive(Debug, Default, Clone)]
+pub struct YuvFrameReuse {
+    key: Option<FrameReuseKey>,
+    hashes: Vec<u64>,
+}
+
+impl YuvFrameReuse {
+    pub fn new() -> YuvFrameReuse {
+        YuvFrameReuse::default()
+    }
+
+    /// Forgets previous frame, next call converts whole image
+    pub fn invalidate(&mut self) {
+        self.key = None;
+        self.hashes.clear();
+    }
+
+    /// Converts planar YUV frame to RGBA reusing unchanged rows of `rgba`.
+    ///
+    /// Returns the number of converted rows, `0` means the frame was identical to the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Source planar YUV frame.
+    /// * `sampling` - Chroma subsampling of the frame.
+    /// * `rgba` - A mutable slice holding previous output, updated in place.
+    /// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+    /// * `range` - The YUV range (limited or full).
+    /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+    ///
+    pub fn yuv_to_rgba(
+        &mut self,
+        image: &YuvPlanarImage<u8>,
+        sampling: YuvChromaSample,
+        rgba: &mut [u8],
+        rgba_stride: u32,
+        range: YuvRange,
+        matrix: YuvStandardMatrix,
+    ) -> Result<usize, YuvError> {
+        self.convert::<{ YuvSourceChannels::Rgba as u8 }>(
+            image,
+            sampling,
+            rgba,
+            rgba_stride,
+            range,
+            matrix,
+        )
+    }
+
+    /// Converts planar YUV frame to BGRA reusing unchanged rows of `bgra`.
+    ///
+    /// Returns the number of converted rows, `0` means the frame was identical to the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Source planar YUV frame.
+    /// * `sampling` - Chroma subsampling of the frame.
+    /// * `bgra` - A mutable slice holding previous output, updated in place.
+    /// * `bgra_stride` - The stride (bytes per row) for BGRA data.
+    /// * `range` - The YUV range (limited or full).
+    /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+    ///
+    pub fn yuv_to_bgra(
+        &mut self,
+        image: &YuvPlanarImage<u8>,
+        sampling: YuvChromaSample,
+        bgra: &mut [u8],
+        bgra_stride: u32,
+        range: YuvRange,
+        matrix: YuvStandardMatrix,
+    ) -> Result<usize, YuvError> {
+        self.convert::<{ YuvSourceChannels::Bgra as u8 }>(
+            image,
+            sampling,
+            bgra,
+            bgra_stride,
+            range,
+            matrix,
+        )
+    }
+
+    fn convert<const DESTINATION_CHANNELS: u8>(
+        &mut self,
+        image: &YuvPlanarImage<u8>,
+        sampling: YuvChromaSample,
+        rgba: &mut [u8],
+        rgba_stride: u32,
+        range: YuvRange,
+        matrix: YuvStandardMatrix,
+    ) -> Result<usize, YuvError> {
+        let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+        image.check_constraints(sampling)?;
+        check_rgba_destination(
+            rgba,
+            rgba_stride,
+            image.size.width,
+            image.size.height,
+            dst_chans.get_channels_count(),
+        )?;
+
+        let matrix = matrix.resolve(image.size.width, image.size.height);
+        let width = image.size.width as usize;
+        let height = image.size.height as usize;
+        let (rows_per_group, chroma_width) = match sampling {
+            YuvChromaSample::YUV420 => (2, width.div_ceil(2)),
+            YuvChromaSample::YUV422 => (1, width.div_ceil(2)),
+            YuvChromaSample::YUV444 => (1, width),
+        };
+        let groups = height.div_ceil(rows_per_group);
+
+        let mut hashes = try_vec(0u64, groups)?;
+        let y_stride = image.y_stride.get() as usize;
+        let u_stride = image.u_stride.get() as usize;
+        let v_stride = image.v_stride.get() as usize;
+
+        let iter;
+        #[cfg(feature = "rayon")]
+        {
+            iter = hashes.par_chunks_mut(1);
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            iter = hashes.chunks_mut(1);
+        }
+        iter.enumerate().for_each(|(group, hash)| {
+            let u_row = &image.u_plane[group * u_stride..group * u_stride + chroma_width];
+            let v_row = &image.v_plane[group * v_stride..group * v_stride + chroma_width];
+            let mut h = xxh3_64_with_seed(v_row, xxh3_64(u_row));
+            let start = group * rows_per_group;
+            for y in start..(start + rows_per_group).min(height) {
+                h = xxh3_64_with_seed(&image.y_plane[y * y_stride..y * y_stride + width], h);
+            }
+            hash[0] = h;
+        });
+
+        let key = FrameReuseKey {
+            size: image.size,
+            sampling,
+            range,
+            matrix,
+            channels: dst_chans,
+            rgba_stride,
+        };
+        let previous = if self.key == Some(key) {
+            self.hashes.as_slice()
+        } else {
+            &[]
+        };
+
+        let handler = match sampling {
+            YuvChromaSample::YUV420 => {
+                yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+            }
+            YuvChromaSample::YUV422 => {
+                yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+            }
+            YuvChromaSample::YUV444 => {
+                yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+            }
+        };
+
+        let rgba_stride_bytes = rgba_stride as usize;
+        let mut converted = 0usize;
+        let mut group = 0usize;
+        while group < groups {
+            if previous.get(group) == Some(&hashes[group]) {
+                group += 1;
+                continue;
+            }
+            // Coalesce run of changed groups into a single call
+            let run_start = group;
+            while group < groups && previous.get(group) != Some(&hashes[group]) {
+                group += 1;
+            }
+            let start = run_start * rows_per_group;
+            let rows = (group * rows_per_group).min(height) - start;
+            handler(
+                &image.y_plane[start * y_stride..],
+                y_stride as u32,
+                &image.u_plane[run_start * u_stride..],
+                u_stride as u32,
+                &image.v_plane[run_start * v_stride..],
+                v_stride as u32,
+                &mut rgba[start * rgba_stride_bytes..],
+                rgba_stride,
+                image.size.width,
+                rows as u32,
+                range,
+                matrix,
+                255,
+            );
+            converted += rows;
+        }
+
+        self.key = Some(key);
+        self.hashes = hashes;
+        Ok(converted)
+    }
+}
@@ -26,13 +26,25 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::gbr_to_image_avx;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::gbr_to_image_neon;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::gbr_to_image_sse;
+use crate::yuv_error::check_rgba_destination;
 use crate::yuv_support::YuvSourceChannels;
+use crate::YuvError;
 
 fn gbr_to_image_impl<const DESTINATION_CHANNELS: u8>(
     source_gbr: &[u8],
@@ -40,23 +52,34 @@ fn gbr_to_image_impl<const DESTINATION_CHANNELS: u8>(
     rgba: &mut [u8],
     rgba_stride: u32,
     width: u32,
-    _: u32,
-) {
+    height: u32,
+) -> Result<(), YuvError> {
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
     let channels = destination_channels.get_channels_count();
+    check_rgba_destination(source_gbr, gbr_stride, width, height, 3)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let _use_avx = std::arch::is_x86_feature_detected!("avx2");
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_avx = crate::cpu_features::has_avx2();
 
     for (dst_row, src_row) in rgba
-        .chunks_exact_mut(rgba_stride as usize)
-        .zip(source_gbr.chunks_exact(gbr_stride as usize))
+        .chunks_mut(rgba_stride as usize)
+        .zip(source_gbr.chunks(gbr_stride as usize))
     {
         let mut _cx = 0usize;
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
             if _use_avx {
                 _cx = gbr_to_image_avx::<DESTINATION_CHANNELS>(src_row, 0, dst_row, 0, width, _cx);
@@ -66,7 +89,11 @@ fn gbr_to_image_impl<const DESTINATION_CHANNELS: u8>(
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             _cx = gbr_to_image_neon::<DESTINATION_CHANNELS>(src_row, 0, dst_row, 0, width, _cx);
         }
@@ -74,6 +101,7 @@ fn gbr_to_image_impl<const DESTINATION_CHANNELS: u8>(
         for (dst, src) in dst_row
             .chunks_exact_mut(channels)
             .zip(src_row.chunks_exact(3))
+            .take(width as usize)
             .skip(_cx)
         {
             let g = src[0];
@@ -87,6 +115,8 @@ fn gbr_to_image_impl<const DESTINATION_CHANNELS: u8>(
             }
         }
     }
+
+    Ok(())
 }
 
 /// Convert YUV Identity Matrix ( aka 'GBR ) to RGB
@@ -115,7 +145,7 @@ pub fn gbr_to_rgb(
     rgb_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     gbr_to_image_impl::<{ YuvSourceChannels::Rgb as u8 }>(
         gbr, gbr_stride, rgb, rgb_stride, width, height,
     )
@@ -147,7 +177,7 @@ pub fn gbr_to_bgr(
     bgr_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     gbr_to_image_impl::<{ YuvSourceChannels::Bgr as u8 }>(
         gbr, gbr_stride, bgr, bgr_stride, width, height,
     )
@@ -179,7 +209,7 @@ pub fn gbr_to_rgba(
     rgb_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     gbr_to_image_impl::<{ YuvSourceChannels::Rgba as u8 }>(
         gbr, gbr_stride, rgb, rgb_stride, width, height,
     )
@@ -211,7 +241,7 @@ pub fn gbr_to_bgra(
     rgb_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     gbr_to_image_impl::<{ YuvSourceChannels::Rgba as u8 }>(
         gbr, gbr_stride, rgb, rgb_stride, width, height,
     )
@@ -26,11 +26,20 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::gbr_to_image_neon_p16;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::gbr_to_image_sse_p16;
+use crate::yuv_error::check_rgba_destination;
 use crate::yuv_support::YuvSourceChannels;
+use crate::YuvError;
 
 fn gbr_to_image_impl_p16<const DESTINATION_CHANNELS: u8>(
     gbr: &[u16],
@@ -40,27 +49,38 @@ fn gbr_to_image_impl_p16<const DESTINATION_CHANNELS: u8>(
     bit_depth: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
     let channels = destination_channels.get_channels_count();
+    check_rgba_destination(gbr, gbr_stride, width, height, 3)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
 
     let mut gbr_offset = 0usize;
     let mut rgba_offset = 0usize;
 
     let max_colors = (1 << bit_depth) as u16 - 1;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
 
     for _ in 0..height as usize {
         let mut _cx = 0usize;
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        let gbr_src = &gbr[gbr_offset / 2..];
+        let dst = &mut rgba[rgba_offset / 2..];
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
             if _use_sse {
                 _cx = gbr_to_image_sse_p16::<DESTINATION_CHANNELS>(
-                    gbr.as_ptr(),
-                    rgba.as_mut_ptr(),
+                    gbr_src.as_ptr(),
+                    dst.as_mut_ptr(),
                     bit_depth,
                     width,
                     _cx,
@@ -68,53 +88,45 @@ fn gbr_to_image_impl_p16<const DESTINATION_CHANNELS: u8>(
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             _cx = gbr_to_image_neon_p16::<DESTINATION_CHANNELS>(
-                gbr.as_ptr(),
-                rgba.as_mut_ptr(),
+                gbr_src.as_ptr(),
+                dst.as_mut_ptr(),
                 bit_depth,
                 width,
                 _cx,
             );
         }
 
-        let gbr_src_ptr = unsafe { (gbr.as_ptr() as *const u8).add(gbr_offset) as *const u16 };
-        let dst_ptr = unsafe { (rgba.as_ptr() as *mut u8).add(rgba_offset) as *mut u16 };
-
         for x in _cx..width as usize {
-            unsafe {
-                let gbr_local = gbr_src_ptr.add(x * 3);
-
-                let g = gbr_local.add(0).read_unaligned();
-                let b = gbr_local.add(1).read_unaligned();
-                let r = gbr_local.add(2).read_unaligned();
-
-                let px = x * channels;
-
-                let rgba_shift = px;
-                let dst_local = dst_ptr.add(rgba_shift);
-
-                dst_local
-                    .add(destination_channels.get_r_channel_offset())
-                    .write_unaligned(r);
-                dst_local
-                    .add(destination_channels.get_g_channel_offset())
-                    .write_unaligned(g);
-                dst_local
-                    .add(destination_channels.get_b_channel_offset())
-                    .write_unaligned(b);
-                if destination_channels.has_alpha() {
-                    dst_local
-                        .add(destination_channels.get_a_channel_offset())
-                        .write_unaligned(max_colors);
-                }
+            let gbr_local = &gbr_src[x * 3..];
+
+            let g = gbr_local[0];
+            let b = gbr_local[1];
+            let r = gbr_local[2];
+
+            let px = x * channels;
+
+            let dst_local = &mut dst[px..];
+
+            dst_local[destination_channels.get_r_channel_offset()] = r;
+            dst_local[destination_channels.get_g_channel_offset()] = g;
+            dst_local[destination_channels.get_b_channel_offset()] = b;
+            if destination_channels.has_alpha() {
+                dst_local[destination_channels.get_a_channel_offset()] = max_colors;
             }
         }
 
         gbr_offset += gbr_stride as usize;
         rgba_offset += rgba_stride as usize;
     }
+
+    Ok(())
 }
 
 /// Convert YUV Identity Matrix ( aka 'GBR ) to RGB
@@ -145,7 +157,7 @@ pub fn gbr_to_rgb_p16(
     bit_depth: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     gbr_to_image_impl_p16::<{ YuvSourceChannels::Rgb as u8 }>(
         gbr, gbr_stride, rgb, rgb_stride, bit_depth, width, height,
     )
@@ -179,7 +191,7 @@ pub fn gbr_to_bgr_p16(
     bit_depth: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     gbr_to_image_impl_p16::<{ YuvSourceChannels::Bgr as u8 }>(
         gbr, gbr_stride, bgr, bgr_stride, bit_depth, width, height,
     )
@@ -213,7 +225,7 @@ pub fn gbr_to_rgba_p16(
     bit_depth: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     gbr_to_image_impl_p16::<{ YuvSourceChannels::Rgba as u8 }>(
         gbr, gbr_stride, rgb, rgb_stride, bit_depth, width, height,
     )
@@ -247,7 +259,7 @@ pub fn gbr_to_bgra_p16(
     bit_depth: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     gbr_to_image_impl_p16::<{ YuvSourceChannels::Rgba as u8 }>(
         gbr, gbr_stride, rgb, rgb_stride, bit_depth, width, height,
     )
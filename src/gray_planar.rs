@@ -0,0 +1,226 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{YuvGrayImage, YuvGrayImageMut, YuvPlanarImage, YuvPlanarImageMut};
+use crate::yuv_error::check_p16_bit_depth;
+use crate::yuv_support::{YuvBytesPacking, YuvChromaSample, YuvEndianness};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+use std::fmt::Debug;
+
+/// Copies `height` rows of `width` samples between planes, strides are in elements
+fn copy_plane<T: Copy + Debug + Send + Sync>(
+    src: &[T],
+    src_stride: usize,
+    dst: &mut [T],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+) {
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst.par_chunks_mut(dst_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst.chunks_mut(dst_stride);
+    }
+    iter.take(height).enumerate().for_each(|(y, dst_row)| {
+        let src_row = &src[y * src_stride..];
+        dst_row[..width].copy_from_slice(&src_row[..width]);
+    });
+}
+
+/// Fills `height` rows of `width` samples with `value`, stride is in elements
+fn fill_plane<T: Copy + Debug + Send + Sync>(
+    dst: &mut [T],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+    value: T,
+) {
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst.par_chunks_mut(dst_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst.chunks_mut(dst_stride);
+    }
+    iter.take(height).for_each(|dst_row| {
+        dst_row[..width].fill(value);
+    });
+}
+
+fn gray_to_planar_impl<T: Copy + Debug + Send + Sync>(
+    gray: &YuvGrayImage<T>,
+    planar: &mut YuvPlanarImageMut<T>,
+    sampling: YuvChromaSample,
+    neutral: T,
+) -> Result<(), YuvError> {
+    if gray.size.width == 0 || gray.size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    gray.check_constraints()?;
+    if planar.size != gray.size {
+        return Err(YuvError::ImageSizeMismatch(gray.size, planar.size));
+    }
+    planar.check_constraints(sampling)?;
+
+    let element_size = std::mem::size_of::<T>();
+    let width = gray.size.width as usize;
+    let height = gray.size.height as usize;
+    let chroma_width = match sampling {
+        YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => width.div_ceil(2),
+        YuvChromaSample::YUV444 => width,
+    };
+    let chroma_height = match sampling {
+        YuvChromaSample::YUV420 => height.div_ceil(2),
+        YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => height,
+    };
+
+    copy_plane(
+        gray.y_plane,
+        gray.y_stride.get() as usize / element_size,
+        planar.y_plane.borrow_mut(),
+        planar.y_stride.get() as usize / element_size,
+        width,
+        height,
+    );
+    fill_plane(
+        planar.u_plane.borrow_mut(),
+        planar.u_stride.get() as usize / element_size,
+        chroma_width,
+        chroma_height,
+        neutral,
+    );
+    fill_plane(
+        planar.v_plane.borrow_mut(),
+        planar.v_stride.get() as usize / element_size,
+        chroma_width,
+        chroma_height,
+        neutral,
+    );
+
+    Ok(())
+}
+
+/// Convert YUV 400 gray image to planar YUV with neutral chroma.
+///
+/// Luma is copied as is and both chroma planes are filled with 128,
+/// so the result decodes to the same gray levels with any matrix and range.
+///
+/// # Arguments
+///
+/// * `gray` - Source gray image.
+/// * `planar` - Target planar image, must have the same size as `gray`.
+/// * `sampling` - Chroma subsampling of the target image.
+///
+pub fn yuv400_to_yuv_planar(
+    gray: &YuvGrayImage<u8>,
+    planar: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+) -> Result<(), YuvError> {
+    gray_to_planar_impl(gray, planar, sampling, 128u8)
+}
+
+/// Convert high bit depth YUV 400 gray image to planar YUV with neutral chroma.
+///
+/// Luma is copied as is and both chroma planes are filled with `1 << (bit_depth - 1)`
+/// stored with requested endianness and bytes packing.
+///
+/// # Arguments
+///
+/// * `gray` - Source gray image.
+/// * `planar` - Target planar image, must have the same size as `gray`.
+/// * `sampling` - Chroma subsampling of the target image.
+/// * `bit_depth` - Bit depth of the samples, from 8 to 16.
+/// * `endianness` - Byte order of the target chroma samples.
+/// * `bytes_packing` - Position of significant bits in the target chroma samples.
+///
+pub fn yuv400_p16_to_yuv_planar(
+    gray: &YuvGrayImage<u16>,
+    planar: &mut YuvPlanarImageMut<u16>,
+    sampling: YuvChromaSample,
+    bit_depth: u32,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    check_p16_bit_depth(bit_depth)?;
+    let neutral: u16 = 1 << (bit_depth - 1);
+    let packed = match bytes_packing {
+        YuvBytesPacking::MostSignificantBytes => neutral << (16 - bit_depth),
+        YuvBytesPacking::LeastSignificantBytes => neutral,
+    };
+    let stored = match endianness {
+        YuvEndianness::BigEndian => packed.to_be(),
+        YuvEndianness::LittleEndian => packed.to_le(),
+    };
+    gray_to_planar_impl(gray, planar, sampling, stored)
+}
+
+/// Extract luma of planar YUV image into YUV 400 gray image.
+///
+/// Samples are copied as is, so this works for any bit depth, endianness and bytes packing.
+///
+/// # Arguments
+///
+/// * `planar` - Source planar image.
+/// * `gray` - Target gray image, must have the same size as `planar`.
+/// * `sampling` - Chroma subsampling of the source image, used to validate its planes.
+///
+pub fn yuv_planar_to_yuv400<T: Copy + Debug + Send + Sync>(
+    planar: &YuvPlanarImage<T>,
+    gray: &mut YuvGrayImageMut<T>,
+    sampling: YuvChromaSample,
+) -> Result<(), YuvError> {
+    if planar.size.width == 0 || planar.size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    planar.check_constraints(sampling)?;
+    if gray.size != planar.size {
+        return Err(YuvError::ImageSizeMismatch(planar.size, gray.size));
+    }
+    gray.check_constraints()?;
+
+    let element_size = std::mem::size_of::<T>();
+    copy_plane(
+        planar.y_plane,
+        planar.y_stride.get() as usize / element_size,
+        gray.y_plane.borrow_mut(),
+        gray.y_stride.get() as usize / element_size,
+        gray.size.width as usize,
+        gray.size.height as usize,
+    );
+    Ok(())
+}
@@ -0,0 +1,286 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_vec, YuvPlanarImage};
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_planar_generic::PlanarRgbTransform;
+use crate::yuv_support::{
+    YuvChromaSample, YuvOvershoot, YuvRange, YuvSourceChannels, YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+/// Rational horizontal scale factor, e.g. pixel aspect ratio of anamorphic content
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct HorizontalScale {
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+impl HorizontalScale {
+    pub const fn new(numerator: u32, denominator: u32) -> HorizontalScale {
+        HorizontalScale {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// Width of the scaled image, rounded to nearest
+    pub const fn scaled_width(&self, width: u32) -> u32 {
+        if self.denominator == 0 {
+            return 0;
+        }
+        ((width as u64 * self.numerator as u64 + self.denominator as u64 / 2)
+            / self.denominator as u64) as u32
+    }
+}
+
+const WEIGHT_BITS: i32 = 8;
+
+/// Bilinear tap, sample is `s[left] * (256 - weight) + s[right] * weight`
+#[derive(Debug, Copy, Clone, Default)]
+struct Tap {
+    left: u32,
+    right: u32,
+    weight: i32,
+}
+
+/// Computes taps of `dst_width` destination samples over `src_width` source samples,
+/// `phase_scale` maps luma grid onto the sampled grid ( 2 for subsampled chroma ).
+fn make_taps(
+    dst_width: usize,
+    src_width: usize,
+    scale: HorizontalScale,
+    phase_scale: f64,
+) -> Result<Vec<Tap>, YuvError> {
+    let mut taps = try_vec(Tap::default(), dst_width)?;
+    let step = scale.denominator as f64 / scale.numerator as f64;
+    let last = src_width as i64 - 1;
+    for (x, tap) in taps.iter_mut().enumerate() {
+        let luma_position = (x as f64 + 0.5) * step;
+        let position = (luma_position / phase_scale - 0.5).max(0.);
+        let left = position.floor() as i64;
+        let weight = ((position - left as f64) * (1 << WEIGHT_BITS) as f64).round() as i32;
+        *tap = Tap {
+            left: left.min(last) as u32,
+            right: (left + 1).min(last) as u32,
+            weight,
+        };
+    }
+    Ok(taps)
+}
+
+#[inline(always)]
+fn interpolate(row: &[u8], tap: &Tap) -> i32 {
+    const ROUNDING: i32 = 1 << (WEIGHT_BITS - 1);
+    (row[tap.left as usize] as i32 * ((1 << WEIGHT_BITS) - tap.weight)
+        + row[tap.right as usize] as i32 * tap.weight
+        + ROUNDING)
+        >> WEIGHT_BITS
+}
+
+fn yuv_to_rgbx_horizontal_scale<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    scale: HorizontalScale,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+
+    image.check_constraints(sampling)?;
+    let dst_width = scale.scaled_width(image.size.width);
+    if dst_width == 0 || scale.numerator == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    let height = image.size.height;
+    check_rgba_destination(rgba, rgba_stride, dst_width, height, channels)?;
+
+    let width = image.size.width as usize;
+    let (chroma_width, phase_scale) = match sampling {
+        YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => (width.div_ceil(2), 2.),
+        YuvChromaSample::YUV444 => (width, 1.),
+    };
+    let luma_taps = make_taps(dst_width as usize, width, scale, 1.)?;
+    let chroma_taps = make_taps(dst_width as usize, chroma_width, scale, phase_scale)?;
+
+    let transform = PlanarRgbTransform::new(range, matrix, 8, 8, YuvOvershoot::Clamp);
+
+    let y_stride = image.y_stride.get() as usize;
+    let u_stride = image.u_stride.get() as usize;
+    let v_stride = image.v_stride.get() as usize;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba
+            .par_chunks_mut(rgba_stride as usize)
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba.chunks_mut(rgba_stride as usize).take(height as usize);
+    }
+    iter.enumerate().for_each(|(y, rgba_row)| {
+        let chroma_y = match sampling {
+            YuvChromaSample::YUV420 => y >> 1,
+            YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => y,
+        };
+        let y_row = &image.y_plane[y * y_stride..];
+        let u_row = &image.u_plane[chroma_y * u_stride..];
+        let v_row = &image.v_plane[chroma_y * v_stride..];
+
+        for ((dst, luma_tap), chroma_tap) in rgba_row
+            .chunks_exact_mut(channels)
+            .zip(luma_taps.iter())
+            .zip(chroma_taps.iter())
+        {
+            let (r, g, b) = transform.to_rgb(
+                interpolate(y_row, luma_tap),
+                interpolate(u_row, chroma_tap),
+                interpolate(v_row, chroma_tap),
+            );
+            dst[dst_chans.get_r_channel_offset()] = r as u8;
+            dst[dst_chans.get_g_channel_offset()] = g as u8;
+            dst[dst_chans.get_b_channel_offset()] = b as u8;
+            if dst_chans.has_alpha() {
+                dst[dst_chans.get_a_channel_offset()] = 255;
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Converts YUV planar 8-bit image to RGB stretched horizontally by a rational factor.
+///
+/// Scaling is fused into conversion with bilinear sampling of luma and chroma,
+/// which is enough for pixel aspect ratio correction of anamorphic content.
+/// Destination is `scale.scaled_width(width)` x `height` pixels.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source.
+/// * `scale` - Horizontal scale factor, e.g. 40/33 for widescreen NTSC DV.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (components per row) for the RGB image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_to_rgb_horizontal_scale(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    scale: HorizontalScale,
+    rgb: &mut [u8],
+    rgb_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_horizontal_scale::<{ YuvSourceChannels::Rgb as u8 }>(
+        image, sampling, scale, rgb, rgb_stride, range, matrix,
+    )
+}
+
+/// Converts YUV planar 8-bit image to RGBA stretched horizontally by a rational factor.
+///
+/// Scaling is fused into conversion with bilinear sampling of luma and chroma,
+/// which is enough for pixel aspect ratio correction of anamorphic content.
+/// Destination is `scale.scaled_width(width)` x `height` pixels.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source.
+/// * `scale` - Horizontal scale factor, e.g. 40/33 for widescreen NTSC DV.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (components per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_to_rgba_horizontal_scale(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    scale: HorizontalScale,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_horizontal_scale::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        sampling,
+        scale,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+    )
+}
+
+/// Converts YUV planar 8-bit image to BGRA stretched horizontally by a rational factor.
+///
+/// Scaling is fused into conversion with bilinear sampling of luma and chroma,
+/// which is enough for pixel aspect ratio correction of anamorphic content.
+/// Destination is `scale.scaled_width(width)` x `height` pixels.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source.
+/// * `scale` - Horizontal scale factor, e.g. 40/33 for widescreen NTSC DV.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (components per row) for the BGRA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_to_bgra_horizontal_scale(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    scale: HorizontalScale,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_horizontal_scale::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        sampling,
+        scale,
+        bgra,
+        bgra_stride,
+        range,
+        matrix,
+    )
+}
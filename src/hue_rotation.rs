@@ -0,0 +1,275 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::neon::{neon_rotate_hue_interleaved_row, neon_rotate_hue_row};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::sse::{sse_rotate_hue_interleaved_row, sse_rotate_hue_row};
+use crate::yuv_rotate::chroma_rows;
+use crate::yuv_support::YuvChromaSample;
+use crate::{YuvBiPlanarImageMut, YuvError, YuvPlanarImageMut};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+const HUE_PRECISION: i32 = 15;
+
+/// Returns Q15 cosine and sine of the angle, both kept inside `i16` so that 0° stays identity
+fn hue_coefficients(degrees: f32) -> Result<(i16, i16), YuvError> {
+    if !degrees.is_finite() {
+        return Err(YuvError::InvalidHueAngle(degrees));
+    }
+    let radians = (degrees as f64).rem_euclid(360.).to_radians();
+    let scale = (1 << HUE_PRECISION) as f64;
+    let cos = (radians.cos() * scale).round().clamp(-32767., 32767.) as i16;
+    let sin = (radians.sin() * scale).round().clamp(-32767., 32767.) as i16;
+    Ok((cos, sin))
+}
+
+/// Rounding Q15 product, matches `pmulhrsw` and `sqrdmulh` bit exactly
+#[inline(always)]
+fn q15_mul(a: i32, b: i32) -> i32 {
+    (a * b + (1 << (HUE_PRECISION - 1))) >> HUE_PRECISION
+}
+
+#[inline(always)]
+fn rotate_pair(cb: u8, cr: u8, cos: i32, sin: i32) -> (u8, u8) {
+    let cb = cb as i32 - 128;
+    let cr = cr as i32 - 128;
+    let rotated_cb = q15_mul(cb, cos) + q15_mul(cr, -sin);
+    let rotated_cr = q15_mul(cr, cos) + q15_mul(cb, sin);
+    (
+        (rotated_cb + 128).clamp(0, 255) as u8,
+        (rotated_cr + 128).clamp(0, 255) as u8,
+    )
+}
+
+fn chroma_width(width: u32, sampling: YuvChromaSample) -> usize {
+    match sampling {
+        YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => width.div_ceil(2) as usize,
+        YuvChromaSample::YUV444 => width as usize,
+    }
+}
+
+/// Rotates hue of planar 8-bit image in place by `degrees` counter clockwise on the CbCr plane.
+///
+/// Chroma vector of every sample is multiplied by 2x2 rotation matrix around the neutral 128 point,
+/// luma plane is left untouched. Results falling outside of 0..=255 are clamped.
+///
+/// # Arguments
+///
+/// * `image` - Planar image to rotate, only U and V planes are modified.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `degrees` - Rotation angle in degrees, any finite value, wraps around 360.
+///
+pub fn rotate_hue_yuv(
+    image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    degrees: f32,
+) -> Result<(), YuvError> {
+    image.check_constraints(sampling)?;
+    let (cos, sin) = hue_coefficients(degrees)?;
+
+    let chroma_width = chroma_width(image.size.width, sampling);
+    let (_, chroma_height) = chroma_rows(sampling, 0, image.size.height as usize);
+    let u_stride = image.u_stride.get() as usize;
+    let v_stride = image.v_stride.get() as usize;
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = image
+            .u_plane
+            .borrow_mut()
+            .par_chunks_mut(u_stride)
+            .zip(image.v_plane.borrow_mut().par_chunks_mut(v_stride))
+            .take(chroma_height);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = image
+            .u_plane
+            .borrow_mut()
+            .chunks_mut(u_stride)
+            .zip(image.v_plane.borrow_mut().chunks_mut(v_stride))
+            .take(chroma_height);
+    }
+    iter.for_each(|(u_row, v_row)| {
+        #[allow(unused_mut)]
+        let mut cx = 0usize;
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            if _use_sse {
+                cx = sse_rotate_hue_row(u_row, v_row, chroma_width, cos, sin);
+            }
+        }
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            cx = neon_rotate_hue_row(u_row, v_row, chroma_width, cos, sin);
+        }
+
+        for (u, v) in u_row
+            .iter_mut()
+            .zip(v_row.iter_mut())
+            .take(chroma_width)
+            .skip(cx)
+        {
+            (*u, *v) = rotate_pair(*u, *v, cos as i32, sin as i32);
+        }
+    });
+
+    Ok(())
+}
+
+fn rotate_hue_interleaved(
+    image: &mut YuvBiPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    cos: i16,
+    sin: i16,
+) -> Result<(), YuvError> {
+    image.check_constraints(sampling)?;
+
+    let row_length = chroma_width(image.size.width, sampling) * 2;
+    let (_, chroma_height) = chroma_rows(sampling, 0, image.size.height as usize);
+    let uv_stride = image.uv_stride.get() as usize;
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = image
+            .uv_plane
+            .borrow_mut()
+            .par_chunks_mut(uv_stride)
+            .take(chroma_height);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = image
+            .uv_plane
+            .borrow_mut()
+            .chunks_mut(uv_stride)
+            .take(chroma_height);
+    }
+    iter.for_each(|uv_row| {
+        #[allow(unused_mut)]
+        let mut cx = 0usize;
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            if _use_sse {
+                cx = sse_rotate_hue_interleaved_row(uv_row, row_length, cos, sin);
+            }
+        }
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            cx = neon_rotate_hue_interleaved_row(uv_row, row_length, cos, sin);
+        }
+
+        for pair in uv_row[cx..row_length].chunks_exact_mut(2) {
+            (pair[0], pair[1]) = rotate_pair(pair[0], pair[1], cos as i32, sin as i32);
+        }
+    });
+
+    Ok(())
+}
+
+/// Rotates hue of NV12 or NV16 image in place by `degrees` counter clockwise on the CbCr plane.
+///
+/// Same transform as [rotate_hue_yuv] applied to interleaved UV plane, luma plane is left untouched.
+///
+/// # Arguments
+///
+/// * `image` - Bi-planar image to rotate, only UV plane is modified.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `degrees` - Rotation angle in degrees, any finite value, wraps around 360.
+///
+pub fn rotate_hue_yuv_nv12(
+    image: &mut YuvBiPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    degrees: f32,
+) -> Result<(), YuvError> {
+    let (cos, sin) = hue_coefficients(degrees)?;
+    rotate_hue_interleaved(image, sampling, cos, sin)
+}
+
+/// Rotates hue of NV21 or NV61 image in place by `degrees` counter clockwise on the CbCr plane.
+///
+/// Same transform as [rotate_hue_yuv] applied to interleaved VU plane, luma plane is left untouched.
+///
+/// # Arguments
+///
+/// * `image` - Bi-planar image to rotate, only VU plane is modified.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `degrees` - Rotation angle in degrees, any finite value, wraps around 360.
+///
+pub fn rotate_hue_yuv_nv21(
+    image: &mut YuvBiPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    degrees: f32,
+) -> Result<(), YuvError> {
+    let (cos, sin) = hue_coefficients(degrees)?;
+    // With Cr stored first the same rotation runs the opposite way
+    rotate_hue_interleaved(image, sampling, cos, -sin)
+}
@@ -0,0 +1,1097 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::{
+    check_chroma_channel, check_chroma_source_channel, check_interleaved_chroma_channel,
+    check_y8_channel,
+};
+use crate::yuv_support::YuvChromaSample;
+use crate::YuvError;
+use std::fmt::Debug;
+
+#[repr(transparent)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+/// Distance in bytes between starts of two consecutive rows.
+///
+/// Exists to keep strides apart from dimensions in long argument lists,
+/// use `RowStride::from(u32)` when stride is already at hand.
+pub struct RowStride(u32);
+
+impl RowStride {
+    #[inline]
+    pub const fn new(stride: u32) -> RowStride {
+        RowStride(stride)
+    }
+
+    #[inline]
+    pub const fn get(self) -> u32 {
+        self.0
+    }
+}
+
+impl From<u32> for RowStride {
+    #[inline]
+    fn from(value: u32) -> Self {
+        RowStride(value)
+    }
+}
+
+impl From<RowStride> for u32 {
+    #[inline]
+    fn from(value: RowStride) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+/// Image dimensions in pixels
+pub struct ImageSize {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl ImageSize {
+    #[inline]
+    pub const fn new(width: u32, height: u32) -> ImageSize {
+        ImageSize { width, height }
+    }
+}
+
+impl From<(u32, u32)> for ImageSize {
+    /// Tuple is treated as `(width, height)`
+    #[inline]
+    fn from(value: (u32, u32)) -> Self {
+        ImageSize {
+            width: value.0,
+            height: value.1,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Storage of an image plane, either borrowed from the caller or owned by the image
+pub enum BufferStoreMut<'a, T: Copy + Debug> {
+    Borrowed(&'a mut [T]),
+    Owned(Vec<T>),
+}
+
+impl<T: Copy + Debug> BufferStoreMut<'_, T> {
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn borrow(&self) -> &[T] {
+        match self {
+            Self::Borrowed(p_ref) => p_ref,
+            Self::Owned(vec) => vec,
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    #[inline]
+    pub fn borrow_mut(&mut self) -> &mut [T] {
+        match self {
+            Self::Borrowed(p_ref) => p_ref,
+            Self::Owned(vec) => vec,
+        }
+    }
+}
+
+impl<T: Copy + Debug + Default> BufferStoreMut<'_, T> {
+    /// Allocates owned storage of `count` default elements,
+    /// reports [YuvError::AllocationFailure] instead of aborting when memory is exhausted
+    pub fn try_alloc(count: usize) -> Result<Self, YuvError> {
+        Ok(BufferStoreMut::Owned(try_vec(T::default(), count)?))
+    }
+}
+
+/// Fallible counterpart of `Vec::with_capacity(count)`
+pub(crate) fn try_with_capacity<T>(count: usize) -> Result<Vec<T>, YuvError> {
+    let mut vec = Vec::new();
+    vec.try_reserve_exact(count)
+        .map_err(|_| YuvError::AllocationFailure(count.saturating_mul(std::mem::size_of::<T>())))?;
+    Ok(vec)
+}
+
+/// Fallible counterpart of `vec![value; count]`
+pub(crate) fn try_vec<T: Copy>(value: T, count: usize) -> Result<Vec<T>, YuvError> {
+    let mut vec = try_with_capacity(count)?;
+    vec.resize(count, value);
+    Ok(vec)
+}
+
+#[derive(Debug, Clone)]
+/// Borrowed grayscale image, luma plane only
+pub struct YuvGrayImage<'a, T: Copy + Debug> {
+    pub y_plane: &'a [T],
+    /// Stride in bytes
+    pub y_stride: RowStride,
+    pub size: ImageSize,
+}
+
+impl<T: Copy + Debug> YuvGrayImage<'_, T> {
+    /// Validates plane against image size and stride
+    pub fn check_constraints(&self) -> Result<(), YuvError> {
+        check_y8_channel(
+            self.y_plane,
+            self.y_stride.get(),
+            self.size.width,
+            self.size.height,
+        )
+    }
+}
+
+#[derive(Debug)]
+/// Mutable grayscale image, luma plane only
+pub struct YuvGrayImageMut<'a, T: Copy + Debug> {
+    pub y_plane: BufferStoreMut<'a, T>,
+    /// Stride in bytes
+    pub y_stride: RowStride,
+    pub size: ImageSize,
+}
+
+impl<T: Copy + Debug + Default> YuvGrayImageMut<'_, T> {
+    /// Allocates tightly packed image of given size
+    ///
+    /// # Panics
+    ///
+    /// Panics when row does not fit `u32` stride or plane does not fit `usize`, see [YuvGrayImageMut::try_alloc].
+    pub fn alloc(size: ImageSize) -> Self {
+        let (y_stride, y_length) =
+            expect_layout(packed_plane::<T>(size.width, size.height, 1, size));
+        YuvGrayImageMut {
+            y_plane: BufferStoreMut::Owned(vec![T::default(); y_length]),
+            y_stride,
+            size,
+        }
+    }
+
+    /// Same as [YuvGrayImageMut::alloc] but reports allocation failure
+    /// and [YuvError::ImageTooLarge] as an error
+    pub fn try_alloc(size: ImageSize) -> Result<Self, YuvError> {
+        let (y_stride, y_length) = packed_plane::<T>(size.width, size.height, 1, size)?;
+        Ok(YuvGrayImageMut {
+            y_plane: BufferStoreMut::try_alloc(y_length)?,
+            y_stride,
+            size,
+        })
+    }
+}
+
+impl<T: Copy + Debug> YuvGrayImageMut<'_, T> {
+    /// Validates plane against image size and stride
+    pub fn check_constraints(&self) -> Result<(), YuvError> {
+        self.to_fixed().check_constraints()
+    }
+
+    /// Borrows image as read only
+    pub fn to_fixed(&self) -> YuvGrayImage<'_, T> {
+        YuvGrayImage {
+            y_plane: self.y_plane.borrow(),
+            y_stride: self.y_stride,
+            size: self.size,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Borrowed grayscale image with separate alpha plane
+pub struct YuvGrayAlphaImage<'a, T: Copy + Debug> {
+    pub y_plane: &'a [T],
+    /// Stride in bytes
+    pub y_stride: RowStride,
+    pub a_plane: &'a [T],
+    /// Stride in bytes
+    pub a_stride: RowStride,
+    pub size: ImageSize,
+}
+
+impl<T: Copy + Debug> YuvGrayAlphaImage<'_, T> {
+    /// Validates planes against image size and strides
+    pub fn check_constraints(&self) -> Result<(), YuvError> {
+        check_y8_channel(
+            self.y_plane,
+            self.y_stride.get(),
+            self.size.width,
+            self.size.height,
+        )?;
+        check_y8_channel(
+            self.a_plane,
+            self.a_stride.get(),
+            self.size.width,
+            self.size.height,
+        )
+    }
+
+    /// Borrows luma plane alone, alpha is dropped
+    pub fn gray(&self) -> YuvGrayImage<'_, T> {
+        YuvGrayImage {
+            y_plane: self.y_plane,
+            y_stride: self.y_stride,
+            size: self.size,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Mutable grayscale image with separate alpha plane
+pub struct YuvGrayAlphaImageMut<'a, T: Copy + Debug> {
+    pub y_plane: BufferStoreMut<'a, T>,
+    /// Stride in bytes
+    pub y_stride: RowStride,
+    pub a_plane: BufferStoreMut<'a, T>,
+    /// Stride in bytes
+    pub a_stride: RowStride,
+    pub size: ImageSize,
+}
+
+impl<T: Copy + Debug + Default> YuvGrayAlphaImageMut<'_, T> {
+    /// Allocates tightly packed image of given size
+    ///
+    /// # Panics
+    ///
+    /// Panics when row does not fit `u32` stride or plane does not fit `usize`, see [YuvGrayAlphaImageMut::try_alloc].
+    pub fn alloc(size: ImageSize) -> Self {
+        let (stride, length) = expect_layout(packed_plane::<T>(size.width, size.height, 1, size));
+        YuvGrayAlphaImageMut {
+            y_plane: BufferStoreMut::Owned(vec![T::default(); length]),
+            y_stride: stride,
+            a_plane: BufferStoreMut::Owned(vec![T::default(); length]),
+            a_stride: stride,
+            size,
+        }
+    }
+
+    /// Same as [YuvGrayAlphaImageMut::alloc] but reports allocation failure
+    /// and [YuvError::ImageTooLarge] as an error
+    pub fn try_alloc(size: ImageSize) -> Result<Self, YuvError> {
+        let (stride, length) = packed_plane::<T>(size.width, size.height, 1, size)?;
+        Ok(YuvGrayAlphaImageMut {
+            y_plane: BufferStoreMut::try_alloc(length)?,
+            y_stride: stride,
+            a_plane: BufferStoreMut::try_alloc(length)?,
+            a_stride: stride,
+            size,
+        })
+    }
+}
+
+impl<T: Copy + Debug> YuvGrayAlphaImageMut<'_, T> {
+    /// Validates planes against image size and strides
+    pub fn check_constraints(&self) -> Result<(), YuvError> {
+        self.to_fixed().check_constraints()
+    }
+
+    /// Borrows image as read only
+    pub fn to_fixed(&self) -> YuvGrayAlphaImage<'_, T> {
+        YuvGrayAlphaImage {
+            y_plane: self.y_plane.borrow(),
+            y_stride: self.y_stride,
+            a_plane: self.a_plane.borrow(),
+            a_stride: self.a_stride,
+            size: self.size,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Borrowed planar YUV image
+pub struct YuvPlanarImage<'a, T: Copy + Debug> {
+    pub y_plane: &'a [T],
+    /// Stride in bytes
+    pub y_stride: RowStride,
+    pub u_plane: &'a [T],
+    /// Stride in bytes
+    pub u_stride: RowStride,
+    pub v_plane: &'a [T],
+    /// Stride in bytes
+    pub v_stride: RowStride,
+    pub size: ImageSize,
+}
+
+impl<T: Copy + Debug> YuvPlanarImage<'_, T> {
+    /// Validates planes against image size, strides and chroma subsampling,
+    /// chroma stride `0` is accepted and repeats a single row for every chroma row
+    pub fn check_constraints(&self, sampling: YuvChromaSample) -> Result<(), YuvError> {
+        check_y8_channel(
+            self.y_plane,
+            self.y_stride.get(),
+            self.size.width,
+            self.size.height,
+        )?;
+        check_chroma_source_channel(
+            self.u_plane,
+            self.u_stride.get(),
+            self.size.width,
+            self.size.height,
+            sampling,
+        )?;
+        check_chroma_source_channel(
+            self.v_plane,
+            self.v_stride.get(),
+            self.size.width,
+            self.size.height,
+            sampling,
+        )?;
+        Ok(())
+    }
+
+    /// Borrows luma plane as grayscale image, chroma is dropped
+    pub fn luma(&self) -> YuvGrayImage<'_, T> {
+        YuvGrayImage {
+            y_plane: self.y_plane,
+            y_stride: self.y_stride,
+            size: self.size,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Mutable planar YUV image
+pub struct YuvPlanarImageMut<'a, T: Copy + Debug> {
+    pub y_plane: BufferStoreMut<'a, T>,
+    /// Stride in bytes
+    pub y_stride: RowStride,
+    pub u_plane: BufferStoreMut<'a, T>,
+    /// Stride in bytes
+    pub u_stride: RowStride,
+    pub v_plane: BufferStoreMut<'a, T>,
+    /// Stride in bytes
+    pub v_stride: RowStride,
+    pub size: ImageSize,
+}
+
+impl<T: Copy + Debug + Default> YuvPlanarImageMut<'_, T> {
+    /// Allocates tightly packed image of given size and chroma subsampling
+    ///
+    /// # Panics
+    ///
+    /// Panics when row does not fit `u32` stride or plane does not fit `usize`, see [YuvPlanarImageMut::try_alloc].
+    pub fn alloc(size: ImageSize, sampling: YuvChromaSample) -> Self {
+        let (y_stride, y_length) =
+            expect_layout(packed_plane::<T>(size.width, size.height, 1, size));
+        let (chroma_width, chroma_height) = chroma_dimensions(size, sampling);
+        let (chroma_stride, chroma_length) =
+            expect_layout(packed_plane::<T>(chroma_width, chroma_height, 1, size));
+        YuvPlanarImageMut {
+            y_plane: BufferStoreMut::Owned(vec![T::default(); y_length]),
+            y_stride,
+            u_plane: BufferStoreMut::Owned(vec![T::default(); chroma_length]),
+            u_stride: chroma_stride,
+            v_plane: BufferStoreMut::Owned(vec![T::default(); chroma_length]),
+            v_stride: chroma_stride,
+            size,
+        }
+    }
+
+    /// Same as [YuvPlanarImageMut::alloc] but reports allocation failure
+    /// and [YuvError::ImageTooLarge] as an error
+    pub fn try_alloc(size: ImageSize, sampling: YuvChromaSample) -> Result<Self, YuvError> {
+        let (y_stride, y_length) = packed_plane::<T>(size.width, size.height, 1, size)?;
+        let (chroma_width, chroma_height) = chroma_dimensions(size, sampling);
+        let (chroma_stride, chroma_length) =
+            packed_plane::<T>(chroma_width, chroma_height, 1, size)?;
+        Ok(YuvPlanarImageMut {
+            y_plane: BufferStoreMut::try_alloc(y_length)?,
+            y_stride,
+            u_plane: BufferStoreMut::try_alloc(chroma_length)?,
+            u_stride: chroma_stride,
+            v_plane: BufferStoreMut::try_alloc(chroma_length)?,
+            v_stride: chroma_stride,
+            size,
+        })
+    }
+}
+
+/// Returns chroma plane dimensions in samples for given chroma subsampling
+fn chroma_dimensions(size: ImageSize, sampling: YuvChromaSample) -> (u32, u32) {
+    let chroma_width = match sampling {
+        YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => size.width.div_ceil(2),
+        YuvChromaSample::YUV444 => size.width,
+    };
+    let chroma_height = match sampling {
+        YuvChromaSample::YUV420 => size.height.div_ceil(2),
+        YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => size.height,
+    };
+    (chroma_width, chroma_height)
+}
+
+/// Returns stride in bytes and length in elements of tightly packed plane of `width` x `height`
+/// samples, each made of `components` elements, of the image of `size`.
+///
+/// Row must fit `u32` stride and the whole plane must be addressable by a slice,
+/// otherwise [YuvError::ImageTooLarge] is returned.
+fn packed_plane<T>(
+    width: u32,
+    height: u32,
+    components: u32,
+    size: ImageSize,
+) -> Result<(RowStride, usize), YuvError> {
+    let element_size = std::mem::size_of::<T>();
+    let stride = width
+        .checked_mul(components)
+        .and_then(|x| x.checked_mul(element_size as u32))
+        .ok_or(YuvError::ImageTooLarge(size))?;
+    let length = (width as usize)
+        .checked_mul(components as usize)
+        .and_then(|x| x.checked_mul(height as usize))
+        .filter(|&x| x <= isize::MAX as usize / element_size)
+        .ok_or(YuvError::ImageTooLarge(size))?;
+    Ok((RowStride::new(stride), length))
+}
+
+#[inline]
+fn expect_layout(layout: Result<(RowStride, usize), YuvError>) -> (RowStride, usize) {
+    match layout {
+        Ok(layout) => layout,
+        Err(err) => panic!("{}", err),
+    }
+}
+
+impl<T: Copy + Debug> YuvPlanarImageMut<'_, T> {
+    /// Validates planes against image size, strides and chroma subsampling
+    pub fn check_constraints(&self, sampling: YuvChromaSample) -> Result<(), YuvError> {
+        check_y8_channel(
+            self.y_plane.borrow(),
+            self.y_stride.get(),
+            self.size.width,
+            self.size.height,
+        )?;
+        check_chroma_channel(
+            self.u_plane.borrow(),
+            self.u_stride.get(),
+            self.size.width,
+            self.size.height,
+            sampling,
+        )?;
+        check_chroma_channel(
+            self.v_plane.borrow(),
+            self.v_stride.get(),
+            self.size.width,
+            self.size.height,
+            sampling,
+        )?;
+        Ok(())
+    }
+
+    /// Borrows image as read only
+    pub fn to_fixed(&self) -> YuvPlanarImage<'_, T> {
+        YuvPlanarImage {
+            y_plane: self.y_plane.borrow(),
+            y_stride: self.y_stride,
+            u_plane: self.u_plane.borrow(),
+            u_stride: self.u_stride,
+            v_plane: self.v_plane.borrow(),
+            v_stride: self.v_stride,
+            size: self.size,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Borrowed bi-planar YUV image, luma plane followed by interleaved chroma plane as in NV12
+pub struct YuvBiPlanarImage<'a, T: Copy + Debug> {
+    pub y_plane: &'a [T],
+    /// Stride in bytes
+    pub y_stride: RowStride,
+    pub uv_plane: &'a [T],
+    /// Stride in bytes
+    pub uv_stride: RowStride,
+    pub size: ImageSize,
+}
+
+impl<T: Copy + Debug> YuvBiPlanarImage<'_, T> {
+    /// Validates planes against image size, strides and chroma subsampling
+    pub fn check_constraints(&self, sampling: YuvChromaSample) -> Result<(), YuvError> {
+        check_y8_channel(
+            self.y_plane,
+            self.y_stride.get(),
+            self.size.width,
+            self.size.height,
+        )?;
+        check_interleaved_chroma_channel(
+            self.uv_plane,
+            self.uv_stride.get(),
+            self.size.width,
+            self.size.height,
+            sampling,
+        )?;
+        Ok(())
+    }
+
+    /// Borrows luma plane as grayscale image, chroma is dropped
+    pub fn luma(&self) -> YuvGrayImage<'_, T> {
+        YuvGrayImage {
+            y_plane: self.y_plane,
+            y_stride: self.y_stride,
+            size: self.size,
+        }
+    }
+}
+
+#[derive(Debug)]
+/// Mutable bi-planar YUV image, luma plane followed by interleaved chroma plane as in NV12
+pub struct YuvBiPlanarImageMut<'a, T: Copy + Debug> {
+    pub y_plane: BufferStoreMut<'a, T>,
+    /// Stride in bytes
+    pub y_stride: RowStride,
+    pub uv_plane: BufferStoreMut<'a, T>,
+    /// Stride in bytes
+    pub uv_stride: RowStride,
+    pub size: ImageSize,
+}
+
+impl<T: Copy + Debug + Default> YuvBiPlanarImageMut<'_, T> {
+    /// Allocates tightly packed image of given size and chroma subsampling
+    ///
+    /// # Panics
+    ///
+    /// Panics when row does not fit `u32` stride or plane does not fit `usize`, see [YuvBiPlanarImageMut::try_alloc].
+    pub fn alloc(size: ImageSize, sampling: YuvChromaSample) -> Self {
+        let (y_stride, y_length) =
+            expect_layout(packed_plane::<T>(size.width, size.height, 1, size));
+        let (chroma_width, chroma_height) = chroma_dimensions(size, sampling);
+        let (uv_stride, uv_length) =
+            expect_layout(packed_plane::<T>(chroma_width, chroma_height, 2, size));
+        YuvBiPlanarImageMut {
+            y_plane: BufferStoreMut::Owned(vec![T::default(); y_length]),
+            y_stride,
+            uv_plane: BufferStoreMut::Owned(vec![T::default(); uv_length]),
+            uv_stride,
+            size,
+        }
+    }
+
+    /// Same as [YuvBiPlanarImageMut::alloc] but reports allocation failure
+    /// and [YuvError::ImageTooLarge] as an error
+    pub fn try_alloc(size: ImageSize, sampling: YuvChromaSample) -> Result<Self, YuvError> {
+        let (y_stride, y_length) = packed_plane::<T>(size.width, size.height, 1, size)?;
+        let (chroma_width, chroma_height) = chroma_dimensions(size, sampling);
+        let (uv_stride, uv_length) = packed_plane::<T>(chroma_width, chroma_height, 2, size)?;
+        Ok(YuvBiPlanarImageMut {
+            y_plane: BufferStoreMut::try_alloc(y_length)?,
+            y_stride,
+            uv_plane: BufferStoreMut::try_alloc(uv_length)?,
+            uv_stride,
+            size,
+        })
+    }
+}
+
+impl<T: Copy + Debug> YuvBiPlanarImageMut<'_, T> {
+    /// Validates planes against image size, strides and chroma subsampling
+    pub fn check_constraints(&self, sampling: YuvChromaSample) -> Result<(), YuvError> {
+        self.to_fixed().check_constraints(sampling)
+    }
+
+    /// Borrows image as read only
+    pub fn to_fixed(&self) -> YuvBiPlanarImage<'_, T> {
+        YuvBiPlanarImage {
+            y_plane: self.y_plane.borrow(),
+            y_stride: self.y_stride,
+            uv_plane: self.uv_plane.borrow(),
+            uv_stride: self.uv_stride,
+            size: self.size,
+        }
+    }
+}
+
+/// Number of elements `rows` rows of `stride` bytes take, when it can be computed
+fn plane_elements<T>(stride: RowStride, rows: u32) -> Option<usize> {
+    (stride.get() as usize)
+        .checked_mul(rows as usize)
+        .map(|bytes| bytes / std::mem::size_of::<T>())
+}
+
+/// Skips `data_offset` bytes at the start of a plane buffer and drops whatever follows
+/// `rows` rows of `stride` bytes, buffers shorter than that are left for validation to reject
+fn skip_data_offset<T>(
+    plane: &[T],
+    data_offset: u32,
+    stride: RowStride,
+    rows: u32,
+) -> Result<&[T], YuvError> {
+    let element_size = std::mem::size_of::<T>();
+    let offset = data_offset as usize;
+    if !offset.is_multiple_of(element_size) || offset / element_size > plane.len() {
+        return Err(YuvError::InvalidDataOffset(data_offset));
+    }
+    let plane = &plane[offset / element_size..];
+    let len = plane_elements::<T>(stride, rows).map_or(plane.len(), |n| n.min(plane.len()));
+    Ok(&plane[..len])
+}
+
+fn skip_data_offset_mut<T>(
+    plane: &mut [T],
+    data_offset: u32,
+    stride: RowStride,
+    rows: u32,
+) -> Result<&mut [T], YuvError> {
+    let element_size = std::mem::size_of::<T>();
+    let offset = data_offset as usize;
+    if !offset.is_multiple_of(element_size) || offset / element_size > plane.len() {
+        return Err(YuvError::InvalidDataOffset(data_offset));
+    }
+    let plane = &mut plane[offset / element_size..];
+    let len = plane_elements::<T>(stride, rows).map_or(plane.len(), |n| n.min(plane.len()));
+    Ok(&mut plane[..len])
+}
+
+impl<'a, T: Copy + Debug> YuvPlanarImage<'a, T> {
+    /// Builds image over V4L2 multi-planar buffers without slicing them first.
+    ///
+    /// Every `*_data_offset` is in bytes and has `v4l2_plane.data_offset` meaning: image data
+    /// of the plane starts that far into its buffer, stride keeps counting from there.
+    /// Drivers usually hand out buffers larger than `stride * rows`, bytes past the last row
+    /// are dropped, so planes are validated against `size` and `sampling` only up to there.
+    ///
+    /// Interlaced buffers, `V4L2_FIELD_SEQ_TB` and alike included, are described as a whole
+    /// frame with the frame stride, use [YuvPlanarImage::field] to get one of its fields.
+    pub fn from_v4l2_planes(
+        y_plane: &'a [T],
+        y_data_offset: u32,
+        y_stride: RowStride,
+        u_plane: &'a [T],
+        u_data_offset: u32,
+        u_stride: RowStride,
+        v_plane: &'a [T],
+        v_data_offset: u32,
+        v_stride: RowStride,
+        size: ImageSize,
+        sampling: YuvChromaSample,
+    ) -> Result<Self, YuvError> {
+        let (_, chroma_height) = chroma_dimensions(size, sampling);
+        let image = YuvPlanarImage {
+            y_plane: skip_data_offset(y_plane, y_data_offset, y_stride, size.height)?,
+            y_stride,
+            u_plane: skip_data_offset(u_plane, u_data_offset, u_stride, chroma_height)?,
+            u_stride,
+            v_plane: skip_data_offset(v_plane, v_data_offset, v_stride, chroma_height)?,
+            v_stride,
+            size,
+        };
+        image.check_constraints(sampling)?;
+        Ok(image)
+    }
+}
+
+impl<'a, T: Copy + Debug> YuvPlanarImageMut<'a, T> {
+    /// Mutable counterpart of [YuvPlanarImage::from_v4l2_planes], planes stay borrowed
+    pub fn from_v4l2_planes_mut(
+        y_plane: &'a mut [T],
+        y_data_offset: u32,
+        y_stride: RowStride,
+        u_plane: &'a mut [T],
+        u_data_offset: u32,
+        u_stride: RowStride,
+        v_plane: &'a mut [T],
+        v_data_offset: u32,
+        v_stride: RowStride,
+        size: ImageSize,
+        sampling: YuvChromaSample,
+    ) -> Result<Self, YuvError> {
+        let (_, chroma_height) = chroma_dimensions(size, sampling);
+        let image = YuvPlanarImageMut {
+            y_plane: BufferStoreMut::Borrowed(skip_data_offset_mut(
+                y_plane,
+                y_data_offset,
+                y_stride,
+                size.height,
+            )?),
+            y_stride,
+            u_plane: BufferStoreMut::Borrowed(skip_data_offset_mut(
+                u_plane,
+                u_data_offset,
+                u_stride,
+                chroma_height,
+            )?),
+            u_stride,
+            v_plane: BufferStoreMut::Borrowed(skip_data_offset_mut(
+                v_plane,
+                v_data_offset,
+                v_stride,
+                chroma_height,
+            )?),
+            v_stride,
+            size,
+        };
+        image.check_constraints(sampling)?;
+        Ok(image)
+    }
+}
+
+impl<'a, T: Copy + Debug> YuvBiPlanarImage<'a, T> {
+    /// Builds NV12 like image over V4L2 multi-planar buffers ( `V4L2_PIX_FMT_NV12M` and friends ),
+    /// same rules as [YuvPlanarImage::from_v4l2_planes] apply.
+    pub fn from_v4l2_planes(
+        y_plane: &'a [T],
+        y_data_offset: u32,
+        y_stride: RowStride,
+        uv_plane: &'a [T],
+        uv_data_offset: u32,
+        uv_stride: RowStride,
+        size: ImageSize,
+        sampling: YuvChromaSample,
+    ) -> Result<Self, YuvError> {
+        let (_, chroma_height) = chroma_dimensions(size, sampling);
+        let image = YuvBiPlanarImage {
+            y_plane: skip_data_offset(y_plane, y_data_offset, y_stride, size.height)?,
+            y_stride,
+            uv_plane: skip_data_offset(uv_plane, uv_data_offset, uv_stride, chroma_height)?,
+            uv_stride,
+            size,
+        };
+        image.check_constraints(sampling)?;
+        Ok(image)
+    }
+}
+
+impl<'a, T: Copy + Debug> YuvBiPlanarImageMut<'a, T> {
+    /// Mutable counterpart of [YuvBiPlanarImage::from_v4l2_planes], planes stay borrowed
+    pub fn from_v4l2_planes_mut(
+        y_plane: &'a mut [T],
+        y_data_offset: u32,
+        y_stride: RowStride,
+        uv_plane: &'a mut [T],
+        uv_data_offset: u32,
+        uv_stride: RowStride,
+        size: ImageSize,
+        sampling: YuvChromaSample,
+    ) -> Result<Self, YuvError> {
+        let (_, chroma_height) = chroma_dimensions(size, sampling);
+        let image = YuvBiPlanarImageMut {
+            y_plane: BufferStoreMut::Borrowed(skip_data_offset_mut(
+                y_plane,
+                y_data_offset,
+                y_stride,
+                size.height,
+            )?),
+            y_stride,
+            uv_plane: BufferStoreMut::Borrowed(skip_data_offset_mut(
+                uv_plane,
+                uv_data_offset,
+                uv_stride,
+                chroma_height,
+            )?),
+            uv_stride,
+            size,
+        };
+        image.check_constraints(sampling)?;
+        Ok(image)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// One of two fields of an interlaced frame
+pub enum YuvField {
+    /// Field holding even frame rows, counting from zero
+    Top,
+    /// Field holding odd frame rows
+    Bottom,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// Placement of the fields of an interlaced frame in its buffers
+pub enum YuvFieldLayout {
+    /// `V4L2_FIELD_INTERLACED`, rows of both fields alternate starting with the top one,
+    /// so a field has twice the frame stride
+    Interlaced,
+    /// `V4L2_FIELD_SEQ_TB`, all rows of the top field are followed by all rows of the bottom one
+    SequentialTopBottom,
+    /// `V4L2_FIELD_SEQ_BT`, all rows of the bottom field go first
+    SequentialBottomTop,
+}
+
+/// Returns part of a frame plane of `rows` rows holding `field`, with stride of the field
+fn field_plane<T>(
+    plane: &[T],
+    stride: RowStride,
+    rows: u32,
+    field: YuvField,
+    layout: YuvFieldLayout,
+    size: ImageSize,
+) -> Result<(&[T], RowStride), YuvError> {
+    // A single replicated row serves both fields as is
+    if stride.get() == 0 {
+        return Ok((plane, stride));
+    }
+    let element_size = std::mem::size_of::<T>();
+    let field_rows = rows / 2;
+    let stored_second = match layout {
+        YuvFieldLayout::Interlaced | YuvFieldLayout::SequentialTopBottom => {
+            field == YuvField::Bottom
+        }
+        YuvFieldLayout::SequentialBottomTop => field == YuvField::Top,
+    };
+    let (offset, field_stride) = match layout {
+        YuvFieldLayout::Interlaced => {
+            let field_stride = stride
+                .get()
+                .checked_mul(2)
+                .ok_or(YuvError::ImageTooLarge(size))?;
+            (stored_second as usize * stride.get() as usize, field_stride)
+        }
+        YuvFieldLayout::SequentialTopBottom | YuvFieldLayout::SequentialBottomTop => (
+            stored_second as usize * stride.get() as usize * field_rows as usize,
+            stride.get(),
+        ),
+    };
+    if !offset.is_multiple_of(element_size) {
+        return Err(YuvError::PlaneReinterpretationFailure);
+    }
+    let field_stride = RowStride::new(field_stride);
+    let plane = &plane[(offset / element_size).min(plane.len())..];
+    let len =
+        plane_elements::<T>(field_stride, field_rows).map_or(plane.len(), |n| n.min(plane.len()));
+    Ok((&plane[..len], field_stride))
+}
+
+/// Checks that luma and chroma rows of a frame split evenly between two fields
+fn check_field_height(size: ImageSize, sampling: YuvChromaSample) -> Result<(), YuvError> {
+    let (_, chroma_height) = chroma_dimensions(size, sampling);
+    if !size.height.is_multiple_of(2) || !chroma_height.is_multiple_of(2) {
+        return Err(YuvError::InvalidFieldHeight(size.height));
+    }
+    Ok(())
+}
+
+impl<'a, T: Copy + Debug> YuvPlanarImage<'a, T> {
+    /// Returns one field of an interlaced frame stored as `layout`, as an image of half height.
+    ///
+    /// Frame height must be even, and a multiple of 4 for 4:2:0, so every field gets whole
+    /// chroma rows. With [YuvFieldLayout::Interlaced] the bottom field ends one frame row short
+    /// of its doubled stride, so it is accepted only with [crate::YuvStrideMode::AutoTail].
+    /// Fields are half as tall as the frame, resolve [crate::YuvStandardMatrix::Auto] against
+    /// the frame before converting them.
+    pub fn field(
+        &self,
+        field: YuvField,
+        layout: YuvFieldLayout,
+        sampling: YuvChromaSample,
+    ) -> Result<YuvPlanarImage<'a, T>, YuvError> {
+        self.check_constraints(sampling)?;
+        check_field_height(self.size, sampling)?;
+        let (_, chroma_height) = chroma_dimensions(self.size, sampling);
+        let (y_plane, y_stride) = field_plane(
+            self.y_plane,
+            self.y_stride,
+            self.size.height,
+            field,
+            layout,
+            self.size,
+        )?;
+        let (u_plane, u_stride) = field_plane(
+            self.u_plane,
+            self.u_stride,
+            chroma_height,
+            field,
+            layout,
+            self.size,
+        )?;
+        let (v_plane, v_stride) = field_plane(
+            self.v_plane,
+            self.v_stride,
+            chroma_height,
+            field,
+            layout,
+            self.size,
+        )?;
+        let image = YuvPlanarImage {
+            y_plane,
+            y_stride,
+            u_plane,
+            u_stride,
+            v_plane,
+            v_stride,
+            size: ImageSize::new(self.size.width, self.size.height / 2),
+        };
+        image.check_constraints(sampling)?;
+        Ok(image)
+    }
+}
+
+impl<'a, T: Copy + Debug> YuvBiPlanarImage<'a, T> {
+    /// Returns one field of an interlaced frame stored as `layout`,
+    /// same rules as [YuvPlanarImage::field] apply.
+    pub fn field(
+        &self,
+        field: YuvField,
+        layout: YuvFieldLayout,
+        sampling: YuvChromaSample,
+    ) -> Result<YuvBiPlanarImage<'a, T>, YuvError> {
+        self.check_constraints(sampling)?;
+        check_field_height(self.size, sampling)?;
+        let (_, chroma_height) = chroma_dimensions(self.size, sampling);
+        let (y_plane, y_stride) = field_plane(
+            self.y_plane,
+            self.y_stride,
+            self.size.height,
+            field,
+            layout,
+            self.size,
+        )?;
+        let (uv_plane, uv_stride) = field_plane(
+            self.uv_plane,
+            self.uv_stride,
+            chroma_height,
+            field,
+            layout,
+            self.size,
+        )?;
+        let image = YuvBiPlanarImage {
+            y_plane,
+            y_stride,
+            uv_plane,
+            uv_stride,
+            size: ImageSize::new(self.size.width, self.size.height / 2),
+        };
+        image.check_constraints(sampling)?;
+        Ok(image)
+    }
+}
+
+/// Views raw bytes of a plane as `u16` samples, bytes are kept as they are so samples are
+/// in byte order of the producer and not necessarily native one
+#[cfg(feature = "bytemuck")]
+fn cast_u16_plane(plane: &[u8], stride: RowStride) -> Result<&[u16], YuvError> {
+    if !stride.get().is_multiple_of(2) {
+        return Err(YuvError::PlaneReinterpretationFailure);
+    }
+    bytemuck::try_cast_slice(plane).map_err(|_| YuvError::PlaneReinterpretationFailure)
+}
+
+#[cfg(feature = "bytemuck")]
+fn cast_u16_plane_mut(plane: &mut [u8], stride: RowStride) -> Result<&mut [u16], YuvError> {
+    if !stride.get().is_multiple_of(2) {
+        return Err(YuvError::PlaneReinterpretationFailure);
+    }
+    bytemuck::try_cast_slice_mut(plane).map_err(|_| YuvError::PlaneReinterpretationFailure)
+}
+
+#[cfg(feature = "bytemuck")]
+impl<'a> YuvGrayImage<'a, u16> {
+    /// Views high bit depth luma plane stored in a byte buffer as `u16` samples.
+    ///
+    /// Buffer must be 2-byte aligned and stride must be even. No bytes are swapped,
+    /// pass byte order of the source as `YuvEndianness` to conversion functions.
+    pub fn from_bytes(
+        y_plane: &'a [u8],
+        y_stride: RowStride,
+        size: ImageSize,
+    ) -> Result<Self, YuvError> {
+        let image = YuvGrayImage {
+            y_plane: cast_u16_plane(y_plane, y_stride)?,
+            y_stride,
+            size,
+        };
+        image.check_constraints()?;
+        Ok(image)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<'a> YuvPlanarImage<'a, u16> {
+    /// Views high bit depth planes stored in byte buffers, as produced by most decoders,
+    /// as `u16` samples ready for p10/p16 conversions.
+    ///
+    /// Every buffer must be 2-byte aligned and every stride must be even, planes are
+    /// validated against `size` and `sampling`. No bytes are swapped, samples keep byte order
+    /// of the source, so pass it as `YuvEndianness` to conversion functions: little endian
+    /// for most decoders on every common platform.
+    pub fn from_bytes(
+        y_plane: &'a [u8],
+        y_stride: RowStride,
+        u_plane: &'a [u8],
+        u_stride: RowStride,
+        v_plane: &'a [u8],
+        v_stride: RowStride,
+        size: ImageSize,
+        sampling: YuvChromaSample,
+    ) -> Result<Self, YuvError> {
+        let image = YuvPlanarImage {
+            y_plane: cast_u16_plane(y_plane, y_stride)?,
+            y_stride,
+            u_plane: cast_u16_plane(u_plane, u_stride)?,
+            u_stride,
+            v_plane: cast_u16_plane(v_plane, v_stride)?,
+            v_stride,
+            size,
+        };
+        image.check_constraints(sampling)?;
+        Ok(image)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<'a> YuvPlanarImageMut<'a, u16> {
+    /// Mutable counterpart of [YuvPlanarImage::from_bytes], planes stay borrowed
+    pub fn from_bytes_mut(
+        y_plane: &'a mut [u8],
+        y_stride: RowStride,
+        u_plane: &'a mut [u8],
+        u_stride: RowStride,
+        v_plane: &'a mut [u8],
+        v_stride: RowStride,
+        size: ImageSize,
+        sampling: YuvChromaSample,
+    ) -> Result<Self, YuvError> {
+        let image = YuvPlanarImageMut {
+            y_plane: BufferStoreMut::Borrowed(cast_u16_plane_mut(y_plane, y_stride)?),
+            y_stride,
+            u_plane: BufferStoreMut::Borrowed(cast_u16_plane_mut(u_plane, u_stride)?),
+            u_stride,
+            v_plane: BufferStoreMut::Borrowed(cast_u16_plane_mut(v_plane, v_stride)?),
+            v_stride,
+            size,
+        };
+        image.check_constraints(sampling)?;
+        Ok(image)
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+impl<'a> YuvBiPlanarImage<'a, u16> {
+    /// Views high bit depth bi-planar image ( P010, P016 and friends ) stored in byte buffers
+    /// as `u16` samples, same rules as [YuvPlanarImage::from_bytes] apply.
+    pub fn from_bytes(
+        y_plane: &'a [u8],
+        y_stride: RowStride,
+        uv_plane: &'a [u8],
+        uv_stride: RowStride,
+        size: ImageSize,
+        sampling: YuvChromaSample,
+    ) -> Result<Self, YuvError> {
+        let image = YuvBiPlanarImage {
+            y_plane: cast_u16_plane(y_plane, y_stride)?,
+            y_stride,
+            uv_plane: cast_u16_plane(uv_plane, uv_stride)?,
+            uv_stride,
+            size,
+        };
+        image.check_constraints(sampling)?;
+        Ok(image)
+    }
+}
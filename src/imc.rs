@@ -0,0 +1,361 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride};
+use crate::yuv_error::{
+    check_chroma_channel, check_imc_frame, check_interleaved_chroma_channel,
+    check_rgba_destination, check_y8_channel,
+};
+use crate::yuv_support::{YuvChromaSample, YuvRange, YuvSourceChannels, YuvStandardMatrix};
+use crate::yuv_to_rgba::yuv_to_rgbx_rows;
+use crate::YuvError;
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// Microsoft single buffer YUV 4:2:0 layouts.
+///
+/// Every layout starts with luma rows, chroma rows follow with the same stride as luma.
+pub enum ImcLayout {
+    /// V plane then U plane, chroma rows use only the first half of the stride
+    Imc1,
+    /// Each chroma row holds V samples followed by U samples starting at half stride
+    Imc2,
+    /// U plane then V plane, chroma rows use only the first half of the stride
+    Imc3,
+    /// Each chroma row holds U samples followed by V samples starting at half stride
+    Imc4,
+}
+
+impl ImcLayout {
+    #[inline]
+    const fn split_chroma_rows(self) -> bool {
+        matches!(self, ImcLayout::Imc2 | ImcLayout::Imc4)
+    }
+}
+
+/// Planes of an IMC frame, all of them share the frame stride
+struct ImcPlanes<'a> {
+    y_plane: &'a [u8],
+    u_plane: &'a [u8],
+    v_plane: &'a [u8],
+}
+
+fn imc_planes(
+    imc: &[u8],
+    imc_stride: u32,
+    layout: ImcLayout,
+    width: u32,
+    height: u32,
+) -> Result<ImcPlanes<'_>, YuvError> {
+    check_imc_frame(imc, imc_stride, width, height, layout.split_chroma_rows())?;
+    let stride = imc_stride as usize;
+    let chroma_start = stride * height as usize;
+    let second_start = match layout {
+        ImcLayout::Imc1 | ImcLayout::Imc3 => chroma_start + stride * height.div_ceil(2) as usize,
+        ImcLayout::Imc2 | ImcLayout::Imc4 => chroma_start + stride / 2,
+    };
+    let first = &imc[chroma_start..];
+    let second = &imc[second_start..];
+    let (u_plane, v_plane) = match layout {
+        ImcLayout::Imc1 | ImcLayout::Imc2 => (second, first),
+        ImcLayout::Imc3 | ImcLayout::Imc4 => (first, second),
+    };
+    Ok(ImcPlanes {
+        y_plane: &imc[..chroma_start],
+        u_plane,
+        v_plane,
+    })
+}
+
+/// Convert IMC1, IMC2, IMC3 or IMC4 frame to YUV 420 planar format.
+///
+/// # Arguments
+///
+/// * `imc` - A slice to load IMC frame.
+/// * `imc_stride` - The stride (bytes per row) of the IMC frame, shared by luma and chroma rows.
+/// * `layout` - Layout of the IMC frame.
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `size` - The size of the image.
+///
+pub fn imc_to_yuv420(
+    imc: &[u8],
+    imc_stride: RowStride,
+    layout: ImcLayout,
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    u_plane: &mut [u8],
+    u_stride: RowStride,
+    v_plane: &mut [u8],
+    v_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (imc_stride, y_stride, u_stride, v_stride) = (
+        imc_stride.get(),
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+    );
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, YuvChromaSample::YUV420)?;
+    check_chroma_channel(v_plane, v_stride, width, height, YuvChromaSample::YUV420)?;
+    let planes = imc_planes(imc, imc_stride, layout, width, height)?;
+
+    let stride = imc_stride as usize;
+    let width = width as usize;
+    let chroma_width = width.div_ceil(2);
+
+    for (dst, src) in y_plane
+        .chunks_mut(y_stride as usize)
+        .zip(planes.y_plane.chunks(stride))
+        .take(height as usize)
+    {
+        dst[..width].copy_from_slice(&src[..width]);
+    }
+    for (((u_dst, v_dst), u_src), v_src) in u_plane
+        .chunks_mut(u_stride as usize)
+        .zip(v_plane.chunks_mut(v_stride as usize))
+        .zip(planes.u_plane.chunks(stride))
+        .zip(planes.v_plane.chunks(stride))
+        .take(height.div_ceil(2) as usize)
+    {
+        u_dst[..chroma_width].copy_from_slice(&u_src[..chroma_width]);
+        v_dst[..chroma_width].copy_from_slice(&v_src[..chroma_width]);
+    }
+
+    Ok(())
+}
+
+/// Convert IMC1, IMC2, IMC3 or IMC4 frame to YUV NV12 format.
+///
+/// # Arguments
+///
+/// * `imc` - A slice to load IMC frame.
+/// * `imc_stride` - The stride (bytes per row) of the IMC frame, shared by luma and chroma rows.
+/// * `layout` - Layout of the IMC frame.
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A mutable slice to store the interleaved UV plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `size` - The size of the image.
+///
+pub fn imc_to_yuv_nv12(
+    imc: &[u8],
+    imc_stride: RowStride,
+    layout: ImcLayout,
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    uv_plane: &mut [u8],
+    uv_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (imc_stride, y_stride, uv_stride) = (imc_stride.get(), y_stride.get(), uv_stride.get());
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_interleaved_chroma_channel(uv_plane, uv_stride, width, height, YuvChromaSample::YUV420)?;
+    let planes = imc_planes(imc, imc_stride, layout, width, height)?;
+
+    let stride = imc_stride as usize;
+    let width = width as usize;
+    let chroma_width = width.div_ceil(2);
+
+    for (dst, src) in y_plane
+        .chunks_mut(y_stride as usize)
+        .zip(planes.y_plane.chunks(stride))
+        .take(height as usize)
+    {
+        dst[..width].copy_from_slice(&src[..width]);
+    }
+    for ((uv_dst, u_src), v_src) in uv_plane
+        .chunks_mut(uv_stride as usize)
+        .zip(planes.u_plane.chunks(stride))
+        .zip(planes.v_plane.chunks(stride))
+        .take(height.div_ceil(2) as usize)
+    {
+        for ((uv, &u), &v) in uv_dst
+            .chunks_exact_mut(2)
+            .zip(u_src.iter())
+            .zip(v_src.iter())
+            .take(chroma_width)
+        {
+            uv[0] = u;
+            uv[1] = v;
+        }
+    }
+
+    Ok(())
+}
+
+fn imc_to_rgbx<const DESTINATION_CHANNELS: u8>(
+    imc: &[u8],
+    imc_stride: u32,
+    layout: ImcLayout,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    check_rgba_destination(
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        dst_chans.get_channels_count(),
+    )?;
+    let planes = imc_planes(imc, imc_stride, layout, width, height)?;
+    yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>(
+        planes.y_plane,
+        imc_stride,
+        planes.u_plane,
+        imc_stride,
+        planes.v_plane,
+        imc_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        255,
+    );
+    Ok(())
+}
+
+/// Convert IMC1, IMC2, IMC3 or IMC4 frame to RGB format.
+///
+/// # Arguments
+///
+/// * `imc` - A slice to load IMC frame.
+/// * `imc_stride` - The stride (bytes per row) of the IMC frame, shared by luma and chroma rows.
+/// * `layout` - Layout of the IMC frame.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for RGB data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn imc_to_rgb(
+    imc: &[u8],
+    imc_stride: RowStride,
+    layout: ImcLayout,
+    rgb: &mut [u8],
+    rgb_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (imc_stride, rgb_stride) = (imc_stride.get(), rgb_stride.get());
+    imc_to_rgbx::<{ YuvSourceChannels::Rgb as u8 }>(
+        imc, imc_stride, layout, rgb, rgb_stride, width, height, range, matrix,
+    )
+}
+
+/// Convert IMC1, IMC2, IMC3 or IMC4 frame to RGBA format.
+///
+/// # Arguments
+///
+/// * `imc` - A slice to load IMC frame.
+/// * `imc_stride` - The stride (bytes per row) of the IMC frame, shared by luma and chroma rows.
+/// * `layout` - Layout of the IMC frame.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn imc_to_rgba(
+    imc: &[u8],
+    imc_stride: RowStride,
+    layout: ImcLayout,
+    rgba: &mut [u8],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (imc_stride, rgba_stride) = (imc_stride.get(), rgba_stride.get());
+    imc_to_rgbx::<{ YuvSourceChannels::Rgba as u8 }>(
+        imc,
+        imc_stride,
+        layout,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert IMC1, IMC2, IMC3 or IMC4 frame to BGRA format.
+///
+/// # Arguments
+///
+/// * `imc` - A slice to load IMC frame.
+/// * `imc_stride` - The stride (bytes per row) of the IMC frame, shared by luma and chroma rows.
+/// * `layout` - Layout of the IMC frame.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for BGRA data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn imc_to_bgra(
+    imc: &[u8],
+    imc_stride: RowStride,
+    layout: ImcLayout,
+    bgra: &mut [u8],
+    bgra_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (imc_stride, bgra_stride) = (imc_stride.get(), bgra_stride.get());
+    imc_to_rgbx::<{ YuvSourceChannels::Bgra as u8 }>(
+        imc,
+        imc_stride,
+        layout,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
@@ -33,3 +33,23 @@ pub(crate) struct ProcessedOffset {
     pub cx: usize,
     pub ux: usize,
 }
+
+/// Alignment in bytes that lets AVX-512 kernels use aligned loads and stores
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
+))]
+pub(crate) const SIMD_ALIGNMENT: usize = 64;
+
+/// Checks that every row of `plane` starts at [SIMD_ALIGNMENT] boundary
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
+))]
+#[inline]
+pub(crate) fn is_plane_aligned<T>(plane: &[T], stride: u32) -> bool {
+    (plane.as_ptr() as usize).is_multiple_of(SIMD_ALIGNMENT)
+        && (stride as usize).is_multiple_of(SIMD_ALIGNMENT)
+}
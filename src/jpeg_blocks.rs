@@ -0,0 +1,349 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_vec, ImageSize, RowStride};
+use crate::rgba_to_yuv::rgbx_to_yuv8;
+use crate::yuv_error::{check_rgba_destination, stride_mode, with_stride_mode, MismatchedSize};
+use crate::yuv_support::{YuvChromaSample, YuvRange, YuvSourceChannels, YuvStandardMatrix};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+const BLOCK: usize = 8;
+const BLOCK_SIZE: usize = BLOCK * BLOCK;
+
+/// Luma blocks per MCU horizontally and vertically
+const fn mcu_blocks(sampling: YuvChromaSample) -> (usize, usize) {
+    match sampling {
+        YuvChromaSample::YUV420 => (2, 2),
+        YuvChromaSample::YUV422 => (2, 1),
+        YuvChromaSample::YUV444 => (1, 1),
+    }
+}
+
+/// Returns size in bytes of MCU ordered output for the image, see [rgba_to_yuv_jpeg_blocks].
+///
+/// # Arguments
+///
+/// * `size` - The size of the image.
+/// * `sampling` - Chroma subsampling of the MCU.
+///
+pub const fn jpeg_blocks_size(size: ImageSize, sampling: YuvChromaSample) -> usize {
+    let (width, height) = (size.width, size.height);
+    let (h_blocks, v_blocks) = mcu_blocks(sampling);
+    let mcus_x = (width as usize).div_ceil(h_blocks * BLOCK);
+    let mcus_y = (height as usize).div_ceil(v_blocks * BLOCK);
+    mcus_x * mcus_y * (h_blocks * v_blocks + 2) * BLOCK_SIZE
+}
+
+/// Repeats the last visible column and row of a plane strip up to its padded size
+fn replicate_edges(plane: &mut [u8], stride: usize, visible_width: usize, visible_rows: usize) {
+    for row in plane.chunks_exact_mut(stride).take(visible_rows) {
+        let edge = row[visible_width - 1];
+        row[visible_width..].fill(edge);
+    }
+    let (visible, padding) = plane.split_at_mut(stride * visible_rows);
+    let last_row = &visible[stride * (visible_rows - 1)..];
+    for row in padding.chunks_exact_mut(stride) {
+        row.copy_from_slice(last_row);
+    }
+}
+
+/// Copies 8x8 block at `x` of a plane strip into `dst` in raster order
+#[inline]
+fn store_block(dst: &mut [u8], plane: &[u8], stride: usize, x: usize) {
+    for (dst, src) in dst.chunks_exact_mut(BLOCK).zip(plane.chunks_exact(stride)) {
+        dst.copy_from_slice(&src[x..x + BLOCK]);
+    }
+}
+
+fn rgbx_to_yuv_jpeg_blocks<const ORIGIN_CHANNELS: u8>(
+    blocks: &mut [u8],
+    rgba: &[u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    if width == 0 || height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    // Strips are converted one MCU row at a time, automatic matrix follows the whole frame
+    let matrix = matrix.resolve(width, height);
+    let channels: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    check_rgba_destination(
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        channels.get_channels_count(),
+    )?;
+    let expected = jpeg_blocks_size(ImageSize::new(width, height), sampling);
+    if blocks.len() != expected {
+        return Err(YuvError::DestinationSizeMismatch(MismatchedSize {
+            expected,
+            received: blocks.len(),
+        }));
+    }
+
+    let (h_blocks, v_blocks) = mcu_blocks(sampling);
+    let mcu_width = h_blocks * BLOCK;
+    let mcu_height = v_blocks * BLOCK;
+    let mcus_x = (width as usize).div_ceil(mcu_width);
+    let mcu_bytes = (h_blocks * v_blocks + 2) * BLOCK_SIZE;
+    let padded_width = mcus_x * mcu_width;
+    let chroma_stride = mcus_x * BLOCK;
+    let chroma_width = (width as usize).div_ceil(h_blocks);
+    let rgba_stride = rgba_stride as usize;
+    let height = height as usize;
+
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => {
+            rgbx_to_yuv8::<ORIGIN_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+        }
+        YuvChromaSample::YUV422 => {
+            rgbx_to_yuv8::<ORIGIN_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+        }
+        YuvChromaSample::YUV444 => {
+            rgbx_to_yuv8::<ORIGIN_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+        }
+    };
+
+    // Strips are validated again on workers, the last one ends with the caller's last row
+    let stride_mode = stride_mode();
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = blocks.par_chunks_mut(mcus_x * mcu_bytes);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = blocks.chunks_mut(mcus_x * mcu_bytes);
+    }
+    iter.enumerate().try_for_each(|(mcu_y, mcu_row)| {
+        // Strip of one MCU row is converted into padded planes, then cut into blocks
+        let y0 = mcu_y * mcu_height;
+        let rows = (height - y0).min(mcu_height);
+        let chroma_rows = rows.div_ceil(v_blocks);
+        let mut y_strip = try_vec(0u8, padded_width * mcu_height)?;
+        let mut u_strip = try_vec(0u8, chroma_stride * BLOCK)?;
+        let mut v_strip = try_vec(0u8, chroma_stride * BLOCK)?;
+
+        let rgba_rows = &rgba[y0 * rgba_stride..rgba.len().min((y0 + rows) * rgba_stride)];
+        with_stride_mode(stride_mode, || {
+            handler(
+                &mut y_strip[..padded_width * rows],
+                padded_width as u32,
+                &mut u_strip[..chroma_stride * chroma_rows],
+                chroma_stride as u32,
+                &mut v_strip[..chroma_stride * chroma_rows],
+                chroma_stride as u32,
+                rgba_rows,
+                rgba_stride as u32,
+                width,
+                rows as u32,
+                range,
+                matrix,
+            )
+        })?;
+
+        replicate_edges(&mut y_strip, padded_width, width as usize, rows);
+        replicate_edges(&mut u_strip, chroma_stride, chroma_width, chroma_rows);
+        replicate_edges(&mut v_strip, chroma_stride, chroma_width, chroma_rows);
+
+        for (mcu_x, mcu) in mcu_row.chunks_exact_mut(mcu_bytes).enumerate() {
+            let mut blocks = mcu.chunks_exact_mut(BLOCK_SIZE);
+            for block_y in 0..v_blocks {
+                let luma_rows = &y_strip[block_y * BLOCK * padded_width..];
+                for block_x in 0..h_blocks {
+                    let x = mcu_x * mcu_width + block_x * BLOCK;
+                    store_block(blocks.next().unwrap(), luma_rows, padded_width, x);
+                }
+            }
+            store_block(
+                blocks.next().unwrap(),
+                &u_strip,
+                chroma_stride,
+                mcu_x * BLOCK,
+            );
+            store_block(
+                blocks.next().unwrap(),
+                &v_strip,
+                chroma_stride,
+                mcu_x * BLOCK,
+            );
+        }
+        Ok(())
+    })
+}
+
+/// Convert RGB to YCbCr 8x8 blocks in JPEG MCU order.
+///
+/// Each MCU holds its luma blocks row by row, followed by one Cb and one Cr block,
+/// MCUs go in raster order and samples inside a block are in raster order.
+/// Partial MCUs at the right and bottom edges are filled by repeating edge samples.
+///
+/// # Arguments
+///
+/// * `blocks` - A mutable slice to store blocks, its size must be [jpeg_blocks_size].
+/// * `rgb` - The input RGB image data slice.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `size` - The size of the image.
+/// * `sampling` - Chroma subsampling, MCU is 16x16 for 4:2:0, 16x8 for 4:2:2 and 8x8 for 4:4:4.
+/// * `range` - The YUV range, JPEG uses full range.
+/// * `matrix` - The YUV standard matrix, JPEG uses BT.601.
+///
+pub fn rgb_to_yuv_jpeg_blocks(
+    blocks: &mut [u8],
+    rgb: &[u8],
+    rgb_stride: RowStride,
+    size: ImageSize,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let rgb_stride = rgb_stride.get();
+    rgbx_to_yuv_jpeg_blocks::<{ YuvSourceChannels::Rgb as u8 }>(
+        blocks, rgb, rgb_stride, width, height, sampling, range, matrix,
+    )
+}
+
+/// Convert BGR to YCbCr 8x8 blocks in JPEG MCU order.
+///
+/// Each MCU holds its luma blocks row by row, followed by one Cb and one Cr block,
+/// MCUs go in raster order and samples inside a block are in raster order.
+/// Partial MCUs at the right and bottom edges are filled by repeating edge samples.
+///
+/// # Arguments
+///
+/// * `blocks` - A mutable slice to store blocks, its size must be [jpeg_blocks_size].
+/// * `bgr` - The input BGR image data slice.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `size` - The size of the image.
+/// * `sampling` - Chroma subsampling, MCU is 16x16 for 4:2:0, 16x8 for 4:2:2 and 8x8 for 4:4:4.
+/// * `range` - The YUV range, JPEG uses full range.
+/// * `matrix` - The YUV standard matrix, JPEG uses BT.601.
+///
+pub fn bgr_to_yuv_jpeg_blocks(
+    blocks: &mut [u8],
+    bgr: &[u8],
+    bgr_stride: RowStride,
+    size: ImageSize,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let bgr_stride = bgr_stride.get();
+    rgbx_to_yuv_jpeg_blocks::<{ YuvSourceChannels::Bgr as u8 }>(
+        blocks, bgr, bgr_stride, width, height, sampling, range, matrix,
+    )
+}
+
+/// Convert RGBA to YCbCr 8x8 blocks in JPEG MCU order, alpha is ignored.
+///
+/// Each MCU holds its luma blocks row by row, followed by one Cb and one Cr block,
+/// MCUs go in raster order and samples inside a block are in raster order.
+/// Partial MCUs at the right and bottom edges are filled by repeating edge samples.
+///
+/// # Arguments
+///
+/// * `blocks` - A mutable slice to store blocks, its size must be [jpeg_blocks_size].
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `size` - The size of the image.
+/// * `sampling` - Chroma subsampling, MCU is 16x16 for 4:2:0, 16x8 for 4:2:2 and 8x8 for 4:4:4.
+/// * `range` - The YUV range, JPEG uses full range.
+/// * `matrix` - The YUV standard matrix, JPEG uses BT.601.
+///
+pub fn rgba_to_yuv_jpeg_blocks(
+    blocks: &mut [u8],
+    rgba: &[u8],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let rgba_stride = rgba_stride.get();
+    rgbx_to_yuv_jpeg_blocks::<{ YuvSourceChannels::Rgba as u8 }>(
+        blocks,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        sampling,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA to YCbCr 8x8 blocks in JPEG MCU order, alpha is ignored.
+///
+/// Each MCU holds its luma blocks row by row, followed by one Cb and one Cr block,
+/// MCUs go in raster order and samples inside a block are in raster order.
+/// Partial MCUs at the right and bottom edges are filled by repeating edge samples.
+///
+/// # Arguments
+///
+/// * `blocks` - A mutable slice to store blocks, its size must be [jpeg_blocks_size].
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `size` - The size of the image.
+/// * `sampling` - Chroma subsampling, MCU is 16x16 for 4:2:0, 16x8 for 4:2:2 and 8x8 for 4:4:4.
+/// * `range` - The YUV range, JPEG uses full range.
+/// * `matrix` - The YUV standard matrix, JPEG uses BT.601.
+///
+pub fn bgra_to_yuv_jpeg_blocks(
+    blocks: &mut [u8],
+    bgra: &[u8],
+    bgra_stride: RowStride,
+    size: ImageSize,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let bgra_stride = bgra_stride.get();
+    rgbx_to_yuv_jpeg_blocks::<{ YuvSourceChannels::Bgra as u8 }>(
+        blocks,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        sampling,
+        range,
+        matrix,
+    )
+}
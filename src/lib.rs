@@ -31,32 +31,102 @@
 #![cfg_attr(feature = "nightly_avx512", feature(cfg_version))]
 #![cfg_attr(feature = "nightly_avx512", feature(avx512_target_feature))]
 #![cfg_attr(feature = "nightly_avx512", feature(stdarch_x86_avx512))]
+#![cfg_attr(feature = "safe-only", forbid(unsafe_code))]
+// Row counters are advanced only by SIMD kernels, which are compiled out in safe builds
+#![cfg_attr(feature = "safe-only", allow(clippy::explicit_counter_loop))]
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod atlas;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 mod avx2;
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
-    feature = "nightly_avx512"
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
 ))]
 mod avx512bw;
+mod batch_tensor;
+mod bayer;
+mod blend_mask;
+mod blend_nv;
+mod chroma_key;
+mod chroma_swap;
+mod color_description;
+mod conversion_context;
+mod cpu_features;
+mod decimate;
+mod fast_approx;
+mod film_grain;
+pub mod fourcc;
+mod frame_pool;
+#[cfg(feature = "frame-hash")]
+mod frame_reuse;
 mod from_identity;
 mod from_identity_p16;
+mod gray_planar;
+mod horizontal_scale;
+mod hue_rotation;
+mod images;
+mod imc;
 mod internals;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+mod jpeg_blocks;
+pub mod libyuv_compat;
+mod luma_levels;
+mod luma_transfer;
+mod lut3d;
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 mod neon;
+pub mod numerics;
+mod nv_preview;
+mod nv_to_yuv444;
+mod palette;
+mod pixel_iter;
+mod planar_nv;
+mod plane_depth;
+#[cfg(feature = "profiling")]
+mod profiling;
+mod pyramid;
+mod raw10;
+mod reorder_channels;
+mod rgb30;
 mod rgb_to_nv_p16;
 mod rgb_to_y;
 mod rgb_to_ycgco;
 mod rgb_to_ycgco_r;
 mod rgb_to_yuv_p16;
+mod rgba64;
 mod rgba_to_nv;
 mod rgba_to_yuv;
+mod scratch;
 mod sharpyuv;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 mod sse;
+mod st2110;
+mod storage_order;
+mod texture_upload;
+mod throughput;
+mod tiled_nv;
 mod to_identity;
-#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+mod uyva;
+mod v410;
+mod vec_convert;
+mod verify_backends;
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    not(feature = "safe-only")
+))]
 mod wasm32;
+mod y216;
 mod y_p16_to_rgb16;
 mod y_p16_with_alpha_to_rgb16;
 mod y_to_rgb;
@@ -65,27 +135,53 @@ mod ycgco_r_to_rgb;
 mod ycgco_to_rgb;
 mod ycgco_to_rgb_alpha;
 mod ycgcor_support;
+mod yuv420_to_yuv422;
 mod yuv_error;
+mod yuv_lut;
 mod yuv_nv_p10_to_rgba;
 mod yuv_nv_p16_to_rgb;
 mod yuv_nv_to_rgba;
+mod yuv_p10_ar30;
 mod yuv_p10_rgba;
+mod yuv_p10_rgba16_rgba8;
 mod yuv_p16_rgba;
 mod yuv_p16_rgba16_alpha;
 mod yuv_p16_rgba_alpha;
 mod yuv_p16_rgba_p16;
+mod yuv_planar_generic;
+mod yuv_rotate;
 mod yuv_support;
+mod yuv_to_gray;
 mod yuv_to_rgba;
+mod yuv_to_rgba16;
 mod yuv_to_rgba_alpha;
+mod yuv_to_rgba_f32;
+mod yuv_to_xyz;
 mod yuv_to_yuy2;
 mod yuv_to_yuy2_p16;
+mod yuy2_nv;
 mod yuy2_to_rgb;
 mod yuy2_to_rgb_p16;
 mod yuy2_to_yuv;
 mod yuy2_to_yuv_p16;
+mod yuy2_transform;
 
+pub use conversion_context::{YuvConversionContext, YuvConvertedRows, YuvRowBand};
+pub use frame_pool::{YuvConvertedFrame, YuvFramePool, YuvFramePoolLayout};
+pub use images::{
+    BufferStoreMut, ImageSize, RowStride, YuvBiPlanarImage, YuvBiPlanarImageMut, YuvField,
+    YuvFieldLayout, YuvGrayAlphaImage, YuvGrayAlphaImageMut, YuvGrayImage, YuvGrayImageMut,
+    YuvPlanarImage, YuvPlanarImageMut,
+};
+pub use pixel_iter::{YuvPixel, YuvPixels};
+pub use yuv_support::Rgb30;
+pub use yuv_support::Rgb30Padding;
+pub use yuv_support::YuvBroadcastSafe;
 pub use yuv_support::YuvBytesPacking;
+pub use yuv_support::YuvChromaSample;
 pub use yuv_support::YuvEndianness;
+pub use yuv_support::YuvNVOrder;
+pub use yuv_support::YuvOvershoot;
 pub use yuv_support::YuvRange;
 pub use yuv_support::YuvStandardMatrix;
 
@@ -101,6 +197,14 @@ pub use yuv_nv_p10_to_rgba::yuv_nv21_p10_to_bgr;
 pub use yuv_nv_p10_to_rgba::yuv_nv21_p10_to_bgra;
 pub use yuv_nv_p10_to_rgba::yuv_nv21_p10_to_rgb;
 pub use yuv_nv_p10_to_rgba::yuv_nv21_p10_to_rgba;
+pub use yuv_nv_p10_to_rgba::yuv_nv24_p10_to_bgr;
+pub use yuv_nv_p10_to_rgba::yuv_nv24_p10_to_bgra;
+pub use yuv_nv_p10_to_rgba::yuv_nv24_p10_to_rgb;
+pub use yuv_nv_p10_to_rgba::yuv_nv24_p10_to_rgba;
+pub use yuv_nv_p10_to_rgba::yuv_nv42_p10_to_bgr;
+pub use yuv_nv_p10_to_rgba::yuv_nv42_p10_to_bgra;
+pub use yuv_nv_p10_to_rgba::yuv_nv42_p10_to_rgb;
+pub use yuv_nv_p10_to_rgba::yuv_nv42_p10_to_rgba;
 pub use yuv_nv_p10_to_rgba::yuv_nv61_p10_to_bgr;
 pub use yuv_nv_p10_to_rgba::yuv_nv61_p10_to_bgra;
 pub use yuv_nv_p10_to_rgba::yuv_nv61_p10_to_rgb;
@@ -118,28 +222,52 @@ pub use yuv_nv_p16_to_rgb::yuv_nv21_to_bgr_p16;
 pub use yuv_nv_p16_to_rgb::yuv_nv21_to_bgra_p16;
 pub use yuv_nv_p16_to_rgb::yuv_nv21_to_rgb_p16;
 pub use yuv_nv_p16_to_rgb::yuv_nv21_to_rgba_p16;
+pub use yuv_nv_p16_to_rgb::yuv_nv24_to_bgr_p16;
+pub use yuv_nv_p16_to_rgb::yuv_nv24_to_bgra_p16;
+pub use yuv_nv_p16_to_rgb::yuv_nv24_to_rgb_p16;
+pub use yuv_nv_p16_to_rgb::yuv_nv24_to_rgba_p16;
+pub use yuv_nv_p16_to_rgb::yuv_nv42_to_bgr_p16;
+pub use yuv_nv_p16_to_rgb::yuv_nv42_to_bgra_p16;
+pub use yuv_nv_p16_to_rgb::yuv_nv42_to_rgb_p16;
+pub use yuv_nv_p16_to_rgb::yuv_nv42_to_rgba_p16;
 pub use yuv_nv_p16_to_rgb::yuv_nv61_to_bgr_p16;
 pub use yuv_nv_p16_to_rgb::yuv_nv61_to_bgra_p16;
 pub use yuv_nv_p16_to_rgb::yuv_nv61_to_rgb_p16;
 pub use yuv_nv_p16_to_rgb::yuv_nv61_to_rgba_p16;
+pub use yuv_nv_p16_to_rgb::{
+    yuv_nv12_p10_to_rgba16, yuv_nv12_p16_to_rgba16, yuv_p010_to_rgba16, yuv_p016_to_rgba16,
+};
 
 pub use yuv_nv_to_rgba::yuv_nv12_to_bgr;
 pub use yuv_nv_to_rgba::yuv_nv12_to_bgra;
+pub use yuv_nv_to_rgba::yuv_nv12_to_bgra_bt601_tv;
+pub use yuv_nv_to_rgba::yuv_nv12_to_bgra_bt709_tv;
+pub use yuv_nv_to_rgba::yuv_nv12_to_bgra_with_constant_alpha;
 pub use yuv_nv_to_rgba::yuv_nv12_to_rgb;
 pub use yuv_nv_to_rgba::yuv_nv12_to_rgba;
+pub use yuv_nv_to_rgba::yuv_nv12_to_rgba_bt601_tv;
+pub use yuv_nv_to_rgba::yuv_nv12_to_rgba_bt709_tv;
+pub use yuv_nv_to_rgba::yuv_nv12_to_rgba_with_constant_alpha;
 pub use yuv_nv_to_rgba::yuv_nv21_to_bgr;
 pub use yuv_nv_to_rgba::yuv_nv21_to_bgra;
+pub use yuv_nv_to_rgba::yuv_nv21_to_bgra_with_constant_alpha;
 pub use yuv_nv_to_rgba::yuv_nv21_to_rgb;
 pub use yuv_nv_to_rgba::yuv_nv21_to_rgba;
+pub use yuv_nv_to_rgba::yuv_nv21_to_rgba_with_constant_alpha;
 pub use yuv_nv_to_rgba::yuv_nv24_to_bgr;
 pub use yuv_nv_to_rgba::yuv_nv24_to_bgra;
+pub use yuv_nv_to_rgba::yuv_nv24_to_bgra_with_constant_alpha;
 pub use yuv_nv_to_rgba::yuv_nv24_to_rgb;
 pub use yuv_nv_to_rgba::yuv_nv24_to_rgba;
+pub use yuv_nv_to_rgba::yuv_nv24_to_rgba_with_constant_alpha;
 pub use yuv_nv_to_rgba::yuv_nv42_to_bgr;
 pub use yuv_nv_to_rgba::yuv_nv42_to_bgra;
+pub use yuv_nv_to_rgba::yuv_nv42_to_bgra_with_constant_alpha;
 pub use yuv_nv_to_rgba::yuv_nv42_to_rgb;
 pub use yuv_nv_to_rgba::yuv_nv42_to_rgba;
+pub use yuv_nv_to_rgba::yuv_nv42_to_rgba_with_constant_alpha;
 
+pub use nv_preview::{bgra_to_yuv_nv12_with_preview, rgba_to_yuv_nv12_with_preview};
 pub use rgba_to_nv::bgr_to_yuv_nv12;
 pub use rgba_to_nv::bgr_to_yuv_nv16;
 pub use rgba_to_nv::bgr_to_yuv_nv21;
@@ -167,29 +295,89 @@ pub use rgba_to_nv::rgba_to_yuv_nv61;
 
 pub use yuv_to_rgba::yuv420_to_bgr;
 pub use yuv_to_rgba::yuv420_to_bgra;
+pub use yuv_to_rgba::yuv420_to_bgra_with_constant_alpha;
+pub use yuv_to_rgba::yuv420_to_bgrx;
 pub use yuv_to_rgba::yuv420_to_rgb;
 pub use yuv_to_rgba::yuv420_to_rgba;
+pub use yuv_to_rgba::yuv420_to_rgba_with_constant_alpha;
+pub use yuv_to_rgba::yuv420_to_rgbx;
 pub use yuv_to_rgba::yuv422_to_bgr;
 pub use yuv_to_rgba::yuv422_to_bgra;
+pub use yuv_to_rgba::yuv422_to_bgra_with_constant_alpha;
+pub use yuv_to_rgba::yuv422_to_bgrx;
 pub use yuv_to_rgba::yuv422_to_rgb;
 pub use yuv_to_rgba::yuv422_to_rgba;
+pub use yuv_to_rgba::yuv422_to_rgba_with_constant_alpha;
+pub use yuv_to_rgba::yuv422_to_rgbx;
 pub use yuv_to_rgba::yuv444_to_bgr;
 pub use yuv_to_rgba::yuv444_to_bgra;
+pub use yuv_to_rgba::yuv444_to_bgra_with_constant_alpha;
+pub use yuv_to_rgba::yuv444_to_bgrx;
 pub use yuv_to_rgba::yuv444_to_rgb;
 pub use yuv_to_rgba::yuv444_to_rgba;
+pub use yuv_to_rgba::yuv444_to_rgba_with_constant_alpha;
+pub use yuv_to_rgba::yuv444_to_rgbx;
+pub use yuv_to_rgba::yv12_to_bgr;
+pub use yuv_to_rgba::yv12_to_bgra;
+pub use yuv_to_rgba::yv12_to_rgb;
+pub use yuv_to_rgba::yv12_to_rgba;
+pub use yuv_to_rgba::yv16_to_bgr;
+pub use yuv_to_rgba::yv16_to_bgra;
+pub use yuv_to_rgba::yv16_to_rgb;
+pub use yuv_to_rgba::yv16_to_rgba;
+pub use yuv_to_rgba::yv24_to_bgr;
+pub use yuv_to_rgba::yv24_to_bgra;
+pub use yuv_to_rgba::yv24_to_rgb;
+pub use yuv_to_rgba::yv24_to_rgba;
+pub use yuv_to_rgba16::yuv420_to_rgb16;
+pub use yuv_to_rgba16::yuv420_to_rgba16;
+pub use yuv_to_rgba16::yuv422_to_rgb16;
+pub use yuv_to_rgba16::yuv422_to_rgba16;
+pub use yuv_to_rgba16::yuv444_to_rgb16;
+pub use yuv_to_rgba16::yuv444_to_rgba16;
+pub use yuv_to_rgba16::yuv_p16_to_rgba16_with_overshoot;
+pub use yuv_to_rgba16::yuv_to_rgba16_with_overshoot;
 
 pub use rgba_to_yuv::bgr_to_yuv420;
 pub use rgba_to_yuv::bgr_to_yuv422;
 pub use rgba_to_yuv::bgr_to_yuv444;
+pub use rgba_to_yuv::bgr_to_yv12;
+pub use rgba_to_yuv::bgr_to_yv16;
+pub use rgba_to_yuv::bgr_to_yv24;
+pub use rgba_to_yuv::bgra_rows_to_yuv420_rows;
 pub use rgba_to_yuv::bgra_to_yuv420;
+pub use rgba_to_yuv::bgra_to_yuv420_with_pixel_stride;
 pub use rgba_to_yuv::bgra_to_yuv422;
+pub use rgba_to_yuv::bgra_to_yuv422_with_pixel_stride;
 pub use rgba_to_yuv::bgra_to_yuv444;
+pub use rgba_to_yuv::bgra_to_yuv444_with_pixel_stride;
+pub use rgba_to_yuv::bgra_to_yuv_broadcast_safe;
+pub use rgba_to_yuv::bgra_to_yv12;
+pub use rgba_to_yuv::bgra_to_yv16;
+pub use rgba_to_yuv::bgra_to_yv24;
+pub use rgba_to_yuv::rgb_rows_to_yuv420_rows;
 pub use rgba_to_yuv::rgb_to_yuv420;
 pub use rgba_to_yuv::rgb_to_yuv422;
 pub use rgba_to_yuv::rgb_to_yuv444;
+pub use rgba_to_yuv::rgb_to_yuv_broadcast_safe;
+pub use rgba_to_yuv::rgb_to_yv12;
+pub use rgba_to_yuv::rgb_to_yv16;
+pub use rgba_to_yuv::rgb_to_yv24;
+pub use rgba_to_yuv::rgba_rows_to_yuv420_rows;
 pub use rgba_to_yuv::rgba_to_yuv420;
+pub use rgba_to_yuv::rgba_to_yuv420_with_pixel_stride;
 pub use rgba_to_yuv::rgba_to_yuv422;
+pub use rgba_to_yuv::rgba_to_yuv422_with_pixel_stride;
 pub use rgba_to_yuv::rgba_to_yuv444;
+pub use rgba_to_yuv::rgba_to_yuv444_with_pixel_stride;
+pub use rgba_to_yuv::rgba_to_yuv_broadcast_safe;
+pub use rgba_to_yuv::rgba_to_yv12;
+pub use rgba_to_yuv::rgba_to_yv16;
+pub use rgba_to_yuv::rgba_to_yv24;
+
+pub use rgba64::{
+    rgba64_be_to_yuv_p16, rgba64_le_to_yuv_p16, yuv_p16_to_rgba64_be, yuv_p16_to_rgba64_le,
+};
 
 pub use rgb_to_yuv_p16::bgr_to_yuv420_p16;
 pub use rgb_to_yuv_p16::bgr_to_yuv422_p16;
@@ -203,6 +391,10 @@ pub use rgb_to_yuv_p16::rgb_to_yuv444_p16;
 pub use rgb_to_yuv_p16::rgba_to_yuv420_p16;
 pub use rgb_to_yuv_p16::rgba_to_yuv422_p16;
 pub use rgb_to_yuv_p16::rgba_to_yuv444_p16;
+pub use rgb_to_yuv_p16::{
+    bgr16_to_yuv422_p16, bgr16_to_yuv444_p16, bgra16_to_yuv422_p16, bgra16_to_yuv444_p16,
+    rgb16_to_yuv422_p16, rgb16_to_yuv444_p16, rgba16_to_yuv422_p16, rgba16_to_yuv444_p16,
+};
 
 pub use yuv_to_rgba_alpha::yuv420_with_alpha_to_bgra;
 pub use yuv_to_rgba_alpha::yuv420_with_alpha_to_rgba;
@@ -210,16 +402,45 @@ pub use yuv_to_rgba_alpha::yuv422_with_alpha_to_bgra;
 pub use yuv_to_rgba_alpha::yuv422_with_alpha_to_rgba;
 pub use yuv_to_rgba_alpha::yuv444_with_alpha_to_bgra;
 pub use yuv_to_rgba_alpha::yuv444_with_alpha_to_rgba;
+pub use yuv_to_rgba_f32::yuv420_to_bgr_f32;
+pub use yuv_to_rgba_f32::yuv420_to_bgra_f32;
+pub use yuv_to_rgba_f32::yuv420_to_rgb_f32;
+pub use yuv_to_rgba_f32::yuv420_to_rgba_f32;
+pub use yuv_to_rgba_f32::yuv422_to_bgr_f32;
+pub use yuv_to_rgba_f32::yuv422_to_bgra_f32;
+pub use yuv_to_rgba_f32::yuv422_to_rgb_f32;
+pub use yuv_to_rgba_f32::yuv422_to_rgba_f32;
+pub use yuv_to_rgba_f32::yuv444_to_bgr_f32;
+pub use yuv_to_rgba_f32::yuv444_to_bgra_f32;
+pub use yuv_to_rgba_f32::yuv444_to_rgb_f32;
+pub use yuv_to_rgba_f32::yuv444_to_rgba_f32;
+pub use yuv_to_xyz::{
+    yuv420_to_lab, yuv420_to_xyz, yuv422_to_lab, yuv422_to_xyz, yuv444_to_lab, yuv444_to_xyz,
+};
 
 pub use rgb_to_y::bgr_to_yuv400;
+pub use rgb_to_y::bgr_to_yuv400_in_place;
 pub use rgb_to_y::bgra_to_yuv400;
+pub use rgb_to_y::bgra_to_yuv400_in_place;
 pub use rgb_to_y::rgb_to_yuv400;
+pub use rgb_to_y::rgb_to_yuv400_in_place;
 pub use rgb_to_y::rgba_to_yuv400;
+pub use rgb_to_y::rgba_to_yuv400_in_place;
 pub use y_to_rgb::yuv400_to_bgr;
 pub use y_to_rgb::yuv400_to_bgra;
 pub use y_to_rgb::yuv400_to_rgb;
 pub use y_to_rgb::yuv400_to_rgba;
 
+pub use rgb30::{
+    ar30_to_rgba16, ar30_to_rgba8, ra30_to_rgb, rgb30_to_rgb, yuv_nv12_to_rgb30, yuv_p010_to_rgb30,
+    yuv_p10_to_rgb30,
+};
+pub use yuv_p10_ar30::yuv420_p10_to_ar30;
+pub use yuv_p10_ar30::yuv420_p10_with_alpha_to_ar30;
+pub use yuv_p10_ar30::yuv422_p10_to_ar30;
+pub use yuv_p10_ar30::yuv422_p10_with_alpha_to_ar30;
+pub use yuv_p10_ar30::yuv444_p10_to_ar30;
+pub use yuv_p10_ar30::yuv444_p10_with_alpha_to_ar30;
 pub use yuv_p10_rgba::yuv420_p10_to_bgr;
 pub use yuv_p10_rgba::yuv420_p10_to_bgra;
 pub use yuv_p10_rgba::yuv420_p10_to_rgb;
@@ -232,6 +453,9 @@ pub use yuv_p10_rgba::yuv444_p10_to_bgr;
 pub use yuv_p10_rgba::yuv444_p10_to_bgra;
 pub use yuv_p10_rgba::yuv444_p10_to_rgb;
 pub use yuv_p10_rgba::yuv444_p10_to_rgba;
+pub use yuv_p10_rgba16_rgba8::yuv420_p10_to_rgba16_with_rgba8;
+pub use yuv_p10_rgba16_rgba8::yuv422_p10_to_rgba16_with_rgba8;
+pub use yuv_p10_rgba16_rgba8::yuv444_p10_to_rgba16_with_rgba8;
 
 pub use rgb_to_ycgco::bgr_to_ycgco420;
 pub use rgb_to_ycgco::bgr_to_ycgco422;
@@ -259,14 +483,30 @@ pub use ycgco_to_rgb::ycgco444_to_bgra;
 pub use ycgco_to_rgb::ycgco444_to_rgb;
 pub use ycgco_to_rgb::ycgco444_to_rgba;
 
+pub use yuv_nv_to_rgba::yuv_nv12_to_bgrx;
+pub use yuv_nv_to_rgba::yuv_nv12_to_rgbx;
 pub use yuv_nv_to_rgba::yuv_nv16_to_bgr;
 pub use yuv_nv_to_rgba::yuv_nv16_to_bgra;
+pub use yuv_nv_to_rgba::yuv_nv16_to_bgra_with_constant_alpha;
+pub use yuv_nv_to_rgba::yuv_nv16_to_bgrx;
 pub use yuv_nv_to_rgba::yuv_nv16_to_rgb;
 pub use yuv_nv_to_rgba::yuv_nv16_to_rgba;
+pub use yuv_nv_to_rgba::yuv_nv16_to_rgba_with_constant_alpha;
+pub use yuv_nv_to_rgba::yuv_nv16_to_rgbx;
+pub use yuv_nv_to_rgba::yuv_nv21_to_bgrx;
+pub use yuv_nv_to_rgba::yuv_nv21_to_rgbx;
+pub use yuv_nv_to_rgba::yuv_nv24_to_bgrx;
+pub use yuv_nv_to_rgba::yuv_nv24_to_rgbx;
+pub use yuv_nv_to_rgba::yuv_nv42_to_bgrx;
+pub use yuv_nv_to_rgba::yuv_nv42_to_rgbx;
 pub use yuv_nv_to_rgba::yuv_nv61_to_bgr;
 pub use yuv_nv_to_rgba::yuv_nv61_to_bgra;
+pub use yuv_nv_to_rgba::yuv_nv61_to_bgra_with_constant_alpha;
+pub use yuv_nv_to_rgba::yuv_nv61_to_bgrx;
 pub use yuv_nv_to_rgba::yuv_nv61_to_rgb;
 pub use yuv_nv_to_rgba::yuv_nv61_to_rgba;
+pub use yuv_nv_to_rgba::yuv_nv61_to_rgba_with_constant_alpha;
+pub use yuv_nv_to_rgba::yuv_nv61_to_rgbx;
 
 pub use ycgco_to_rgb_alpha::ycgco420_with_alpha_to_bgra;
 pub use ycgco_to_rgb_alpha::ycgco420_with_alpha_to_rgba;
@@ -338,6 +578,14 @@ pub use rgb_to_nv_p16::rgba_to_yuv_nv21_p16;
 pub use rgb_to_nv_p16::rgba_to_yuv_nv24_p16;
 pub use rgb_to_nv_p16::rgba_to_yuv_nv42_p16;
 pub use rgb_to_nv_p16::rgba_to_yuv_nv61_p16;
+pub use rgb_to_nv_p16::{
+    rgb16_to_p010, rgb16_to_p210, rgb16_to_p410, rgba16_to_p010, rgba16_to_p210, rgba16_to_p410,
+};
+
+pub use uyva::{
+    bgra_to_uyva, rgba_to_uyva, uyva_to_bgra, uyva_to_rgba, uyva_to_yuva422, uyva_to_yuva444,
+    yuva422_to_uyva, yuva444_to_uyva,
+};
 
 pub use yuy2_to_rgb::uyvy422_to_bgr;
 pub use yuy2_to_rgb::uyvy422_to_bgra;
@@ -382,6 +630,15 @@ pub use yuv_to_yuy2_p16::yuv444_to_vyuy422_p16;
 pub use yuv_to_yuy2_p16::yuv444_to_yuyv422_p16;
 pub use yuv_to_yuy2_p16::yuv444_to_yvyu422_p16;
 
+pub use y216::i216_to_y216;
+pub use y216::p216_to_y216;
+pub use y216::y216_to_bgr16;
+pub use y216::y216_to_bgra16;
+pub use y216::y216_to_i216;
+pub use y216::y216_to_p216;
+pub use y216::y216_to_rgb16;
+pub use y216::y216_to_rgba16;
+
 pub use yuy2_to_rgb_p16::uyvy422_to_bgr_p16;
 pub use yuy2_to_rgb_p16::uyvy422_to_bgra_p16;
 pub use yuy2_to_rgb_p16::uyvy422_to_rgb_p16;
@@ -405,20 +662,215 @@ pub use from_identity_p16::gbr_to_rgb_p16;
 pub use from_identity_p16::gbr_to_rgba_p16;
 
 pub use sharpyuv::bgr_to_sharp_yuv420;
+pub use sharpyuv::bgr_to_sharp_yuv420_with_scratch;
 pub use sharpyuv::bgr_to_sharp_yuv422;
+pub use sharpyuv::bgr_to_sharp_yuv422_with_scratch;
 pub use sharpyuv::bgra_to_sharp_yuv420;
+pub use sharpyuv::bgra_to_sharp_yuv420_with_scratch;
 pub use sharpyuv::bgra_to_sharp_yuv422;
+pub use sharpyuv::bgra_to_sharp_yuv422_with_scratch;
 pub use sharpyuv::rgb_to_sharp_yuv420;
+pub use sharpyuv::rgb_to_sharp_yuv420_with_scratch;
 pub use sharpyuv::rgb_to_sharp_yuv422;
+pub use sharpyuv::rgb_to_sharp_yuv422_with_scratch;
 pub use sharpyuv::rgba_to_sharp_yuv420;
+pub use sharpyuv::rgba_to_sharp_yuv420_with_scratch;
 pub use sharpyuv::rgba_to_sharp_yuv422;
+pub use sharpyuv::rgba_to_sharp_yuv422_with_scratch;
+pub use sharpyuv::sharp_yuv_scratch_size;
 pub use sharpyuv::SharpYuvGammaTransfer;
 
 pub use y_p16_to_rgb16::*;
 pub use y_p16_with_alpha_to_rgb16::*;
 pub use y_with_alpha_to_rgb::*;
 pub use yuv_error::YuvError;
+pub use yuv_error::{stride_mode, with_stride_mode, YuvStrideMode};
 pub use yuv_p16_rgba::*;
 pub use yuv_p16_rgba16_alpha::*;
 pub use yuv_p16_rgba_alpha::*;
 pub use yuv_p16_rgba_p16::*;
+
+pub use chroma_swap::swap_chroma_planes;
+
+pub use blend_mask::blend_mask_yuv;
+pub use blend_mask::blend_mask_yuv_nv12;
+pub use blend_mask::blend_mask_yuv_nv16;
+pub use blend_mask::blend_mask_yuv_nv21;
+pub use blend_mask::blend_mask_yuv_nv24;
+pub use blend_mask::blend_mask_yuv_nv42;
+pub use blend_mask::blend_mask_yuv_nv61;
+pub use blend_mask::YuvColor;
+
+pub use blend_nv::blend_bgra_over_nv12;
+pub use blend_nv::blend_bgra_over_nv21;
+pub use blend_nv::blend_rgba_over_nv12;
+pub use blend_nv::blend_rgba_over_nv21;
+pub use chroma_key::chroma_key_yuv_to_bgra;
+pub use chroma_key::chroma_key_yuv_to_rgba;
+pub use palette::{blend_palette8_over_nv12, blend_palette8_over_nv21, palette8_to_yuva};
+
+pub use batch_tensor::{
+    yuv420_p10_to_rgb_f32_chw, yuv420_to_rgb_f32_chw, yuv_batch_to_rgb_tensor, TensorLayout,
+    TensorNormalization,
+};
+pub use bayer::{
+    bayer16_to_yuv420, bayer16_to_yuv_nv12, bayer_to_yuv420, bayer_to_yuv_nv12, BayerDemosaic,
+    BayerPattern,
+};
+pub use lut3d::{
+    apply_lut3d_bgra, apply_lut3d_rgb, apply_lut3d_rgba, yuv_to_bgra_with_lut3d,
+    yuv_to_rgb_with_lut3d, yuv_to_rgba_with_lut3d, Lut3d, Lut3dInterpolation,
+};
+pub use yuv_lut::{
+    yuv_p10_to_rgb16_with_lut, yuv_p10_to_rgba16_with_lut, yuv_to_bgra_with_lut,
+    yuv_to_rgb_with_lut, yuv_to_rgba_with_lut, YuvRgbLut,
+};
+
+pub use atlas::{
+    yuv_nv12_to_bgra_at_offset, yuv_nv12_to_rgba_at_offset, yuv_to_bgra_at_offset,
+    yuv_to_rgba_at_offset,
+};
+
+pub use yuv_rotate::{
+    yuv_nv12_to_bgra_rotated, yuv_nv12_to_rgba_rotated, yuv_to_bgra_rotated, yuv_to_rgba_rotated,
+    YuvRotation,
+};
+
+pub use color_description::bgr_to_yuv_with_description;
+pub use color_description::bgra_to_yuv_with_description;
+pub use color_description::rgb_to_yuv_with_description;
+pub use color_description::rgba_to_yuv_with_description;
+pub use color_description::yuv_to_bgr_with_description;
+pub use color_description::yuv_to_bgra_with_description;
+pub use color_description::yuv_to_rgb_with_description;
+pub use color_description::yuv_to_rgba_with_description;
+pub use color_description::{
+    CicpTag, ColorDescription, RgbExportTags, YuvChromaSiting, YuvColorPrimaries,
+    YuvMatrixCoefficients, YuvTransferCharacteristics,
+};
+
+pub use jpeg_blocks::{
+    bgr_to_yuv_jpeg_blocks, bgra_to_yuv_jpeg_blocks, jpeg_blocks_size, rgb_to_yuv_jpeg_blocks,
+    rgba_to_yuv_jpeg_blocks,
+};
+
+pub use scratch::YuvScratchArena;
+
+pub use reorder_channels::{reorder_channels, ChannelLayout};
+
+pub use plane_depth::{
+    plane_u16_to_u8_shifted, plane_u8_to_u16_shifted, yuv_nv_p16_to_yuv_nv12, yuv_p010_to_yuv_nv12,
+};
+pub use raw10::{pack_raw10_plane, unpack_raw10_plane};
+
+pub use nv_to_yuv444::{
+    yuv_nv12_to_yuv444, yuv_nv21_to_yuv444, yuv_p010_to_yuv444_p10, YuvChromaUpsampling,
+};
+
+pub use fast_approx::{
+    bgra_to_yuv_fastest, rgb_to_yuv_fastest, rgba_to_yuv_fastest, yuv_to_bgra_fastest,
+    yuv_to_rgb_fastest, yuv_to_rgba_fastest, YuvConversionMode,
+};
+
+pub use storage_order::{
+    yuv_column_major_to_row_major, yuv_p16_column_major_to_row_major,
+    yuv_to_bgra_with_storage_order, yuv_to_rgb_with_storage_order, yuv_to_rgba_with_storage_order,
+    YuvStorageOrder,
+};
+
+pub use yuv420_to_yuv422::{
+    yuv420_p16_to_yuv422_p16, yuv420_to_yuv422, yuv_nv12_to_yuv_nv16, yuv_p010_to_yuv_p210,
+};
+
+pub use film_grain::{
+    yuv_to_bgra_with_grain, yuv_to_rgb_with_grain, yuv_to_rgba_with_grain, YuvGrainPlane,
+};
+
+pub use texture_upload::{yuv_to_bgra_texture, yuv_to_rgba_texture, YuvTextureOptions};
+
+pub use st2110::{pgroup422_p10_to_rgba, pgroup422_p10_to_yuv422_p10, yuv422_p10_to_pgroup422_p10};
+
+pub use hue_rotation::{rotate_hue_yuv, rotate_hue_yuv_nv12, rotate_hue_yuv_nv21};
+
+pub use horizontal_scale::{
+    yuv_to_bgra_horizontal_scale, yuv_to_rgb_horizontal_scale, yuv_to_rgba_horizontal_scale,
+    HorizontalScale,
+};
+
+pub use vec_convert::{
+    decode_nv12_to_rgba_vec, decode_nv21_to_rgba_vec, decode_yuv420_to_rgba_vec,
+    decode_yuv422_to_rgba_vec, decode_yuv444_to_rgba_vec, encode_rgba_to_nv12_vec,
+    encode_rgba_to_yuv420_vec,
+};
+
+pub use luma_levels::{adjust_levels, equalize_luma};
+pub use luma_transfer::apply_transfer_to_luma;
+pub use yuv_to_gray::{nv12_to_gray8, yuv420_to_gray8};
+
+pub use gray_planar::{yuv400_p16_to_yuv_planar, yuv400_to_yuv_planar, yuv_planar_to_yuv400};
+
+pub use planar_nv::{
+    yuv420_to_yuv_nv12, yuv420_to_yuv_nv21, yuv_nv12_to_yuv420, yuv_nv21_to_yuv420,
+};
+
+pub use imc::{imc_to_bgra, imc_to_rgb, imc_to_rgba, imc_to_yuv420, imc_to_yuv_nv12, ImcLayout};
+
+pub use v410::{v410_to_ar30, v410_to_rgba, v410_to_yuv444_p10, yuv444_p10_to_v410};
+
+pub use decimate::{
+    decimate_yuv, decimate_yuv_nv12, decimate_yuv_p010, decimate_yuv_p16, YuvDecimation,
+};
+pub use pyramid::{build_y_pyramid, build_yuv_pyramid, YuvPyramidFilter};
+
+pub use tiled_nv::tiled_nv12_scratch_size;
+pub use tiled_nv::tiled_nv12_to_bgr;
+pub use tiled_nv::tiled_nv12_to_bgr_with_scratch;
+pub use tiled_nv::tiled_nv12_to_bgra;
+pub use tiled_nv::tiled_nv12_to_bgra_with_scratch;
+pub use tiled_nv::tiled_nv12_to_nv12;
+pub use tiled_nv::tiled_nv12_to_rgb;
+pub use tiled_nv::tiled_nv12_to_rgb_with_scratch;
+pub use tiled_nv::tiled_nv12_to_rgba;
+pub use tiled_nv::tiled_nv12_to_rgba_with_scratch;
+pub use tiled_nv::YuvTileLayout;
+
+pub use yuy2_nv::uyvy422_to_yuv_nv12;
+pub use yuy2_nv::uyvy422_to_yuv_nv21;
+pub use yuy2_nv::vyuy422_to_yuv_nv12;
+pub use yuy2_nv::vyuy422_to_yuv_nv21;
+pub use yuy2_nv::yuv_nv12_to_uyvy422;
+pub use yuy2_nv::yuv_nv12_to_vyuy422;
+pub use yuy2_nv::yuv_nv12_to_yuyv422;
+pub use yuy2_nv::yuv_nv12_to_yvyu422;
+pub use yuy2_nv::yuv_nv21_to_uyvy422;
+pub use yuy2_nv::yuv_nv21_to_vyuy422;
+pub use yuy2_nv::yuv_nv21_to_yuyv422;
+pub use yuy2_nv::yuv_nv21_to_yvyu422;
+pub use yuy2_nv::yuyv422_to_yuv_nv12;
+pub use yuy2_nv::yuyv422_to_yuv_nv21;
+pub use yuy2_nv::yvyu422_to_yuv_nv12;
+pub use yuy2_nv::yvyu422_to_yuv_nv21;
+pub use yuy2_nv::YuvChromaVerticalFilter;
+
+pub use yuy2_transform::uyvy422_crop;
+pub use yuy2_transform::uyvy422_flip_horizontal;
+pub use yuy2_transform::uyvy422_rotate180;
+pub use yuy2_transform::vyuy422_crop;
+pub use yuy2_transform::vyuy422_flip_horizontal;
+pub use yuy2_transform::vyuy422_rotate180;
+pub use yuy2_transform::yuyv422_crop;
+pub use yuy2_transform::yuyv422_flip_horizontal;
+pub use yuy2_transform::yuyv422_rotate180;
+pub use yuy2_transform::yvyu422_crop;
+pub use yuy2_transform::yvyu422_flip_horizontal;
+pub use yuy2_transform::yvyu422_rotate180;
+
+#[cfg(feature = "bench")]
+pub use cpu_features::set_backend_limit;
+pub use cpu_features::YuvDispatchBackend;
+#[cfg(feature = "frame-hash")]
+pub use frame_reuse::{luma_row_hashes, YuvFrameReuse};
+#[cfg(feature = "profiling")]
+pub use profiling::{set_profiling_callback, YuvConversionProfile, YuvProfilingCallback};
+pub use throughput::{query_expected_throughput, set_expected_throughput, GBps, YuvFormatPair};
+pub use verify_backends::verify_backends;
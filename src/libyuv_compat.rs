@@ -0,0 +1,934 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+//! Drop-in names for code ported from libyuv.
+//!
+//! Functions keep libyuv names, argument order and return codes: `0` on success and `-1`
+//! when arguments are rejected. Negative `height` flips the image vertically as it does in libyuv.
+//! Strides are in bytes and must not be negative, slices replace raw pointers.
+//!
+//! libyuv names packed formats after a little endian 32-bit word, so libyuv `ARGB` is
+//! [crate::yuv420_to_bgra] byte order `B, G, R, A` and libyuv `ABGR` is `R, G, B, A` in memory.
+//!
+//! Color conventions follow libyuv prefixes:
+//! `I` and `NV` are BT.601 limited range, `J` is BT.601 full range, `H` is BT.709 limited range.
+#![allow(non_snake_case)]
+
+use crate::images::try_vec;
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_support::{YuvRange, YuvStandardMatrix};
+use crate::YuvError;
+
+type PlanarToRgbx = fn(
+    &[u8],
+    u32,
+    &[u8],
+    u32,
+    &[u8],
+    u32,
+    &mut [u8],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+) -> Result<(), YuvError>;
+
+type BiPlanarToRgbx = fn(
+    &[u8],
+    u32,
+    &[u8],
+    u32,
+    &mut [u8],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+) -> Result<(), YuvError>;
+
+type RgbxToPlanar = fn(
+    &mut [u8],
+    u32,
+    &mut [u8],
+    u32,
+    &mut [u8],
+    u32,
+    &[u8],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+) -> Result<(), YuvError>;
+
+type RgbxToBiPlanar = fn(
+    &mut [u8],
+    u32,
+    &mut [u8],
+    u32,
+    &[u8],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+) -> Result<(), YuvError>;
+
+const PACKED_CHANNELS: usize = 4;
+
+/// Validates libyuv style dimensions, returns width, absolute height and whether image is flipped
+fn libyuv_size(width: i32, height: i32, strides: &[i32]) -> Option<(u32, u32, bool)> {
+    if width <= 0 || height == 0 || strides.iter().any(|&stride| stride < 0) {
+        return None;
+    }
+    Some((width as u32, height.unsigned_abs(), height < 0))
+}
+
+/// Reverses order of the first `row_size` bytes of `rows` rows
+fn flip_rows(data: &mut [u8], stride: usize, row_size: usize, rows: usize) {
+    for y in 0..rows / 2 {
+        let (top, bottom) = data.split_at_mut((rows - 1 - y) * stride);
+        let top_row = &mut top[y * stride..y * stride + row_size];
+        top_row.swap_with_slice(&mut bottom[..row_size]);
+    }
+}
+
+/// Copies packed image bottom up into tightly packed buffer, this is what libyuv reads
+/// when source height is negative
+fn flipped_copy(
+    src: &[u8],
+    stride: usize,
+    row_size: usize,
+    rows: usize,
+) -> Result<Vec<u8>, YuvError> {
+    let mut flipped = try_vec(0u8, row_size * rows)?;
+    for (y, dst) in flipped.chunks_exact_mut(row_size).enumerate() {
+        let offset = (rows - 1 - y) * stride;
+        dst.copy_from_slice(&src[offset..offset + row_size]);
+    }
+    Ok(flipped)
+}
+
+fn to_return_code(result: Result<(), YuvError>) -> i32 {
+    match result {
+        Ok(_) => 0,
+        Err(_) => -1,
+    }
+}
+
+fn planar_to_packed(
+    handler: PlanarToRgbx,
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_u: &[u8],
+    src_stride_u: i32,
+    src_v: &[u8],
+    src_stride_v: i32,
+    dst: &mut [u8],
+    dst_stride: i32,
+    width: i32,
+    height: i32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> i32 {
+    let (width, height, flip) = match libyuv_size(
+        width,
+        height,
+        &[src_stride_y, src_stride_u, src_stride_v, dst_stride],
+    ) {
+        Some(size) => size,
+        None => return -1,
+    };
+    let result = handler(
+        src_y,
+        src_stride_y as u32,
+        src_u,
+        src_stride_u as u32,
+        src_v,
+        src_stride_v as u32,
+        dst,
+        dst_stride as u32,
+        width,
+        height,
+        range,
+        matrix,
+    );
+    if result.is_ok() && flip {
+        flip_rows(
+            dst,
+            dst_stride as usize,
+            width as usize * PACKED_CHANNELS,
+            height as usize,
+        );
+    }
+    to_return_code(result)
+}
+
+fn bi_planar_to_packed(
+    handler: BiPlanarToRgbx,
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_uv: &[u8],
+    src_stride_uv: i32,
+    dst: &mut [u8],
+    dst_stride: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    let (width, height, flip) =
+        match libyuv_size(width, height, &[src_stride_y, src_stride_uv, dst_stride]) {
+            Some(size) => size,
+            None => return -1,
+        };
+    let result = handler(
+        src_y,
+        src_stride_y as u32,
+        src_uv,
+        src_stride_uv as u32,
+        dst,
+        dst_stride as u32,
+        width,
+        height,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt601,
+    );
+    if result.is_ok() && flip {
+        flip_rows(
+            dst,
+            dst_stride as usize,
+            width as usize * PACKED_CHANNELS,
+            height as usize,
+        );
+    }
+    to_return_code(result)
+}
+
+fn packed_to_planar(
+    handler: RgbxToPlanar,
+    src: &[u8],
+    src_stride: i32,
+    dst_y: &mut [u8],
+    dst_stride_y: i32,
+    dst_u: &mut [u8],
+    dst_stride_u: i32,
+    dst_v: &mut [u8],
+    dst_stride_v: i32,
+    width: i32,
+    height: i32,
+    range: YuvRange,
+) -> i32 {
+    let (width, height, flip) = match libyuv_size(
+        width,
+        height,
+        &[src_stride, dst_stride_y, dst_stride_u, dst_stride_v],
+    ) {
+        Some(size) => size,
+        None => return -1,
+    };
+    let row_size = width as usize * PACKED_CHANNELS;
+    let flipped;
+    let (src, src_stride) = if flip {
+        if check_rgba_destination(src, src_stride as u32, width, height, PACKED_CHANNELS).is_err() {
+            return -1;
+        }
+        flipped = match flipped_copy(src, src_stride as usize, row_size, height as usize) {
+            Ok(flipped) => flipped,
+            Err(_) => return -1,
+        };
+        (flipped.as_slice(), row_size as u32)
+    } else {
+        (src, src_stride as u32)
+    };
+    to_return_code(handler(
+        dst_y,
+        dst_stride_y as u32,
+        dst_u,
+        dst_stride_u as u32,
+        dst_v,
+        dst_stride_v as u32,
+        src,
+        src_stride,
+        width,
+        height,
+        range,
+        YuvStandardMatrix::Bt601,
+    ))
+}
+
+fn packed_to_bi_planar(
+    handler: RgbxToBiPlanar,
+    src: &[u8],
+    src_stride: i32,
+    dst_y: &mut [u8],
+    dst_stride_y: i32,
+    dst_uv: &mut [u8],
+    dst_stride_uv: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    let (width, height, flip) =
+        match libyuv_size(width, height, &[src_stride, dst_stride_y, dst_stride_uv]) {
+            Some(size) => size,
+            None => return -1,
+        };
+    let row_size = width as usize * PACKED_CHANNELS;
+    let flipped;
+    let (src, src_stride) = if flip {
+        if check_rgba_destination(src, src_stride as u32, width, height, PACKED_CHANNELS).is_err() {
+            return -1;
+        }
+        flipped = match flipped_copy(src, src_stride as usize, row_size, height as usize) {
+            Ok(flipped) => flipped,
+            Err(_) => return -1,
+        };
+        (flipped.as_slice(), row_size as u32)
+    } else {
+        (src, src_stride as u32)
+    };
+    to_return_code(handler(
+        dst_y,
+        dst_stride_y as u32,
+        dst_uv,
+        dst_stride_uv as u32,
+        src,
+        src_stride,
+        width,
+        height,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt601,
+    ))
+}
+
+/// libyuv `I420ToARGB`, BT.601 limited range, output bytes are `B, G, R, A`.
+pub fn I420ToARGB(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_u: &[u8],
+    src_stride_u: i32,
+    src_v: &[u8],
+    src_stride_v: i32,
+    dst_argb: &mut [u8],
+    dst_stride_argb: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    planar_to_packed(
+        crate::yuv420_to_bgra,
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_argb,
+        dst_stride_argb,
+        width,
+        height,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt601,
+    )
+}
+
+/// libyuv `I420ToABGR`, BT.601 limited range, output bytes are `R, G, B, A`.
+pub fn I420ToABGR(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_u: &[u8],
+    src_stride_u: i32,
+    src_v: &[u8],
+    src_stride_v: i32,
+    dst_abgr: &mut [u8],
+    dst_stride_abgr: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    planar_to_packed(
+        crate::yuv420_to_rgba,
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_abgr,
+        dst_stride_abgr,
+        width,
+        height,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt601,
+    )
+}
+
+/// libyuv `I422ToARGB`, BT.601 limited range, output bytes are `B, G, R, A`.
+pub fn I422ToARGB(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_u: &[u8],
+    src_stride_u: i32,
+    src_v: &[u8],
+    src_stride_v: i32,
+    dst_argb: &mut [u8],
+    dst_stride_argb: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    planar_to_packed(
+        crate::yuv422_to_bgra,
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_argb,
+        dst_stride_argb,
+        width,
+        height,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt601,
+    )
+}
+
+/// libyuv `I422ToABGR`, BT.601 limited range, output bytes are `R, G, B, A`.
+pub fn I422ToABGR(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_u: &[u8],
+    src_stride_u: i32,
+    src_v: &[u8],
+    src_stride_v: i32,
+    dst_abgr: &mut [u8],
+    dst_stride_abgr: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    planar_to_packed(
+        crate::yuv422_to_rgba,
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_abgr,
+        dst_stride_abgr,
+        width,
+        height,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt601,
+    )
+}
+
+/// libyuv `I444ToARGB`, BT.601 limited range, output bytes are `B, G, R, A`.
+pub fn I444ToARGB(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_u: &[u8],
+    src_stride_u: i32,
+    src_v: &[u8],
+    src_stride_v: i32,
+    dst_argb: &mut [u8],
+    dst_stride_argb: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    planar_to_packed(
+        crate::yuv444_to_bgra,
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_argb,
+        dst_stride_argb,
+        width,
+        height,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt601,
+    )
+}
+
+/// libyuv `I444ToABGR`, BT.601 limited range, output bytes are `R, G, B, A`.
+pub fn I444ToABGR(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_u: &[u8],
+    src_stride_u: i32,
+    src_v: &[u8],
+    src_stride_v: i32,
+    dst_abgr: &mut [u8],
+    dst_stride_abgr: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    planar_to_packed(
+        crate::yuv444_to_rgba,
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_abgr,
+        dst_stride_abgr,
+        width,
+        height,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt601,
+    )
+}
+
+/// libyuv `J420ToARGB`, BT.601 full range, output bytes are `B, G, R, A`.
+pub fn J420ToARGB(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_u: &[u8],
+    src_stride_u: i32,
+    src_v: &[u8],
+    src_stride_v: i32,
+    dst_argb: &mut [u8],
+    dst_stride_argb: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    planar_to_packed(
+        crate::yuv420_to_bgra,
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_argb,
+        dst_stride_argb,
+        width,
+        height,
+        YuvRange::Full,
+        YuvStandardMatrix::Bt601,
+    )
+}
+
+/// libyuv `J420ToABGR`, BT.601 full range, output bytes are `R, G, B, A`.
+pub fn J420ToABGR(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_u: &[u8],
+    src_stride_u: i32,
+    src_v: &[u8],
+    src_stride_v: i32,
+    dst_abgr: &mut [u8],
+    dst_stride_abgr: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    planar_to_packed(
+        crate::yuv420_to_rgba,
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_abgr,
+        dst_stride_abgr,
+        width,
+        height,
+        YuvRange::Full,
+        YuvStandardMatrix::Bt601,
+    )
+}
+
+/// libyuv `H420ToARGB`, BT.709 limited range, output bytes are `B, G, R, A`.
+pub fn H420ToARGB(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_u: &[u8],
+    src_stride_u: i32,
+    src_v: &[u8],
+    src_stride_v: i32,
+    dst_argb: &mut [u8],
+    dst_stride_argb: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    planar_to_packed(
+        crate::yuv420_to_bgra,
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_argb,
+        dst_stride_argb,
+        width,
+        height,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt709,
+    )
+}
+
+/// libyuv `H420ToABGR`, BT.709 limited range, output bytes are `R, G, B, A`.
+pub fn H420ToABGR(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_u: &[u8],
+    src_stride_u: i32,
+    src_v: &[u8],
+    src_stride_v: i32,
+    dst_abgr: &mut [u8],
+    dst_stride_abgr: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    planar_to_packed(
+        crate::yuv420_to_rgba,
+        src_y,
+        src_stride_y,
+        src_u,
+        src_stride_u,
+        src_v,
+        src_stride_v,
+        dst_abgr,
+        dst_stride_abgr,
+        width,
+        height,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt709,
+    )
+}
+
+/// libyuv `NV12ToARGB`, BT.601 limited range, output bytes are `B, G, R, A`.
+pub fn NV12ToARGB(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_uv: &[u8],
+    src_stride_uv: i32,
+    dst_argb: &mut [u8],
+    dst_stride_argb: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    bi_planar_to_packed(
+        crate::yuv_nv12_to_bgra,
+        src_y,
+        src_stride_y,
+        src_uv,
+        src_stride_uv,
+        dst_argb,
+        dst_stride_argb,
+        width,
+        height,
+    )
+}
+
+/// libyuv `NV12ToABGR`, BT.601 limited range, output bytes are `R, G, B, A`.
+pub fn NV12ToABGR(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_uv: &[u8],
+    src_stride_uv: i32,
+    dst_abgr: &mut [u8],
+    dst_stride_abgr: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    bi_planar_to_packed(
+        crate::yuv_nv12_to_rgba,
+        src_y,
+        src_stride_y,
+        src_uv,
+        src_stride_uv,
+        dst_abgr,
+        dst_stride_abgr,
+        width,
+        height,
+    )
+}
+
+/// libyuv `NV21ToARGB`, BT.601 limited range, output bytes are `B, G, R, A`.
+pub fn NV21ToARGB(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_vu: &[u8],
+    src_stride_vu: i32,
+    dst_argb: &mut [u8],
+    dst_stride_argb: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    bi_planar_to_packed(
+        crate::yuv_nv21_to_bgra,
+        src_y,
+        src_stride_y,
+        src_vu,
+        src_stride_vu,
+        dst_argb,
+        dst_stride_argb,
+        width,
+        height,
+    )
+}
+
+/// libyuv `NV21ToABGR`, BT.601 limited range, output bytes are `R, G, B, A`.
+pub fn NV21ToABGR(
+    src_y: &[u8],
+    src_stride_y: i32,
+    src_vu: &[u8],
+    src_stride_vu: i32,
+    dst_abgr: &mut [u8],
+    dst_stride_abgr: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    bi_planar_to_packed(
+        crate::yuv_nv21_to_rgba,
+        src_y,
+        src_stride_y,
+        src_vu,
+        src_stride_vu,
+        dst_abgr,
+        dst_stride_abgr,
+        width,
+        height,
+    )
+}
+
+/// libyuv `ARGBToI420`, BT.601 limited range, input bytes are `B, G, R, A`.
+pub fn ARGBToI420(
+    src_argb: &[u8],
+    src_stride_argb: i32,
+    dst_y: &mut [u8],
+    dst_stride_y: i32,
+    dst_u: &mut [u8],
+    dst_stride_u: i32,
+    dst_v: &mut [u8],
+    dst_stride_v: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    packed_to_planar(
+        crate::bgra_to_yuv420,
+        src_argb,
+        src_stride_argb,
+        dst_y,
+        dst_stride_y,
+        dst_u,
+        dst_stride_u,
+        dst_v,
+        dst_stride_v,
+        width,
+        height,
+        YuvRange::TV,
+    )
+}
+
+/// libyuv `ABGRToI420`, BT.601 limited range, input bytes are `R, G, B, A`.
+pub fn ABGRToI420(
+    src_abgr: &[u8],
+    src_stride_abgr: i32,
+    dst_y: &mut [u8],
+    dst_stride_y: i32,
+    dst_u: &mut [u8],
+    dst_stride_u: i32,
+    dst_v: &mut [u8],
+    dst_stride_v: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    packed_to_planar(
+        crate::rgba_to_yuv420,
+        src_abgr,
+        src_stride_abgr,
+        dst_y,
+        dst_stride_y,
+        dst_u,
+        dst_stride_u,
+        dst_v,
+        dst_stride_v,
+        width,
+        height,
+        YuvRange::TV,
+    )
+}
+
+/// libyuv `ARGBToJ420`, BT.601 full range, input bytes are `B, G, R, A`.
+pub fn ARGBToJ420(
+    src_argb: &[u8],
+    src_stride_argb: i32,
+    dst_y: &mut [u8],
+    dst_stride_y: i32,
+    dst_u: &mut [u8],
+    dst_stride_u: i32,
+    dst_v: &mut [u8],
+    dst_stride_v: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    packed_to_planar(
+        crate::bgra_to_yuv420,
+        src_argb,
+        src_stride_argb,
+        dst_y,
+        dst_stride_y,
+        dst_u,
+        dst_stride_u,
+        dst_v,
+        dst_stride_v,
+        width,
+        height,
+        YuvRange::Full,
+    )
+}
+
+/// libyuv `ABGRToJ420`, BT.601 full range, input bytes are `R, G, B, A`.
+pub fn ABGRToJ420(
+    src_abgr: &[u8],
+    src_stride_abgr: i32,
+    dst_y: &mut [u8],
+    dst_stride_y: i32,
+    dst_u: &mut [u8],
+    dst_stride_u: i32,
+    dst_v: &mut [u8],
+    dst_stride_v: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    packed_to_planar(
+        crate::rgba_to_yuv420,
+        src_abgr,
+        src_stride_abgr,
+        dst_y,
+        dst_stride_y,
+        dst_u,
+        dst_stride_u,
+        dst_v,
+        dst_stride_v,
+        width,
+        height,
+        YuvRange::Full,
+    )
+}
+
+/// libyuv `ARGBToNV12`, BT.601 limited range, input bytes are `B, G, R, A`.
+pub fn ARGBToNV12(
+    src_argb: &[u8],
+    src_stride_argb: i32,
+    dst_y: &mut [u8],
+    dst_stride_y: i32,
+    dst_uv: &mut [u8],
+    dst_stride_uv: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    packed_to_bi_planar(
+        crate::bgra_to_yuv_nv12,
+        src_argb,
+        src_stride_argb,
+        dst_y,
+        dst_stride_y,
+        dst_uv,
+        dst_stride_uv,
+        width,
+        height,
+    )
+}
+
+/// libyuv `ABGRToNV12`, BT.601 limited range, input bytes are `R, G, B, A`.
+pub fn ABGRToNV12(
+    src_abgr: &[u8],
+    src_stride_abgr: i32,
+    dst_y: &mut [u8],
+    dst_stride_y: i32,
+    dst_uv: &mut [u8],
+    dst_stride_uv: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    packed_to_bi_planar(
+        crate::rgba_to_yuv_nv12,
+        src_abgr,
+        src_stride_abgr,
+        dst_y,
+        dst_stride_y,
+        dst_uv,
+        dst_stride_uv,
+        width,
+        height,
+    )
+}
+
+/// libyuv `ARGBToNV21`, BT.601 limited range, input bytes are `B, G, R, A`.
+pub fn ARGBToNV21(
+    src_argb: &[u8],
+    src_stride_argb: i32,
+    dst_y: &mut [u8],
+    dst_stride_y: i32,
+    dst_vu: &mut [u8],
+    dst_stride_vu: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    packed_to_bi_planar(
+        crate::bgra_to_yuv_nv21,
+        src_argb,
+        src_stride_argb,
+        dst_y,
+        dst_stride_y,
+        dst_vu,
+        dst_stride_vu,
+        width,
+        height,
+    )
+}
+
+/// libyuv `ABGRToNV21`, BT.601 limited range, input bytes are `R, G, B, A`.
+pub fn ABGRToNV21(
+    src_abgr: &[u8],
+    src_stride_abgr: i32,
+    dst_y: &mut [u8],
+    dst_stride_y: i32,
+    dst_vu: &mut [u8],
+    dst_stride_vu: i32,
+    width: i32,
+    height: i32,
+) -> i32 {
+    packed_to_bi_planar(
+        crate::rgba_to_yuv_nv21,
+        src_abgr,
+        src_stride_abgr,
+        dst_y,
+        dst_stride_y,
+        dst_vu,
+        dst_stride_vu,
+        width,
+        height,
+    )
+}
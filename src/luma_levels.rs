@@ -0,0 +1,211 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::YuvGrayImageMut;
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::neon::neon_luma_lut_row;
+use crate::yuv_support::{get_yuv_range, YuvRange};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+fn luma_histogram(plane: &YuvGrayImageMut<u8>) -> [u32; 256] {
+    let width = plane.size.width as usize;
+    let height = plane.size.height as usize;
+    let stride = plane.y_stride.get() as usize;
+
+    let accumulate = |mut histogram: [u32; 256], row: &[u8]| {
+        for &v in row[..width].iter() {
+            histogram[v as usize] += 1;
+        }
+        histogram
+    };
+
+    let histogram;
+    #[cfg(feature = "rayon")]
+    {
+        histogram = plane
+            .y_plane
+            .borrow()
+            .par_chunks(stride)
+            .take(height)
+            .fold(|| [0u32; 256], accumulate)
+            .reduce(
+                || [0u32; 256],
+                |mut a, b| {
+                    for (dst, src) in a.iter_mut().zip(b.iter()) {
+                        *dst += src;
+                    }
+                    a
+                },
+            );
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        histogram = plane
+            .y_plane
+            .borrow()
+            .chunks(stride)
+            .take(height)
+            .fold([0u32; 256], accumulate);
+    }
+    histogram
+}
+
+fn apply_luma_lut(plane: &mut YuvGrayImageMut<u8>, lut: &[u8; 256]) {
+    let width = plane.size.width as usize;
+    let height = plane.size.height as usize;
+    let stride = plane.y_stride.get() as usize;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = plane
+            .y_plane
+            .borrow_mut()
+            .par_chunks_mut(stride)
+            .take(height);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = plane.y_plane.borrow_mut().chunks_mut(stride).take(height);
+    }
+    iter.for_each(|row| {
+        #[allow(unused_mut)]
+        let mut cx = 0usize;
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            cx = neon_luma_lut_row(lut, row, width);
+        }
+
+        for v in row.iter_mut().take(width).skip(cx) {
+            *v = lut[*v as usize];
+        }
+    });
+}
+
+/// Equalizes histogram of 8-bit Y plane in place, chroma is left untouched.
+///
+/// Works on the luma of planar and bi-planar images alike, since only Y plane is touched.
+/// Cumulative distribution of the active area is remapped onto the nominal luma range,
+/// planes holding a single luma value are left as is.
+///
+/// On NEON lookups are done with 64 byte table instructions, on x86 plain table loads
+/// are used since emulating a 256 entries shuffle costs more than it saves.
+///
+/// # Arguments
+///
+/// * `plane` - Y plane, updated in place.
+/// * `range` - The YUV range (limited or full).
+///
+pub fn equalize_luma(plane: &mut YuvGrayImageMut<u8>, range: YuvRange) -> Result<(), YuvError> {
+    plane.check_constraints()?;
+
+    let histogram = luma_histogram(plane);
+    let total = plane.size.width as u64 * plane.size.height as u64;
+    let chroma_range = get_yuv_range(8, range);
+    let bias_y = chroma_range.bias_y as f32;
+    let range_y = chroma_range.range_y as f32;
+
+    let mut cdf = [0u64; 256];
+    let mut acc = 0u64;
+    for (dst, &count) in cdf.iter_mut().zip(histogram.iter()) {
+        acc += count as u64;
+        *dst = acc;
+    }
+    let cdf_min = cdf.iter().copied().find(|&x| x != 0).unwrap_or(0);
+    if total == 0 || total == cdf_min {
+        return Ok(());
+    }
+
+    let scale = 1. / (total - cdf_min) as f32;
+    let mut lut = [0u8; 256];
+    for (dst, &c) in lut.iter_mut().zip(cdf.iter()) {
+        let normalized = (c.saturating_sub(cdf_min) as f32 * scale).min(1.);
+        *dst = (normalized * range_y + bias_y).round() as u8;
+    }
+
+    apply_luma_lut(plane, &lut);
+    Ok(())
+}
+
+/// Applies levels adjustment to 8-bit Y plane in place, chroma is left untouched.
+///
+/// Samples are clipped to `[black, white]`, normalized, raised to `1 / gamma`
+/// and stretched back onto the nominal luma range, same as levels tool in photo editors.
+///
+/// # Arguments
+///
+/// * `plane` - Y plane, updated in place.
+/// * `black` - Input value mapped to nominal black.
+/// * `white` - Input value mapped to nominal white, must be greater than `black`.
+/// * `gamma` - Midtones gamma, values above 1 brighten, must be finite and positive.
+/// * `range` - The YUV range (limited or full).
+///
+pub fn adjust_levels(
+    plane: &mut YuvGrayImageMut<u8>,
+    black: u8,
+    white: u8,
+    gamma: f32,
+    range: YuvRange,
+) -> Result<(), YuvError> {
+    plane.check_constraints()?;
+    if white <= black {
+        return Err(YuvError::LevelsRangeMismatch(black, white));
+    }
+    if !gamma.is_finite() || gamma <= 0. {
+        return Err(YuvError::InvalidGamma(gamma));
+    }
+
+    let chroma_range = get_yuv_range(8, range);
+    let bias_y = chroma_range.bias_y as f32;
+    let range_y = chroma_range.range_y as f32;
+    let inverse_gamma = 1. / gamma;
+    let black_f = black as f32;
+    let scale = 1. / (white - black) as f32;
+
+    let mut lut = [0u8; 256];
+    for (code, dst) in lut.iter_mut().enumerate() {
+        let normalized = ((code as f32 - black_f) * scale).clamp(0., 1.);
+        *dst = (normalized.powf(inverse_gamma) * range_y + bias_y).round() as u8;
+    }
+
+    apply_luma_lut(plane, &lut);
+    Ok(())
+}
@@ -0,0 +1,264 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::color_description::YuvTransferCharacteristics;
+use crate::images::{try_vec, YuvGrayImageMut};
+use crate::sharpyuv::SharpYuvGammaTransfer;
+use crate::yuv_support::{get_yuv_range, YuvRange};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+const HLG_A: f32 = 0.17883277;
+const HLG_B: f32 = 1. - 4. * HLG_A;
+const HLG_C: f32 = 0.5599107;
+
+const PQ_M1: f32 = 2610. / 16384.;
+const PQ_M2: f32 = 2523. / 4096. * 128.;
+const PQ_C1: f32 = 3424. / 4096.;
+const PQ_C2: f32 = 2413. / 4096. * 32.;
+const PQ_C3: f32 = 2392. / 4096. * 32.;
+
+#[inline]
+fn smpte240_to_linear(gamma: f32) -> f32 {
+    if gamma < 4. * 0.0228 {
+        gamma / 4.
+    } else {
+        ((gamma + 0.1115) / 1.1115).powf(1. / 0.45)
+    }
+}
+
+#[inline]
+fn smpte240_from_linear(linear: f32) -> f32 {
+    if linear < 0.0228 {
+        4. * linear
+    } else {
+        1.1115 * linear.powf(0.45) - 0.1115
+    }
+}
+
+#[inline]
+fn log_to_linear(gamma: f32, decades: f32) -> f32 {
+    if gamma <= 0. {
+        0.
+    } else {
+        10f32.powf((gamma - 1.) * decades)
+    }
+}
+
+#[inline]
+fn log_from_linear(linear: f32, decades: f32) -> f32 {
+    let threshold = 10f32.powf(-decades);
+    if linear < threshold {
+        0.
+    } else {
+        1. + linear.log10() / decades
+    }
+}
+
+#[inline]
+fn pq_to_linear(gamma: f32) -> f32 {
+    let p = gamma.powf(1. / PQ_M2);
+    ((p - PQ_C1).max(0.) / (PQ_C2 - PQ_C3 * p)).powf(1. / PQ_M1)
+}
+
+#[inline]
+fn pq_from_linear(linear: f32) -> f32 {
+    let p = linear.powf(PQ_M1);
+    ((PQ_C1 + PQ_C2 * p) / (1. + PQ_C3 * p)).powf(PQ_M2)
+}
+
+#[inline]
+fn hlg_to_linear(gamma: f32) -> f32 {
+    if gamma <= 0.5 {
+        gamma * gamma / 3.
+    } else {
+        (((gamma - HLG_C) / HLG_A).exp() + HLG_B) / 12.
+    }
+}
+
+#[inline]
+fn hlg_from_linear(linear: f32) -> f32 {
+    if linear <= 1. / 12. {
+        (3. * linear).sqrt()
+    } else {
+        HLG_A * (12. * linear - HLG_B).ln() + HLG_C
+    }
+}
+
+#[inline]
+fn smpte428_to_linear(gamma: f32) -> f32 {
+    gamma.powf(2.6) * 52.37 / 48.
+}
+
+#[inline]
+fn smpte428_from_linear(linear: f32) -> f32 {
+    (linear * 48. / 52.37).powf(1. / 2.6)
+}
+
+/// Transfer function pair, both directions work on values normalized to [0, 1]
+#[derive(Debug, Copy, Clone)]
+pub(crate) enum LumaTransfer {
+    Sharp(SharpYuvGammaTransfer),
+    Linear,
+    Smpte240,
+    Log(f32),
+    Pq,
+    Hlg,
+    Smpte428,
+}
+
+impl LumaTransfer {
+    pub(crate) fn new(transfer: YuvTransferCharacteristics) -> Result<LumaTransfer, YuvError> {
+        match transfer {
+            YuvTransferCharacteristics::Bt709
+            | YuvTransferCharacteristics::Smpte170m
+            | YuvTransferCharacteristics::Iec61966
+            | YuvTransferCharacteristics::Bt1361
+            | YuvTransferCharacteristics::Bt2020_10bit
+            | YuvTransferCharacteristics::Bt2020_12bit => {
+                Ok(LumaTransfer::Sharp(SharpYuvGammaTransfer::Rec709))
+            }
+            YuvTransferCharacteristics::Bt470M => {
+                Ok(LumaTransfer::Sharp(SharpYuvGammaTransfer::Gamma2p2))
+            }
+            YuvTransferCharacteristics::Bt470Bg => {
+                Ok(LumaTransfer::Sharp(SharpYuvGammaTransfer::Gamma2p8))
+            }
+            YuvTransferCharacteristics::Srgb => {
+                Ok(LumaTransfer::Sharp(SharpYuvGammaTransfer::Srgb))
+            }
+            YuvTransferCharacteristics::Smpte240m => Ok(LumaTransfer::Smpte240),
+            YuvTransferCharacteristics::Linear => Ok(LumaTransfer::Linear),
+            YuvTransferCharacteristics::Log100 => Ok(LumaTransfer::Log(2.)),
+            YuvTransferCharacteristics::Log100Sqrt10 => Ok(LumaTransfer::Log(2.5)),
+            YuvTransferCharacteristics::Smpte2084 => Ok(LumaTransfer::Pq),
+            YuvTransferCharacteristics::Hlg => Ok(LumaTransfer::Hlg),
+            YuvTransferCharacteristics::Smpte428 => Ok(LumaTransfer::Smpte428),
+            YuvTransferCharacteristics::Unspecified => {
+                Err(YuvError::UnsupportedTransferCharacteristics(transfer))
+            }
+        }
+    }
+
+    #[inline]
+    pub(crate) fn linearize(&self, value: f32) -> f32 {
+        match self {
+            LumaTransfer::Sharp(transfer) => transfer.linearize(value),
+            LumaTransfer::Linear => value,
+            LumaTransfer::Smpte240 => smpte240_to_linear(value),
+            LumaTransfer::Log(decades) => log_to_linear(value, *decades),
+            LumaTransfer::Pq => pq_to_linear(value),
+            LumaTransfer::Hlg => hlg_to_linear(value),
+            LumaTransfer::Smpte428 => smpte428_to_linear(value),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn gamma(&self, value: f32) -> f32 {
+        match self {
+            LumaTransfer::Sharp(transfer) => transfer.gamma(value),
+            LumaTransfer::Linear => value,
+            LumaTransfer::Smpte240 => smpte240_from_linear(value),
+            LumaTransfer::Log(decades) => log_from_linear(value, *decades),
+            LumaTransfer::Pq => pq_from_linear(value),
+            LumaTransfer::Hlg => hlg_from_linear(value),
+            LumaTransfer::Smpte428 => smpte428_from_linear(value),
+        }
+    }
+}
+
+/// Re-encodes Y plane from one transfer function into another in place.
+///
+/// Every code value is decoded into linear light with `from`, encoded back with `to`,
+/// linear light is normalized to [0, 1] on both sides, so e.g. HLG peak maps onto SDR white.
+/// Chroma is left untouched, this is meant for quick previews rather than accurate tone mapping.
+///
+/// A lookup table of `2^bit_depth` entries is built once, pixels are then processed with
+/// plain independent table loads, which outperform SIMD gathers on such table sizes.
+/// Limited range super-blacks and super-whites are clamped to nominal range.
+///
+/// # Arguments
+///
+/// * `plane` - Y plane with samples stored in lower `bit_depth` bits, updated in place.
+/// * `from` - Transfer function the plane is currently encoded with.
+/// * `to` - Transfer function to re-encode the plane with.
+/// * `bit_depth` - Bit depth of the samples, 8 to 16.
+/// * `range` - The YUV range (limited or full).
+///
+pub fn apply_transfer_to_luma(
+    plane: &mut YuvGrayImageMut<u16>,
+    from: YuvTransferCharacteristics,
+    to: YuvTransferCharacteristics,
+    bit_depth: u32,
+    range: YuvRange,
+) -> Result<(), YuvError> {
+    plane.check_constraints()?;
+    let from = LumaTransfer::new(from)?;
+    let to = LumaTransfer::new(to)?;
+    let bit_depth = bit_depth.clamp(8, 16);
+
+    let chroma_range = get_yuv_range(bit_depth, range);
+    let max_value = (1u32 << bit_depth) - 1;
+    let bias_y = chroma_range.bias_y as f32;
+    let range_y = chroma_range.range_y as f32;
+
+    let mut lut = try_vec(0u16, max_value as usize + 1)?;
+    for (code, dst) in lut.iter_mut().enumerate() {
+        let normalized = ((code as f32 - bias_y) / range_y).clamp(0., 1.);
+        let encoded = to.gamma(from.linearize(normalized)).clamp(0., 1.);
+        *dst = (encoded * range_y + bias_y).round() as u16;
+    }
+
+    let width = plane.size.width as usize;
+    let height = plane.size.height as usize;
+    let stride = plane.y_stride.get() as usize / 2;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = plane
+            .y_plane
+            .borrow_mut()
+            .par_chunks_mut(stride)
+            .take(height);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = plane.y_plane.borrow_mut().chunks_mut(stride).take(height);
+    }
+    iter.for_each(|row| {
+        for v in row[..width].iter_mut() {
+            *v = lut[(*v as u32).min(max_value) as usize];
+        }
+    });
+
+    Ok(())
+}
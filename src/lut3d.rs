@@ -0,0 +1,672 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride, YuvPlanarImage};
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::neon::neon_lut3d_row;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::sse::sse_lut3d_row;
+use crate::yuv_error::{check_rgba_destination, MismatchedSize};
+use crate::yuv_lut::yuv_to_rgbx_banded;
+use crate::yuv_support::{YuvChromaSample, YuvRange, YuvSourceChannels, YuvStandardMatrix};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+/// Interpolation between 3D LUT grid points
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum Lut3dInterpolation {
+    /// Blends 8 corners of the enclosing cube
+    Trilinear,
+    /// Blends 4 corners of the enclosing tetrahedron, keeps neutral axis exact
+    #[default]
+    Tetrahedral,
+}
+
+/// 3D color lookup table, usually 17^3 or 33^3 points, mapping RGB into RGB.
+///
+/// Values are normalized to `[0, 1]`, red index changes fastest as in `.cube` files.
+#[derive(Debug, Clone)]
+pub struct Lut3d {
+    size: usize,
+    /// Entries padded to 4 lanes so each one is a single vector load
+    table: Vec<[f32; 4]>,
+    domain_min: [f32; 3],
+    domain_max: [f32; 3],
+}
+
+impl Lut3d {
+    /// Creates LUT with `size` points per axis from `size^3` RGB entries, red index fastest.
+    pub fn new(size: usize, data: &[[f32; 3]]) -> Result<Lut3d, YuvError> {
+        if !(2..=256).contains(&size) {
+            return Err(YuvError::InvalidLut3dSize(size));
+        }
+        let entries = size * size * size;
+        if data.len() != entries {
+            return Err(YuvError::LutSizeMismatch(MismatchedSize {
+                expected: entries,
+                received: data.len(),
+            }));
+        }
+        Ok(Lut3d {
+            size,
+            table: data.iter().map(|v| [v[0], v[1], v[2], 0.]).collect(),
+            domain_min: [0.; 3],
+            domain_max: [1.; 3],
+        })
+    }
+
+    /// Points per axis
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Parses text of an Adobe / Resolve `.cube` file holding a 3D LUT.
+    ///
+    /// `TITLE`, `LUT_3D_SIZE`, `DOMAIN_MIN`, `DOMAIN_MAX` and `LUT_3D_INPUT_RANGE` are understood,
+    /// other keywords are skipped. Files with a 1D LUT are rejected.
+    #[cfg(feature = "cube")]
+    pub fn from_cube(text: &str) -> Result<Lut3d, YuvError> {
+        let mut size = 0usize;
+        let mut domain_min = [0f32; 3];
+        let mut domain_max = [1f32; 3];
+        let mut data: Vec<[f32; 3]> = Vec::new();
+
+        for (line_index, line) in text.lines().enumerate() {
+            let line_number = line_index + 1;
+            let parse_error = YuvError::CubeParseError(line_number);
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut tokens = line.split_whitespace();
+            let first = tokens.next().unwrap_or_default();
+            let parse_floats = |tokens: std::str::SplitWhitespace, count: usize| {
+                let values = tokens
+                    .map(|v| v.parse::<f32>())
+                    .collect::<Result<Vec<f32>, _>>()
+                    .map_err(|_| YuvError::CubeParseError(line_number))?;
+                if values.len() != count || values.iter().any(|v| !v.is_finite()) {
+                    return Err(YuvError::CubeParseError(line_number));
+                }
+                Ok(values)
+            };
+            match first {
+                "TITLE" => {}
+                "LUT_1D_SIZE" => return Err(parse_error),
+                "LUT_3D_SIZE" => {
+                    if size != 0 {
+                        return Err(parse_error);
+                    }
+                    size = tokens
+                        .next()
+                        .and_then(|v| v.parse::<usize>().ok())
+                        .ok_or(parse_error)?;
+                    if !(2..=256).contains(&size) {
+                        return Err(YuvError::InvalidLut3dSize(size));
+                    }
+                    data.try_reserve_exact(size * size * size)
+                        .map_err(|_| YuvError::AllocationFailure(size * size * size * 12))?;
+                }
+                "DOMAIN_MIN" => {
+                    let values = parse_floats(tokens, 3)?;
+                    domain_min.copy_from_slice(&values);
+                }
+                "DOMAIN_MAX" => {
+                    let values = parse_floats(tokens, 3)?;
+                    domain_max.copy_from_slice(&values);
+                }
+                "LUT_3D_INPUT_RANGE" => {
+                    let values = parse_floats(tokens, 2)?;
+                    domain_min = [values[0]; 3];
+                    domain_max = [values[1]; 3];
+                }
+                _ if first.starts_with(|c: char| c.is_ascii_alphabetic()) => {}
+                _ => {
+                    if size == 0 || data.len() == size * size * size {
+                        return Err(parse_error);
+                    }
+                    let values = parse_floats(line.split_whitespace(), 3)?;
+                    data.push([values[0], values[1], values[2]]);
+                }
+            }
+        }
+
+        if domain_min
+            .iter()
+            .zip(domain_max.iter())
+            .any(|(min, max)| min >= max)
+        {
+            return Err(YuvError::CubeParseError(text.lines().count()));
+        }
+
+        let mut lut = Lut3d::new(size, &data)?;
+        lut.domain_min = domain_min;
+        lut.domain_max = domain_max;
+        Ok(lut)
+    }
+}
+
+/// Grid cell and position inside of it for every 8-bit code value of each channel
+pub(crate) struct Lut3dGrid {
+    pub(crate) index: [[u32; 256]; 3],
+    pub(crate) fraction: [[f32; 256]; 3],
+    pub(crate) stride_g: usize,
+    pub(crate) stride_b: usize,
+}
+
+impl Lut3dGrid {
+    fn new(lut: &Lut3d) -> Box<Lut3dGrid> {
+        let mut grid = Box::new(Lut3dGrid {
+            index: [[0u32; 256]; 3],
+            fraction: [[0f32; 256]; 3],
+            stride_g: lut.size,
+            stride_b: lut.size * lut.size,
+        });
+        let last_cell = (lut.size - 2) as f32;
+        for channel in 0..3 {
+            let min = lut.domain_min[channel];
+            let scale = 1. / (lut.domain_max[channel] - min);
+            for v in 0..256usize {
+                let position =
+                    ((v as f32 / 255. - min) * scale).clamp(0., 1.) * (lut.size - 1) as f32;
+                let cell = position.floor().min(last_cell);
+                grid.index[channel][v] = cell as u32;
+                grid.fraction[channel][v] = position - cell;
+            }
+        }
+        grid
+    }
+
+    /// Table offsets and weights of the 4 corners of tetrahedron enclosing the color
+    #[inline(always)]
+    pub(crate) fn tetrahedral_taps(&self, r: u8, g: u8, b: u8) -> ([usize; 4], [f32; 4]) {
+        let (sg, sb) = (self.stride_g, self.stride_b);
+        let base = self.index[0][r as usize] as usize
+            + self.index[1][g as usize] as usize * sg
+            + self.index[2][b as usize] as usize * sb;
+        let fx = self.fraction[0][r as usize];
+        let fy = self.fraction[1][g as usize];
+        let fz = self.fraction[2][b as usize];
+        let c111 = base + 1 + sg + sb;
+        if fx > fy {
+            if fy > fz {
+                (
+                    [base, base + 1, base + 1 + sg, c111],
+                    [1. - fx, fx - fy, fy - fz, fz],
+                )
+            } else if fx > fz {
+                (
+                    [base, base + 1, base + 1 + sb, c111],
+                    [1. - fx, fx - fz, fz - fy, fy],
+                )
+            } else {
+                (
+                    [base, base + sb, base + 1 + sb, c111],
+                    [1. - fz, fz - fx, fx - fy, fy],
+                )
+            }
+        } else if fz > fy {
+            (
+                [base, base + sb, base + sg + sb, c111],
+                [1. - fz, fz - fy, fy - fx, fx],
+            )
+        } else if fz > fx {
+            (
+                [base, base + sg, base + sg + sb, c111],
+                [1. - fy, fy - fz, fz - fx, fx],
+            )
+        } else {
+            (
+                [base, base + sg, base + 1 + sg, c111],
+                [1. - fy, fy - fx, fx - fz, fz],
+            )
+        }
+    }
+
+    /// Table offsets and weights of the 8 corners of cube enclosing the color
+    #[inline(always)]
+    pub(crate) fn trilinear_taps(&self, r: u8, g: u8, b: u8) -> ([usize; 8], [f32; 8]) {
+        let (sg, sb) = (self.stride_g, self.stride_b);
+        let base = self.index[0][r as usize] as usize
+            + self.index[1][g as usize] as usize * sg
+            + self.index[2][b as usize] as usize * sb;
+        let fx = self.fraction[0][r as usize];
+        let fy = self.fraction[1][g as usize];
+        let fz = self.fraction[2][b as usize];
+        let (ix, iy, iz) = (1. - fx, 1. - fy, 1. - fz);
+        (
+            [
+                base,
+                base + 1,
+                base + sg,
+                base + 1 + sg,
+                base + sb,
+                base + 1 + sb,
+                base + sg + sb,
+                base + 1 + sg + sb,
+            ],
+            [
+                ix * iy * iz,
+                fx * iy * iz,
+                ix * fy * iz,
+                fx * fy * iz,
+                ix * iy * fz,
+                fx * iy * fz,
+                ix * fy * fz,
+                fx * fy * fz,
+            ],
+        )
+    }
+}
+
+#[inline(always)]
+fn blend_taps(table: &[[f32; 4]], offsets: &[usize], weights: &[f32]) -> [u8; 3] {
+    let mut sum = [0f32; 3];
+    for (&offset, &weight) in offsets.iter().zip(weights.iter()) {
+        let entry = &table[offset];
+        sum[0] += entry[0] * weight;
+        sum[1] += entry[1] * weight;
+        sum[2] += entry[2] * weight;
+    }
+    sum.map(|v| ((v * 255. + 0.5) as i32).clamp(0, 255) as u8)
+}
+
+/// Maps a row of interleaved pixels through the LUT in place, alpha is untouched
+fn lut3d_row<const DESTINATION_CHANNELS: u8>(
+    table: &[[f32; 4]],
+    grid: &Lut3dGrid,
+    row: &mut [u8],
+    width: usize,
+    interpolation: Lut3dInterpolation,
+    _use_sse: bool,
+) {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+
+    #[allow(unused_mut)]
+    let mut cx = 0usize;
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    unsafe {
+        if _use_sse {
+            cx = match interpolation {
+                Lut3dInterpolation::Trilinear => {
+                    sse_lut3d_row::<DESTINATION_CHANNELS, false>(table, grid, row, width)
+                }
+                Lut3dInterpolation::Tetrahedral => {
+                    sse_lut3d_row::<DESTINATION_CHANNELS, true>(table, grid, row, width)
+                }
+            };
+        }
+    }
+
+    #[cfg(all(
+        target_arch = "aarch64",
+        target_feature = "neon",
+        not(feature = "safe-only")
+    ))]
+    unsafe {
+        cx = match interpolation {
+            Lut3dInterpolation::Trilinear => {
+                neon_lut3d_row::<DESTINATION_CHANNELS, false>(table, grid, row, width)
+            }
+            Lut3dInterpolation::Tetrahedral => {
+                neon_lut3d_row::<DESTINATION_CHANNELS, true>(table, grid, row, width)
+            }
+        };
+    }
+
+    let r_offset = dst_chans.get_r_channel_offset();
+    let g_offset = dst_chans.get_g_channel_offset();
+    let b_offset = dst_chans.get_b_channel_offset();
+
+    for dst in row[cx * channels..width * channels].chunks_exact_mut(channels) {
+        let (r, g, b) = (dst[r_offset], dst[g_offset], dst[b_offset]);
+        let mapped = match interpolation {
+            Lut3dInterpolation::Trilinear => {
+                let (offsets, weights) = grid.trilinear_taps(r, g, b);
+                blend_taps(table, &offsets, &weights)
+            }
+            Lut3dInterpolation::Tetrahedral => {
+                let (offsets, weights) = grid.tetrahedral_taps(r, g, b);
+                blend_taps(table, &offsets, &weights)
+            }
+        };
+        dst[r_offset] = mapped[0];
+        dst[g_offset] = mapped[1];
+        dst[b_offset] = mapped[2];
+    }
+}
+
+fn has_lut3d_sse() -> bool {
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    {
+        crate::cpu_features::has_sse41()
+    }
+    #[cfg(not(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    )))]
+    {
+        false
+    }
+}
+
+fn apply_lut3d_impl<const DESTINATION_CHANNELS: u8>(
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    lut: &Lut3d,
+    interpolation: Lut3dInterpolation,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+
+    let grid = Lut3dGrid::new(lut);
+    let use_sse = has_lut3d_sse();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba
+            .par_chunks_mut(rgba_stride as usize)
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba.chunks_mut(rgba_stride as usize).take(height as usize);
+    }
+
+    iter.for_each(|row| {
+        lut3d_row::<DESTINATION_CHANNELS>(
+            &lut.table,
+            &grid,
+            row,
+            width as usize,
+            interpolation,
+            use_sse,
+        );
+    });
+
+    Ok(())
+}
+
+fn yuv_to_rgbx_with_lut3d<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    lut: &Lut3d,
+    interpolation: Lut3dInterpolation,
+) -> Result<(), YuvError> {
+    let grid = Lut3dGrid::new(lut);
+    let use_sse = has_lut3d_sse();
+    let width = image.size.width as usize;
+    yuv_to_rgbx_banded::<DESTINATION_CHANNELS>(
+        image,
+        sampling,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+        |row| {
+            lut3d_row::<DESTINATION_CHANNELS>(
+                &lut.table,
+                &grid,
+                row,
+                width,
+                interpolation,
+                use_sse,
+            );
+        },
+    )
+}
+
+/// Maps RGB image through a 3D LUT in place.
+///
+/// # Arguments
+///
+/// * `rgb` - A mutable slice of RGB image data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `size` - The size of the image.
+/// * `lut` - 3D LUT to apply.
+/// * `interpolation` - Interpolation between LUT grid points.
+///
+pub fn apply_lut3d_rgb(
+    rgb: &mut [u8],
+    rgb_stride: RowStride,
+    size: ImageSize,
+    lut: &Lut3d,
+    interpolation: Lut3dInterpolation,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let rgb_stride = rgb_stride.get();
+    apply_lut3d_impl::<{ YuvSourceChannels::Rgb as u8 }>(
+        rgb,
+        rgb_stride,
+        width,
+        height,
+        lut,
+        interpolation,
+    )
+}
+
+/// Maps RGBA image through a 3D LUT in place.
+///
+/// # Arguments
+///
+/// * `rgba` - A mutable slice of RGBA image data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `size` - The size of the image.
+/// * `lut` - 3D LUT to apply.
+/// * `interpolation` - Interpolation between LUT grid points.
+///
+pub fn apply_lut3d_rgba(
+    rgba: &mut [u8],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    lut: &Lut3d,
+    interpolation: Lut3dInterpolation,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let rgba_stride = rgba_stride.get();
+    apply_lut3d_impl::<{ YuvSourceChannels::Rgba as u8 }>(
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        lut,
+        interpolation,
+    )
+}
+
+/// Maps BGRA image through a 3D LUT in place.
+///
+/// # Arguments
+///
+/// * `bgra` - A mutable slice of BGRA image data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `size` - The size of the image.
+/// * `lut` - 3D LUT to apply.
+/// * `interpolation` - Interpolation between LUT grid points.
+///
+pub fn apply_lut3d_bgra(
+    bgra: &mut [u8],
+    bgra_stride: RowStride,
+    size: ImageSize,
+    lut: &Lut3d,
+    interpolation: Lut3dInterpolation,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let bgra_stride = bgra_stride.get();
+    apply_lut3d_impl::<{ YuvSourceChannels::Bgra as u8 }>(
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        lut,
+        interpolation,
+    )
+}
+
+/// Convert YUV planar format with 8-bit pixel format to RGB format and map it through a 3D LUT.
+///
+/// Rows are converted in small bands by SIMD kernels and the LUT is applied to each band
+/// right after it, so no extra pass over the whole frame is needed.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `lut` - 3D LUT to apply.
+/// * `interpolation` - Interpolation between LUT grid points.
+///
+pub fn yuv_to_rgb_with_lut3d(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgb: &mut [u8],
+    rgb_stride: RowStride,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    lut: &Lut3d,
+    interpolation: Lut3dInterpolation,
+) -> Result<(), YuvError> {
+    let rgb_stride = rgb_stride.get();
+    yuv_to_rgbx_with_lut3d::<{ YuvSourceChannels::Rgb as u8 }>(
+        image,
+        sampling,
+        rgb,
+        rgb_stride,
+        range,
+        matrix,
+        lut,
+        interpolation,
+    )
+}
+
+/// Convert YUV planar format with 8-bit pixel format to RGBA format and map it through a 3D LUT.
+///
+/// Rows are converted in small bands by SIMD kernels and the LUT is applied to each band
+/// right after it, so no extra pass over the whole frame is needed. Alpha is always opaque.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `lut` - 3D LUT to apply.
+/// * `interpolation` - Interpolation between LUT grid points.
+///
+pub fn yuv_to_rgba_with_lut3d(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: RowStride,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    lut: &Lut3d,
+    interpolation: Lut3dInterpolation,
+) -> Result<(), YuvError> {
+    let rgba_stride = rgba_stride.get();
+    yuv_to_rgbx_with_lut3d::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        sampling,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+        lut,
+        interpolation,
+    )
+}
+
+/// Convert YUV planar format with 8-bit pixel format to BGRA format and map it through a 3D LUT.
+///
+/// Rows are converted in small bands by SIMD kernels and the LUT is applied to each band
+/// right after it, so no extra pass over the whole frame is needed. Alpha is always opaque.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `lut` - 3D LUT to apply.
+/// * `interpolation` - Interpolation between LUT grid points.
+///
+pub fn yuv_to_bgra_with_lut3d(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    bgra: &mut [u8],
+    bgra_stride: RowStride,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    lut: &Lut3d,
+    interpolation: Lut3dInterpolation,
+) -> Result<(), YuvError> {
+    let bgra_stride = bgra_stride.get();
+    yuv_to_rgbx_with_lut3d::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        sampling,
+        bgra,
+        bgra_stride,
+        range,
+        matrix,
+        lut,
+        interpolation,
+    )
+}
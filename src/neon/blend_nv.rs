@@ -0,0 +1,235 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+use crate::yuv_support::{CbCrForwardTransform, YuvChromaRange, YuvNVOrder, YuvSourceChannels};
+use std::arch::aarch64::*;
+
+struct NeonBlendCoefficients {
+    yr: int32x4_t,
+    yg: int32x4_t,
+    yb: int32x4_t,
+    cb_r: int32x4_t,
+    cb_g: int32x4_t,
+    cb_b: int32x4_t,
+    cr_r: int32x4_t,
+    cr_g: int32x4_t,
+    cr_b: int32x4_t,
+    bias_y: int32x4_t,
+    bias_uv: int32x4_t,
+    min: int32x4_t,
+    cap_y: int32x4_t,
+    min_uv: int32x4_t,
+    cap_uv: int32x4_t,
+}
+
+#[inline(always)]
+unsafe fn neon_div_by_255(v: int32x4_t) -> int32x4_t {
+    let v = vaddq_s32(v, vdupq_n_s32(128));
+    vshrq_n_s32::<8>(vaddq_s32(v, vshrq_n_s32::<8>(v)))
+}
+
+#[inline(always)]
+unsafe fn neon_widen_lo(v: uint16x8_t) -> int32x4_t {
+    vreinterpretq_s32_u32(vmovl_u16(vget_low_u16(v)))
+}
+
+#[inline(always)]
+unsafe fn neon_widen_hi(v: uint16x8_t) -> int32x4_t {
+    vreinterpretq_s32_u32(vmovl_high_u16(v))
+}
+
+#[inline(always)]
+unsafe fn neon_narrow(lo: int32x4_t, hi: int32x4_t) -> uint8x8_t {
+    vmovn_u16(vcombine_u16(
+        vmovn_u32(vreinterpretq_u32_s32(lo)),
+        vmovn_u32(vreinterpretq_u32_s32(hi)),
+    ))
+}
+
+/// Blends four pixels, returns blended luma and chroma sums of horizontal pairs
+#[inline(always)]
+unsafe fn neon_blend_quad(
+    c: &NeonBlendCoefficients,
+    r: int32x4_t,
+    g: int32x4_t,
+    b: int32x4_t,
+    a: int32x4_t,
+    y: int32x4_t,
+    u_px: int32x4_t,
+    v_px: int32x4_t,
+) -> (int32x4_t, int32x4_t, int32x4_t) {
+    let inv_a = vsubq_s32(vdupq_n_s32(255), a);
+
+    let ys = vmlaq_s32(vmlaq_s32(vmlaq_s32(c.bias_y, r, c.yr), g, c.yg), b, c.yb);
+    let ys = vminq_s32(vmaxq_s32(vshrq_n_s32::<8>(ys), c.min), c.cap_y);
+    let cb = vmlaq_s32(
+        vmlaq_s32(vmlaq_s32(c.bias_uv, r, c.cb_r), g, c.cb_g),
+        b,
+        c.cb_b,
+    );
+    let cb = vminq_s32(vmaxq_s32(vshrq_n_s32::<8>(cb), c.min_uv), c.cap_uv);
+    let cr = vmlaq_s32(
+        vmlaq_s32(vmlaq_s32(c.bias_uv, r, c.cr_r), g, c.cr_g),
+        b,
+        c.cr_b,
+    );
+    let cr = vminq_s32(vmaxq_s32(vshrq_n_s32::<8>(cr), c.min_uv), c.cap_uv);
+
+    let y_blended = neon_div_by_255(vmlaq_s32(vmulq_s32(y, inv_a), ys, a));
+    let u_blended = neon_div_by_255(vmlaq_s32(vmulq_s32(u_px, inv_a), cb, a));
+    let v_blended = neon_div_by_255(vmlaq_s32(vmulq_s32(v_px, inv_a), cr, a));
+    (
+        y_blended,
+        vpaddq_s32(u_blended, u_blended),
+        vpaddq_s32(v_blended, v_blended),
+    )
+}
+
+/// Blends two rows of sprite pixels over NV luma rows and their shared chroma row,
+/// every chroma pair covers two columns of both rows. Returns count of chroma pairs processed
+#[inline(always)]
+pub unsafe fn neon_blend_rgba_nv_row<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8>(
+    transform: &CbCrForwardTransform<i32>,
+    range: &YuvChromaRange,
+    sprite0: &[u8],
+    sprite1: &[u8],
+    y0: &mut [u8],
+    y1: &mut [u8],
+    uv: &mut [u8],
+    pairs: usize,
+) -> usize {
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let order: YuvNVOrder = UV_ORDER.into();
+
+    const PRECISION: i32 = 8;
+    const ROUNDING_CONST_BIAS: i32 = 1 << (PRECISION - 1);
+    let coefficients = NeonBlendCoefficients {
+        yr: vdupq_n_s32(transform.yr),
+        yg: vdupq_n_s32(transform.yg),
+        yb: vdupq_n_s32(transform.yb),
+        cb_r: vdupq_n_s32(transform.cb_r),
+        cb_g: vdupq_n_s32(transform.cb_g),
+        cb_b: vdupq_n_s32(transform.cb_b),
+        cr_r: vdupq_n_s32(transform.cr_r),
+        cr_g: vdupq_n_s32(transform.cr_g),
+        cr_b: vdupq_n_s32(transform.cr_b),
+        bias_y: vdupq_n_s32(range.bias_y as i32 * (1 << PRECISION) + ROUNDING_CONST_BIAS),
+        bias_uv: vdupq_n_s32(range.bias_uv as i32 * (1 << PRECISION) + ROUNDING_CONST_BIAS),
+        min: vdupq_n_s32(range.bias_y as i32),
+        cap_y: vdupq_n_s32(range.bias_y as i32 + range.range_y as i32),
+        min_uv: vdupq_n_s32(range.min_uv() as i32),
+        cap_uv: vdupq_n_s32(range.max_uv() as i32),
+    };
+
+    let sprites = [sprite0.as_ptr(), sprite1.as_ptr()];
+    let y_rows = [y0.as_mut_ptr(), y1.as_mut_ptr()];
+    let uv_ptr = uv.as_mut_ptr();
+
+    let mut cx = 0usize;
+
+    while cx + 4 <= pairs {
+        let uv_values = vmovl_u8(vld1_u8(uv_ptr.add(cx * 2)));
+        let (uv_lo, uv_hi) = (neon_widen_lo(uv_values), neon_widen_hi(uv_values));
+        // Broadcast chroma of each pair to both of its pixels
+        let (u_lo, v_lo, u_hi, v_hi) = match order {
+            YuvNVOrder::UV => (
+                vtrn1q_s32(uv_lo, uv_lo),
+                vtrn2q_s32(uv_lo, uv_lo),
+                vtrn1q_s32(uv_hi, uv_hi),
+                vtrn2q_s32(uv_hi, uv_hi),
+            ),
+            YuvNVOrder::VU => (
+                vtrn2q_s32(uv_lo, uv_lo),
+                vtrn1q_s32(uv_lo, uv_lo),
+                vtrn2q_s32(uv_hi, uv_hi),
+                vtrn1q_s32(uv_hi, uv_hi),
+            ),
+        };
+
+        let mut u_sum_lo = vdupq_n_s32(0);
+        let mut v_sum_lo = vdupq_n_s32(0);
+        let mut u_sum_hi = vdupq_n_s32(0);
+        let mut v_sum_hi = vdupq_n_s32(0);
+
+        for (sprite, y_row) in sprites.iter().zip(y_rows.iter()) {
+            let px = vld4_u8(sprite.add(cx * 8));
+            let channels = [px.0, px.1, px.2, px.3];
+            let r = vmovl_u8(channels[src_chans.get_r_channel_offset()]);
+            let g = vmovl_u8(channels[src_chans.get_g_channel_offset()]);
+            let b = vmovl_u8(channels[src_chans.get_b_channel_offset()]);
+            let a = vmovl_u8(channels[src_chans.get_a_channel_offset()]);
+
+            let y_dst = y_row.add(cx * 2);
+            let y_values = vmovl_u8(vld1_u8(y_dst));
+
+            let (y_lo, u_lo_sum, v_lo_sum) = neon_blend_quad(
+                &coefficients,
+                neon_widen_lo(r),
+                neon_widen_lo(g),
+                neon_widen_lo(b),
+                neon_widen_lo(a),
+                neon_widen_lo(y_values),
+                u_lo,
+                v_lo,
+            );
+            let (y_hi, u_hi_sum, v_hi_sum) = neon_blend_quad(
+                &coefficients,
+                neon_widen_hi(r),
+                neon_widen_hi(g),
+                neon_widen_hi(b),
+                neon_widen_hi(a),
+                neon_widen_hi(y_values),
+                u_hi,
+                v_hi,
+            );
+            vst1_u8(y_dst, neon_narrow(y_lo, y_hi));
+
+            u_sum_lo = vaddq_s32(u_sum_lo, u_lo_sum);
+            v_sum_lo = vaddq_s32(v_sum_lo, v_lo_sum);
+            u_sum_hi = vaddq_s32(u_sum_hi, u_hi_sum);
+            v_sum_hi = vaddq_s32(v_sum_hi, v_hi_sum);
+        }
+
+        // Horizontal neighbours were summed pairwise, rows in the loop above
+        let rounding = vdupq_n_s32(2);
+        let u_lo = vshrq_n_s32::<2>(vaddq_s32(u_sum_lo, rounding));
+        let v_lo = vshrq_n_s32::<2>(vaddq_s32(v_sum_lo, rounding));
+        let u_hi = vshrq_n_s32::<2>(vaddq_s32(u_sum_hi, rounding));
+        let v_hi = vshrq_n_s32::<2>(vaddq_s32(v_sum_hi, rounding));
+        let (lo, hi) = match order {
+            YuvNVOrder::UV => (vzip1q_s32(u_lo, v_lo), vzip1q_s32(u_hi, v_hi)),
+            YuvNVOrder::VU => (vzip1q_s32(v_lo, u_lo), vzip1q_s32(v_hi, u_hi)),
+        };
+        vst1_u8(uv_ptr.add(cx * 2), neon_narrow(lo, hi));
+
+        cx += 4;
+    }
+
+    cx
+}
@@ -0,0 +1,87 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::chroma_key::ChromaKeyParams;
+use std::arch::aarch64::*;
+
+#[inline(always)]
+unsafe fn neon_key_alpha_ps(
+    d2: int32x4_t,
+    tolerance: float32x4_t,
+    scale: float32x4_t,
+) -> uint32x4_t {
+    let distance = vsqrtq_f32(vcvtq_f32_s32(d2));
+    let alpha = vminq_f32(
+        vmaxq_f32(
+            vmulq_f32(vsubq_f32(distance, tolerance), scale),
+            vdupq_n_f32(0.),
+        ),
+        vdupq_n_f32(255.),
+    );
+    vcvtq_u32_f32(vaddq_f32(alpha, vdupq_n_f32(0.5)))
+}
+
+/// Computes chroma key alpha for a row of chroma samples, returns count of samples processed
+#[inline(always)]
+pub unsafe fn neon_chroma_key_alpha_row(
+    params: &ChromaKeyParams,
+    u_plane: &[u8],
+    v_plane: &[u8],
+    alpha: &mut [u8],
+    width: usize,
+) -> usize {
+    let key_u = vdupq_n_s16(params.key_u);
+    let key_v = vdupq_n_s16(params.key_v);
+    let tolerance = vdupq_n_f32(params.tolerance);
+    let scale = vdupq_n_f32(params.scale);
+
+    let mut cx = 0usize;
+
+    while cx + 8 <= width {
+        let u = vreinterpretq_s16_u16(vmovl_u8(vld1_u8(u_plane.as_ptr().add(cx))));
+        let v = vreinterpretq_s16_u16(vmovl_u8(vld1_u8(v_plane.as_ptr().add(cx))));
+        let du = vsubq_s16(u, key_u);
+        let dv = vsubq_s16(v, key_v);
+
+        let d2_lo = vmlal_s16(
+            vmull_s16(vget_low_s16(du), vget_low_s16(du)),
+            vget_low_s16(dv),
+            vget_low_s16(dv),
+        );
+        let d2_hi = vmlal_high_s16(vmull_high_s16(du, du), dv, dv);
+
+        let a_lo = neon_key_alpha_ps(d2_lo, tolerance, scale);
+        let a_hi = neon_key_alpha_ps(d2_hi, tolerance, scale);
+        let packed = vmovn_u16(vcombine_u16(vmovn_u32(a_lo), vmovn_u32(a_hi)));
+        vst1_u8(alpha.as_mut_ptr().add(cx), packed);
+
+        cx += 8;
+    }
+
+    cx
+}
@@ -0,0 +1,159 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+use std::arch::aarch64::*;
+
+/// Averages `FACTOR`x`FACTOR` blocks of `FACTOR` source rows into a destination row,
+/// `CHANNELS` is 2 for interleaved chroma, returns count of destination samples processed
+#[inline(always)]
+pub unsafe fn neon_decimate_row<const FACTOR: usize, const CHANNELS: usize>(
+    rows: &[&[u8]; FACTOR],
+    dst: &mut [u8],
+    length: usize,
+) -> usize {
+    let dst_ptr = dst.as_mut_ptr();
+
+    let mut cx = 0usize;
+
+    while (cx + 16) * FACTOR <= length {
+        let src_x = cx * FACTOR;
+        if CHANNELS == 2 {
+            // Every de-interleaving load brings 16 source pairs
+            let mut u_sums = [vdupq_n_u16(0); 2];
+            let mut v_sums = [vdupq_n_u16(0); 2];
+            for row in rows.iter() {
+                for i in 0..FACTOR / 2 {
+                    let uv = vld2q_u8(row.as_ptr().add(src_x + i * 32));
+                    u_sums[i] = vpadalq_u8(u_sums[i], uv.0);
+                    v_sums[i] = vpadalq_u8(v_sums[i], uv.1);
+                }
+            }
+            let (u, v) = if FACTOR == 2 {
+                (vrshrn_n_u16::<2>(u_sums[0]), vrshrn_n_u16::<2>(v_sums[0]))
+            } else {
+                (
+                    vrshrn_n_u16::<4>(vpaddq_u16(u_sums[0], u_sums[1])),
+                    vrshrn_n_u16::<4>(vpaddq_u16(v_sums[0], v_sums[1])),
+                )
+            };
+            vst2_u8(dst_ptr.add(cx), uint8x8x2_t(u, v));
+        } else {
+            let mut sums = [vdupq_n_u16(0); FACTOR];
+            for row in rows.iter() {
+                for (i, sum) in sums.iter_mut().enumerate() {
+                    *sum = vpadalq_u8(*sum, vld1q_u8(row.as_ptr().add(src_x + i * 16)));
+                }
+            }
+            let result = if FACTOR == 2 {
+                vcombine_u8(vrshrn_n_u16::<2>(sums[0]), vrshrn_n_u16::<2>(sums[1]))
+            } else {
+                vcombine_u8(
+                    vrshrn_n_u16::<4>(vpaddq_u16(sums[0], sums[1])),
+                    vrshrn_n_u16::<4>(vpaddq_u16(sums[FACTOR - 2], sums[FACTOR - 1])),
+                )
+            };
+            vst1q_u8(dst_ptr.add(cx), result);
+        }
+
+        cx += 16;
+    }
+
+    cx
+}
+
+/// Averages `FACTOR`x`FACTOR` blocks of 16-bit samples, see [neon_decimate_row].
+///
+/// Samples are shifted right by `shift` before summing and the result is shifted back,
+/// so MSB aligned samples are averaged on their significant bits only.
+#[inline(always)]
+pub unsafe fn neon_decimate_row_u16<const FACTOR: usize, const CHANNELS: usize>(
+    rows: &[&[u16]; FACTOR],
+    dst: &mut [u16],
+    length: usize,
+    shift: u32,
+) -> usize {
+    let v_shr = vdupq_n_s16(-(shift as i16));
+    let v_shl = vdup_n_s16(shift as i16);
+
+    let dst_ptr = dst.as_mut_ptr();
+
+    let mut cx = 0usize;
+
+    while (cx + 8) * FACTOR <= length {
+        let src_x = cx * FACTOR;
+        if CHANNELS == 2 {
+            // Every de-interleaving load brings 8 source pairs
+            let mut u_sums = [vdupq_n_u32(0); 2];
+            let mut v_sums = [vdupq_n_u32(0); 2];
+            for row in rows.iter() {
+                for i in 0..FACTOR / 2 {
+                    let uv = vld2q_u16(row.as_ptr().add(src_x + i * 16));
+                    u_sums[i] = vpadalq_u16(u_sums[i], vshlq_u16(uv.0, v_shr));
+                    v_sums[i] = vpadalq_u16(v_sums[i], vshlq_u16(uv.1, v_shr));
+                }
+            }
+            let (u, v) = if FACTOR == 2 {
+                (vrshrn_n_u32::<2>(u_sums[0]), vrshrn_n_u32::<2>(v_sums[0]))
+            } else {
+                (
+                    vrshrn_n_u32::<4>(vpaddq_u32(u_sums[0], u_sums[1])),
+                    vrshrn_n_u32::<4>(vpaddq_u32(v_sums[0], v_sums[1])),
+                )
+            };
+            vst2_u16(
+                dst_ptr.add(cx),
+                uint16x4x2_t(vshl_u16(u, v_shl), vshl_u16(v, v_shl)),
+            );
+        } else {
+            let mut sums = [vdupq_n_u32(0); FACTOR];
+            for row in rows.iter() {
+                for (i, sum) in sums.iter_mut().enumerate() {
+                    let values = vshlq_u16(vld1q_u16(row.as_ptr().add(src_x + i * 8)), v_shr);
+                    *sum = vpadalq_u16(*sum, values);
+                }
+            }
+            let (lo, hi) = if FACTOR == 2 {
+                (vrshrn_n_u32::<2>(sums[0]), vrshrn_n_u32::<2>(sums[1]))
+            } else {
+                (
+                    vrshrn_n_u32::<4>(vpaddq_u32(sums[0], sums[1])),
+                    vrshrn_n_u32::<4>(vpaddq_u32(sums[FACTOR - 2], sums[FACTOR - 1])),
+                )
+            };
+            vst1q_u16(
+                dst_ptr.add(cx),
+                vcombine_u16(vshl_u16(lo, v_shl), vshl_u16(hi, v_shl)),
+            );
+        }
+
+        cx += 8;
+    }
+
+    cx
+}
@@ -58,14 +58,14 @@ pub unsafe fn gbr_to_image_neon<const DESTINATION_CHANNELS: u8>(
             YuvSourceChannels::Rgb => {
                 vst3q_u8(rgb_start_ptr, uint8x16x3_t(r_pixel, g_pixel, b_pixel));
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let a_pixel = vdupq_n_u8(255);
                 vst4q_u8(
                     rgb_start_ptr,
                     uint8x16x4_t(r_pixel, g_pixel, b_pixel, a_pixel),
                 );
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let a_pixel = vdupq_n_u8(255);
                 vst4q_u8(
                     rgb_start_ptr,
@@ -95,14 +95,14 @@ pub unsafe fn gbr_to_image_neon<const DESTINATION_CHANNELS: u8>(
             YuvSourceChannels::Rgb => {
                 vst3_u8(rgb_start_ptr, uint8x8x3_t(r_pixel, g_pixel, b_pixel));
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let a_pixel = vdup_n_u8(255);
                 vst4_u8(
                     rgb_start_ptr,
                     uint8x8x4_t(r_pixel, g_pixel, b_pixel, a_pixel),
                 );
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let a_pixel = vdup_n_u8(255);
                 vst4_u8(
                     rgb_start_ptr,
@@ -59,13 +59,13 @@ pub unsafe fn gbr_to_image_neon_p16<const DESTINATION_CHANNELS: u8>(
             YuvSourceChannels::Rgb => {
                 vst3q_u16(rgb_start_ptr, uint16x8x3_t(r_pixel, g_pixel, b_pixel));
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 vst4q_u16(
                     rgb_start_ptr,
                     uint16x8x4_t(r_pixel, g_pixel, b_pixel, v_max_colors),
                 );
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 vst4q_u16(
                     rgb_start_ptr,
                     uint16x8x4_t(b_pixel, g_pixel, r_pixel, v_max_colors),
@@ -94,13 +94,13 @@ pub unsafe fn gbr_to_image_neon_p16<const DESTINATION_CHANNELS: u8>(
             YuvSourceChannels::Rgb => {
                 vst3_u16(rgb_start_ptr, uint16x4x3_t(r_pixel, g_pixel, b_pixel));
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 vst4_u16(
                     rgb_start_ptr,
                     uint16x4x4_t(r_pixel, g_pixel, b_pixel, vget_low_u16(v_max_colors)),
                 );
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 vst4_u16(
                     rgb_start_ptr,
                     uint16x4x4_t(b_pixel, g_pixel, r_pixel, vget_low_u16(v_max_colors)),
@@ -0,0 +1,117 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::arch::aarch64::*;
+
+/// Rotates Cb and Cr rows in place 16 samples at a time, returns count of samples processed.
+///
+/// `cos` and `sin` are Q15, every product is rounded on its own exactly as the scalar path does.
+#[inline(always)]
+pub unsafe fn neon_rotate_hue_row(
+    u_row: &mut [u8],
+    v_row: &mut [u8],
+    width: usize,
+    cos: i16,
+    sin: i16,
+) -> usize {
+    let bias = vdupq_n_s16(128);
+    let v_cos = vdupq_n_s16(cos);
+    let v_sin = vdupq_n_s16(sin);
+    let v_neg_sin = vdupq_n_s16(-sin);
+
+    let mut cx = 0usize;
+
+    while cx + 16 <= width {
+        let u_values = vld1q_u8(u_row.as_ptr().add(cx));
+        let v_values = vld1q_u8(v_row.as_ptr().add(cx));
+        let u_lo = vsubq_s16(vreinterpretq_s16_u16(vmovl_u8(vget_low_u8(u_values))), bias);
+        let u_hi = vsubq_s16(vreinterpretq_s16_u16(vmovl_high_u8(u_values)), bias);
+        let v_lo = vsubq_s16(vreinterpretq_s16_u16(vmovl_u8(vget_low_u8(v_values))), bias);
+        let v_hi = vsubq_s16(vreinterpretq_s16_u16(vmovl_high_u8(v_values)), bias);
+
+        let rotated_u_lo = vaddq_s16(vqrdmulhq_s16(u_lo, v_cos), vqrdmulhq_s16(v_lo, v_neg_sin));
+        let rotated_u_hi = vaddq_s16(vqrdmulhq_s16(u_hi, v_cos), vqrdmulhq_s16(v_hi, v_neg_sin));
+        let rotated_v_lo = vaddq_s16(vqrdmulhq_s16(v_lo, v_cos), vqrdmulhq_s16(u_lo, v_sin));
+        let rotated_v_hi = vaddq_s16(vqrdmulhq_s16(v_hi, v_cos), vqrdmulhq_s16(u_hi, v_sin));
+
+        let u_packed = vcombine_u8(
+            vqmovun_s16(vaddq_s16(rotated_u_lo, bias)),
+            vqmovun_s16(vaddq_s16(rotated_u_hi, bias)),
+        );
+        let v_packed = vcombine_u8(
+            vqmovun_s16(vaddq_s16(rotated_v_lo, bias)),
+            vqmovun_s16(vaddq_s16(rotated_v_hi, bias)),
+        );
+        vst1q_u8(u_row.as_mut_ptr().add(cx), u_packed);
+        vst1q_u8(v_row.as_mut_ptr().add(cx), v_packed);
+        cx += 16;
+    }
+
+    cx
+}
+
+/// Rotates interleaved chroma pairs in place 8 pairs at a time, returns count of bytes processed.
+///
+/// First sample of a pair is taken as Cb, callers flip `sin` for Cr first order.
+#[inline(always)]
+pub unsafe fn neon_rotate_hue_interleaved_row(
+    uv_row: &mut [u8],
+    length: usize,
+    cos: i16,
+    sin: i16,
+) -> usize {
+    let bias = vdupq_n_s16(128);
+    let v_cos = vdupq_n_s16(cos);
+    // Pair partners are swapped, so the first of a pair takes -sin and the second +sin
+    let sin_pattern: [i16; 8] = [-sin, sin, -sin, sin, -sin, sin, -sin, sin];
+    let v_sin = vld1q_s16(sin_pattern.as_ptr());
+
+    let mut cx = 0usize;
+
+    while cx + 16 <= length {
+        let values = vld1q_u8(uv_row.as_ptr().add(cx));
+        let lo = vsubq_s16(vreinterpretq_s16_u16(vmovl_u8(vget_low_u8(values))), bias);
+        let hi = vsubq_s16(vreinterpretq_s16_u16(vmovl_high_u8(values)), bias);
+        let rotated_lo = vaddq_s16(
+            vqrdmulhq_s16(lo, v_cos),
+            vqrdmulhq_s16(vrev32q_s16(lo), v_sin),
+        );
+        let rotated_hi = vaddq_s16(
+            vqrdmulhq_s16(hi, v_cos),
+            vqrdmulhq_s16(vrev32q_s16(hi), v_sin),
+        );
+        let packed = vcombine_u8(
+            vqmovun_s16(vaddq_s16(rotated_lo, bias)),
+            vqmovun_s16(vaddq_s16(rotated_hi, bias)),
+        );
+        vst1q_u8(uv_row.as_mut_ptr().add(cx), packed);
+        cx += 16;
+    }
+
+    cx
+}
@@ -0,0 +1,60 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::arch::aarch64::*;
+
+/// Maps a row of 8-bit samples through a 256 entries table 16 pixels at a time,
+/// returns count of pixels processed.
+///
+/// Table is split into four 64 byte quarters, `vqtbx4q_u8` leaves lanes with out of range
+/// indices untouched so every quarter fills only its own lanes.
+#[inline(always)]
+pub unsafe fn neon_luma_lut_row(lut: &[u8; 256], row: &mut [u8], width: usize) -> usize {
+    let t0 = vld1q_u8_x4(lut.as_ptr());
+    let t1 = vld1q_u8_x4(lut.as_ptr().add(64));
+    let t2 = vld1q_u8_x4(lut.as_ptr().add(128));
+    let t3 = vld1q_u8_x4(lut.as_ptr().add(192));
+    let quarter = vdupq_n_u8(64);
+
+    let mut cx = 0usize;
+
+    while cx + 16 <= width {
+        let index = vld1q_u8(row.as_ptr().add(cx));
+        let mut mapped = vqtbl4q_u8(t0, index);
+        let index = vsubq_u8(index, quarter);
+        mapped = vqtbx4q_u8(mapped, t1, index);
+        let index = vsubq_u8(index, quarter);
+        mapped = vqtbx4q_u8(mapped, t2, index);
+        let index = vsubq_u8(index, quarter);
+        mapped = vqtbx4q_u8(mapped, t3, index);
+        vst1q_u8(row.as_mut_ptr().add(cx), mapped);
+        cx += 16;
+    }
+
+    cx
+}
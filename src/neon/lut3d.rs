@@ -0,0 +1,80 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+use crate::lut3d::Lut3dGrid;
+use crate::yuv_support::YuvSourceChannels;
+use std::arch::aarch64::*;
+
+#[inline(always)]
+unsafe fn blend_taps<const TAPS: usize>(
+    table: &[[f32; 4]],
+    offsets: &[usize; TAPS],
+    weights: &[f32; TAPS],
+) -> uint8x8_t {
+    let mut sum = vdupq_n_f32(0.);
+    for (&offset, &weight) in offsets.iter().zip(weights.iter()) {
+        let entry = vld1q_f32(table.get_unchecked(offset).as_ptr());
+        sum = vaddq_f32(sum, vmulq_f32(entry, vdupq_n_f32(weight)));
+    }
+    let scaled = vaddq_f32(vmulq_f32(sum, vdupq_n_f32(255.)), vdupq_n_f32(0.5));
+    let values = vqmovun_s32(vcvtq_s32_f32(scaled));
+    vqmovn_u16(vcombine_u16(values, values))
+}
+
+/// Maps a row of interleaved pixels through a 3D LUT in place, blending all channels
+/// of a grid entry in one vector, returns count of pixels processed
+#[inline(always)]
+pub unsafe fn neon_lut3d_row<const DESTINATION_CHANNELS: u8, const TETRAHEDRAL: bool>(
+    table: &[[f32; 4]],
+    grid: &Lut3dGrid,
+    row: &mut [u8],
+    width: usize,
+) -> usize {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+    let r_offset = dst_chans.get_r_channel_offset();
+    let g_offset = dst_chans.get_g_channel_offset();
+    let b_offset = dst_chans.get_b_channel_offset();
+
+    for dst in row[..width * channels].chunks_exact_mut(channels) {
+        let (r, g, b) = (dst[r_offset], dst[g_offset], dst[b_offset]);
+        let mapped = if TETRAHEDRAL {
+            let (offsets, weights) = grid.tetrahedral_taps(r, g, b);
+            blend_taps(table, &offsets, &weights)
+        } else {
+            let (offsets, weights) = grid.trilinear_taps(r, g, b);
+            blend_taps(table, &offsets, &weights)
+        };
+        dst[r_offset] = vget_lane_u8::<0>(mapped);
+        dst[g_offset] = vget_lane_u8::<1>(mapped);
+        dst[b_offset] = vget_lane_u8::<2>(mapped);
+    }
+
+    width
+}
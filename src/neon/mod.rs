@@ -27,11 +27,21 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
+mod blend_nv;
+mod chroma_key;
+mod decimate;
 mod from_identity;
 mod from_identity_p16;
+mod hue_rotation;
+mod luma_gray;
+mod luma_lut;
+mod lut3d;
 mod neon_simd_support;
 mod neon_ycgco;
 mod neon_ycgco_r;
+mod plane_depth;
+mod pyramid;
+mod reorder_channels;
 mod rgb_to_y;
 mod rgb_to_ycgco;
 mod rgb_to_ycgco_r;
@@ -39,6 +49,7 @@ mod rgb_to_yuv_p16;
 mod rgba_to_nv;
 mod rgba_to_yuv;
 mod to_identity;
+mod v410;
 mod y_p16_to_rgba16;
 mod y_to_rgb;
 mod ycgco_to_rgb;
@@ -53,12 +64,23 @@ mod yuv_p16_to_rgba16_alpha;
 mod yuv_p16_to_rgba_alpha;
 mod yuv_to_rgba;
 mod yuv_to_rgba_alpha;
+mod yuv_to_rgba_f32;
 mod yuv_to_yuy2;
 mod yuy2_to_rgb;
 mod yuy2_to_yuv;
 
+pub use blend_nv::neon_blend_rgba_nv_row;
+pub use chroma_key::neon_chroma_key_alpha_row;
+pub use decimate::{neon_decimate_row, neon_decimate_row_u16};
 pub use from_identity::gbr_to_image_neon;
 pub use from_identity_p16::gbr_to_image_neon_p16;
+pub use hue_rotation::{neon_rotate_hue_interleaved_row, neon_rotate_hue_row};
+pub use luma_gray::neon_expand_luma_row;
+pub use luma_lut::neon_luma_lut_row;
+pub use lut3d::neon_lut3d_row;
+pub use plane_depth::{neon_plane_u16_to_u8_row, neon_plane_u8_to_u16_row};
+pub use pyramid::neon_box_decimate_row;
+pub use reorder_channels::neon_reorder_channels_row;
 pub use rgb_to_y::neon_rgb_to_y_row;
 pub use rgb_to_ycgco::neon_rgb_to_ycgco_row;
 pub use rgb_to_ycgco_r::neon_rgb_to_ycgcor_row;
@@ -66,6 +88,7 @@ pub use rgb_to_yuv_p16::neon_rgba_to_yuv_p16;
 pub use rgba_to_nv::neon_rgbx_to_nv_row;
 pub use rgba_to_yuv::neon_rgba_to_yuv;
 pub use to_identity::image_to_gbr_neon;
+pub use v410::{neon_v410_to_yuv444_row, neon_yuv444_to_v410_row};
 pub use y_p16_to_rgba16::neon_y_p16_to_rgba16_row;
 pub use y_to_rgb::neon_y_to_rgb_row;
 pub use ycgco_to_rgb::neon_ycgco_to_rgb_row;
@@ -80,6 +103,7 @@ pub use yuv_p16_to_rgba16_alpha::neon_yuv_p16_to_rgba16_alpha_row;
 pub use yuv_p16_to_rgba_alpha::neon_yuv_p16_to_rgba_alpha_row;
 pub use yuv_to_rgba::neon_yuv_to_rgba_row;
 pub use yuv_to_rgba_alpha::neon_yuv_to_rgba_alpha;
+pub use yuv_to_rgba_f32::neon_yuv_to_rgba_f32_row;
 pub use yuv_to_yuy2::yuv_to_yuy2_neon_impl;
 pub use yuy2_to_rgb::yuy2_to_rgb_neon;
 pub use yuy2_to_yuv::yuy2_to_yuv_neon_impl;
@@ -43,3 +43,17 @@ pub unsafe fn neon_premultiply_alpha(v: uint8x16_t, a_values: uint8x16_t) -> uin
     let lo = neon_div_by_255(acc_lo);
     vcombine_u8(lo, hi)
 }
+
+/// Stores 16 RGBX pixels keeping the 4th byte of every destination pixel untouched
+#[inline(always)]
+pub unsafe fn neon_store_rgbx(ptr: *mut u8, r: uint8x16_t, g: uint8x16_t, b: uint8x16_t) {
+    let x = vld4q_u8(ptr).3;
+    vst4q_u8(ptr, uint8x16x4_t(r, g, b, x));
+}
+
+/// Stores 8 RGBX pixels keeping the 4th byte of every destination pixel untouched
+#[inline(always)]
+pub unsafe fn neon_store_half_rgbx(ptr: *mut u8, r: uint8x8_t, g: uint8x8_t, b: uint8x8_t) {
+    let x = vld4_u8(ptr).3;
+    vst4_u8(ptr, uint8x8x4_t(r, g, b, x));
+}
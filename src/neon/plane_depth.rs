@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::arch::aarch64::*;
+
+/// Widens a row of 8-bit samples to 16 bits shifted left by `shift` 16 samples at a time,
+/// returns count of samples processed.
+#[inline(always)]
+pub unsafe fn neon_plane_u8_to_u16_row(
+    src: &[u8],
+    dst: &mut [u16],
+    width: usize,
+    shift: u32,
+) -> usize {
+    let count = vdupq_n_s16(shift as i16);
+
+    let mut cx = 0usize;
+
+    while cx + 16 <= width {
+        let values = vld1q_u8(src.as_ptr().add(cx));
+        let lo = vshlq_u16(vmovl_u8(vget_low_u8(values)), count);
+        let hi = vshlq_u16(vmovl_high_u8(values), count);
+        vst1q_u16(dst.as_mut_ptr().add(cx), lo);
+        vst1q_u16(dst.as_mut_ptr().add(cx + 8), hi);
+        cx += 16;
+    }
+
+    cx
+}
+
+/// Narrows a row of 16-bit samples to 8 bits shifted right by `shift` with rounding
+/// and saturation 16 samples at a time, returns count of samples processed.
+#[inline(always)]
+pub unsafe fn neon_plane_u16_to_u8_row(
+    src: &[u16],
+    dst: &mut [u8],
+    width: usize,
+    shift: u32,
+) -> usize {
+    // Rounding shift keeps the carry, result matches `(v + half) >> shift` computed in 32 bits
+    let count = vdupq_n_s16(-(shift as i16));
+
+    let mut cx = 0usize;
+
+    while cx + 16 <= width {
+        let lo = vrshlq_u16(vld1q_u16(src.as_ptr().add(cx)), count);
+        let hi = vrshlq_u16(vld1q_u16(src.as_ptr().add(cx + 8)), count);
+        vst1q_u8(
+            dst.as_mut_ptr().add(cx),
+            vcombine_u8(vqmovn_u16(lo), vqmovn_u16(hi)),
+        );
+        cx += 16;
+    }
+
+    cx
+}
@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+use std::arch::aarch64::*;
+
+/// Averages 2x2 blocks of two source rows into a destination row,
+/// returns count of destination pixels processed
+#[inline(always)]
+pub unsafe fn neon_box_decimate_row(
+    row0: &[u8],
+    row1: &[u8],
+    dst: &mut [u8],
+    src_width: usize,
+) -> usize {
+    let row0_ptr = row0.as_ptr();
+    let row1_ptr = row1.as_ptr();
+    let dst_ptr = dst.as_mut_ptr();
+
+    let mut cx = 0usize;
+
+    while (cx + 16) * 2 <= src_width {
+        let src_x = cx * 2;
+        let top = vld1q_u8_x2(row0_ptr.add(src_x));
+        let bottom = vld1q_u8_x2(row1_ptr.add(src_x));
+
+        let sum_lo = vpadalq_u8(vpaddlq_u8(top.0), bottom.0);
+        let sum_hi = vpadalq_u8(vpaddlq_u8(top.1), bottom.1);
+
+        let result = vcombine_u8(vrshrn_n_u16::<2>(sum_lo), vrshrn_n_u16::<2>(sum_hi));
+        vst1q_u8(dst_ptr.add(cx), result);
+
+        cx += 16;
+    }
+
+    cx
+}
@@ -73,13 +73,13 @@ pub unsafe fn neon_rgb_to_y_row<const ORIGIN_CHANNELS: u8>(
                     b_values_u8 = rgb_values.0;
                 }
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let rgb_values = vld4q_u8(rgba_ptr.add(rgba_offset + cx * channels));
                 r_values_u8 = rgb_values.0;
                 g_values_u8 = rgb_values.1;
                 b_values_u8 = rgb_values.2;
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let rgb_values = vld4q_u8(rgba_ptr.add(rgba_offset + cx * channels));
                 r_values_u8 = rgb_values.2;
                 g_values_u8 = rgb_values.1;
@@ -102,13 +102,13 @@ pub unsafe fn neon_rgba_to_yuv_p16<
                     b_values = rgb_values.0;
                 }
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let rgb_values = vld4q_u16(src_ptr);
                 r_values = rgb_values.0;
                 g_values = rgb_values.1;
                 b_values = rgb_values.2;
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let rgb_values = vld4q_u16(src_ptr);
                 r_values = rgb_values.2;
                 g_values = rgb_values.1;
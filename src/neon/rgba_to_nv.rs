@@ -67,7 +67,8 @@ pub unsafe fn neon_rgbx_to_nv_row<
 
     let i_bias_y = vdupq_n_s16(range.bias_y as i16);
     let i_cap_y = vdupq_n_u16(range.range_y as u16 + range.bias_y as u16);
-    let i_cap_uv = vdupq_n_u16(range.bias_y as u16 + range.range_uv as u16);
+    let i_min_uv = vdupq_n_s16(range.min_uv() as i16);
+    let i_cap_uv = vdupq_n_u16(range.max_uv() as u16);
 
     let y_bias = vdupq_n_s32(bias_y);
     let uv_bias = vdupq_n_s32(bias_uv);
@@ -103,13 +104,13 @@ pub unsafe fn neon_rgbx_to_nv_row<
                     b_values_u8 = rgb_values.0;
                 }
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let rgb_values = vld4q_u8(rgba_ptr.add(rgba_offset + cx * channels));
                 r_values_u8 = rgb_values.0;
                 g_values_u8 = rgb_values.1;
                 b_values_u8 = rgb_values.2;
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let rgb_values = vld4q_u8(rgba_ptr.add(rgba_offset + cx * channels));
                 r_values_u8 = rgb_values.2;
                 g_values_u8 = rgb_values.1;
@@ -184,7 +185,7 @@ pub unsafe fn neon_rgbx_to_nv_row<
             let cb_high = vminq_u16(
                 vreinterpretq_u16_s16(vmaxq_s16(
                     vcombine_s16(vshrn_n_s32::<8>(cb_h_low), vshrn_n_s32::<8>(cb_h_high)),
-                    i_bias_y,
+                    i_min_uv,
                 )),
                 i_cap_uv,
             );
@@ -200,7 +201,7 @@ pub unsafe fn neon_rgbx_to_nv_row<
             let cr_high = vminq_u16(
                 vreinterpretq_u16_s16(vmaxq_s16(
                     vcombine_s16(vshrn_n_s32::<8>(cr_h_low), vshrn_n_s32::<8>(cr_h_high)),
-                    i_bias_y,
+                    i_min_uv,
                 )),
                 i_cap_uv,
             );
@@ -216,7 +217,7 @@ pub unsafe fn neon_rgbx_to_nv_row<
             let cb_low = vminq_u16(
                 vreinterpretq_u16_s16(vmaxq_s16(
                     vcombine_s16(vshrn_n_s32::<8>(cb_l_low), vshrn_n_s32::<8>(cb_l_high)),
-                    i_bias_y,
+                    i_min_uv,
                 )),
                 i_cap_uv,
             );
@@ -232,7 +233,7 @@ pub unsafe fn neon_rgbx_to_nv_row<
             let cr_low = vminq_u16(
                 vreinterpretq_u16_s16(vmaxq_s16(
                     vcombine_s16(vshrn_n_s32::<8>(cr_l_low), vshrn_n_s32::<8>(cr_l_high)),
-                    i_bias_y,
+                    i_min_uv,
                 )),
                 i_cap_uv,
             );
@@ -66,7 +66,8 @@ pub unsafe fn neon_rgba_to_yuv<
 
     let i_bias_y = vdupq_n_s16(range.bias_y as i16);
     let i_cap_y = vdupq_n_u16(range.range_y as u16 + range.bias_y as u16);
-    let i_cap_uv = vdupq_n_u16(range.bias_y as u16 + range.range_uv as u16);
+    let i_min_uv = vdupq_n_s16(range.min_uv() as i16);
+    let i_cap_uv = vdupq_n_u16(range.max_uv() as u16);
 
     let y_bias = vdupq_n_s32(bias_y);
     let uv_bias = vdupq_n_s32(bias_uv);
@@ -103,13 +104,13 @@ pub unsafe fn neon_rgba_to_yuv<
                     b_values_u8 = rgb_values.0;
                 }
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let rgb_values = vld4q_u8(rgba_ptr.add(rgba_offset + cx * channels));
                 r_values_u8 = rgb_values.0;
                 g_values_u8 = rgb_values.1;
                 b_values_u8 = rgb_values.2;
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let rgb_values = vld4q_u8(rgba_ptr.add(rgba_offset + cx * channels));
                 r_values_u8 = rgb_values.2;
                 g_values_u8 = rgb_values.1;
@@ -193,7 +194,7 @@ pub unsafe fn neon_rgba_to_yuv<
                         vshrn_n_s32::<PRECISION>(cb_h_low),
                         vshrn_n_s32::<PRECISION>(cb_h_high),
                     ),
-                    i_bias_y,
+                    i_min_uv,
                 )),
                 i_cap_uv,
             );
@@ -212,7 +213,7 @@ pub unsafe fn neon_rgba_to_yuv<
                         vshrn_n_s32::<PRECISION>(cr_h_low),
                         vshrn_n_s32::<PRECISION>(cr_h_high),
                     ),
-                    i_bias_y,
+                    i_min_uv,
                 )),
                 i_cap_uv,
             );
@@ -231,7 +232,7 @@ pub unsafe fn neon_rgba_to_yuv<
                         vshrn_n_s32::<PRECISION>(cb_l_low),
                         vshrn_n_s32::<PRECISION>(cb_l_high),
                     ),
-                    i_bias_y,
+                    i_min_uv,
                 )),
                 i_cap_uv,
             );
@@ -250,7 +251,7 @@ pub unsafe fn neon_rgba_to_yuv<
                         vshrn_n_s32::<PRECISION>(cr_l_low),
                         vshrn_n_s32::<PRECISION>(cr_l_high),
                     ),
-                    i_bias_y,
+                    i_min_uv,
                 )),
                 i_cap_uv,
             );
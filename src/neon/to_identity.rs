@@ -59,13 +59,13 @@ pub unsafe fn image_to_gbr_neon<const SOURCE_CHANNELS: u8>(
                 g_pixel = rgb_pixel.1;
                 b_pixel = rgb_pixel.2;
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let rgb_pixel = vld4q_u8(rgb_start_ptr);
                 r_pixel = rgb_pixel.0;
                 g_pixel = rgb_pixel.1;
                 b_pixel = rgb_pixel.2;
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let rgb_pixel = vld4q_u8(rgb_start_ptr);
                 r_pixel = rgb_pixel.2;
                 g_pixel = rgb_pixel.1;
@@ -100,13 +100,13 @@ pub unsafe fn image_to_gbr_neon<const SOURCE_CHANNELS: u8>(
                 g_pixel = rgb_pixel.1;
                 b_pixel = rgb_pixel.2;
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let rgb_pixel = vld4_u8(rgb_start_ptr);
                 r_pixel = rgb_pixel.0;
                 g_pixel = rgb_pixel.1;
                 b_pixel = rgb_pixel.2;
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let rgb_pixel = vld4_u8(rgb_start_ptr);
                 r_pixel = rgb_pixel.2;
                 g_pixel = rgb_pixel.1;
@@ -0,0 +1,113 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::arch::aarch64::*;
+
+/// Splits a row of v410 words into Y, U and V samples 8 pixels at a time,
+/// returns count of pixels processed.
+#[inline(always)]
+pub unsafe fn neon_v410_to_yuv444_row(
+    v410: &[u32],
+    y_plane: &mut [u16],
+    u_plane: &mut [u16],
+    v_plane: &mut [u16],
+    width: usize,
+) -> usize {
+    let mask = vdupq_n_u32(0x3ff);
+
+    let mut cx = 0usize;
+
+    while cx + 8 <= width {
+        let lo = vld1q_u32(v410.as_ptr().add(cx));
+        let hi = vld1q_u32(v410.as_ptr().add(cx + 4));
+
+        let u_values = vcombine_u16(
+            vmovn_u32(vandq_u32(vshrq_n_u32::<2>(lo), mask)),
+            vmovn_u32(vandq_u32(vshrq_n_u32::<2>(hi), mask)),
+        );
+        let y_values = vcombine_u16(
+            vmovn_u32(vandq_u32(vshrq_n_u32::<12>(lo), mask)),
+            vmovn_u32(vandq_u32(vshrq_n_u32::<12>(hi), mask)),
+        );
+        let v_values = vcombine_u16(
+            vmovn_u32(vshrq_n_u32::<22>(lo)),
+            vmovn_u32(vshrq_n_u32::<22>(hi)),
+        );
+
+        vst1q_u16(y_plane.as_mut_ptr().add(cx), y_values);
+        vst1q_u16(u_plane.as_mut_ptr().add(cx), u_values);
+        vst1q_u16(v_plane.as_mut_ptr().add(cx), v_values);
+
+        cx += 8;
+    }
+
+    cx
+}
+
+/// Packs Y, U and V samples into a row of v410 words 8 pixels at a time,
+/// returns count of pixels processed.
+#[inline(always)]
+pub unsafe fn neon_yuv444_to_v410_row(
+    y_plane: &[u16],
+    u_plane: &[u16],
+    v_plane: &[u16],
+    v410: &mut [u32],
+    width: usize,
+) -> usize {
+    let mask = vdupq_n_u16(0x3ff);
+
+    let mut cx = 0usize;
+
+    while cx + 8 <= width {
+        let y_values = vandq_u16(vld1q_u16(y_plane.as_ptr().add(cx)), mask);
+        let u_values = vandq_u16(vld1q_u16(u_plane.as_ptr().add(cx)), mask);
+        let v_values = vandq_u16(vld1q_u16(v_plane.as_ptr().add(cx)), mask);
+
+        let lo = vorrq_u32(
+            vorrq_u32(
+                vshlq_n_u32::<2>(vmovl_u16(vget_low_u16(u_values))),
+                vshlq_n_u32::<12>(vmovl_u16(vget_low_u16(y_values))),
+            ),
+            vshlq_n_u32::<22>(vmovl_u16(vget_low_u16(v_values))),
+        );
+        let hi = vorrq_u32(
+            vorrq_u32(
+                vshlq_n_u32::<2>(vmovl_high_u16(u_values)),
+                vshlq_n_u32::<12>(vmovl_high_u16(y_values)),
+            ),
+            vshlq_n_u32::<22>(vmovl_high_u16(v_values)),
+        );
+
+        vst1q_u32(v410.as_mut_ptr().add(cx), lo);
+        vst1q_u32(v410.as_mut_ptr().add(cx + 4), hi);
+
+        cx += 8;
+    }
+
+    cx
+}
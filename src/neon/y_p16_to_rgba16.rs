@@ -104,11 +104,11 @@ pub unsafe fn neon_y_p16_to_rgba16_row<
                 let dst_pack = uint16x8x3_t(r_values, r_values, r_values);
                 vst3q_u16(dst_ptr.add(dst_offset + cx * channels), dst_pack);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let dst_pack = uint16x8x4_t(r_values, r_values, r_values, v_alpha);
                 vst4q_u16(dst_ptr.add(dst_offset + cx * channels), dst_pack);
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let dst_pack = uint16x8x4_t(r_values, r_values, r_values, v_alpha);
                 vst4q_u16(dst_ptr.add(dst_offset + cx * channels), dst_pack);
             }
@@ -137,11 +137,11 @@ pub unsafe fn neon_ycgcor_to_rgb_row<const DESTINATION_CHANNELS: u8, const SAMPL
                 let dst_pack: uint8x16x3_t = uint8x16x3_t(b_values, g_values, r_values);
                 vst3q_u8(rgba_ptr.add(dst_shift), dst_pack);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let dst_pack: uint8x16x4_t = uint8x16x4_t(r_values, g_values, b_values, v_alpha);
                 vst4q_u8(rgba_ptr.add(dst_shift), dst_pack);
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let dst_pack: uint8x16x4_t = uint8x16x4_t(b_values, g_values, r_values, v_alpha);
                 vst4q_u8(rgba_ptr.add(dst_shift), dst_pack);
             }
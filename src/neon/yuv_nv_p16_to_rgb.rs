@@ -198,11 +198,11 @@ pub unsafe fn neon_yuv_nv_p16_to_rgba_row<
                 let dst_pack = uint16x8x3_t(b_values, g_values, r_values);
                 vst3q_u16(dst_ptr, dst_pack);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let dst_pack = uint16x8x4_t(r_values, g_values, b_values, v_max_colors);
                 vst4q_u16(dst_ptr, dst_pack);
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let dst_pack = uint16x8x4_t(b_values, g_values, r_values, v_max_colors);
                 vst4q_u16(dst_ptr, dst_pack);
             }
@@ -28,6 +28,7 @@
  */
 
 use crate::internals::ProcessedOffset;
+use crate::neon::neon_simd_support::{neon_store_half_rgbx, neon_store_rgbx};
 use crate::yuv_support::{
     CbCrInverseTransform, YuvChromaRange, YuvChromaSample, YuvNVOrder, YuvSourceChannels,
 };
@@ -49,6 +50,7 @@ pub unsafe fn neon_yuv_nv_to_rgba_row<
     uv_offset: usize,
     rgba_offset: usize,
     width: usize,
+    alpha: u8,
 ) -> ProcessedOffset {
     let order: YuvNVOrder = UV_ORDER.into();
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
@@ -67,7 +69,7 @@ pub unsafe fn neon_yuv_nv_to_rgba_row<
     let v_min_values = vdupq_n_s16(0i16);
     let v_g_coeff_1 = vdupq_n_s16(-(transform.g_coeff_1 as i16));
     let v_g_coeff_2 = vdupq_n_s16(-(transform.g_coeff_2 as i16));
-    let v_alpha = vdupq_n_u8(255u8);
+    let v_alpha = vdupq_n_u8(alpha);
 
     let mut cx = start_cx;
     let mut ux = start_ux;
@@ -171,6 +173,12 @@ pub unsafe fn neon_yuv_nv_to_rgba_row<
                 let dst_pack: uint8x16x4_t = uint8x16x4_t(b_values, g_values, r_values, v_alpha);
                 vst4q_u8(bgra_ptr.add(dst_shift), dst_pack);
             }
+            YuvSourceChannels::Rgbx => {
+                neon_store_rgbx(bgra_ptr.add(dst_shift), r_values, g_values, b_values);
+            }
+            YuvSourceChannels::Bgrx => {
+                neon_store_rgbx(bgra_ptr.add(dst_shift), b_values, g_values, r_values);
+            }
         }
 
         cx += 16;
@@ -263,6 +271,12 @@ pub unsafe fn neon_yuv_nv_to_rgba_row<
                     uint8x8x4_t(b_values, g_values, r_values, vget_low_u8(v_alpha));
                 vst4_u8(bgra_ptr.add(dst_shift), dst_pack);
             }
+            YuvSourceChannels::Rgbx => {
+                neon_store_half_rgbx(bgra_ptr.add(dst_shift), r_values, g_values, b_values);
+            }
+            YuvSourceChannels::Bgrx => {
+                neon_store_half_rgbx(bgra_ptr.add(dst_shift), b_values, g_values, r_values);
+            }
         }
 
         cx += 8;
@@ -179,11 +179,11 @@ pub unsafe fn neon_yuv_p16_to_rgba_alpha_row<
         match destination_channels {
             YuvSourceChannels::Rgb => {}
             YuvSourceChannels::Bgr => {}
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let dst_pack: uint8x8x4_t = uint8x8x4_t(r_values, g_values, b_values, v_alpha);
                 vst4_u8(dst_ptr.add(dst_offset + cx * channels), dst_pack);
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let dst_pack: uint8x8x4_t = uint8x8x4_t(b_values, g_values, r_values, v_alpha);
                 vst4_u8(dst_ptr.add(dst_offset + cx * channels), dst_pack);
             }
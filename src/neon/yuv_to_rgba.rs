@@ -28,6 +28,7 @@
  */
 
 use crate::internals::ProcessedOffset;
+use crate::neon::neon_simd_support::{neon_store_half_rgbx, neon_store_rgbx};
 use crate::yuv_support::{
     CbCrInverseTransform, YuvChromaRange, YuvChromaSample, YuvSourceChannels,
 };
@@ -48,6 +49,7 @@ pub unsafe fn neon_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLIN
     v_offset: usize,
     rgba_offset: usize,
     width: usize,
+    alpha: u8,
 ) -> ProcessedOffset {
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
@@ -69,9 +71,9 @@ pub unsafe fn neon_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLIN
     let v_min_values = vdupq_n_s16(0i16);
     let v_g_coeff_1 = vdupq_n_s16(-(transform.g_coeff_1 as i16));
     let v_g_coeff_2 = vdupq_n_s16(-(transform.g_coeff_2 as i16));
-    let v_alpha = vdupq_n_u8(255u8);
+    let v_alpha = vdupq_n_u8(alpha);
 
-    while cx + 16 < width {
+    while cx + 16 <= width {
         let y_values = vqsubq_u8(vld1q_u8(y_ptr.add(y_offset + cx)), y_corr);
 
         let u_high_u8: uint8x8_t;
@@ -167,6 +169,12 @@ pub unsafe fn neon_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLIN
                 let dst_pack: uint8x16x4_t = uint8x16x4_t(b_values, g_values, r_values, v_alpha);
                 vst4q_u8(rgba_ptr.add(dst_shift), dst_pack);
             }
+            YuvSourceChannels::Rgbx => {
+                neon_store_rgbx(rgba_ptr.add(dst_shift), r_values, g_values, b_values);
+            }
+            YuvSourceChannels::Bgrx => {
+                neon_store_rgbx(rgba_ptr.add(dst_shift), b_values, g_values, r_values);
+            }
         }
 
         cx += 16;
@@ -181,7 +189,7 @@ pub unsafe fn neon_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLIN
         }
     }
 
-    while cx + 8 < width {
+    while cx + 8 <= width {
         let y_values = vqsub_u8(vld1_u8(y_ptr.add(y_offset + cx)), vget_low_u8(y_corr));
 
         let u_low_u8: uint8x8_t;
@@ -251,6 +259,12 @@ pub unsafe fn neon_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLIN
                     uint8x8x4_t(b_values, g_values, r_values, vget_low_u8(v_alpha));
                 vst4_u8(rgba_ptr.add(dst_shift), dst_pack);
             }
+            YuvSourceChannels::Rgbx => {
+                neon_store_half_rgbx(rgba_ptr.add(dst_shift), r_values, g_values, b_values);
+            }
+            YuvSourceChannels::Bgrx => {
+                neon_store_half_rgbx(rgba_ptr.add(dst_shift), b_values, g_values, r_values);
+            }
         }
 
         cx += 8;
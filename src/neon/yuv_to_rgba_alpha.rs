@@ -164,11 +164,11 @@ pub unsafe fn neon_yuv_to_rgba_alpha<const DESTINATION_CHANNELS: u8, const SAMPL
             YuvSourceChannels::Rgb | YuvSourceChannels::Bgr => {
                 panic!("Should not be reached");
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let dst_pack: uint8x16x4_t = uint8x16x4_t(r_values, g_values, b_values, a_values);
                 vst4q_u8(rgba_ptr.add(dst_shift), dst_pack);
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let dst_pack: uint8x16x4_t = uint8x16x4_t(b_values, g_values, r_values, a_values);
                 vst4q_u8(rgba_ptr.add(dst_shift), dst_pack);
             }
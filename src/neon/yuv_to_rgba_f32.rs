@@ -0,0 +1,138 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_support::{
+    CbCrInverseTransform, YuvChromaRange, YuvChromaSample, YuvSourceChannels,
+};
+use std::arch::aarch64::*;
+
+#[inline(always)]
+unsafe fn neon_clamp_unit_f32(v: float32x4_t) -> float32x4_t {
+    vminq_f32(vmaxq_f32(v, vdupq_n_f32(0.)), vdupq_n_f32(1.))
+}
+
+/// Converts a row of 8-bit planar YUV to normalized RGB(A) floats 8 pixels at a time,
+/// `transform` must be already normalized to `[0, 1]` output. Returns count of pixels processed.
+#[inline(always)]
+pub unsafe fn neon_yuv_to_rgba_f32_row<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
+    range: &YuvChromaRange,
+    transform: &CbCrInverseTransform<f32>,
+    y_plane: &[u8],
+    u_plane: &[u8],
+    v_plane: &[u8],
+    rgba: &mut [f32],
+    width: usize,
+) -> usize {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = destination_channels.get_channels_count();
+
+    let v_bias_y = vdupq_n_f32(range.bias_y as f32);
+    let v_bias_uv = vdupq_n_f32(range.bias_uv as f32);
+    let v_y_coef = vdupq_n_f32(transform.y_coef);
+    let v_cr_coef = vdupq_n_f32(transform.cr_coef);
+    let v_cb_coef = vdupq_n_f32(transform.cb_coef);
+    let v_g_coef_1 = vdupq_n_f32(transform.g_coeff_1);
+    let v_g_coef_2 = vdupq_n_f32(transform.g_coeff_2);
+    let v_alpha = vdupq_n_f32(1.);
+
+    let mut cx = 0usize;
+
+    while cx + 8 <= width {
+        let y_values = vmovl_u8(vld1_u8(y_plane.as_ptr().add(cx)));
+        let (u_values, v_values) = match chroma_subsampling {
+            YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => {
+                let u_values = vcreate_u8(
+                    (u_plane.as_ptr().add(cx / 2) as *const u32).read_unaligned() as u64,
+                );
+                let v_values = vcreate_u8(
+                    (v_plane.as_ptr().add(cx / 2) as *const u32).read_unaligned() as u64,
+                );
+                (
+                    vmovl_u8(vzip1_u8(u_values, u_values)),
+                    vmovl_u8(vzip1_u8(v_values, v_values)),
+                )
+            }
+            YuvChromaSample::YUV444 => (
+                vmovl_u8(vld1_u8(u_plane.as_ptr().add(cx))),
+                vmovl_u8(vld1_u8(v_plane.as_ptr().add(cx))),
+            ),
+        };
+
+        let halves = [
+            (
+                vget_low_u16(y_values),
+                vget_low_u16(u_values),
+                vget_low_u16(v_values),
+            ),
+            (
+                vget_high_u16(y_values),
+                vget_high_u16(u_values),
+                vget_high_u16(v_values),
+            ),
+        ];
+
+        for (i, &(y_half, u_half, v_half)) in halves.iter().enumerate() {
+            let y_f = vmulq_f32(
+                vsubq_f32(vcvtq_f32_u32(vmovl_u16(y_half)), v_bias_y),
+                v_y_coef,
+            );
+            let u_f = vsubq_f32(vcvtq_f32_u32(vmovl_u16(u_half)), v_bias_uv);
+            let v_f = vsubq_f32(vcvtq_f32_u32(vmovl_u16(v_half)), v_bias_uv);
+
+            let r = neon_clamp_unit_f32(vaddq_f32(y_f, vmulq_f32(v_f, v_cr_coef)));
+            let b = neon_clamp_unit_f32(vaddq_f32(y_f, vmulq_f32(u_f, v_cb_coef)));
+            let g = neon_clamp_unit_f32(vsubq_f32(
+                vsubq_f32(y_f, vmulq_f32(v_f, v_g_coef_1)),
+                vmulq_f32(u_f, v_g_coef_2),
+            ));
+
+            let mut components = [v_alpha; 4];
+            components[destination_channels.get_r_channel_offset()] = r;
+            components[destination_channels.get_g_channel_offset()] = g;
+            components[destination_channels.get_b_channel_offset()] = b;
+
+            let dst_ptr = rgba.as_mut_ptr().add((cx + i * 4) * channels);
+            if channels == 4 {
+                vst4q_f32(
+                    dst_ptr,
+                    float32x4x4_t(components[0], components[1], components[2], components[3]),
+                );
+            } else {
+                vst3q_f32(
+                    dst_ptr,
+                    float32x4x3_t(components[0], components[1], components[2]),
+                );
+            }
+        }
+
+        cx += 8;
+    }
+
+    cx
+}
@@ -159,11 +159,11 @@ pub fn yuy2_to_rgb_neon<const DST_CHANNELS: u8, const YUY2_TARGET: usize>(
                     let packed = uint8x16x3_t(r_l, g_l, b_l);
                     vst3q_u8(dst_ptr, packed);
                 }
-                YuvSourceChannels::Rgba => {
+                YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                     let packed = uint8x16x4_t(r_l, g_l, b_l, v_alpha);
                     vst4q_u8(dst_ptr, packed);
                 }
-                YuvSourceChannels::Bgra => {
+                YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                     let packed = uint8x16x4_t(b_l, g_l, r_l, v_alpha);
                     vst4q_u8(dst_ptr, packed);
                 }
@@ -229,11 +229,11 @@ pub fn yuy2_to_rgb_neon<const DST_CHANNELS: u8, const YUY2_TARGET: usize>(
                     let packed = uint8x16x3_t(r_h, g_h, b_h);
                     vst3q_u8(dst_ptr.add(16 * dst_chans.get_channels_count()), packed);
                 }
-                YuvSourceChannels::Rgba => {
+                YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                     let packed = uint8x16x4_t(r_h, g_h, b_h, v_alpha);
                     vst4q_u8(dst_ptr.add(16 * dst_chans.get_channels_count()), packed);
                 }
-                YuvSourceChannels::Bgra => {
+                YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                     let packed = uint8x16x4_t(b_h, g_h, r_h, v_alpha);
                     vst4q_u8(dst_ptr.add(16 * dst_chans.get_channels_count()), packed);
                 }
@@ -339,11 +339,11 @@ pub fn yuy2_to_rgb_neon<const DST_CHANNELS: u8, const YUY2_TARGET: usize>(
                     let packed = uint8x16x3_t(r_l, g_l, b_l);
                     vst3q_u8(dst_ptr, packed);
                 }
-                YuvSourceChannels::Rgba => {
+                YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                     let packed = uint8x16x4_t(r_l, g_l, b_l, v_alpha);
                     vst4q_u8(dst_ptr, packed);
                 }
-                YuvSourceChannels::Bgra => {
+                YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                     let packed = uint8x16x4_t(b_l, g_l, r_l, v_alpha);
                     vst4q_u8(dst_ptr, packed);
                 }
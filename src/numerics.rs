@@ -0,0 +1,101 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+//! Scalar helpers shared by the conversion kernels.
+//!
+//! Custom kernels built on top of this crate may use them to stay bit exact
+//! with the crate's own scalar paths.
+use crate::yuv_support::{YuvBytesPacking, YuvEndianness};
+
+/// Rounding right shift by `PRECISION` bits, saturated into `0..=(1 << BIT_DEPTH) - 1`.
+///
+/// This is the scalar counterpart of a saturating rounding narrow ( e.g. NEON `vqrshrun` )
+/// and is how fixed point results are brought back to pixel range.
+///
+/// `PRECISION` must be in `1..=30` and `BIT_DEPTH` in `1..=16`.
+///
+/// # Examples
+///
+/// ```
+/// use yuvutils_rs::numerics::qrshr;
+///
+/// assert_eq!(qrshr::<6, 8>(100 << 6), 100);
+/// assert_eq!(qrshr::<6, 8>((100 << 6) + 32), 101);
+/// assert_eq!(qrshr::<6, 8>((100 << 6) + 31), 100);
+/// assert_eq!(qrshr::<6, 8>(-500), 0);
+/// assert_eq!(qrshr::<6, 10>(1200 << 6), 1023);
+/// ```
+#[inline(always)]
+pub const fn qrshr<const PRECISION: i32, const BIT_DEPTH: usize>(val: i32) -> i32 {
+    let rounding: i32 = 1 << (PRECISION - 1);
+    let max_value: i32 = (1 << BIT_DEPTH) - 1;
+    let v = (val + rounding) >> PRECISION;
+    if v < 0 {
+        0
+    } else if v > max_value {
+        max_value
+    } else {
+        v
+    }
+}
+
+/// Reads a stored high bit depth sample into a native value.
+///
+/// Sample is converted from `ENDIANNESS` ( [YuvEndianness] as `u8` ) to the native byte order,
+/// and when `BYTES_POSITION` ( [YuvBytesPacking] as `u8` ) is most significant bytes
+/// it is shifted right by `msb`, which is `16 - bit_depth`.
+///
+/// # Examples
+///
+/// ```
+/// use yuvutils_rs::numerics::to_ne;
+/// use yuvutils_rs::{YuvBytesPacking, YuvEndianness};
+///
+/// const LE: u8 = YuvEndianness::LittleEndian as u8;
+/// const BE: u8 = YuvEndianness::BigEndian as u8;
+/// const MSB: u8 = YuvBytesPacking::MostSignificantBytes as u8;
+/// const LSB: u8 = YuvBytesPacking::LeastSignificantBytes as u8;
+///
+/// assert_eq!(to_ne::<LE, LSB>(1000u16.to_le(), 6), 1000);
+/// assert_eq!(to_ne::<BE, LSB>(1000u16.to_be(), 6), 1000);
+/// // P010 keeps 10-bit samples in the high bits
+/// assert_eq!(to_ne::<LE, MSB>((1000u16 << 6).to_le(), 6), 1000);
+/// ```
+#[inline(always)]
+pub const fn to_ne<const ENDIANNESS: u8, const BYTES_POSITION: u8>(v: u16, msb: i32) -> u16 {
+    let new_v = if ENDIANNESS == YuvEndianness::BigEndian as u8 {
+        u16::from_be(v)
+    } else {
+        u16::from_le(v)
+    };
+    if BYTES_POSITION == YuvBytesPacking::MostSignificantBytes as u8 {
+        new_v >> msb
+    } else {
+        new_v
+    }
+}
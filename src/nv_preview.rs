@@ -0,0 +1,214 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_vec, YuvBiPlanarImageMut};
+use crate::rgba_to_nv::rgbx_to_nv;
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_support::{
+    YuvChromaSample, YuvNVOrder, YuvRange, YuvSourceChannels, YuvStandardMatrix,
+};
+use crate::YuvError;
+
+/// Accumulates `src_row` into per preview pixel channel sums
+#[inline(always)]
+fn accumulate_preview_row(sums: &mut [u64], src_row: &[u8], width: usize, factor: usize) {
+    for (x, px) in src_row.chunks_exact(4).take(width).enumerate() {
+        let dst = &mut sums[x / factor * 4..x / factor * 4 + 4];
+        dst[0] += px[0] as u64;
+        dst[1] += px[1] as u64;
+        dst[2] += px[2] as u64;
+        dst[3] += px[3] as u64;
+    }
+}
+
+fn rgbx_to_nv12_with_preview<const ORIGIN_CHANNELS: u8>(
+    rgba: &[u8],
+    rgba_stride: u32,
+    bi_planar_image: &mut YuvBiPlanarImageMut<u8>,
+    preview: &mut [u8],
+    preview_stride: u32,
+    preview_factor: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    if preview_factor == 0 {
+        return Err(YuvError::InvalidPreviewFactor(preview_factor));
+    }
+    bi_planar_image.check_constraints(YuvChromaSample::YUV420)?;
+    let width = bi_planar_image.size.width;
+    let height = bi_planar_image.size.height;
+    check_rgba_destination(rgba, rgba_stride, width, height, 4)?;
+    let matrix = matrix.resolve(width, height);
+    let preview_width = width.div_ceil(preview_factor);
+    let preview_height = height.div_ceil(preview_factor);
+    check_rgba_destination(preview, preview_stride, preview_width, preview_height, 4)?;
+
+    let factor = preview_factor as usize;
+    // Band covers whole preview rows and whole 4:2:0 chroma row pairs,
+    // so it is still hot in cache when its preview rows are reduced
+    let band_rows = if factor & 1 == 0 { factor } else { factor * 2 };
+    let y_stride = bi_planar_image.y_stride.get();
+    let uv_stride = bi_planar_image.uv_stride.get();
+    let y_plane = bi_planar_image.y_plane.borrow_mut();
+    let uv_plane = bi_planar_image.uv_plane.borrow_mut();
+
+    let mut sums = try_vec(0u64, preview_width as usize * 4)?;
+
+    for band_start in (0..height as usize).step_by(band_rows) {
+        let rows = band_rows.min(height as usize - band_start);
+        let band_end = band_start + rows;
+        // Bands are cut to their exact rows, the last row of a plane may end before the stride does
+        let y_band =
+            band_start * y_stride as usize..(band_end * y_stride as usize).min(y_plane.len());
+        let uv_band = band_start / 2 * uv_stride as usize
+            ..(band_end.div_ceil(2) * uv_stride as usize).min(uv_plane.len());
+        let rgba_band =
+            band_start * rgba_stride as usize..(band_end * rgba_stride as usize).min(rgba.len());
+        rgbx_to_nv::<ORIGIN_CHANNELS, { YuvNVOrder::UV as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+            &mut y_plane[y_band],
+            y_stride,
+            &mut uv_plane[uv_band],
+            uv_stride,
+            &rgba[rgba_band],
+            rgba_stride,
+            width,
+            rows as u32,
+            range,
+            matrix,
+        )?;
+
+        for box_start in (band_start..band_start + rows).step_by(factor) {
+            let box_rows = factor.min(height as usize - box_start);
+            sums.fill(0);
+            for y in box_start..box_start + box_rows {
+                accumulate_preview_row(
+                    &mut sums,
+                    &rgba[y * rgba_stride as usize..],
+                    width as usize,
+                    factor,
+                );
+            }
+
+            let preview_row = &mut preview[box_start / factor * preview_stride as usize..];
+            for (px, (dst, sum)) in preview_row
+                .chunks_exact_mut(4)
+                .zip(sums.chunks_exact(4))
+                .take(preview_width as usize)
+                .enumerate()
+            {
+                let box_width = factor.min(width as usize - px * factor);
+                let count = (box_width * box_rows) as u64;
+                for (dst, &sum) in dst.iter_mut().zip(sum.iter()) {
+                    *dst = ((sum + count / 2) / count) as u8;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert RGBA to NV12 and a box downscaled RGBA preview of the same frame.
+///
+/// Source is walked once in bands, every band is encoded into NV12 at full resolution
+/// and reduced into the preview while it is still in cache.
+/// Preview is `width.div_ceil(preview_factor)` by `height.div_ceil(preview_factor)` pixels,
+/// boxes on the right and bottom edges average only the pixels they cover.
+///
+/// # Arguments
+///
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `bi_planar_image` - Destination NV12 image, its size defines the size of the source.
+/// * `preview` - A mutable slice to store the RGBA preview.
+/// * `preview_stride` - The stride (bytes per row) for the RGBA preview.
+/// * `preview_factor` - Integer downscale factor of the preview, 1 gives a full size copy.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgba_to_yuv_nv12_with_preview(
+    rgba: &[u8],
+    rgba_stride: u32,
+    bi_planar_image: &mut YuvBiPlanarImageMut<u8>,
+    preview: &mut [u8],
+    preview_stride: u32,
+    preview_factor: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_nv12_with_preview::<{ YuvSourceChannels::Rgba as u8 }>(
+        rgba,
+        rgba_stride,
+        bi_planar_image,
+        preview,
+        preview_stride,
+        preview_factor,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA to NV12 and a box downscaled BGRA preview of the same frame.
+///
+/// Source is walked once in bands, every band is encoded into NV12 at full resolution
+/// and reduced into the preview while it is still in cache.
+/// Preview is `width.div_ceil(preview_factor)` by `height.div_ceil(preview_factor)` pixels,
+/// boxes on the right and bottom edges average only the pixels they cover.
+///
+/// # Arguments
+///
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `bi_planar_image` - Destination NV12 image, its size defines the size of the source.
+/// * `preview` - A mutable slice to store the BGRA preview.
+/// * `preview_stride` - The stride (bytes per row) for the BGRA preview.
+/// * `preview_factor` - Integer downscale factor of the preview, 1 gives a full size copy.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgra_to_yuv_nv12_with_preview(
+    bgra: &[u8],
+    bgra_stride: u32,
+    bi_planar_image: &mut YuvBiPlanarImageMut<u8>,
+    preview: &mut [u8],
+    preview_stride: u32,
+    preview_factor: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_nv12_with_preview::<{ YuvSourceChannels::Bgra as u8 }>(
+        bgra,
+        bgra_stride,
+        bi_planar_image,
+        preview,
+        preview_stride,
+        preview_factor,
+        range,
+        matrix,
+    )
+}
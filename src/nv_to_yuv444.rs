@@ -0,0 +1,236 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{YuvBiPlanarImage, YuvPlanarImageMut};
+use crate::yuv_support::{YuvChromaSample, YuvNVOrder};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+use std::fmt::Debug;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// Chroma reconstruction used when 4:2:0 chroma is brought to full resolution
+pub enum YuvChromaUpsampling {
+    /// Every chroma sample covers its 2x2 block of luma samples
+    Nearest,
+    /// Chroma samples are taken as centered between luma samples ( JPEG, MPEG-1 siting ),
+    /// four nearest samples are weighted 9:3:3:1, edges are replicated
+    #[default]
+    Bilinear,
+}
+
+/// Index of the second chroma sample blended into output sample `i`,
+/// which is nearest to chroma sample `i / 2`
+#[inline(always)]
+pub(crate) fn far_index(i: usize, chroma_size: usize, upsampling: YuvChromaUpsampling) -> usize {
+    let near = i / 2;
+    match upsampling {
+        YuvChromaUpsampling::Nearest => near,
+        YuvChromaUpsampling::Bilinear => {
+            if i & 1 == 0 {
+                near.saturating_sub(1)
+            } else {
+                (near + 1).min(chroma_size - 1)
+            }
+        }
+    }
+}
+
+/// Deinterleaves chroma of 4:2:0 bi-planar image and upsamples it into full resolution planes
+/// in a single pass, every sample goes through `load` on read and `store` on write
+fn yuv_nv_to_yuv444_impl<T: Copy + Debug + Send + Sync>(
+    bi_planar_image: &YuvBiPlanarImage<T>,
+    order: YuvNVOrder,
+    planar_image: &mut YuvPlanarImageMut<T>,
+    upsampling: YuvChromaUpsampling,
+    load: impl Fn(T) -> u32 + Send + Sync,
+    store: impl Fn(u32) -> T + Send + Sync,
+) -> Result<(), YuvError> {
+    if bi_planar_image.size.width == 0 || bi_planar_image.size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    bi_planar_image.check_constraints(YuvChromaSample::YUV420)?;
+    if planar_image.size != bi_planar_image.size {
+        return Err(YuvError::ImageSizeMismatch(
+            bi_planar_image.size,
+            planar_image.size,
+        ));
+    }
+    planar_image.check_constraints(YuvChromaSample::YUV444)?;
+
+    let width = bi_planar_image.size.width as usize;
+    let height = bi_planar_image.size.height;
+    let chroma_width = width.div_ceil(2);
+    let chroma_height = height.div_ceil(2) as usize;
+    let element_size = std::mem::size_of::<T>();
+    let src_y_stride = bi_planar_image.y_stride.get() as usize / element_size;
+    let src_uv_stride = bi_planar_image.uv_stride.get() as usize / element_size;
+    let src_y_plane = bi_planar_image.y_plane;
+    let src_uv_plane = bi_planar_image.uv_plane;
+    let u_position = order.get_u_position();
+    let v_position = order.get_v_position();
+
+    let y_stride = planar_image.y_stride.get() as usize / element_size;
+    let u_stride = planar_image.u_stride.get() as usize / element_size;
+    let v_stride = planar_image.v_stride.get() as usize / element_size;
+    let y_plane = planar_image.y_plane.borrow_mut();
+    let u_plane = planar_image.u_plane.borrow_mut();
+    let v_plane = planar_image.v_plane.borrow_mut();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = y_plane
+            .par_chunks_mut(y_stride)
+            .zip(u_plane.par_chunks_mut(u_stride))
+            .zip(v_plane.par_chunks_mut(v_stride));
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = y_plane
+            .chunks_mut(y_stride)
+            .zip(u_plane.chunks_mut(u_stride))
+            .zip(v_plane.chunks_mut(v_stride));
+    }
+
+    iter.take(height as usize)
+        .enumerate()
+        .for_each(|(y, ((y_dst, u_dst), v_dst))| {
+            let src_y = &src_y_plane[y * src_y_stride..];
+            for (dst, &src) in y_dst.iter_mut().zip(src_y.iter()).take(width) {
+                *dst = store(load(src));
+            }
+
+            let near_row = &src_uv_plane[(y / 2) * src_uv_stride..];
+            let far_row = &src_uv_plane[far_index(y, chroma_height, upsampling) * src_uv_stride..];
+
+            for (x, (u_dst, v_dst)) in u_dst
+                .iter_mut()
+                .zip(v_dst.iter_mut())
+                .take(width)
+                .enumerate()
+            {
+                let near = (x / 2) * 2;
+                match upsampling {
+                    YuvChromaUpsampling::Nearest => {
+                        *u_dst = store(load(near_row[near + u_position]));
+                        *v_dst = store(load(near_row[near + v_position]));
+                    }
+                    YuvChromaUpsampling::Bilinear => {
+                        let far = far_index(x, chroma_width, upsampling) * 2;
+                        let blend = |position: usize| -> T {
+                            let value = 9 * load(near_row[near + position])
+                                + 3 * load(far_row[near + position])
+                                + 3 * load(near_row[far + position])
+                                + load(far_row[far + position]);
+                            store((value + 8) >> 4)
+                        };
+                        *u_dst = blend(u_position);
+                        *v_dst = blend(v_position);
+                    }
+                }
+            }
+        });
+
+    Ok(())
+}
+
+/// Convert YUV NV12 to YUV 444 planar format, chroma is deinterleaved and upsampled in one pass.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source NV12 image.
+/// * `planar_image` - Target 4:4:4 planar image of the same size.
+/// * `upsampling` - Chroma reconstruction, see [YuvChromaUpsampling].
+///
+pub fn yuv_nv12_to_yuv444(
+    bi_planar_image: &YuvBiPlanarImage<u8>,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    upsampling: YuvChromaUpsampling,
+) -> Result<(), YuvError> {
+    yuv_nv_to_yuv444_impl(
+        bi_planar_image,
+        YuvNVOrder::UV,
+        planar_image,
+        upsampling,
+        |v| v as u32,
+        |v| v as u8,
+    )
+}
+
+/// Convert YUV NV21 to YUV 444 planar format, chroma is deinterleaved and upsampled in one pass.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source NV21 image.
+/// * `planar_image` - Target 4:4:4 planar image of the same size.
+/// * `upsampling` - Chroma reconstruction, see [YuvChromaUpsampling].
+///
+pub fn yuv_nv21_to_yuv444(
+    bi_planar_image: &YuvBiPlanarImage<u8>,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    upsampling: YuvChromaUpsampling,
+) -> Result<(), YuvError> {
+    yuv_nv_to_yuv444_impl(
+        bi_planar_image,
+        YuvNVOrder::VU,
+        planar_image,
+        upsampling,
+        |v| v as u32,
+        |v| v as u8,
+    )
+}
+
+/// Convert P010 to YUV 444 planar format with 10-bit pixel format ( I410 ),
+/// chroma is deinterleaved and upsampled in one pass.
+///
+/// P010 keeps 10-bit samples in the most significant bits of little endian 16-bit words
+/// with Cb before Cr in the interleaved plane, target planes keep them in the least significant bits.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source P010 image.
+/// * `planar_image` - Target 4:4:4 planar image of the same size.
+/// * `upsampling` - Chroma reconstruction, see [YuvChromaUpsampling].
+///
+pub fn yuv_p010_to_yuv444_p10(
+    bi_planar_image: &YuvBiPlanarImage<u16>,
+    planar_image: &mut YuvPlanarImageMut<u16>,
+    upsampling: YuvChromaUpsampling,
+) -> Result<(), YuvError> {
+    yuv_nv_to_yuv444_impl(
+        bi_planar_image,
+        YuvNVOrder::UV,
+        planar_image,
+        upsampling,
+        |v| (u16::from_le(v) >> 6) as u32,
+        |v| v as u16,
+    )
+}
@@ -0,0 +1,298 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::blend_nv::{blend_rgba_over_nv12, blend_rgba_over_nv21};
+use crate::images::{try_vec, ImageSize, YuvBiPlanarImageMut, YuvPlanarImageMut};
+use crate::yuv_error::check_y8_channel;
+use crate::yuv_support::{
+    get_forward_transform, get_yuv_range, ToIntegerTransform, YuvChromaSample, YuvNVOrder,
+    YuvRange, YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+/// Converts RGBA palette into YUVA entries, indices past the palette end are fully transparent.
+///
+/// Uses exactly the same fixed point transform as scalar RGB to YUV conversion.
+fn palette_to_yuva(
+    palette: &[[u8; 4]],
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> [[u8; 4]; 256] {
+    let range = get_yuv_range(8, range);
+    let kr_kb = matrix.get_kr_kb();
+    const PRECISION: i32 = 8;
+    let transform = get_forward_transform(
+        (1u32 << 8u32) - 1u32,
+        range.range_y,
+        range.range_uv,
+        kr_kb.kr,
+        kr_kb.kb,
+    )
+    .to_integers(PRECISION as u32);
+    const ROUNDING_CONST_BIAS: i32 = 1 << (PRECISION - 1);
+    let bias_y = range.bias_y as i32 * (1 << PRECISION) + ROUNDING_CONST_BIAS;
+    let bias_uv = range.bias_uv as i32 * (1 << PRECISION) + ROUNDING_CONST_BIAS;
+    let i_bias_y = range.bias_y as i32;
+    let i_cap_y = range.range_y as i32 + i_bias_y;
+    let i_min_uv = range.min_uv() as i32;
+    let i_cap_uv = range.max_uv() as i32;
+
+    let transparent = [i_bias_y as u8, range.bias_uv as u8, range.bias_uv as u8, 0];
+    let mut entries = [transparent; 256];
+    for (dst, src) in entries.iter_mut().zip(palette.iter()) {
+        let r = src[0] as i32;
+        let g = src[1] as i32;
+        let b = src[2] as i32;
+        let y = (r * transform.yr + g * transform.yg + b * transform.yb + bias_y) >> PRECISION;
+        let cb =
+            (r * transform.cb_r + g * transform.cb_g + b * transform.cb_b + bias_uv) >> PRECISION;
+        let cr =
+            (r * transform.cr_r + g * transform.cr_g + b * transform.cr_b + bias_uv) >> PRECISION;
+        *dst = [
+            y.clamp(i_bias_y, i_cap_y) as u8,
+            cb.clamp(i_min_uv, i_cap_uv) as u8,
+            cr.clamp(i_min_uv, i_cap_uv) as u8,
+            src[3],
+        ];
+    }
+    entries
+}
+
+/// Expands palettized bitmap into YUV 4:4:4 planes with separate alpha plane.
+///
+/// Palette is converted once, then every pixel is a table lookup, so each pixel gets
+/// exactly the color of its palette entry, as required for DVD and PGS subtitles.
+/// Palette holds straight (not premultiplied) RGBA entries, indices past its end are
+/// written as fully transparent black.
+///
+/// # Arguments
+///
+/// * `bitmap` - Palette indices, one byte per pixel.
+/// * `bitmap_stride` - Stride of the bitmap in bytes.
+/// * `palette` - RGBA palette of up to 256 entries.
+/// * `planar_image` - Destination YUV 4:4:4 image, its size defines the size of the bitmap.
+/// * `a_plane` - Destination alpha plane.
+/// * `a_stride` - Stride of the alpha plane in bytes.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn palette8_to_yuva(
+    bitmap: &[u8],
+    bitmap_stride: u32,
+    palette: &[[u8; 4]],
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    a_plane: &mut [u8],
+    a_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    planar_image.check_constraints(YuvChromaSample::YUV444)?;
+    let width = planar_image.size.width;
+    let height = planar_image.size.height;
+    check_y8_channel(bitmap, bitmap_stride, width, height)?;
+    check_y8_channel(a_plane, a_stride, width, height)?;
+
+    let entries = palette_to_yuva(palette, range, matrix.resolve(width, height));
+
+    let y_stride = planar_image.y_stride.get() as usize;
+    let u_stride = planar_image.u_stride.get() as usize;
+    let v_stride = planar_image.v_stride.get() as usize;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = planar_image
+            .y_plane
+            .borrow_mut()
+            .par_chunks_mut(y_stride)
+            .zip(planar_image.u_plane.borrow_mut().par_chunks_mut(u_stride))
+            .zip(planar_image.v_plane.borrow_mut().par_chunks_mut(v_stride))
+            .zip(a_plane.par_chunks_mut(a_stride as usize))
+            .zip(bitmap.par_chunks(bitmap_stride as usize))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = planar_image
+            .y_plane
+            .borrow_mut()
+            .chunks_mut(y_stride)
+            .zip(planar_image.u_plane.borrow_mut().chunks_mut(u_stride))
+            .zip(planar_image.v_plane.borrow_mut().chunks_mut(v_stride))
+            .zip(a_plane.chunks_mut(a_stride as usize))
+            .zip(bitmap.chunks(bitmap_stride as usize))
+            .take(height as usize);
+    }
+    iter.for_each(|((((y_row, u_row), v_row), a_row), src)| {
+        for ((((y_dst, u_dst), v_dst), a_dst), &index) in y_row
+            .iter_mut()
+            .zip(u_row.iter_mut())
+            .zip(v_row.iter_mut())
+            .zip(a_row.iter_mut())
+            .zip(src.iter())
+            .take(width as usize)
+        {
+            let entry = entries[index as usize];
+            *y_dst = entry[0];
+            *u_dst = entry[1];
+            *v_dst = entry[2];
+            *a_dst = entry[3];
+        }
+    });
+
+    Ok(())
+}
+
+fn blend_palette8_over_nv_impl<const UV_ORDER: u8>(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    bitmap: &[u8],
+    bitmap_stride: u32,
+    bitmap_size: ImageSize,
+    palette: &[[u8; 4]],
+    x: i32,
+    y: i32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    check_y8_channel(bitmap, bitmap_stride, bitmap_size.width, bitmap_size.height)?;
+    let mut entries = [[0u8; 4]; 256];
+    for (dst, src) in entries.iter_mut().zip(palette.iter()) {
+        *dst = *src;
+    }
+
+    let width = bitmap_size.width as usize;
+    let mut sprite = try_vec(0u8, width * 4 * bitmap_size.height as usize)?;
+    for (dst, src) in sprite
+        .chunks_exact_mut(width * 4)
+        .zip(bitmap.chunks(bitmap_stride as usize))
+    {
+        for (px, &index) in dst.chunks_exact_mut(4).zip(src.iter()) {
+            px.copy_from_slice(&entries[index as usize]);
+        }
+    }
+
+    let order: YuvNVOrder = UV_ORDER.into();
+    let handler = match order {
+        YuvNVOrder::UV => blend_rgba_over_nv12,
+        YuvNVOrder::VU => blend_rgba_over_nv21,
+    };
+    handler(
+        frame,
+        &sprite,
+        bitmap_size.width * 4,
+        bitmap_size,
+        x,
+        y,
+        range,
+        matrix,
+    )
+}
+
+/// Alpha blends palettized bitmap over NV12 frame in place.
+///
+/// Blending follows [blend_rgba_over_nv12], indices past the palette end are transparent.
+///
+/// # Arguments
+///
+/// * `frame` - NV12 frame to blend onto.
+/// * `bitmap` - Palette indices, one byte per pixel.
+/// * `bitmap_stride` - Stride of the bitmap in bytes.
+/// * `bitmap_size` - The size of the bitmap in pixels.
+/// * `palette` - Straight alpha RGBA palette of up to 256 entries.
+/// * `x` - Horizontal position of the bitmap top left corner in the frame.
+/// * `y` - Vertical position of the bitmap top left corner in the frame.
+/// * `range` - The YUV range (limited or full) of the frame.
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other) of the frame.
+///
+pub fn blend_palette8_over_nv12(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    bitmap: &[u8],
+    bitmap_stride: u32,
+    bitmap_size: ImageSize,
+    palette: &[[u8; 4]],
+    x: i32,
+    y: i32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    blend_palette8_over_nv_impl::<{ YuvNVOrder::UV as u8 }>(
+        frame,
+        bitmap,
+        bitmap_stride,
+        bitmap_size,
+        palette,
+        x,
+        y,
+        range,
+        matrix,
+    )
+}
+
+/// Alpha blends palettized bitmap over NV21 frame in place.
+///
+/// Blending follows [blend_rgba_over_nv21], indices past the palette end are transparent.
+///
+/// # Arguments
+///
+/// * `frame` - NV21 frame to blend onto.
+/// * `bitmap` - Palette indices, one byte per pixel.
+/// * `bitmap_stride` - Stride of the bitmap in bytes.
+/// * `bitmap_size` - The size of the bitmap in pixels.
+/// * `palette` - Straight alpha RGBA palette of up to 256 entries.
+/// * `x` - Horizontal position of the bitmap top left corner in the frame.
+/// * `y` - Vertical position of the bitmap top left corner in the frame.
+/// * `range` - The YUV range (limited or full) of the frame.
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other) of the frame.
+///
+pub fn blend_palette8_over_nv21(
+    frame: &mut YuvBiPlanarImageMut<u8>,
+    bitmap: &[u8],
+    bitmap_stride: u32,
+    bitmap_size: ImageSize,
+    palette: &[[u8; 4]],
+    x: i32,
+    y: i32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    blend_palette8_over_nv_impl::<{ YuvNVOrder::VU as u8 }>(
+        frame,
+        bitmap,
+        bitmap_stride,
+        bitmap_size,
+        palette,
+        x,
+        y,
+        range,
+        matrix,
+    )
+}
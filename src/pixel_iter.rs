@@ -0,0 +1,210 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride, YuvBiPlanarImage, YuvPlanarImage};
+use crate::yuv_support::{YuvChromaSample, YuvNVOrder};
+use crate::YuvError;
+use std::fmt::Debug;
+use std::iter::FusedIterator;
+use std::mem::size_of;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Single pixel of YUV image along with chroma sample it shares
+pub struct YuvPixel<T: Copy> {
+    pub x: u32,
+    pub y: u32,
+    pub y_value: T,
+    pub u_value: T,
+    pub v_value: T,
+}
+
+#[derive(Debug, Clone)]
+struct ChromaComponent<'a, T> {
+    plane: &'a [T],
+    /// Stride in elements
+    stride: usize,
+    /// Distance in elements between neighbour samples, 2 for interleaved chroma
+    step: usize,
+    offset: usize,
+}
+
+impl<T: Copy> ChromaComponent<'_, T> {
+    #[inline(always)]
+    fn get(&self, cx: usize, cy: usize) -> T {
+        self.plane[cy * self.stride + cx * self.step + self.offset]
+    }
+}
+
+#[derive(Debug, Clone)]
+/// Iterator over pixels of planar or bi-planar YUV image in row-major order.
+///
+/// Chroma sample of every pixel is picked according to subsampling,
+/// so pixels of the same 2x2 block in 4:2:0 or pair in 4:2:2 yield the same `U` and `V`.
+pub struct YuvPixels<'a, T: Copy> {
+    y_plane: &'a [T],
+    /// Stride in elements
+    y_stride: usize,
+    u: ChromaComponent<'a, T>,
+    v: ChromaComponent<'a, T>,
+    width: u32,
+    height: u32,
+    shift_x: u32,
+    shift_y: u32,
+    x: u32,
+    y: u32,
+}
+
+impl<'a, T: Copy> YuvPixels<'a, T> {
+    fn new(
+        y_plane: &'a [T],
+        y_stride: RowStride,
+        u: ChromaComponent<'a, T>,
+        v: ChromaComponent<'a, T>,
+        size: ImageSize,
+        sampling: YuvChromaSample,
+    ) -> Self {
+        let (width, height) = (size.width, size.height);
+        let (shift_x, shift_y) = match sampling {
+            YuvChromaSample::YUV420 => (1, 1),
+            YuvChromaSample::YUV422 => (1, 0),
+            YuvChromaSample::YUV444 => (0, 0),
+        };
+        YuvPixels {
+            y_plane,
+            y_stride: y_stride.get() as usize / size_of::<T>(),
+            u,
+            v,
+            width,
+            height,
+            shift_x,
+            shift_y,
+            x: 0,
+            y: if width == 0 { height } else { 0 },
+        }
+    }
+}
+
+impl<T: Copy> Iterator for YuvPixels<'_, T> {
+    type Item = YuvPixel<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.y >= self.height {
+            return None;
+        }
+        let (x, y) = (self.x, self.y);
+        let cx = (x >> self.shift_x) as usize;
+        let cy = (y >> self.shift_y) as usize;
+        let pixel = YuvPixel {
+            x,
+            y,
+            y_value: self.y_plane[y as usize * self.y_stride + x as usize],
+            u_value: self.u.get(cx, cy),
+            v_value: self.v.get(cx, cy),
+        };
+
+        self.x += 1;
+        if self.x == self.width {
+            self.x = 0;
+            self.y += 1;
+        }
+        Some(pixel)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.y >= self.height {
+            0
+        } else {
+            (self.height - self.y) as usize * self.width as usize - self.x as usize
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Copy> ExactSizeIterator for YuvPixels<'_, T> {}
+
+impl<T: Copy> FusedIterator for YuvPixels<'_, T> {}
+
+impl<'a, T: Copy + Debug> YuvPlanarImage<'a, T> {
+    /// Iterates over every pixel with its shared chroma sample, planes are validated first
+    pub fn pixels(&self, sampling: YuvChromaSample) -> Result<YuvPixels<'a, T>, YuvError> {
+        self.check_constraints(sampling)?;
+        Ok(YuvPixels::new(
+            self.y_plane,
+            self.y_stride,
+            ChromaComponent {
+                plane: self.u_plane,
+                stride: self.u_stride.get() as usize / size_of::<T>(),
+                step: 1,
+                offset: 0,
+            },
+            ChromaComponent {
+                plane: self.v_plane,
+                stride: self.v_stride.get() as usize / size_of::<T>(),
+                step: 1,
+                offset: 0,
+            },
+            self.size,
+            sampling,
+        ))
+    }
+}
+
+impl<'a, T: Copy + Debug> YuvBiPlanarImage<'a, T> {
+    /// Iterates over every pixel with its shared chroma sample, planes are validated first.
+    ///
+    /// `order` tells whether chroma plane is stored as `UV` ( NV12, NV16, NV24 )
+    /// or `VU` ( NV21, NV61, NV42 ).
+    pub fn pixels(
+        &self,
+        sampling: YuvChromaSample,
+        order: YuvNVOrder,
+    ) -> Result<YuvPixels<'a, T>, YuvError> {
+        self.check_constraints(sampling)?;
+        let uv_stride = self.uv_stride.get() as usize / size_of::<T>();
+        Ok(YuvPixels::new(
+            self.y_plane,
+            self.y_stride,
+            ChromaComponent {
+                plane: self.uv_plane,
+                stride: uv_stride,
+                step: 2,
+                offset: order.get_u_position(),
+            },
+            ChromaComponent {
+                plane: self.uv_plane,
+                stride: uv_stride,
+                step: 2,
+                offset: order.get_v_position(),
+            },
+            self.size,
+            sampling,
+        ))
+    }
+}
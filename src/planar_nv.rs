@@ -0,0 +1,258 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{YuvBiPlanarImage, YuvBiPlanarImageMut, YuvPlanarImage, YuvPlanarImageMut};
+use crate::yuv_support::{YuvChromaSample, YuvNVOrder};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+/// Copies `height` rows of `width` bytes between planes
+fn copy_luma(
+    src: &[u8],
+    src_stride: usize,
+    dst: &mut [u8],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+) {
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst.par_chunks_mut(dst_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst.chunks_mut(dst_stride);
+    }
+    iter.take(height).enumerate().for_each(|(y, dst_row)| {
+        let src_row = &src[y * src_stride..];
+        dst_row[..width].copy_from_slice(&src_row[..width]);
+    });
+}
+
+fn yuv420_to_yuv_nv_impl(
+    planar_image: &YuvPlanarImage<u8>,
+    bi_planar_image: &mut YuvBiPlanarImageMut<u8>,
+    order: YuvNVOrder,
+) -> Result<(), YuvError> {
+    if planar_image.size.width == 0 || planar_image.size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    planar_image.check_constraints(YuvChromaSample::YUV420)?;
+    if bi_planar_image.size != planar_image.size {
+        return Err(YuvError::ImageSizeMismatch(
+            planar_image.size,
+            bi_planar_image.size,
+        ));
+    }
+    bi_planar_image.check_constraints(YuvChromaSample::YUV420)?;
+
+    let width = planar_image.size.width as usize;
+    let height = planar_image.size.height as usize;
+    let chroma_width = width.div_ceil(2);
+    let u_position = order.get_u_position();
+    let v_position = order.get_v_position();
+
+    copy_luma(
+        planar_image.y_plane,
+        planar_image.y_stride.get() as usize,
+        bi_planar_image.y_plane.borrow_mut(),
+        bi_planar_image.y_stride.get() as usize,
+        width,
+        height,
+    );
+
+    let u_stride = planar_image.u_stride.get() as usize;
+    let v_stride = planar_image.v_stride.get() as usize;
+    let u_plane = planar_image.u_plane;
+    let v_plane = planar_image.v_plane;
+    let uv_stride = bi_planar_image.uv_stride.get() as usize;
+    let uv_plane = bi_planar_image.uv_plane.borrow_mut();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = uv_plane.par_chunks_mut(uv_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = uv_plane.chunks_mut(uv_stride);
+    }
+    iter.take(height.div_ceil(2))
+        .enumerate()
+        .for_each(|(y, uv_dst)| {
+            let u_src = &u_plane[y * u_stride..][..chroma_width];
+            let v_src = &v_plane[y * v_stride..][..chroma_width];
+            for ((dst, &u), &v) in uv_dst[..chroma_width * 2]
+                .chunks_exact_mut(2)
+                .zip(u_src.iter())
+                .zip(v_src.iter())
+            {
+                dst[u_position] = u;
+                dst[v_position] = v;
+            }
+        });
+
+    Ok(())
+}
+
+fn yuv_nv_to_yuv420_impl(
+    bi_planar_image: &YuvBiPlanarImage<u8>,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    order: YuvNVOrder,
+) -> Result<(), YuvError> {
+    if bi_planar_image.size.width == 0 || bi_planar_image.size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    bi_planar_image.check_constraints(YuvChromaSample::YUV420)?;
+    if planar_image.size != bi_planar_image.size {
+        return Err(YuvError::ImageSizeMismatch(
+            bi_planar_image.size,
+            planar_image.size,
+        ));
+    }
+    planar_image.check_constraints(YuvChromaSample::YUV420)?;
+
+    let width = bi_planar_image.size.width as usize;
+    let height = bi_planar_image.size.height as usize;
+    let chroma_width = width.div_ceil(2);
+    let u_position = order.get_u_position();
+    let v_position = order.get_v_position();
+
+    copy_luma(
+        bi_planar_image.y_plane,
+        bi_planar_image.y_stride.get() as usize,
+        planar_image.y_plane.borrow_mut(),
+        planar_image.y_stride.get() as usize,
+        width,
+        height,
+    );
+
+    let uv_stride = bi_planar_image.uv_stride.get() as usize;
+    let uv_plane = bi_planar_image.uv_plane;
+    let u_stride = planar_image.u_stride.get() as usize;
+    let v_stride = planar_image.v_stride.get() as usize;
+    let u_plane = planar_image.u_plane.borrow_mut();
+    let v_plane = planar_image.v_plane.borrow_mut();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = u_plane
+            .par_chunks_mut(u_stride)
+            .zip(v_plane.par_chunks_mut(v_stride));
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = u_plane
+            .chunks_mut(u_stride)
+            .zip(v_plane.chunks_mut(v_stride));
+    }
+    iter.take(height.div_ceil(2))
+        .enumerate()
+        .for_each(|(y, (u_dst, v_dst))| {
+            let uv_src = &uv_plane[y * uv_stride..][..chroma_width * 2];
+            for ((src, u), v) in uv_src
+                .chunks_exact(2)
+                .zip(u_dst[..chroma_width].iter_mut())
+                .zip(v_dst[..chroma_width].iter_mut())
+            {
+                *u = src[u_position];
+                *v = src[v_position];
+            }
+        });
+
+    Ok(())
+}
+
+/// Convert YUV 420 planar format ( I420 ) to YUV NV12 by interleaving chroma planes.
+///
+/// Samples are moved without any resampling, luma is copied as is.
+///
+/// # Arguments
+///
+/// * `planar_image` - Source 4:2:0 planar image.
+/// * `bi_planar_image` - Target NV12 image of the same size.
+///
+pub fn yuv420_to_yuv_nv12(
+    planar_image: &YuvPlanarImage<u8>,
+    bi_planar_image: &mut YuvBiPlanarImageMut<u8>,
+) -> Result<(), YuvError> {
+    yuv420_to_yuv_nv_impl(planar_image, bi_planar_image, YuvNVOrder::UV)
+}
+
+/// Convert YUV 420 planar format ( I420 ) to YUV NV21 by interleaving chroma planes.
+///
+/// Samples are moved without any resampling, luma is copied as is.
+///
+/// # Arguments
+///
+/// * `planar_image` - Source 4:2:0 planar image.
+/// * `bi_planar_image` - Target NV21 image of the same size.
+///
+pub fn yuv420_to_yuv_nv21(
+    planar_image: &YuvPlanarImage<u8>,
+    bi_planar_image: &mut YuvBiPlanarImageMut<u8>,
+) -> Result<(), YuvError> {
+    yuv420_to_yuv_nv_impl(planar_image, bi_planar_image, YuvNVOrder::VU)
+}
+
+/// Convert YUV NV12 to YUV 420 planar format ( I420 ) by deinterleaving the chroma plane.
+///
+/// Samples are moved without any resampling, luma is copied as is.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source NV12 image.
+/// * `planar_image` - Target 4:2:0 planar image of the same size.
+///
+pub fn yuv_nv12_to_yuv420(
+    bi_planar_image: &YuvBiPlanarImage<u8>,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+) -> Result<(), YuvError> {
+    yuv_nv_to_yuv420_impl(bi_planar_image, planar_image, YuvNVOrder::UV)
+}
+
+/// Convert YUV NV21 to YUV 420 planar format ( I420 ) by deinterleaving the chroma plane.
+///
+/// Samples are moved without any resampling, luma is copied as is.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source NV21 image.
+/// * `planar_image` - Target 4:2:0 planar image of the same size.
+///
+pub fn yuv_nv21_to_yuv420(
+    bi_planar_image: &YuvBiPlanarImage<u8>,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+) -> Result<(), YuvError> {
+    yuv_nv_to_yuv420_impl(bi_planar_image, planar_image, YuvNVOrder::VU)
+}
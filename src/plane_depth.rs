@@ -0,0 +1,356 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride, YuvBiPlanarImage, YuvBiPlanarImageMut};
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::neon::{neon_plane_u16_to_u8_row, neon_plane_u8_to_u16_row};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::sse::{sse_plane_u16_to_u8_row, sse_plane_u8_to_u16_row};
+use crate::yuv_error::{check_p16_bit_depth, check_y8_channel};
+use crate::yuv_planar_generic::BAYER_8X8;
+use crate::yuv_support::{YuvBytesPacking, YuvChromaSample};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+/// Widens 8-bit plane into 16-bit one, every sample is shifted left by `shift`.
+///
+/// Shift 8 produces MSB aligned samples as used by P010/P016 like containers,
+/// smaller shifts produce LSB aligned high bit depth samples.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load 8-bit plane data.
+/// * `src_stride` - The stride (bytes per row) for the source plane.
+/// * `dst` - A mutable slice to store 16-bit plane data.
+/// * `dst_stride` - The stride (bytes per row) for the destination plane.
+/// * `size` - The size of the image.
+/// * `shift` - Left shift applied to every sample, from 0 to 8.
+///
+pub fn plane_u8_to_u16_shifted(
+    src: &[u8],
+    src_stride: RowStride,
+    dst: &mut [u16],
+    dst_stride: RowStride,
+    size: ImageSize,
+    shift: u32,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (src_stride, dst_stride) = (src_stride.get(), dst_stride.get());
+    if shift > 8 {
+        return Err(YuvError::InvalidShift(shift));
+    }
+    check_y8_channel(src, src_stride, width, height)?;
+    check_y8_channel(dst, dst_stride, width, height)?;
+
+    let width = width as usize;
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride as usize / 2)
+            .zip(src.par_chunks(src_stride as usize))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride as usize / 2)
+            .zip(src.chunks(src_stride as usize))
+            .take(height as usize);
+    }
+    iter.for_each(|(dst, src)| {
+        #[allow(unused_mut)]
+        let mut cx = 0usize;
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            if _use_sse {
+                cx = sse_plane_u8_to_u16_row(src, dst, width, shift);
+            }
+        }
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            cx = neon_plane_u8_to_u16_row(src, dst, width, shift);
+        }
+
+        for (dst, &src) in dst.iter_mut().zip(src.iter()).take(width).skip(cx) {
+            *dst = (src as u16) << shift;
+        }
+    });
+
+    Ok(())
+}
+
+/// Narrows 16-bit plane into 8-bit one, every sample is shifted right by `shift`
+/// with rounding, results above 255 saturate.
+///
+/// Shift 8 takes MSB aligned samples of P010/P016 like containers back to 8 bits,
+/// for LSB aligned samples shift is bit depth minus 8.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load 16-bit plane data.
+/// * `src_stride` - The stride (bytes per row) for the source plane.
+/// * `dst` - A mutable slice to store 8-bit plane data.
+/// * `dst_stride` - The stride (bytes per row) for the destination plane.
+/// * `size` - The size of the image.
+/// * `shift` - Right shift applied to every sample, from 0 to 15.
+///
+pub fn plane_u16_to_u8_shifted(
+    src: &[u16],
+    src_stride: RowStride,
+    dst: &mut [u8],
+    dst_stride: RowStride,
+    size: ImageSize,
+    shift: u32,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (src_stride, dst_stride) = (src_stride.get(), dst_stride.get());
+    if shift > 15 {
+        return Err(YuvError::InvalidShift(shift));
+    }
+    check_y8_channel(src, src_stride, width, height)?;
+    check_y8_channel(dst, dst_stride, width, height)?;
+
+    let width = width as usize;
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride as usize)
+            .zip(src.par_chunks(src_stride as usize / 2))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride as usize)
+            .zip(src.chunks(src_stride as usize / 2))
+            .take(height as usize);
+    }
+    iter.for_each(|(dst, src)| {
+        #[allow(unused_mut)]
+        let mut cx = 0usize;
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            if _use_sse {
+                cx = sse_plane_u16_to_u8_row(src, dst, width, shift);
+            }
+        }
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            cx = neon_plane_u16_to_u8_row(src, dst, width, shift);
+        }
+
+        let rounding = (1u32 << shift) >> 1;
+        for (dst, &src) in dst.iter_mut().zip(src.iter()).take(width).skip(cx) {
+            *dst = ((src as u32 + rounding) >> shift).min(255) as u8;
+        }
+    });
+
+    Ok(())
+}
+
+/// Narrows rows of little endian high bit depth samples to 8 bits, optionally ordered dithered.
+///
+/// `interleave` is count of samples per pixel, so interleaved U and V of the same pixel
+/// share a dithering threshold.
+fn narrow_p16_rows(
+    src: &[u16],
+    src_stride: usize,
+    dst: &mut [u8],
+    dst_stride: usize,
+    row_samples: usize,
+    rows: usize,
+    interleave: usize,
+    bit_depth: u32,
+    bytes_packing: YuvBytesPacking,
+    dither: bool,
+) {
+    let msb_shift = match bytes_packing {
+        YuvBytesPacking::MostSignificantBytes => 16 - bit_depth,
+        YuvBytesPacking::LeastSignificantBytes => 0,
+    };
+    let shift = bit_depth - 8;
+    let max_value = (1u32 << bit_depth) - 1;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride)
+            .zip(src.par_chunks(src_stride))
+            .take(rows);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride)
+            .zip(src.chunks(src_stride))
+            .take(rows);
+    }
+    iter.enumerate().for_each(|(y, (dst, src))| {
+        // Thresholds are centered in each of 64 buckets so the mean matches plain rounding
+        let thresholds = if dither {
+            BAYER_8X8[y & 7].map(|v| ((v as u32 * 2 + 1) << shift) / 128)
+        } else {
+            [(1u32 << shift) >> 1; 8]
+        };
+        for (x, (dst, &src)) in dst.iter_mut().zip(src.iter()).take(row_samples).enumerate() {
+            let v = ((u16::from_le(src) >> msb_shift) as u32).min(max_value);
+            *dst = ((v + thresholds[(x / interleave) & 7]) >> shift).min(255) as u8;
+        }
+    });
+}
+
+/// Convert bi-planar 4:2:0 image with 8+ bit depth stored in 16-bit words to NV12
+/// without going through RGB.
+///
+/// Samples are little endian, luma and fused UV plane are narrowed independently.
+/// Interleaved chroma order is kept, so NV21 like sources produce NV21.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source bi-planar image, e.g. P010, P012 or P016.
+/// * `target_image` - Target NV12 image of the same size.
+/// * `bit_depth` - Bit depth of the source, from 8 to 16.
+/// * `bytes_packing` - Position of significant bits, P010 like containers are most significant.
+/// * `dither` - Ordered dither while reducing to 8 bits, otherwise values are rounded.
+///
+pub fn yuv_nv_p16_to_yuv_nv12(
+    bi_planar_image: &YuvBiPlanarImage<u16>,
+    target_image: &mut YuvBiPlanarImageMut<u8>,
+    bit_depth: u32,
+    bytes_packing: YuvBytesPacking,
+    dither: bool,
+) -> Result<(), YuvError> {
+    check_p16_bit_depth(bit_depth)?;
+    bi_planar_image.check_constraints(YuvChromaSample::YUV420)?;
+    if target_image.size != bi_planar_image.size {
+        return Err(YuvError::ImageSizeMismatch(
+            bi_planar_image.size,
+            target_image.size,
+        ));
+    }
+    target_image.check_constraints(YuvChromaSample::YUV420)?;
+
+    let width = bi_planar_image.size.width as usize;
+    let height = bi_planar_image.size.height as usize;
+
+    narrow_p16_rows(
+        bi_planar_image.y_plane,
+        bi_planar_image.y_stride.get() as usize / 2,
+        target_image.y_plane.borrow_mut(),
+        target_image.y_stride.get() as usize,
+        width,
+        height,
+        1,
+        bit_depth,
+        bytes_packing,
+        dither,
+    );
+    narrow_p16_rows(
+        bi_planar_image.uv_plane,
+        bi_planar_image.uv_stride.get() as usize / 2,
+        target_image.uv_plane.borrow_mut(),
+        target_image.uv_stride.get() as usize,
+        width.div_ceil(2) * 2,
+        height.div_ceil(2),
+        2,
+        bit_depth,
+        bytes_packing,
+        dither,
+    );
+    Ok(())
+}
+
+/// Convert P010 to NV12 without going through RGB.
+///
+/// P010 keeps 10-bit samples in the most significant bits of little endian 16-bit words,
+/// see [yuv_nv_p16_to_yuv_nv12] for other containers.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source P010 image.
+/// * `target_image` - Target NV12 image of the same size.
+/// * `dither` - Ordered dither while reducing to 8 bits, otherwise values are rounded.
+///
+pub fn yuv_p010_to_yuv_nv12(
+    bi_planar_image: &YuvBiPlanarImage<u16>,
+    target_image: &mut YuvBiPlanarImageMut<u8>,
+    dither: bool,
+) -> Result<(), YuvError> {
+    yuv_nv_p16_to_yuv_nv12(
+        bi_planar_image,
+        target_image,
+        10,
+        YuvBytesPacking::MostSignificantBytes,
+        dither,
+    )
+}
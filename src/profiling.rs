@@ -0,0 +1,121 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+pub(crate) use crate::cpu_features::dispatch_backend;
+use crate::cpu_features::YuvDispatchBackend;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Copy, Clone)]
+/// Statistics of single conversion call
+pub struct YuvConversionProfile {
+    /// Name of the internal conversion routine, e.g. `yuv_to_rgba`
+    pub name: &'static str,
+    /// Wall time spent in conversion
+    pub duration: Duration,
+    /// Total size of source and destination buffers in bytes
+    pub bytes_processed: usize,
+    /// Backend that processed the bulk of the rows
+    pub backend: YuvDispatchBackend,
+    /// Amount of row jobs given to rayon, 0 when conversion was single threaded
+    pub rayon_splits: usize,
+}
+
+/// Callback receiving conversion statistics, it is called on the thread that did the conversion
+pub type YuvProfilingCallback = fn(&YuvConversionProfile);
+
+static PROFILING_CALLBACK: RwLock<Option<YuvProfilingCallback>> = RwLock::new(None);
+
+/// Installs process wide profiling callback, `None` removes the current one.
+///
+/// Each instrumented conversion reports its wall time, amount of touched bytes,
+/// SIMD backend selected by runtime dispatch and amount of rows handed to rayon.
+pub fn set_profiling_callback(callback: Option<YuvProfilingCallback>) {
+    if let Ok(mut lock) = PROFILING_CALLBACK.write() {
+        *lock = callback;
+    }
+}
+
+pub(crate) struct ProfileScope {
+    name: &'static str,
+    bytes_processed: usize,
+    backend: YuvDispatchBackend,
+    rayon_splits: usize,
+    start: Instant,
+}
+
+impl ProfileScope {
+    #[inline]
+    pub(crate) fn new(
+        name: &'static str,
+        bytes_processed: usize,
+        backend: YuvDispatchBackend,
+        rayon_splits: usize,
+    ) -> ProfileScope {
+        ProfileScope {
+            name,
+            bytes_processed,
+            backend,
+            rayon_splits,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for ProfileScope {
+    fn drop(&mut self) {
+        let callback = match PROFILING_CALLBACK.read() {
+            Ok(lock) => *lock,
+            Err(_) => None,
+        };
+        if let Some(callback) = callback {
+            callback(&YuvConversionProfile {
+                name: self.name,
+                duration: self.start.elapsed(),
+                bytes_processed: self.bytes_processed,
+                backend: self.backend,
+                rayon_splits: self.rayon_splits,
+            });
+        }
+    }
+}
+
+/// Amount of row jobs rayon receives for image with `rows`
+#[inline]
+#[allow(unused_variables)]
+pub(crate) const fn rayon_splits(rows: u32) -> usize {
+    #[cfg(feature = "rayon")]
+    {
+        rows as usize
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        0
+    }
+}
@@ -0,0 +1,320 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, YuvGrayImage, YuvGrayImageMut, YuvPlanarImage, YuvPlanarImageMut};
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::neon::neon_box_decimate_row;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::sse::sse_box_decimate_row;
+use crate::yuv_support::YuvChromaSample;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// Decimation filter used to build each next pyramid level
+pub enum YuvPyramidFilter {
+    /// Average of 2x2 block
+    #[default]
+    Box,
+    /// Separable 5-tap Gaussian \[1, 4, 6, 4, 1\], sampled at even positions
+    Gaussian,
+}
+
+#[derive(Copy, Clone)]
+struct DecimatedPlane<'a> {
+    data: &'a [u8],
+    stride: usize,
+    size: ImageSize,
+}
+
+#[inline]
+fn next_level_size(size: ImageSize) -> ImageSize {
+    ImageSize::new(size.width.div_ceil(2), size.height.div_ceil(2))
+}
+
+fn box_decimate_plane(src: DecimatedPlane, dst: &mut [u8], dst_stride: usize) {
+    let src_width = src.size.width as usize;
+    let src_height = src.size.height as usize;
+    let dst_width = src.size.width.div_ceil(2) as usize;
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst.par_chunks_mut(dst_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst.chunks_mut(dst_stride);
+    }
+
+    iter.enumerate().for_each(|(y, dst_row)| {
+        let y0 = y * 2;
+        let y1 = (y0 + 1).min(src_height - 1);
+        let row0 = &src.data[y0 * src.stride..][..src_width];
+        let row1 = &src.data[y1 * src.stride..][..src_width];
+
+        let mut _cx = 0usize;
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            if _use_sse {
+                _cx = sse_box_decimate_row(row0, row1, dst_row, src_width);
+            }
+        }
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            _cx = neon_box_decimate_row(row0, row1, dst_row, src_width);
+        }
+
+        for (x, dst) in dst_row.iter_mut().enumerate().take(dst_width).skip(_cx) {
+            let x0 = x * 2;
+            let x1 = (x0 + 1).min(src_width - 1);
+            let sum = row0[x0] as u16 + row0[x1] as u16 + row1[x0] as u16 + row1[x1] as u16;
+            *dst = ((sum + 2) >> 2) as u8;
+        }
+    });
+}
+
+fn gaussian_decimate_plane(src: DecimatedPlane, dst: &mut [u8], dst_stride: usize) {
+    let src_width = src.size.width as usize;
+    let src_height = src.size.height as usize;
+    let dst_width = src.size.width.div_ceil(2) as usize;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst.par_chunks_mut(dst_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst.chunks_mut(dst_stride);
+    }
+
+    iter.enumerate().for_each(|(y, dst_row)| {
+        let rows: [&[u8]; 5] = std::array::from_fn(|i| {
+            let src_y = (y * 2 + i).saturating_sub(2).min(src_height - 1);
+            &src.data[src_y * src.stride..][..src_width]
+        });
+
+        let vertical = |x: usize| {
+            rows[0][x] as u32
+                + 4 * rows[1][x] as u32
+                + 6 * rows[2][x] as u32
+                + 4 * rows[3][x] as u32
+                + rows[4][x] as u32
+        };
+
+        // Neighbouring output pixels share three vertical sums, sliding them along
+        // keeps the row free of any intermediate buffer
+        let last = src_width - 1;
+        let (mut v0, mut v1, mut v2) = (vertical(0), vertical(0), vertical(0));
+        for (x, dst) in dst_row.iter_mut().take(dst_width).enumerate() {
+            let cx = x * 2;
+            let v3 = vertical((cx + 1).min(last));
+            let v4 = vertical((cx + 2).min(last));
+            let sum = v0 + 4 * v1 + 6 * v2 + 4 * v3 + v4;
+            *dst = ((sum + 128) >> 8) as u8;
+            (v0, v1, v2) = (v2, v3, v4);
+        }
+    });
+}
+
+fn decimate_plane(
+    src: DecimatedPlane,
+    dst: &mut [u8],
+    dst_stride: usize,
+    filter: YuvPyramidFilter,
+) {
+    match filter {
+        YuvPyramidFilter::Box => box_decimate_plane(src, dst, dst_stride),
+        YuvPyramidFilter::Gaussian => gaussian_decimate_plane(src, dst, dst_stride),
+    }
+}
+
+/// Builds pyramid of luma plane by repeatedly halving image in each dimension.
+///
+/// Odd dimensions are rounded up, edge pixels are replicated.
+/// Pyramid stops early when image reaches 1x1.
+///
+/// # Arguments
+///
+/// * `image` - Source image, not included into resulting pyramid.
+/// * `levels` - Count of downscaled levels to produce.
+/// * `filter` - Decimation filter, see [YuvPyramidFilter].
+///
+pub fn build_y_pyramid(
+    image: &YuvGrayImage<u8>,
+    levels: u32,
+    filter: YuvPyramidFilter,
+) -> Result<Vec<YuvGrayImageMut<'static, u8>>, YuvError> {
+    image.check_constraints()?;
+    if image.size.width == 0 || image.size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+
+    // Halving any u32 dimension reaches 1 in at most 32 steps
+    let capacity = levels.min(u32::BITS) as usize;
+    let mut pyramid: Vec<YuvGrayImageMut<'static, u8>> = Vec::new();
+    pyramid.try_reserve_exact(capacity).map_err(|_| {
+        YuvError::AllocationFailure(capacity * std::mem::size_of::<YuvGrayImageMut<u8>>())
+    })?;
+    for _ in 0..levels {
+        let source = pyramid
+            .last()
+            .map(|x| x.to_fixed())
+            .unwrap_or(image.clone());
+        if source.size.width == 1 && source.size.height == 1 {
+            break;
+        }
+        let mut level = YuvGrayImageMut::try_alloc(next_level_size(source.size))?;
+        let y_stride = level.y_stride.get() as usize;
+        decimate_plane(
+            DecimatedPlane {
+                data: source.y_plane,
+                stride: source.y_stride.get() as usize,
+                size: source.size,
+            },
+            level.y_plane.borrow_mut(),
+            y_stride,
+            filter,
+        );
+        pyramid.push(level);
+    }
+
+    Ok(pyramid)
+}
+
+/// Builds pyramid of planar YUV image by repeatedly halving image in each dimension.
+///
+/// Each plane is decimated independently so chroma subsampling is preserved on every level.
+/// Odd dimensions are rounded up, edge pixels are replicated.
+/// Pyramid stops early when image reaches 1x1.
+///
+/// # Arguments
+///
+/// * `image` - Source image, not included into resulting pyramid.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `levels` - Count of downscaled levels to produce.
+/// * `filter` - Decimation filter, see [YuvPyramidFilter].
+///
+pub fn build_yuv_pyramid(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    levels: u32,
+    filter: YuvPyramidFilter,
+) -> Result<Vec<YuvPlanarImageMut<'static, u8>>, YuvError> {
+    image.check_constraints(sampling)?;
+    if image.size.width == 0 || image.size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+
+    let chroma_size = |size: ImageSize| match sampling {
+        YuvChromaSample::YUV420 => ImageSize::new(size.width.div_ceil(2), size.height.div_ceil(2)),
+        YuvChromaSample::YUV422 => ImageSize::new(size.width.div_ceil(2), size.height),
+        YuvChromaSample::YUV444 => size,
+    };
+
+    // Halving any u32 dimension reaches 1 in at most 32 steps
+    let capacity = levels.min(u32::BITS) as usize;
+    let mut pyramid: Vec<YuvPlanarImageMut<'static, u8>> = Vec::new();
+    pyramid.try_reserve_exact(capacity).map_err(|_| {
+        YuvError::AllocationFailure(capacity * std::mem::size_of::<YuvPlanarImageMut<u8>>())
+    })?;
+    for _ in 0..levels {
+        let source = pyramid
+            .last()
+            .map(|x| x.to_fixed())
+            .unwrap_or(image.clone());
+        if source.size.width == 1 && source.size.height == 1 {
+            break;
+        }
+        let mut level = YuvPlanarImageMut::try_alloc(next_level_size(source.size), sampling)?;
+        let source_chroma_size = chroma_size(source.size);
+
+        let y_stride = level.y_stride.get() as usize;
+        decimate_plane(
+            DecimatedPlane {
+                data: source.y_plane,
+                stride: source.y_stride.get() as usize,
+                size: source.size,
+            },
+            level.y_plane.borrow_mut(),
+            y_stride,
+            filter,
+        );
+        let u_stride = level.u_stride.get() as usize;
+        decimate_plane(
+            DecimatedPlane {
+                data: source.u_plane,
+                stride: source.u_stride.get() as usize,
+                size: source_chroma_size,
+            },
+            level.u_plane.borrow_mut(),
+            u_stride,
+            filter,
+        );
+        let v_stride = level.v_stride.get() as usize;
+        decimate_plane(
+            DecimatedPlane {
+                data: source.v_plane,
+                stride: source.v_stride.get() as usize,
+                size: source_chroma_size,
+            },
+            level.v_plane.borrow_mut(),
+            v_stride,
+            filter,
+        );
+        pyramid.push(level);
+    }
+
+    Ok(pyramid)
+}
@@ -0,0 +1,163 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride};
+use crate::yuv_error::{check_raw10_plane, check_y8_channel};
+use crate::yuv_support::YuvBytesPacking;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+/// Shift placing 10-bit sample into 16-bit word with requested packing
+const fn packing_shift(bytes_packing: YuvBytesPacking) -> u32 {
+    match bytes_packing {
+        YuvBytesPacking::MostSignificantBytes => 6,
+        YuvBytesPacking::LeastSignificantBytes => 0,
+    }
+}
+
+/// Unpacks 10-bit packed plane ( MIPI RAW10, Y10P ) into 16-bit samples.
+///
+/// Every 4 samples take 5 bytes: high 8 bits of each sample followed by a byte
+/// holding their low 2 bits, first sample in the least significant bits.
+/// Unpacked plane can be fed to p10 conversions with native endianness.
+///
+/// # Arguments
+///
+/// * `packed` - A slice to load 10-bit packed plane data.
+/// * `packed_stride` - The stride (bytes per row) for the packed plane, at least `ceil(size.width / 4) * 5`.
+/// * `dst` - A mutable slice to store 16-bit plane data.
+/// * `dst_stride` - The stride (bytes per row) for the destination plane.
+/// * `size` - The size of the plane.
+/// * `bytes_packing` - Position of 10 significant bits in destination 16-bit words.
+///
+pub fn unpack_raw10_plane(
+    packed: &[u8],
+    packed_stride: RowStride,
+    dst: &mut [u16],
+    dst_stride: RowStride,
+    size: ImageSize,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (packed_stride, dst_stride) = (packed_stride.get(), dst_stride.get());
+    check_raw10_plane(packed, packed_stride, width, height)?;
+    check_y8_channel(dst, dst_stride, width, height)?;
+
+    let width = width as usize;
+    let shift = packing_shift(bytes_packing);
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride as usize / 2)
+            .zip(packed.par_chunks(packed_stride as usize))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride as usize / 2)
+            .zip(packed.chunks(packed_stride as usize))
+            .take(height as usize);
+    }
+    iter.for_each(|(dst, packed)| {
+        for (dst, group) in dst[..width].chunks_mut(4).zip(packed.chunks_exact(5)) {
+            let low_bits = group[4];
+            for (i, dst) in dst.iter_mut().enumerate() {
+                let value = ((group[i] as u16) << 2) | ((low_bits >> (i * 2)) & 0b11) as u16;
+                *dst = value << shift;
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Packs 16-bit samples into 10-bit packed plane ( MIPI RAW10, Y10P ).
+///
+/// Layout is the same as in [unpack_raw10_plane], samples above 10 bits are truncated
+/// and padding samples of the last group in a row are written as zeros.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load 16-bit plane data.
+/// * `src_stride` - The stride (bytes per row) for the source plane.
+/// * `packed` - A mutable slice to store 10-bit packed plane data.
+/// * `packed_stride` - The stride (bytes per row) for the packed plane, at least `ceil(size.width / 4) * 5`.
+/// * `size` - The size of the plane.
+/// * `bytes_packing` - Position of 10 significant bits in source 16-bit words.
+///
+pub fn pack_raw10_plane(
+    src: &[u16],
+    src_stride: RowStride,
+    packed: &mut [u8],
+    packed_stride: RowStride,
+    size: ImageSize,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (src_stride, packed_stride) = (src_stride.get(), packed_stride.get());
+    check_y8_channel(src, src_stride, width, height)?;
+    check_raw10_plane(packed, packed_stride, width, height)?;
+
+    let width = width as usize;
+    let shift = packing_shift(bytes_packing);
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = packed
+            .par_chunks_mut(packed_stride as usize)
+            .zip(src.par_chunks(src_stride as usize / 2))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = packed
+            .chunks_mut(packed_stride as usize)
+            .zip(src.chunks(src_stride as usize / 2))
+            .take(height as usize);
+    }
+    iter.for_each(|(packed, src)| {
+        for (group, src) in packed.chunks_exact_mut(5).zip(src[..width].chunks(4)) {
+            let mut low_bits = 0u8;
+            for (i, dst) in group[..4].iter_mut().enumerate() {
+                let value = src.get(i).map_or(0, |&v| (v >> shift) & 0x3ff);
+                *dst = (value >> 2) as u8;
+                low_bits |= ((value & 0b11) as u8) << (i * 2);
+            }
+            group[4] = low_bits;
+        }
+    });
+
+    Ok(())
+}
@@ -0,0 +1,230 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride};
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::neon::neon_reorder_channels_row;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::sse::sse_reorder_channels_row;
+use crate::yuv_error::check_rgba_destination;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// Byte order of an interleaved 8-bit pixel
+pub enum ChannelLayout {
+    Rgb,
+    Bgr,
+    Rgba,
+    Bgra,
+    Argb,
+    Abgr,
+}
+
+impl ChannelLayout {
+    #[inline]
+    pub const fn channels(self) -> usize {
+        match self {
+            ChannelLayout::Rgb | ChannelLayout::Bgr => 3,
+            ChannelLayout::Rgba
+            | ChannelLayout::Bgra
+            | ChannelLayout::Argb
+            | ChannelLayout::Abgr => 4,
+        }
+    }
+
+    /// Byte positions of red, green, blue and alpha inside a pixel
+    #[inline]
+    const fn positions(self) -> [Option<usize>; 4] {
+        match self {
+            ChannelLayout::Rgb => [Some(0), Some(1), Some(2), None],
+            ChannelLayout::Bgr => [Some(2), Some(1), Some(0), None],
+            ChannelLayout::Rgba => [Some(0), Some(1), Some(2), Some(3)],
+            ChannelLayout::Bgra => [Some(2), Some(1), Some(0), Some(3)],
+            ChannelLayout::Argb => [Some(1), Some(2), Some(3), Some(0)],
+            ChannelLayout::Abgr => [Some(3), Some(2), Some(1), Some(0)],
+        }
+    }
+}
+
+/// Byte shuffle of 4 pixels at once, shared by scalar and SIMD paths
+#[derive(Debug, Copy, Clone)]
+pub(crate) struct ChannelShuffle {
+    pub(crate) src_channels: usize,
+    pub(crate) dst_channels: usize,
+    /// Source byte of every destination byte, `0x80` marks bytes taken from `fill`
+    pub(crate) shuffle: [u8; 16],
+    /// Opaque alpha where source has none, zero elsewhere
+    pub(crate) fill: [u8; 16],
+}
+
+impl ChannelShuffle {
+    fn new(src_layout: ChannelLayout, dst_layout: ChannelLayout) -> ChannelShuffle {
+        let src_channels = src_layout.channels();
+        let dst_channels = dst_layout.channels();
+        let src_positions = src_layout.positions();
+        let dst_positions = dst_layout.positions();
+        let mut shuffle = [0x80u8; 16];
+        let mut fill = [0u8; 16];
+        for pixel in 0..4 {
+            for (src_position, dst_position) in src_positions.iter().zip(dst_positions.iter()) {
+                if let Some(dst_position) = dst_position {
+                    let dst_index = pixel * dst_channels + dst_position;
+                    match src_position {
+                        Some(src_position) => {
+                            shuffle[dst_index] = (pixel * src_channels + src_position) as u8;
+                        }
+                        None => fill[dst_index] = 255,
+                    }
+                }
+            }
+        }
+        ChannelShuffle {
+            src_channels,
+            dst_channels,
+            shuffle,
+            fill,
+        }
+    }
+
+    #[inline(always)]
+    fn apply_pixel(&self, src: &[u8], dst: &mut [u8]) {
+        for ((dst, &index), &fill) in dst
+            .iter_mut()
+            .zip(self.shuffle.iter())
+            .zip(self.fill.iter())
+            .take(self.dst_channels)
+        {
+            *dst = if index == 0x80 {
+                fill
+            } else {
+                src[index as usize]
+            };
+        }
+    }
+}
+
+/// Reorders channels of an interleaved 8-bit image, copying it from `src` to `dst`.
+///
+/// Any pair of layouts is accepted: channels are permuted, alpha is dropped when `dst_layout`
+/// has none and set to opaque when `src_layout` has none. This is a shuffle only pass meant for
+/// pipelines that need another byte order after conversion was already done.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the source pixels.
+/// * `src_stride` - The stride (bytes per row) for the source image.
+/// * `src_layout` - Byte order of the source pixels.
+/// * `dst` - A mutable slice to store the reordered pixels.
+/// * `dst_stride` - The stride (bytes per row) for the destination image.
+/// * `dst_layout` - Byte order of the destination pixels.
+/// * `size` - The size of the image.
+///
+pub fn reorder_channels(
+    src: &[u8],
+    src_stride: RowStride,
+    src_layout: ChannelLayout,
+    dst: &mut [u8],
+    dst_stride: RowStride,
+    dst_layout: ChannelLayout,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (src_stride, dst_stride) = (src_stride.get(), dst_stride.get());
+    check_rgba_destination(src, src_stride, width, height, src_layout.channels())?;
+    check_rgba_destination(dst, dst_stride, width, height, dst_layout.channels())?;
+
+    let shuffle = ChannelShuffle::new(src_layout, dst_layout);
+    let width = width as usize;
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride as usize)
+            .zip(src.par_chunks(src_stride as usize))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride as usize)
+            .zip(src.chunks(src_stride as usize))
+            .take(height as usize);
+    }
+    iter.for_each(|(dst, src)| {
+        #[allow(unused_mut)]
+        let mut cx = 0usize;
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            if _use_sse {
+                cx = sse_reorder_channels_row(&shuffle, src, dst, width);
+            }
+        }
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            cx = neon_reorder_channels_row(&shuffle, src, dst, width);
+        }
+
+        for (dst, src) in dst
+            .chunks_exact_mut(shuffle.dst_channels)
+            .zip(src.chunks_exact(shuffle.src_channels))
+            .take(width)
+            .skip(cx)
+        {
+            shuffle.apply_pixel(src, dst);
+        }
+    });
+
+    Ok(())
+}
@@ -0,0 +1,497 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride, YuvBiPlanarImage, YuvPlanarImage};
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_planar_generic::{yuv_biplanar_to_rgb_generic, yuv_planar_to_rgb30, BAYER_8X8};
+use crate::yuv_support::{
+    Rgb30, Rgb30Padding, YuvBytesPacking, YuvChromaSample, YuvEndianness, YuvNVOrder, YuvRange,
+    YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+type YuvP10ToRgb30 = fn(
+    &[u16],
+    u32,
+    &[u16],
+    u32,
+    &[u16],
+    u32,
+    &mut [u32],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+    usize,
+    Rgb30,
+    Rgb30Padding,
+) -> Result<(), YuvError>;
+
+fn yuv_p10_to_rgb30_dispatcher<const SAMPLING: u8>(
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> YuvP10ToRgb30 {
+    match (endianness, bytes_packing) {
+        (YuvEndianness::BigEndian, YuvBytesPacking::MostSignificantBytes) => {
+            yuv_planar_to_rgb30::<
+                u16,
+                SAMPLING,
+                { YuvEndianness::BigEndian as u8 },
+                { YuvBytesPacking::MostSignificantBytes as u8 },
+            >
+        }
+        (YuvEndianness::BigEndian, YuvBytesPacking::LeastSignificantBytes) => {
+            yuv_planar_to_rgb30::<
+                u16,
+                SAMPLING,
+                { YuvEndianness::BigEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+            >
+        }
+        (YuvEndianness::LittleEndian, YuvBytesPacking::MostSignificantBytes) => {
+            yuv_planar_to_rgb30::<
+                u16,
+                SAMPLING,
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::MostSignificantBytes as u8 },
+            >
+        }
+        (YuvEndianness::LittleEndian, YuvBytesPacking::LeastSignificantBytes) => {
+            yuv_planar_to_rgb30::<
+                u16,
+                SAMPLING,
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+            >
+        }
+    }
+}
+
+/// Convert YUV planar format with 10-bit pixel format to any 30-bit packed RGB layout.
+///
+/// Covers `XR30`/`XB30` framebuffers as well as `AR30`/`AB30`,
+/// `padding` decides what lands in the two most significant bits.
+///
+/// # Arguments
+///
+/// * `planar_image` - Source planar image with 10-bit depth.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `rgb30` - A mutable slice to store the packed 30-bit RGB data.
+/// * `rgb30_stride` - The stride (bytes per row) for packed RGB data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes.
+/// * `bytes_packing` - Position of significant bytes (most significant or least significant).
+/// * `layout` - Component order of the destination, see [Rgb30].
+/// * `padding` - Value of the two padding bits, see [Rgb30Padding].
+///
+pub fn yuv_p10_to_rgb30(
+    planar_image: &YuvPlanarImage<u16>,
+    sampling: YuvChromaSample,
+    rgb30: &mut [u32],
+    rgb30_stride: RowStride,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+    layout: Rgb30,
+    padding: Rgb30Padding,
+) -> Result<(), YuvError> {
+    let rgb30_stride = rgb30_stride.get();
+    let dispatcher =
+        match sampling {
+            YuvChromaSample::YUV420 => yuv_p10_to_rgb30_dispatcher::<
+                { YuvChromaSample::YUV420 as u8 },
+            >(endianness, bytes_packing),
+            YuvChromaSample::YUV422 => yuv_p10_to_rgb30_dispatcher::<
+                { YuvChromaSample::YUV422 as u8 },
+            >(endianness, bytes_packing),
+            YuvChromaSample::YUV444 => yuv_p10_to_rgb30_dispatcher::<
+                { YuvChromaSample::YUV444 as u8 },
+            >(endianness, bytes_packing),
+        };
+    dispatcher(
+        planar_image.y_plane,
+        planar_image.y_stride.get(),
+        planar_image.u_plane,
+        planar_image.u_stride.get(),
+        planar_image.v_plane,
+        planar_image.v_stride.get(),
+        rgb30,
+        rgb30_stride,
+        planar_image.size.width,
+        planar_image.size.height,
+        range,
+        matrix,
+        10,
+        layout,
+        padding,
+    )
+}
+
+/// Convert NV12 or NV21 to any 30-bit packed RGB layout.
+///
+/// 8-bit source is expanded straight to 10 bits per component, so the result
+/// can be scanned out on `XR30`/`XB30` planes without an intermediate RGBA buffer.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source bi-planar 4:2:0 image with 8-bit depth.
+/// * `order` - Order of chroma samples in the interleaved plane (NV12 or NV21).
+/// * `rgb30` - A mutable slice to store the packed 30-bit RGB data.
+/// * `rgb30_stride` - The stride (bytes per row) for packed RGB data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `layout` - Component order of the destination, see [Rgb30].
+/// * `padding` - Value of the two padding bits, see [Rgb30Padding].
+///
+pub fn yuv_nv12_to_rgb30(
+    bi_planar_image: &YuvBiPlanarImage<u8>,
+    order: YuvNVOrder,
+    rgb30: &mut [u32],
+    rgb30_stride: RowStride,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    layout: Rgb30,
+    padding: Rgb30Padding,
+) -> Result<(), YuvError> {
+    let rgb30_stride = rgb30_stride.get();
+    bi_planar_image.check_constraints(YuvChromaSample::YUV420)?;
+    let width = bi_planar_image.size.width;
+    let height = bi_planar_image.size.height;
+    check_rgba_destination(rgb30, rgb30_stride, width, height, 1)?;
+
+    yuv_biplanar_to_rgb_generic::<
+        u8,
+        u32,
+        { YuvChromaSample::YUV420 as u8 },
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::LeastSignificantBytes as u8 },
+    >(
+        bi_planar_image.y_plane,
+        bi_planar_image.y_stride.get(),
+        bi_planar_image.uv_plane,
+        bi_planar_image.uv_stride.get(),
+        order,
+        rgb30,
+        rgb30_stride,
+        width,
+        height,
+        range,
+        matrix,
+        8,
+        10,
+        |dst, x, r, g, b| {
+            dst[x] = layout.pack(r as u32, g as u32, b as u32, padding).to_le();
+        },
+    );
+    Ok(())
+}
+
+/// Convert P010 to any 30-bit packed RGB layout.
+///
+/// P010 keeps 10-bit samples in the most significant bits of little endian 16-bit words
+/// with Cb before Cr in the interleaved plane.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source P010 image.
+/// * `rgb30` - A mutable slice to store the packed 30-bit RGB data.
+/// * `rgb30_stride` - The stride (bytes per row) for packed RGB data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `layout` - Component order of the destination, see [Rgb30].
+/// * `padding` - Value of the two padding bits, see [Rgb30Padding].
+///
+pub fn yuv_p010_to_rgb30(
+    bi_planar_image: &YuvBiPlanarImage<u16>,
+    rgb30: &mut [u32],
+    rgb30_stride: RowStride,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    layout: Rgb30,
+    padding: Rgb30Padding,
+) -> Result<(), YuvError> {
+    let rgb30_stride = rgb30_stride.get();
+    bi_planar_image.check_constraints(YuvChromaSample::YUV420)?;
+    let width = bi_planar_image.size.width;
+    let height = bi_planar_image.size.height;
+    check_rgba_destination(rgb30, rgb30_stride, width, height, 1)?;
+
+    yuv_biplanar_to_rgb_generic::<
+        u16,
+        u32,
+        { YuvChromaSample::YUV420 as u8 },
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::MostSignificantBytes as u8 },
+    >(
+        bi_planar_image.y_plane,
+        bi_planar_image.y_stride.get(),
+        bi_planar_image.uv_plane,
+        bi_planar_image.uv_stride.get(),
+        YuvNVOrder::UV,
+        rgb30,
+        rgb30_stride,
+        width,
+        height,
+        range,
+        matrix,
+        10,
+        10,
+        |dst, x, r, g, b| {
+            dst[x] = layout.pack(r as u32, g as u32, b as u32, padding).to_le();
+        },
+    );
+    Ok(())
+}
+
+/// Walks 30-bit packed rows and hands every unpacked pixel to `store` as `(row, y, x, [r, g, b, a])`,
+/// sizes must be already validated
+fn unpack_rgb30_rows<V: Copy + Send>(
+    rgb30: &[u32],
+    rgb30_stride: u32,
+    layout: Rgb30,
+    dst: &mut [V],
+    dst_stride: usize,
+    width: u32,
+    height: u32,
+    store: impl Fn(&mut [V], usize, usize, [u32; 4]) + Send + Sync,
+) {
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgb30
+            .par_chunks(rgb30_stride as usize / 4)
+            .zip(dst.par_chunks_mut(dst_stride))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgb30
+            .chunks(rgb30_stride as usize / 4)
+            .zip(dst.chunks_mut(dst_stride))
+            .take(height as usize);
+    }
+    iter.enumerate().for_each(|(y, (src, dst))| {
+        for (x, &v) in src.iter().take(width as usize).enumerate() {
+            store(dst, y, x, layout.unpack(u32::from_le(v)));
+        }
+    });
+}
+
+/// Reduces 10-bit component to 8 bits, either rounded or ordered dithered by `threshold`
+#[inline(always)]
+fn rgb30_component_to_8(v: u32, threshold: u32) -> u8 {
+    ((v * 255 + threshold) / 1023) as u8
+}
+
+/// Dithering threshold for the pixel, plain rounding when dithering is off
+#[inline(always)]
+fn rgb30_threshold(dither: bool, y: usize, x: usize) -> u32 {
+    if dither {
+        // Thresholds are centered in each of 64 buckets so the mean matches plain rounding
+        (BAYER_8X8[y & 7][x & 7] as u32 * 2 + 1) * 1023 / 128
+    } else {
+        511
+    }
+}
+
+/// Convert 30-bit packed RGB to RGBA with 8-bit per channel precision.
+///
+/// Two padding bits are expanded to alpha, so `XR30`/`XB30`/`RX30` sources
+/// must be written with opaque padding or converted with [rgb30_to_rgb] instead.
+///
+/// # Arguments
+///
+/// * `rgb30` - A slice to load the packed 30-bit RGB data.
+/// * `rgb30_stride` - The stride (bytes per row) for packed RGB data.
+/// * `layout` - Component order of the source, see [Rgb30].
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+/// * `size` - The size of the image.
+/// * `dither` - Ordered dither while reducing 10 bits to 8, otherwise values are rounded.
+///
+pub fn ar30_to_rgba8(
+    rgb30: &[u32],
+    rgb30_stride: RowStride,
+    layout: Rgb30,
+    rgba: &mut [u8],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    dither: bool,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (rgb30_stride, rgba_stride) = (rgb30_stride.get(), rgba_stride.get());
+    check_rgba_destination(rgb30, rgb30_stride, width, height, 1)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, 4)?;
+    unpack_rgb30_rows(
+        rgb30,
+        rgb30_stride,
+        layout,
+        rgba,
+        rgba_stride as usize,
+        width,
+        height,
+        |dst, y, x, [r, g, b, a]| {
+            let threshold = rgb30_threshold(dither, y, x);
+            let dst = &mut dst[x * 4..x * 4 + 4];
+            dst[0] = rgb30_component_to_8(r, threshold);
+            dst[1] = rgb30_component_to_8(g, threshold);
+            dst[2] = rgb30_component_to_8(b, threshold);
+            dst[3] = (a * 85) as u8;
+        },
+    );
+    Ok(())
+}
+
+/// Convert 30-bit packed RGB to RGBA with 10-bit per channel stored in 16-bit words.
+///
+/// Two padding bits are expanded to 10-bit alpha.
+///
+/// # Arguments
+///
+/// * `rgb30` - A slice to load the packed 30-bit RGB data.
+/// * `rgb30_stride` - The stride (bytes per row) for packed RGB data.
+/// * `layout` - Component order of the source, see [Rgb30].
+/// * `rgba16` - A mutable slice to store the converted RGBA data.
+/// * `rgba16_stride` - The stride (bytes per row) for RGBA data.
+/// * `size` - The size of the image.
+///
+pub fn ar30_to_rgba16(
+    rgb30: &[u32],
+    rgb30_stride: RowStride,
+    layout: Rgb30,
+    rgba16: &mut [u16],
+    rgba16_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (rgb30_stride, rgba16_stride) = (rgb30_stride.get(), rgba16_stride.get());
+    check_rgba_destination(rgb30, rgb30_stride, width, height, 1)?;
+    check_rgba_destination(rgba16, rgba16_stride, width, height, 4)?;
+    unpack_rgb30_rows(
+        rgb30,
+        rgb30_stride,
+        layout,
+        rgba16,
+        rgba16_stride as usize / 2,
+        width,
+        height,
+        |dst, _, x, [r, g, b, a]| {
+            let dst = &mut dst[x * 4..x * 4 + 4];
+            dst[0] = r as u16;
+            dst[1] = g as u16;
+            dst[2] = b as u16;
+            dst[3] = (a * 341) as u16;
+        },
+    );
+    Ok(())
+}
+
+/// Convert 30-bit packed RGB to RGB with 8-bit per channel precision, padding bits are dropped.
+///
+/// Suits `XR30`/`XB30`/`RX30` framebuffers whatever is stored in the padding.
+///
+/// # Arguments
+///
+/// * `rgb30` - A slice to load the packed 30-bit RGB data.
+/// * `rgb30_stride` - The stride (bytes per row) for packed RGB data.
+/// * `layout` - Component order of the source, see [Rgb30].
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for RGB data.
+/// * `size` - The size of the image.
+/// * `dither` - Ordered dither while reducing 10 bits to 8, otherwise values are rounded.
+///
+pub fn rgb30_to_rgb(
+    rgb30: &[u32],
+    rgb30_stride: RowStride,
+    layout: Rgb30,
+    rgb: &mut [u8],
+    rgb_stride: RowStride,
+    size: ImageSize,
+    dither: bool,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (rgb30_stride, rgb_stride) = (rgb30_stride.get(), rgb_stride.get());
+    check_rgba_destination(rgb30, rgb30_stride, width, height, 1)?;
+    check_rgba_destination(rgb, rgb_stride, width, height, 3)?;
+    unpack_rgb30_rows(
+        rgb30,
+        rgb30_stride,
+        layout,
+        rgb,
+        rgb_stride as usize,
+        width,
+        height,
+        |dst, y, x, [r, g, b, _]| {
+            let threshold = rgb30_threshold(dither, y, x);
+            let dst = &mut dst[x * 3..x * 3 + 3];
+            dst[0] = rgb30_component_to_8(r, threshold);
+            dst[1] = rgb30_component_to_8(g, threshold);
+            dst[2] = rgb30_component_to_8(b, threshold);
+        },
+    );
+    Ok(())
+}
+
+/// Convert `RA30` ( `RGBA1010102` ) to RGB with 8-bit per channel precision, alpha is dropped.
+///
+/// Shorthand for [rgb30_to_rgb] with [Rgb30::Ra30] layout.
+///
+/// # Arguments
+///
+/// * `ra30` - A slice to load the `RA30` data.
+/// * `ra30_stride` - The stride (bytes per row) for `RA30` data.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for RGB data.
+/// * `size` - The size of the image.
+/// * `dither` - Ordered dither while reducing 10 bits to 8, otherwise values are rounded.
+///
+pub fn ra30_to_rgb(
+    ra30: &[u32],
+    ra30_stride: RowStride,
+    rgb: &mut [u8],
+    rgb_stride: RowStride,
+    size: ImageSize,
+    dither: bool,
+) -> Result<(), YuvError> {
+    rgb30_to_rgb(
+        ra30,
+        ra30_stride,
+        Rgb30::Ra30,
+        rgb,
+        rgb_stride,
+        size,
+        dither,
+    )
+}
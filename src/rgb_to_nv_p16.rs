@@ -26,10 +26,16 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+use crate::images::YuvBiPlanarImageMut;
+use crate::yuv_error::{
+    check_interleaved_chroma_channel, check_rgba_destination, check_y8_channel,
+};
+use crate::yuv_support::p16_dispatch;
 use crate::yuv_support::{
     get_forward_transform, get_yuv_range, ToIntegerTransform, YuvChromaSample, YuvNVOrder,
     YuvSourceChannels,
 };
+use crate::YuvError;
 use crate::{YuvBytesPacking, YuvEndianness, YuvRange, YuvStandardMatrix};
 
 #[inline(always)]
@@ -67,13 +73,17 @@ fn rgbx_to_yuv_bi_planar_10_impl<
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     let nv_order: YuvNVOrder = NV_ORDER.into();
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
     let channels = src_chans.get_channels_count();
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_interleaved_chroma_channel(uv_plane, uv_stride, width, height, chroma_subsampling)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+
     let range = get_yuv_range(BIT_DEPTH as u32, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let max_range = (1u32 << BIT_DEPTH as u32) - 1u32;
     let transform_precise =
         get_forward_transform(max_range, range.range_y, range.range_uv, kr_kb.kr, kr_kb.kb);
@@ -89,17 +99,16 @@ fn rgbx_to_yuv_bi_planar_10_impl<
         YuvChromaSample::YUV444 => 1usize,
     };
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_sse = crate::cpu_features::has_sse41();
 
     let mut y_offset = 0usize;
     let mut uv_offset = 0usize;
     let mut rgba_offset = 0usize;
 
-    let y_dst_ptr = y_plane.as_mut_ptr() as *mut u8;
-    let uv_dst_ptr = uv_plane.as_mut_ptr() as *mut u8;
-    let rgb_src_ptr = rgba.as_ptr() as *const u8;
-
     for y in 0..height as usize {
         let mut _cx = 0usize;
         let mut _ux = 0usize;
@@ -108,16 +117,16 @@ fn rgbx_to_yuv_bi_planar_10_impl<
             || chroma_subsampling == YuvChromaSample::YUV422
             || y & 1 == 0;
 
-        let y_st_ptr = unsafe { y_dst_ptr.add(y_offset) as *mut u16 };
-        let uv_st_ptr = unsafe { uv_dst_ptr.add(uv_offset) as *mut u16 };
-        let rgb_ld_ptr = unsafe { rgb_src_ptr.add(rgba_offset) as *const u16 };
+        let y_dst = &mut y_plane[y_offset / 2..];
+        let uv_dst = &mut uv_plane[uv_offset / 2..];
+        let rgba_src = &rgba[rgba_offset / 2..];
 
         for x in (_cx..width as usize).step_by(iterator_step) {
             let px = x * channels;
-            let src = unsafe { rgb_ld_ptr.add(px) };
-            let r0 = unsafe { src.add(src_chans.get_r_channel_offset()).read_unaligned() } as i32;
-            let g0 = unsafe { src.add(src_chans.get_g_channel_offset()).read_unaligned() } as i32;
-            let b0 = unsafe { src.add(src_chans.get_b_channel_offset()).read_unaligned() } as i32;
+            let src = &rgba_src[px..];
+            let r0 = src[src_chans.get_r_channel_offset()] as i32;
+            let g0 = src[src_chans.get_g_channel_offset()] as i32;
+            let b0 = src[src_chans.get_b_channel_offset()] as i32;
 
             let mut r1 = r0;
             let mut g1 = g0;
@@ -125,34 +134,20 @@ fn rgbx_to_yuv_bi_planar_10_impl<
 
             let y_0 =
                 (r0 * transform.yr + g0 * transform.yg + b0 * transform.yb + bias_y) >> PRECISION;
-            unsafe {
-                y_st_ptr.add(x).write_unaligned(transform_integer::<
-                    ENDIANNESS,
-                    BYTES_POSITION,
-                    BIT_DEPTH,
-                >(y_0));
-            }
+            y_dst[x] = transform_integer::<ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(y_0);
             match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => {
                     if x + 1 < width as usize {
                         let next_px = (x + 1) * channels;
-                        let src = unsafe { rgb_ld_ptr.add(next_px) };
-                        r1 = unsafe { src.add(src_chans.get_r_channel_offset()).read_unaligned() }
-                            as i32;
-                        g1 = unsafe { src.add(src_chans.get_g_channel_offset()).read_unaligned() }
-                            as i32;
-                        b1 = unsafe { src.add(src_chans.get_b_channel_offset()).read_unaligned() }
-                            as i32;
+                        let src = &rgba_src[next_px..];
+                        r1 = src[src_chans.get_r_channel_offset()] as i32;
+                        g1 = src[src_chans.get_g_channel_offset()] as i32;
+                        b1 = src[src_chans.get_b_channel_offset()] as i32;
                         let y_1 =
                             (r1 * transform.yr + g1 * transform.yg + b1 * transform.yb + bias_y)
                                 >> PRECISION;
-                        unsafe {
-                            y_st_ptr.add(x + 1).write_unaligned(transform_integer::<
-                                ENDIANNESS,
-                                BYTES_POSITION,
-                                BIT_DEPTH,
-                            >(y_1));
-                        }
+                        y_dst[x + 1] =
+                            transform_integer::<ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(y_1);
                     }
                 }
                 _ => {}
@@ -182,19 +177,11 @@ fn rgbx_to_yuv_bi_planar_10_impl<
                     YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => _ux,
                     YuvChromaSample::YUV444 => _ux,
                 };
-                unsafe {
-                    let dst_ptr = uv_st_ptr.add(u_pos);
-                    dst_ptr
-                        .add(nv_order.get_u_position())
-                        .write_unaligned(
-                            transform_integer::<ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(cb),
-                        );
-                    dst_ptr
-                        .add(nv_order.get_v_position())
-                        .write_unaligned(
-                            transform_integer::<ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(cr),
-                        );
-                }
+                let dst = &mut uv_dst[u_pos..];
+                dst[nv_order.get_u_position()] =
+                    transform_integer::<ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(cb);
+                dst[nv_order.get_v_position()] =
+                    transform_integer::<ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(cr);
             }
 
             _ux += 2;
@@ -213,6 +200,8 @@ fn rgbx_to_yuv_bi_planar_10_impl<
             }
         }
     }
+
+    Ok(())
 }
 
 fn rgbx_to_yuv_bi_planar_10<
@@ -233,7 +222,7 @@ fn rgbx_to_yuv_bi_planar_10<
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     if bit_depth == 10 {
         rgbx_to_yuv_bi_planar_10_impl::<
             ORIGIN_CHANNELS,
@@ -253,7 +242,7 @@ fn rgbx_to_yuv_bi_planar_10<
             height,
             range,
             matrix,
-        );
+        )
     } else if bit_depth == 12 {
         rgbx_to_yuv_bi_planar_10_impl::<
             ORIGIN_CHANNELS,
@@ -273,7 +262,7 @@ fn rgbx_to_yuv_bi_planar_10<
             height,
             range,
             matrix,
-        );
+        )
     } else {
         panic!("Bit depth {} is not implemented", bit_depth);
     }
@@ -319,56 +308,24 @@ pub fn rgb_to_yuv_nv12_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV 420 bi-planar (NV21 10-bit) format with 10 or 12 bit depth.
@@ -411,56 +368,24 @@ pub fn rgb_to_yuv_nv21_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV 420 bi-planar (NV12 10-bit) format with 10 or 12 bit depth.
@@ -503,52 +428,20 @@ pub fn rgba_to_yuv_nv12_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -561,7 +454,7 @@ pub fn rgba_to_yuv_nv12_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV 420 bi-planar (NV21 10-bit) format with 10 or 12 bit depth.
@@ -604,52 +497,20 @@ pub fn rgba_to_yuv_nv21_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -662,7 +523,7 @@ pub fn rgba_to_yuv_nv21_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV 420 bi-planar (NV12 10-bit) format with 10 or 12 bit depth.
@@ -705,56 +566,24 @@ pub fn bgr_to_yuv_nv12_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV 420 bi-planar (NV21 10-bit) format with 10 or 12 bit depth.
@@ -797,56 +626,24 @@ pub fn bgr_to_yuv_nv21_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV 420 bi-planar (NV12 10-bit) format with 10 or 12 bit depth.
@@ -889,52 +686,20 @@ pub fn bgra_to_yuv_nv12_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -947,7 +712,7 @@ pub fn bgra_to_yuv_nv12_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV 420 bi-planar (NV21 10-bit) format with 10 or 12 bit depth.
@@ -990,52 +755,20 @@ pub fn bgra_to_yuv_nv21_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1048,7 +781,7 @@ pub fn bgra_to_yuv_nv21_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV 422 bi-planar (NV16 10-bit) format with 10 or 12 bit depth.
@@ -1091,56 +824,24 @@ pub fn bgr_to_yuv_nv16_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV 422 bi-planar (NV61 10-bit) format with 10 or 12 bit depth.
@@ -1183,56 +884,24 @@ pub fn bgr_to_yuv_nv61_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV 422 bi-planar (NV16 10-bit) format with 10 or 12 bit depth.
@@ -1275,56 +944,24 @@ pub fn rgb_to_yuv_nv16_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV 422 bi-planar (NV61 10-bit) format with 10 or 12 bit depth.
@@ -1367,56 +1004,24 @@ pub fn rgb_to_yuv_nv61_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV 422 bi-planar (NV16 10-bit) format with 10 or 12 bit depth.
@@ -1459,52 +1064,20 @@ pub fn rgba_to_yuv_nv16_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1517,7 +1090,7 @@ pub fn rgba_to_yuv_nv16_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV 422 bi-planar (NV61 10-bit) format with 10 or 12 bit depth.
@@ -1560,52 +1133,20 @@ pub fn rgba_to_yuv_nv61_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1618,7 +1159,7 @@ pub fn rgba_to_yuv_nv61_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV 422 bi-planar (NV16 10-bit) format with 10 or 12 bit depth.
@@ -1661,52 +1202,20 @@ pub fn bgra_to_yuv_nv16_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1719,7 +1228,7 @@ pub fn bgra_to_yuv_nv16_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV 422 bi-planar (NV61 10-bit) format with 10 or 12 bit depth.
@@ -1762,52 +1271,20 @@ pub fn bgra_to_yuv_nv61_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1820,7 +1297,7 @@ pub fn bgra_to_yuv_nv61_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV 444 bi-planar (NV24 10-bit) format with 10 or 12 bit depth.
@@ -1863,56 +1340,24 @@ pub fn rgb_to_yuv_nv24_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV 444 bi-planar (NV42 10-bit) format with 10 or 12 bit depth.
@@ -1955,56 +1400,24 @@ pub fn rgb_to_yuv_nv42_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV 444 bi-planar (NV24 10-bit) format with 10 or 12 bit depth.
@@ -2047,56 +1460,24 @@ pub fn bgr_to_yuv_nv24_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV 444 bi-planar (NV42 10-bit) format with 10 or 12 bit depth.
@@ -2139,56 +1520,24 @@ pub fn bgr_to_yuv_nv42_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV 444 bi-planar (NV24 10-bit) format with 10 or 12 bit depth.
@@ -2231,52 +1580,20 @@ pub fn bgra_to_yuv_nv24_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -2289,7 +1606,7 @@ pub fn bgra_to_yuv_nv24_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV 444 bi-planar (NV42 10-bit) format with 10 or 12 bit depth.
@@ -2332,52 +1649,20 @@ pub fn bgra_to_yuv_nv42_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -2390,7 +1675,7 @@ pub fn bgra_to_yuv_nv42_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV 444 bi-planar (NV24 10-bit) format with 10 or 12 bit depth.
@@ -2433,52 +1718,20 @@ pub fn rgba_to_yuv_nv24_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -2491,7 +1744,7 @@ pub fn rgba_to_yuv_nv24_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV 444 bi-planar (NV42 10-bit) format with 10 or 12 bit depth.
@@ -2534,52 +1787,20 @@ pub fn rgba_to_yuv_nv42_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv_bi_planar_10::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv_bi_planar_10,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -2592,5 +1813,253 @@ pub fn rgba_to_yuv_nv42_p16(
         height,
         range,
         matrix,
-    );
+    )
+}
+
+/// Fills 10-bit little endian bi-planar image with Cb before Cr from RGB(A) holding 10-bit values
+fn rgbx_to_yuv_p10<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
+    target_image: &mut YuvBiPlanarImageMut<u16>,
+    rgba: &[u16],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    target_image.check_constraints(SAMPLING.into())?;
+    let width = target_image.size.width;
+    let height = target_image.size.height;
+    let y_stride = target_image.y_stride.get();
+    let uv_stride = target_image.uv_stride.get();
+    let handler = match bytes_packing {
+        YuvBytesPacking::MostSignificantBytes => {
+            rgbx_to_yuv_bi_planar_10_impl::<
+                ORIGIN_CHANNELS,
+                { YuvNVOrder::UV as u8 },
+                SAMPLING,
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::MostSignificantBytes as u8 },
+                10,
+            >
+        }
+        YuvBytesPacking::LeastSignificantBytes => {
+            rgbx_to_yuv_bi_planar_10_impl::<
+                ORIGIN_CHANNELS,
+                { YuvNVOrder::UV as u8 },
+                SAMPLING,
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+                10,
+            >
+        }
+    };
+    handler(
+        target_image.y_plane.borrow_mut(),
+        y_stride,
+        target_image.uv_plane.borrow_mut(),
+        uv_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert RGB with 10-bit components to P010, 10-bit bi-planar YUV 4:2:0.
+///
+/// Source components are expected in `0..1024`, samples are written as little endian words
+/// with Cb before Cr in the interleaved plane.
+///
+/// # Arguments
+///
+/// * `target_image` - Target P010 image, its size defines the processed area.
+/// * `rgb16` - The input RGB image data slice.
+/// * `rgb16_stride` - The stride (bytes per row) for the RGB image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `bytes_packing` - [YuvBytesPacking::MostSignificantBytes] for real P010,
+///   [YuvBytesPacking::LeastSignificantBytes] keeps samples in the lower bits.
+///
+pub fn rgb16_to_p010(
+    target_image: &mut YuvBiPlanarImageMut<u16>,
+    rgb16: &[u16],
+    rgb16_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv_p10::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        target_image,
+        rgb16,
+        rgb16_stride,
+        range,
+        matrix,
+        bytes_packing,
+    )
+}
+
+/// Convert RGBA with 10-bit components to P010, 10-bit bi-planar YUV 4:2:0.
+///
+/// Source components are expected in `0..1024`, samples are written as little endian words
+/// with Cb before Cr in the interleaved plane.
+///
+/// # Arguments
+///
+/// * `target_image` - Target P010 image, its size defines the processed area.
+/// * `rgba16` - The input RGBA image data slice.
+/// * `rgba16_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `bytes_packing` - [YuvBytesPacking::MostSignificantBytes] for real P010,
+///   [YuvBytesPacking::LeastSignificantBytes] keeps samples in the lower bits.
+///
+pub fn rgba16_to_p010(
+    target_image: &mut YuvBiPlanarImageMut<u16>,
+    rgba16: &[u16],
+    rgba16_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv_p10::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        target_image,
+        rgba16,
+        rgba16_stride,
+        range,
+        matrix,
+        bytes_packing,
+    )
+}
+
+/// Convert RGB with 10-bit components to P210, 10-bit bi-planar YUV 4:2:2.
+///
+/// Source components are expected in `0..1024`, samples are written as little endian words
+/// with Cb before Cr in the interleaved plane.
+///
+/// # Arguments
+///
+/// * `target_image` - Target P210 image, its size defines the processed area.
+/// * `rgb16` - The input RGB image data slice.
+/// * `rgb16_stride` - The stride (bytes per row) for the RGB image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `bytes_packing` - [YuvBytesPacking::MostSignificantBytes] for real P210,
+///   [YuvBytesPacking::LeastSignificantBytes] keeps samples in the lower bits.
+///
+pub fn rgb16_to_p210(
+    target_image: &mut YuvBiPlanarImageMut<u16>,
+    rgb16: &[u16],
+    rgb16_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv_p10::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        target_image,
+        rgb16,
+        rgb16_stride,
+        range,
+        matrix,
+        bytes_packing,
+    )
+}
+
+/// Convert RGBA with 10-bit components to P210, 10-bit bi-planar YUV 4:2:2.
+///
+/// Source components are expected in `0..1024`, samples are written as little endian words
+/// with Cb before Cr in the interleaved plane.
+///
+/// # Arguments
+///
+/// * `target_image` - Target P210 image, its size defines the processed area.
+/// * `rgba16` - The input RGBA image data slice.
+/// * `rgba16_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `bytes_packing` - [YuvBytesPacking::MostSignificantBytes] for real P210,
+///   [YuvBytesPacking::LeastSignificantBytes] keeps samples in the lower bits.
+///
+pub fn rgba16_to_p210(
+    target_image: &mut YuvBiPlanarImageMut<u16>,
+    rgba16: &[u16],
+    rgba16_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv_p10::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        target_image,
+        rgba16,
+        rgba16_stride,
+        range,
+        matrix,
+        bytes_packing,
+    )
+}
+
+/// Convert RGB with 10-bit components to P410, 10-bit bi-planar YUV 4:4:4.
+///
+/// Source components are expected in `0..1024`, samples are written as little endian words
+/// with Cb before Cr in the interleaved plane.
+///
+/// # Arguments
+///
+/// * `target_image` - Target P410 image, its size defines the processed area.
+/// * `rgb16` - The input RGB image data slice.
+/// * `rgb16_stride` - The stride (bytes per row) for the RGB image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `bytes_packing` - [YuvBytesPacking::MostSignificantBytes] for real P410,
+///   [YuvBytesPacking::LeastSignificantBytes] keeps samples in the lower bits.
+///
+pub fn rgb16_to_p410(
+    target_image: &mut YuvBiPlanarImageMut<u16>,
+    rgb16: &[u16],
+    rgb16_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv_p10::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        target_image,
+        rgb16,
+        rgb16_stride,
+        range,
+        matrix,
+        bytes_packing,
+    )
+}
+
+/// Convert RGBA with 10-bit components to P410, 10-bit bi-planar YUV 4:4:4.
+///
+/// Source components are expected in `0..1024`, samples are written as little endian words
+/// with Cb before Cr in the interleaved plane.
+///
+/// # Arguments
+///
+/// * `target_image` - Target P410 image, its size defines the processed area.
+/// * `rgba16` - The input RGBA image data slice.
+/// * `rgba16_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `bytes_packing` - [YuvBytesPacking::MostSignificantBytes] for real P410,
+///   [YuvBytesPacking::LeastSignificantBytes] keeps samples in the lower bits.
+///
+pub fn rgba16_to_p410(
+    target_image: &mut YuvBiPlanarImageMut<u16>,
+    rgba16: &[u16],
+    rgba16_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv_p10::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        target_image,
+        rgba16,
+        rgba16_stride,
+        range,
+        matrix,
+        bytes_packing,
+    )
 }
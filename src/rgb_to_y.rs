@@ -27,138 +27,216 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::avx2_rgb_to_y_row;
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
-    feature = "nightly_avx512"
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
 ))]
 use crate::avx512bw::avx512_row_rgb_to_y;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_rgb_to_y_row;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "profiling")]
+use crate::profiling::{dispatch_backend, ProfileScope};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::sse_rgb_to_y;
-use crate::yuv_error::{check_rgba_destination, check_y8_channel};
+use crate::yuv_error::{check_rgba_destination, check_stride, check_y8_channel, MismatchedSize};
 use crate::yuv_support::*;
 use crate::YuvError;
 
-// Chroma subsampling always assumed as YUV 400
-fn rgbx_to_y<const ORIGIN_CHANNELS: u8>(
-    y_plane: &mut [u8],
-    y_stride: u32,
-    rgba: &[u8],
-    rgba_stride: u32,
-    width: u32,
-    height: u32,
-    range: YuvRange,
-    matrix: YuvStandardMatrix,
-) -> Result<(), YuvError> {
-    let source_channels: YuvSourceChannels = ORIGIN_CHANNELS.into();
-    let channels = source_channels.get_channels_count();
-
-    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
-    check_y8_channel(y_plane, y_stride, width, height)?;
-
-    let range = get_yuv_range(8, range);
-    let kr_kb = matrix.get_kr_kb();
-    let max_range_p8 = (1u32 << 8u32) - 1u32;
-    let transform_precise = get_forward_transform(
-        max_range_p8,
-        range.range_y,
-        range.range_uv,
-        kr_kb.kr,
-        kr_kb.kb,
-    );
-    let transform = transform_precise.to_integers(8);
-    let precision_scale = (1 << 8) as f32;
-    let bias_y = ((range.bias_y as f32 + 0.5f32) * precision_scale) as i32;
-
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_avx = std::arch::is_x86_feature_detected!("avx2");
+/// Coefficients and backend choice shared by every converted row
+struct YRowConverter<const ORIGIN_CHANNELS: u8> {
+    transform: CbCrForwardTransform<i32>,
+    range: YuvChromaRange,
+    bias_y: i32,
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    use_sse: bool,
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly_avx512"
+        not(feature = "safe-only")
     ))]
-    let mut _use_avx512 = std::arch::is_x86_feature_detected!("avx512bw");
+    use_avx: bool,
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly_avx512",
+        not(feature = "safe-only")
+    ))]
+    use_avx512: bool,
+}
 
-    let mut y_offset = 0usize;
-    let mut rgba_offset = 0usize;
+impl<const ORIGIN_CHANNELS: u8> YRowConverter<ORIGIN_CHANNELS> {
+    fn new(width: u32, height: u32, range: YuvRange, matrix: YuvStandardMatrix) -> Self {
+        let range = get_yuv_range(8, range);
+        let kr_kb = matrix.resolve(width, height).get_kr_kb();
+        let max_range_p8 = (1u32 << 8u32) - 1u32;
+        let transform_precise = get_forward_transform(
+            max_range_p8,
+            range.range_y,
+            range.range_uv,
+            kr_kb.kr,
+            kr_kb.kb,
+        );
+        let transform = transform_precise.to_integers(8);
+        let precision_scale = (1 << 8) as f32;
+        let bias_y = ((range.bias_y as f32 + 0.5f32) * precision_scale) as i32;
+        YRowConverter {
+            transform,
+            range,
+            bias_y,
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "safe-only")
+            ))]
+            use_sse: crate::cpu_features::has_sse41(),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "safe-only")
+            ))]
+            use_avx: crate::cpu_features::has_avx2(),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly_avx512",
+                not(feature = "safe-only")
+            ))]
+            use_avx512: crate::cpu_features::has_avx512bw(),
+        }
+    }
+
+    /// Converts first `width` pixels of `rgba` into first `width` samples of `y_row`
+    fn convert_row(&self, y_row: &mut [u8], rgba: &[u8], width: usize) {
+        let source_channels: YuvSourceChannels = ORIGIN_CHANNELS.into();
+        let channels = source_channels.get_channels_count();
+        let transform = &self.transform;
+        let _range = &self.range;
 
-    for _ in 0..height as usize {
         let mut _cx = 0usize;
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
             #[cfg(feature = "nightly_avx512")]
-            if _use_avx512 {
+            if self.use_avx512 {
                 let processed_offset = avx512_row_rgb_to_y::<ORIGIN_CHANNELS>(
-                    &transform,
-                    &range,
-                    y_plane.as_mut_ptr(),
+                    transform,
+                    _range,
+                    y_row.as_mut_ptr(),
                     rgba,
-                    y_offset,
-                    rgba_offset,
+                    0,
+                    0,
                     _cx,
-                    width as usize,
+                    width,
                 );
                 _cx = processed_offset;
             }
-            if _use_avx {
+            if self.use_avx {
                 let processed_offset = avx2_rgb_to_y_row::<ORIGIN_CHANNELS>(
-                    &transform,
-                    &range,
-                    y_plane.as_mut_ptr(),
+                    transform,
+                    _range,
+                    y_row.as_mut_ptr(),
                     rgba,
-                    y_offset,
-                    rgba_offset,
+                    0,
+                    0,
                     _cx,
-                    width as usize,
+                    width,
                 );
                 _cx = processed_offset;
             }
-            if _use_sse {
+            if self.use_sse {
                 let processed_offset = sse_rgb_to_y::<ORIGIN_CHANNELS>(
-                    &transform,
-                    &range,
-                    y_plane.as_mut_ptr(),
+                    transform,
+                    _range,
+                    y_row.as_mut_ptr(),
                     rgba,
-                    y_offset,
-                    rgba_offset,
+                    0,
+                    0,
                     _cx,
-                    width as usize,
+                    width,
                 );
                 _cx = processed_offset;
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             _cx = neon_rgb_to_y_row::<ORIGIN_CHANNELS>(
-                &transform,
-                &range,
-                y_plane.as_mut_ptr(),
+                transform,
+                _range,
+                y_row.as_mut_ptr(),
                 rgba,
-                y_offset,
-                rgba_offset,
+                0,
+                0,
                 _cx,
-                width as usize,
+                width,
             );
         }
 
-        for x in _cx..width as usize {
-            let px = x * channels;
-            let dst_offset = rgba_offset + px;
-            unsafe {
-                let dst = rgba.get_unchecked(dst_offset..);
-                let r = *dst.get_unchecked(source_channels.get_r_channel_offset()) as i32;
-                let g = *dst.get_unchecked(source_channels.get_g_channel_offset()) as i32;
-                let b = *dst.get_unchecked(source_channels.get_b_channel_offset()) as i32;
-                let y = (r * transform.yr + g * transform.yg + b * transform.yb + bias_y) >> 8;
-                *y_plane.get_unchecked_mut(y_offset + x) = y as u8;
-            }
+        for x in _cx..width {
+            let src = &rgba[x * channels..];
+            let r = src[source_channels.get_r_channel_offset()] as i32;
+            let g = src[source_channels.get_g_channel_offset()] as i32;
+            let b = src[source_channels.get_b_channel_offset()] as i32;
+            let y = (r * transform.yr + g * transform.yg + b * transform.yb + self.bias_y) >> 8;
+            y_row[x] = y as u8;
         }
+    }
+}
+
+// Chroma subsampling always assumed as YUV 400
+fn rgbx_to_y<const ORIGIN_CHANNELS: u8>(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    rgba: &[u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let source_channels: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let channels = source_channels.get_channels_count();
+
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+
+    #[cfg(feature = "profiling")]
+    let _profile = ProfileScope::new(
+        "rgba_to_y",
+        y_plane.len() + rgba.len(),
+        dispatch_backend(true, false),
+        0,
+    );
+
+    let converter = YRowConverter::<ORIGIN_CHANNELS>::new(width, height, range, matrix);
+
+    let mut y_offset = 0usize;
+    let mut rgba_offset = 0usize;
+
+    for _ in 0..height as usize {
+        converter.convert_row(
+            &mut y_plane[y_offset..],
+            &rgba[rgba_offset..],
+            width as usize,
+        );
 
         y_offset += y_stride as usize;
         rgba_offset += rgba_stride as usize;
@@ -167,6 +245,64 @@ fn rgbx_to_y<const ORIGIN_CHANNELS: u8>(
     Ok(())
 }
 
+/// Pixels copied out of the shared buffer at once by in-place conversion
+const IN_PLACE_BLOCK: usize = 128;
+
+fn rgbx_to_y_in_place<const ORIGIN_CHANNELS: u8>(
+    data: &mut [u8],
+    rgba_stride: u32,
+    y_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let source_channels: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let channels = source_channels.get_channels_count();
+
+    check_rgba_destination(data, rgba_stride, width, height, channels)?;
+    // Luma rows fit wherever source rows do, only stride needs a check
+    check_stride(y_stride as usize, width as usize)?;
+    if y_stride > rgba_stride {
+        return Err(YuvError::InPlaceStrideMismatch(MismatchedSize {
+            expected: rgba_stride as usize,
+            received: y_stride as usize,
+        }));
+    }
+
+    #[cfg(feature = "profiling")]
+    let _profile = ProfileScope::new(
+        "rgba_to_y_in_place",
+        data.len(),
+        dispatch_backend(true, false),
+        0,
+    );
+
+    let converter = YRowConverter::<ORIGIN_CHANNELS>::new(width, height, range, matrix);
+
+    let width = width as usize;
+    let mut block = [0u8; IN_PLACE_BLOCK * 4];
+
+    for y in 0..height as usize {
+        let src_row = y * rgba_stride as usize;
+        let dst_row = y * y_stride as usize;
+        let mut x = 0usize;
+        while x < width {
+            let count = IN_PLACE_BLOCK.min(width - x);
+            let src_start = src_row + x * channels;
+            let src_end = src_start + count * channels;
+            block[..count * channels].copy_from_slice(&data[src_start..src_end]);
+            let dst_start = dst_row + x;
+            // Luma of a block never reaches source bytes that are still to be read
+            debug_assert!(dst_start + count <= src_end);
+            converter.convert_row(&mut data[dst_start..dst_start + count], &block, count);
+            x += count;
+        }
+    }
+
+    Ok(())
+}
+
 /// Convert RGB image data to YUV 400 planar format.
 ///
 /// This function performs RGB to YUV conversion and stores the result in YUV400 planar format,
@@ -324,3 +460,132 @@ pub fn bgr_to_yuv400(
         y_plane, y_stride, rgb, rgb_stride, width, height, range, matrix,
     )
 }
+
+/// Convert RGB image data to YUV 400 in place, luma is written over the same buffer.
+///
+/// Y row `n` starts at `n * y_stride` of the very buffer holding RGB row `n` at `n * rgb_stride`,
+/// so Y rows end up packed in the prefix of the allocation and bytes past them are left
+/// unspecified. Rows are converted in order in small blocks copied out first, and because
+/// `y_stride <= rgb_stride` and every pixel shrinks to a single byte, luma never overwrites
+/// source pixels that are still to be read. Larger `y_stride` is rejected with
+/// [YuvError::InPlaceStrideMismatch]. Conversion is sequential, `rayon` is not used.
+///
+/// # Arguments
+///
+/// * `data` - Buffer holding RGB rows on input and Y (luminance) rows on output.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane, at most `rgb_stride`.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgb_to_yuv400_in_place(
+    data: &mut [u8],
+    rgb_stride: u32,
+    y_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_y_in_place::<{ YuvSourceChannels::Rgb as u8 }>(
+        data, rgb_stride, y_stride, width, height, range, matrix,
+    )
+}
+
+/// Convert RGBA image data to YUV 400 in place, luma is written over the same buffer.
+///
+/// Same aliasing rules as in [rgb_to_yuv400_in_place] apply.
+///
+/// # Arguments
+///
+/// * `data` - Buffer holding RGBA rows on input and Y (luminance) rows on output.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane, at most `rgba_stride`.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgba_to_yuv400_in_place(
+    data: &mut [u8],
+    rgba_stride: u32,
+    y_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_y_in_place::<{ YuvSourceChannels::Rgba as u8 }>(
+        data,
+        rgba_stride,
+        y_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA image data to YUV 400 in place, luma is written over the same buffer.
+///
+/// Same aliasing rules as in [rgb_to_yuv400_in_place] apply.
+///
+/// # Arguments
+///
+/// * `data` - Buffer holding BGRA rows on input and Y (luminance) rows on output.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane, at most `bgra_stride`.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgra_to_yuv400_in_place(
+    data: &mut [u8],
+    bgra_stride: u32,
+    y_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_y_in_place::<{ YuvSourceChannels::Bgra as u8 }>(
+        data,
+        bgra_stride,
+        y_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGR image data to YUV 400 in place, luma is written over the same buffer.
+///
+/// Same aliasing rules as in [rgb_to_yuv400_in_place] apply.
+///
+/// # Arguments
+///
+/// * `data` - Buffer holding BGR rows on input and Y (luminance) rows on output.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane, at most `bgr_stride`.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgr_to_yuv400_in_place(
+    data: &mut [u8],
+    bgr_stride: u32,
+    y_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_y_in_place::<{ YuvSourceChannels::Bgr as u8 }>(
+        data, bgr_stride, y_stride, width, height, range, matrix,
+    )
+}
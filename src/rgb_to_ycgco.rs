@@ -27,23 +27,36 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::avx2_rgb_to_ycgco_row;
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
-    feature = "nightly_avx512"
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
 ))]
 use crate::avx512bw::avx512_rgb_to_ycgco_row;
 #[allow(unused_imports)]
 use crate::internals::ProcessedOffset;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_rgb_to_ycgco_row;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::sse_rgb_to_ycgco_row;
+use crate::yuv_error::{check_chroma_channel, check_rgba_destination, check_y8_channel};
 #[allow(unused_imports)]
 use crate::yuv_support::*;
+use crate::YuvError;
 
-fn rgbx_to_ycgco<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
+pub(crate) fn rgbx_to_ycgco<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
     y_plane: &mut [u8],
     y_stride: u32,
     cg_plane: &mut [u8],
@@ -55,10 +68,16 @@ fn rgbx_to_ycgco<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let source_channels: YuvSourceChannels = ORIGIN_CHANNELS.into();
     let channels = source_channels.get_channels_count();
+
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(cg_plane, cg_stride, width, height, chroma_subsampling)?;
+    check_chroma_channel(co_plane, co_stride, width, height, chroma_subsampling)?;
+
     let range = get_yuv_range(8, range);
     let precision_scale = (1 << 8) as f32;
     let bias_y = ((range.bias_y as f32 + 0.5f32) * precision_scale) as i32;
@@ -81,15 +100,22 @@ fn rgbx_to_ycgco<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
     let mut co_offset = 0usize;
     let mut rgba_offset = 0usize;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_avx = std::arch::is_x86_feature_detected!("avx2");
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly_avx512"
+        not(feature = "safe-only")
+    ))]
+    let mut _use_sse = crate::cpu_features::has_sse41();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
     ))]
-    let mut _use_avx512 = std::arch::is_x86_feature_detected!("avx512bw");
+    let mut _use_avx = crate::cpu_features::has_avx2();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly_avx512",
+        not(feature = "safe-only")
+    ))]
+    let mut _use_avx512 = crate::cpu_features::has_avx512bw();
 
     for y in 0..height as usize {
         #[allow(unused_variables)]
@@ -103,7 +129,10 @@ fn rgbx_to_ycgco<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
             || chroma_subsampling == YuvChromaSample::YUV422
             || y & 1 == 0;
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
             #[cfg(feature = "nightly_avx512")]
             if _use_avx512 {
@@ -165,7 +194,11 @@ fn rgbx_to_ycgco<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             let processed_offset = neon_rgb_to_ycgco_row::<ORIGIN_CHANNELS, SAMPLING>(
                 &range,
@@ -189,10 +222,10 @@ fn rgbx_to_ycgco<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
         for x in (cx..width as usize).step_by(iterator_step) {
             let px = x * channels;
             let rgba_shift = rgba_offset + px;
-            let src0 = unsafe { rgba.get_unchecked(rgba_shift..) };
-            let r0 = unsafe { *src0.get_unchecked(source_channels.get_r_channel_offset()) } as i32;
-            let g0 = unsafe { *src0.get_unchecked(source_channels.get_g_channel_offset()) } as i32;
-            let b0 = unsafe { *src0.get_unchecked(source_channels.get_b_channel_offset()) } as i32;
+            let src0 = &rgba[rgba_shift..];
+            let r0 = src0[source_channels.get_r_channel_offset()] as i32;
+            let g0 = src0[source_channels.get_g_channel_offset()] as i32;
+            let b0 = src0[source_channels.get_b_channel_offset()] as i32;
 
             let mut r1 = r0;
             let mut g1 = g0;
@@ -200,25 +233,22 @@ fn rgbx_to_ycgco<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
 
             let hg = (g0 * range_reduction_y) >> 1;
             let y_0 = (hg + ((r0 * range_reduction_y + b0 * range_reduction_y) >> 2) + bias_y) >> 8;
-            unsafe { *y_plane.get_unchecked_mut(y_offset + x) = y_0 as u8 };
+            y_plane[y_offset + x] = y_0 as u8;
             match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => {
                     if x + 1 < width as usize {
                         let next_px = (x + 1) * channels;
                         let rgba_shift = rgba_offset + next_px;
-                        let src1 = unsafe { rgba.get_unchecked(rgba_shift..) };
-                        r1 = unsafe { *src1.get_unchecked(source_channels.get_r_channel_offset()) }
-                            as i32;
-                        g1 = unsafe { *src1.get_unchecked(source_channels.get_g_channel_offset()) }
-                            as i32;
-                        b1 = unsafe { *src1.get_unchecked(source_channels.get_b_channel_offset()) }
-                            as i32;
+                        let src1 = &rgba[rgba_shift..];
+                        r1 = src1[source_channels.get_r_channel_offset()] as i32;
+                        g1 = src1[source_channels.get_g_channel_offset()] as i32;
+                        b1 = src1[source_channels.get_b_channel_offset()] as i32;
                         let hg_1 = (g1 * range_reduction_y) >> 1;
                         let y_1 = (hg_1
                             + ((r1 * range_reduction_y + b1 * range_reduction_y) >> 2)
                             + bias_y)
                             >> 8;
-                        unsafe { *y_plane.get_unchecked_mut(y_offset + x + 1) = y_1 as u8 };
+                        y_plane[y_offset + x + 1] = y_1 as u8;
                     }
                 }
                 _ => {}
@@ -249,12 +279,12 @@ fn rgbx_to_ycgco<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
                     YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => cg_offset + ux,
                     YuvChromaSample::YUV444 => cg_offset + ux,
                 };
-                unsafe { *cg_plane.get_unchecked_mut(u_pos) = cg as u8 };
+                cg_plane[u_pos] = cg as u8;
                 let v_pos = match chroma_subsampling {
                     YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => co_offset + ux,
                     YuvChromaSample::YUV444 => co_offset + ux,
                 };
-                unsafe { *co_plane.get_unchecked_mut(v_pos) = co as u8 };
+                co_plane[v_pos] = co as u8;
             }
 
             ux += 1;
@@ -275,6 +305,8 @@ fn rgbx_to_ycgco<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
             }
         }
     }
+
+    Ok(())
 }
 
 /// Convert RGB image data to YCgCo 422 planar format.
@@ -313,11 +345,11 @@ pub fn rgb_to_ycgco422(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV422 as u8 }>(
         y_plane, y_stride, cg_plane, cg_stride, co_plane, co_stride, rgb, rgb_stride, width,
         height, range,
-    );
+    )
 }
 
 /// Convert BGR image data to YCgCo 422 planar format.
@@ -356,11 +388,11 @@ pub fn bgr_to_ycgco422(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV422 as u8 }>(
         y_plane, y_stride, cg_plane, cg_stride, co_plane, co_stride, bgr, bgr_stride, width,
         height, range,
-    );
+    )
 }
 
 /// Convert RGBA image data to YCgCo 422 planar format.
@@ -400,7 +432,7 @@ pub fn rgba_to_ycgco422(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV422 as u8 }>(
         y_plane,
         y_stride,
@@ -413,7 +445,7 @@ pub fn rgba_to_ycgco422(
         width,
         height,
         range,
-    );
+    )
 }
 
 /// Convert BGRA image data to YCgCo 422 planar format.
@@ -453,7 +485,7 @@ pub fn bgra_to_ycgco422(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV422 as u8 }>(
         y_plane,
         y_stride,
@@ -466,7 +498,7 @@ pub fn bgra_to_ycgco422(
         width,
         height,
         range,
-    );
+    )
 }
 
 /// Convert RGB image data to YCgCo 420 planar format.
@@ -506,11 +538,11 @@ pub fn rgb_to_ycgco420(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV420 as u8 }>(
         y_plane, y_stride, cg_plane, cg_stride, co_plane, co_stride, rgb, rgb_stride, width,
         height, range,
-    );
+    )
 }
 
 /// Convert BGR image data to YCgCo 420 planar format.
@@ -550,11 +582,11 @@ pub fn bgr_to_ycgco420(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV420 as u8 }>(
         y_plane, y_stride, cg_plane, cg_stride, co_plane, co_stride, bgr, bgr_stride, width,
         height, range,
-    );
+    )
 }
 
 /// Convert RGBA image data to YCgCo 420 planar format.
@@ -594,7 +626,7 @@ pub fn rgba_to_ycgco420(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV420 as u8 }>(
         y_plane,
         y_stride,
@@ -607,7 +639,7 @@ pub fn rgba_to_ycgco420(
         width,
         height,
         range,
-    );
+    )
 }
 
 /// Convert BGRA image data to YCgCo 420 planar format.
@@ -647,7 +679,7 @@ pub fn bgra_to_ycgco420(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV420 as u8 }>(
         y_plane,
         y_stride,
@@ -660,7 +692,7 @@ pub fn bgra_to_ycgco420(
         width,
         height,
         range,
-    );
+    )
 }
 
 /// Convert RGB image data to YCgCo 444 planar format.
@@ -700,11 +732,11 @@ pub fn rgb_to_ycgco444(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV444 as u8 }>(
         y_plane, y_stride, cg_plane, cg_stride, co_plane, co_stride, rgb, rgb_stride, width,
         height, range,
-    );
+    )
 }
 
 /// Convert BGR image data to YCgCo 444 planar format.
@@ -744,11 +776,11 @@ pub fn bgr_to_ycgco444(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV444 as u8 }>(
         y_plane, y_stride, cg_plane, cg_stride, co_plane, co_stride, bgr, bgr_stride, width,
         height, range,
-    );
+    )
 }
 
 /// Convert RGBA image data to YCgCo 444 planar format.
@@ -788,7 +820,7 @@ pub fn rgba_to_ycgco444(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV444 as u8 }>(
         y_plane,
         y_stride,
@@ -801,7 +833,7 @@ pub fn rgba_to_ycgco444(
         width,
         height,
         range,
-    );
+    )
 }
 
 /// Convert BGRA image data to YCgCo 444 planar format.
@@ -841,7 +873,7 @@ pub fn bgra_to_ycgco444(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV444 as u8 }>(
         y_plane,
         y_stride,
@@ -854,5 +886,5 @@ pub fn bgra_to_ycgco444(
         width,
         height,
         range,
-    );
+    )
 }
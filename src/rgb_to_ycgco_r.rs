@@ -27,12 +27,21 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_rgb_to_ycgcor_row;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::sse_rgb_to_ycgcor_row;
 use crate::ycgcor_support::YCgCoR;
+use crate::yuv_error::{check_chroma_channel, check_rgba_destination, check_y8_channel};
 use crate::yuv_support::{get_yuv_range, YuvChromaSample, YuvSourceChannels};
+use crate::YuvError;
 use crate::YuvRange;
 
 fn rgbx_to_ycgco_type_r<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const R_TYPE: usize>(
@@ -47,10 +56,16 @@ fn rgbx_to_ycgco_type_r<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const R_T
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let src_channels: YuvSourceChannels = ORIGIN_CHANNELS.into();
     let channels = src_channels.get_channels_count();
+
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(cg_plane, cg_stride, width, height, chroma_subsampling)?;
+    check_chroma_channel(co_plane, co_stride, width, height, chroma_subsampling)?;
+
     let precision_scale = (1 << 8) as f32;
     let range = get_yuv_range(8, range);
     let bias_y = ((range.bias_y as f32 + 0.5f32) * precision_scale) as i32;
@@ -68,8 +83,11 @@ fn rgbx_to_ycgco_type_r<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const R_T
     let range_reduction_uv =
         (range.range_uv as f32 / max_colors as f32 * precision_scale).round() as i32;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_sse = crate::cpu_features::has_sse41();
 
     let mut y_offset = 0usize;
     let mut cg_offset = 0usize;
@@ -80,18 +98,21 @@ fn rgbx_to_ycgco_type_r<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const R_T
         let mut _cx = 0usize;
         let mut _ux = 0usize;
 
-        let y_ptr = unsafe { (y_plane.as_ptr() as *const u8).add(y_offset) as *mut u16 };
-        let cg_ptr = unsafe { (cg_plane.as_ptr() as *const u8).add(cg_offset) as *mut u16 };
-        let co_ptr = unsafe { (co_plane.as_ptr() as *const u8).add(co_offset) as *mut u16 };
+        let y_dst = &mut y_plane[y_offset / 2..];
+        let cg_dst = &mut cg_plane[cg_offset / 2..];
+        let co_dst = &mut co_plane[co_offset / 2..];
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
             if _use_sse {
                 let processed = sse_rgb_to_ycgcor_row::<ORIGIN_CHANNELS, SAMPLING>(
                     &range,
-                    y_ptr,
-                    cg_ptr,
-                    co_ptr,
+                    y_dst.as_mut_ptr(),
+                    cg_dst.as_mut_ptr(),
+                    co_dst.as_mut_ptr(),
                     rgba,
                     rgba_offset,
                     _cx,
@@ -103,13 +124,17 @@ fn rgbx_to_ycgco_type_r<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const R_T
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             let processed = neon_rgb_to_ycgcor_row::<ORIGIN_CHANNELS, SAMPLING>(
                 &range,
-                y_ptr,
-                cg_ptr,
-                co_ptr,
+                y_dst.as_mut_ptr(),
+                cg_dst.as_mut_ptr(),
+                co_dst.as_mut_ptr(),
                 rgba,
                 rgba_offset,
                 _cx,
@@ -123,46 +148,35 @@ fn rgbx_to_ycgco_type_r<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const R_T
         for x in (_cx..width as usize).step_by(iterator_step) {
             let px = x * channels;
             let rgba_shift = rgba_offset + px;
-            let src = unsafe { rgba.get_unchecked(rgba_shift..) };
-            let r = unsafe { *src.get_unchecked(src_channels.get_r_channel_offset()) } as i32;
-            let g = unsafe { *src.get_unchecked(src_channels.get_g_channel_offset()) } as i32;
-            let b = unsafe { *src.get_unchecked(src_channels.get_b_channel_offset()) } as i32;
+            let src = &rgba[rgba_shift..];
+            let r = src[src_channels.get_r_channel_offset()] as i32;
+            let g = src[src_channels.get_g_channel_offset()] as i32;
+            let b = src[src_channels.get_b_channel_offset()] as i32;
             let co = r - b;
             let t = b + (co >> 1);
             let cg = g - t;
             let y_0 = ((t + (cg >> 1)) * range_reduction_y + bias_y) >> 8;
-            unsafe {
-                y_ptr.add(x).write_unaligned(y_0 as u16);
-            }
+            y_dst[x] = y_0 as u16;
             let u_pos = _ux;
             let corrected_cg = (cg * range_reduction_uv + bias_uv) >> 8;
-            unsafe {
-                cg_ptr.add(u_pos).write_unaligned(corrected_cg as u16);
-            };
+            cg_dst[u_pos] = corrected_cg as u16;
             let v_pos = _ux;
             let corrected_co = (co * range_reduction_uv + bias_uv) >> 8;
-            unsafe {
-                co_ptr.add(v_pos).write_unaligned(corrected_co as u16);
-            };
+            co_dst[v_pos] = corrected_co as u16;
             match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => {
                     if x + 1 < width as usize {
                         let next_px = (x + 1) * channels;
                         let rgba_shift = rgba_offset + next_px;
-                        let src = unsafe { rgba.get_unchecked(rgba_shift..) };
-                        let r = unsafe { *src.get_unchecked(src_channels.get_r_channel_offset()) }
-                            as i32;
-                        let g = unsafe { *src.get_unchecked(src_channels.get_g_channel_offset()) }
-                            as i32;
-                        let b = unsafe { *src.get_unchecked(src_channels.get_b_channel_offset()) }
-                            as i32;
+                        let src = &rgba[rgba_shift..];
+                        let r = src[src_channels.get_r_channel_offset()] as i32;
+                        let g = src[src_channels.get_g_channel_offset()] as i32;
+                        let b = src[src_channels.get_b_channel_offset()] as i32;
                         let co = r - b;
                         let t = b + (co >> 1);
                         let cg = g - t;
                         let y_1 = ((t + (cg >> 1)) * range_reduction_y + bias_y) >> 8;
-                        unsafe {
-                            y_ptr.add(x + 1).write_unaligned(y_1 as u16);
-                        }
+                        y_dst[x + 1] = y_1 as u16;
                     }
                 }
                 _ => {}
@@ -186,6 +200,8 @@ fn rgbx_to_ycgco_type_r<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const R_T
             }
         }
     }
+
+    Ok(())
 }
 
 /// Convert RGB image data to YCgCo 422 planar format.
@@ -224,7 +240,7 @@ pub fn rgb_to_ycgcoro422(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco_type_r::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -232,7 +248,7 @@ pub fn rgb_to_ycgcoro422(
     >(
         y_plane, y_stride, cg_plane, cg_stride, co_plane, co_stride, rgb, rgb_stride, width,
         height, range,
-    );
+    )
 }
 
 /// Convert BGR image data to YCgCo 422 planar format.
@@ -271,7 +287,7 @@ pub fn bgr_to_ycgcoro422(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco_type_r::<
         { YuvSourceChannels::Bgr as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -279,7 +295,7 @@ pub fn bgr_to_ycgcoro422(
     >(
         y_plane, y_stride, cg_plane, cg_stride, co_plane, co_stride, bgr, bgr_stride, width,
         height, range,
-    );
+    )
 }
 
 /// Convert RGBA image data to YCgCo-Ro 422 planar format.
@@ -319,7 +335,7 @@ pub fn rgba_to_ycgcoro422(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco_type_r::<
         { YuvSourceChannels::Rgba as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -336,7 +352,7 @@ pub fn rgba_to_ycgcoro422(
         width,
         height,
         range,
-    );
+    )
 }
 
 /// Convert BGRA image data to YCgCo-Ro 422 planar format.
@@ -376,7 +392,7 @@ pub fn bgra_to_ycgcoro422(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco_type_r::<
         { YuvSourceChannels::Bgra as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -393,7 +409,7 @@ pub fn bgra_to_ycgcoro422(
         width,
         height,
         range,
-    );
+    )
 }
 
 /// Convert RGB image data to YCgCo-Ro 420 planar format.
@@ -433,7 +449,7 @@ pub fn rgb_to_ycgcoro420(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco_type_r::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -441,7 +457,7 @@ pub fn rgb_to_ycgcoro420(
     >(
         y_plane, y_stride, cg_plane, cg_stride, co_plane, co_stride, rgb, rgb_stride, width,
         height, range,
-    );
+    )
 }
 
 /// Convert BGR image data to YCgCo-Ro 420 planar format.
@@ -481,7 +497,7 @@ pub fn bgr_to_ycgcoro420(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco_type_r::<
         { YuvSourceChannels::Bgr as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -489,7 +505,7 @@ pub fn bgr_to_ycgcoro420(
     >(
         y_plane, y_stride, cg_plane, cg_stride, co_plane, co_stride, bgr, bgr_stride, width,
         height, range,
-    );
+    )
 }
 
 /// Convert RGBA image data to YCgCo-Ro 420 planar format.
@@ -529,7 +545,7 @@ pub fn rgba_to_ycgcoro420(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco_type_r::<
         { YuvSourceChannels::Rgba as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -546,7 +562,7 @@ pub fn rgba_to_ycgcoro420(
         width,
         height,
         range,
-    );
+    )
 }
 
 /// Convert BGRA image data to YCgCo-Ro 420 planar format.
@@ -586,7 +602,7 @@ pub fn bgra_to_ycgcoro420(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco_type_r::<
         { YuvSourceChannels::Bgra as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -603,7 +619,7 @@ pub fn bgra_to_ycgcoro420(
         width,
         height,
         range,
-    );
+    )
 }
 
 /// Convert RGB image data to YCgCo-Ro 444 planar format.
@@ -643,7 +659,7 @@ pub fn rgb_to_ycgcoro444(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco_type_r::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -651,7 +667,7 @@ pub fn rgb_to_ycgcoro444(
     >(
         y_plane, y_stride, cg_plane, cg_stride, co_plane, co_stride, rgb, rgb_stride, width,
         height, range,
-    );
+    )
 }
 
 /// Convert BGR image data to YCgCo-Ro 444 planar format.
@@ -691,7 +707,7 @@ pub fn bgr_to_ycgcoro444(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco_type_r::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -699,7 +715,7 @@ pub fn bgr_to_ycgcoro444(
     >(
         y_plane, y_stride, cg_plane, cg_stride, co_plane, co_stride, bgr, bgr_stride, width,
         height, range,
-    );
+    )
 }
 
 /// Convert RGBA image data to YCgCo-Ro 444 planar format.
@@ -739,7 +755,7 @@ pub fn rgba_to_ycgcoro444(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco_type_r::<
         { YuvSourceChannels::Rgba as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -756,7 +772,7 @@ pub fn rgba_to_ycgcoro444(
         width,
         height,
         range,
-    );
+    )
 }
 
 /// Convert BGRA image data to YCgCo-Ro 444 planar format.
@@ -796,7 +812,7 @@ pub fn bgra_to_ycgcoro444(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_ycgco_type_r::<
         { YuvSourceChannels::Bgra as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -813,5 +829,5 @@ pub fn bgra_to_ycgcoro444(
         width,
         height,
         range,
-    );
+    )
 }
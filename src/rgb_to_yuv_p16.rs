@@ -26,13 +26,25 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_rgba_to_yuv_p16;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::sse_rgba_to_yuv_p16;
+use crate::yuv_error::{
+    check_chroma_channel, check_p16_bit_depth, check_rgba_destination, check_y8_channel,
+};
+use crate::yuv_support::p16_dispatch;
 use crate::yuv_support::{
     get_forward_transform, get_yuv_range, ToIntegerTransform, YuvChromaSample, YuvSourceChannels,
 };
+use crate::YuvError;
 use crate::{YuvBytesPacking, YuvEndianness, YuvRange, YuvStandardMatrix};
 
 #[inline(always)]
@@ -72,13 +84,17 @@ fn rgbx_to_yuv_impl<
     range: YuvRange,
     matrix: YuvStandardMatrix,
     bit_depth: u32,
-) {
+) -> Result<(), YuvError> {
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
     let channels = src_chans.get_channels_count();
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
 
     let range = get_yuv_range(bit_depth, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let max_range_p8 = (1u32 << bit_depth) - 1u32;
     let transform_precise = get_forward_transform(
         max_range_p8,
@@ -99,43 +115,44 @@ fn rgbx_to_yuv_impl<
         YuvChromaSample::YUV444 => 1usize,
     };
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_sse = crate::cpu_features::has_sse41();
 
     let mut y_offset = 0usize;
     let mut u_offset = 0usize;
     let mut v_offset = 0usize;
     let mut rgba_offset = 0usize;
 
-    let y_dst_ptr = y_plane.as_mut_ptr() as *mut u8;
-    let u_dst_ptr = u_plane.as_mut_ptr() as *mut u8;
-    let v_dst_ptr = v_plane.as_mut_ptr() as *mut u8;
-    let rgb_src_ptr = rgba.as_ptr() as *const u8;
-
     for y in 0..height as usize {
         let mut _cx = 0usize;
         let mut _ux = 0usize;
 
-        let y_st_ptr = unsafe { y_dst_ptr.add(y_offset) as *mut u16 };
-        let u_st_ptr = unsafe { u_dst_ptr.add(u_offset) as *mut u16 };
-        let v_st_ptr = unsafe { v_dst_ptr.add(v_offset) as *mut u16 };
-        let rgb_ld_ptr = unsafe { rgb_src_ptr.add(rgba_offset) as *const u16 };
+        let y_dst = &mut y_plane[y_offset / 2..];
+        let u_dst = &mut u_plane[u_offset / 2..];
+        let v_dst = &mut v_plane[v_offset / 2..];
+        let rgba_src = &rgba[rgba_offset / 2..];
 
         let compute_uv_row = chroma_subsampling == YuvChromaSample::YUV444
             || chroma_subsampling == YuvChromaSample::YUV422
             || y & 1 == 0;
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
             if _use_sse {
                 let offset =
                     sse_rgba_to_yuv_p16::<ORIGIN_CHANNELS, SAMPLING, ENDIANNESS, BYTES_POSITION>(
                         &transform,
                         &range,
-                        y_st_ptr,
-                        u_st_ptr,
-                        v_st_ptr,
-                        rgb_ld_ptr,
+                        y_dst.as_mut_ptr(),
+                        u_dst.as_mut_ptr(),
+                        v_dst.as_mut_ptr(),
+                        rgba_src.as_ptr(),
                         _cx,
                         _ux,
                         width as usize,
@@ -147,16 +164,20 @@ fn rgbx_to_yuv_impl<
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             let offset =
                 neon_rgba_to_yuv_p16::<ORIGIN_CHANNELS, SAMPLING, ENDIANNESS, BYTES_POSITION>(
                     &transform,
                     &range,
-                    y_st_ptr,
-                    u_st_ptr,
-                    v_st_ptr,
-                    rgb_ld_ptr,
+                    y_dst.as_mut_ptr(),
+                    u_dst.as_mut_ptr(),
+                    v_dst.as_mut_ptr(),
+                    rgba_src.as_ptr(),
                     _cx,
                     _ux,
                     width as usize,
@@ -169,10 +190,10 @@ fn rgbx_to_yuv_impl<
 
         for x in (_cx..width as usize).step_by(iterator_step) {
             let px = x * channels;
-            let src = unsafe { rgb_ld_ptr.add(px) };
-            let r0 = unsafe { src.add(src_chans.get_r_channel_offset()).read_unaligned() } as i32;
-            let g0 = unsafe { src.add(src_chans.get_g_channel_offset()).read_unaligned() } as i32;
-            let b0 = unsafe { src.add(src_chans.get_b_channel_offset()).read_unaligned() } as i32;
+            let src = &rgba_src[px..];
+            let r0 = src[src_chans.get_r_channel_offset()] as i32;
+            let g0 = src[src_chans.get_g_channel_offset()] as i32;
+            let b0 = src[src_chans.get_b_channel_offset()] as i32;
 
             let mut r1 = r0;
             let mut g1 = g0;
@@ -180,36 +201,21 @@ fn rgbx_to_yuv_impl<
 
             let y_0 =
                 (r0 * transform.yr + g0 * transform.yg + b0 * transform.yb + bias_y) >> PRECISION;
-            unsafe {
-                y_st_ptr
-                    .add(x)
-                    .write_unaligned(transform_integer::<ENDIANNESS, BYTES_POSITION>(
-                        y_0, bit_depth,
-                    ));
-            }
+            y_dst[x] = transform_integer::<ENDIANNESS, BYTES_POSITION>(y_0, bit_depth);
 
             match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => {
                     if x + 1 < width as usize {
                         let next_px = (x + 1) * channels;
-                        let src = unsafe { rgb_ld_ptr.add(next_px) };
-                        r1 = unsafe { src.add(src_chans.get_r_channel_offset()).read_unaligned() }
-                            as i32;
-                        g1 = unsafe { src.add(src_chans.get_g_channel_offset()).read_unaligned() }
-                            as i32;
-                        b1 = unsafe { src.add(src_chans.get_b_channel_offset()).read_unaligned() }
-                            as i32;
+                        let src = &rgba_src[next_px..];
+                        r1 = src[src_chans.get_r_channel_offset()] as i32;
+                        g1 = src[src_chans.get_g_channel_offset()] as i32;
+                        b1 = src[src_chans.get_b_channel_offset()] as i32;
                         let y_1 =
                             (r1 * transform.yr + g1 * transform.yg + b1 * transform.yb + bias_y)
                                 >> PRECISION;
-                        unsafe {
-                            y_st_ptr.add(x + 1).write_unaligned(transform_integer::<
-                                ENDIANNESS,
-                                BYTES_POSITION,
-                            >(
-                                y_1, bit_depth
-                            ));
-                        }
+                        y_dst[x + 1] =
+                            transform_integer::<ENDIANNESS, BYTES_POSITION>(y_1, bit_depth);
                     }
                 }
                 _ => {}
@@ -239,24 +245,12 @@ fn rgbx_to_yuv_impl<
                     YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => _ux,
                     YuvChromaSample::YUV444 => _ux,
                 };
-                unsafe {
-                    u_st_ptr
-                        .add(u_pos)
-                        .write_unaligned(transform_integer::<ENDIANNESS, BYTES_POSITION>(
-                            cb, bit_depth,
-                        ));
-                }
+                u_dst[u_pos] = transform_integer::<ENDIANNESS, BYTES_POSITION>(cb, bit_depth);
                 let v_pos = match chroma_subsampling {
                     YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => _ux,
                     YuvChromaSample::YUV444 => _ux,
                 };
-                unsafe {
-                    v_st_ptr
-                        .add(v_pos)
-                        .write_unaligned(transform_integer::<ENDIANNESS, BYTES_POSITION>(
-                            cr, bit_depth,
-                        ));
-                }
+                v_dst[v_pos] = transform_integer::<ENDIANNESS, BYTES_POSITION>(cr, bit_depth);
             }
 
             _ux += 1;
@@ -277,6 +271,8 @@ fn rgbx_to_yuv_impl<
             }
         }
     }
+
+    Ok(())
 }
 
 fn rgbx_to_yuv<
@@ -298,7 +294,7 @@ fn rgbx_to_yuv<
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_yuv_impl::<ORIGIN_CHANNELS, SAMPLING, ENDIANNESS, BYTES_POSITION>(
         y_plane,
         y_stride,
@@ -313,7 +309,7 @@ fn rgbx_to_yuv<
         range,
         matrix,
         bit_depth,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV 422 planar format with 10 or 12 bit depth.
@@ -360,52 +356,22 @@ pub fn rgb_to_yuv422_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv,
+        [{ YuvSourceChannels::Rgb as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, bit_depth, width,
         height, range, matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV 422 planar format with 10 or 12 bit depth.
@@ -452,52 +418,22 @@ pub fn bgr_to_yuv422_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv,
+        [{ YuvSourceChannels::Bgr as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, bit_depth, width,
         height, range, matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV 422 planar format with 10 or 12 bit depth.
@@ -544,48 +480,18 @@ pub fn rgba_to_yuv422_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -600,7 +506,7 @@ pub fn rgba_to_yuv422_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV 422 planar format with 10 or 12 bit depth.
@@ -647,48 +553,18 @@ pub fn bgra_to_yuv422_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -703,7 +579,7 @@ pub fn bgra_to_yuv422_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV 420 planar format with 10 or 12 bit depth.
@@ -750,52 +626,22 @@ pub fn rgb_to_yuv420_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv,
+        [{ YuvSourceChannels::Rgb as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, bit_depth, width,
         height, range, matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV 420 planar format with 10 or 12 bit depth.
@@ -842,52 +688,22 @@ pub fn bgr_to_yuv420_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv,
+        [{ YuvSourceChannels::Bgr as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, bit_depth, width,
         height, range, matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV 420 planar format with 10 or 12 bit depth.
@@ -934,48 +750,18 @@ pub fn rgba_to_yuv420_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -990,7 +776,7 @@ pub fn rgba_to_yuv420_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV 420 planar format with 10 or 12 bit depth.
@@ -1037,48 +823,18 @@ pub fn bgra_to_yuv420_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1093,7 +849,7 @@ pub fn bgra_to_yuv420_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV 444 planar format with 10 or 12 bit depth.
@@ -1140,52 +896,22 @@ pub fn rgb_to_yuv444_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv,
+        [{ YuvSourceChannels::Rgb as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, bit_depth, width,
         height, range, matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV 444 planar format with 10 or 12 bit depth.
@@ -1232,52 +958,22 @@ pub fn bgr_to_yuv444_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv,
+        [{ YuvSourceChannels::Bgr as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, bit_depth, width,
         height, range, matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV 444 planar format with 10 or 12 bit depth.
@@ -1324,48 +1020,18 @@ pub fn rgba_to_yuv444_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1380,7 +1046,7 @@ pub fn rgba_to_yuv444_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV 444 planar format with 10 or 12 bit depth.
@@ -1427,48 +1093,18 @@ pub fn bgra_to_yuv444_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
+) -> Result<(), YuvError> {
     if bit_depth != 10 && bit_depth != 12 {
         panic!("Only 10 and 12 bit depth is supported");
     }
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                rgbx_to_yuv::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+    let dispatcher = p16_dispatch!(
+        rgbx_to_yuv,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1483,5 +1119,669 @@ pub fn bgra_to_yuv444_p16(
         height,
         range,
         matrix,
+    )
+}
+
+/// RGB(A) with components of `bit_depth` bits to planar YUV of the same depth, any depth from 8 to 16.
+///
+/// Depths up to 12 bits take the regular path, wider samples overflow 16-bit SIMD lanes
+/// and are converted by a scalar loop instead.
+fn rgbx16_to_yuv_p16<
+    const ORIGIN_CHANNELS: u8,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+>(
+    y_plane: &mut [u16],
+    y_stride: u32,
+    u_plane: &mut [u16],
+    u_stride: u32,
+    v_plane: &mut [u16],
+    v_stride: u32,
+    rgba: &[u16],
+    rgba_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    check_p16_bit_depth(bit_depth)?;
+    if bit_depth <= 12 {
+        return rgbx_to_yuv_impl::<ORIGIN_CHANNELS, SAMPLING, ENDIANNESS, BYTES_POSITION>(
+            y_plane,
+            y_stride,
+            u_plane,
+            u_stride,
+            v_plane,
+            v_stride,
+            rgba,
+            rgba_stride,
+            width,
+            height,
+            range,
+            matrix,
+            bit_depth,
+        );
+    }
+
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let channels = src_chans.get_channels_count();
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+
+    let range = get_yuv_range(bit_depth, range);
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
+    let max_colors = (1u32 << bit_depth) - 1u32;
+    let transform = get_forward_transform(
+        max_colors,
+        range.range_y,
+        range.range_uv,
+        kr_kb.kr,
+        kr_kb.kb,
+    )
+    .to_integers(PRECISION as u32);
+    const PRECISION: i32 = 8;
+    const ROUNDING_CONST_BIAS: i32 = 1 << (PRECISION - 1);
+    let bias_y = range.bias_y as i32 * (1 << PRECISION) + ROUNDING_CONST_BIAS;
+    let bias_uv = range.bias_uv as i32 * (1 << PRECISION) + ROUNDING_CONST_BIAS;
+    let max_colors = max_colors as i32;
+
+    let chroma_width = match chroma_subsampling {
+        YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => (width as usize).div_ceil(2),
+        YuvChromaSample::YUV444 => width as usize,
+    };
+
+    for y in 0..height as usize {
+        let rgba_src = &rgba[y * rgba_stride as usize / 2..];
+        let y_dst = &mut y_plane[y * y_stride as usize / 2..];
+        let (chroma_y, compute_uv_row) = match chroma_subsampling {
+            YuvChromaSample::YUV420 => (y >> 1, y & 1 == 0),
+            YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => (y, true),
+        };
+
+        for (x, dst) in y_dst.iter_mut().take(width as usize).enumerate() {
+            let src = &rgba_src[x * channels..];
+            let r = src[src_chans.get_r_channel_offset()] as i32;
+            let g = src[src_chans.get_g_channel_offset()] as i32;
+            let b = src[src_chans.get_b_channel_offset()] as i32;
+            let y_0 =
+                (r * transform.yr + g * transform.yg + b * transform.yb + bias_y) >> PRECISION;
+            *dst = transform_integer::<ENDIANNESS, BYTES_POSITION>(
+                y_0.clamp(0, max_colors),
+                bit_depth,
+            );
+        }
+
+        if !compute_uv_row {
+            continue;
+        }
+
+        let u_dst = &mut u_plane[chroma_y * u_stride as usize / 2..];
+        let v_dst = &mut v_plane[chroma_y * v_stride as usize / 2..];
+
+        for (ux, (u_dst, v_dst)) in u_dst
+            .iter_mut()
+            .zip(v_dst.iter_mut())
+            .take(chroma_width)
+            .enumerate()
+        {
+            let (r, g, b) = match chroma_subsampling {
+                YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => {
+                    let x0 = ux * 2;
+                    // Last pixel of odd width is paired with itself, see [YuvChromaSample]
+                    let x1 = (x0 + 1).min(width as usize - 1);
+                    let src0 = &rgba_src[x0 * channels..];
+                    let src1 = &rgba_src[x1 * channels..];
+                    (
+                        (src0[src_chans.get_r_channel_offset()] as i32
+                            + src1[src_chans.get_r_channel_offset()] as i32
+                            + 1)
+                            >> 1,
+                        (src0[src_chans.get_g_channel_offset()] as i32
+                            + src1[src_chans.get_g_channel_offset()] as i32
+                            + 1)
+                            >> 1,
+                        (src0[src_chans.get_b_channel_offset()] as i32
+                            + src1[src_chans.get_b_channel_offset()] as i32
+                            + 1)
+                            >> 1,
+                    )
+                }
+                YuvChromaSample::YUV444 => {
+                    let src = &rgba_src[ux * channels..];
+                    (
+                        src[src_chans.get_r_channel_offset()] as i32,
+                        src[src_chans.get_g_channel_offset()] as i32,
+                        src[src_chans.get_b_channel_offset()] as i32,
+                    )
+                }
+            };
+            let cb = (r * transform.cb_r + g * transform.cb_g + b * transform.cb_b + bias_uv)
+                >> PRECISION;
+            let cr = (r * transform.cr_r + g * transform.cr_g + b * transform.cr_b + bias_uv)
+                >> PRECISION;
+            *u_dst =
+                transform_integer::<ENDIANNESS, BYTES_POSITION>(cb.clamp(0, max_colors), bit_depth);
+            *v_dst =
+                transform_integer::<ENDIANNESS, BYTES_POSITION>(cr.clamp(0, max_colors), bit_depth);
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert RGB image data with 8+ bit depth to YUV 422 planar format of the same bit depth.
+///
+/// Counterpart of [crate::yuv422_p16_to_rgb16], components and samples share `bit_depth`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgb16` - The input RGB image data slice.
+/// * `rgb16_stride` - The stride (bytes per row) for the RGB image data.
+/// * `bit_depth` - Bit depth of source components and YUV samples, from 8 to 16.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of final YUV
+/// * `bytes_packing` - position of significant bytes for YUV ( most significant or least significant )
+///
+pub fn rgb16_to_yuv422_p16(
+    y_plane: &mut [u16],
+    y_stride: u32,
+    u_plane: &mut [u16],
+    u_stride: u32,
+    v_plane: &mut [u16],
+    v_stride: u32,
+    rgb16: &[u16],
+    rgb16_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        rgbx16_to_yuv_p16,
+        [{ YuvSourceChannels::Rgb as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgb16,
+        rgb16_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGR image data with 8+ bit depth to YUV 422 planar format of the same bit depth.
+///
+/// Counterpart of [crate::yuv422_p16_to_bgr16], components and samples share `bit_depth`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgr16` - The input BGR image data slice.
+/// * `bgr16_stride` - The stride (bytes per row) for the BGR image data.
+/// * `bit_depth` - Bit depth of source components and YUV samples, from 8 to 16.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of final YUV
+/// * `bytes_packing` - position of significant bytes for YUV ( most significant or least significant )
+///
+pub fn bgr16_to_yuv422_p16(
+    y_plane: &mut [u16],
+    y_stride: u32,
+    u_plane: &mut [u16],
+    u_stride: u32,
+    v_plane: &mut [u16],
+    v_stride: u32,
+    bgr16: &[u16],
+    bgr16_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        rgbx16_to_yuv_p16,
+        [{ YuvSourceChannels::Bgr as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgr16,
+        bgr16_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert RGBA image data with 8+ bit depth to YUV 422 planar format of the same bit depth.
+///
+/// Counterpart of [crate::yuv422_p16_to_rgba16], components and samples share `bit_depth`. Alpha is ignored.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba16` - The input RGBA image data slice.
+/// * `rgba16_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `bit_depth` - Bit depth of source components and YUV samples, from 8 to 16.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of final YUV
+/// * `bytes_packing` - position of significant bytes for YUV ( most significant or least significant )
+///
+pub fn rgba16_to_yuv422_p16(
+    y_plane: &mut [u16],
+    y_stride: u32,
+    u_plane: &mut [u16],
+    u_stride: u32,
+    v_plane: &mut [u16],
+    v_stride: u32,
+    rgba16: &[u16],
+    rgba16_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        rgbx16_to_yuv_p16,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba16,
+        rgba16_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA image data with 8+ bit depth to YUV 422 planar format of the same bit depth.
+///
+/// Counterpart of [crate::yuv422_p16_to_bgra16], components and samples share `bit_depth`. Alpha is ignored.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra16` - The input BGRA image data slice.
+/// * `bgra16_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `bit_depth` - Bit depth of source components and YUV samples, from 8 to 16.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of final YUV
+/// * `bytes_packing` - position of significant bytes for YUV ( most significant or least significant )
+///
+pub fn bgra16_to_yuv422_p16(
+    y_plane: &mut [u16],
+    y_stride: u32,
+    u_plane: &mut [u16],
+    u_stride: u32,
+    v_plane: &mut [u16],
+    v_stride: u32,
+    bgra16: &[u16],
+    bgra16_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        rgbx16_to_yuv_p16,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra16,
+        bgra16_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert RGB image data with 8+ bit depth to YUV 444 planar format of the same bit depth.
+///
+/// Counterpart of [crate::yuv444_p16_to_rgb16], components and samples share `bit_depth`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgb16` - The input RGB image data slice.
+/// * `rgb16_stride` - The stride (bytes per row) for the RGB image data.
+/// * `bit_depth` - Bit depth of source components and YUV samples, from 8 to 16.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of final YUV
+/// * `bytes_packing` - position of significant bytes for YUV ( most significant or least significant )
+///
+pub fn rgb16_to_yuv444_p16(
+    y_plane: &mut [u16],
+    y_stride: u32,
+    u_plane: &mut [u16],
+    u_stride: u32,
+    v_plane: &mut [u16],
+    v_stride: u32,
+    rgb16: &[u16],
+    rgb16_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        rgbx16_to_yuv_p16,
+        [{ YuvSourceChannels::Rgb as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        },],
+        endianness,
+        bytes_packing
     );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgb16,
+        rgb16_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGR image data with 8+ bit depth to YUV 444 planar format of the same bit depth.
+///
+/// Counterpart of [crate::yuv444_p16_to_bgr16], components and samples share `bit_depth`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgr16` - The input BGR image data slice.
+/// * `bgr16_stride` - The stride (bytes per row) for the BGR image data.
+/// * `bit_depth` - Bit depth of source components and YUV samples, from 8 to 16.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of final YUV
+/// * `bytes_packing` - position of significant bytes for YUV ( most significant or least significant )
+///
+pub fn bgr16_to_yuv444_p16(
+    y_plane: &mut [u16],
+    y_stride: u32,
+    u_plane: &mut [u16],
+    u_stride: u32,
+    v_plane: &mut [u16],
+    v_stride: u32,
+    bgr16: &[u16],
+    bgr16_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        rgbx16_to_yuv_p16,
+        [{ YuvSourceChannels::Bgr as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgr16,
+        bgr16_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert RGBA image data with 8+ bit depth to YUV 444 planar format of the same bit depth.
+///
+/// Counterpart of [crate::yuv444_p16_to_rgba16], components and samples share `bit_depth`. Alpha is ignored.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba16` - The input RGBA image data slice.
+/// * `rgba16_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `bit_depth` - Bit depth of source components and YUV samples, from 8 to 16.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of final YUV
+/// * `bytes_packing` - position of significant bytes for YUV ( most significant or least significant )
+///
+pub fn rgba16_to_yuv444_p16(
+    y_plane: &mut [u16],
+    y_stride: u32,
+    u_plane: &mut [u16],
+    u_stride: u32,
+    v_plane: &mut [u16],
+    v_stride: u32,
+    rgba16: &[u16],
+    rgba16_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        rgbx16_to_yuv_p16,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba16,
+        rgba16_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA image data with 8+ bit depth to YUV 444 planar format of the same bit depth.
+///
+/// Counterpart of [crate::yuv444_p16_to_bgra16], components and samples share `bit_depth`. Alpha is ignored.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra16` - The input BGRA image data slice.
+/// * `bgra16_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `bit_depth` - Bit depth of source components and YUV samples, from 8 to 16.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of final YUV
+/// * `bytes_packing` - position of significant bytes for YUV ( most significant or least significant )
+///
+pub fn bgra16_to_yuv444_p16(
+    y_plane: &mut [u16],
+    y_stride: u32,
+    u_plane: &mut [u16],
+    u_stride: u32,
+    v_plane: &mut [u16],
+    v_stride: u32,
+    bgra16: &[u16],
+    bgra16_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        rgbx16_to_yuv_p16,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra16,
+        bgra16_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
 }
@@ -0,0 +1,454 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_vec, YuvPlanarImage, YuvPlanarImageMut};
+use crate::rgb_to_yuv_p16::{rgb_to_yuv420_p16, rgb_to_yuv422_p16, rgb_to_yuv444_p16};
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_planar_generic::yuv_planar_to_rgb_generic;
+use crate::yuv_support::{
+    YuvBytesPacking, YuvChromaSample, YuvEndianness, YuvOvershoot, YuvRange, YuvStandardMatrix,
+};
+use crate::YuvError;
+
+/// Rows gathered into a native RGB buffer at once, kept even so 4:2:0 chroma pairs never split
+const RGBA64_BATCH_ROWS: usize = 16;
+
+#[inline(always)]
+fn load_rgba64<const RGBA64_ENDIANNESS: u8>(v: u16) -> u16 {
+    let endianness: YuvEndianness = RGBA64_ENDIANNESS.into();
+    match endianness {
+        YuvEndianness::BigEndian => u16::from_be(v),
+        YuvEndianness::LittleEndian => u16::from_le(v),
+    }
+}
+
+#[inline(always)]
+fn store_rgba64<const RGBA64_ENDIANNESS: u8>(v: u16) -> u16 {
+    let endianness: YuvEndianness = RGBA64_ENDIANNESS.into();
+    match endianness {
+        YuvEndianness::BigEndian => v.to_be(),
+        YuvEndianness::LittleEndian => v.to_le(),
+    }
+}
+
+type RgbToYuvP16 = fn(
+    &mut [u16],
+    u32,
+    &mut [u16],
+    u32,
+    &mut [u16],
+    u32,
+    &[u16],
+    u32,
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+    YuvEndianness,
+    YuvBytesPacking,
+) -> Result<(), YuvError>;
+
+/// Gathers small batches of RGBA64 rows into native RGB rows reduced to `bit_depth`
+/// and passes them to regular p16 forward converters
+fn rgba64_to_yuv_p16_impl<const RGBA64_ENDIANNESS: u8>(
+    rgba64: &[u16],
+    rgba64_stride: u32,
+    planar_image: &mut YuvPlanarImageMut<u16>,
+    sampling: YuvChromaSample,
+    bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    if bit_depth != 10 && bit_depth != 12 {
+        return Err(YuvError::UnsupportedBitDepth(bit_depth));
+    }
+    planar_image.check_constraints(sampling)?;
+    let width = planar_image.size.width;
+    let height = planar_image.size.height;
+    check_rgba_destination(rgba64, rgba64_stride, width, height, 4)?;
+
+    let encoder: RgbToYuvP16 = match sampling {
+        YuvChromaSample::YUV420 => rgb_to_yuv420_p16,
+        YuvChromaSample::YUV422 => rgb_to_yuv422_p16,
+        YuvChromaSample::YUV444 => rgb_to_yuv444_p16,
+    };
+
+    let y_stride = planar_image.y_stride.get();
+    let u_stride = planar_image.u_stride.get();
+    let v_stride = planar_image.v_stride.get();
+    let y_plane = planar_image.y_plane.borrow_mut();
+    let u_plane = planar_image.u_plane.borrow_mut();
+    let v_plane = planar_image.v_plane.borrow_mut();
+
+    let shift = 16 - bit_depth;
+    let rounding = 1u32 << (shift - 1);
+    let max_value = (1u32 << bit_depth) - 1;
+
+    let packed_stride = width as usize * 3;
+    let mut packed = try_vec(0u16, packed_stride * RGBA64_BATCH_ROWS.min(height as usize))?;
+
+    let matrix = matrix.resolve(width, height);
+    for start_row in (0..height as usize).step_by(RGBA64_BATCH_ROWS) {
+        let rows = RGBA64_BATCH_ROWS.min(height as usize - start_row);
+        let (chroma_start, chroma_end) = match sampling {
+            YuvChromaSample::YUV420 => (start_row / 2, (start_row + rows).div_ceil(2)),
+            YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => (start_row, start_row + rows),
+        };
+
+        for (y, dst_row) in packed.chunks_mut(packed_stride).take(rows).enumerate() {
+            let src_row = &rgba64[(start_row + y) * rgba64_stride as usize / 2..];
+            for (dst, src) in dst_row.chunks_exact_mut(3).zip(src_row.chunks_exact(4)) {
+                for (dst, &src) in dst.iter_mut().zip(src.iter()) {
+                    let v = load_rgba64::<RGBA64_ENDIANNESS>(src) as u32;
+                    *dst = ((v + rounding) >> shift).min(max_value) as u16;
+                }
+            }
+        }
+
+        let y_end = ((start_row + rows) * y_stride as usize / 2).min(y_plane.len());
+        let u_end = (chroma_end * u_stride as usize / 2).min(u_plane.len());
+        let v_end = (chroma_end * v_stride as usize / 2).min(v_plane.len());
+        encoder(
+            &mut y_plane[start_row * y_stride as usize / 2..y_end],
+            y_stride,
+            &mut u_plane[chroma_start * u_stride as usize / 2..u_end],
+            u_stride,
+            &mut v_plane[chroma_start * v_stride as usize / 2..v_end],
+            v_stride,
+            &packed[..packed_stride * rows],
+            packed_stride as u32 * 2,
+            bit_depth,
+            width,
+            rows as u32,
+            range,
+            matrix,
+            endianness,
+            bytes_packing,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Convert little endian RGBA64 (16 bits per channel) image data to YUV planar format
+/// with 10 or 12 bit depth.
+///
+/// Byte order conversion and reduction to `bit_depth` are done on the fly on small batches
+/// of rows. Alpha is ignored.
+///
+/// # Arguments
+///
+/// * `rgba64` - The input RGBA64 image data slice, every channel is little endian.
+/// * `rgba64_stride` - The stride (bytes per row) for the RGBA64 image data.
+/// * `planar_image` - Target planar image.
+/// * `sampling` - Chroma subsampling of the target image.
+/// * `bit_depth` - Only 10 or 12 bit-depth is supported.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of final YUV.
+/// * `bytes_packing` - Position of significant bytes for YUV (most significant or least significant).
+///
+pub fn rgba64_le_to_yuv_p16(
+    rgba64: &[u16],
+    rgba64_stride: u32,
+    planar_image: &mut YuvPlanarImageMut<u16>,
+    sampling: YuvChromaSample,
+    bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    rgba64_to_yuv_p16_impl::<{ YuvEndianness::LittleEndian as u8 }>(
+        rgba64,
+        rgba64_stride,
+        planar_image,
+        sampling,
+        bit_depth,
+        range,
+        matrix,
+        endianness,
+        bytes_packing,
+    )
+}
+
+/// Convert big endian RGBA64 (16 bits per channel) image data to YUV planar format
+/// with 10 or 12 bit depth.
+///
+/// Byte order conversion and reduction to `bit_depth` are done on the fly on small batches
+/// of rows, so PNG16 scanlines can be passed as is. Alpha is ignored.
+///
+/// # Arguments
+///
+/// * `rgba64` - The input RGBA64 image data slice, every channel is big endian.
+/// * `rgba64_stride` - The stride (bytes per row) for the RGBA64 image data.
+/// * `planar_image` - Target planar image.
+/// * `sampling` - Chroma subsampling of the target image.
+/// * `bit_depth` - Only 10 or 12 bit-depth is supported.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of final YUV.
+/// * `bytes_packing` - Position of significant bytes for YUV (most significant or least significant).
+///
+pub fn rgba64_be_to_yuv_p16(
+    rgba64: &[u16],
+    rgba64_stride: u32,
+    planar_image: &mut YuvPlanarImageMut<u16>,
+    sampling: YuvChromaSample,
+    bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    rgba64_to_yuv_p16_impl::<{ YuvEndianness::BigEndian as u8 }>(
+        rgba64,
+        rgba64_stride,
+        planar_image,
+        sampling,
+        bit_depth,
+        range,
+        matrix,
+        endianness,
+        bytes_packing,
+    )
+}
+
+fn yuv_p16_to_rgba64_impl<
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+    const RGBA64_ENDIANNESS: u8,
+>(
+    planar_image: &YuvPlanarImage<u16>,
+    bit_depth: usize,
+    rgba64: &mut [u16],
+    rgba64_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) {
+    yuv_planar_to_rgb_generic::<u16, u16, SAMPLING, ENDIANNESS, BYTES_POSITION>(
+        planar_image.y_plane,
+        planar_image.y_stride.get(),
+        planar_image.u_plane,
+        planar_image.u_stride.get(),
+        planar_image.v_plane,
+        planar_image.v_stride.get(),
+        rgba64,
+        rgba64_stride,
+        planar_image.size.width,
+        planar_image.size.height,
+        range,
+        matrix,
+        bit_depth,
+        16,
+        YuvOvershoot::Clamp,
+        |dst, x, r, g, b| {
+            let dst = &mut dst[x * 4..x * 4 + 4];
+            dst[0] = store_rgba64::<RGBA64_ENDIANNESS>(r as u16);
+            dst[1] = store_rgba64::<RGBA64_ENDIANNESS>(g as u16);
+            dst[2] = store_rgba64::<RGBA64_ENDIANNESS>(b as u16);
+            dst[3] = u16::MAX;
+        },
+    );
+}
+
+type YuvP16ToRgba64 = fn(&YuvPlanarImage<u16>, usize, &mut [u16], u32, YuvRange, YuvStandardMatrix);
+
+fn yuv_p16_to_rgba64_dispatcher<const SAMPLING: u8, const RGBA64_ENDIANNESS: u8>(
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> YuvP16ToRgba64 {
+    match (endianness, bytes_packing) {
+        (YuvEndianness::BigEndian, YuvBytesPacking::MostSignificantBytes) => {
+            yuv_p16_to_rgba64_impl::<
+                SAMPLING,
+                { YuvEndianness::BigEndian as u8 },
+                { YuvBytesPacking::MostSignificantBytes as u8 },
+                RGBA64_ENDIANNESS,
+            >
+        }
+        (YuvEndianness::BigEndian, YuvBytesPacking::LeastSignificantBytes) => {
+            yuv_p16_to_rgba64_impl::<
+                SAMPLING,
+                { YuvEndianness::BigEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+                RGBA64_ENDIANNESS,
+            >
+        }
+        (YuvEndianness::LittleEndian, YuvBytesPacking::MostSignificantBytes) => {
+            yuv_p16_to_rgba64_impl::<
+                SAMPLING,
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::MostSignificantBytes as u8 },
+                RGBA64_ENDIANNESS,
+            >
+        }
+        (YuvEndianness::LittleEndian, YuvBytesPacking::LeastSignificantBytes) => {
+            yuv_p16_to_rgba64_impl::<
+                SAMPLING,
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+                RGBA64_ENDIANNESS,
+            >
+        }
+    }
+}
+
+fn yuv_p16_to_rgba64_checked<const RGBA64_ENDIANNESS: u8>(
+    planar_image: &YuvPlanarImage<u16>,
+    sampling: YuvChromaSample,
+    bit_depth: u32,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+    rgba64: &mut [u16],
+    rgba64_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    if !(8..=16).contains(&bit_depth) {
+        return Err(YuvError::UnsupportedBitDepth(bit_depth));
+    }
+    planar_image.check_constraints(sampling)?;
+    check_rgba_destination(
+        rgba64,
+        rgba64_stride,
+        planar_image.size.width,
+        planar_image.size.height,
+        4,
+    )?;
+
+    let dispatcher = match sampling {
+        YuvChromaSample::YUV420 => yuv_p16_to_rgba64_dispatcher::<
+            { YuvChromaSample::YUV420 as u8 },
+            RGBA64_ENDIANNESS,
+        >(endianness, bytes_packing),
+        YuvChromaSample::YUV422 => yuv_p16_to_rgba64_dispatcher::<
+            { YuvChromaSample::YUV422 as u8 },
+            RGBA64_ENDIANNESS,
+        >(endianness, bytes_packing),
+        YuvChromaSample::YUV444 => yuv_p16_to_rgba64_dispatcher::<
+            { YuvChromaSample::YUV444 as u8 },
+            RGBA64_ENDIANNESS,
+        >(endianness, bytes_packing),
+    };
+    dispatcher(
+        planar_image,
+        bit_depth as usize,
+        rgba64,
+        rgba64_stride,
+        range,
+        matrix,
+    );
+    Ok(())
+}
+
+/// Convert YUV planar format with 8+ bit depth to little endian RGBA64 (16 bits per channel).
+///
+/// Values are expanded straight to 16 bits and stored little endian in the same pass,
+/// alpha is always opaque.
+///
+/// # Arguments
+///
+/// * `planar_image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `bit_depth` - Bit depth of source YUV planes, 8 to 16.
+/// * `endianness` - The endianness of stored YUV bytes.
+/// * `bytes_packing` - Position of significant bytes for YUV (most significant or least significant).
+/// * `rgba64` - A mutable slice to store the converted RGBA64 data.
+/// * `rgba64_stride` - The stride (bytes per row) for the RGBA64 image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_p16_to_rgba64_le(
+    planar_image: &YuvPlanarImage<u16>,
+    sampling: YuvChromaSample,
+    bit_depth: u32,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+    rgba64: &mut [u16],
+    rgba64_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_p16_to_rgba64_checked::<{ YuvEndianness::LittleEndian as u8 }>(
+        planar_image,
+        sampling,
+        bit_depth,
+        endianness,
+        bytes_packing,
+        rgba64,
+        rgba64_stride,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV planar format with 8+ bit depth to big endian RGBA64 (16 bits per channel).
+///
+/// Values are expanded straight to 16 bits and stored big endian in the same pass,
+/// so the output may be written as PNG16 scanlines as is. Alpha is always opaque.
+///
+/// # Arguments
+///
+/// * `planar_image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `bit_depth` - Bit depth of source YUV planes, 8 to 16.
+/// * `endianness` - The endianness of stored YUV bytes.
+/// * `bytes_packing` - Position of significant bytes for YUV (most significant or least significant).
+/// * `rgba64` - A mutable slice to store the converted RGBA64 data.
+/// * `rgba64_stride` - The stride (bytes per row) for the RGBA64 image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_p16_to_rgba64_be(
+    planar_image: &YuvPlanarImage<u16>,
+    sampling: YuvChromaSample,
+    bit_depth: u32,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+    rgba64: &mut [u16],
+    rgba64_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_p16_to_rgba64_checked::<{ YuvEndianness::BigEndian as u8 }>(
+        planar_image,
+        sampling,
+        bit_depth,
+        endianness,
+        bytes_packing,
+        rgba64,
+        rgba64_stride,
+        range,
+        matrix,
+    )
+}
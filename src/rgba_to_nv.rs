@@ -26,15 +26,31 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::avx2_rgba_to_nv;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_rgbx_to_nv_row;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "profiling")]
+use crate::profiling::{dispatch_backend, ProfileScope};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::sse_rgba_to_nv_row;
+use crate::yuv_error::{
+    check_interleaved_chroma_channel, check_rgba_destination, check_y8_channel,
+};
 use crate::yuv_support::*;
+use crate::YuvError;
 
-fn rgbx_to_nv<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8, const SAMPLING: u8>(
+pub(crate) fn rgbx_to_nv<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8, const SAMPLING: u8>(
     y_plane: &mut [u8],
     y_stride: u32,
     uv_plane: &mut [u8],
@@ -45,13 +61,25 @@ fn rgbx_to_nv<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8, const SAMPLING: u8>
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     let order: YuvNVOrder = UV_ORDER.into();
+
+    #[cfg(feature = "profiling")]
+    let _profile = ProfileScope::new(
+        "rgba_to_nv",
+        y_plane.len() + uv_plane.len() + rgba.len(),
+        dispatch_backend(false, false),
+        0,
+    );
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let source_channels: YuvSourceChannels = ORIGIN_CHANNELS.into();
     let channels = source_channels.get_channels_count();
+
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_interleaved_chroma_channel(uv_plane, uv_stride, width, height, chroma_subsampling)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
     let range = get_yuv_range(8, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let max_range_p8 = (1u32 << 8u32) - 1;
     let transform_precise = get_forward_transform(
         max_range_p8,
@@ -78,12 +106,19 @@ fn rgbx_to_nv<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8, const SAMPLING: u8>
 
     let i_bias_y = range.bias_y as i32;
     let i_cap_y = range.range_y as i32 + i_bias_y;
-    let i_cap_uv = i_bias_y + range.range_uv as i32;
+    let i_min_uv = range.min_uv() as i32;
+    let i_cap_uv = range.max_uv() as i32;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let _use_avx2 = std::arch::is_x86_feature_detected!("avx2");
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_avx2 = crate::cpu_features::has_avx2();
 
     for y in 0..height as usize {
         #[allow(unused_variables)]
@@ -95,7 +130,10 @@ fn rgbx_to_nv<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8, const SAMPLING: u8>
             || chroma_subsampling == YuvChromaSample::YUV422
             || y & 1 == 0;
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
             if _use_avx2 {
                 let offset = avx2_rgba_to_nv::<ORIGIN_CHANNELS, UV_ORDER, SAMPLING>(
@@ -135,7 +173,11 @@ fn rgbx_to_nv<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8, const SAMPLING: u8>
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             let offset = neon_rgbx_to_nv_row::<ORIGIN_CHANNELS, UV_ORDER, SAMPLING>(
                 y_plane,
@@ -158,13 +200,10 @@ fn rgbx_to_nv<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8, const SAMPLING: u8>
         for x in (cx..width as usize).step_by(iterator_step) {
             let px = x * channels;
             let rgba_shift = rgba_offset + px;
-            let source_slice = unsafe { rgba.get_unchecked(rgba_shift..) };
-            let r0 = unsafe { *source_slice.get_unchecked(source_channels.get_r_channel_offset()) }
-                as i32;
-            let g0 = unsafe { *source_slice.get_unchecked(source_channels.get_g_channel_offset()) }
-                as i32;
-            let b0 = unsafe { *source_slice.get_unchecked(source_channels.get_b_channel_offset()) }
-                as i32;
+            let source_slice = &rgba[rgba_shift..];
+            let r0 = source_slice[source_channels.get_r_channel_offset()] as i32;
+            let g0 = source_slice[source_channels.get_g_channel_offset()] as i32;
+            let b0 = source_slice[source_channels.get_b_channel_offset()] as i32;
 
             let mut r1 = r0;
             let mut g1 = g0;
@@ -176,23 +215,14 @@ fn rgbx_to_nv<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8, const SAMPLING: u8>
                     if next_x < width as usize {
                         let next_px = next_x * channels;
                         let rgba_shift = rgba_offset + next_px;
-                        let source_slice = unsafe { rgba.get_unchecked(rgba_shift..) };
-                        r1 = unsafe {
-                            *source_slice.get_unchecked(source_channels.get_r_channel_offset())
-                        } as i32;
-                        g1 = unsafe {
-                            *source_slice.get_unchecked(source_channels.get_g_channel_offset())
-                        } as i32;
-                        b1 = unsafe {
-                            *source_slice.get_unchecked(source_channels.get_b_channel_offset())
-                        } as i32;
+                        let source_slice = &rgba[rgba_shift..];
+                        r1 = { source_slice[source_channels.get_r_channel_offset()] } as i32;
+                        g1 = { source_slice[source_channels.get_g_channel_offset()] } as i32;
+                        b1 = { source_slice[source_channels.get_b_channel_offset()] } as i32;
                         let y_1 =
                             (r1 * transform.yr + g1 * transform.yg + b1 * transform.yb + bias_y)
                                 >> PRECISION;
-                        unsafe {
-                            *y_plane.get_unchecked_mut(y_offset + next_x) =
-                                y_1.clamp(i_bias_y, i_cap_y) as u8;
-                        }
+                        y_plane[y_offset + next_x] = y_1.clamp(i_bias_y, i_cap_y) as u8;
                     }
                 }
                 _ => {}
@@ -220,16 +250,10 @@ fn rgbx_to_nv<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8, const SAMPLING: u8>
                     >> PRECISION;
                 let cr = (r * transform.cr_r + g * transform.cr_g + b * transform.cr_b + bias_uv)
                     >> PRECISION;
-                unsafe {
-                    *y_plane.get_unchecked_mut(y_offset + x) = y_0.clamp(i_bias_y, i_cap_y) as u8;
-                }
+                y_plane[y_offset + x] = y_0.clamp(i_bias_y, i_cap_y) as u8;
                 let uv_pos = uv_offset + ux;
-                unsafe {
-                    *uv_plane.get_unchecked_mut(uv_pos + order.get_u_position()) =
-                        cb.clamp(i_bias_y, i_cap_uv) as u8;
-                    *uv_plane.get_unchecked_mut(uv_pos + order.get_v_position()) =
-                        cr.clamp(i_bias_y, i_cap_uv) as u8;
-                }
+                uv_plane[uv_pos + order.get_u_position()] = cb.clamp(i_min_uv, i_cap_uv) as u8;
+                uv_plane[uv_pos + order.get_v_position()] = cr.clamp(i_min_uv, i_cap_uv) as u8;
             }
 
             ux += 2;
@@ -248,6 +272,8 @@ fn rgbx_to_nv<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8, const SAMPLING: u8>
             }
         }
     }
+
+    Ok(())
 }
 
 /// Convert RGB image data to YUV NV16 bi-planar format.
@@ -284,14 +310,14 @@ pub fn rgb_to_yuv_nv16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvNVOrder::UV as u8 },
         { YuvChromaSample::YUV422 as u8 },
     >(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV NV61 bi-planar format.
@@ -328,14 +354,14 @@ pub fn rgb_to_yuv_nv61(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvNVOrder::VU as u8 },
         { YuvChromaSample::YUV422 as u8 },
     >(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV NV16 bi-planar format.
@@ -372,14 +398,14 @@ pub fn bgr_to_yuv_nv16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Bgr as u8 },
         { YuvNVOrder::UV as u8 },
         { YuvChromaSample::YUV422 as u8 },
     >(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV NV61 bi-planar format.
@@ -416,14 +442,14 @@ pub fn bgr_to_yuv_nv61(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Bgr as u8 },
         { YuvNVOrder::VU as u8 },
         { YuvChromaSample::YUV422 as u8 },
     >(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV NV16 bi-planar format.
@@ -460,7 +486,7 @@ pub fn rgba_to_yuv_nv16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Rgba as u8 },
         { YuvNVOrder::UV as u8 },
@@ -476,7 +502,7 @@ pub fn rgba_to_yuv_nv16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV NV61 bi-planar format.
@@ -513,7 +539,7 @@ pub fn rgba_to_yuv_nv61(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Rgba as u8 },
         { YuvNVOrder::VU as u8 },
@@ -529,7 +555,7 @@ pub fn rgba_to_yuv_nv61(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV NV16 bi-planar format.
@@ -566,7 +592,7 @@ pub fn bgra_to_yuv_nv16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Bgra as u8 },
         { YuvNVOrder::UV as u8 },
@@ -582,7 +608,7 @@ pub fn bgra_to_yuv_nv16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV NV61 bi-planar format.
@@ -619,7 +645,7 @@ pub fn bgra_to_yuv_nv61(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Bgra as u8 },
         { YuvNVOrder::VU as u8 },
@@ -635,7 +661,7 @@ pub fn bgra_to_yuv_nv61(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV NV12 bi-planar format.
@@ -672,14 +698,14 @@ pub fn rgb_to_yuv_nv12(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvNVOrder::UV as u8 },
         { YuvChromaSample::YUV420 as u8 },
     >(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV NV21 bi-planar format.
@@ -716,14 +742,14 @@ pub fn rgb_to_yuv_nv21(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvNVOrder::VU as u8 },
         { YuvChromaSample::YUV420 as u8 },
     >(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV NV12 bi-planar format.
@@ -760,14 +786,14 @@ pub fn bgr_to_yuv_nv12(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Bgr as u8 },
         { YuvNVOrder::UV as u8 },
         { YuvChromaSample::YUV420 as u8 },
     >(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV NV21 bi-planar format.
@@ -804,14 +830,14 @@ pub fn bgr_to_yuv_nv21(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Bgr as u8 },
         { YuvNVOrder::VU as u8 },
         { YuvChromaSample::YUV420 as u8 },
     >(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV NV12 bi-planar format.
@@ -848,7 +874,7 @@ pub fn rgba_to_yuv_nv12(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Rgba as u8 },
         { YuvNVOrder::UV as u8 },
@@ -864,7 +890,7 @@ pub fn rgba_to_yuv_nv12(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV NV21 bi-planar format.
@@ -901,7 +927,7 @@ pub fn rgba_to_yuv_nv21(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Rgba as u8 },
         { YuvNVOrder::VU as u8 },
@@ -917,7 +943,7 @@ pub fn rgba_to_yuv_nv21(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV NV12 bi-planar format.
@@ -954,7 +980,7 @@ pub fn bgra_to_yuv_nv12(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Bgra as u8 },
         { YuvNVOrder::UV as u8 },
@@ -970,7 +996,7 @@ pub fn bgra_to_yuv_nv12(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV NV21 bi-planar format.
@@ -1007,7 +1033,7 @@ pub fn bgra_to_yuv_nv21(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Bgra as u8 },
         { YuvNVOrder::VU as u8 },
@@ -1023,7 +1049,7 @@ pub fn bgra_to_yuv_nv21(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV NV24 bi-planar format.
@@ -1060,14 +1086,14 @@ pub fn rgb_to_yuv_nv24(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvNVOrder::UV as u8 },
         { YuvChromaSample::YUV444 as u8 },
     >(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert RGB image data to YUV NV42 bi-planar format.
@@ -1104,14 +1130,14 @@ pub fn rgb_to_yuv_nv42(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvNVOrder::VU as u8 },
         { YuvChromaSample::YUV444 as u8 },
     >(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV NV24 bi-planar format.
@@ -1148,14 +1174,14 @@ pub fn bgr_to_yuv_nv24(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Bgr as u8 },
         { YuvNVOrder::UV as u8 },
         { YuvChromaSample::YUV444 as u8 },
     >(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert BGR image data to YUV NV42 bi-planar format.
@@ -1192,14 +1218,14 @@ pub fn bgr_to_yuv_nv42(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Bgr as u8 },
         { YuvNVOrder::VU as u8 },
         { YuvChromaSample::YUV444 as u8 },
     >(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV NV24 bi-planar format.
@@ -1236,7 +1262,7 @@ pub fn rgba_to_yuv_nv24(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Rgba as u8 },
         { YuvNVOrder::UV as u8 },
@@ -1252,7 +1278,7 @@ pub fn rgba_to_yuv_nv24(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert RGBA image data to YUV NV42 bi-planar format.
@@ -1289,7 +1315,7 @@ pub fn rgba_to_yuv_nv42(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Rgba as u8 },
         { YuvNVOrder::VU as u8 },
@@ -1305,7 +1331,7 @@ pub fn rgba_to_yuv_nv42(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV NV24 bi-planar format.
@@ -1342,7 +1368,7 @@ pub fn bgra_to_yuv_nv24(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Bgra as u8 },
         { YuvNVOrder::UV as u8 },
@@ -1358,7 +1384,7 @@ pub fn bgra_to_yuv_nv24(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert BGRA image data to YUV NV42 bi-planar format.
@@ -1395,7 +1421,7 @@ pub fn bgra_to_yuv_nv42(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     rgbx_to_nv::<
         { YuvSourceChannels::Bgra as u8 },
         { YuvNVOrder::VU as u8 },
@@ -1411,5 +1437,5 @@ pub fn bgra_to_yuv_nv42(
         height,
         range,
         matrix,
-    );
+    )
 }
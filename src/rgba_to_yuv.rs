@@ -27,90 +27,155 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::avx2_rgba_to_yuv;
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
-    feature = "nightly_avx512"
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
 ))]
 use crate::avx512bw::avx512_rgba_to_yuv;
 #[allow(unused_imports)]
+use crate::images::try_vec;
+#[allow(unused_imports)]
 use crate::internals::*;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_rgba_to_yuv;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "profiling")]
+use crate::profiling::{dispatch_backend, ProfileScope};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::sse_rgba_to_yuv_row;
-use crate::yuv_error::{check_chroma_channel, check_rgba_destination, check_y8_channel};
+use crate::yuv_error::{
+    check_chroma_channel, check_rgba_destination, check_rgba_pixel_stride, check_y8_channel,
+    MismatchedSize,
+};
 #[allow(unused_imports)]
 use crate::yuv_support::*;
-use crate::YuvError;
-
-fn rgbx_to_yuv8<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
-    y_plane: &mut [u8],
-    y_stride: u32,
-    u_plane: &mut [u8],
-    u_stride: u32,
-    v_plane: &mut [u8],
-    v_stride: u32,
-    rgba: &[u8],
-    rgba_stride: u32,
-    width: u32,
-    height: u32,
-    range: YuvRange,
-    matrix: YuvStandardMatrix,
-) -> Result<(), YuvError> {
-    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
-    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
-    let channels = src_chans.get_channels_count();
+use crate::{YuvError, YuvPlanarImageMut};
 
-    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
-    check_y8_channel(y_plane, y_stride, width, height)?;
-    check_chroma_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
-    check_chroma_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+/// Per row RGB(A) to 8-bit YUV encoder, rows are converted one by one so whole frames
+/// and externally managed bands produce the same output.
+struct RgbToYuv8Encoder {
+    transform: CbCrForwardTransform<i32>,
+    #[cfg_attr(feature = "safe-only", allow(dead_code))]
+    range: YuvChromaRange,
+    bias_y: i32,
+    bias_uv: i32,
+    i_bias_y: i32,
+    i_cap_y: i32,
+    i_min_uv: i32,
+    i_cap_uv: i32,
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    use_sse: bool,
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    use_avx: bool,
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly_avx512",
+        not(feature = "safe-only")
+    ))]
+    use_avx512: bool,
+}
 
-    let range = get_yuv_range(8, range);
-    let kr_kb = matrix.get_kr_kb();
+impl RgbToYuv8Encoder {
     const PRECISION: i32 = 8;
-    let max_range_p8 = (1u32 << 8u32) - 1u32;
-    let transform_precise = get_forward_transform(
-        max_range_p8,
-        range.range_y,
-        range.range_uv,
-        kr_kb.kr,
-        kr_kb.kb,
-    );
-    let transform = transform_precise.to_integers(PRECISION as u32);
 
-    const ROUNDING_CONST_BIAS: i32 = 1 << (PRECISION - 1);
-    let bias_y = range.bias_y as i32 * (1 << PRECISION) + ROUNDING_CONST_BIAS;
-    let bias_uv = range.bias_uv as i32 * (1 << PRECISION) + ROUNDING_CONST_BIAS;
+    fn new(range: YuvRange, matrix: YuvStandardMatrix) -> RgbToYuv8Encoder {
+        let range = get_yuv_range(8, range);
+        let kr_kb = matrix.get_kr_kb();
+        let max_range_p8 = (1u32 << 8u32) - 1u32;
+        let transform_precise = get_forward_transform(
+            max_range_p8,
+            range.range_y,
+            range.range_uv,
+            kr_kb.kr,
+            kr_kb.kb,
+        );
+        let transform = transform_precise.to_integers(Self::PRECISION as u32);
 
-    let iterator_step = match chroma_subsampling {
-        YuvChromaSample::YUV420 => 2usize,
-        YuvChromaSample::YUV422 => 2usize,
-        YuvChromaSample::YUV444 => 1usize,
-    };
+        const ROUNDING_CONST_BIAS: i32 = 1 << (RgbToYuv8Encoder::PRECISION - 1);
+        let bias_y = range.bias_y as i32 * (1 << Self::PRECISION) + ROUNDING_CONST_BIAS;
+        let bias_uv = range.bias_uv as i32 * (1 << Self::PRECISION) + ROUNDING_CONST_BIAS;
 
-    let mut y_offset = 0usize;
-    let mut u_offset = 0usize;
-    let mut v_offset = 0usize;
-    let mut rgba_offset = 0usize;
+        let i_bias_y = range.bias_y as i32;
+        let i_cap_y = range.range_y as i32 + i_bias_y;
+        let i_min_uv = range.min_uv() as i32;
+        let i_cap_uv = range.max_uv() as i32;
 
-    let i_bias_y = range.bias_y as i32;
-    let i_cap_y = range.range_y as i32 + i_bias_y;
-    let i_cap_uv = i_bias_y + range.range_uv as i32;
+        RgbToYuv8Encoder {
+            transform,
+            range,
+            bias_y,
+            bias_uv,
+            i_bias_y,
+            i_cap_y,
+            i_min_uv,
+            i_cap_uv,
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "safe-only")
+            ))]
+            use_sse: crate::cpu_features::has_sse41(),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "safe-only")
+            ))]
+            use_avx: crate::cpu_features::has_avx2(),
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly_avx512",
+                not(feature = "safe-only")
+            ))]
+            use_avx512: crate::cpu_features::has_avx512bw(),
+        }
+    }
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_avx = std::arch::is_x86_feature_detected!("avx2");
-    #[cfg(all(
-        any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly_avx512"
-    ))]
-    let mut _use_avx512 = std::arch::is_x86_feature_detected!("avx512bw");
+    /// Encodes a single row, chroma is written only when `compute_uv_row` is set.
+    ///
+    /// Rows must hold at least `width` pixels, chroma rows at least the chroma width.
+    fn encode_row<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
+        &self,
+        y_row: &mut [u8],
+        u_row: &mut [u8],
+        v_row: &mut [u8],
+        rgba_row: &[u8],
+        width: usize,
+        compute_uv_row: bool,
+    ) {
+        let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+        let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+        let channels = src_chans.get_channels_count();
+        let transform = &self.transform;
+        let bias_y = self.bias_y;
+        let bias_uv = self.bias_uv;
+        let i_bias_y = self.i_bias_y;
+        let i_cap_y = self.i_cap_y;
+        let i_min_uv = self.i_min_uv;
+        let i_cap_uv = self.i_cap_uv;
+        const PRECISION: i32 = RgbToYuv8Encoder::PRECISION;
+
+        let iterator_step = match chroma_subsampling {
+            YuvChromaSample::YUV420 => 2usize,
+            YuvChromaSample::YUV422 => 2usize,
+            YuvChromaSample::YUV444 => 1usize,
+        };
 
-    for y in 0..height as usize {
         #[allow(unused_variables)]
         #[allow(unused_mut)]
         let mut cx = 0usize;
@@ -118,26 +183,25 @@ fn rgbx_to_yuv8<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
         #[allow(unused_mut)]
         let mut ux = 0usize;
 
-        let compute_uv_row = chroma_subsampling == YuvChromaSample::YUV444
-            || chroma_subsampling == YuvChromaSample::YUV422
-            || y & 1 == 0;
-
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
             #[cfg(feature = "nightly_avx512")]
             {
-                if _use_avx512 {
+                if self.use_avx512 {
                     let processed_offset = avx512_rgba_to_yuv::<ORIGIN_CHANNELS, SAMPLING>(
-                        &transform,
-                        &range,
-                        y_plane.as_mut_ptr().add(y_offset),
-                        u_plane.as_mut_ptr().add(u_offset),
-                        v_plane.as_mut_ptr().add(v_offset),
-                        rgba,
-                        rgba_offset,
+                        transform,
+                        &self.range,
+                        y_row.as_mut_ptr(),
+                        u_row.as_mut_ptr(),
+                        v_row.as_mut_ptr(),
+                        rgba_row,
+                        0,
                         cx,
                         ux,
-                        width as usize,
+                        width,
                         compute_uv_row,
                     );
                     cx = processed_offset.cx;
@@ -145,36 +209,36 @@ fn rgbx_to_yuv8<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
                 }
             }
 
-            if _use_avx {
+            if self.use_avx {
                 let processed_offset = avx2_rgba_to_yuv::<ORIGIN_CHANNELS, SAMPLING>(
-                    &transform,
-                    &range,
-                    y_plane.as_mut_ptr().add(y_offset),
-                    u_plane.as_mut_ptr().add(u_offset),
-                    v_plane.as_mut_ptr().add(v_offset),
-                    rgba,
-                    rgba_offset,
+                    transform,
+                    &self.range,
+                    y_row.as_mut_ptr(),
+                    u_row.as_mut_ptr(),
+                    v_row.as_mut_ptr(),
+                    rgba_row,
+                    0,
                     cx,
                     ux,
-                    width as usize,
+                    width,
                     compute_uv_row,
                 );
                 cx = processed_offset.cx;
                 ux = processed_offset.ux;
             }
 
-            if _use_sse {
+            if self.use_sse {
                 let processed_offset = sse_rgba_to_yuv_row::<ORIGIN_CHANNELS, SAMPLING>(
-                    &transform,
-                    &range,
-                    y_plane.as_mut_ptr().add(y_offset),
-                    u_plane.as_mut_ptr().add(u_offset),
-                    v_plane.as_mut_ptr().add(v_offset),
-                    rgba,
-                    rgba_offset,
+                    transform,
+                    &self.range,
+                    y_row.as_mut_ptr(),
+                    u_row.as_mut_ptr(),
+                    v_row.as_mut_ptr(),
+                    rgba_row,
+                    0,
                     cx,
                     ux,
-                    width as usize,
+                    width,
                     compute_uv_row,
                 );
                 cx = processed_offset.cx;
@@ -182,56 +246,52 @@ fn rgbx_to_yuv8<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             let offset = neon_rgba_to_yuv::<ORIGIN_CHANNELS, SAMPLING, PRECISION>(
-                &transform,
-                &range,
-                y_plane.as_mut_ptr().add(y_offset),
-                u_plane.as_mut_ptr().add(u_offset),
-                v_plane.as_mut_ptr().add(v_offset),
-                rgba,
-                rgba_offset,
+                transform,
+                &self.range,
+                y_row.as_mut_ptr(),
+                u_row.as_mut_ptr(),
+                v_row.as_mut_ptr(),
+                rgba_row,
+                0,
                 cx,
                 ux,
-                width as usize,
+                width,
                 compute_uv_row,
             );
             cx = offset.cx;
             ux = offset.ux;
         }
 
-        for x in (cx..width as usize).step_by(iterator_step) {
-            let px = x * channels;
-            let rgba_shift = rgba_offset + px;
-            let src = unsafe { rgba.get_unchecked(rgba_shift..) };
-            let r0 = unsafe { *src.get_unchecked(src_chans.get_r_channel_offset()) } as i32;
-            let g0 = unsafe { *src.get_unchecked(src_chans.get_g_channel_offset()) } as i32;
-            let b0 = unsafe { *src.get_unchecked(src_chans.get_b_channel_offset()) } as i32;
+        for x in (cx..width).step_by(iterator_step) {
+            let src = &rgba_row[x * channels..];
+            let r0 = src[src_chans.get_r_channel_offset()] as i32;
+            let g0 = src[src_chans.get_g_channel_offset()] as i32;
+            let b0 = src[src_chans.get_b_channel_offset()] as i32;
             let y_0 =
                 (r0 * transform.yr + g0 * transform.yg + b0 * transform.yb + bias_y) >> PRECISION;
-            unsafe {
-                *y_plane.get_unchecked_mut(y_offset + x) = y_0.clamp(i_bias_y, i_cap_y) as u8;
-            }
+            y_row[x] = y_0.clamp(i_bias_y, i_cap_y) as u8;
+            // Last pixel of odd width is paired with itself, see [YuvChromaSample]
             let mut r1 = r0;
             let mut g1 = g0;
             let mut b1 = b0;
             match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => {
-                    if x + 1 < width as usize {
-                        let next_px = (x + 1) * channels;
-                        let rgba_shift = rgba_offset + next_px;
-                        let src = unsafe { rgba.get_unchecked(rgba_shift..) };
-                        r1 = unsafe { *src.get_unchecked(src_chans.get_r_channel_offset()) } as i32;
-                        g1 = unsafe { *src.get_unchecked(src_chans.get_g_channel_offset()) } as i32;
-                        b1 = unsafe { *src.get_unchecked(src_chans.get_b_channel_offset()) } as i32;
+                    if x + 1 < width {
+                        let src = &rgba_row[(x + 1) * channels..];
+                        r1 = src[src_chans.get_r_channel_offset()] as i32;
+                        g1 = src[src_chans.get_g_channel_offset()] as i32;
+                        b1 = src[src_chans.get_b_channel_offset()] as i32;
                         let y_1 =
                             (r1 * transform.yr + g1 * transform.yg + b1 * transform.yb + bias_y)
                                 >> PRECISION;
-                        unsafe {
-                            *y_plane.get_unchecked_mut(y_offset + x + 1) =
-                                y_1.clamp(i_bias_y, i_cap_y) as u8;
-                        }
+                        y_row[x + 1] = y_1.clamp(i_bias_y, i_cap_y) as u8;
                     }
                 }
                 _ => {}
@@ -258,24 +318,164 @@ fn rgbx_to_yuv8<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
                 let cr = (r * transform.cr_r + g * transform.cr_g + b * transform.cr_b + bias_uv)
                     >> PRECISION;
 
-                let u_pos = match chroma_subsampling {
-                    YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => u_offset + ux,
-                    YuvChromaSample::YUV444 => u_offset + ux,
-                };
-                unsafe {
-                    *u_plane.get_unchecked_mut(u_pos) = cb.clamp(i_bias_y, i_cap_uv) as u8;
-                }
-                let v_pos = match chroma_subsampling {
-                    YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => v_offset + ux,
-                    YuvChromaSample::YUV444 => v_offset + ux,
-                };
-                unsafe {
-                    *v_plane.get_unchecked_mut(v_pos) = cr.clamp(i_bias_y, i_cap_uv) as u8;
-                }
+                u_row[ux] = cb.clamp(i_min_uv, i_cap_uv) as u8;
+                v_row[ux] = cr.clamp(i_min_uv, i_cap_uv) as u8;
             }
 
             ux += 1;
         }
+    }
+
+    /// Scalar encoding of a single row clamping into `legal` window instead of nominal range,
+    /// returns count of samples that had to be clipped.
+    fn encode_row_legal<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
+        &self,
+        y_row: &mut [u8],
+        u_row: &mut [u8],
+        v_row: &mut [u8],
+        rgba_row: &[u8],
+        width: usize,
+        compute_uv_row: bool,
+        legal: &LegalWindow,
+    ) -> usize {
+        let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+        let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+        let channels = src_chans.get_channels_count();
+        let transform = &self.transform;
+        const PRECISION: i32 = RgbToYuv8Encoder::PRECISION;
+
+        let iterator_step = match chroma_subsampling {
+            YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => 2usize,
+            YuvChromaSample::YUV444 => 1usize,
+        };
+
+        let mut clipped = 0usize;
+
+        for (ux, x) in (0..width).step_by(iterator_step).enumerate() {
+            let src = &rgba_row[x * channels..];
+            let r0 = src[src_chans.get_r_channel_offset()] as i32;
+            let g0 = src[src_chans.get_g_channel_offset()] as i32;
+            let b0 = src[src_chans.get_b_channel_offset()] as i32;
+            let y_0 = (r0 * transform.yr + g0 * transform.yg + b0 * transform.yb + self.bias_y)
+                >> PRECISION;
+            y_row[x] = clamp_counted(y_0, legal.min_y, legal.max_y, &mut clipped);
+            let mut r1 = r0;
+            let mut g1 = g0;
+            let mut b1 = b0;
+            if chroma_subsampling != YuvChromaSample::YUV444 && x + 1 < width {
+                let src = &rgba_row[(x + 1) * channels..];
+                r1 = src[src_chans.get_r_channel_offset()] as i32;
+                g1 = src[src_chans.get_g_channel_offset()] as i32;
+                b1 = src[src_chans.get_b_channel_offset()] as i32;
+                let y_1 = (r1 * transform.yr + g1 * transform.yg + b1 * transform.yb + self.bias_y)
+                    >> PRECISION;
+                y_row[x + 1] = clamp_counted(y_1, legal.min_y, legal.max_y, &mut clipped);
+            }
+
+            if compute_uv_row {
+                let (r, g, b) = if chroma_subsampling == YuvChromaSample::YUV444 {
+                    (r0, g0, b0)
+                } else {
+                    ((r0 + r1 + 1) >> 1, (g0 + g1 + 1) >> 1, (b0 + b1 + 1) >> 1)
+                };
+                let cb =
+                    (r * transform.cb_r + g * transform.cb_g + b * transform.cb_b + self.bias_uv)
+                        >> PRECISION;
+                let cr =
+                    (r * transform.cr_r + g * transform.cr_g + b * transform.cr_b + self.bias_uv)
+                        >> PRECISION;
+                u_row[ux] = clamp_counted(cb, legal.min_uv, legal.max_uv, &mut clipped);
+                v_row[ux] = clamp_counted(cr, legal.min_uv, legal.max_uv, &mut clipped);
+            }
+        }
+
+        clipped
+    }
+}
+
+/// Inclusive code bounds of broadcast safe output
+struct LegalWindow {
+    min_y: i32,
+    max_y: i32,
+    min_uv: i32,
+    max_uv: i32,
+}
+
+impl LegalWindow {
+    fn new(encoder: &RgbToYuv8Encoder, margin: u8) -> Result<LegalWindow, YuvError> {
+        let margin_value = margin as i32;
+        let window = LegalWindow {
+            min_y: encoder.i_bias_y + margin_value,
+            max_y: encoder.i_cap_y - margin_value,
+            min_uv: encoder.i_min_uv + margin_value,
+            max_uv: encoder.i_cap_uv - margin_value,
+        };
+        if window.min_y > window.max_y || window.min_uv > window.max_uv {
+            return Err(YuvError::InvalidBroadcastMargin(margin));
+        }
+        Ok(window)
+    }
+}
+
+#[inline(always)]
+fn clamp_counted(v: i32, min: i32, max: i32, clipped: &mut usize) -> u8 {
+    if v < min || v > max {
+        *clipped += 1;
+    }
+    v.clamp(min, max) as u8
+}
+
+pub(crate) fn rgbx_to_yuv8<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    rgba: &[u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let channels = src_chans.get_channels_count();
+
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
+    #[cfg(feature = "profiling")]
+    let _profile = ProfileScope::new(
+        "rgba_to_yuv",
+        y_plane.len() + u_plane.len() + v_plane.len() + rgba.len(),
+        dispatch_backend(true, false),
+        0,
+    );
+
+    let encoder = RgbToYuv8Encoder::new(range, matrix.resolve(width, height));
+
+    let mut y_offset = 0usize;
+    let mut u_offset = 0usize;
+    let mut v_offset = 0usize;
+    let mut rgba_offset = 0usize;
+
+    for y in 0..height as usize {
+        let compute_uv_row = chroma_subsampling == YuvChromaSample::YUV444
+            || chroma_subsampling == YuvChromaSample::YUV422
+            || y & 1 == 0;
+
+        encoder.encode_row::<ORIGIN_CHANNELS, SAMPLING>(
+            &mut y_plane[y_offset..],
+            &mut u_plane[u_offset..],
+            &mut v_plane[v_offset..],
+            &rgba[rgba_offset..],
+            width as usize,
+            compute_uv_row,
+        );
 
         y_offset += y_stride as usize;
         rgba_offset += rgba_stride as usize;
@@ -296,6 +496,103 @@ fn rgbx_to_yuv8<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
     Ok(())
 }
 
+/// Rows gathered into a packed buffer at once, kept even so 4:2:0 chroma pairs never split
+const PIXEL_STRIDE_BATCH_ROWS: usize = 16;
+
+/// Encodes source whose pixels are `pixel_stride` bytes apart by gathering small batches
+/// of rows into a packed buffer and passing them to [rgbx_to_yuv8]
+fn rgbx_to_yuv8_with_pixel_stride<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    rgba: &[u8],
+    rgba_stride: u32,
+    pixel_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let channels = src_chans.get_channels_count();
+
+    if pixel_stride as usize == channels {
+        return rgbx_to_yuv8::<ORIGIN_CHANNELS, SAMPLING>(
+            y_plane,
+            y_stride,
+            u_plane,
+            u_stride,
+            v_plane,
+            v_stride,
+            rgba,
+            rgba_stride,
+            width,
+            height,
+            range,
+            matrix,
+        );
+    }
+
+    check_rgba_pixel_stride(rgba, rgba_stride, pixel_stride, width, height, channels)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
+    let packed_stride = width as usize * channels;
+    let mut packed = try_vec(
+        0u8,
+        packed_stride * PIXEL_STRIDE_BATCH_ROWS.min(height as usize),
+    )?;
+
+    // Batches are converted as separate images, automatic matrix follows the whole frame
+    let matrix = matrix.resolve(width, height);
+    for start_row in (0..height as usize).step_by(PIXEL_STRIDE_BATCH_ROWS) {
+        let rows = PIXEL_STRIDE_BATCH_ROWS.min(height as usize - start_row);
+        let (chroma_start, chroma_end) = match chroma_subsampling {
+            YuvChromaSample::YUV420 => (start_row / 2, (start_row + rows).div_ceil(2)),
+            YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => (start_row, start_row + rows),
+        };
+
+        for (y, dst_row) in packed
+            .chunks_exact_mut(packed_stride)
+            .take(rows)
+            .enumerate()
+        {
+            let src_row = &rgba[(start_row + y) * rgba_stride as usize..];
+            for (dst, src) in dst_row
+                .chunks_exact_mut(channels)
+                .zip(src_row.chunks(pixel_stride as usize))
+            {
+                dst.copy_from_slice(&src[..channels]);
+            }
+        }
+
+        let y_end = ((start_row + rows) * y_stride as usize).min(y_plane.len());
+        let u_end = (chroma_end * u_stride as usize).min(u_plane.len());
+        let v_end = (chroma_end * v_stride as usize).min(v_plane.len());
+        rgbx_to_yuv8::<ORIGIN_CHANNELS, SAMPLING>(
+            &mut y_plane[start_row * y_stride as usize..y_end],
+            y_stride,
+            &mut u_plane[chroma_start * u_stride as usize..u_end],
+            u_stride,
+            &mut v_plane[chroma_start * v_stride as usize..v_end],
+            v_stride,
+            &packed[..packed_stride * rows],
+            packed_stride as u32,
+            width,
+            rows as u32,
+            range,
+            matrix,
+        )?;
+    }
+
+    Ok(())
+}
+
 /// Convert RGB image data to YUV 422 planar format.
 ///
 /// This function performs RGB to YUV conversion and stores the result in YUV422 planar format,
@@ -895,3 +1192,1271 @@ pub fn bgra_to_yuv444(
         matrix,
     )
 }
+
+/// Convert RGB image data to YV12 planar format.
+///
+/// This function performs RGB to YUV conversion and stores the result in YV12 planar format,
+/// that is YUV 420 with V plane stored before U plane.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data, the second plane in YV12.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data, the third plane in YV12.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `rgb` - The input RGB image data slice.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgb_to_yv12(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    rgb: &[u8],
+    rgb_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert BGR image data to YV12 planar format.
+///
+/// This function performs BGR to YUV conversion and stores the result in YV12 planar format,
+/// that is YUV 420 with V plane stored before U plane.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data, the second plane in YV12.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data, the third plane in YV12.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `bgr` - The input BGR image data slice.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgr_to_yv12(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    bgr: &[u8],
+    bgr_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert RGBA image data to YV12 planar format.
+///
+/// This function performs RGBA to YUV conversion and stores the result in YV12 planar format,
+/// that is YUV 420 with V plane stored before U plane.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data, the second plane in YV12.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data, the third plane in YV12.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgba_to_yv12(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    rgba: &[u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA image data to YV12 planar format.
+///
+/// This function performs BGRA to YUV conversion and stores the result in YV12 planar format,
+/// that is YUV 420 with V plane stored before U plane.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data, the second plane in YV12.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data, the third plane in YV12.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgra_to_yv12(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    bgra: &[u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert RGB image data to YV16 planar format.
+///
+/// This function performs RGB to YUV conversion and stores the result in YV16 planar format,
+/// that is YUV 422 with V plane stored before U plane.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data, the second plane in YV16.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data, the third plane in YV16.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `rgb` - The input RGB image data slice.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgb_to_yv16(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    rgb: &[u8],
+    rgb_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert BGR image data to YV16 planar format.
+///
+/// This function performs BGR to YUV conversion and stores the result in YV16 planar format,
+/// that is YUV 422 with V plane stored before U plane.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data, the second plane in YV16.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data, the third plane in YV16.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `bgr` - The input BGR image data slice.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgr_to_yv16(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    bgr: &[u8],
+    bgr_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert RGBA image data to YV16 planar format.
+///
+/// This function performs RGBA to YUV conversion and stores the result in YV16 planar format,
+/// that is YUV 422 with V plane stored before U plane.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data, the second plane in YV16.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data, the third plane in YV16.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgba_to_yv16(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    rgba: &[u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA image data to YV16 planar format.
+///
+/// This function performs BGRA to YUV conversion and stores the result in YV16 planar format,
+/// that is YUV 422 with V plane stored before U plane.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data, the second plane in YV16.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data, the third plane in YV16.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgra_to_yv16(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    bgra: &[u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert RGB image data to YV24 planar format.
+///
+/// This function performs RGB to YUV conversion and stores the result in YV24 planar format,
+/// that is YUV 444 with V plane stored before U plane.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data, the second plane in YV24.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data, the third plane in YV24.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `rgb` - The input RGB image data slice.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgb_to_yv24(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    rgb: &[u8],
+    rgb_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert BGR image data to YV24 planar format.
+///
+/// This function performs BGR to YUV conversion and stores the result in YV24 planar format,
+/// that is YUV 444 with V plane stored before U plane.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data, the second plane in YV24.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data, the third plane in YV24.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `bgr` - The input BGR image data slice.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgr_to_yv24(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    bgr: &[u8],
+    bgr_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert RGBA image data to YV24 planar format.
+///
+/// This function performs RGBA to YUV conversion and stores the result in YV24 planar format,
+/// that is YUV 444 with V plane stored before U plane.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data, the second plane in YV24.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data, the third plane in YV24.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgba_to_yv24(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    rgba: &[u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA image data to YV24 planar format.
+///
+/// This function performs BGRA to YUV conversion and stores the result in YV24 planar format,
+/// that is YUV 444 with V plane stored before U plane.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data, the second plane in YV24.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data, the third plane in YV24.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgra_to_yv24(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    bgra: &[u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert RGBA image data with padded pixels to YUV 420 planar format.
+///
+/// This function performs RGBA to YUV conversion of a source whose pixels are `pixel_stride` bytes apart,
+/// as produced by screen captures with 64-bit pixel alignment, without a separate compaction pass.
+/// Only the first four bytes of each pixel are read.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `pixel_stride` - The distance in bytes between two neighbouring pixels, at least 4.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgba_to_yuv420_with_pixel_stride(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    rgba: &[u8],
+    rgba_stride: u32,
+    pixel_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8_with_pixel_stride::<
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        pixel_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert RGBA image data with padded pixels to YUV 422 planar format.
+///
+/// This function performs RGBA to YUV conversion of a source whose pixels are `pixel_stride` bytes apart,
+/// as produced by screen captures with 64-bit pixel alignment, without a separate compaction pass.
+/// Only the first four bytes of each pixel are read.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `pixel_stride` - The distance in bytes between two neighbouring pixels, at least 4.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgba_to_yuv422_with_pixel_stride(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    rgba: &[u8],
+    rgba_stride: u32,
+    pixel_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8_with_pixel_stride::<
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV422 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        pixel_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert RGBA image data with padded pixels to YUV 444 planar format.
+///
+/// This function performs RGBA to YUV conversion of a source whose pixels are `pixel_stride` bytes apart,
+/// as produced by screen captures with 64-bit pixel alignment, without a separate compaction pass.
+/// Only the first four bytes of each pixel are read.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `pixel_stride` - The distance in bytes between two neighbouring pixels, at least 4.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgba_to_yuv444_with_pixel_stride(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    rgba: &[u8],
+    rgba_stride: u32,
+    pixel_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8_with_pixel_stride::<
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV444 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        pixel_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA image data with padded pixels to YUV 420 planar format.
+///
+/// This function performs BGRA to YUV conversion of a source whose pixels are `pixel_stride` bytes apart,
+/// as produced by screen captures with 64-bit pixel alignment, without a separate compaction pass.
+/// Only the first four bytes of each pixel are read.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `pixel_stride` - The distance in bytes between two neighbouring pixels, at least 4.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgra_to_yuv420_with_pixel_stride(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    bgra: &[u8],
+    bgra_stride: u32,
+    pixel_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8_with_pixel_stride::<
+        { YuvSourceChannels::Bgra as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        pixel_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA image data with padded pixels to YUV 422 planar format.
+///
+/// This function performs BGRA to YUV conversion of a source whose pixels are `pixel_stride` bytes apart,
+/// as produced by screen captures with 64-bit pixel alignment, without a separate compaction pass.
+/// Only the first four bytes of each pixel are read.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `pixel_stride` - The distance in bytes between two neighbouring pixels, at least 4.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgra_to_yuv422_with_pixel_stride(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    bgra: &[u8],
+    bgra_stride: u32,
+    pixel_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8_with_pixel_stride::<
+        { YuvSourceChannels::Bgra as u8 },
+        { YuvChromaSample::YUV422 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        pixel_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA image data with padded pixels to YUV 444 planar format.
+///
+/// This function performs BGRA to YUV conversion of a source whose pixels are `pixel_stride` bytes apart,
+/// as produced by screen captures with 64-bit pixel alignment, without a separate compaction pass.
+/// Only the first four bytes of each pixel are read.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `pixel_stride` - The distance in bytes between two neighbouring pixels, at least 4.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgra_to_yuv444_with_pixel_stride(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    bgra: &[u8],
+    bgra_stride: u32,
+    pixel_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_to_yuv8_with_pixel_stride::<
+        { YuvSourceChannels::Bgra as u8 },
+        { YuvChromaSample::YUV444 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        pixel_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+fn check_row_len(row_len: usize, expected: usize) -> Result<(), YuvError> {
+    if row_len < expected {
+        return Err(YuvError::LumaPlaneMinimumSizeMismatch(MismatchedSize {
+            expected,
+            received: row_len,
+        }));
+    }
+    Ok(())
+}
+
+fn rgbx_rows_to_yuv420_rows<const ORIGIN_CHANNELS: u8>(
+    rgba_row0: &[u8],
+    rgba_row1: &[u8],
+    y_row0: &mut [u8],
+    y_row1: &mut [u8],
+    u_row: &mut [u8],
+    v_row: &mut [u8],
+    width: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    if width == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    // Rows carry no frame height, so automatic matrix cannot be guessed here
+    if matches!(matrix, YuvStandardMatrix::Auto) {
+        return Err(YuvError::UnresolvedAutoMatrix);
+    }
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let channels = src_chans.get_channels_count();
+    let width = width as usize;
+    let chroma_width = width.div_ceil(2);
+
+    for rgba_row in [rgba_row0, rgba_row1] {
+        if rgba_row.len() < width * channels {
+            return Err(YuvError::MinimumDestinationSizeMismatch(MismatchedSize {
+                expected: width * channels,
+                received: rgba_row.len(),
+            }));
+        }
+    }
+    check_row_len(y_row0.len(), width)?;
+    check_row_len(y_row1.len(), width)?;
+    check_row_len(u_row.len(), chroma_width)?;
+    check_row_len(v_row.len(), chroma_width)?;
+
+    let encoder = RgbToYuv8Encoder::new(range, matrix);
+    encoder.encode_row::<ORIGIN_CHANNELS, { YuvChromaSample::YUV420 as u8 }>(
+        y_row0, u_row, v_row, rgba_row0, width, true,
+    );
+    encoder.encode_row::<ORIGIN_CHANNELS, { YuvChromaSample::YUV420 as u8 }>(
+        y_row1, u_row, v_row, rgba_row1, width, false,
+    );
+    Ok(())
+}
+
+/// Convert a pair of RGB rows to two luma rows and one chroma row of YUV 420.
+///
+/// This function converts exactly one 4:2:0 row pair, so streaming encoders can feed
+/// macroblock bands row pair by row pair. Output is identical to the corresponding rows
+/// produced by [rgb_to_yuv420]; chroma is taken from the first row as there.
+///
+/// # Arguments
+///
+/// * `rgb_row0` - The first (even) RGB row, at least `width * 3` bytes.
+/// * `rgb_row1` - The second (odd) RGB row, at least `width * 3` bytes.
+/// * `y_row0` - A mutable slice to store the Y row of the first source row, at least `width` bytes.
+/// * `y_row1` - A mutable slice to store the Y row of the second source row, at least `width` bytes.
+/// * `u_row` - A mutable slice to store the U row, at least `(width + 1) / 2` bytes.
+/// * `v_row` - A mutable slice to store the V row, at least `(width + 1) / 2` bytes.
+/// * `width` - The width of the rows in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix, [YuvStandardMatrix::Auto] is rejected as rows carry
+///   no frame height, resolve it against the frame first with [YuvStandardMatrix::resolve].
+///
+pub fn rgb_rows_to_yuv420_rows(
+    rgb_row0: &[u8],
+    rgb_row1: &[u8],
+    y_row0: &mut [u8],
+    y_row1: &mut [u8],
+    u_row: &mut [u8],
+    v_row: &mut [u8],
+    width: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_rows_to_yuv420_rows::<{ YuvSourceChannels::Rgb as u8 }>(
+        rgb_row0, rgb_row1, y_row0, y_row1, u_row, v_row, width, range, matrix,
+    )
+}
+
+/// Convert a pair of RGBA rows to two luma rows and one chroma row of YUV 420.
+///
+/// This function converts exactly one 4:2:0 row pair, so streaming encoders can feed
+/// macroblock bands row pair by row pair. Output is identical to the corresponding rows
+/// produced by [rgba_to_yuv420]; chroma is taken from the first row as there.
+///
+/// # Arguments
+///
+/// * `rgba_row0` - The first (even) RGBA row, at least `width * 4` bytes.
+/// * `rgba_row1` - The second (odd) RGBA row, at least `width * 4` bytes.
+/// * `y_row0` - A mutable slice to store the Y row of the first source row, at least `width` bytes.
+/// * `y_row1` - A mutable slice to store the Y row of the second source row, at least `width` bytes.
+/// * `u_row` - A mutable slice to store the U row, at least `(width + 1) / 2` bytes.
+/// * `v_row` - A mutable slice to store the V row, at least `(width + 1) / 2` bytes.
+/// * `width` - The width of the rows in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix, [YuvStandardMatrix::Auto] is rejected as rows carry
+///   no frame height, resolve it against the frame first with [YuvStandardMatrix::resolve].
+///
+pub fn rgba_rows_to_yuv420_rows(
+    rgba_row0: &[u8],
+    rgba_row1: &[u8],
+    y_row0: &mut [u8],
+    y_row1: &mut [u8],
+    u_row: &mut [u8],
+    v_row: &mut [u8],
+    width: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_rows_to_yuv420_rows::<{ YuvSourceChannels::Rgba as u8 }>(
+        rgba_row0, rgba_row1, y_row0, y_row1, u_row, v_row, width, range, matrix,
+    )
+}
+
+/// Convert a pair of BGRA rows to two luma rows and one chroma row of YUV 420.
+///
+/// This function converts exactly one 4:2:0 row pair, so streaming encoders can feed
+/// macroblock bands row pair by row pair. Output is identical to the corresponding rows
+/// produced by [bgra_to_yuv420]; chroma is taken from the first row as there.
+///
+/// # Arguments
+///
+/// * `bgra_row0` - The first (even) BGRA row, at least `width * 4` bytes.
+/// * `bgra_row1` - The second (odd) BGRA row, at least `width * 4` bytes.
+/// * `y_row0` - A mutable slice to store the Y row of the first source row, at least `width` bytes.
+/// * `y_row1` - A mutable slice to store the Y row of the second source row, at least `width` bytes.
+/// * `u_row` - A mutable slice to store the U row, at least `(width + 1) / 2` bytes.
+/// * `v_row` - A mutable slice to store the V row, at least `(width + 1) / 2` bytes.
+/// * `width` - The width of the rows in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix, [YuvStandardMatrix::Auto] is rejected as rows carry
+///   no frame height, resolve it against the frame first with [YuvStandardMatrix::resolve].
+///
+pub fn bgra_rows_to_yuv420_rows(
+    bgra_row0: &[u8],
+    bgra_row1: &[u8],
+    y_row0: &mut [u8],
+    y_row1: &mut [u8],
+    u_row: &mut [u8],
+    v_row: &mut [u8],
+    width: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    rgbx_rows_to_yuv420_rows::<{ YuvSourceChannels::Bgra as u8 }>(
+        bgra_row0, bgra_row1, y_row0, y_row1, u_row, v_row, width, range, matrix,
+    )
+}
+
+fn rgbx_to_yuv8_broadcast_safe_impl<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
+    rgba: &[u8],
+    rgba_stride: u32,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    broadcast_safe: YuvBroadcastSafe,
+) -> Result<usize, YuvError> {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let channels = src_chans.get_channels_count();
+
+    let width = planar_image.size.width;
+    let height = planar_image.size.height;
+    let y_stride = planar_image.y_stride.get() as usize;
+    let u_stride = planar_image.u_stride.get() as usize;
+    let v_stride = planar_image.v_stride.get() as usize;
+
+    if !broadcast_safe.broadcast_safe {
+        rgbx_to_yuv8::<ORIGIN_CHANNELS, SAMPLING>(
+            planar_image.y_plane.borrow_mut(),
+            y_stride as u32,
+            planar_image.u_plane.borrow_mut(),
+            u_stride as u32,
+            planar_image.v_plane.borrow_mut(),
+            v_stride as u32,
+            rgba,
+            rgba_stride,
+            width,
+            height,
+            range,
+            matrix,
+        )?;
+        return Ok(0);
+    }
+
+    planar_image.check_constraints(chroma_subsampling)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+
+    let encoder = RgbToYuv8Encoder::new(range, matrix.resolve(width, height));
+    let legal = LegalWindow::new(&encoder, broadcast_safe.margin)?;
+
+    let y_plane = planar_image.y_plane.borrow_mut();
+    let u_plane = planar_image.u_plane.borrow_mut();
+    let v_plane = planar_image.v_plane.borrow_mut();
+
+    let mut clipped = 0usize;
+
+    for y in 0..height as usize {
+        let chroma_y = match chroma_subsampling {
+            YuvChromaSample::YUV420 => y / 2,
+            YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => y,
+        };
+        let compute_uv_row = chroma_subsampling != YuvChromaSample::YUV420 || y & 1 == 0;
+        clipped += encoder.encode_row_legal::<ORIGIN_CHANNELS, SAMPLING>(
+            &mut y_plane[y * y_stride..],
+            &mut u_plane[chroma_y * u_stride..],
+            &mut v_plane[chroma_y * v_stride..],
+            &rgba[y * rgba_stride as usize..],
+            width as usize,
+            compute_uv_row,
+            &legal,
+        );
+    }
+
+    Ok(clipped)
+}
+
+pub(crate) fn rgbx_to_yuv8_broadcast_safe<const ORIGIN_CHANNELS: u8>(
+    rgba: &[u8],
+    rgba_stride: u32,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    broadcast_safe: YuvBroadcastSafe,
+) -> Result<usize, YuvError> {
+    match sampling {
+        YuvChromaSample::YUV420 => {
+            rgbx_to_yuv8_broadcast_safe_impl::<ORIGIN_CHANNELS, { YuvChromaSample::YUV420 as u8 }>(
+                rgba,
+                rgba_stride,
+                planar_image,
+                range,
+                matrix,
+                broadcast_safe,
+            )
+        }
+        YuvChromaSample::YUV422 => {
+            rgbx_to_yuv8_broadcast_safe_impl::<ORIGIN_CHANNELS, { YuvChromaSample::YUV422 as u8 }>(
+                rgba,
+                rgba_stride,
+                planar_image,
+                range,
+                matrix,
+                broadcast_safe,
+            )
+        }
+        YuvChromaSample::YUV444 => {
+            rgbx_to_yuv8_broadcast_safe_impl::<ORIGIN_CHANNELS, { YuvChromaSample::YUV444 as u8 }>(
+                rgba,
+                rgba_stride,
+                planar_image,
+                range,
+                matrix,
+                broadcast_safe,
+            )
+        }
+    }
+}
+
+/// Convert RGB image data to YUV planar format with optional broadcast legal clamping.
+///
+/// With [YuvBroadcastSafe::broadcast_safe] set every Y, Cb and Cr value is clamped into
+/// nominal range of `range` shrunk by [YuvBroadcastSafe::margin] codes and clipped samples
+/// are counted. Otherwise this behaves as regular forward conversion and reports no clipping.
+///
+/// # Arguments
+///
+/// * `rgb` - The input RGB image data slice.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `planar_image` - Target planar image.
+/// * `sampling` - Chroma subsampling of the target image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `broadcast_safe` - Broadcast legal clamping options.
+///
+/// Returns count of Y, Cb and Cr samples that were clipped into legal range.
+///
+pub fn rgb_to_yuv_broadcast_safe(
+    rgb: &[u8],
+    rgb_stride: u32,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    broadcast_safe: YuvBroadcastSafe,
+) -> Result<usize, YuvError> {
+    rgbx_to_yuv8_broadcast_safe::<{ YuvSourceChannels::Rgb as u8 }>(
+        rgb,
+        rgb_stride,
+        planar_image,
+        sampling,
+        range,
+        matrix,
+        broadcast_safe,
+    )
+}
+
+/// Convert RGBA image data to YUV planar format with optional broadcast legal clamping.
+///
+/// With [YuvBroadcastSafe::broadcast_safe] set every Y, Cb and Cr value is clamped into
+/// nominal range of `range` shrunk by [YuvBroadcastSafe::margin] codes and clipped samples
+/// are counted. Otherwise this behaves as regular forward conversion and reports no clipping.
+///
+/// # Arguments
+///
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `planar_image` - Target planar image.
+/// * `sampling` - Chroma subsampling of the target image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `broadcast_safe` - Broadcast legal clamping options.
+///
+/// Returns count of Y, Cb and Cr samples that were clipped into legal range.
+///
+pub fn rgba_to_yuv_broadcast_safe(
+    rgba: &[u8],
+    rgba_stride: u32,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    broadcast_safe: YuvBroadcastSafe,
+) -> Result<usize, YuvError> {
+    rgbx_to_yuv8_broadcast_safe::<{ YuvSourceChannels::Rgba as u8 }>(
+        rgba,
+        rgba_stride,
+        planar_image,
+        sampling,
+        range,
+        matrix,
+        broadcast_safe,
+    )
+}
+
+/// Convert BGRA image data to YUV planar format with optional broadcast legal clamping.
+///
+/// With [YuvBroadcastSafe::broadcast_safe] set every Y, Cb and Cr value is clamped into
+/// nominal range of `range` shrunk by [YuvBroadcastSafe::margin] codes and clipped samples
+/// are counted. Otherwise this behaves as regular forward conversion and reports no clipping.
+///
+/// # Arguments
+///
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `planar_image` - Target planar image.
+/// * `sampling` - Chroma subsampling of the target image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `broadcast_safe` - Broadcast legal clamping options.
+///
+/// Returns count of Y, Cb and Cr samples that were clipped into legal range.
+///
+pub fn bgra_to_yuv_broadcast_safe(
+    bgra: &[u8],
+    bgra_stride: u32,
+    planar_image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    broadcast_safe: YuvBroadcastSafe,
+) -> Result<usize, YuvError> {
+    rgbx_to_yuv8_broadcast_safe::<{ YuvSourceChannels::Bgra as u8 }>(
+        bgra,
+        bgra_stride,
+        planar_image,
+        sampling,
+        range,
+        matrix,
+        broadcast_safe,
+    )
+}
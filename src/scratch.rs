@@ -0,0 +1,183 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::BufferStoreMut;
+use crate::yuv_error::MismatchedSize;
+use crate::YuvError;
+use std::fmt::Debug;
+#[cfg(not(feature = "safe-only"))]
+use std::mem::MaybeUninit;
+
+/// Bump allocator over caller-provided memory.
+///
+/// Conversions that need intermediate buffers have `_with_scratch` variants taking this arena,
+/// so they can run on threads where heap allocation is forbidden.
+/// Every allocation is carved out of the remaining memory and lives as long as the backing buffer,
+/// memory is never reclaimed, create a new arena over the same buffer to reuse it.
+///
+/// Uninitialized memory cannot be handed out without `unsafe`, so with `safe-only` feature
+/// arenas are made by [YuvScratchArena::from_bytes] only.
+///
+/// Use matching `*_scratch_size` helpers to find out how much memory a conversion requires.
+#[derive(Debug)]
+pub struct YuvScratchArena<'a> {
+    memory: ScratchMemory<'a>,
+}
+
+#[derive(Debug)]
+enum ScratchMemory<'a> {
+    #[cfg(not(feature = "safe-only"))]
+    Uninit(&'a mut [MaybeUninit<u8>]),
+    Bytes(&'a mut [u8]),
+}
+
+/// Element types intermediate buffers are carved as
+pub(crate) trait ScratchElement: Copy + Default + Debug {
+    /// Reinterprets zeroed bytes aligned for `Self` as elements
+    fn from_bytes(bytes: &mut [u8]) -> &mut [Self];
+}
+
+impl ScratchElement for u8 {
+    fn from_bytes(bytes: &mut [u8]) -> &mut [u8] {
+        bytes
+    }
+}
+
+impl ScratchElement for u16 {
+    fn from_bytes(bytes: &mut [u8]) -> &mut [u16] {
+        #[cfg(feature = "safe-only")]
+        {
+            bytemuck::cast_slice_mut(bytes)
+        }
+        #[cfg(not(feature = "safe-only"))]
+        unsafe {
+            let (head, body, _) = bytes.align_to_mut::<u16>();
+            debug_assert!(head.is_empty());
+            body
+        }
+    }
+}
+
+impl<'a> YuvScratchArena<'a> {
+    /// Arena over uninitialized memory, not available with `safe-only` feature.
+    #[cfg(not(feature = "safe-only"))]
+    pub fn new(buffer: &'a mut [MaybeUninit<u8>]) -> YuvScratchArena<'a> {
+        YuvScratchArena {
+            memory: ScratchMemory::Uninit(buffer),
+        }
+    }
+
+    /// Arena over initialized memory.
+    pub fn from_bytes(buffer: &'a mut [u8]) -> YuvScratchArena<'a> {
+        YuvScratchArena {
+            memory: ScratchMemory::Bytes(buffer),
+        }
+    }
+
+    /// Bytes left in the arena
+    pub fn remaining(&self) -> usize {
+        match &self.memory {
+            #[cfg(not(feature = "safe-only"))]
+            ScratchMemory::Uninit(buffer) => buffer.len(),
+            ScratchMemory::Bytes(buffer) => buffer.len(),
+        }
+    }
+
+    /// Alignment padding and bytes taken by `count` elements of `T` placed at `address`
+    /// of a buffer with `available` bytes
+    fn required<T>(
+        address: *const u8,
+        available: usize,
+        count: usize,
+    ) -> Result<(usize, usize), YuvError> {
+        let align_offset = address.align_offset(std::mem::align_of::<T>());
+        let required = count
+            .checked_mul(std::mem::size_of::<T>())
+            .and_then(|x| x.checked_add(align_offset))
+            .ok_or(YuvError::PointerOverflow)?;
+        if required > available {
+            return Err(YuvError::ScratchSizeMismatch(MismatchedSize {
+                expected: required,
+                received: available,
+            }));
+        }
+        Ok((align_offset, required))
+    }
+
+    /// Allocates `count` default initialized elements aligned for `T`
+    pub(crate) fn alloc<T: ScratchElement>(
+        &mut self,
+        count: usize,
+    ) -> Result<BufferStoreMut<'a, T>, YuvError> {
+        match std::mem::replace(&mut self.memory, ScratchMemory::Bytes(&mut [])) {
+            ScratchMemory::Bytes(buffer) => {
+                let (align_offset, required) =
+                    match Self::required::<T>(buffer.as_ptr(), buffer.len(), count) {
+                        Ok(v) => v,
+                        Err(err) => {
+                            self.memory = ScratchMemory::Bytes(buffer);
+                            return Err(err);
+                        }
+                    };
+                let (head, tail) = buffer.split_at_mut(required);
+                self.memory = ScratchMemory::Bytes(tail);
+                let bytes = &mut head[align_offset..];
+                bytes.fill(0);
+                Ok(BufferStoreMut::Borrowed(T::from_bytes(bytes)))
+            }
+            #[cfg(not(feature = "safe-only"))]
+            ScratchMemory::Uninit(buffer) => {
+                let (align_offset, required) =
+                    match Self::required::<T>(buffer.as_ptr() as *const u8, buffer.len(), count) {
+                        Ok(v) => v,
+                        Err(err) => {
+                            self.memory = ScratchMemory::Uninit(buffer);
+                            return Err(err);
+                        }
+                    };
+                let (head, tail) = buffer.split_at_mut(required);
+                self.memory = ScratchMemory::Uninit(tail);
+                let dst = head[align_offset..].as_mut_ptr() as *mut T;
+                unsafe {
+                    for i in 0..count {
+                        dst.add(i).write(T::default());
+                    }
+                    Ok(BufferStoreMut::Borrowed(std::slice::from_raw_parts_mut(
+                        dst, count,
+                    )))
+                }
+            }
+        }
+    }
+}
+
+/// Worst case bytes needed to allocate `count` elements of `T` from [YuvScratchArena]
+#[inline]
+pub(crate) const fn scratch_bytes<T>(count: usize) -> usize {
+    count * std::mem::size_of::<T>() + std::mem::align_of::<T>() - 1
+}
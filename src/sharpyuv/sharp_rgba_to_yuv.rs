@@ -27,6 +27,8 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 #![forbid(unsafe_code)]
+use crate::images::BufferStoreMut;
+use crate::scratch::{scratch_bytes, YuvScratchArena};
 use crate::sharpyuv::SharpYuvGammaTransfer;
 use crate::yuv_error::{check_chroma_channel, check_rgba_destination, check_y8_channel};
 use crate::yuv_support::*;
@@ -52,13 +54,20 @@ fn sharpen_row420<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const PRECISION
     let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
     let channels = src_chans.get_channels_count();
 
+    // Rows may carry padding, the last odd pixel has to land at `width - 1`
+    let rgba = &rgba[..width * channels];
+    let y_plane = &mut y_plane[..width];
+    let u_plane = &mut u_plane[..width.div_ceil(2)];
+    let v_plane = &mut v_plane[..width.div_ceil(2)];
+
     let rounding_const_bias: i32 = 1 << (PRECISION - 1);
     let bias_y = range.bias_y as i32 * (1 << PRECISION) + rounding_const_bias;
     let bias_uv = range.bias_uv as i32 * (1 << PRECISION) + rounding_const_bias;
 
     let i_bias_y = range.bias_y as i32;
     let i_cap_y = range.range_y as i32 + i_bias_y;
-    let i_cap_uv = i_bias_y + range.range_uv as i32;
+    let i_min_uv = range.min_uv() as i32;
+    let i_cap_uv = range.max_uv() as i32;
 
     let y_even_row = y & 1 == 0;
 
@@ -142,8 +151,8 @@ fn sharpen_row420<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const PRECISION
                 + corrected_b * transform.cr_b
                 + bias_uv)
                 >> PRECISION;
-            *u_dst = cb.clamp(i_bias_y, i_cap_uv) as u8;
-            *v_dst = cr.clamp(i_bias_y, i_cap_uv) as u8;
+            *u_dst = cb.clamp(i_min_uv, i_cap_uv) as u8;
+            *v_dst = cr.clamp(i_min_uv, i_cap_uv) as u8;
         }
     }
 
@@ -197,8 +206,8 @@ fn sharpen_row420<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const PRECISION
                 >> PRECISION;
             let u_last = u_plane.last_mut().unwrap();
             let v_last = v_plane.last_mut().unwrap();
-            *u_last = cb.clamp(i_bias_y, i_cap_uv) as u8;
-            *v_last = cr.clamp(i_bias_y, i_cap_uv) as u8;
+            *u_last = cb.clamp(i_min_uv, i_cap_uv) as u8;
+            *v_last = cr.clamp(i_min_uv, i_cap_uv) as u8;
         }
     }
 }
@@ -217,13 +226,20 @@ fn sharpen_row422<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const PRECISION
     let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
     let channels = src_chans.get_channels_count();
 
+    // Rows may carry padding, the last odd pixel has to land at `width - 1`
+    let rgba = &rgba[..width * channels];
+    let y_plane = &mut y_plane[..width];
+    let u_plane = &mut u_plane[..width.div_ceil(2)];
+    let v_plane = &mut v_plane[..width.div_ceil(2)];
+
     let rounding_const_bias: i32 = 1 << (PRECISION - 1);
     let bias_y = range.bias_y as i32 * (1 << PRECISION) + rounding_const_bias;
     let bias_uv = range.bias_uv as i32 * (1 << PRECISION) + rounding_const_bias;
 
     let i_bias_y = range.bias_y as i32;
     let i_cap_y = range.range_y as i32 + i_bias_y;
-    let i_cap_uv = i_bias_y + range.range_uv as i32;
+    let i_min_uv = range.min_uv() as i32;
+    let i_cap_uv = range.max_uv() as i32;
 
     for ((((y_dst, u_dst), v_dst), rgba), rgb_linearized) in y_plane
         .chunks_exact_mut(2)
@@ -281,8 +297,8 @@ fn sharpen_row422<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const PRECISION
             + corrected_b * transform.cr_b
             + bias_uv)
             >> PRECISION;
-        *u_dst = cb.clamp(i_bias_y, i_cap_uv) as u8;
-        *v_dst = cr.clamp(i_bias_y, i_cap_uv) as u8;
+        *u_dst = cb.clamp(i_min_uv, i_cap_uv) as u8;
+        *v_dst = cr.clamp(i_min_uv, i_cap_uv) as u8;
     }
 
     let rem_rgba = rgba.chunks_exact(channels * 2).remainder();
@@ -304,8 +320,8 @@ fn sharpen_row422<const ORIGIN_CHANNELS: u8, const SAMPLING: u8, const PRECISION
 
         let u_last = u_plane.last_mut().unwrap();
         let v_last = v_plane.last_mut().unwrap();
-        *u_last = cb.clamp(i_bias_y, i_cap_uv) as u8;
-        *v_last = cr.clamp(i_bias_y, i_cap_uv) as u8;
+        *u_last = cb.clamp(i_min_uv, i_cap_uv) as u8;
+        *v_last = cr.clamp(i_min_uv, i_cap_uv) as u8;
     }
 }
 
@@ -323,6 +339,7 @@ fn rgbx_to_sharp_yuv<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
     range: YuvRange,
     matrix: YuvStandardMatrix,
     sharp_yuv_gamma_transfer: SharpYuvGammaTransfer,
+    scratch: Option<&mut YuvScratchArena>,
 ) -> Result<(), YuvError> {
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
@@ -355,7 +372,12 @@ fn rgbx_to_sharp_yuv<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
     }
 
     // Always using 3 Channels ( RGB etc. ) layout since we do not need a alpha channel
-    let mut rgb_layout: Vec<u16> = vec![0u16; width as usize * height as usize * 3];
+    let rgb_layout_length = width as usize * height as usize * 3;
+    let mut rgb_layout_store = match scratch {
+        Some(arena) => arena.alloc::<u16>(rgb_layout_length)?,
+        None => BufferStoreMut::try_alloc(rgb_layout_length)?,
+    };
+    let rgb_layout = rgb_layout_store.borrow_mut();
 
     let rgb_layout_stride_len = width as usize * 3;
 
@@ -364,13 +386,13 @@ fn rgbx_to_sharp_yuv<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
     {
         iter_linearize = rgb_layout
             .chunks_exact_mut(rgb_layout_stride_len)
-            .zip(rgba.chunks_exact(rgba_stride as usize));
+            .zip(rgba.chunks(rgba_stride as usize));
     }
     #[cfg(feature = "rayon")]
     {
         iter_linearize = rgb_layout
             .par_chunks_exact_mut(rgb_layout_stride_len)
-            .zip(rgba.par_chunks_exact(rgba_stride as usize));
+            .zip(rgba.par_chunks(rgba_stride as usize));
     }
 
     iter_linearize.for_each(|(rgb_layout_cast, src_layout)| {
@@ -385,7 +407,7 @@ fn rgbx_to_sharp_yuv<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
     });
 
     let range = get_yuv_range(8, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let max_range_p8 = (1u32 << 8u32) - 1u32;
     let transform_precise = get_forward_transform(
         max_range_p8,
@@ -405,32 +427,32 @@ fn rgbx_to_sharp_yuv<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
     if chroma_subsampling == YuvChromaSample::YUV420 {
         #[cfg(feature = "rayon")]
         {
-            y_iter = y_plane.par_chunks_exact_mut(y_stride as usize * 2);
-            u_iter = u_plane.par_chunks_exact_mut(u_stride as usize);
-            v_iter = v_plane.par_chunks_exact_mut(v_stride as usize);
-            rgb_iter = rgba.par_chunks_exact(rgba_stride as usize * 2);
+            y_iter = y_plane.par_chunks_mut(y_stride as usize * 2);
+            u_iter = u_plane.par_chunks_mut(u_stride as usize);
+            v_iter = v_plane.par_chunks_mut(v_stride as usize);
+            rgb_iter = rgba.par_chunks(rgba_stride as usize * 2);
         }
         #[cfg(not(feature = "rayon"))]
         {
-            y_iter = y_plane.chunks_exact_mut(y_stride as usize * 2);
-            u_iter = u_plane.chunks_exact_mut(u_stride as usize);
-            v_iter = v_plane.chunks_exact_mut(v_stride as usize);
-            rgb_iter = rgba.chunks_exact(rgba_stride as usize * 2);
+            y_iter = y_plane.chunks_mut(y_stride as usize * 2);
+            u_iter = u_plane.chunks_mut(u_stride as usize);
+            v_iter = v_plane.chunks_mut(v_stride as usize);
+            rgb_iter = rgba.chunks(rgba_stride as usize * 2);
         }
     } else {
         #[cfg(feature = "rayon")]
         {
-            y_iter = y_plane.par_chunks_exact_mut(y_stride as usize);
-            u_iter = u_plane.par_chunks_exact_mut(u_stride as usize);
-            v_iter = v_plane.par_chunks_exact_mut(v_stride as usize);
-            rgb_iter = rgba.par_chunks_exact(rgba_stride as usize);
+            y_iter = y_plane.par_chunks_mut(y_stride as usize);
+            u_iter = u_plane.par_chunks_mut(u_stride as usize);
+            v_iter = v_plane.par_chunks_mut(v_stride as usize);
+            rgb_iter = rgba.par_chunks(rgba_stride as usize);
         }
         #[cfg(not(feature = "rayon"))]
         {
-            y_iter = y_plane.chunks_exact_mut(y_stride as usize);
-            u_iter = u_plane.chunks_exact_mut(u_stride as usize);
-            v_iter = v_plane.chunks_exact_mut(v_stride as usize);
-            rgb_iter = rgba.chunks_exact(rgba_stride as usize);
+            y_iter = y_plane.chunks_mut(y_stride as usize);
+            u_iter = u_plane.chunks_mut(u_stride as usize);
+            v_iter = v_plane.chunks_mut(v_stride as usize);
+            rgb_iter = rgba.chunks(rgba_stride as usize);
         }
     }
 
@@ -443,8 +465,8 @@ fn rgbx_to_sharp_yuv<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
                 let v_y = j * 2;
 
                 for (virtual_y, (y_plane, rgba)) in y_plane
-                    .chunks_exact_mut(y_stride as usize)
-                    .zip(rgba.chunks_exact(rgba_stride as usize))
+                    .chunks_mut(y_stride as usize)
+                    .zip(rgba.chunks(rgba_stride as usize))
                     .enumerate()
                 {
                     let y = virtual_y + v_y;
@@ -491,50 +513,6 @@ fn rgbx_to_sharp_yuv<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
             }
         });
 
-    // Handle last row if image is odd
-    if height & 1 != 0 && chroma_subsampling == YuvChromaSample::YUV420 {
-        let y_iter = y_plane.chunks_exact_mut(y_stride as usize).rev().take(1);
-        let u_iter = u_plane.chunks_exact_mut(u_stride as usize).rev().take(1);
-        let v_iter = v_plane.chunks_exact_mut(v_stride as usize).rev().take(1);
-        let rgb_iter = rgba.chunks_exact(rgba_stride as usize).rev().take(1);
-        let rgb_linearized_iter = rgb_layout
-            .chunks_exact_mut(rgb_layout_stride_len)
-            .rev()
-            .take(1);
-
-        let full_iter = rgb_iter
-            .zip(rgb_linearized_iter)
-            .zip(y_iter)
-            .zip(u_iter)
-            .zip(v_iter);
-
-        full_iter.for_each(|((((rgba, rgb_layout), y_plane), u_plane), v_plane)| {
-            let y = height as usize - 1;
-            let rgb_layout_start = y * rgb_layout_stride_len;
-            let rgb_layout_start_next = (y + 1) * rgb_layout_stride_len;
-            let rgb_layout_lane =
-                &rgb_layout[rgb_layout_start..((width as usize) * 3 + rgb_layout_start)];
-            let rgb_layout_next_lane = if y + 1 < height as usize {
-                &rgb_layout[rgb_layout_start_next..((width as usize) * 3 + rgb_layout_start_next)]
-            } else {
-                rgb_layout_lane
-            };
-            sharpen_row420::<ORIGIN_CHANNELS, SAMPLING, PRECISION>(
-                y,
-                rgba,
-                y_plane,
-                u_plane,
-                v_plane,
-                rgb_layout_lane,
-                rgb_layout_next_lane,
-                &gamma_map_table,
-                &range,
-                &transform,
-                width as usize,
-            );
-        });
-    }
-
     Ok(())
 }
 
@@ -592,6 +570,7 @@ pub fn rgb_to_sharp_yuv422(
         range,
         matrix,
         gamma_transfer,
+        None,
     )
 }
 
@@ -649,6 +628,7 @@ pub fn bgr_to_sharp_yuv422(
         range,
         matrix,
         gamma_transfer,
+        None,
     )
 }
 
@@ -706,6 +686,7 @@ pub fn rgba_to_sharp_yuv422(
         range,
         matrix,
         gamma_transfer,
+        None,
     )
 }
 
@@ -763,6 +744,7 @@ pub fn bgra_to_sharp_yuv422(
         range,
         matrix,
         gamma_transfer,
+        None,
     )
 }
 
@@ -820,6 +802,7 @@ pub fn rgb_to_sharp_yuv420(
         range,
         matrix,
         gamma_transfer,
+        None,
     )
 }
 
@@ -877,6 +860,7 @@ pub fn bgr_to_sharp_yuv420(
         range,
         matrix,
         gamma_transfer,
+        None,
     )
 }
 
@@ -934,6 +918,7 @@ pub fn rgba_to_sharp_yuv420(
         range,
         matrix,
         gamma_transfer,
+        None,
     )
 }
 
@@ -991,5 +976,461 @@ pub fn bgra_to_sharp_yuv420(
         range,
         matrix,
         gamma_transfer,
+        None,
+    )
+}
+
+/// Returns count of bytes [YuvScratchArena] must hold for `*_to_sharp_yuv*_with_scratch` conversions.
+///
+/// Includes worst case alignment padding, so any buffer of this size is enough.
+pub fn sharp_yuv_scratch_size(width: u32, height: u32) -> usize {
+    scratch_bytes::<u16>(width as usize * height as usize * 3)
+}
+
+/// Convert RGB image data to YUV 422 planar format using sharp YUV algorithm and caller provided scratch memory.
+///
+/// Same as [rgb_to_sharp_yuv422] but linearized frame is taken from `scratch` instead of the heap,
+/// see [sharp_yuv_scratch_size] for required arena size.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgb` - The input RGB image data slice.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `gamma_transfer` - Transfer function used to linearize the image.
+/// * `scratch` - Arena to take intermediate buffers from.
+///
+pub fn rgb_to_sharp_yuv422_with_scratch(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    rgb: &[u8],
+    rgb_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    gamma_transfer: SharpYuvGammaTransfer,
+    scratch: &mut YuvScratchArena,
+) -> Result<(), YuvError> {
+    rgbx_to_sharp_yuv::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgb,
+        rgb_stride,
+        width,
+        height,
+        range,
+        matrix,
+        gamma_transfer,
+        Some(scratch),
+    )
+}
+
+/// Convert BGR image data to YUV 422 planar format using sharp YUV algorithm and caller provided scratch memory.
+///
+/// Same as [bgr_to_sharp_yuv422] but linearized frame is taken from `scratch` instead of the heap,
+/// see [sharp_yuv_scratch_size] for required arena size.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgr` - The input BGR image data slice.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `gamma_transfer` - Transfer function used to linearize the image.
+/// * `scratch` - Arena to take intermediate buffers from.
+///
+pub fn bgr_to_sharp_yuv422_with_scratch(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    bgr: &[u8],
+    bgr_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    gamma_transfer: SharpYuvGammaTransfer,
+    scratch: &mut YuvScratchArena,
+) -> Result<(), YuvError> {
+    rgbx_to_sharp_yuv::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgr,
+        bgr_stride,
+        width,
+        height,
+        range,
+        matrix,
+        gamma_transfer,
+        Some(scratch),
+    )
+}
+
+/// Convert RGBA image data to YUV 422 planar format using sharp YUV algorithm and caller provided scratch memory.
+///
+/// Same as [rgba_to_sharp_yuv422] but linearized frame is taken from `scratch` instead of the heap,
+/// see [sharp_yuv_scratch_size] for required arena size.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `gamma_transfer` - Transfer function used to linearize the image.
+/// * `scratch` - Arena to take intermediate buffers from.
+///
+pub fn rgba_to_sharp_yuv422_with_scratch(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    rgba: &[u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    gamma_transfer: SharpYuvGammaTransfer,
+    scratch: &mut YuvScratchArena,
+) -> Result<(), YuvError> {
+    rgbx_to_sharp_yuv::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        gamma_transfer,
+        Some(scratch),
+    )
+}
+
+/// Convert BGRA image data to YUV 422 planar format using sharp YUV algorithm and caller provided scratch memory.
+///
+/// Same as [bgra_to_sharp_yuv422] but linearized frame is taken from `scratch` instead of the heap,
+/// see [sharp_yuv_scratch_size] for required arena size.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `gamma_transfer` - Transfer function used to linearize the image.
+/// * `scratch` - Arena to take intermediate buffers from.
+///
+pub fn bgra_to_sharp_yuv422_with_scratch(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    bgra: &[u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    gamma_transfer: SharpYuvGammaTransfer,
+    scratch: &mut YuvScratchArena,
+) -> Result<(), YuvError> {
+    rgbx_to_sharp_yuv::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+        gamma_transfer,
+        Some(scratch),
+    )
+}
+
+/// Convert RGB image data to YUV 420 planar format using sharp YUV algorithm and caller provided scratch memory.
+///
+/// Same as [rgb_to_sharp_yuv420] but linearized frame is taken from `scratch` instead of the heap,
+/// see [sharp_yuv_scratch_size] for required arena size.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgb` - The input RGB image data slice.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `gamma_transfer` - Transfer function used to linearize the image.
+/// * `scratch` - Arena to take intermediate buffers from.
+///
+pub fn rgb_to_sharp_yuv420_with_scratch(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    rgb: &[u8],
+    rgb_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    gamma_transfer: SharpYuvGammaTransfer,
+    scratch: &mut YuvScratchArena,
+) -> Result<(), YuvError> {
+    rgbx_to_sharp_yuv::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgb,
+        rgb_stride,
+        width,
+        height,
+        range,
+        matrix,
+        gamma_transfer,
+        Some(scratch),
+    )
+}
+
+/// Convert BGR image data to YUV 420 planar format using sharp YUV algorithm and caller provided scratch memory.
+///
+/// Same as [bgr_to_sharp_yuv420] but linearized frame is taken from `scratch` instead of the heap,
+/// see [sharp_yuv_scratch_size] for required arena size.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgr` - The input BGR image data slice.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `gamma_transfer` - Transfer function used to linearize the image.
+/// * `scratch` - Arena to take intermediate buffers from.
+///
+pub fn bgr_to_sharp_yuv420_with_scratch(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    bgr: &[u8],
+    bgr_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    gamma_transfer: SharpYuvGammaTransfer,
+    scratch: &mut YuvScratchArena,
+) -> Result<(), YuvError> {
+    rgbx_to_sharp_yuv::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgr,
+        bgr_stride,
+        width,
+        height,
+        range,
+        matrix,
+        gamma_transfer,
+        Some(scratch),
+    )
+}
+
+/// Convert RGBA image data to YUV 420 planar format using sharp YUV algorithm and caller provided scratch memory.
+///
+/// Same as [rgba_to_sharp_yuv420] but linearized frame is taken from `scratch` instead of the heap,
+/// see [sharp_yuv_scratch_size] for required arena size.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - The input RGBA image data slice.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `gamma_transfer` - Transfer function used to linearize the image.
+/// * `scratch` - Arena to take intermediate buffers from.
+///
+pub fn rgba_to_sharp_yuv420_with_scratch(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    rgba: &[u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    gamma_transfer: SharpYuvGammaTransfer,
+    scratch: &mut YuvScratchArena,
+) -> Result<(), YuvError> {
+    rgbx_to_sharp_yuv::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        gamma_transfer,
+        Some(scratch),
+    )
+}
+
+/// Convert BGRA image data to YUV 420 planar format using sharp YUV algorithm and caller provided scratch memory.
+///
+/// Same as [bgra_to_sharp_yuv420] but linearized frame is taken from `scratch` instead of the heap,
+/// see [sharp_yuv_scratch_size] for required arena size.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra` - The input BGRA image data slice.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the image in pixels.
+/// * `height` - The height of the image in pixels.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `gamma_transfer` - Transfer function used to linearize the image.
+/// * `scratch` - Arena to take intermediate buffers from.
+///
+pub fn bgra_to_sharp_yuv420_with_scratch(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    bgra: &[u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    gamma_transfer: SharpYuvGammaTransfer,
+    scratch: &mut YuvScratchArena,
+) -> Result<(), YuvError> {
+    rgbx_to_sharp_yuv::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+        gamma_transfer,
+        Some(scratch),
     )
 }
@@ -0,0 +1,188 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+use crate::yuv_support::{CbCrForwardTransform, YuvChromaRange, YuvNVOrder, YuvSourceChannels};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[inline(always)]
+unsafe fn sse_div_by_255(v: __m128i) -> __m128i {
+    let v = _mm_add_epi32(v, _mm_set1_epi32(128));
+    _mm_srli_epi32::<8>(_mm_add_epi32(v, _mm_srli_epi32::<8>(v)))
+}
+
+#[inline(always)]
+unsafe fn sse_channel(px: __m128i, offset: usize) -> __m128i {
+    _mm_and_si128(
+        _mm_srl_epi32(px, _mm_cvtsi32_si128(offset as i32 * 8)),
+        _mm_set1_epi32(0xff),
+    )
+}
+
+#[inline(always)]
+unsafe fn sse_load_u8x4(ptr: *const u8) -> __m128i {
+    _mm_cvtepu8_epi32(_mm_cvtsi32_si128((ptr as *const i32).read_unaligned()))
+}
+
+#[inline(always)]
+unsafe fn sse_store_u8x4(ptr: *mut u8, v: __m128i) {
+    let packed = _mm_packus_epi16(_mm_packus_epi32(v, v), v);
+    (ptr as *mut i32).write_unaligned(_mm_cvtsi128_si32(packed));
+}
+
+/// Blends two rows of sprite pixels over NV luma rows and their shared chroma row,
+/// every chroma pair covers two columns of both rows. Returns count of chroma pairs processed
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_blend_rgba_nv_row<const ORIGIN_CHANNELS: u8, const UV_ORDER: u8>(
+    transform: &CbCrForwardTransform<i32>,
+    range: &YuvChromaRange,
+    sprite0: &[u8],
+    sprite1: &[u8],
+    y0: &mut [u8],
+    y1: &mut [u8],
+    uv: &mut [u8],
+    pairs: usize,
+) -> usize {
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let order: YuvNVOrder = UV_ORDER.into();
+
+    const PRECISION: i32 = 8;
+    const ROUNDING_CONST_BIAS: i32 = 1 << (PRECISION - 1);
+    let bias_y = _mm_set1_epi32(range.bias_y as i32 * (1 << PRECISION) + ROUNDING_CONST_BIAS);
+    let bias_uv = _mm_set1_epi32(range.bias_uv as i32 * (1 << PRECISION) + ROUNDING_CONST_BIAS);
+    let min = _mm_set1_epi32(range.bias_y as i32);
+    let cap_y = _mm_set1_epi32(range.bias_y as i32 + range.range_y as i32);
+    let min_uv = _mm_set1_epi32(range.min_uv() as i32);
+    let cap_uv = _mm_set1_epi32(range.max_uv() as i32);
+    let v_255 = _mm_set1_epi32(255);
+
+    let v_yr = _mm_set1_epi32(transform.yr);
+    let v_yg = _mm_set1_epi32(transform.yg);
+    let v_yb = _mm_set1_epi32(transform.yb);
+    let v_cb_r = _mm_set1_epi32(transform.cb_r);
+    let v_cb_g = _mm_set1_epi32(transform.cb_g);
+    let v_cb_b = _mm_set1_epi32(transform.cb_b);
+    let v_cr_r = _mm_set1_epi32(transform.cr_r);
+    let v_cr_g = _mm_set1_epi32(transform.cr_g);
+    let v_cr_b = _mm_set1_epi32(transform.cr_b);
+
+    let sprites = [sprite0.as_ptr(), sprite1.as_ptr()];
+    let y_rows = [y0.as_mut_ptr(), y1.as_mut_ptr()];
+    let uv_ptr = uv.as_mut_ptr();
+
+    let mut cx = 0usize;
+
+    while cx + 2 <= pairs {
+        let uv_values = sse_load_u8x4(uv_ptr.add(cx * 2));
+        // Broadcast chroma of each pair to both of its pixels
+        let (u_px, v_px) = match order {
+            YuvNVOrder::UV => (
+                _mm_shuffle_epi32::<0xA0>(uv_values),
+                _mm_shuffle_epi32::<0xF5>(uv_values),
+            ),
+            YuvNVOrder::VU => (
+                _mm_shuffle_epi32::<0xF5>(uv_values),
+                _mm_shuffle_epi32::<0xA0>(uv_values),
+            ),
+        };
+
+        let mut u_sum = _mm_setzero_si128();
+        let mut v_sum = _mm_setzero_si128();
+
+        for (sprite, y_row) in sprites.iter().zip(y_rows.iter()) {
+            let px = _mm_loadu_si128(sprite.add(cx * 8) as *const __m128i);
+            let r = sse_channel(px, src_chans.get_r_channel_offset());
+            let g = sse_channel(px, src_chans.get_g_channel_offset());
+            let b = sse_channel(px, src_chans.get_b_channel_offset());
+            let a = sse_channel(px, src_chans.get_a_channel_offset());
+            let inv_a = _mm_sub_epi32(v_255, a);
+
+            let ys = _mm_add_epi32(
+                _mm_add_epi32(_mm_mullo_epi32(r, v_yr), _mm_mullo_epi32(g, v_yg)),
+                _mm_add_epi32(_mm_mullo_epi32(b, v_yb), bias_y),
+            );
+            let ys = _mm_min_epi32(_mm_max_epi32(_mm_srai_epi32::<PRECISION>(ys), min), cap_y);
+            let cb = _mm_add_epi32(
+                _mm_add_epi32(_mm_mullo_epi32(r, v_cb_r), _mm_mullo_epi32(g, v_cb_g)),
+                _mm_add_epi32(_mm_mullo_epi32(b, v_cb_b), bias_uv),
+            );
+            let cb = _mm_min_epi32(
+                _mm_max_epi32(_mm_srai_epi32::<PRECISION>(cb), min_uv),
+                cap_uv,
+            );
+            let cr = _mm_add_epi32(
+                _mm_add_epi32(_mm_mullo_epi32(r, v_cr_r), _mm_mullo_epi32(g, v_cr_g)),
+                _mm_add_epi32(_mm_mullo_epi32(b, v_cr_b), bias_uv),
+            );
+            let cr = _mm_min_epi32(
+                _mm_max_epi32(_mm_srai_epi32::<PRECISION>(cr), min_uv),
+                cap_uv,
+            );
+
+            let y_dst = y_row.add(cx * 2);
+            let y_values = sse_load_u8x4(y_dst);
+            let y_blended = sse_div_by_255(_mm_add_epi32(
+                _mm_mullo_epi32(y_values, inv_a),
+                _mm_mullo_epi32(ys, a),
+            ));
+            sse_store_u8x4(y_dst, y_blended);
+
+            u_sum = _mm_add_epi32(
+                u_sum,
+                sse_div_by_255(_mm_add_epi32(
+                    _mm_mullo_epi32(u_px, inv_a),
+                    _mm_mullo_epi32(cb, a),
+                )),
+            );
+            v_sum = _mm_add_epi32(
+                v_sum,
+                sse_div_by_255(_mm_add_epi32(
+                    _mm_mullo_epi32(v_px, inv_a),
+                    _mm_mullo_epi32(cr, a),
+                )),
+            );
+        }
+
+        // Horizontal neighbours are summed here, rows were summed above
+        let rounding = _mm_set1_epi32(2);
+        let u_avg = _mm_srli_epi32::<2>(_mm_add_epi32(_mm_hadd_epi32(u_sum, u_sum), rounding));
+        let v_avg = _mm_srli_epi32::<2>(_mm_add_epi32(_mm_hadd_epi32(v_sum, v_sum), rounding));
+        let interleaved = match order {
+            YuvNVOrder::UV => _mm_unpacklo_epi32(u_avg, v_avg),
+            YuvNVOrder::VU => _mm_unpacklo_epi32(v_avg, u_avg),
+        };
+        sse_store_u8x4(uv_ptr.add(cx * 2), interleaved);
+
+        cx += 2;
+    }
+
+    cx
+}
@@ -0,0 +1,84 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::chroma_key::ChromaKeyParams;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[inline(always)]
+unsafe fn sse_key_alpha_ps(d2: __m128i, tolerance: __m128, scale: __m128) -> __m128i {
+    let distance = _mm_sqrt_ps(_mm_cvtepi32_ps(d2));
+    let alpha = _mm_min_ps(
+        _mm_max_ps(
+            _mm_mul_ps(_mm_sub_ps(distance, tolerance), scale),
+            _mm_setzero_ps(),
+        ),
+        _mm_set1_ps(255.),
+    );
+    _mm_cvttps_epi32(_mm_add_ps(alpha, _mm_set1_ps(0.5)))
+}
+
+/// Computes chroma key alpha for a row of chroma samples, returns count of samples processed
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_chroma_key_alpha_row(
+    params: &ChromaKeyParams,
+    u_plane: &[u8],
+    v_plane: &[u8],
+    alpha: &mut [u8],
+    width: usize,
+) -> usize {
+    let key_u = _mm_set1_epi16(params.key_u);
+    let key_v = _mm_set1_epi16(params.key_v);
+    let tolerance = _mm_set1_ps(params.tolerance);
+    let scale = _mm_set1_ps(params.scale);
+
+    let mut cx = 0usize;
+
+    while cx + 8 <= width {
+        let u = _mm_cvtepu8_epi16(_mm_loadu_si64(u_plane.as_ptr().add(cx)));
+        let v = _mm_cvtepu8_epi16(_mm_loadu_si64(v_plane.as_ptr().add(cx)));
+        let du = _mm_sub_epi16(u, key_u);
+        let dv = _mm_sub_epi16(v, key_v);
+
+        let lo = _mm_unpacklo_epi16(du, dv);
+        let hi = _mm_unpackhi_epi16(du, dv);
+        let d2_lo = _mm_madd_epi16(lo, lo);
+        let d2_hi = _mm_madd_epi16(hi, hi);
+
+        let a_lo = sse_key_alpha_ps(d2_lo, tolerance, scale);
+        let a_hi = sse_key_alpha_ps(d2_hi, tolerance, scale);
+        let packed = _mm_packus_epi16(_mm_packus_epi32(a_lo, a_hi), _mm_setzero_si128());
+        _mm_storel_epi64(alpha.as_mut_ptr().add(cx) as *mut __m128i, packed);
+
+        cx += 8;
+    }
+
+    cx
+}
@@ -0,0 +1,161 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Byte shuffle grouping samples of each chroma channel together, so adjacent lanes
+/// hold the same channel before they are summed pairwise
+#[inline(always)]
+unsafe fn deinterleave_u8_shuffle<const FACTOR: usize>() -> __m128i {
+    if FACTOR == 2 {
+        _mm_setr_epi8(0, 2, 1, 3, 4, 6, 5, 7, 8, 10, 9, 11, 12, 14, 13, 15)
+    } else {
+        _mm_setr_epi8(0, 2, 4, 6, 1, 3, 5, 7, 8, 10, 12, 14, 9, 11, 13, 15)
+    }
+}
+
+#[inline(always)]
+unsafe fn deinterleave_u16_shuffle<const FACTOR: usize>() -> __m128i {
+    if FACTOR == 2 {
+        _mm_setr_epi8(0, 1, 4, 5, 2, 3, 6, 7, 8, 9, 12, 13, 10, 11, 14, 15)
+    } else {
+        _mm_setr_epi8(0, 1, 4, 5, 8, 9, 12, 13, 2, 3, 6, 7, 10, 11, 14, 15)
+    }
+}
+
+/// Averages `FACTOR`x`FACTOR` blocks of `FACTOR` source rows into a destination row,
+/// `CHANNELS` is 2 for interleaved chroma, returns count of destination samples processed
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_decimate_row<const FACTOR: usize, const CHANNELS: usize>(
+    rows: &[&[u8]; FACTOR],
+    dst: &mut [u8],
+    length: usize,
+) -> usize {
+    let ones = _mm_set1_epi8(1);
+    let rounding = _mm_set1_epi16((FACTOR * FACTOR / 2) as i16);
+    let shuffle = deinterleave_u8_shuffle::<FACTOR>();
+
+    let dst_ptr = dst.as_mut_ptr();
+
+    let mut cx = 0usize;
+
+    while (cx + 16) * FACTOR <= length {
+        let src_x = cx * FACTOR;
+        let mut sums = [_mm_setzero_si128(); FACTOR];
+        for row in rows.iter() {
+            for (i, sum) in sums.iter_mut().enumerate() {
+                let mut values =
+                    _mm_loadu_si128(row.as_ptr().add(src_x + i * 16) as *const __m128i);
+                if CHANNELS == 2 {
+                    values = _mm_shuffle_epi8(values, shuffle);
+                }
+                *sum = _mm_add_epi16(*sum, _mm_maddubs_epi16(values, ones));
+            }
+        }
+
+        let packed = if FACTOR == 2 {
+            let lo = _mm_srli_epi16::<2>(_mm_add_epi16(sums[0], rounding));
+            let hi = _mm_srli_epi16::<2>(_mm_add_epi16(sums[1], rounding));
+            _mm_packus_epi16(lo, hi)
+        } else {
+            let lo = _mm_hadd_epi16(sums[0], sums[1]);
+            let hi = _mm_hadd_epi16(sums[FACTOR - 2], sums[FACTOR - 1]);
+            let lo = _mm_srli_epi16::<4>(_mm_add_epi16(lo, rounding));
+            let hi = _mm_srli_epi16::<4>(_mm_add_epi16(hi, rounding));
+            _mm_packus_epi16(lo, hi)
+        };
+        _mm_storeu_si128(dst_ptr.add(cx) as *mut __m128i, packed);
+
+        cx += 16;
+    }
+
+    cx
+}
+
+/// Averages `FACTOR`x`FACTOR` blocks of 16-bit samples, see [sse_decimate_row].
+///
+/// Samples are shifted right by `shift` before summing and the result is shifted back,
+/// so MSB aligned samples are averaged on their significant bits only.
+/// Significant bits must not exceed 15.
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_decimate_row_u16<const FACTOR: usize, const CHANNELS: usize>(
+    rows: &[&[u16]; FACTOR],
+    dst: &mut [u16],
+    length: usize,
+    shift: u32,
+) -> usize {
+    let ones = _mm_set1_epi16(1);
+    let rounding = _mm_set1_epi32((FACTOR * FACTOR / 2) as i32);
+    let shuffle = deinterleave_u16_shuffle::<FACTOR>();
+    let v_shift = _mm_cvtsi32_si128(shift as i32);
+
+    let dst_ptr = dst.as_mut_ptr();
+
+    let mut cx = 0usize;
+
+    while (cx + 8) * FACTOR <= length {
+        let src_x = cx * FACTOR;
+        let mut sums = [_mm_setzero_si128(); FACTOR];
+        for row in rows.iter() {
+            for (i, sum) in sums.iter_mut().enumerate() {
+                let mut values = _mm_srl_epi16(
+                    _mm_loadu_si128(row.as_ptr().add(src_x + i * 8) as *const __m128i),
+                    v_shift,
+                );
+                if CHANNELS == 2 {
+                    values = _mm_shuffle_epi8(values, shuffle);
+                }
+                *sum = _mm_add_epi32(*sum, _mm_madd_epi16(values, ones));
+            }
+        }
+
+        let packed = if FACTOR == 2 {
+            let lo = _mm_srli_epi32::<2>(_mm_add_epi32(sums[0], rounding));
+            let hi = _mm_srli_epi32::<2>(_mm_add_epi32(sums[1], rounding));
+            _mm_packus_epi32(lo, hi)
+        } else {
+            let lo = _mm_hadd_epi32(sums[0], sums[1]);
+            let hi = _mm_hadd_epi32(sums[FACTOR - 2], sums[FACTOR - 1]);
+            let lo = _mm_srli_epi32::<4>(_mm_add_epi32(lo, rounding));
+            let hi = _mm_srli_epi32::<4>(_mm_add_epi32(hi, rounding));
+            _mm_packus_epi32(lo, hi)
+        };
+        _mm_storeu_si128(
+            dst_ptr.add(cx) as *mut __m128i,
+            _mm_sll_epi16(packed, v_shift),
+        );
+
+        cx += 8;
+    }
+
+    cx
+}
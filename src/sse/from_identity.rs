@@ -72,14 +72,14 @@ pub unsafe fn gbr_to_image_sse<const DESTINATION_CHANNELS: u8>(
                 _mm_storeu_si128(rgb_start_ptr.add(16) as *mut __m128i, new_pixel.1);
                 _mm_storeu_si128(rgb_start_ptr.add(32) as *mut __m128i, new_pixel.2);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let new_pixel = sse_interleave_rgba(r_pixel, g_pixel, b_pixel, default_alpha);
                 _mm_storeu_si128(rgb_start_ptr as *mut __m128i, new_pixel.0);
                 _mm_storeu_si128(rgb_start_ptr.add(16) as *mut __m128i, new_pixel.1);
                 _mm_storeu_si128(rgb_start_ptr.add(32) as *mut __m128i, new_pixel.2);
                 _mm_storeu_si128(rgb_start_ptr.add(48) as *mut __m128i, new_pixel.3);
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let new_pixel = sse_interleave_rgba(b_pixel, g_pixel, r_pixel, default_alpha);
                 _mm_storeu_si128(rgb_start_ptr as *mut __m128i, new_pixel.0);
                 _mm_storeu_si128(rgb_start_ptr.add(16) as *mut __m128i, new_pixel.1);
@@ -121,12 +121,12 @@ pub unsafe fn gbr_to_image_sse<const DESTINATION_CHANNELS: u8>(
                     8,
                 );
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let new_pixel = sse_interleave_rgba(r_pixel, g_pixel, b_pixel, default_alpha);
                 _mm_storeu_si128(rgb_start_ptr as *mut __m128i, new_pixel.0);
                 _mm_storeu_si128(rgb_start_ptr.add(16) as *mut __m128i, new_pixel.1);
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let new_pixel = sse_interleave_rgba(b_pixel, g_pixel, r_pixel, default_alpha);
                 _mm_storeu_si128(rgb_start_ptr as *mut __m128i, new_pixel.0);
                 _mm_storeu_si128(rgb_start_ptr.add(16) as *mut __m128i, new_pixel.1);
@@ -71,14 +71,14 @@ pub unsafe fn gbr_to_image_sse_p16<const DESTINATION_CHANNELS: u8>(
                 _mm_storeu_si128(rgb_start_ptr.add(8) as *mut __m128i, new_pixel.1);
                 _mm_storeu_si128(rgb_start_ptr.add(16) as *mut __m128i, new_pixel.2);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let new_pixel = _mm_interleave_rgba_epi16(r_pixel, g_pixel, b_pixel, default_alpha);
                 _mm_storeu_si128(rgb_start_ptr as *mut __m128i, new_pixel.0);
                 _mm_storeu_si128(rgb_start_ptr.add(8) as *mut __m128i, new_pixel.1);
                 _mm_storeu_si128(rgb_start_ptr.add(16) as *mut __m128i, new_pixel.2);
                 _mm_storeu_si128(rgb_start_ptr.add(24) as *mut __m128i, new_pixel.3);
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let new_pixel = _mm_interleave_rgba_epi16(b_pixel, g_pixel, r_pixel, default_alpha);
                 _mm_storeu_si128(rgb_start_ptr as *mut __m128i, new_pixel.0);
                 _mm_storeu_si128(rgb_start_ptr.add(8) as *mut __m128i, new_pixel.1);
@@ -120,12 +120,12 @@ pub unsafe fn gbr_to_image_sse_p16<const DESTINATION_CHANNELS: u8>(
                     8,
                 );
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let new_pixel = _mm_interleave_rgba_epi16(r_pixel, g_pixel, b_pixel, default_alpha);
                 _mm_storeu_si128(rgb_start_ptr as *mut __m128i, new_pixel.0);
                 _mm_storeu_si128(rgb_start_ptr.add(8) as *mut __m128i, new_pixel.1);
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let new_pixel = _mm_interleave_rgba_epi16(b_pixel, g_pixel, r_pixel, default_alpha);
                 _mm_storeu_si128(rgb_start_ptr as *mut __m128i, new_pixel.0);
                 _mm_storeu_si128(rgb_start_ptr.add(8) as *mut __m128i, new_pixel.1);
@@ -0,0 +1,133 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Rotates Cb and Cr rows in place 16 samples at a time, returns count of samples processed.
+///
+/// `cos` and `sin` are Q15, every product is rounded on its own exactly as the scalar path does.
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_rotate_hue_row(
+    u_row: &mut [u8],
+    v_row: &mut [u8],
+    width: usize,
+    cos: i16,
+    sin: i16,
+) -> usize {
+    let zeros = _mm_setzero_si128();
+    let bias = _mm_set1_epi16(128);
+    let v_cos = _mm_set1_epi16(cos);
+    let v_sin = _mm_set1_epi16(sin);
+    let v_neg_sin = _mm_set1_epi16(-sin);
+
+    let mut cx = 0usize;
+
+    while cx + 16 <= width {
+        let u_values = _mm_loadu_si128(u_row.as_ptr().add(cx) as *const __m128i);
+        let v_values = _mm_loadu_si128(v_row.as_ptr().add(cx) as *const __m128i);
+        let u_lo = _mm_sub_epi16(_mm_unpacklo_epi8(u_values, zeros), bias);
+        let u_hi = _mm_sub_epi16(_mm_unpackhi_epi8(u_values, zeros), bias);
+        let v_lo = _mm_sub_epi16(_mm_unpacklo_epi8(v_values, zeros), bias);
+        let v_hi = _mm_sub_epi16(_mm_unpackhi_epi8(v_values, zeros), bias);
+
+        let rotated_u_lo = _mm_add_epi16(
+            _mm_mulhrs_epi16(u_lo, v_cos),
+            _mm_mulhrs_epi16(v_lo, v_neg_sin),
+        );
+        let rotated_u_hi = _mm_add_epi16(
+            _mm_mulhrs_epi16(u_hi, v_cos),
+            _mm_mulhrs_epi16(v_hi, v_neg_sin),
+        );
+        let rotated_v_lo =
+            _mm_add_epi16(_mm_mulhrs_epi16(v_lo, v_cos), _mm_mulhrs_epi16(u_lo, v_sin));
+        let rotated_v_hi =
+            _mm_add_epi16(_mm_mulhrs_epi16(v_hi, v_cos), _mm_mulhrs_epi16(u_hi, v_sin));
+
+        let u_packed = _mm_packus_epi16(
+            _mm_add_epi16(rotated_u_lo, bias),
+            _mm_add_epi16(rotated_u_hi, bias),
+        );
+        let v_packed = _mm_packus_epi16(
+            _mm_add_epi16(rotated_v_lo, bias),
+            _mm_add_epi16(rotated_v_hi, bias),
+        );
+        _mm_storeu_si128(u_row.as_mut_ptr().add(cx) as *mut __m128i, u_packed);
+        _mm_storeu_si128(v_row.as_mut_ptr().add(cx) as *mut __m128i, v_packed);
+        cx += 16;
+    }
+
+    cx
+}
+
+/// Rotates interleaved chroma pairs in place 8 pairs at a time, returns count of bytes processed.
+///
+/// First sample of a pair is taken as Cb, callers flip `sin` for Cr first order.
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_rotate_hue_interleaved_row(
+    uv_row: &mut [u8],
+    length: usize,
+    cos: i16,
+    sin: i16,
+) -> usize {
+    let zeros = _mm_setzero_si128();
+    let bias = _mm_set1_epi16(128);
+    let v_cos = _mm_set1_epi16(cos);
+    // Pair partners are swapped, so the first of a pair takes -sin and the second +sin
+    let v_sin = _mm_setr_epi16(-sin, sin, -sin, sin, -sin, sin, -sin, sin);
+
+    let mut cx = 0usize;
+
+    while cx + 16 <= length {
+        let values = _mm_loadu_si128(uv_row.as_ptr().add(cx) as *const __m128i);
+        let lo = _mm_sub_epi16(_mm_unpacklo_epi8(values, zeros), bias);
+        let hi = _mm_sub_epi16(_mm_unpackhi_epi8(values, zeros), bias);
+        let swapped_lo =
+            _mm_shufflehi_epi16::<0b10_11_00_01>(_mm_shufflelo_epi16::<0b10_11_00_01>(lo));
+        let swapped_hi =
+            _mm_shufflehi_epi16::<0b10_11_00_01>(_mm_shufflelo_epi16::<0b10_11_00_01>(hi));
+        let rotated_lo = _mm_add_epi16(
+            _mm_mulhrs_epi16(lo, v_cos),
+            _mm_mulhrs_epi16(swapped_lo, v_sin),
+        );
+        let rotated_hi = _mm_add_epi16(
+            _mm_mulhrs_epi16(hi, v_cos),
+            _mm_mulhrs_epi16(swapped_hi, v_sin),
+        );
+        let packed = _mm_packus_epi16(
+            _mm_add_epi16(rotated_lo, bias),
+            _mm_add_epi16(rotated_hi, bias),
+        );
+        _mm_storeu_si128(uv_row.as_mut_ptr().add(cx) as *mut __m128i, packed);
+        cx += 16;
+    }
+
+    cx
+}
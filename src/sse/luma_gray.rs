@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Expands a row of limited range luma to full range gray 16 pixels at a time,
+/// `scale` is `255 / range` in Q14, returns count of pixels processed.
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_expand_luma_row(
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+    bias: i16,
+    scale: i16,
+) -> usize {
+    let v_bias = _mm_set1_epi16(bias);
+    let v_scale = _mm_set1_epi16(scale);
+
+    let mut cx = 0usize;
+
+    while cx + 16 <= width {
+        let values = _mm_loadu_si128(src.as_ptr().add(cx) as *const __m128i);
+        let lo = _mm_sub_epi16(_mm_cvtepu8_epi16(values), v_bias);
+        let hi = _mm_sub_epi16(_mm_unpackhi_epi8(values, _mm_setzero_si128()), v_bias);
+        // Doubling turns Q15 rounding multiply high into Q14 one
+        let lo = _mm_mulhrs_epi16(_mm_slli_epi16::<1>(lo), v_scale);
+        let hi = _mm_mulhrs_epi16(_mm_slli_epi16::<1>(hi), v_scale);
+        _mm_storeu_si128(
+            dst.as_mut_ptr().add(cx) as *mut __m128i,
+            _mm_packus_epi16(lo, hi),
+        );
+        cx += 16;
+    }
+
+    cx
+}
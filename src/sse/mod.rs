@@ -26,8 +26,17 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+mod blend_nv;
+mod chroma_key;
+mod decimate;
 mod from_identity;
 mod from_identity_p16;
+mod hue_rotation;
+mod luma_gray;
+mod lut3d;
+mod plane_depth;
+mod pyramid;
+mod reorder_channels;
 mod rgb_to_nv;
 mod rgb_to_y;
 mod rgb_to_ycgco;
@@ -38,6 +47,7 @@ pub(crate) mod sse_support;
 mod sse_ycbcr;
 mod sse_ycgco_r;
 mod to_identity;
+mod v410;
 mod ycgco_to_rgb;
 mod ycgco_to_rgb_alpha;
 mod ycgcor_to_rgb;
@@ -45,12 +55,22 @@ mod yuv_nv_p16_to_rgb;
 mod yuv_nv_to_rgba;
 mod yuv_to_rgba;
 mod yuv_to_rgba_alpha;
+mod yuv_to_rgba_f32;
 mod yuv_to_yuy2;
 mod yuy2_to_rgb;
 mod yuy2_to_yuv;
 
+pub use blend_nv::sse_blend_rgba_nv_row;
+pub use chroma_key::sse_chroma_key_alpha_row;
+pub use decimate::{sse_decimate_row, sse_decimate_row_u16};
 pub use from_identity::gbr_to_image_sse;
 pub use from_identity_p16::gbr_to_image_sse_p16;
+pub use hue_rotation::{sse_rotate_hue_interleaved_row, sse_rotate_hue_row};
+pub use luma_gray::sse_expand_luma_row;
+pub use lut3d::sse_lut3d_row;
+pub use plane_depth::{sse_plane_u16_to_u8_row, sse_plane_u8_to_u16_row};
+pub use pyramid::sse_box_decimate_row;
+pub use reorder_channels::sse_reorder_channels_row;
 pub use rgb_to_nv::sse_rgba_to_nv_row;
 pub use rgb_to_y::sse_rgb_to_y;
 pub use rgb_to_ycgco::sse_rgb_to_ycgco_row;
@@ -59,6 +79,7 @@ pub use rgb_to_yuv_p16::sse_rgba_to_yuv_p16;
 pub use rgba_to_yuv::sse_rgba_to_yuv_row;
 pub(crate) use sse_support::*;
 pub use to_identity::image_to_gbr_sse;
+pub use v410::{sse_v410_to_yuv444_row, sse_yuv444_to_v410_row};
 pub use ycgco_to_rgb::sse_ycgco_to_rgb_row;
 pub use ycgco_to_rgb_alpha::sse_ycgco_to_rgb_alpha_row;
 pub use ycgcor_to_rgb::sse_ycgcor_type_to_rgb_row;
@@ -66,6 +87,7 @@ pub use yuv_nv_p16_to_rgb::sse_yuv_nv_p16_to_rgba_row;
 pub use yuv_nv_to_rgba::sse_yuv_nv_to_rgba;
 pub use yuv_to_rgba::sse_yuv_to_rgba_row;
 pub use yuv_to_rgba_alpha::sse_yuv_to_rgba_alpha_row;
+pub use yuv_to_rgba_f32::sse_yuv_to_rgba_f32_row;
 pub use yuv_to_yuy2::yuv_to_yuy2_sse_impl;
 pub use yuy2_to_rgb::yuy2_to_rgb_sse;
 pub use yuy2_to_yuv::yuy2_to_yuv_sse_impl;
@@ -0,0 +1,94 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Widens a row of 8-bit samples to 16 bits shifted left by `shift` 16 samples at a time,
+/// returns count of samples processed.
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_plane_u8_to_u16_row(
+    src: &[u8],
+    dst: &mut [u16],
+    width: usize,
+    shift: u32,
+) -> usize {
+    let count = _mm_cvtsi32_si128(shift as i32);
+    let zeros = _mm_setzero_si128();
+
+    let mut cx = 0usize;
+
+    while cx + 16 <= width {
+        let values = _mm_loadu_si128(src.as_ptr().add(cx) as *const __m128i);
+        let lo = _mm_sll_epi16(_mm_unpacklo_epi8(values, zeros), count);
+        let hi = _mm_sll_epi16(_mm_unpackhi_epi8(values, zeros), count);
+        _mm_storeu_si128(dst.as_mut_ptr().add(cx) as *mut __m128i, lo);
+        _mm_storeu_si128(dst.as_mut_ptr().add(cx + 8) as *mut __m128i, hi);
+        cx += 16;
+    }
+
+    cx
+}
+
+/// Narrows a row of 16-bit samples to 8 bits shifted right by `shift` with rounding
+/// and saturation 16 samples at a time, returns count of samples processed.
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_plane_u16_to_u8_row(
+    src: &[u16],
+    dst: &mut [u8],
+    width: usize,
+    shift: u32,
+) -> usize {
+    let count = _mm_cvtsi32_si128(shift as i32);
+    // Rounding bit is added after the shift, so the sum never overflows 16 bits
+    let round_count = _mm_cvtsi32_si128(shift.saturating_sub(1) as i32);
+    let round_mask = _mm_set1_epi16(if shift == 0 { 0 } else { 1 });
+    let max_value = _mm_set1_epi16(255);
+
+    let mut cx = 0usize;
+
+    while cx + 16 <= width {
+        let lo = _mm_loadu_si128(src.as_ptr().add(cx) as *const __m128i);
+        let hi = _mm_loadu_si128(src.as_ptr().add(cx + 8) as *const __m128i);
+        let lo = _mm_add_epi16(
+            _mm_srl_epi16(lo, count),
+            _mm_and_si128(_mm_srl_epi16(lo, round_count), round_mask),
+        );
+        let hi = _mm_add_epi16(
+            _mm_srl_epi16(hi, count),
+            _mm_and_si128(_mm_srl_epi16(hi, round_count), round_mask),
+        );
+        let packed = _mm_packus_epi16(_mm_min_epu16(lo, max_value), _mm_min_epu16(hi, max_value));
+        _mm_storeu_si128(dst.as_mut_ptr().add(cx) as *mut __m128i, packed);
+        cx += 16;
+    }
+
+    cx
+}
@@ -0,0 +1,78 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Averages 2x2 blocks of two source rows into a destination row,
+/// returns count of destination pixels processed
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_box_decimate_row(
+    row0: &[u8],
+    row1: &[u8],
+    dst: &mut [u8],
+    src_width: usize,
+) -> usize {
+    let ones = _mm_set1_epi8(1);
+    let rounding = _mm_set1_epi16(2);
+
+    let row0_ptr = row0.as_ptr();
+    let row1_ptr = row1.as_ptr();
+    let dst_ptr = dst.as_mut_ptr();
+
+    let mut cx = 0usize;
+
+    while (cx + 16) * 2 <= src_width {
+        let src_x = cx * 2;
+        let top0 = _mm_loadu_si128(row0_ptr.add(src_x) as *const __m128i);
+        let top1 = _mm_loadu_si128(row0_ptr.add(src_x + 16) as *const __m128i);
+        let bottom0 = _mm_loadu_si128(row1_ptr.add(src_x) as *const __m128i);
+        let bottom1 = _mm_loadu_si128(row1_ptr.add(src_x + 16) as *const __m128i);
+
+        let sum_lo = _mm_add_epi16(
+            _mm_maddubs_epi16(top0, ones),
+            _mm_maddubs_epi16(bottom0, ones),
+        );
+        let sum_hi = _mm_add_epi16(
+            _mm_maddubs_epi16(top1, ones),
+            _mm_maddubs_epi16(bottom1, ones),
+        );
+
+        let lo = _mm_srli_epi16::<2>(_mm_add_epi16(sum_lo, rounding));
+        let hi = _mm_srli_epi16::<2>(_mm_add_epi16(sum_hi, rounding));
+
+        _mm_storeu_si128(dst_ptr.add(cx) as *mut __m128i, _mm_packus_epi16(lo, hi));
+
+        cx += 16;
+    }
+
+    cx
+}
@@ -0,0 +1,64 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::reorder_channels::ChannelShuffle;
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Reorders channels of a row 4 pixels at a time, returns count of pixels processed.
+///
+/// Every step loads and stores whole 16 bytes, bytes past the 4th pixel of 3 channel
+/// destination are rewritten by the next step or by the scalar tail.
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_reorder_channels_row(
+    shuffle: &ChannelShuffle,
+    src: &[u8],
+    dst: &mut [u8],
+    width: usize,
+) -> usize {
+    let mask = _mm_loadu_si128(shuffle.shuffle.as_ptr() as *const __m128i);
+    let fill = _mm_loadu_si128(shuffle.fill.as_ptr() as *const __m128i);
+    let src_row = width * shuffle.src_channels;
+    let dst_row = width * shuffle.dst_channels;
+
+    let mut cx = 0usize;
+
+    while cx * shuffle.src_channels + 16 <= src_row && cx * shuffle.dst_channels + 16 <= dst_row {
+        let pixels = _mm_loadu_si128(src.as_ptr().add(cx * shuffle.src_channels) as *const __m128i);
+        let reordered = _mm_or_si128(_mm_shuffle_epi8(pixels, mask), fill);
+        _mm_storeu_si128(
+            dst.as_mut_ptr().add(cx * shuffle.dst_channels) as *mut __m128i,
+            reordered,
+        );
+        cx += 4;
+    }
+
+    cx
+}
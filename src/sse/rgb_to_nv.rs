@@ -112,7 +112,10 @@ pub unsafe fn sse_rgba_to_nv_row<
                     b_values = it1;
                 }
             }
-            YuvSourceChannels::Rgba | YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Rgba
+            | YuvSourceChannels::Bgra
+            | YuvSourceChannels::Rgbx
+            | YuvSourceChannels::Bgrx => {
                 let row_start = rgba_ptr.add(px);
                 let row_1 = _mm_loadu_si128(row_start as *const __m128i);
                 let row_2 = _mm_loadu_si128(row_start.add(16) as *const __m128i);
@@ -120,7 +123,10 @@ pub unsafe fn sse_rgba_to_nv_row<
                 let row_4 = _mm_loadu_si128(row_start.add(48) as *const __m128i);
 
                 let (it1, it2, it3, _) = sse_deinterleave_rgba(row_1, row_2, row_3, row_4);
-                if source_channels == YuvSourceChannels::Rgba {
+                if matches!(
+                    source_channels,
+                    YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx
+                ) {
                     r_values = it1;
                     g_values = it2;
                     b_values = it3;
@@ -211,13 +217,19 @@ pub unsafe fn sse_rgba_to_nv_row<
                     b_values = it1;
                 }
             }
-            YuvSourceChannels::Rgba | YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Rgba
+            | YuvSourceChannels::Bgra
+            | YuvSourceChannels::Rgbx
+            | YuvSourceChannels::Bgrx => {
                 let row_start = rgba_ptr.add(px);
                 let row_1 = _mm_loadu_si128(row_start as *const __m128i);
                 let row_2 = _mm_loadu_si128(row_start.add(16) as *const __m128i);
 
                 let (it1, it2, it3, _) = sse_deinterleave_rgba(row_1, row_2, zeros, zeros);
-                if source_channels == YuvSourceChannels::Rgba {
+                if matches!(
+                    source_channels,
+                    YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx
+                ) {
                     r_values = it1;
                     g_values = it2;
                     b_values = it3;
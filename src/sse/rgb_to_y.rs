@@ -87,7 +87,10 @@ pub unsafe fn sse_rgb_to_y<const ORIGIN_CHANNELS: u8>(
                     b_values = it1;
                 }
             }
-            YuvSourceChannels::Rgba | YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Rgba
+            | YuvSourceChannels::Bgra
+            | YuvSourceChannels::Rgbx
+            | YuvSourceChannels::Bgrx => {
                 let source_ptr = rgba_ptr.add(px);
                 let row_1 = _mm_loadu_si128(source_ptr as *const __m128i);
                 let row_2 = _mm_loadu_si128(source_ptr.add(16) as *const __m128i);
@@ -95,7 +98,10 @@ pub unsafe fn sse_rgb_to_y<const ORIGIN_CHANNELS: u8>(
                 let row_4 = _mm_loadu_si128(source_ptr.add(48) as *const __m128i);
 
                 let (it1, it2, it3, _) = sse_deinterleave_rgba(row_1, row_2, row_3, row_4);
-                if source_channels == YuvSourceChannels::Rgba {
+                if matches!(
+                    source_channels,
+                    YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx
+                ) {
                     r_values = it1;
                     g_values = it2;
                     b_values = it3;
@@ -116,14 +116,14 @@ pub unsafe fn sse_rgba_to_yuv_p16<
                     b_values = rgb_values.0;
                 }
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let row3 = _mm_loadu_si128(src_ptr.add(24) as *const __m128i);
                 let rgb_values = _mm_deinterleave_rgba_epi16(row0, row1, row2, row3);
                 r_values = rgb_values.0;
                 g_values = rgb_values.1;
                 b_values = rgb_values.2;
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let row3 = _mm_loadu_si128(src_ptr.add(24) as *const __m128i);
                 let rgb_values = _mm_deinterleave_rgba_epi16(row0, row1, row2, row3);
                 r_values = rgb_values.2;
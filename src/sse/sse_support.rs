@@ -87,6 +87,28 @@ pub unsafe fn sse_store_rgba_half_epi8(
     _mm_storeu_si128(ptr.add(16) as *mut __m128i, row2);
 }
 
+/// Stores 16 RGBX pixels keeping the 4th byte of every destination pixel is masked out and never written
+#[inline]
+pub unsafe fn sse_store_rgbx(ptr: *mut u8, r: __m128i, g: __m128i, b: __m128i) {
+    let (row1, row2, row3, row4) = sse_interleave_rgba(r, g, b, _mm_setzero_si128());
+    let mask = _mm_set1_epi32(0x00ff_ffff);
+    let dst = ptr as *mut i8;
+    _mm_maskmoveu_si128(row1, mask, dst);
+    _mm_maskmoveu_si128(row2, mask, dst.add(16));
+    _mm_maskmoveu_si128(row3, mask, dst.add(32));
+    _mm_maskmoveu_si128(row4, mask, dst.add(48));
+}
+
+/// Stores 8 RGBX pixels keeping the 4th byte of every destination pixel is masked out and never written
+#[inline]
+pub unsafe fn sse_store_rgbx_half_epi8(ptr: *mut u8, r: __m128i, g: __m128i, b: __m128i) {
+    let (row1, row2, _, _) = sse_interleave_rgba(r, g, b, _mm_setzero_si128());
+    let mask = _mm_set1_epi32(0x00ff_ffff);
+    let dst = ptr as *mut i8;
+    _mm_maskmoveu_si128(row1, mask, dst);
+    _mm_maskmoveu_si128(row2, mask, dst.add(16));
+}
+
 #[inline]
 pub unsafe fn sse_deinterleave_rgba(
     rgba0: __m128i,
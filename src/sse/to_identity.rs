@@ -70,14 +70,14 @@ pub unsafe fn image_to_gbr_sse<const SOURCE_CHANNELS: u8>(
                 g_pixel = rgb_pixel.1;
                 b_pixel = rgb_pixel.2;
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let row3 = _mm_loadu_si128(rgb_start_ptr.add(48) as *const __m128i);
                 let rgb_pixel = sse_deinterleave_rgba(row0, row1, row2, row3);
                 r_pixel = rgb_pixel.0;
                 g_pixel = rgb_pixel.1;
                 b_pixel = rgb_pixel.2;
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let row3 = _mm_loadu_si128(rgb_start_ptr.add(48) as *const __m128i);
                 let rgb_pixel = sse_deinterleave_rgba(row0, row1, row2, row3);
                 r_pixel = rgb_pixel.2;
@@ -118,14 +118,14 @@ pub unsafe fn image_to_gbr_sse<const SOURCE_CHANNELS: u8>(
                 g_pixel = rgb_pixel.1;
                 b_pixel = rgb_pixel.2;
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let row1 = _mm_loadu_si128(rgb_start_ptr.add(16) as *const __m128i);
                 let rgb_pixel = sse_deinterleave_rgba(row0, row1, row_zeros, row_zeros);
                 r_pixel = rgb_pixel.0;
                 g_pixel = rgb_pixel.1;
                 b_pixel = rgb_pixel.2;
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let row1 = _mm_loadu_si128(rgb_start_ptr.add(16) as *const __m128i);
                 let rgb_pixel = sse_deinterleave_rgba(row0, row1, row_zeros, row_zeros);
                 r_pixel = rgb_pixel.2;
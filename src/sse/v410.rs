@@ -0,0 +1,124 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Splits a row of v410 words into Y, U and V samples 8 pixels at a time,
+/// returns count of pixels processed.
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_v410_to_yuv444_row(
+    v410: &[u32],
+    y_plane: &mut [u16],
+    u_plane: &mut [u16],
+    v_plane: &mut [u16],
+    width: usize,
+) -> usize {
+    let mask = _mm_set1_epi32(0x3ff);
+
+    let mut cx = 0usize;
+
+    while cx + 8 <= width {
+        let lo = _mm_loadu_si128(v410.as_ptr().add(cx) as *const __m128i);
+        let hi = _mm_loadu_si128(v410.as_ptr().add(cx + 4) as *const __m128i);
+
+        let u_values = _mm_packus_epi32(
+            _mm_and_si128(_mm_srli_epi32::<2>(lo), mask),
+            _mm_and_si128(_mm_srli_epi32::<2>(hi), mask),
+        );
+        let y_values = _mm_packus_epi32(
+            _mm_and_si128(_mm_srli_epi32::<12>(lo), mask),
+            _mm_and_si128(_mm_srli_epi32::<12>(hi), mask),
+        );
+        let v_values = _mm_packus_epi32(_mm_srli_epi32::<22>(lo), _mm_srli_epi32::<22>(hi));
+
+        _mm_storeu_si128(y_plane.as_mut_ptr().add(cx) as *mut __m128i, y_values);
+        _mm_storeu_si128(u_plane.as_mut_ptr().add(cx) as *mut __m128i, u_values);
+        _mm_storeu_si128(v_plane.as_mut_ptr().add(cx) as *mut __m128i, v_values);
+
+        cx += 8;
+    }
+
+    cx
+}
+
+/// Packs Y, U and V samples into a row of v410 words 8 pixels at a time,
+/// returns count of pixels processed.
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_yuv444_to_v410_row(
+    y_plane: &[u16],
+    u_plane: &[u16],
+    v_plane: &[u16],
+    v410: &mut [u32],
+    width: usize,
+) -> usize {
+    let mask = _mm_set1_epi16(0x3ff);
+
+    let mut cx = 0usize;
+
+    while cx + 8 <= width {
+        let y_values = _mm_and_si128(
+            _mm_loadu_si128(y_plane.as_ptr().add(cx) as *const __m128i),
+            mask,
+        );
+        let u_values = _mm_and_si128(
+            _mm_loadu_si128(u_plane.as_ptr().add(cx) as *const __m128i),
+            mask,
+        );
+        let v_values = _mm_and_si128(
+            _mm_loadu_si128(v_plane.as_ptr().add(cx) as *const __m128i),
+            mask,
+        );
+
+        let zeros = _mm_setzero_si128();
+
+        let lo = _mm_or_si128(
+            _mm_or_si128(
+                _mm_slli_epi32::<2>(_mm_unpacklo_epi16(u_values, zeros)),
+                _mm_slli_epi32::<12>(_mm_unpacklo_epi16(y_values, zeros)),
+            ),
+            _mm_slli_epi32::<22>(_mm_unpacklo_epi16(v_values, zeros)),
+        );
+        let hi = _mm_or_si128(
+            _mm_or_si128(
+                _mm_slli_epi32::<2>(_mm_unpackhi_epi16(u_values, zeros)),
+                _mm_slli_epi32::<12>(_mm_unpackhi_epi16(y_values, zeros)),
+            ),
+            _mm_slli_epi32::<22>(_mm_unpackhi_epi16(v_values, zeros)),
+        );
+
+        _mm_storeu_si128(v410.as_mut_ptr().add(cx) as *mut __m128i, lo);
+        _mm_storeu_si128(v410.as_mut_ptr().add(cx + 4) as *mut __m128i, hi);
+
+        cx += 8;
+    }
+
+    cx
+}
@@ -138,7 +138,7 @@ pub unsafe fn sse_ycgcor_type_to_rgb_row<const DESTINATION_CHANNELS: u8, const S
             YuvSourceChannels::Bgr => {
                 sse_store_rgb_u8(rgba_ptr.add(dst_shift), b_values, g_values, r_values);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 sse_store_rgba(
                     rgba_ptr.add(dst_shift),
                     r_values,
@@ -147,7 +147,7 @@ pub unsafe fn sse_ycgcor_type_to_rgb_row<const DESTINATION_CHANNELS: u8, const S
                     v_alpha,
                 );
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 sse_store_rgba(
                     rgba_ptr.add(dst_shift),
                     b_values,
@@ -167,8 +167,8 @@ pub unsafe fn sse_yuv_nv_p16_to_rgba_row<
                 }
                 let u_values_c = _mm_sub_epi16(u_vl, uv_corr_q);
                 let v_values_c = _mm_sub_epi16(v_vl, uv_corr_q);
-                u_high = _mm_cvtepi16_epi32(_mm_slli_si128::<8>(u_values_c));
-                v_high = _mm_cvtepi16_epi32(_mm_slli_si128::<8>(v_values_c));
+                u_high = _mm_cvtepi16_epi32(_mm_srli_si128::<8>(u_values_c));
+                v_high = _mm_cvtepi16_epi32(_mm_srli_si128::<8>(v_values_c));
                 u_low = _mm_cvtepi16_epi32(u_values_c);
                 v_low = _mm_cvtepi16_epi32(v_values_c);
             }
@@ -236,7 +236,7 @@ pub unsafe fn sse_yuv_nv_p16_to_rgba_row<
                 _mm_storeu_si128(dst_ptr.add(8) as *mut __m128i, dst_pack.1);
                 _mm_storeu_si128(dst_ptr.add(16) as *mut __m128i, dst_pack.2);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let dst_pack =
                     _mm_interleave_rgba_epi16(r_values, g_values, b_values, v_max_colors);
                 _mm_storeu_si128(dst_ptr as *mut __m128i, dst_pack.0);
@@ -244,7 +244,7 @@ pub unsafe fn sse_yuv_nv_p16_to_rgba_row<
                 _mm_storeu_si128(dst_ptr.add(16) as *mut __m128i, dst_pack.2);
                 _mm_storeu_si128(dst_ptr.add(24) as *mut __m128i, dst_pack.3);
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let dst_pack =
                     _mm_interleave_rgba_epi16(b_values, g_values, r_values, v_max_colors);
                 _mm_storeu_si128(dst_ptr as *mut __m128i, dst_pack.0);
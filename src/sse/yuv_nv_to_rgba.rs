@@ -29,7 +29,10 @@
 
 use crate::internals::ProcessedOffset;
 use crate::sse::sse_support::{sse_store_rgb_u8, sse_store_rgba};
-use crate::sse::{_mm_deinterleave_x2_epi8, sse_interleave_rgb, sse_interleave_rgba};
+use crate::sse::{
+    _mm_deinterleave_x2_epi8, sse_interleave_rgb, sse_interleave_rgba, sse_store_rgbx,
+    sse_store_rgbx_half_epi8,
+};
 use crate::yuv_support::{
     CbCrInverseTransform, YuvChromaRange, YuvChromaSample, YuvNVOrder, YuvSourceChannels,
 };
@@ -55,6 +58,7 @@ pub unsafe fn sse_yuv_nv_to_rgba<
     uv_offset: usize,
     rgba_offset: usize,
     width: usize,
+    alpha: u8,
 ) -> ProcessedOffset {
     let order: YuvNVOrder = UV_ORDER.into();
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
@@ -76,7 +80,7 @@ pub unsafe fn sse_yuv_nv_to_rgba<
     let v_min_values = _mm_setzero_si128();
     let v_g_coeff_1 = _mm_set1_epi16(-(transform.g_coeff_1 as i16));
     let v_g_coeff_2 = _mm_set1_epi16(-(transform.g_coeff_2 as i16));
-    let v_alpha = _mm_set1_epi8(255u8 as i8);
+    let v_alpha = _mm_set1_epi8(alpha as i8);
     let rounding_const = _mm_set1_epi16(1 << 5);
 
     let zeros = _mm_setzero_si128();
@@ -232,6 +236,12 @@ pub unsafe fn sse_yuv_nv_to_rgba<
                     v_alpha,
                 );
             }
+            YuvSourceChannels::Rgbx => {
+                sse_store_rgbx(rgba_ptr.add(dst_shift), r_values, g_values, b_values);
+            }
+            YuvSourceChannels::Bgrx => {
+                sse_store_rgbx(rgba_ptr.add(dst_shift), b_values, g_values, r_values);
+            }
         }
 
         cx += 16;
@@ -350,6 +360,12 @@ pub unsafe fn sse_yuv_nv_to_rgba<
                 _mm_storeu_si128(dst_ptr as *mut __m128i, row1);
                 _mm_storeu_si128(dst_ptr.add(16) as *mut __m128i, row2);
             }
+            YuvSourceChannels::Rgbx => {
+                sse_store_rgbx_half_epi8(dst_ptr, r_values, g_values, b_values);
+            }
+            YuvSourceChannels::Bgrx => {
+                sse_store_rgbx_half_epi8(dst_ptr, b_values, g_values, r_values);
+            }
         }
 
         cx += 8;
@@ -364,5 +380,13 @@ pub unsafe fn sse_yuv_nv_to_rgba<
         }
     }
 
+    if matches!(
+        destination_channels,
+        YuvSourceChannels::Rgbx | YuvSourceChannels::Bgrx
+    ) {
+        // Masked stores are non temporal, order them before the caller hands rows out
+        _mm_sfence();
+    }
+
     ProcessedOffset { cx, ux: uv_x }
 }
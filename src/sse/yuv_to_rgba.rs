@@ -29,7 +29,9 @@
 
 use crate::internals::ProcessedOffset;
 use crate::sse::sse_support::{sse_store_rgb_u8, sse_store_rgba};
-use crate::sse::{sse_store_rgb_half_u8, sse_store_rgba_half_epi8};
+use crate::sse::{
+    sse_store_rgb_half_u8, sse_store_rgba_half_epi8, sse_store_rgbx, sse_store_rgbx_half_epi8,
+};
 use crate::yuv_support::{
     CbCrInverseTransform, YuvChromaRange, YuvChromaSample, YuvSourceChannels,
 };
@@ -53,6 +55,7 @@ pub unsafe fn sse_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLING
     v_offset: usize,
     rgba_offset: usize,
     width: usize,
+    alpha: u8,
 ) -> ProcessedOffset {
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
@@ -73,12 +76,12 @@ pub unsafe fn sse_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLING
     let v_cb_coeff = _mm_set1_epi16(transform.cb_coef as i16);
     let v_g_coeff_1 = _mm_set1_epi16(-(transform.g_coeff_1 as i16));
     let v_g_coeff_2 = _mm_set1_epi16(-(transform.g_coeff_2 as i16));
-    let v_alpha = _mm_set1_epi8(255u8 as i8);
+    let v_alpha = _mm_set1_epi8(alpha as i8);
     let rounding_const = _mm_set1_epi16(1 << 5);
 
     let zeros = _mm_setzero_si128();
 
-    while cx + 16 < width {
+    while cx + 16 <= width {
         let y_values = _mm_subs_epu8(
             _mm_loadu_si128(y_ptr.add(y_offset + cx) as *const __m128i),
             y_corr,
@@ -205,6 +208,12 @@ pub unsafe fn sse_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLING
                     v_alpha,
                 );
             }
+            YuvSourceChannels::Rgbx => {
+                sse_store_rgbx(rgba_ptr.add(dst_shift), r_values, g_values, b_values);
+            }
+            YuvSourceChannels::Bgrx => {
+                sse_store_rgbx(rgba_ptr.add(dst_shift), b_values, g_values, r_values);
+            }
         }
 
         cx += 16;
@@ -219,7 +228,7 @@ pub unsafe fn sse_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLING
         }
     }
 
-    while cx + 8 < width {
+    while cx + 8 <= width {
         let y_values = _mm_subs_epi8(_mm_loadu_si64(y_ptr.add(y_offset + cx)), y_corr);
 
         let (u_low_u16, v_low_u16);
@@ -313,6 +322,12 @@ pub unsafe fn sse_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLING
                     v_alpha,
                 );
             }
+            YuvSourceChannels::Rgbx => {
+                sse_store_rgbx_half_epi8(rgba_ptr.add(dst_shift), r_values, g_values, b_values);
+            }
+            YuvSourceChannels::Bgrx => {
+                sse_store_rgbx_half_epi8(rgba_ptr.add(dst_shift), b_values, g_values, r_values);
+            }
         }
 
         cx += 8;
@@ -327,5 +342,13 @@ pub unsafe fn sse_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLING
         }
     }
 
+    if matches!(
+        destination_channels,
+        YuvSourceChannels::Rgbx | YuvSourceChannels::Bgrx
+    ) {
+        // Masked stores are non temporal, order them before the caller hands rows out
+        _mm_sfence();
+    }
+
     ProcessedOffset { cx, ux: uv_x }
 }
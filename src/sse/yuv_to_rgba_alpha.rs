@@ -208,7 +208,7 @@ pub unsafe fn sse_yuv_to_rgba_alpha_row<const DESTINATION_CHANNELS: u8, const SA
             YuvSourceChannels::Bgr => {
                 sse_store_rgb_u8(rgba_ptr.add(dst_shift), b_values, g_values, r_values);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 sse_store_rgba(
                     rgba_ptr.add(dst_shift),
                     r_values,
@@ -217,7 +217,7 @@ pub unsafe fn sse_yuv_to_rgba_alpha_row<const DESTINATION_CHANNELS: u8, const SA
                     a_values,
                 );
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 sse_store_rgba(
                     rgba_ptr.add(dst_shift),
                     b_values,
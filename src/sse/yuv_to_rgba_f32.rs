@@ -0,0 +1,140 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_support::{
+    CbCrInverseTransform, YuvChromaRange, YuvChromaSample, YuvSourceChannels,
+};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[inline(always)]
+unsafe fn sse_load_u8x4_ps(ptr: *const u8) -> __m128 {
+    _mm_cvtepi32_ps(_mm_cvtepu8_epi32(_mm_cvtsi32_si128(
+        (ptr as *const i32).read_unaligned(),
+    )))
+}
+
+/// Loads two chroma samples and duplicates each one for a pair of pixels
+#[inline(always)]
+unsafe fn sse_load_u8x2_dup_ps(ptr: *const u8) -> __m128 {
+    let values = _mm_cvtepu8_epi32(_mm_cvtsi32_si128(
+        (ptr as *const u16).read_unaligned() as i32
+    ));
+    _mm_cvtepi32_ps(_mm_unpacklo_epi32(values, values))
+}
+
+#[inline(always)]
+unsafe fn sse_clamp_unit_ps(v: __m128) -> __m128 {
+    _mm_min_ps(_mm_max_ps(v, _mm_setzero_ps()), _mm_set1_ps(1.))
+}
+
+/// Converts a row of 8-bit planar YUV to normalized RGB(A) floats 4 pixels at a time,
+/// `transform` must be already normalized to `[0, 1]` output. Returns count of pixels processed.
+///
+/// Three channel layouts store one value past each block, so the last block is left
+/// to the scalar tail.
+#[target_feature(enable = "sse4.1")]
+pub unsafe fn sse_yuv_to_rgba_f32_row<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
+    range: &YuvChromaRange,
+    transform: &CbCrInverseTransform<f32>,
+    y_plane: &[u8],
+    u_plane: &[u8],
+    v_plane: &[u8],
+    rgba: &mut [f32],
+    width: usize,
+) -> usize {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = destination_channels.get_channels_count();
+
+    let v_bias_y = _mm_set1_ps(range.bias_y as f32);
+    let v_bias_uv = _mm_set1_ps(range.bias_uv as f32);
+    let v_y_coef = _mm_set1_ps(transform.y_coef);
+    let v_cr_coef = _mm_set1_ps(transform.cr_coef);
+    let v_cb_coef = _mm_set1_ps(transform.cb_coef);
+    let v_g_coef_1 = _mm_set1_ps(transform.g_coeff_1);
+    let v_g_coef_2 = _mm_set1_ps(transform.g_coeff_2);
+    let v_alpha = _mm_set1_ps(1.);
+
+    let overrun = if channels == 3 { 1 } else { 0 };
+
+    let mut cx = 0usize;
+
+    while cx + 4 + overrun <= width {
+        let y_values = sse_load_u8x4_ps(y_plane.as_ptr().add(cx));
+        let (u_values, v_values) = match chroma_subsampling {
+            YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => (
+                sse_load_u8x2_dup_ps(u_plane.as_ptr().add(cx / 2)),
+                sse_load_u8x2_dup_ps(v_plane.as_ptr().add(cx / 2)),
+            ),
+            YuvChromaSample::YUV444 => (
+                sse_load_u8x4_ps(u_plane.as_ptr().add(cx)),
+                sse_load_u8x4_ps(v_plane.as_ptr().add(cx)),
+            ),
+        };
+
+        let y_values = _mm_mul_ps(_mm_sub_ps(y_values, v_bias_y), v_y_coef);
+        let u_values = _mm_sub_ps(u_values, v_bias_uv);
+        let v_values = _mm_sub_ps(v_values, v_bias_uv);
+
+        let r = sse_clamp_unit_ps(_mm_add_ps(y_values, _mm_mul_ps(v_values, v_cr_coef)));
+        let b = sse_clamp_unit_ps(_mm_add_ps(y_values, _mm_mul_ps(u_values, v_cb_coef)));
+        let g = sse_clamp_unit_ps(_mm_sub_ps(
+            _mm_sub_ps(y_values, _mm_mul_ps(v_values, v_g_coef_1)),
+            _mm_mul_ps(u_values, v_g_coef_2),
+        ));
+
+        let mut components = [v_alpha; 4];
+        components[destination_channels.get_r_channel_offset()] = r;
+        components[destination_channels.get_g_channel_offset()] = g;
+        components[destination_channels.get_b_channel_offset()] = b;
+
+        // Transpose planar components into 4 interleaved pixels
+        let t0 = _mm_unpacklo_ps(components[0], components[1]);
+        let t1 = _mm_unpacklo_ps(components[2], components[3]);
+        let t2 = _mm_unpackhi_ps(components[0], components[1]);
+        let t3 = _mm_unpackhi_ps(components[2], components[3]);
+        let pixels = [
+            _mm_movelh_ps(t0, t1),
+            _mm_movehl_ps(t1, t0),
+            _mm_movelh_ps(t2, t3),
+            _mm_movehl_ps(t3, t2),
+        ];
+
+        let dst_ptr = rgba.as_mut_ptr().add(cx * channels);
+        for (i, pixel) in pixels.iter().enumerate() {
+            _mm_storeu_ps(dst_ptr.add(i * channels), *pixel);
+        }
+
+        cx += 4;
+    }
+
+    cx
+}
@@ -216,14 +216,14 @@ unsafe fn yuy2_to_rgb_sse_impl<const DST_CHANNELS: u8, const YUY2_TARGET: usize>
                     _mm_storeu_si128(dst_ptr.add(16) as *mut __m128i, packed.1);
                     _mm_storeu_si128(dst_ptr.add(32) as *mut __m128i, packed.2);
                 }
-                YuvSourceChannels::Rgba => {
+                YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                     let packed = sse_interleave_rgba(r_l, g_l, b_l, v_alpha);
                     _mm_storeu_si128(dst_ptr as *mut __m128i, packed.0);
                     _mm_storeu_si128(dst_ptr.add(16) as *mut __m128i, packed.1);
                     _mm_storeu_si128(dst_ptr.add(32) as *mut __m128i, packed.2);
                     _mm_storeu_si128(dst_ptr.add(48) as *mut __m128i, packed.3);
                 }
-                YuvSourceChannels::Bgra => {
+                YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                     let packed = sse_interleave_rgba(b_l, g_l, r_l, v_alpha);
                     _mm_storeu_si128(dst_ptr as *mut __m128i, packed.0);
                     _mm_storeu_si128(dst_ptr.add(16) as *mut __m128i, packed.1);
@@ -314,7 +314,7 @@ unsafe fn yuy2_to_rgb_sse_impl<const DST_CHANNELS: u8, const YUY2_TARGET: usize>
                     _mm_storeu_si128(v_dst.add(16) as *mut __m128i, packed.1);
                     _mm_storeu_si128(v_dst.add(32) as *mut __m128i, packed.2);
                 }
-                YuvSourceChannels::Rgba => {
+                YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                     let packed = sse_interleave_rgba(r_h, g_h, b_h, v_alpha);
                     let v_dst = dst_ptr.add(16 * dst_chans.get_channels_count());
                     _mm_storeu_si128(v_dst as *mut __m128i, packed.0);
@@ -322,7 +322,7 @@ unsafe fn yuy2_to_rgb_sse_impl<const DST_CHANNELS: u8, const YUY2_TARGET: usize>
                     _mm_storeu_si128(v_dst.add(32) as *mut __m128i, packed.2);
                     _mm_storeu_si128(v_dst.add(48) as *mut __m128i, packed.3);
                 }
-                YuvSourceChannels::Bgra => {
+                YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                     let packed = sse_interleave_rgba(b_h, g_h, r_h, v_alpha);
                     let v_dst = dst_ptr.add(16 * dst_chans.get_channels_count());
                     _mm_storeu_si128(v_dst as *mut __m128i, packed.0);
@@ -461,14 +461,14 @@ unsafe fn yuy2_to_rgb_sse_impl<const DST_CHANNELS: u8, const YUY2_TARGET: usize>
                     _mm_storeu_si128(dst_ptr.add(16) as *mut __m128i, packed.1);
                     _mm_storeu_si128(dst_ptr.add(32) as *mut __m128i, packed.2);
                 }
-                YuvSourceChannels::Rgba => {
+                YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                     let packed = sse_interleave_rgba(r_v, g_v, b_v, v_alpha);
                     _mm_storeu_si128(dst_ptr as *mut __m128i, packed.0);
                     _mm_storeu_si128(dst_ptr.add(16) as *mut __m128i, packed.1);
                     _mm_storeu_si128(dst_ptr.add(32) as *mut __m128i, packed.2);
                     _mm_storeu_si128(dst_ptr.add(48) as *mut __m128i, packed.3);
                 }
-                YuvSourceChannels::Bgra => {
+                YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                     let packed = sse_interleave_rgba(b_v, g_v, r_v, v_alpha);
                     _mm_storeu_si128(dst_ptr as *mut __m128i, packed.0);
                     _mm_storeu_si128(dst_ptr.add(16) as *mut __m128i, packed.1);
@@ -0,0 +1,300 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+//! SMPTE ST 2110-20 ( and ST 2022-6 payload ) 4:2:2 10-bit pgroup packing.
+//!
+//! Every pgroup holds two pixels in 5 bytes as `Cb Y0 Cr Y1`, each sample takes
+//! 10 bits in network order ( most significant bit first ).
+use crate::images::{try_vec, ImageSize, RowStride};
+use crate::yuv_error::{
+    check_chroma_channel, check_pgroup422_frame, check_rgba_destination, check_y8_channel,
+};
+use crate::yuv_p10_rgba::yuv422_p10_to_rgba;
+use crate::yuv_support::{
+    YuvBytesPacking, YuvChromaSample, YuvEndianness, YuvRange, YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+/// Splits pgroups into 10-bit planes, sizes must be already validated
+fn pgroup_to_planes(
+    pgroup: &[u8],
+    pgroup_stride: u32,
+    y_plane: &mut [u16],
+    y_stride: u32,
+    u_plane: &mut [u16],
+    u_stride: u32,
+    v_plane: &mut [u16],
+    v_stride: u32,
+    width: u32,
+    height: u32,
+) {
+    let width = width as usize;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = pgroup
+            .par_chunks(pgroup_stride as usize)
+            .zip(y_plane.par_chunks_mut(y_stride as usize / 2))
+            .zip(u_plane.par_chunks_mut(u_stride as usize / 2))
+            .zip(v_plane.par_chunks_mut(v_stride as usize / 2))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = pgroup
+            .chunks(pgroup_stride as usize)
+            .zip(y_plane.chunks_mut(y_stride as usize / 2))
+            .zip(u_plane.chunks_mut(u_stride as usize / 2))
+            .zip(v_plane.chunks_mut(v_stride as usize / 2))
+            .take(height as usize);
+    }
+    iter.for_each(|(((pgroup, y_dst), u_dst), v_dst)| {
+        for (((group, y_dst), u_dst), v_dst) in pgroup
+            .chunks_exact(5)
+            .zip(y_dst[..width].chunks_mut(2))
+            .zip(u_dst.iter_mut())
+            .zip(v_dst.iter_mut())
+        {
+            let word = ((group[0] as u64) << 32)
+                | ((group[1] as u64) << 24)
+                | ((group[2] as u64) << 16)
+                | ((group[3] as u64) << 8)
+                | (group[4] as u64);
+            *u_dst = ((word >> 30) & 0x3ff) as u16;
+            y_dst[0] = ((word >> 20) & 0x3ff) as u16;
+            *v_dst = ((word >> 10) & 0x3ff) as u16;
+            if let Some(y1) = y_dst.get_mut(1) {
+                *y1 = (word & 0x3ff) as u16;
+            }
+        }
+    });
+}
+
+/// Convert SMPTE ST 2110 pgroups ( packed 10-bit 4:2:2 `Cb Y0 Cr Y1` in network order )
+/// to YUV 422 planar format with 10-bit pixel format ( I210 ).
+///
+/// Odd width is supported, second luma sample of the last pgroup in a row is ignored.
+///
+/// # Arguments
+///
+/// * `pgroup` - A slice to load pgroup data.
+/// * `pgroup_stride` - The stride (bytes per row) for pgroup data.
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `size` - The size of the image.
+///
+pub fn pgroup422_p10_to_yuv422_p10(
+    pgroup: &[u8],
+    pgroup_stride: RowStride,
+    y_plane: &mut [u16],
+    y_stride: RowStride,
+    u_plane: &mut [u16],
+    u_stride: RowStride,
+    v_plane: &mut [u16],
+    v_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (pgroup_stride, y_stride, u_stride, v_stride) = (
+        pgroup_stride.get(),
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+    );
+    check_pgroup422_frame(pgroup, pgroup_stride, width, height)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, YuvChromaSample::YUV422)?;
+    check_chroma_channel(v_plane, v_stride, width, height, YuvChromaSample::YUV422)?;
+    pgroup_to_planes(
+        pgroup,
+        pgroup_stride,
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        width,
+        height,
+    );
+    Ok(())
+}
+
+/// Convert YUV 422 planar format with 10-bit pixel format ( I210 ) to SMPTE ST 2110 pgroups
+/// ( packed 10-bit 4:2:2 `Cb Y0 Cr Y1` in network order ).
+///
+/// For odd width the last luma sample of a row is repeated into the padding sample of the last pgroup.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `pgroup` - A mutable slice to store pgroup data.
+/// * `pgroup_stride` - The stride (bytes per row) for pgroup data.
+/// * `size` - The size of the image.
+///
+pub fn yuv422_p10_to_pgroup422_p10(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    u_plane: &[u16],
+    u_stride: RowStride,
+    v_plane: &[u16],
+    v_stride: RowStride,
+    pgroup: &mut [u8],
+    pgroup_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, pgroup_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        pgroup_stride.get(),
+    );
+    check_pgroup422_frame(pgroup, pgroup_stride, width, height)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, YuvChromaSample::YUV422)?;
+    check_chroma_channel(v_plane, v_stride, width, height, YuvChromaSample::YUV422)?;
+
+    let width = width as usize;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = pgroup
+            .par_chunks_mut(pgroup_stride as usize)
+            .zip(y_plane.par_chunks(y_stride as usize / 2))
+            .zip(u_plane.par_chunks(u_stride as usize / 2))
+            .zip(v_plane.par_chunks(v_stride as usize / 2))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = pgroup
+            .chunks_mut(pgroup_stride as usize)
+            .zip(y_plane.chunks(y_stride as usize / 2))
+            .zip(u_plane.chunks(u_stride as usize / 2))
+            .zip(v_plane.chunks(v_stride as usize / 2))
+            .take(height as usize);
+    }
+    iter.for_each(|(((pgroup, y_src), u_src), v_src)| {
+        for (((group, y_src), &u_src), &v_src) in pgroup
+            .chunks_exact_mut(5)
+            .zip(y_src[..width].chunks(2))
+            .zip(u_src.iter())
+            .zip(v_src.iter())
+        {
+            let y0 = y_src[0];
+            let y1 = y_src.get(1).copied().unwrap_or(y0);
+            let word = ((u_src as u64 & 0x3ff) << 30)
+                | ((y0 as u64 & 0x3ff) << 20)
+                | ((v_src as u64 & 0x3ff) << 10)
+                | (y1 as u64 & 0x3ff);
+            group[0] = (word >> 32) as u8;
+            group[1] = (word >> 24) as u8;
+            group[2] = (word >> 16) as u8;
+            group[3] = (word >> 8) as u8;
+            group[4] = word as u8;
+        }
+    });
+
+    Ok(())
+}
+
+/// Convert SMPTE ST 2110 pgroups ( packed 10-bit 4:2:2 `Cb Y0 Cr Y1` in network order )
+/// to RGBA format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `pgroup` - A slice to load pgroup data.
+/// * `pgroup_stride` - The stride (bytes per row) for pgroup data.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn pgroup422_p10_to_rgba(
+    pgroup: &[u8],
+    pgroup_stride: RowStride,
+    rgba: &mut [u8],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (pgroup_stride, rgba_stride) = (pgroup_stride.get(), rgba_stride.get());
+    check_pgroup422_frame(pgroup, pgroup_stride, width, height)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, 4)?;
+    let chroma_width = width.div_ceil(2);
+    let mut y_plane = try_vec(0u16, width as usize * height as usize)?;
+    let mut u_plane = try_vec(0u16, chroma_width as usize * height as usize)?;
+    let mut v_plane = try_vec(0u16, chroma_width as usize * height as usize)?;
+    pgroup_to_planes(
+        pgroup,
+        pgroup_stride,
+        &mut y_plane,
+        width * 2,
+        &mut u_plane,
+        chroma_width * 2,
+        &mut v_plane,
+        chroma_width * 2,
+        width,
+        height,
+    );
+    yuv422_p10_to_rgba(
+        &y_plane,
+        width * 2,
+        &u_plane,
+        chroma_width * 2,
+        &v_plane,
+        chroma_width * 2,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        YuvEndianness::LittleEndian,
+        YuvBytesPacking::LeastSignificantBytes,
+    )
+}
@@ -0,0 +1,413 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_vec, YuvPlanarImage, YuvPlanarImageMut};
+use crate::yuv_error::{check_rgba_destination, check_y8_channel};
+use crate::yuv_rotate::chroma_rows;
+use crate::yuv_support::{YuvChromaSample, YuvRange, YuvSourceChannels, YuvStandardMatrix};
+use crate::yuv_to_rgba::yuv_to_rgbx;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+use std::fmt::Debug;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash, Default)]
+/// Order in which samples of every plane are laid out in memory
+pub enum YuvStorageOrder {
+    /// Rows are contiguous, strides are distances between rows
+    #[default]
+    RowMajor,
+    /// Columns are contiguous, strides are distances between columns,
+    /// as produced by Fortran and MATLAB style scientific imaging stacks
+    ColumnMajor,
+}
+
+/// Logical rows converted at once from column-major source, must be even to keep 4:2:0 chroma aligned
+const TRANSPOSE_STRIP_HEIGHT: usize = 16;
+/// Side of the square block moved at once by the transposition
+const TRANSPOSE_BLOCK: usize = 32;
+
+/// Writes `height` rows of `width` elements into a row-major plane from a column-major one,
+/// source column `x` starts at `x * src_stride`. Work goes in square blocks so both sides stay in cache.
+fn transpose_plane<T: Copy + Send + Sync>(
+    src: &[T],
+    src_stride: usize,
+    dst: &mut [T],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+) {
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst.par_chunks_mut(dst_stride * TRANSPOSE_BLOCK);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst.chunks_mut(dst_stride * TRANSPOSE_BLOCK);
+    }
+    iter.take(height.div_ceil(TRANSPOSE_BLOCK))
+        .enumerate()
+        .for_each(|(block, dst_block)| {
+            let first_row = block * TRANSPOSE_BLOCK;
+            let rows = TRANSPOSE_BLOCK.min(height - first_row);
+            for first_column in (0..width).step_by(TRANSPOSE_BLOCK) {
+                let columns = TRANSPOSE_BLOCK.min(width - first_column);
+                for x in first_column..first_column + columns {
+                    let column = &src[x * src_stride + first_row..][..rows];
+                    for (y, &value) in column.iter().enumerate() {
+                        dst_block[y * dst_stride + x] = value;
+                    }
+                }
+            }
+        });
+}
+
+/// Returns chroma plane dimensions of `width` x `height` image
+#[inline]
+fn chroma_size(sampling: YuvChromaSample, width: usize, height: usize) -> (usize, usize) {
+    match sampling {
+        YuvChromaSample::YUV420 => (width.div_ceil(2), height.div_ceil(2)),
+        YuvChromaSample::YUV422 => (width.div_ceil(2), height),
+        YuvChromaSample::YUV444 => (width, height),
+    }
+}
+
+/// Validates column-major planes, every column is a row of the transposed image
+fn check_column_major<T: Copy + Debug>(
+    image: &YuvPlanarImage<T>,
+    sampling: YuvChromaSample,
+) -> Result<(), YuvError> {
+    if image.size.width == 0 || image.size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    let (chroma_width, chroma_height) = chroma_size(
+        sampling,
+        image.size.width as usize,
+        image.size.height as usize,
+    );
+    check_y8_channel(
+        image.y_plane,
+        image.y_stride.get(),
+        image.size.height,
+        image.size.width,
+    )?;
+    check_y8_channel(
+        image.u_plane,
+        image.u_stride.get(),
+        chroma_height as u32,
+        chroma_width as u32,
+    )?;
+    check_y8_channel(
+        image.v_plane,
+        image.v_stride.get(),
+        chroma_height as u32,
+        chroma_width as u32,
+    )?;
+    Ok(())
+}
+
+fn yuv_to_rgbx_with_storage_order<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    order: YuvStorageOrder,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => {
+            yuv_to_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+        }
+        YuvChromaSample::YUV422 => {
+            yuv_to_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+        }
+        YuvChromaSample::YUV444 => {
+            yuv_to_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+        }
+    };
+
+    if order == YuvStorageOrder::RowMajor {
+        image.check_constraints(sampling)?;
+        return handler(
+            image.y_plane,
+            image.y_stride.get(),
+            image.u_plane,
+            image.u_stride.get(),
+            image.v_plane,
+            image.v_stride.get(),
+            rgba,
+            rgba_stride,
+            image.size.width,
+            image.size.height,
+            range,
+            matrix,
+        );
+    }
+
+    check_column_major(image, sampling)?;
+    let matrix = matrix.resolve(image.size.width, image.size.height);
+    let channels = YuvSourceChannels::from(DESTINATION_CHANNELS).get_channels_count();
+    let width = image.size.width as usize;
+    let height = image.size.height as usize;
+    check_rgba_destination(rgba, rgba_stride, width as u32, height as u32, channels)?;
+
+    let (chroma_width, _) = chroma_size(sampling, width, height);
+    let (_, chroma_strip_height) = chroma_rows(sampling, 0, TRANSPOSE_STRIP_HEIGHT);
+    let mut y_strip = try_vec(0u8, width * TRANSPOSE_STRIP_HEIGHT)?;
+    let mut u_strip = try_vec(0u8, chroma_width * chroma_strip_height)?;
+    let mut v_strip = try_vec(0u8, chroma_width * chroma_strip_height)?;
+    let rgba_stride = rgba_stride as usize;
+
+    // Each strip of logical rows is transposed into small row-major planes that stay in cache
+    // and converted straight away, so the full frame is never transposed
+    for start in (0..height).step_by(TRANSPOSE_STRIP_HEIGHT) {
+        let rows = TRANSPOSE_STRIP_HEIGHT.min(height - start);
+        let (chroma_start, chroma_rows) = chroma_rows(sampling, start, rows);
+        transpose_plane(
+            &image.y_plane[start..],
+            image.y_stride.get() as usize,
+            &mut y_strip,
+            width,
+            width,
+            rows,
+        );
+        transpose_plane(
+            &image.u_plane[chroma_start..],
+            image.u_stride.get() as usize,
+            &mut u_strip,
+            chroma_width,
+            chroma_width,
+            chroma_rows,
+        );
+        transpose_plane(
+            &image.v_plane[chroma_start..],
+            image.v_stride.get() as usize,
+            &mut v_strip,
+            chroma_width,
+            chroma_width,
+            chroma_rows,
+        );
+        let rgba_end = ((start + rows) * rgba_stride).min(rgba.len());
+        handler(
+            &y_strip[..width * rows],
+            width as u32,
+            &u_strip[..chroma_width * chroma_rows],
+            chroma_width as u32,
+            &v_strip[..chroma_width * chroma_rows],
+            chroma_width as u32,
+            &mut rgba[start * rgba_stride..rgba_end],
+            rgba_stride as u32,
+            width as u32,
+            rows as u32,
+            range,
+            matrix,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Convert planar YUV image stored in either order to RGBA.
+///
+/// Column-major source is transposed strip by strip in cache right before conversion,
+/// so no full size intermediate frame is needed. Destination is always row-major.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image, for column-major source strides are distances in bytes between columns.
+/// * `order` - Storage order of the source planes.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_to_rgba_with_storage_order(
+    image: &YuvPlanarImage<u8>,
+    order: YuvStorageOrder,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_with_storage_order::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        order,
+        sampling,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+    )
+}
+
+/// Convert planar YUV image stored in either order to BGRA.
+///
+/// See [yuv_to_rgba_with_storage_order] for how column-major source is handled.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image, for column-major source strides are distances in bytes between columns.
+/// * `order` - Storage order of the source planes.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_to_bgra_with_storage_order(
+    image: &YuvPlanarImage<u8>,
+    order: YuvStorageOrder,
+    sampling: YuvChromaSample,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_with_storage_order::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        order,
+        sampling,
+        bgra,
+        bgra_stride,
+        range,
+        matrix,
+    )
+}
+
+/// Convert planar YUV image stored in either order to RGB.
+///
+/// See [yuv_to_rgba_with_storage_order] for how column-major source is handled.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image, for column-major source strides are distances in bytes between columns.
+/// * `order` - Storage order of the source planes.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_to_rgb_with_storage_order(
+    image: &YuvPlanarImage<u8>,
+    order: YuvStorageOrder,
+    sampling: YuvChromaSample,
+    rgb: &mut [u8],
+    rgb_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_with_storage_order::<{ YuvSourceChannels::Rgb as u8 }>(
+        image, order, sampling, rgb, rgb_stride, range, matrix,
+    )
+}
+
+fn yuv_column_major_to_row_major_impl<T: Copy + Debug + Send + Sync>(
+    image: &YuvPlanarImage<T>,
+    target_image: &mut YuvPlanarImageMut<T>,
+    sampling: YuvChromaSample,
+) -> Result<(), YuvError> {
+    check_column_major(image, sampling)?;
+    if target_image.size != image.size {
+        return Err(YuvError::ImageSizeMismatch(image.size, target_image.size));
+    }
+    target_image.check_constraints(sampling)?;
+
+    let width = image.size.width as usize;
+    let height = image.size.height as usize;
+    let (chroma_width, chroma_height) = chroma_size(sampling, width, height);
+    let element_size = std::mem::size_of::<T>();
+
+    transpose_plane(
+        image.y_plane,
+        image.y_stride.get() as usize / element_size,
+        target_image.y_plane.borrow_mut(),
+        target_image.y_stride.get() as usize / element_size,
+        width,
+        height,
+    );
+    transpose_plane(
+        image.u_plane,
+        image.u_stride.get() as usize / element_size,
+        target_image.u_plane.borrow_mut(),
+        target_image.u_stride.get() as usize / element_size,
+        chroma_width,
+        chroma_height,
+    );
+    transpose_plane(
+        image.v_plane,
+        image.v_stride.get() as usize / element_size,
+        target_image.v_plane.borrow_mut(),
+        target_image.v_stride.get() as usize / element_size,
+        chroma_width,
+        chroma_height,
+    );
+    Ok(())
+}
+
+/// Reorders column-major planar YUV image into row-major one of the same size,
+/// so it may be passed to any other conversion.
+///
+/// Planes are transposed in blocks that fit in cache.
+///
+/// # Arguments
+///
+/// * `image` - Source column-major image, strides are distances in bytes between columns.
+/// * `target_image` - Target row-major image.
+/// * `sampling` - Chroma subsampling of both images.
+///
+pub fn yuv_column_major_to_row_major(
+    image: &YuvPlanarImage<u8>,
+    target_image: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSample,
+) -> Result<(), YuvError> {
+    yuv_column_major_to_row_major_impl(image, target_image, sampling)
+}
+
+/// Reorders column-major planar YUV image with 8+ bit pixel format into row-major one
+/// of the same size, samples are moved as is so any bit depth and endianness is kept.
+///
+/// # Arguments
+///
+/// * `image` - Source column-major image, strides are distances in bytes between columns.
+/// * `target_image` - Target row-major image.
+/// * `sampling` - Chroma subsampling of both images.
+///
+pub fn yuv_p16_column_major_to_row_major(
+    image: &YuvPlanarImage<u16>,
+    target_image: &mut YuvPlanarImageMut<u16>,
+    sampling: YuvChromaSample,
+) -> Result<(), YuvError> {
+    yuv_column_major_to_row_major_impl(image, target_image, sampling)
+}
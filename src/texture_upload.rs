@@ -0,0 +1,250 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::color_description::YuvTransferCharacteristics;
+use crate::images::{YuvGrayImage, YuvPlanarImage};
+use crate::luma_transfer::LumaTransfer;
+use crate::sharpyuv::SharpYuvGammaTransfer;
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_rotate::chroma_rows;
+use crate::yuv_support::{YuvChromaSample, YuvRange, YuvSourceChannels, YuvStandardMatrix};
+use crate::yuv_to_rgba::yuv_to_rgbx_rows;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+/// Adjustments applied by [yuv_to_rgba_texture] while pixels are written
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct YuvTextureOptions {
+    /// Store rows bottom up, as OpenGL places texture origin in the lower left corner
+    pub flip_y: bool,
+    /// Scale colour by alpha, takes effect only when alpha plane is supplied
+    pub premultiply_alpha: bool,
+    /// Transfer function of the source to re-encode colour from into sRGB,
+    /// for `*_SRGB` texture formats. `None` keeps decoded values as they are.
+    pub srgb_from: Option<YuvTransferCharacteristics>,
+}
+
+/// Builds table re-encoding 8-bit values from `from` transfer into sRGB
+fn srgb_lut(from: YuvTransferCharacteristics) -> Result<[u8; 256], YuvError> {
+    let from = LumaTransfer::new(from)?;
+    let to = LumaTransfer::Sharp(SharpYuvGammaTransfer::Srgb);
+    let mut lut = [0u8; 256];
+    for (code, dst) in lut.iter_mut().enumerate() {
+        let encoded = to.gamma(from.linearize(code as f32 / 255.)).clamp(0., 1.);
+        *dst = (encoded * 255.).round() as u8;
+    }
+    Ok(lut)
+}
+
+fn yuv_to_rgbx_texture<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    alpha: Option<&YuvGrayImage<u8>>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    options: YuvTextureOptions,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+    let width = image.size.width;
+    let height = image.size.height;
+
+    image.check_constraints(sampling)?;
+    if let Some(alpha) = alpha {
+        if alpha.size != image.size {
+            return Err(YuvError::ImageSizeMismatch(image.size, alpha.size));
+        }
+        alpha.check_constraints()?;
+    }
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    // Rows are converted one at a time, so matrix is resolved against the whole frame
+    let matrix = matrix.resolve(width, height);
+
+    let lut = match options.srgb_from {
+        Some(YuvTransferCharacteristics::Srgb) | None => None,
+        Some(from) => Some(srgb_lut(from)?),
+    };
+
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => {
+            yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+        }
+        YuvChromaSample::YUV422 => {
+            yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+        }
+        YuvChromaSample::YUV444 => {
+            yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+        }
+    };
+
+    let width = width as usize;
+    let height = height as usize;
+    let y_stride = image.y_stride.get() as usize;
+    let u_stride = image.u_stride.get() as usize;
+    let v_stride = image.v_stride.get() as usize;
+    let r_offset = dst_chans.get_r_channel_offset();
+    let g_offset = dst_chans.get_g_channel_offset();
+    let b_offset = dst_chans.get_b_channel_offset();
+    let a_offset = dst_chans.get_a_channel_offset();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba.par_chunks_mut(rgba_stride as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba.chunks_mut(rgba_stride as usize);
+    }
+
+    iter.take(height).enumerate().for_each(|(y, rgba_row)| {
+        let src_row = if options.flip_y { height - 1 - y } else { y };
+        let (chroma_row, _) = chroma_rows(sampling, src_row, 1);
+
+        handler(
+            &image.y_plane[src_row * y_stride..],
+            y_stride as u32,
+            &image.u_plane[chroma_row * u_stride..],
+            u_stride as u32,
+            &image.v_plane[chroma_row * v_stride..],
+            v_stride as u32,
+            rgba_row,
+            rgba_stride,
+            width as u32,
+            1,
+            range,
+            matrix,
+            255,
+        );
+
+        let rgba_row = &mut rgba_row[..width * channels];
+        if let Some(lut) = &lut {
+            for dst in rgba_row.chunks_exact_mut(channels) {
+                dst[r_offset] = lut[dst[r_offset] as usize];
+                dst[g_offset] = lut[dst[g_offset] as usize];
+                dst[b_offset] = lut[dst[b_offset] as usize];
+            }
+        }
+        if let Some(alpha) = alpha {
+            let a_stride = alpha.y_stride.get() as usize;
+            let a_src = &alpha.y_plane[src_row * a_stride..][..width];
+            for (dst, &a) in rgba_row.chunks_exact_mut(channels).zip(a_src.iter()) {
+                if options.premultiply_alpha {
+                    dst[r_offset] = (dst[r_offset] as u32 * a as u32 / 255) as u8;
+                    dst[g_offset] = (dst[g_offset] as u32 * a as u32 / 255) as u8;
+                    dst[b_offset] = (dst[b_offset] as u32 * a as u32 / 255) as u8;
+                }
+                dst[a_offset] = a;
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Convert YUV planar format with 8-bit pixel format to RGBA texture data in a single pass.
+///
+/// Meant for texture uploads: each row is converted, re-encoded to sRGB, given alpha and
+/// premultiplied while it is still in cache, and written to its final, possibly flipped, place.
+/// Without `alpha` every pixel is opaque and `premultiply_alpha` has no effect.
+/// Premultiplication is done on encoded values, as compositors commonly expect.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `alpha` - Optional alpha plane of the same size as `image`.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `options` - Row flip, premultiplication and sRGB re-encoding to apply.
+///
+pub fn yuv_to_rgba_texture(
+    image: &YuvPlanarImage<u8>,
+    alpha: Option<&YuvGrayImage<u8>>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    options: YuvTextureOptions,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_texture::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        alpha,
+        sampling,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+        options,
+    )
+}
+
+/// Convert YUV planar format with 8-bit pixel format to BGRA texture data in a single pass.
+///
+/// Same as [yuv_to_rgba_texture] for `BGRA` texture formats common with Vulkan swapchains.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `alpha` - Optional alpha plane of the same size as `image`.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `options` - Row flip, premultiplication and sRGB re-encoding to apply.
+///
+pub fn yuv_to_bgra_texture(
+    image: &YuvPlanarImage<u8>,
+    alpha: Option<&YuvGrayImage<u8>>,
+    sampling: YuvChromaSample,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    options: YuvTextureOptions,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_texture::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        alpha,
+        sampling,
+        bgra,
+        bgra_stride,
+        range,
+        matrix,
+        options,
+    )
+}
@@ -0,0 +1,313 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::cpu_features::{dispatch_backend, YuvDispatchBackend};
+use std::sync::RwLock;
+use std::time::Duration;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// Conversion with known throughput, names follow the conversion functions
+pub enum YuvFormatPair {
+    RgbaToYuv420,
+    RgbaToYuv422,
+    RgbaToYuv444,
+    Yuv420ToRgba,
+    Yuv422ToRgba,
+    Yuv444ToRgba,
+    RgbaToYuvNv12,
+    YuvNv12ToRgba,
+    RgbaToYuv400,
+    Yuv400ToRgba,
+    Yuv420ToYuyv422,
+    Yuyv422ToYuv420,
+    Yuyv422ToRgba,
+    Yuv420ToYuvNv12,
+    YuvNv12ToYuv420,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum FrameLayout {
+    Rgba,
+    Yuv420,
+    Yuv422,
+    Yuv444,
+    Gray,
+    Yuyv,
+}
+
+impl FrameLayout {
+    const fn bytes(self, width: usize, height: usize) -> usize {
+        let luma = width * height;
+        let chroma_width = width.div_ceil(2);
+        match self {
+            FrameLayout::Rgba => luma * 4,
+            FrameLayout::Yuv420 => luma + chroma_width * 2 * height.div_ceil(2),
+            FrameLayout::Yuv422 => luma + chroma_width * 2 * height,
+            FrameLayout::Yuv444 => luma * 3,
+            FrameLayout::Gray => luma,
+            FrameLayout::Yuyv => chroma_width * 4 * height,
+        }
+    }
+}
+
+impl YuvFormatPair {
+    /// Source and destination layouts, NV12 has the same amount of bytes as I420
+    const fn layouts(self) -> (FrameLayout, FrameLayout) {
+        match self {
+            YuvFormatPair::RgbaToYuv420 | YuvFormatPair::RgbaToYuvNv12 => {
+                (FrameLayout::Rgba, FrameLayout::Yuv420)
+            }
+            YuvFormatPair::RgbaToYuv422 => (FrameLayout::Rgba, FrameLayout::Yuv422),
+            YuvFormatPair::RgbaToYuv444 => (FrameLayout::Rgba, FrameLayout::Yuv444),
+            YuvFormatPair::Yuv420ToRgba | YuvFormatPair::YuvNv12ToRgba => {
+                (FrameLayout::Yuv420, FrameLayout::Rgba)
+            }
+            YuvFormatPair::Yuv422ToRgba => (FrameLayout::Yuv422, FrameLayout::Rgba),
+            YuvFormatPair::Yuv444ToRgba => (FrameLayout::Yuv444, FrameLayout::Rgba),
+            YuvFormatPair::RgbaToYuv400 => (FrameLayout::Rgba, FrameLayout::Gray),
+            YuvFormatPair::Yuv400ToRgba => (FrameLayout::Gray, FrameLayout::Rgba),
+            YuvFormatPair::Yuv420ToYuyv422 => (FrameLayout::Yuv420, FrameLayout::Yuyv),
+            YuvFormatPair::Yuyv422ToYuv420 => (FrameLayout::Yuyv, FrameLayout::Yuv420),
+            YuvFormatPair::Yuyv422ToRgba => (FrameLayout::Yuyv, FrameLayout::Rgba),
+            YuvFormatPair::Yuv420ToYuvNv12 | YuvFormatPair::YuvNv12ToYuv420 => {
+                (FrameLayout::Yuv420, FrameLayout::Yuv420)
+            }
+        }
+    }
+
+    /// Amount of bytes read and written by single conversion of 8-bit frame with tightly packed rows
+    pub const fn frame_bytes(self, width: u32, height: u32) -> usize {
+        let (src, dst) = self.layouts();
+        src.bytes(width as usize, height as usize) + dst.bytes(width as usize, height as usize)
+    }
+
+    /// Backend the conversion dispatches to on the current machine
+    pub fn backend(self) -> YuvDispatchBackend {
+        match self {
+            YuvFormatPair::Yuv420ToRgba
+            | YuvFormatPair::Yuv422ToRgba
+            | YuvFormatPair::Yuv444ToRgba
+            | YuvFormatPair::YuvNv12ToRgba
+            | YuvFormatPair::Yuv400ToRgba
+            | YuvFormatPair::Yuyv422ToYuv420
+            | YuvFormatPair::Yuyv422ToRgba => dispatch_backend(true, true),
+            YuvFormatPair::RgbaToYuv420
+            | YuvFormatPair::RgbaToYuv422
+            | YuvFormatPair::RgbaToYuv444
+            | YuvFormatPair::RgbaToYuv400 => dispatch_backend(true, false),
+            YuvFormatPair::RgbaToYuvNv12 | YuvFormatPair::Yuv420ToYuyv422 => {
+                dispatch_backend(false, false)
+            }
+            // Chroma repacking has no SIMD kernels
+            YuvFormatPair::Yuv420ToYuvNv12 | YuvFormatPair::YuvNv12ToYuv420 => {
+                YuvDispatchBackend::Scalar
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default)]
+/// Throughput in gigabytes ( 10^9 bytes ) of source and destination data per second
+pub struct GBps(pub f32);
+
+impl GBps {
+    /// Expected time to convert `bytes` of source and destination data
+    pub fn duration_for(self, bytes: usize) -> Duration {
+        if self.0 <= 0. || !self.0.is_finite() {
+            return Duration::MAX;
+        }
+        Duration::from_secs_f64(bytes as f64 / (self.0 as f64 * 1e9))
+    }
+}
+
+/// Single threaded throughput of 1920x1080 frames produced by `yuvutils-bench --calibrate`
+/// on x86_64 built with `bench` feature only, rayon off.
+///
+/// Backends without an entry were not measured, they are answered with the nearest
+/// measured lower backend, see [YuvDispatchBackend::measured_fallback].
+static BASELINE: [(YuvFormatPair, YuvDispatchBackend, f32); 28] = [
+    (
+        YuvFormatPair::RgbaToYuv420,
+        YuvDispatchBackend::Scalar,
+        0.65,
+    ),
+    (YuvFormatPair::RgbaToYuv420, YuvDispatchBackend::Sse41, 2.79),
+    (
+        YuvFormatPair::RgbaToYuv422,
+        YuvDispatchBackend::Scalar,
+        0.51,
+    ),
+    (YuvFormatPair::RgbaToYuv422, YuvDispatchBackend::Sse41, 2.05),
+    (
+        YuvFormatPair::RgbaToYuv444,
+        YuvDispatchBackend::Scalar,
+        0.59,
+    ),
+    (YuvFormatPair::RgbaToYuv444, YuvDispatchBackend::Sse41, 2.83),
+    (
+        YuvFormatPair::Yuv420ToRgba,
+        YuvDispatchBackend::Scalar,
+        0.57,
+    ),
+    (YuvFormatPair::Yuv420ToRgba, YuvDispatchBackend::Sse41, 5.73),
+    (
+        YuvFormatPair::Yuv422ToRgba,
+        YuvDispatchBackend::Scalar,
+        0.69,
+    ),
+    (YuvFormatPair::Yuv422ToRgba, YuvDispatchBackend::Sse41, 6.15),
+    (
+        YuvFormatPair::Yuv444ToRgba,
+        YuvDispatchBackend::Scalar,
+        0.71,
+    ),
+    (YuvFormatPair::Yuv444ToRgba, YuvDispatchBackend::Sse41, 7.19),
+    (
+        YuvFormatPair::RgbaToYuvNv12,
+        YuvDispatchBackend::Scalar,
+        0.59,
+    ),
+    (
+        YuvFormatPair::RgbaToYuvNv12,
+        YuvDispatchBackend::Sse41,
+        2.38,
+    ),
+    (
+        YuvFormatPair::YuvNv12ToRgba,
+        YuvDispatchBackend::Scalar,
+        0.50,
+    ),
+    (
+        YuvFormatPair::YuvNv12ToRgba,
+        YuvDispatchBackend::Sse41,
+        5.38,
+    ),
+    (
+        YuvFormatPair::RgbaToYuv400,
+        YuvDispatchBackend::Scalar,
+        1.37,
+    ),
+    (YuvFormatPair::RgbaToYuv400, YuvDispatchBackend::Sse41, 4.58),
+    (
+        YuvFormatPair::Yuv400ToRgba,
+        YuvDispatchBackend::Scalar,
+        2.32,
+    ),
+    (YuvFormatPair::Yuv400ToRgba, YuvDispatchBackend::Sse41, 2.39),
+    (
+        YuvFormatPair::Yuv420ToYuyv422,
+        YuvDispatchBackend::Scalar,
+        0.75,
+    ),
+    (
+        YuvFormatPair::Yuv420ToYuyv422,
+        YuvDispatchBackend::Sse41,
+        13.52,
+    ),
+    (
+        YuvFormatPair::Yuyv422ToYuv420,
+        YuvDispatchBackend::Scalar,
+        1.06,
+    ),
+    (
+        YuvFormatPair::Yuyv422ToYuv420,
+        YuvDispatchBackend::Sse41,
+        10.48,
+    ),
+    (
+        YuvFormatPair::Yuyv422ToRgba,
+        YuvDispatchBackend::Scalar,
+        0.60,
+    ),
+    (
+        YuvFormatPair::Yuyv422ToRgba,
+        YuvDispatchBackend::Sse41,
+        4.49,
+    ),
+    (
+        YuvFormatPair::Yuv420ToYuvNv12,
+        YuvDispatchBackend::Scalar,
+        8.26,
+    ),
+    (
+        YuvFormatPair::YuvNv12ToYuv420,
+        YuvDispatchBackend::Scalar,
+        5.87,
+    ),
+];
+
+static CALIBRATED: RwLock<Vec<(YuvFormatPair, GBps)>> = RwLock::new(Vec::new());
+
+/// Returns expected single threaded throughput of the conversion on the current machine.
+///
+/// Value measured with [set_expected_throughput] is preferred, otherwise compile-time
+/// baseline for the backend that runtime dispatch picks is returned.
+/// Baseline has only scalar and SSE 4.1 figures, AVX2 and AVX-512 answer with the SSE 4.1
+/// figure and NEON and wasm with the scalar one. Those are conservative, faster backends
+/// should be calibrated on the target with [set_expected_throughput].
+/// Figures are a budgeting aid measured on a reference machine, not a hard bound.
+///
+/// # Arguments
+///
+/// * `format_pair` - Conversion to query.
+///
+pub fn query_expected_throughput(format_pair: YuvFormatPair) -> Option<GBps> {
+    if let Ok(lock) = CALIBRATED.read() {
+        if let Some((_, value)) = lock.iter().find(|x| x.0 == format_pair) {
+            return Some(*value);
+        }
+    }
+    let mut backend = Some(format_pair.backend());
+    while let Some(current) = backend {
+        if let Some(entry) = BASELINE
+            .iter()
+            .find(|x| x.0 == format_pair && x.1 == current)
+        {
+            return Some(GBps(entry.2));
+        }
+        backend = current.measured_fallback();
+    }
+    None
+}
+
+/// Overrides compile-time baseline with throughput measured on the current machine,
+/// `None` restores the baseline.
+///
+/// # Arguments
+///
+/// * `format_pair` - Measured conversion.
+/// * `throughput` - Measured throughput, see [YuvFormatPair::frame_bytes] for the amount of bytes per frame.
+///
+pub fn set_expected_throughput(format_pair: YuvFormatPair, throughput: Option<GBps>) {
+    if let Ok(mut lock) = CALIBRATED.write() {
+        lock.retain(|x| x.0 != format_pair);
+        if let Some(throughput) = throughput {
+            lock.push((format_pair, throughput));
+        }
+    }
+}
@@ -0,0 +1,642 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{BufferStoreMut, ImageSize, RowStride};
+use crate::scratch::{scratch_bytes, YuvScratchArena};
+use crate::yuv_error::{
+    check_interleaved_chroma_channel, check_overflow_v3, check_rgba_destination, check_y8_channel,
+    MismatchedSize,
+};
+use crate::yuv_nv_to_rgba::yuv_nv_to_rgbx;
+use crate::yuv_support::{YuvChromaSample, YuvNVOrder, YuvSourceChannels};
+use crate::{YuvError, YuvRange, YuvStandardMatrix};
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Tiled NV12 layouts produced by hardware decoders
+pub enum YuvTileLayout {
+    /// Samsung `NV12MT`, 64x32 tiles in both planes ordered in `Z` flipped pattern
+    /// over pairs of tile rows ( V4L2_PIX_FMT_NV12MT ).
+    Nv12Mt64x32 = 0,
+    /// MediaTek `MM21` ( uncompressed `MT21` ), 16x32 luma tiles and 16x16 chroma tiles
+    /// in linear order ( V4L2_PIX_FMT_MM21 ). Compressed `MT21C` is not supported.
+    Mm21 = 1,
+}
+
+impl YuvTileLayout {
+    #[inline]
+    const fn tile_width(self) -> usize {
+        match self {
+            YuvTileLayout::Nv12Mt64x32 => 64,
+            YuvTileLayout::Mm21 => 16,
+        }
+    }
+
+    #[inline]
+    const fn luma_tile_height(self) -> usize {
+        match self {
+            YuvTileLayout::Nv12Mt64x32 => 32,
+            YuvTileLayout::Mm21 => 32,
+        }
+    }
+
+    #[inline]
+    const fn chroma_tile_height(self) -> usize {
+        match self {
+            YuvTileLayout::Nv12Mt64x32 => 32,
+            YuvTileLayout::Mm21 => 16,
+        }
+    }
+
+    /// `Z` flipped order walks tiles by column pairs, so NV12MT rows hold even tiles count
+    #[inline]
+    const fn x_tiles(self, row_width: usize) -> usize {
+        let x_tiles = row_width.div_ceil(self.tile_width());
+        match self {
+            YuvTileLayout::Nv12Mt64x32 => (x_tiles + 1) & !1,
+            YuvTileLayout::Mm21 => x_tiles,
+        }
+    }
+
+    #[inline]
+    const fn tile_index(self, x: usize, y: usize, x_tiles: usize, y_tiles: usize) -> usize {
+        match self {
+            YuvTileLayout::Nv12Mt64x32 => {
+                let mut offset = (y & !1) * x_tiles + x;
+                if y & 1 == 1 {
+                    offset += (x & !3) + 2;
+                } else if (y_tiles & 1) == 0 || y != (y_tiles - 1) {
+                    offset += (x + 2) & !3;
+                }
+                offset
+            }
+            YuvTileLayout::Mm21 => y * x_tiles + x,
+        }
+    }
+}
+
+/// Tiled plane geometry: bytes per row to restore, rows, tile height and tiles count
+#[derive(Debug, Copy, Clone)]
+struct TiledPlane {
+    row_width: usize,
+    rows: usize,
+    tile_height: usize,
+    x_tiles: usize,
+    y_tiles: usize,
+}
+
+impl TiledPlane {
+    fn new(layout: YuvTileLayout, row_width: usize, rows: usize, tile_height: usize) -> Self {
+        TiledPlane {
+            row_width,
+            rows,
+            tile_height,
+            x_tiles: layout.x_tiles(row_width),
+            y_tiles: rows.div_ceil(tile_height),
+        }
+    }
+
+    fn check(&self, layout: YuvTileLayout, data: &[u8]) -> Result<(), YuvError> {
+        check_overflow_v3(
+            self.x_tiles * self.y_tiles,
+            layout.tile_width(),
+            self.tile_height,
+        )?;
+        let required = self.x_tiles * self.y_tiles * layout.tile_width() * self.tile_height;
+        if data.len() < required {
+            return Err(YuvError::LumaPlaneMinimumSizeMismatch(MismatchedSize {
+                expected: required,
+                received: data.len(),
+            }));
+        }
+        Ok(())
+    }
+
+    /// Gathers single linear row from the tiles
+    #[inline]
+    fn detile_row(&self, layout: YuvTileLayout, tiled: &[u8], row: usize, dst: &mut [u8]) {
+        let tile_width = layout.tile_width();
+        let tile_size = tile_width * self.tile_height;
+        let tile_y = row / self.tile_height;
+        let in_tile_offset = (row % self.tile_height) * tile_width;
+        for (tile_x, dst) in dst[..self.row_width].chunks_mut(tile_width).enumerate() {
+            let tile = layout.tile_index(tile_x, tile_y, self.x_tiles, self.y_tiles);
+            let src_start = tile * tile_size + in_tile_offset;
+            dst.copy_from_slice(&tiled[src_start..(src_start + dst.len())]);
+        }
+    }
+}
+
+/// Converts tiled NV12 into linear NV12 layout.
+///
+/// Tiled planes must hold whole tiles, i.e. luma at least `ceil(width / tile_width) * ceil(height / tile_height)`
+/// tiles and chroma the same for `ceil(height / 2)` rows. NV12MT rows always hold even amount of tiles,
+/// so its width is effectively aligned to 128.
+///
+/// # Arguments
+///
+/// * `tiled_y_plane` - A slice to load tiled Y (luminance) plane.
+/// * `tiled_uv_plane` - A slice to load tiled UV (chrominance) plane.
+/// * `y_plane` - A mutable slice to store linear Y plane.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A mutable slice to store linear UV plane.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `size` - The size of the image.
+/// * `layout` - Tiles layout, see [YuvTileLayout].
+///
+pub fn tiled_nv12_to_nv12(
+    tiled_y_plane: &[u8],
+    tiled_uv_plane: &[u8],
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    uv_plane: &mut [u8],
+    uv_stride: RowStride,
+    size: ImageSize,
+    layout: YuvTileLayout,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, uv_stride) = (y_stride.get(), uv_stride.get());
+    if width == 0 || height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_interleaved_chroma_channel(uv_plane, uv_stride, width, height, YuvChromaSample::YUV420)?;
+
+    let luma = TiledPlane::new(
+        layout,
+        width as usize,
+        height as usize,
+        layout.luma_tile_height(),
+    );
+    let chroma = TiledPlane::new(
+        layout,
+        width.div_ceil(2) as usize * 2,
+        height.div_ceil(2) as usize,
+        layout.chroma_tile_height(),
+    );
+    luma.check(layout, tiled_y_plane)?;
+    chroma.check(layout, tiled_uv_plane)?;
+
+    for (row, dst) in y_plane.chunks_mut(y_stride as usize).enumerate() {
+        luma.detile_row(layout, tiled_y_plane, row, dst);
+    }
+    for (row, dst) in uv_plane.chunks_mut(uv_stride as usize).enumerate() {
+        chroma.detile_row(layout, tiled_uv_plane, row, dst);
+    }
+    Ok(())
+}
+
+fn tiled_nv12_to_rgbx<const DESTINATION_CHANNELS: u8>(
+    tiled_y_plane: &[u8],
+    tiled_uv_plane: &[u8],
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    layout: YuvTileLayout,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    scratch: Option<&mut YuvScratchArena>,
+) -> Result<(), YuvError> {
+    if width == 0 || height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    check_rgba_destination(
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        dst_chans.get_channels_count(),
+    )?;
+    let matrix = matrix.resolve(width, height);
+
+    let luma = TiledPlane::new(
+        layout,
+        width as usize,
+        height as usize,
+        layout.luma_tile_height(),
+    );
+    let chroma = TiledPlane::new(
+        layout,
+        width.div_ceil(2) as usize * 2,
+        height.div_ceil(2) as usize,
+        layout.chroma_tile_height(),
+    );
+    luma.check(layout, tiled_y_plane)?;
+    chroma.check(layout, tiled_uv_plane)?;
+
+    // Detiling is done in bands of luma tile height to keep scratch in cache,
+    // band height is even so chroma rows always belongs to a single band
+    let band_height = layout.luma_tile_height();
+    let y_band_length = luma.row_width * band_height;
+    let uv_band_length = chroma.row_width * band_height / 2;
+    let (mut y_band_store, mut uv_band_store) = match scratch {
+        Some(arena) => (
+            arena.alloc::<u8>(y_band_length)?,
+            arena.alloc::<u8>(uv_band_length)?,
+        ),
+        None => (
+            BufferStoreMut::try_alloc(y_band_length)?,
+            BufferStoreMut::try_alloc(uv_band_length)?,
+        ),
+    };
+    let (y_band, uv_band) = (y_band_store.borrow_mut(), uv_band_store.borrow_mut());
+
+    for (band, rgba_band) in rgba
+        .chunks_mut(rgba_stride as usize * band_height)
+        .enumerate()
+    {
+        let first_row = band * band_height;
+        let rows = band_height.min(luma.rows - first_row);
+        let chroma_rows = rows.div_ceil(2);
+        for row in 0..rows {
+            let dst = &mut y_band[row * luma.row_width..(row + 1) * luma.row_width];
+            luma.detile_row(layout, tiled_y_plane, first_row + row, dst);
+        }
+        for row in 0..chroma_rows {
+            let dst = &mut uv_band[row * chroma.row_width..(row + 1) * chroma.row_width];
+            chroma.detile_row(layout, tiled_uv_plane, first_row / 2 + row, dst);
+        }
+        yuv_nv_to_rgbx::<
+            { YuvNVOrder::UV as u8 },
+            DESTINATION_CHANNELS,
+            { YuvChromaSample::YUV420 as u8 },
+        >(
+            &y_band[..luma.row_width * rows],
+            luma.row_width as u32,
+            &uv_band[..chroma.row_width * chroma_rows],
+            chroma.row_width as u32,
+            rgba_band,
+            rgba_stride,
+            width,
+            rows as u32,
+            range,
+            matrix,
+        )?;
+    }
+    Ok(())
+}
+
+/// Convert tiled NV12 format to RGB format.
+///
+/// This function takes tiled NV12 data with 8-bit precision, produced by hardware decoders,
+/// and converts it to RGB format with 8-bit per channel precision without intermediate linear frame.
+///
+/// # Arguments
+///
+/// * `tiled_y_plane` - A slice to load tiled Y (luminance) plane.
+/// * `tiled_uv_plane` - A slice to load tiled UV (chrominance) plane.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `size` - The size of the image.
+/// * `layout` - Tiles layout, see [YuvTileLayout].
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn tiled_nv12_to_rgb(
+    tiled_y_plane: &[u8],
+    tiled_uv_plane: &[u8],
+    rgb: &mut [u8],
+    rgb_stride: RowStride,
+    size: ImageSize,
+    layout: YuvTileLayout,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    tiled_nv12_to_rgbx::<{ YuvSourceChannels::Rgb as u8 }>(
+        tiled_y_plane,
+        tiled_uv_plane,
+        rgb,
+        rgb_stride.get(),
+        size.width,
+        size.height,
+        layout,
+        range,
+        matrix,
+        None,
+    )
+}
+
+/// Convert tiled NV12 format to BGR format.
+///
+/// This function takes tiled NV12 data with 8-bit precision, produced by hardware decoders,
+/// and converts it to BGR format with 8-bit per channel precision without intermediate linear frame.
+///
+/// # Arguments
+///
+/// * `tiled_y_plane` - A slice to load tiled Y (luminance) plane.
+/// * `tiled_uv_plane` - A slice to load tiled UV (chrominance) plane.
+/// * `bgr` - A mutable slice to store the converted BGR data.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `size` - The size of the image.
+/// * `layout` - Tiles layout, see [YuvTileLayout].
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn tiled_nv12_to_bgr(
+    tiled_y_plane: &[u8],
+    tiled_uv_plane: &[u8],
+    bgr: &mut [u8],
+    bgr_stride: RowStride,
+    size: ImageSize,
+    layout: YuvTileLayout,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    tiled_nv12_to_rgbx::<{ YuvSourceChannels::Bgr as u8 }>(
+        tiled_y_plane,
+        tiled_uv_plane,
+        bgr,
+        bgr_stride.get(),
+        size.width,
+        size.height,
+        layout,
+        range,
+        matrix,
+        None,
+    )
+}
+
+/// Convert tiled NV12 format to RGBA format.
+///
+/// This function takes tiled NV12 data with 8-bit precision, produced by hardware decoders,
+/// and converts it to RGBA format with 8-bit per channel precision without intermediate linear frame.
+///
+/// # Arguments
+///
+/// * `tiled_y_plane` - A slice to load tiled Y (luminance) plane.
+/// * `tiled_uv_plane` - A slice to load tiled UV (chrominance) plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `size` - The size of the image.
+/// * `layout` - Tiles layout, see [YuvTileLayout].
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn tiled_nv12_to_rgba(
+    tiled_y_plane: &[u8],
+    tiled_uv_plane: &[u8],
+    rgba: &mut [u8],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    layout: YuvTileLayout,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    tiled_nv12_to_rgbx::<{ YuvSourceChannels::Rgba as u8 }>(
+        tiled_y_plane,
+        tiled_uv_plane,
+        rgba,
+        rgba_stride.get(),
+        size.width,
+        size.height,
+        layout,
+        range,
+        matrix,
+        None,
+    )
+}
+
+/// Convert tiled NV12 format to BGRA format.
+///
+/// This function takes tiled NV12 data with 8-bit precision, produced by hardware decoders,
+/// and converts it to BGRA format with 8-bit per channel precision without intermediate linear frame.
+///
+/// # Arguments
+///
+/// * `tiled_y_plane` - A slice to load tiled Y (luminance) plane.
+/// * `tiled_uv_plane` - A slice to load tiled UV (chrominance) plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `size` - The size of the image.
+/// * `layout` - Tiles layout, see [YuvTileLayout].
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn tiled_nv12_to_bgra(
+    tiled_y_plane: &[u8],
+    tiled_uv_plane: &[u8],
+    bgra: &mut [u8],
+    bgra_stride: RowStride,
+    size: ImageSize,
+    layout: YuvTileLayout,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    tiled_nv12_to_rgbx::<{ YuvSourceChannels::Bgra as u8 }>(
+        tiled_y_plane,
+        tiled_uv_plane,
+        bgra,
+        bgra_stride.get(),
+        size.width,
+        size.height,
+        layout,
+        range,
+        matrix,
+        None,
+    )
+}
+
+/// Returns count of bytes [YuvScratchArena] must hold for `tiled_nv12_to_*_with_scratch` conversions.
+///
+/// Includes worst case alignment padding, so any buffer of this size is enough.
+pub fn tiled_nv12_scratch_size(size: ImageSize, layout: YuvTileLayout) -> usize {
+    let band_height = layout.luma_tile_height();
+    let chroma_row_width = size.width.div_ceil(2) as usize * 2;
+    scratch_bytes::<u8>(size.width as usize * band_height)
+        + scratch_bytes::<u8>(chroma_row_width * band_height / 2)
+}
+
+/// Convert tiled NV12 format to RGB format using caller provided scratch memory.
+///
+/// Same as [tiled_nv12_to_rgb] but intermediate bands are taken from `scratch` instead of the heap,
+/// see [tiled_nv12_scratch_size] for required arena size.
+///
+/// # Arguments
+///
+/// * `tiled_y_plane` - A slice to load tiled Y (luminance) plane.
+/// * `tiled_uv_plane` - A slice to load tiled UV (chrominance) plane.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `size` - The size of the image.
+/// * `layout` - Tiles layout, see [YuvTileLayout].
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `scratch` - Arena to take intermediate buffers from.
+///
+pub fn tiled_nv12_to_rgb_with_scratch(
+    tiled_y_plane: &[u8],
+    tiled_uv_plane: &[u8],
+    rgb: &mut [u8],
+    rgb_stride: RowStride,
+    size: ImageSize,
+    layout: YuvTileLayout,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    scratch: &mut YuvScratchArena,
+) -> Result<(), YuvError> {
+    tiled_nv12_to_rgbx::<{ YuvSourceChannels::Rgb as u8 }>(
+        tiled_y_plane,
+        tiled_uv_plane,
+        rgb,
+        rgb_stride.get(),
+        size.width,
+        size.height,
+        layout,
+        range,
+        matrix,
+        Some(scratch),
+    )
+}
+
+/// Convert tiled NV12 format to BGR format using caller provided scratch memory.
+///
+/// Same as [tiled_nv12_to_bgr] but intermediate bands are taken from `scratch` instead of the heap,
+/// see [tiled_nv12_scratch_size] for required arena size.
+///
+/// # Arguments
+///
+/// * `tiled_y_plane` - A slice to load tiled Y (luminance) plane.
+/// * `tiled_uv_plane` - A slice to load tiled UV (chrominance) plane.
+/// * `bgr` - A mutable slice to store the converted BGR data.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `size` - The size of the image.
+/// * `layout` - Tiles layout, see [YuvTileLayout].
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `scratch` - Arena to take intermediate buffers from.
+///
+pub fn tiled_nv12_to_bgr_with_scratch(
+    tiled_y_plane: &[u8],
+    tiled_uv_plane: &[u8],
+    bgr: &mut [u8],
+    bgr_stride: RowStride,
+    size: ImageSize,
+    layout: YuvTileLayout,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    scratch: &mut YuvScratchArena,
+) -> Result<(), YuvError> {
+    tiled_nv12_to_rgbx::<{ YuvSourceChannels::Bgr as u8 }>(
+        tiled_y_plane,
+        tiled_uv_plane,
+        bgr,
+        bgr_stride.get(),
+        size.width,
+        size.height,
+        layout,
+        range,
+        matrix,
+        Some(scratch),
+    )
+}
+
+/// Convert tiled NV12 format to RGBA format using caller provided scratch memory.
+///
+/// Same as [tiled_nv12_to_rgba] but intermediate bands are taken from `scratch` instead of the heap,
+/// see [tiled_nv12_scratch_size] for required arena size.
+///
+/// # Arguments
+///
+/// * `tiled_y_plane` - A slice to load tiled Y (luminance) plane.
+/// * `tiled_uv_plane` - A slice to load tiled UV (chrominance) plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `size` - The size of the image.
+/// * `layout` - Tiles layout, see [YuvTileLayout].
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `scratch` - Arena to take intermediate buffers from.
+///
+pub fn tiled_nv12_to_rgba_with_scratch(
+    tiled_y_plane: &[u8],
+    tiled_uv_plane: &[u8],
+    rgba: &mut [u8],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    layout: YuvTileLayout,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    scratch: &mut YuvScratchArena,
+) -> Result<(), YuvError> {
+    tiled_nv12_to_rgbx::<{ YuvSourceChannels::Rgba as u8 }>(
+        tiled_y_plane,
+        tiled_uv_plane,
+        rgba,
+        rgba_stride.get(),
+        size.width,
+        size.height,
+        layout,
+        range,
+        matrix,
+        Some(scratch),
+    )
+}
+
+/// Convert tiled NV12 format to BGRA format using caller provided scratch memory.
+///
+/// Same as [tiled_nv12_to_bgra] but intermediate bands are taken from `scratch` instead of the heap,
+/// see [tiled_nv12_scratch_size] for required arena size.
+///
+/// # Arguments
+///
+/// * `tiled_y_plane` - A slice to load tiled Y (luminance) plane.
+/// * `tiled_uv_plane` - A slice to load tiled UV (chrominance) plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `size` - The size of the image.
+/// * `layout` - Tiles layout, see [YuvTileLayout].
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `scratch` - Arena to take intermediate buffers from.
+///
+pub fn tiled_nv12_to_bgra_with_scratch(
+    tiled_y_plane: &[u8],
+    tiled_uv_plane: &[u8],
+    bgra: &mut [u8],
+    bgra_stride: RowStride,
+    size: ImageSize,
+    layout: YuvTileLayout,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    scratch: &mut YuvScratchArena,
+) -> Result<(), YuvError> {
+    tiled_nv12_to_rgbx::<{ YuvSourceChannels::Bgra as u8 }>(
+        tiled_y_plane,
+        tiled_uv_plane,
+        bgra,
+        bgra_stride.get(),
+        size.width,
+        size.height,
+        layout,
+        range,
+        matrix,
+        Some(scratch),
+    )
+}
@@ -26,13 +26,25 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::image_to_gbr_avx;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::image_to_gbr_neon;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::image_to_gbr_sse;
+use crate::yuv_error::check_rgba_destination;
 use crate::yuv_support::YuvSourceChannels;
+use crate::YuvError;
 
 fn image_to_gbr<const SOURCE_CHANNELS: u8>(
     rgba: &[u8],
@@ -41,14 +53,22 @@ fn image_to_gbr<const SOURCE_CHANNELS: u8>(
     gbr_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     let source_channels: YuvSourceChannels = SOURCE_CHANNELS.into();
     let channels = source_channels.get_channels_count();
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    check_rgba_destination(gbr, gbr_stride, width, height, 3)?;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let _use_avx2 = std::arch::is_x86_feature_detected!("avx2");
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_avx2 = crate::cpu_features::has_avx2();
 
     let mut gbr_offset = 0usize;
     let mut rgba_offset = 0usize;
@@ -56,7 +76,10 @@ fn image_to_gbr<const SOURCE_CHANNELS: u8>(
     for _ in 0..height as usize {
         let mut _cx = 0usize;
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
             if _use_avx2 {
                 _cx = image_to_gbr_avx::<SOURCE_CHANNELS>(
@@ -80,7 +103,11 @@ fn image_to_gbr<const SOURCE_CHANNELS: u8>(
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             _cx = image_to_gbr_neon::<SOURCE_CHANNELS>(
                 rgba,
@@ -97,24 +124,24 @@ fn image_to_gbr<const SOURCE_CHANNELS: u8>(
 
             let rgba_shift = rgba_offset + px;
 
-            let dst_local = unsafe { rgba.get_unchecked(rgba_shift..) };
+            let dst_local = &rgba[rgba_shift..];
 
-            let r = unsafe { *dst_local.get_unchecked(source_channels.get_r_channel_offset()) };
-            let g = unsafe { *dst_local.get_unchecked(source_channels.get_g_channel_offset()) };
-            let b = unsafe { *dst_local.get_unchecked(source_channels.get_b_channel_offset()) };
+            let r = dst_local[source_channels.get_r_channel_offset()];
+            let g = dst_local[source_channels.get_g_channel_offset()];
+            let b = dst_local[source_channels.get_b_channel_offset()];
 
-            let gbr_local = unsafe { gbr.get_unchecked_mut((gbr_offset + x * 3)..) };
+            let gbr_local = &mut gbr[(gbr_offset + x * 3)..];
 
-            unsafe {
-                *gbr_local.get_unchecked_mut(0) = g;
-                *gbr_local.get_unchecked_mut(1) = b;
-                *gbr_local.get_unchecked_mut(2) = r;
-            }
+            gbr_local[0] = g;
+            gbr_local[1] = b;
+            gbr_local[2] = r;
         }
 
         gbr_offset += gbr_stride as usize;
         rgba_offset += rgba_stride as usize;
     }
+
+    Ok(())
 }
 
 /// Convert RGB to YUV Identity Matrix ( aka 'GBR )
@@ -143,7 +170,7 @@ pub fn rgb_to_gbr(
     gbr_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     image_to_gbr::<{ YuvSourceChannels::Rgb as u8 }>(
         rgb, rgb_stride, gbr, gbr_stride, width, height,
     )
@@ -175,7 +202,7 @@ pub fn bgr_to_gbr(
     gbr_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     image_to_gbr::<{ YuvSourceChannels::Bgr as u8 }>(
         bgr, bgr_stride, gbr, gbr_stride, width, height,
     )
@@ -207,7 +234,7 @@ pub fn bgra_to_gbr(
     gbr_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     image_to_gbr::<{ YuvSourceChannels::Bgra as u8 }>(
         bgra,
         bgra_stride,
@@ -244,7 +271,7 @@ pub fn rgba_to_gbr(
     gbr_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     image_to_gbr::<{ YuvSourceChannels::Rgba as u8 }>(
         rgba,
         rgba_stride,
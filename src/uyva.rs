@@ -0,0 +1,678 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_vec, ImageSize, RowStride};
+use crate::rgba_to_yuv::rgbx_to_yuv8;
+use crate::yuv_error::{check_rgba_destination, check_y8_channel, check_yuv_packed422};
+use crate::yuv_support::{
+    YuvChromaSample, YuvRange, YuvSourceChannels, YuvStandardMatrix, Yuy2Description,
+};
+use crate::yuv_to_yuy2::yuv_to_yuy2_impl;
+use crate::yuy2_to_rgb::yuy2_to_rgb_impl;
+use crate::yuy2_to_yuv::yuy2_to_yuv_impl;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+/// Size of UYVY part of NDI UYVA frame, alpha plane starts right after it
+#[inline]
+fn uyva_packed_size(uyva_stride: u32, height: u32) -> Result<usize, YuvError> {
+    (uyva_stride as usize)
+        .checked_mul(height as usize)
+        .ok_or(YuvError::PointerOverflow)
+}
+
+/// Splits NDI UYVA frame into UYVY rows and alpha plane, alpha rows are `uyva_stride / 2` bytes apart
+fn split_uyva(
+    uyva: &[u8],
+    uyva_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(&[u8], &[u8], u32), YuvError> {
+    let packed_size = uyva_packed_size(uyva_stride, height)?;
+    let (packed, alpha) = uyva.split_at(packed_size.min(uyva.len()));
+    let alpha_stride = uyva_stride / 2;
+    check_yuv_packed422(packed, uyva_stride, width, height)?;
+    check_y8_channel(alpha, alpha_stride, width, height)?;
+    Ok((packed, alpha, alpha_stride))
+}
+
+fn split_uyva_mut(
+    uyva: &mut [u8],
+    uyva_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(&mut [u8], &mut [u8], u32), YuvError> {
+    let packed_size = uyva_packed_size(uyva_stride, height)?;
+    let split = packed_size.min(uyva.len());
+    let (packed, alpha) = uyva.split_at_mut(split);
+    let alpha_stride = uyva_stride / 2;
+    check_yuv_packed422(packed, uyva_stride, width, height)?;
+    check_y8_channel(alpha, alpha_stride, width, height)?;
+    Ok((packed, alpha, alpha_stride))
+}
+
+/// Copies `width` samples of every row between two 8-bit planes
+fn copy_alpha_plane(
+    src: &[u8],
+    src_stride: u32,
+    dst: &mut [u8],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+) {
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride as usize)
+            .zip(src.par_chunks(src_stride as usize))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride as usize)
+            .zip(src.chunks(src_stride as usize))
+            .take(height as usize);
+    }
+    iter.for_each(|(dst, src)| {
+        dst[..width as usize].copy_from_slice(&src[..width as usize]);
+    });
+}
+
+fn uyva_to_rgbx<const DESTINATION_CHANNELS: u8>(
+    uyva: &[u8],
+    uyva_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (packed, alpha, alpha_stride) = split_uyva(uyva, uyva_stride, width, height)?;
+    yuy2_to_rgb_impl::<DESTINATION_CHANNELS, { Yuy2Description::UYVY as usize }>(
+        packed,
+        uyva_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )?;
+    let src_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = src_chans.get_channels_count();
+    let a_offset = src_chans.get_a_channel_offset();
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba
+            .par_chunks_mut(rgba_stride as usize)
+            .zip(alpha.par_chunks(alpha_stride as usize))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba
+            .chunks_mut(rgba_stride as usize)
+            .zip(alpha.chunks(alpha_stride as usize))
+            .take(height as usize);
+    }
+    iter.for_each(|(rgba, alpha)| {
+        for (dst, &a) in rgba
+            .chunks_exact_mut(channels)
+            .zip(alpha.iter())
+            .take(width as usize)
+        {
+            dst[a_offset] = a;
+        }
+    });
+    Ok(())
+}
+
+fn rgbx_to_uyva<const ORIGIN_CHANNELS: u8>(
+    uyva: &mut [u8],
+    uyva_stride: u32,
+    rgba: &[u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let channels = src_chans.get_channels_count();
+    let a_offset = src_chans.get_a_channel_offset();
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    let (packed, alpha, alpha_stride) = split_uyva_mut(uyva, uyva_stride, width, height)?;
+
+    let chroma_width = width.div_ceil(2);
+    let mut y_plane = try_vec(0u8, width as usize * height as usize)?;
+    let mut u_plane = try_vec(0u8, chroma_width as usize * height as usize)?;
+    let mut v_plane = try_vec(0u8, chroma_width as usize * height as usize)?;
+    rgbx_to_yuv8::<ORIGIN_CHANNELS, { YuvChromaSample::YUV422 as u8 }>(
+        &mut y_plane,
+        width,
+        &mut u_plane,
+        chroma_width,
+        &mut v_plane,
+        chroma_width,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )?;
+    yuv_to_yuy2_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::UYVY as usize }>(
+        &y_plane,
+        width,
+        &u_plane,
+        chroma_width,
+        &v_plane,
+        chroma_width,
+        packed,
+        uyva_stride,
+        width,
+        height,
+    )?;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = alpha
+            .par_chunks_mut(alpha_stride as usize)
+            .zip(rgba.par_chunks(rgba_stride as usize))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = alpha
+            .chunks_mut(alpha_stride as usize)
+            .zip(rgba.chunks(rgba_stride as usize))
+            .take(height as usize);
+    }
+    iter.for_each(|(alpha, rgba)| {
+        for (a, src) in alpha
+            .iter_mut()
+            .zip(rgba.chunks_exact(channels))
+            .take(width as usize)
+        {
+            *a = src[a_offset];
+        }
+    });
+    Ok(())
+}
+
+fn uyva_to_yuva<const SAMPLING: u8>(
+    y_plane: &mut [u8],
+    y_stride: u32,
+    u_plane: &mut [u8],
+    u_stride: u32,
+    v_plane: &mut [u8],
+    v_stride: u32,
+    a_plane: &mut [u8],
+    a_stride: u32,
+    uyva: &[u8],
+    uyva_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    let (packed, alpha, alpha_stride) = split_uyva(uyva, uyva_stride, width, height)?;
+    check_y8_channel(a_plane, a_stride, width, height)?;
+    yuy2_to_yuv_impl::<SAMPLING, { Yuy2Description::UYVY as usize }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        packed,
+        uyva_stride,
+        width,
+        height,
+    )?;
+    copy_alpha_plane(alpha, alpha_stride, a_plane, a_stride, width, height);
+    Ok(())
+}
+
+fn yuva_to_uyva<const SAMPLING: u8>(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    a_plane: &[u8],
+    a_stride: u32,
+    uyva: &mut [u8],
+    uyva_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    let (packed, alpha, alpha_stride) = split_uyva_mut(uyva, uyva_stride, width, height)?;
+    check_y8_channel(a_plane, a_stride, width, height)?;
+    yuv_to_yuy2_impl::<SAMPLING, { Yuy2Description::UYVY as usize }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        packed,
+        uyva_stride,
+        width,
+        height,
+    )?;
+    copy_alpha_plane(a_plane, a_stride, alpha, alpha_stride, width, height);
+    Ok(())
+}
+
+/// Convert NDI UYVA ( UYVY 4:2:2 with alpha plane ) to RGBA image.
+///
+/// NDI UYVA frame is `size.height` rows of UYVY followed right away by an 8-bit alpha plane
+/// of `size.height` rows, alpha rows are `uyva_stride / 2` bytes apart.
+///
+/// # Arguments
+///
+/// * `uyva` - A slice to load the UYVA frame.
+/// * `uyva_stride` - The stride (bytes per row) for the UYVY rows.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn uyva_to_rgba(
+    uyva: &[u8],
+    uyva_stride: RowStride,
+    rgba: &mut [u8],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (uyva_stride, rgba_stride) = (uyva_stride.get(), rgba_stride.get());
+    uyva_to_rgbx::<{ YuvSourceChannels::Rgba as u8 }>(
+        uyva,
+        uyva_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert NDI UYVA ( UYVY 4:2:2 with alpha plane ) to BGRA image.
+///
+/// NDI UYVA frame is `size.height` rows of UYVY followed right away by an 8-bit alpha plane
+/// of `size.height` rows, alpha rows are `uyva_stride / 2` bytes apart.
+///
+/// # Arguments
+///
+/// * `uyva` - A slice to load the UYVA frame.
+/// * `uyva_stride` - The stride (bytes per row) for the UYVY rows.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn uyva_to_bgra(
+    uyva: &[u8],
+    uyva_stride: RowStride,
+    bgra: &mut [u8],
+    bgra_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (uyva_stride, bgra_stride) = (uyva_stride.get(), bgra_stride.get());
+    uyva_to_rgbx::<{ YuvSourceChannels::Bgra as u8 }>(
+        uyva,
+        uyva_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert RGBA image to NDI UYVA ( UYVY 4:2:2 with alpha plane ).
+///
+/// NDI UYVA frame is `size.height` rows of UYVY followed right away by an 8-bit alpha plane
+/// of `size.height` rows, alpha rows are `uyva_stride / 2` bytes apart.
+///
+/// # Arguments
+///
+/// * `uyva` - A mutable slice to store the UYVA frame.
+/// * `uyva_stride` - The stride (bytes per row) for the UYVY rows.
+/// * `rgba` - A slice to load the RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn rgba_to_uyva(
+    uyva: &mut [u8],
+    uyva_stride: RowStride,
+    rgba: &[u8],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (uyva_stride, rgba_stride) = (uyva_stride.get(), rgba_stride.get());
+    rgbx_to_uyva::<{ YuvSourceChannels::Rgba as u8 }>(
+        uyva,
+        uyva_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert BGRA image to NDI UYVA ( UYVY 4:2:2 with alpha plane ).
+///
+/// NDI UYVA frame is `size.height` rows of UYVY followed right away by an 8-bit alpha plane
+/// of `size.height` rows, alpha rows are `uyva_stride / 2` bytes apart.
+///
+/// # Arguments
+///
+/// * `uyva` - A mutable slice to store the UYVA frame.
+/// * `uyva_stride` - The stride (bytes per row) for the UYVY rows.
+/// * `bgra` - A slice to load the BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn bgra_to_uyva(
+    uyva: &mut [u8],
+    uyva_stride: RowStride,
+    bgra: &[u8],
+    bgra_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (uyva_stride, bgra_stride) = (uyva_stride.get(), bgra_stride.get());
+    rgbx_to_uyva::<{ YuvSourceChannels::Bgra as u8 }>(
+        uyva,
+        uyva_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert NDI UYVA ( UYVY 4:2:2 with alpha plane ) to planar YUVA 4:2:2.
+///
+/// NDI UYVA frame is `size.height` rows of UYVY followed right away by an 8-bit alpha plane
+/// of `size.height` rows, alpha rows are `uyva_stride / 2` bytes apart.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `a_plane` - A mutable slice to store the alpha plane data.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `uyva` - A slice to load the UYVA frame.
+/// * `uyva_stride` - The stride (bytes per row) for the UYVY rows.
+/// * `size` - The size of the image.
+///
+pub fn uyva_to_yuva422(
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    u_plane: &mut [u8],
+    u_stride: RowStride,
+    v_plane: &mut [u8],
+    v_stride: RowStride,
+    a_plane: &mut [u8],
+    a_stride: RowStride,
+    uyva: &[u8],
+    uyva_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, a_stride, uyva_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        a_stride.get(),
+        uyva_stride.get(),
+    );
+    uyva_to_yuva::<{ YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        a_plane,
+        a_stride,
+        uyva,
+        uyva_stride,
+        width,
+        height,
+    )
+}
+
+/// Convert NDI UYVA ( UYVY 4:2:2 with alpha plane ) to planar YUVA 4:4:4.
+///
+/// NDI UYVA frame is `size.height` rows of UYVY followed right away by an 8-bit alpha plane
+/// of `size.height` rows, alpha rows are `uyva_stride / 2` bytes apart.
+///
+/// Chroma is duplicated to every pixel of a pair.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `a_plane` - A mutable slice to store the alpha plane data.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `uyva` - A slice to load the UYVA frame.
+/// * `uyva_stride` - The stride (bytes per row) for the UYVY rows.
+/// * `size` - The size of the image.
+///
+pub fn uyva_to_yuva444(
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    u_plane: &mut [u8],
+    u_stride: RowStride,
+    v_plane: &mut [u8],
+    v_stride: RowStride,
+    a_plane: &mut [u8],
+    a_stride: RowStride,
+    uyva: &[u8],
+    uyva_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, a_stride, uyva_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        a_stride.get(),
+        uyva_stride.get(),
+    );
+    uyva_to_yuva::<{ YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        a_plane,
+        a_stride,
+        uyva,
+        uyva_stride,
+        width,
+        height,
+    )
+}
+
+/// Convert planar YUVA 4:2:2 to NDI UYVA ( UYVY 4:2:2 with alpha plane ).
+///
+/// NDI UYVA frame is `size.height` rows of UYVY followed right away by an 8-bit alpha plane
+/// of `size.height` rows, alpha rows are `uyva_stride / 2` bytes apart.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `a_plane` - A slice to load the alpha plane data.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `uyva` - A mutable slice to store the UYVA frame.
+/// * `uyva_stride` - The stride (bytes per row) for the UYVY rows.
+/// * `size` - The size of the image.
+///
+pub fn yuva422_to_uyva(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    a_plane: &[u8],
+    a_stride: RowStride,
+    uyva: &mut [u8],
+    uyva_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, a_stride, uyva_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        a_stride.get(),
+        uyva_stride.get(),
+    );
+    yuva_to_uyva::<{ YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        a_plane,
+        a_stride,
+        uyva,
+        uyva_stride,
+        width,
+        height,
+    )
+}
+
+/// Convert planar YUVA 4:4:4 to NDI UYVA ( UYVY 4:2:2 with alpha plane ).
+///
+/// NDI UYVA frame is `size.height` rows of UYVY followed right away by an 8-bit alpha plane
+/// of `size.height` rows, alpha rows are `uyva_stride / 2` bytes apart.
+///
+/// Chroma of every pixel pair is averaged.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `a_plane` - A slice to load the alpha plane data.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `uyva` - A mutable slice to store the UYVA frame.
+/// * `uyva_stride` - The stride (bytes per row) for the UYVY rows.
+/// * `size` - The size of the image.
+///
+pub fn yuva444_to_uyva(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    a_plane: &[u8],
+    a_stride: RowStride,
+    uyva: &mut [u8],
+    uyva_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, a_stride, uyva_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        a_stride.get(),
+        uyva_stride.get(),
+    );
+    yuva_to_uyva::<{ YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        a_plane,
+        a_stride,
+        uyva,
+        uyva_stride,
+        width,
+        height,
+    )
+}
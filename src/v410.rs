@@ -0,0 +1,421 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_vec, ImageSize, RowStride};
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::neon::{neon_v410_to_yuv444_row, neon_yuv444_to_v410_row};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::sse::{sse_v410_to_yuv444_row, sse_yuv444_to_v410_row};
+use crate::yuv_error::{check_chroma_channel, check_rgba_destination, check_y8_channel};
+use crate::yuv_p10_ar30::yuv444_p10_to_ar30;
+use crate::yuv_p10_rgba::yuv444_p10_to_rgba;
+use crate::yuv_support::{
+    YuvBytesPacking, YuvChromaSample, YuvEndianness, YuvRange, YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+/// Splits v410 words into 10-bit planes, sizes must be already validated
+fn v410_to_planes(
+    v410: &[u32],
+    v410_stride: u32,
+    y_plane: &mut [u16],
+    y_stride: u32,
+    u_plane: &mut [u16],
+    u_stride: u32,
+    v_plane: &mut [u16],
+    v_stride: u32,
+    width: u32,
+    height: u32,
+) {
+    let width = width as usize;
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = v410
+            .par_chunks(v410_stride as usize / 4)
+            .zip(y_plane.par_chunks_mut(y_stride as usize / 2))
+            .zip(u_plane.par_chunks_mut(u_stride as usize / 2))
+            .zip(v_plane.par_chunks_mut(v_stride as usize / 2))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = v410
+            .chunks(v410_stride as usize / 4)
+            .zip(y_plane.chunks_mut(y_stride as usize / 2))
+            .zip(u_plane.chunks_mut(u_stride as usize / 2))
+            .zip(v_plane.chunks_mut(v_stride as usize / 2))
+            .take(height as usize);
+    }
+    iter.for_each(|(((v410, y_dst), u_dst), v_dst)| {
+        #[allow(unused_mut)]
+        let mut cx = 0usize;
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            if _use_sse {
+                cx = sse_v410_to_yuv444_row(v410, y_dst, u_dst, v_dst, width);
+            }
+        }
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            cx = neon_v410_to_yuv444_row(v410, y_dst, u_dst, v_dst, width);
+        }
+
+        for (((&word, y_dst), u_dst), v_dst) in v410
+            .iter()
+            .zip(y_dst.iter_mut())
+            .zip(u_dst.iter_mut())
+            .zip(v_dst.iter_mut())
+            .take(width)
+            .skip(cx)
+        {
+            let word = u32::from_le(word);
+            *u_dst = ((word >> 2) & 0x3ff) as u16;
+            *y_dst = ((word >> 12) & 0x3ff) as u16;
+            *v_dst = (word >> 22) as u16;
+        }
+    });
+}
+
+/// Convert v410 ( packed 10-bit 4:4:4 ) to YUV 444 planar format with 10-bit pixel format.
+///
+/// Each pixel of v410 is a little endian 32-bit word holding U in bits 2..12,
+/// Y in bits 12..22 and V in bits 22..32, two lowest bits are padding.
+///
+/// # Arguments
+///
+/// * `v410` - A slice to load v410 data.
+/// * `v410_stride` - The stride (bytes per row) for v410 data.
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `size` - The size of the image.
+///
+pub fn v410_to_yuv444_p10(
+    v410: &[u32],
+    v410_stride: RowStride,
+    y_plane: &mut [u16],
+    y_stride: RowStride,
+    u_plane: &mut [u16],
+    u_stride: RowStride,
+    v_plane: &mut [u16],
+    v_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (v410_stride, y_stride, u_stride, v_stride) = (
+        v410_stride.get(),
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+    );
+    check_rgba_destination(v410, v410_stride, width, height, 1)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, YuvChromaSample::YUV444)?;
+    check_chroma_channel(v_plane, v_stride, width, height, YuvChromaSample::YUV444)?;
+
+    v410_to_planes(
+        v410,
+        v410_stride,
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        width,
+        height,
+    );
+
+    Ok(())
+}
+
+/// Convert YUV 444 planar format with 10-bit pixel format to v410 ( packed 10-bit 4:4:4 ).
+///
+/// Samples are expected in lower 10 bits, upper bits are discarded.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `v410` - A mutable slice to store v410 data.
+/// * `v410_stride` - The stride (bytes per row) for v410 data.
+/// * `size` - The size of the image.
+///
+pub fn yuv444_p10_to_v410(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    u_plane: &[u16],
+    u_stride: RowStride,
+    v_plane: &[u16],
+    v_stride: RowStride,
+    v410: &mut [u32],
+    v410_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, v410_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        v410_stride.get(),
+    );
+    check_rgba_destination(v410, v410_stride, width, height, 1)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, YuvChromaSample::YUV444)?;
+    check_chroma_channel(v_plane, v_stride, width, height, YuvChromaSample::YUV444)?;
+
+    let width = width as usize;
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = v410
+            .par_chunks_mut(v410_stride as usize / 4)
+            .zip(y_plane.par_chunks(y_stride as usize / 2))
+            .zip(u_plane.par_chunks(u_stride as usize / 2))
+            .zip(v_plane.par_chunks(v_stride as usize / 2))
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = v410
+            .chunks_mut(v410_stride as usize / 4)
+            .zip(y_plane.chunks(y_stride as usize / 2))
+            .zip(u_plane.chunks(u_stride as usize / 2))
+            .zip(v_plane.chunks(v_stride as usize / 2))
+            .take(height as usize);
+    }
+    iter.for_each(|(((v410, y_src), u_src), v_src)| {
+        #[allow(unused_mut)]
+        let mut cx = 0usize;
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            if _use_sse {
+                cx = sse_yuv444_to_v410_row(y_src, u_src, v_src, v410, width);
+            }
+        }
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            cx = neon_yuv444_to_v410_row(y_src, u_src, v_src, v410, width);
+        }
+
+        for (((dst, &y_src), &u_src), &v_src) in v410
+            .iter_mut()
+            .zip(y_src.iter())
+            .zip(u_src.iter())
+            .zip(v_src.iter())
+            .take(width)
+            .skip(cx)
+        {
+            let word = ((u_src as u32 & 0x3ff) << 2)
+                | ((y_src as u32 & 0x3ff) << 12)
+                | ((v_src as u32 & 0x3ff) << 22);
+            *dst = word.to_le();
+        }
+    });
+
+    Ok(())
+}
+
+/// Unpacks v410 into tightly packed temporary planes and hands them to `convert`
+fn v410_with_planes(
+    v410: &[u32],
+    v410_stride: u32,
+    width: u32,
+    height: u32,
+    convert: impl FnOnce(&[u16], &[u16], &[u16], u32) -> Result<(), YuvError>,
+) -> Result<(), YuvError> {
+    check_rgba_destination(v410, v410_stride, width, height, 1)?;
+    let plane_size = width as usize * height as usize;
+    let mut y_plane = try_vec(0u16, plane_size)?;
+    let mut u_plane = try_vec(0u16, plane_size)?;
+    let mut v_plane = try_vec(0u16, plane_size)?;
+    let plane_stride = width * 2;
+    v410_to_planes(
+        v410,
+        v410_stride,
+        &mut y_plane,
+        plane_stride,
+        &mut u_plane,
+        plane_stride,
+        &mut v_plane,
+        plane_stride,
+        width,
+        height,
+    );
+    convert(&y_plane, &u_plane, &v_plane, plane_stride)
+}
+
+/// Convert v410 ( packed 10-bit 4:4:4 ) to RGBA format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `v410` - A slice to load v410 data.
+/// * `v410_stride` - The stride (bytes per row) for v410 data.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn v410_to_rgba(
+    v410: &[u32],
+    v410_stride: RowStride,
+    rgba: &mut [u8],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (v410_stride, rgba_stride) = (v410_stride.get(), rgba_stride.get());
+    check_rgba_destination(rgba, rgba_stride, width, height, 4)?;
+    v410_with_planes(
+        v410,
+        v410_stride,
+        width,
+        height,
+        |y_plane, u_plane, v_plane, stride| {
+            yuv444_p10_to_rgba(
+                y_plane,
+                stride,
+                u_plane,
+                stride,
+                v_plane,
+                stride,
+                rgba,
+                rgba_stride,
+                width,
+                height,
+                range,
+                matrix,
+                YuvEndianness::LittleEndian,
+                YuvBytesPacking::LeastSignificantBytes,
+            )
+        },
+    )
+}
+
+/// Convert v410 ( packed 10-bit 4:4:4 ) to AR30 keeping full 10-bit precision.
+///
+/// # Arguments
+///
+/// * `v410` - A slice to load v410 data.
+/// * `v410_stride` - The stride (bytes per row) for v410 data.
+/// * `ar30` - A mutable slice to store the converted AR30 data.
+/// * `ar30_stride` - The stride (bytes per row) for AR30 data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn v410_to_ar30(
+    v410: &[u32],
+    v410_stride: RowStride,
+    ar30: &mut [u32],
+    ar30_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (v410_stride, ar30_stride) = (v410_stride.get(), ar30_stride.get());
+    check_rgba_destination(ar30, ar30_stride, width, height, 1)?;
+    v410_with_planes(
+        v410,
+        v410_stride,
+        width,
+        height,
+        |y_plane, u_plane, v_plane, stride| {
+            yuv444_p10_to_ar30(
+                y_plane,
+                RowStride::new(stride),
+                u_plane,
+                RowStride::new(stride),
+                v_plane,
+                RowStride::new(stride),
+                ar30,
+                RowStride::new(ar30_stride),
+                size,
+                range,
+                matrix,
+                YuvEndianness::LittleEndian,
+                YuvBytesPacking::LeastSignificantBytes,
+            )
+        },
+    )
+}
@@ -0,0 +1,373 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_vec, ImageSize};
+use crate::rgba_to_nv::rgba_to_yuv_nv12;
+use crate::rgba_to_yuv::rgba_to_yuv420;
+use crate::yuv_nv_to_rgba::{yuv_nv12_to_rgba, yuv_nv21_to_rgba};
+use crate::yuv_support::{YuvRange, YuvStandardMatrix};
+use crate::yuv_to_rgba::{yuv420_to_rgba, yuv422_to_rgba, yuv444_to_rgba};
+use crate::YuvError;
+
+type NvToRgba = fn(
+    &[u8],
+    u32,
+    &[u8],
+    u32,
+    &mut [u8],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+) -> Result<(), YuvError>;
+
+type PlanarToRgba = fn(
+    &[u8],
+    u32,
+    &[u8],
+    u32,
+    &[u8],
+    u32,
+    &mut [u8],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+) -> Result<(), YuvError>;
+
+/// Y, U and V planes with tight strides
+type PlanarVec = (Vec<u8>, Vec<u8>, Vec<u8>);
+
+/// Allocates `width` x `height` plane of `channels` bytes per pixel with tight stride
+fn alloc_plane(width: u32, height: u32, channels: usize) -> Result<(Vec<u8>, u32), YuvError> {
+    let stride = width as usize * channels;
+    let plane = try_vec(0u8, stride * height as usize)?;
+    Ok((plane, stride as u32))
+}
+
+fn decode_nv_to_rgba_vec(
+    y_plane: &[u8],
+    uv_plane: &[u8],
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    convert: NvToRgba,
+) -> Result<Vec<u8>, YuvError> {
+    let (mut rgba, rgba_stride) = alloc_plane(width, height, 4)?;
+    convert(
+        y_plane,
+        width,
+        uv_plane,
+        width.div_ceil(2) * 2,
+        &mut rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )?;
+    Ok(rgba)
+}
+
+fn decode_planar_to_rgba_vec(
+    y_plane: &[u8],
+    u_plane: &[u8],
+    v_plane: &[u8],
+    chroma_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    convert: PlanarToRgba,
+) -> Result<Vec<u8>, YuvError> {
+    let (mut rgba, rgba_stride) = alloc_plane(width, height, 4)?;
+    convert(
+        y_plane,
+        width,
+        u_plane,
+        chroma_stride,
+        v_plane,
+        chroma_stride,
+        &mut rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )?;
+    Ok(rgba)
+}
+
+/// Convert tightly packed NV12 to a newly allocated RGBA buffer.
+///
+/// Luma stride is expected to be `width`, UV stride `2 * ceil(width / 2)`,
+/// returned RGBA has stride `4 * width`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `uv_plane` - A slice to load the interleaved UV (chrominance) plane data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn decode_nv12_to_rgba_vec(
+    y_plane: &[u8],
+    uv_plane: &[u8],
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<Vec<u8>, YuvError> {
+    let (width, height) = (size.width, size.height);
+    decode_nv_to_rgba_vec(
+        y_plane,
+        uv_plane,
+        width,
+        height,
+        range,
+        matrix,
+        yuv_nv12_to_rgba,
+    )
+}
+
+/// Convert tightly packed NV21 to a newly allocated RGBA buffer.
+///
+/// Luma stride is expected to be `width`, VU stride `2 * ceil(width / 2)`,
+/// returned RGBA has stride `4 * width`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `vu_plane` - A slice to load the interleaved VU (chrominance) plane data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn decode_nv21_to_rgba_vec(
+    y_plane: &[u8],
+    vu_plane: &[u8],
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<Vec<u8>, YuvError> {
+    let (width, height) = (size.width, size.height);
+    decode_nv_to_rgba_vec(
+        y_plane,
+        vu_plane,
+        width,
+        height,
+        range,
+        matrix,
+        yuv_nv21_to_rgba,
+    )
+}
+
+/// Convert tightly packed YUV 420 planar ( I420 ) to a newly allocated RGBA buffer.
+///
+/// Luma stride is expected to be `width`, chroma strides `ceil(width / 2)`,
+/// returned RGBA has stride `4 * width`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn decode_yuv420_to_rgba_vec(
+    y_plane: &[u8],
+    u_plane: &[u8],
+    v_plane: &[u8],
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<Vec<u8>, YuvError> {
+    let (width, height) = (size.width, size.height);
+    decode_planar_to_rgba_vec(
+        y_plane,
+        u_plane,
+        v_plane,
+        width.div_ceil(2),
+        width,
+        height,
+        range,
+        matrix,
+        yuv420_to_rgba,
+    )
+}
+
+/// Convert tightly packed YUV 422 planar ( I422 ) to a newly allocated RGBA buffer.
+///
+/// Luma stride is expected to be `width`, chroma strides `ceil(width / 2)`,
+/// returned RGBA has stride `4 * width`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn decode_yuv422_to_rgba_vec(
+    y_plane: &[u8],
+    u_plane: &[u8],
+    v_plane: &[u8],
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<Vec<u8>, YuvError> {
+    let (width, height) = (size.width, size.height);
+    decode_planar_to_rgba_vec(
+        y_plane,
+        u_plane,
+        v_plane,
+        width.div_ceil(2),
+        width,
+        height,
+        range,
+        matrix,
+        yuv422_to_rgba,
+    )
+}
+
+/// Convert tightly packed YUV 444 planar ( I444 ) to a newly allocated RGBA buffer.
+///
+/// All plane strides are expected to be `width`, returned RGBA has stride `4 * width`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn decode_yuv444_to_rgba_vec(
+    y_plane: &[u8],
+    u_plane: &[u8],
+    v_plane: &[u8],
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<Vec<u8>, YuvError> {
+    let (width, height) = (size.width, size.height);
+    decode_planar_to_rgba_vec(
+        y_plane,
+        u_plane,
+        v_plane,
+        width,
+        width,
+        height,
+        range,
+        matrix,
+        yuv444_to_rgba,
+    )
+}
+
+/// Convert tightly packed RGBA to newly allocated NV12 planes.
+///
+/// RGBA stride is expected to be `4 * width`, returns Y plane with stride `width`
+/// and UV plane with stride `2 * ceil(width / 2)`.
+///
+/// # Arguments
+///
+/// * `rgba` - A slice to load RGBA data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn encode_rgba_to_nv12_vec(
+    rgba: &[u8],
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(Vec<u8>, Vec<u8>), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (mut y_plane, y_stride) = alloc_plane(width, height, 1)?;
+    let (mut uv_plane, uv_stride) = alloc_plane(width.div_ceil(2), height.div_ceil(2), 2)?;
+    rgba_to_yuv_nv12(
+        &mut y_plane,
+        y_stride,
+        &mut uv_plane,
+        uv_stride,
+        rgba,
+        width * 4,
+        width,
+        height,
+        range,
+        matrix,
+    )?;
+    Ok((y_plane, uv_plane))
+}
+
+/// Convert tightly packed RGBA to newly allocated YUV 420 planes ( I420 ).
+///
+/// RGBA stride is expected to be `4 * width`, returns Y plane with stride `width`
+/// and U, V planes with stride `ceil(width / 2)`.
+///
+/// # Arguments
+///
+/// * `rgba` - A slice to load RGBA data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn encode_rgba_to_yuv420_vec(
+    rgba: &[u8],
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<PlanarVec, YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (mut y_plane, y_stride) = alloc_plane(width, height, 1)?;
+    let (mut u_plane, u_stride) = alloc_plane(width.div_ceil(2), height.div_ceil(2), 1)?;
+    let (mut v_plane, v_stride) = alloc_plane(width.div_ceil(2), height.div_ceil(2), 1)?;
+    rgba_to_yuv420(
+        &mut y_plane,
+        y_stride,
+        &mut u_plane,
+        u_stride,
+        &mut v_plane,
+        v_stride,
+        rgba,
+        width * 4,
+        width,
+        height,
+        range,
+        matrix,
+    )?;
+    Ok((y_plane, u_plane, v_plane))
+}
@@ -0,0 +1,250 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::cpu_features::YuvDispatchBackend;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::cpu_features::{disable_backend, set_verification_limit};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::{
+    rgba_to_yuv420, yuv420_to_rgba, yuv_nv12_to_rgba, YuvError, YuvRange, YuvStandardMatrix,
+};
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+const PROBE_WIDTH: u32 = 67;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+const PROBE_HEIGHT: u32 = 6;
+/// Slack for rounding differences between fixed point kernels,
+/// anything above this is treated as a broken backend
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+const PROBE_TOLERANCE: u8 = 2;
+
+/// Keeps concurrent callers from interleaving their temporary dispatch caps
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+static VERIFICATION_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+/// Outputs of the probe conversions, compared between backends
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+struct ProbeResult {
+    y_plane: Vec<u8>,
+    u_plane: Vec<u8>,
+    v_plane: Vec<u8>,
+    rgba: Vec<u8>,
+    nv_rgba: Vec<u8>,
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+impl ProbeResult {
+    fn run() -> Result<ProbeResult, YuvError> {
+        let (width, height) = (PROBE_WIDTH, PROBE_HEIGHT);
+        let chroma_width = width.div_ceil(2);
+        let chroma_height = height.div_ceil(2);
+        let pixels = (width * height) as usize;
+        let chroma_pixels = (chroma_width * chroma_height) as usize;
+
+        let source = (0..pixels * 4)
+            .map(|i| ((i * 73 + (i / 4) * 29) % 256) as u8)
+            .collect::<Vec<u8>>();
+
+        let mut result = ProbeResult {
+            y_plane: vec![0u8; pixels],
+            u_plane: vec![0u8; chroma_pixels],
+            v_plane: vec![0u8; chroma_pixels],
+            rgba: vec![0u8; pixels * 4],
+            nv_rgba: vec![0u8; pixels * 4],
+        };
+
+        rgba_to_yuv420(
+            &mut result.y_plane,
+            width,
+            &mut result.u_plane,
+            chroma_width,
+            &mut result.v_plane,
+            chroma_width,
+            &source,
+            width * 4,
+            width,
+            height,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )?;
+
+        // Decoders are probed on fixed planes so forward errors do not propagate
+        let y_plane = (0..pixels)
+            .map(|i| (16 + (i * 37) % 220) as u8)
+            .collect::<Vec<u8>>();
+        let u_plane = (0..chroma_pixels)
+            .map(|i| (16 + (i * 53) % 225) as u8)
+            .collect::<Vec<u8>>();
+        let v_plane = (0..chroma_pixels)
+            .map(|i| (16 + (i * 89) % 225) as u8)
+            .collect::<Vec<u8>>();
+        let uv_plane = u_plane
+            .iter()
+            .zip(v_plane.iter())
+            .flat_map(|(&u, &v)| [u, v])
+            .collect::<Vec<u8>>();
+
+        yuv420_to_rgba(
+            &y_plane,
+            width,
+            &u_plane,
+            chroma_width,
+            &v_plane,
+            chroma_width,
+            &mut result.rgba,
+            width * 4,
+            width,
+            height,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )?;
+
+        yuv_nv12_to_rgba(
+            &y_plane,
+            width,
+            &uv_plane,
+            chroma_width * 2,
+            &mut result.nv_rgba,
+            width * 4,
+            width,
+            height,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )?;
+
+        Ok(result)
+    }
+
+    fn matches(&self, reference: &ProbeResult) -> bool {
+        let close = |a: &[u8], b: &[u8]| {
+            a.iter()
+                .zip(b.iter())
+                .all(|(&a, &b)| a.abs_diff(b) <= PROBE_TOLERANCE)
+        };
+        close(&self.y_plane, &reference.y_plane)
+            && close(&self.u_plane, &reference.u_plane)
+            && close(&self.v_plane, &reference.v_plane)
+            && close(&self.rgba, &reference.rgba)
+            && close(&self.nv_rgba, &reference.nv_rgba)
+    }
+}
+
+/// Runs a few tiny conversions on every detected x86 backend and compares them with scalar code,
+/// backends producing wrong results are disabled for the rest of the process.
+///
+/// Meant for services that must not trust SIMD units blindly, e.g. under emulators
+/// with incomplete AVX-512 support. This is opt-in and should be called once at startup
+/// before any other conversion is running, dispatch is temporarily capped while probing.
+/// Concurrent calls are serialized, conversions running meanwhile on other threads stay correct
+/// but may be dispatched to a slower backend until probing is over.
+///
+/// Returns backends that were disabled. NEON and wasm backends are chosen at compile time
+/// and cannot be verified this way, for them this returns an empty list.
+pub fn verify_backends() -> Vec<YuvDispatchBackend> {
+    #[allow(unused_mut)]
+    let mut disabled = Vec::new();
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    {
+        let candidates = [
+            (
+                YuvDispatchBackend::Sse41,
+                std::arch::is_x86_feature_detected!("sse4.1"),
+            ),
+            (
+                YuvDispatchBackend::Avx2,
+                std::arch::is_x86_feature_detected!("avx2"),
+            ),
+            (
+                YuvDispatchBackend::Avx512Bw,
+                cfg!(feature = "nightly_avx512") && std::arch::is_x86_feature_detected!("avx512bw"),
+            ),
+        ];
+
+        let _guard = VERIFICATION_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        set_verification_limit(0);
+        let reference = ProbeResult::run();
+        set_verification_limit(u8::MAX);
+        let reference = match reference {
+            Ok(reference) => reference,
+            Err(_) => return disabled,
+        };
+
+        for (backend, detected) in candidates {
+            let rank = match backend.x86_rank() {
+                Some(rank) => rank,
+                None => continue,
+            };
+            if !detected {
+                continue;
+            }
+            set_verification_limit(rank);
+            let valid = match ProbeResult::run() {
+                Ok(probe) => probe.matches(&reference),
+                Err(_) => false,
+            };
+            set_verification_limit(u8::MAX);
+            if !valid {
+                disable_backend(rank);
+                disabled.push(backend);
+            }
+        }
+    }
+
+    disabled
+}
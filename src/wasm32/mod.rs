@@ -31,7 +31,11 @@ mod utils;
 mod y_to_rgb;
 mod yuv_nv_to_rgba;
 mod yuv_to_rgba;
+mod yuy2_to_rgb;
+mod yuy2_to_yuv;
 
 pub use y_to_rgb::wasm_y_to_rgb_row;
 pub use yuv_nv_to_rgba::wasm_yuv_nv_to_rgba_row;
 pub use yuv_to_rgba::wasm_yuv_to_rgba_row;
+pub use yuy2_to_rgb::yuy2_to_rgb_wasm;
+pub use yuy2_to_yuv::yuy2_to_yuv_wasm;
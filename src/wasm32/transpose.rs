@@ -27,6 +27,7 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 use crate::wasm32::utils::{wasm_unpackhi_i8x16, wasm_unpacklo_i8x16};
+use crate::yuv_support::Yuy2Description;
 use std::arch::wasm32::*;
 
 #[inline]
@@ -50,6 +51,25 @@ pub unsafe fn v128_load_deinterleave_half_u8_x2(ptr: *const u8) -> (v128, v128)
     v128_deinterleave_u8_x2(a, b)
 }
 
+/// Splits 8 packed 4:2:2 macro-pixels into 16 luma values and 8 values for each chroma
+#[inline]
+pub unsafe fn wasm_deinterleave_yuy2(
+    yuy2_source: Yuy2Description,
+    a: v128,
+    b: v128,
+) -> (v128, v128, v128) {
+    let (even, odd) = v128_deinterleave_u8_x2(a, b);
+    let (y_values, chroma) = match yuy2_source {
+        Yuy2Description::YUYV | Yuy2Description::YVYU => (even, odd),
+        Yuy2Description::UYVY | Yuy2Description::VYUY => (odd, even),
+    };
+    let (c_even, c_odd) = v128_deinterleave_u8_x2(chroma, u8x16_splat(0));
+    match yuy2_source {
+        Yuy2Description::YUYV | Yuy2Description::UYVY => (y_values, c_even, c_odd),
+        Yuy2Description::YVYU | Yuy2Description::VYUY => (y_values, c_odd, c_even),
+    }
+}
+
 #[inline]
 pub unsafe fn wasm_store_interleave_u8x4(ptr: *mut u8, packed: (v128, v128, v128, v128)) {
     let a = packed.0;
@@ -76,6 +96,35 @@ pub unsafe fn wasm_store_interleave_u8x4(ptr: *mut u8, packed: (v128, v128, v128
     v128_store(ptr.add(48) as *mut v128, v3);
 }
 
+/// Stores 16 RGBX pixels keeping the 4th byte of every destination pixel untouched
+#[inline]
+pub unsafe fn wasm_store_interleave_rgbx(ptr: *mut u8, packed: (v128, v128, v128)) {
+    let a = packed.0;
+    let b = packed.1;
+    let c = packed.2;
+    let d = i8x16_splat(0);
+
+    let u0 = wasm_unpacklo_i8x16(a, c);
+    let u1 = wasm_unpackhi_i8x16(a, c);
+    let u2 = wasm_unpacklo_i8x16(b, d);
+    let u3 = wasm_unpackhi_i8x16(b, d);
+
+    let v0 = wasm_unpacklo_i8x16(u0, u2);
+    let v1 = wasm_unpackhi_i8x16(u0, u2);
+    let v2 = wasm_unpacklo_i8x16(u1, u3);
+    let v3 = wasm_unpackhi_i8x16(u1, u3);
+
+    let mask = u32x4_splat(0x00ff_ffff);
+    let dst0 = ptr as *mut v128;
+    let dst1 = ptr.add(16) as *mut v128;
+    let dst2 = ptr.add(32) as *mut v128;
+    let dst3 = ptr.add(48) as *mut v128;
+    v128_store(dst0, v128_bitselect(v0, v128_load(dst0), mask));
+    v128_store(dst1, v128_bitselect(v1, v128_load(dst1), mask));
+    v128_store(dst2, v128_bitselect(v2, v128_load(dst2), mask));
+    v128_store(dst3, v128_bitselect(v3, v128_load(dst3), mask));
+}
+
 #[inline]
 pub unsafe fn wasm_store_interleave_u8x3(ptr: *mut u8, packed: (v128, v128, v128)) {
     let a = packed.0;
@@ -83,11 +83,11 @@ pub unsafe fn wasm_y_to_rgb_row<const DESTINATION_CHANNELS: u8>(
                 let dst_pack = (r_values, r_values, r_values);
                 wasm_store_interleave_u8x3(rgba_ptr.add(dst_shift), dst_pack);
             }
-            YuvSourceChannels::Rgba => {
+            YuvSourceChannels::Rgba | YuvSourceChannels::Rgbx => {
                 let dst_pack = (r_values, r_values, r_values, v_alpha);
                 wasm_store_interleave_u8x4(rgba_ptr.add(dst_shift), dst_pack);
             }
-            YuvSourceChannels::Bgra => {
+            YuvSourceChannels::Bgra | YuvSourceChannels::Bgrx => {
                 let dst_pack = (r_values, r_values, r_values, v_alpha);
                 wasm_store_interleave_u8x4(rgba_ptr.add(dst_shift), dst_pack);
             }
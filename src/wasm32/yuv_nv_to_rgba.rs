@@ -28,8 +28,8 @@
  */
 use crate::internals::ProcessedOffset;
 use crate::wasm32::transpose::{
-    v128_load_deinterleave_half_u8_x2, v128_load_deinterleave_u8_x2, wasm_store_interleave_u8x3,
-    wasm_store_interleave_u8x4,
+    v128_load_deinterleave_half_u8_x2, v128_load_deinterleave_u8_x2, wasm_store_interleave_rgbx,
+    wasm_store_interleave_u8x3, wasm_store_interleave_u8x4,
 };
 use crate::wasm32::utils::{
     u16x8_pack_sat_u8x16, wasm_interleave_even_u8, wasm_interleave_odd_u8, wasm_unpackhi_i8x16,
@@ -57,6 +57,7 @@ pub unsafe fn wasm_yuv_nv_to_rgba_row<
     uv_offset: usize,
     rgba_offset: usize,
     width: usize,
+    alpha: u8,
 ) -> ProcessedOffset {
     let order: YuvNVOrder = UV_ORDER.into();
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
@@ -75,7 +76,7 @@ pub unsafe fn wasm_yuv_nv_to_rgba_row<
     let v_min_values = i16x8_splat(0i16);
     let v_g_coeff_1 = i16x8_splat(-1i16 * (transform.g_coeff_1 as i16));
     let v_g_coeff_2 = i16x8_splat(-1i16 * (transform.g_coeff_2 as i16));
-    let v_alpha = u8x16_splat(255u8);
+    let v_alpha = u8x16_splat(alpha);
     let rounding_const = i16x8_splat(1 << 5);
 
     let mut cx = start_cx;
@@ -215,6 +216,12 @@ pub unsafe fn wasm_yuv_nv_to_rgba_row<
                 let dst_pack = (b_values, g_values, r_values, v_alpha);
                 wasm_store_interleave_u8x4(bgra_ptr.add(dst_shift), dst_pack);
             }
+            YuvSourceChannels::Rgbx => {
+                wasm_store_interleave_rgbx(bgra_ptr.add(dst_shift), (r_values, g_values, b_values));
+            }
+            YuvSourceChannels::Bgrx => {
+                wasm_store_interleave_rgbx(bgra_ptr.add(dst_shift), (b_values, g_values, r_values));
+            }
         }
 
         cx += 16;
@@ -28,7 +28,9 @@
  */
 
 use crate::internals::ProcessedOffset;
-use crate::wasm32::transpose::{wasm_store_interleave_u8x3, wasm_store_interleave_u8x4};
+use crate::wasm32::transpose::{
+    wasm_store_interleave_rgbx, wasm_store_interleave_u8x3, wasm_store_interleave_u8x4,
+};
 use crate::wasm32::utils::{
     u16x8_pack_sat_u8x16, v128_load_half, wasm_unpackhi_i8x16, wasm_unpacklo_i8x16,
 };
@@ -52,6 +54,7 @@ pub unsafe fn wasm_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLIN
     v_offset: usize,
     rgba_offset: usize,
     width: usize,
+    alpha: u8,
 ) -> ProcessedOffset {
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
@@ -73,10 +76,10 @@ pub unsafe fn wasm_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLIN
     let v_min_values = i16x8_splat(0i16);
     let v_g_coeff_1 = i16x8_splat(-1i16 * transform.g_coeff_1 as i16);
     let v_g_coeff_2 = i16x8_splat(-1i16 * transform.g_coeff_2 as i16);
-    let v_alpha = u8x16_splat(255u8);
+    let v_alpha = u8x16_splat(alpha);
     let rounding_const = i16x8_splat(1 << 5);
 
-    while cx + 16 < width {
+    while cx + 16 <= width {
         let y_values = u8x16_sub_sat(v128_load(y_ptr.add(y_offset + cx) as *const v128), y_corr);
 
         let u_high_u8;
@@ -215,6 +218,12 @@ pub unsafe fn wasm_yuv_to_rgba_row<const DESTINATION_CHANNELS: u8, const SAMPLIN
                 let dst_pack = (b_values, g_values, r_values, v_alpha);
                 wasm_store_interleave_u8x4(rgba_ptr.add(dst_shift), dst_pack);
             }
+            YuvSourceChannels::Rgbx => {
+                wasm_store_interleave_rgbx(rgba_ptr.add(dst_shift), (r_values, g_values, b_values));
+            }
+            YuvSourceChannels::Bgrx => {
+                wasm_store_interleave_rgbx(rgba_ptr.add(dst_shift), (b_values, g_values, r_values));
+            }
         }
 
         cx += 16;
@@ -0,0 +1,226 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::wasm32::transpose::{
+    wasm_deinterleave_yuy2, wasm_store_interleave_rgbx, wasm_store_interleave_u8x3,
+    wasm_store_interleave_u8x4,
+};
+use crate::wasm32::utils::{u16x8_pack_sat_u8x16, wasm_unpacklo_i8x16};
+use crate::yuv_support::{
+    CbCrInverseTransform, YuvChromaRange, YuvSourceChannels, Yuy2Description,
+};
+use crate::yuv_to_yuy2::YuvToYuy2Navigation;
+use std::arch::wasm32::*;
+
+pub fn yuy2_to_rgb_wasm<const DST_CHANNELS: u8, const YUY2_TARGET: usize>(
+    range: &YuvChromaRange,
+    transform: &CbCrInverseTransform<i32>,
+    yuy2_store: &[u8],
+    yuy2_offset: usize,
+    rgb: &mut [u8],
+    rgb_offset: usize,
+    width: u32,
+    nav: YuvToYuy2Navigation,
+) -> YuvToYuy2Navigation {
+    unsafe {
+        yuy2_to_rgb_wasm_impl::<DST_CHANNELS, YUY2_TARGET>(
+            range,
+            transform,
+            yuy2_store,
+            yuy2_offset,
+            rgb,
+            rgb_offset,
+            width,
+            nav,
+        )
+    }
+}
+
+#[target_feature(enable = "simd128")]
+unsafe fn yuy2_to_rgb_wasm_impl<const DST_CHANNELS: u8, const YUY2_TARGET: usize>(
+    range: &YuvChromaRange,
+    transform: &CbCrInverseTransform<i32>,
+    yuy2_store: &[u8],
+    yuy2_offset: usize,
+    rgb: &mut [u8],
+    rgb_offset: usize,
+    width: u32,
+    nav: YuvToYuy2Navigation,
+) -> YuvToYuy2Navigation {
+    let yuy2_source: Yuy2Description = YUY2_TARGET.into();
+    let dst_chans: YuvSourceChannels = DST_CHANNELS.into();
+
+    let mut _cx = nav.cx;
+    let mut _yuy2_x = nav.x;
+
+    let max_x_8 = (width as usize / 2).saturating_sub(8);
+
+    let y_corr = u8x16_splat(range.bias_y as u8);
+    let uv_corr = i16x8_splat(range.bias_uv as i16);
+    let v_luma_coeff = u8x16_splat(transform.y_coef as u8);
+    let v_cr_coeff = i16x8_splat(transform.cr_coef as i16);
+    let v_cb_coeff = i16x8_splat(transform.cb_coef as i16);
+    let v_min_values = i16x8_splat(0i16);
+    let v_g_coeff_1 = i16x8_splat(-1i16 * transform.g_coeff_1 as i16);
+    let v_g_coeff_2 = i16x8_splat(-1i16 * transform.g_coeff_2 as i16);
+    let v_alpha = u8x16_splat(255u8);
+    let rounding_const = i16x8_splat(1 << 5);
+
+    for x in (_yuy2_x..max_x_8).step_by(8) {
+        let yuy2_offset = yuy2_offset + x * 4;
+        let dst_pos = rgb_offset + _cx * dst_chans.get_channels_count();
+        let dst_ptr = rgb.as_mut_ptr().add(dst_pos);
+
+        let yuy2_ptr = yuy2_store.as_ptr().add(yuy2_offset);
+
+        let j0 = v128_load(yuy2_ptr as *const v128);
+        let j1 = v128_load(yuy2_ptr.add(16) as *const v128);
+
+        let (y_values, u_values, v_values) = wasm_deinterleave_yuy2(yuy2_source, j0, j1);
+
+        let y_values = u8x16_sub_sat(y_values, y_corr);
+
+        let u_doubled = wasm_unpacklo_i8x16(u_values, u_values);
+        let v_doubled = wasm_unpacklo_i8x16(v_values, v_values);
+
+        let u_high = i16x8_sub(u16x8_extend_high_u8x16(u_doubled), uv_corr);
+        let v_high = i16x8_sub(u16x8_extend_high_u8x16(v_doubled), uv_corr);
+        let y_high = u16x8_extmul_high_u8x16(y_values, v_luma_coeff);
+
+        let r_high = i16x8_shr(
+            i16x8_add_sat(
+                i16x8_max(
+                    i16x8_add_sat(y_high, i16x8_mul(v_high, v_cr_coeff)),
+                    v_min_values,
+                ),
+                rounding_const,
+            ),
+            6,
+        );
+        let b_high = i16x8_shr(
+            i16x8_add_sat(
+                i16x8_max(
+                    i16x8_add_sat(y_high, i16x8_mul(u_high, v_cb_coeff)),
+                    v_min_values,
+                ),
+                rounding_const,
+            ),
+            6,
+        );
+        let g_high = i16x8_shr(
+            i16x8_add_sat(
+                i16x8_max(
+                    i16x8_add_sat(
+                        y_high,
+                        i16x8_add_sat(
+                            i16x8_mul(v_high, v_g_coeff_1),
+                            i16x8_mul(u_high, v_g_coeff_2),
+                        ),
+                    ),
+                    v_min_values,
+                ),
+                rounding_const,
+            ),
+            6,
+        );
+
+        let u_low = i16x8_sub(u16x8_extend_low_u8x16(u_doubled), uv_corr);
+        let v_low = i16x8_sub(u16x8_extend_low_u8x16(v_doubled), uv_corr);
+        let y_low = u16x8_extmul_low_u8x16(y_values, v_luma_coeff);
+
+        let r_low = i16x8_shr(
+            i16x8_add_sat(
+                i16x8_max(
+                    i16x8_add_sat(y_low, i16x8_mul(v_low, v_cr_coeff)),
+                    v_min_values,
+                ),
+                rounding_const,
+            ),
+            6,
+        );
+        let b_low = i16x8_shr(
+            i16x8_add_sat(
+                i16x8_max(
+                    i16x8_add_sat(y_low, i16x8_mul(u_low, v_cb_coeff)),
+                    v_min_values,
+                ),
+                rounding_const,
+            ),
+            6,
+        );
+        let g_low = i16x8_shr(
+            i16x8_add_sat(
+                i16x8_max(
+                    i16x8_add_sat(
+                        y_low,
+                        i16x8_add_sat(i16x8_mul(v_low, v_g_coeff_1), i16x8_mul(u_low, v_g_coeff_2)),
+                    ),
+                    v_min_values,
+                ),
+                rounding_const,
+            ),
+            6,
+        );
+
+        let r_values = u16x8_pack_sat_u8x16(r_low, r_high);
+        let g_values = u16x8_pack_sat_u8x16(g_low, g_high);
+        let b_values = u16x8_pack_sat_u8x16(b_low, b_high);
+
+        match dst_chans {
+            YuvSourceChannels::Rgb => {
+                wasm_store_interleave_u8x3(dst_ptr, (r_values, g_values, b_values));
+            }
+            YuvSourceChannels::Bgr => {
+                wasm_store_interleave_u8x3(dst_ptr, (b_values, g_values, r_values));
+            }
+            YuvSourceChannels::Rgba => {
+                wasm_store_interleave_u8x4(dst_ptr, (r_values, g_values, b_values, v_alpha));
+            }
+            YuvSourceChannels::Bgra => {
+                wasm_store_interleave_u8x4(dst_ptr, (b_values, g_values, r_values, v_alpha));
+            }
+            YuvSourceChannels::Rgbx => {
+                wasm_store_interleave_rgbx(dst_ptr, (r_values, g_values, b_values));
+            }
+            YuvSourceChannels::Bgrx => {
+                wasm_store_interleave_rgbx(dst_ptr, (b_values, g_values, r_values));
+            }
+        }
+
+        _yuy2_x = x;
+        if x + 8 < max_x_8 {
+            _cx += 16;
+        }
+    }
+
+    YuvToYuy2Navigation {
+        cx: _cx,
+        uv_x: 0,
+        x: _yuy2_x,
+    }
+}
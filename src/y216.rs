@@ -0,0 +1,606 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride};
+use crate::yuv_error::{
+    check_chroma_channel, check_interleaved_chroma_channel, check_y8_channel, check_yuv_packed422,
+};
+use crate::yuv_support::{YuvChromaSample, YuvSourceChannels, Yuy2Description};
+use crate::yuy2_to_rgb_p16::yuy2_to_rgb_impl_p16;
+use crate::{YuvError, YuvRange, YuvStandardMatrix};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+fn y216_to_yuv422_impl<const YUY2_SOURCE: usize>(
+    y_plane: &mut [u16],
+    y_stride: u32,
+    u_plane: &mut [u16],
+    u_stride: u32,
+    v_plane: &mut [u16],
+    v_stride: u32,
+    y216_store: &[u16],
+    y216_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    let yuy2_source: Yuy2Description = YUY2_SOURCE.into();
+
+    check_yuv_packed422(y216_store, y216_stride, width, height)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, YuvChromaSample::YUV422)?;
+    check_chroma_channel(v_plane, v_stride, width, height, YuvChromaSample::YUV422)?;
+
+    let width = width as usize;
+
+    let y_iter;
+    let u_iter;
+    let v_iter;
+    let y216_iter;
+    #[cfg(feature = "rayon")]
+    {
+        y_iter = y_plane.par_chunks_mut(y_stride as usize / 2);
+        u_iter = u_plane.par_chunks_mut(u_stride as usize / 2);
+        v_iter = v_plane.par_chunks_mut(v_stride as usize / 2);
+        y216_iter = y216_store.par_chunks(y216_stride as usize / 2);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        y_iter = y_plane.chunks_mut(y_stride as usize / 2);
+        u_iter = u_plane.chunks_mut(u_stride as usize / 2);
+        v_iter = v_plane.chunks_mut(v_stride as usize / 2);
+        y216_iter = y216_store.chunks(y216_stride as usize / 2);
+    }
+
+    y_iter.zip(u_iter).zip(v_iter).zip(y216_iter).for_each(
+        |(((y_row, u_row), v_row), y216_row)| {
+            for (((y_dst, u_dst), v_dst), src) in y_row
+                .chunks_exact_mut(2)
+                .zip(u_row.iter_mut())
+                .zip(v_row.iter_mut())
+                .zip(y216_row.chunks_exact(4))
+                .take(width / 2)
+            {
+                y_dst[0] = src[yuy2_source.get_first_y_position()];
+                y_dst[1] = src[yuy2_source.get_second_y_position()];
+                *u_dst = src[yuy2_source.get_u_position()];
+                *v_dst = src[yuy2_source.get_v_position()];
+            }
+
+            if width & 1 == 1 {
+                let cx = (width - 1) / 2;
+                let src = &y216_row[cx * 4..];
+                y_row[width - 1] = src[yuy2_source.get_first_y_position()];
+                u_row[cx] = src[yuy2_source.get_u_position()];
+                v_row[cx] = src[yuy2_source.get_v_position()];
+            }
+        },
+    );
+
+    Ok(())
+}
+
+fn yuv422_to_y216_impl<const YUY2_TARGET: usize>(
+    y_plane: &[u16],
+    y_stride: u32,
+    u_plane: &[u16],
+    u_stride: u32,
+    v_plane: &[u16],
+    v_stride: u32,
+    y216_store: &mut [u16],
+    y216_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    let yuy2_target: Yuy2Description = YUY2_TARGET.into();
+
+    check_yuv_packed422(y216_store, y216_stride, width, height)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, YuvChromaSample::YUV422)?;
+    check_chroma_channel(v_plane, v_stride, width, height, YuvChromaSample::YUV422)?;
+
+    let width = width as usize;
+
+    let y216_iter;
+    #[cfg(feature = "rayon")]
+    {
+        y216_iter = y216_store.par_chunks_mut(y216_stride as usize / 2);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        y216_iter = y216_store.chunks_mut(y216_stride as usize / 2);
+    }
+
+    y216_iter.enumerate().for_each(|(y, y216_row)| {
+        let y_row = &y_plane[y * (y_stride as usize / 2)..];
+        let u_row = &u_plane[y * (u_stride as usize / 2)..];
+        let v_row = &v_plane[y * (v_stride as usize / 2)..];
+
+        for (((dst, y_src), u_value), v_value) in y216_row
+            .chunks_exact_mut(4)
+            .zip(y_row.chunks_exact(2))
+            .zip(u_row.iter())
+            .zip(v_row.iter())
+            .take(width / 2)
+        {
+            dst[yuy2_target.get_first_y_position()] = y_src[0];
+            dst[yuy2_target.get_second_y_position()] = y_src[1];
+            dst[yuy2_target.get_u_position()] = *u_value;
+            dst[yuy2_target.get_v_position()] = *v_value;
+        }
+
+        if width & 1 == 1 {
+            let cx = (width - 1) / 2;
+            let dst = &mut y216_row[cx * 4..cx * 4 + 4];
+            dst[yuy2_target.get_first_y_position()] = y_row[width - 1];
+            dst[yuy2_target.get_second_y_position()] = y_row[width - 1];
+            dst[yuy2_target.get_u_position()] = u_row[cx];
+            dst[yuy2_target.get_v_position()] = v_row[cx];
+        }
+    });
+
+    Ok(())
+}
+
+fn y216_to_p216_impl<const YUY2_SOURCE: usize>(
+    y_plane: &mut [u16],
+    y_stride: u32,
+    uv_plane: &mut [u16],
+    uv_stride: u32,
+    y216_store: &[u16],
+    y216_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    let yuy2_source: Yuy2Description = YUY2_SOURCE.into();
+
+    check_yuv_packed422(y216_store, y216_stride, width, height)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_interleaved_chroma_channel(uv_plane, uv_stride, width, height, YuvChromaSample::YUV422)?;
+
+    let width = width as usize;
+
+    let y_iter;
+    let uv_iter;
+    let y216_iter;
+    #[cfg(feature = "rayon")]
+    {
+        y_iter = y_plane.par_chunks_mut(y_stride as usize / 2);
+        uv_iter = uv_plane.par_chunks_mut(uv_stride as usize / 2);
+        y216_iter = y216_store.par_chunks(y216_stride as usize / 2);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        y_iter = y_plane.chunks_mut(y_stride as usize / 2);
+        uv_iter = uv_plane.chunks_mut(uv_stride as usize / 2);
+        y216_iter = y216_store.chunks(y216_stride as usize / 2);
+    }
+
+    y_iter
+        .zip(uv_iter)
+        .zip(y216_iter)
+        .for_each(|((y_row, uv_row), y216_row)| {
+            for ((y_dst, uv_dst), src) in y_row
+                .chunks_exact_mut(2)
+                .zip(uv_row.chunks_exact_mut(2))
+                .zip(y216_row.chunks_exact(4))
+                .take(width / 2)
+            {
+                y_dst[0] = src[yuy2_source.get_first_y_position()];
+                y_dst[1] = src[yuy2_source.get_second_y_position()];
+                uv_dst[0] = src[yuy2_source.get_u_position()];
+                uv_dst[1] = src[yuy2_source.get_v_position()];
+            }
+
+            if width & 1 == 1 {
+                let cx = (width - 1) / 2;
+                let src = &y216_row[cx * 4..];
+                y_row[width - 1] = src[yuy2_source.get_first_y_position()];
+                uv_row[cx * 2] = src[yuy2_source.get_u_position()];
+                uv_row[cx * 2 + 1] = src[yuy2_source.get_v_position()];
+            }
+        });
+
+    Ok(())
+}
+
+fn p216_to_y216_impl<const YUY2_TARGET: usize>(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    y216_store: &mut [u16],
+    y216_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    let yuy2_target: Yuy2Description = YUY2_TARGET.into();
+
+    check_yuv_packed422(y216_store, y216_stride, width, height)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_interleaved_chroma_channel(uv_plane, uv_stride, width, height, YuvChromaSample::YUV422)?;
+
+    let width = width as usize;
+
+    let y216_iter;
+    #[cfg(feature = "rayon")]
+    {
+        y216_iter = y216_store.par_chunks_mut(y216_stride as usize / 2);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        y216_iter = y216_store.chunks_mut(y216_stride as usize / 2);
+    }
+
+    y216_iter.enumerate().for_each(|(y, y216_row)| {
+        let y_row = &y_plane[y * (y_stride as usize / 2)..];
+        let uv_row = &uv_plane[y * (uv_stride as usize / 2)..];
+
+        for ((dst, y_src), uv_src) in y216_row
+            .chunks_exact_mut(4)
+            .zip(y_row.chunks_exact(2))
+            .zip(uv_row.chunks_exact(2))
+            .take(width / 2)
+        {
+            dst[yuy2_target.get_first_y_position()] = y_src[0];
+            dst[yuy2_target.get_second_y_position()] = y_src[1];
+            dst[yuy2_target.get_u_position()] = uv_src[0];
+            dst[yuy2_target.get_v_position()] = uv_src[1];
+        }
+
+        if width & 1 == 1 {
+            let cx = (width - 1) / 2;
+            let dst = &mut y216_row[cx * 4..cx * 4 + 4];
+            dst[yuy2_target.get_first_y_position()] = y_row[width - 1];
+            dst[yuy2_target.get_second_y_position()] = y_row[width - 1];
+            dst[yuy2_target.get_u_position()] = uv_row[cx * 2];
+            dst[yuy2_target.get_v_position()] = uv_row[cx * 2 + 1];
+        }
+    });
+
+    Ok(())
+}
+
+/// Convert Y216 ( YUV Packed 4:2:2 16-bit ) format to I216 planar format.
+///
+/// Y216 stores `Y0 U Y1 V` macropixels with 16 bits per sample, samples are copied as is,
+/// MSB-aligned lower depths ( Y210, Y212 ) keep their alignment in the planar output.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A mutable slice to store the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A mutable slice to store the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `y216_store` - A slice to load the Y216 data.
+/// * `y216_stride` - The stride (bytes per row) for the Y216 plane.
+/// * `size` - The size of the image.
+///
+pub fn y216_to_i216(
+    y_plane: &mut [u16],
+    y_stride: RowStride,
+    u_plane: &mut [u16],
+    u_stride: RowStride,
+    v_plane: &mut [u16],
+    v_stride: RowStride,
+    y216_store: &[u16],
+    y216_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, y216_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        y216_stride.get(),
+    );
+    y216_to_yuv422_impl::<{ Yuy2Description::YUYV as usize }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        y216_store,
+        y216_stride,
+        width,
+        height,
+    )
+}
+
+/// Convert I216 planar format to Y216 ( YUV Packed 4:2:2 16-bit ) format.
+///
+/// Samples are copied as is, for odd widths the last luma sample fills both luma slots
+/// of the final macropixel.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `y216_store` - A mutable slice to store the Y216 data.
+/// * `y216_stride` - The stride (bytes per row) for the Y216 plane.
+/// * `size` - The size of the image.
+///
+pub fn i216_to_y216(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    u_plane: &[u16],
+    u_stride: RowStride,
+    v_plane: &[u16],
+    v_stride: RowStride,
+    y216_store: &mut [u16],
+    y216_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, y216_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        y216_stride.get(),
+    );
+    yuv422_to_y216_impl::<{ Yuy2Description::YUYV as usize }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        y216_store,
+        y216_stride,
+        width,
+        height,
+    )
+}
+
+/// Convert Y216 ( YUV Packed 4:2:2 16-bit ) format to P216 bi-planar format.
+///
+/// Luma is copied as is, chroma of each macropixel is stored as one `UV` pair of the
+/// interleaved chroma plane which has full image height.
+///
+/// # Arguments
+///
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A mutable slice to store the interleaved UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `y216_store` - A slice to load the Y216 data.
+/// * `y216_stride` - The stride (bytes per row) for the Y216 plane.
+/// * `size` - The size of the image.
+///
+pub fn y216_to_p216(
+    y_plane: &mut [u16],
+    y_stride: RowStride,
+    uv_plane: &mut [u16],
+    uv_stride: RowStride,
+    y216_store: &[u16],
+    y216_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, uv_stride, y216_stride) = (y_stride.get(), uv_stride.get(), y216_stride.get());
+    y216_to_p216_impl::<{ Yuy2Description::YUYV as usize }>(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        y216_store,
+        y216_stride,
+        width,
+        height,
+    )
+}
+
+/// Convert P216 bi-planar format to Y216 ( YUV Packed 4:2:2 16-bit ) format.
+///
+/// Samples are copied as is, for odd widths the last luma sample fills both luma slots
+/// of the final macropixel.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the interleaved UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `y216_store` - A mutable slice to store the Y216 data.
+/// * `y216_stride` - The stride (bytes per row) for the Y216 plane.
+/// * `size` - The size of the image.
+///
+pub fn p216_to_y216(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    uv_plane: &[u16],
+    uv_stride: RowStride,
+    y216_store: &mut [u16],
+    y216_stride: RowStride,
+    size: ImageSize,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, uv_stride, y216_stride) = (y_stride.get(), uv_stride.get(), y216_stride.get());
+    p216_to_y216_impl::<{ Yuy2Description::YUYV as usize }>(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        y216_store,
+        y216_stride,
+        width,
+        height,
+    )
+}
+
+/// Convert Y216 ( YUV Packed 4:2:2 16-bit ) format to RGB 16-bit image.
+///
+/// # Arguments
+///
+/// * `y216_store` - A slice to load the Y216 data.
+/// * `y216_stride` - The stride (bytes per row) for the Y216 plane.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn y216_to_rgb16(
+    y216_store: &[u16],
+    y216_stride: RowStride,
+    rgb: &mut [u16],
+    rgb_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y216_stride, rgb_stride) = (y216_stride.get(), rgb_stride.get());
+    yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Rgb as u8 }, { Yuy2Description::YUYV as usize }>(
+        y216_store,
+        y216_stride,
+        rgb,
+        rgb_stride,
+        16,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert Y216 ( YUV Packed 4:2:2 16-bit ) format to BGR 16-bit image.
+///
+/// # Arguments
+///
+/// * `y216_store` - A slice to load the Y216 data.
+/// * `y216_stride` - The stride (bytes per row) for the Y216 plane.
+/// * `bgr` - A mutable slice to store the converted BGR data.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn y216_to_bgr16(
+    y216_store: &[u16],
+    y216_stride: RowStride,
+    bgr: &mut [u16],
+    bgr_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y216_stride, bgr_stride) = (y216_stride.get(), bgr_stride.get());
+    yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Bgr as u8 }, { Yuy2Description::YUYV as usize }>(
+        y216_store,
+        y216_stride,
+        bgr,
+        bgr_stride,
+        16,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert Y216 ( YUV Packed 4:2:2 16-bit ) format to RGBA 16-bit image.
+///
+/// # Arguments
+///
+/// * `y216_store` - A slice to load the Y216 data.
+/// * `y216_stride` - The stride (bytes per row) for the Y216 plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn y216_to_rgba16(
+    y216_store: &[u16],
+    y216_stride: RowStride,
+    rgba: &mut [u16],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y216_stride, rgba_stride) = (y216_stride.get(), rgba_stride.get());
+    yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Rgba as u8 }, { Yuy2Description::YUYV as usize }>(
+        y216_store,
+        y216_stride,
+        rgba,
+        rgba_stride,
+        16,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert Y216 ( YUV Packed 4:2:2 16-bit ) format to BGRA 16-bit image.
+///
+/// # Arguments
+///
+/// * `y216_store` - A slice to load the Y216 data.
+/// * `y216_stride` - The stride (bytes per row) for the Y216 plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn y216_to_bgra16(
+    y216_store: &[u16],
+    y216_stride: RowStride,
+    bgra: &mut [u16],
+    bgra_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y216_stride, bgra_stride) = (y216_stride.get(), bgra_stride.get());
+    yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Bgra as u8 }, { Yuy2Description::YUYV as usize }>(
+        y216_store,
+        y216_stride,
+        bgra,
+        bgra_stride,
+        16,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
@@ -26,14 +26,20 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_y_p16_to_rgba16_row;
+use crate::yuv_error::{check_p16_bit_depth, check_rgba_destination, check_y8_channel};
+use crate::yuv_support::p16_dispatch;
 use crate::yuv_support::*;
+use crate::YuvError;
 #[cfg(feature = "rayon")]
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 #[cfg(feature = "rayon")]
 use rayon::prelude::ParallelSliceMut;
-use std::slice;
 
 // Chroma subsampling always assumed as 400
 fn yuv400_p16_to_rgbx<
@@ -50,14 +56,18 @@ fn yuv400_p16_to_rgbx<
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
 
     let max_colors = (1 << bit_depth) - 1;
 
     let channels = destination_channels.get_channels_count();
+    check_p16_bit_depth(bit_depth)?;
+    check_y8_channel(y_plane16, y_stride, width, height)?;
+    check_rgba_destination(rgba16, rgba_stride, width, height, channels)?;
+
     let range = get_yuv_range(bit_depth, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let transform = get_inverse_transform(
         max_colors,
         range.range_y,
@@ -66,74 +76,85 @@ fn yuv400_p16_to_rgbx<
         kr_kb.kb,
     );
 
-    const PRECISION: i32 = 6;
-    const ROUNDING_CONST: i32 = 1 << (PRECISION - 1);
-    let inverse_transform = transform.to_integers(PRECISION as u32);
+    let precision = p16_inverse_precision(bit_depth);
+    let rounding_const: i32 = 1 << (precision - 1);
+    let inverse_transform = transform.to_integers(precision);
     let y_coef = inverse_transform.y_coef;
 
     let bias_y = range.bias_y as i32;
-
-    let casted_rgba = unsafe {
-        slice::from_raw_parts_mut(
-            rgba16.as_mut_ptr() as *mut u8,
-            rgba_stride as usize * height as usize,
-        )
-    };
+    let endianness: YuvEndianness = ENDIANNESS.into();
+    let bytes_position: YuvBytesPacking = BYTES_POSITION.into();
+    let msb_shift = 16 - bit_depth;
 
     let iter;
     #[cfg(feature = "rayon")]
     {
-        iter = casted_rgba.par_chunks_exact_mut(rgba_stride as usize);
+        iter = rgba16
+            .par_chunks_mut(rgba_stride as usize / 2)
+            .take(height as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = casted_rgba.chunks_exact_mut(rgba_stride as usize);
+        iter = rgba16
+            .chunks_mut(rgba_stride as usize / 2)
+            .take(height as usize);
     }
 
-    iter.enumerate().for_each(|(y, rgba16)| unsafe {
+    iter.enumerate().for_each(|(y, rgba16)| {
         let y_offset = y * (y_stride as usize);
 
         let mut _cx = 0usize;
 
-        let dst_ptr = rgba16.as_mut_ptr() as *mut u16;
-        let y_ptr = (y_plane16.as_ptr() as *const u8).add(y_offset) as *const u16;
+        let y_src = &y_plane16[y_offset / 2..];
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
-        {
-            let offset = neon_y_p16_to_rgba16_row::<DESTINATION_CHANNELS, ENDIANNESS, BYTES_POSITION>(
-                y_ptr,
-                dst_ptr,
-                0,
-                width,
-                &range,
-                &inverse_transform,
-                0,
-                bit_depth as usize,
-            );
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        if bit_depth <= P16_SIMD_MAX_BIT_DEPTH {
+            let offset = unsafe {
+                neon_y_p16_to_rgba16_row::<DESTINATION_CHANNELS, ENDIANNESS, BYTES_POSITION>(
+                    y_src.as_ptr(),
+                    rgba16.as_mut_ptr(),
+                    0,
+                    width,
+                    &range,
+                    &inverse_transform,
+                    0,
+                    bit_depth as usize,
+                )
+            };
             _cx = offset.cx;
         }
 
-        for x in _cx..width as usize {
-            let y_value =
-                (y_ptr.add(x).read_unaligned() as i32 - bias_y) * y_coef;
+        for (x, &y_vl) in y_src.iter().enumerate().take(width as usize).skip(_cx) {
+            let mut y_vl = match endianness {
+                YuvEndianness::BigEndian => u16::from_be(y_vl),
+                YuvEndianness::LittleEndian => u16::from_le(y_vl),
+            };
+            if bytes_position == YuvBytesPacking::MostSignificantBytes {
+                y_vl >>= msb_shift;
+            }
+            let y_value = (y_vl as i32 - bias_y) * y_coef;
 
-            let r = ((y_value + ROUNDING_CONST) >> PRECISION)
+            let r = ((y_value + rounding_const) >> precision)
                 .min(max_colors as i32)
                 .max(0);
 
             let px = x * channels;
 
-            let rgba_shift = px;
-
-            let dst = dst_ptr.add(rgba_shift);
-            dst.add(destination_channels.get_r_channel_offset()).write_unaligned(r as u16);
-            dst.add(destination_channels.get_g_channel_offset()).write_unaligned(r as u16);
-            dst.add(destination_channels.get_b_channel_offset()).write_unaligned(r as u16);
+            let dst = &mut rgba16[px..];
+            dst[destination_channels.get_r_channel_offset()] = r as u16;
+            dst[destination_channels.get_g_channel_offset()] = r as u16;
+            dst[destination_channels.get_b_channel_offset()] = r as u16;
             if destination_channels.has_alpha() {
-                dst.add(destination_channels.get_a_channel_offset()).write_unaligned(max_colors as u16);
+                dst[destination_channels.get_a_channel_offset()] = max_colors as u16;
             }
         }
     });
+
+    Ok(())
 }
 
 /// Convert YUV 400 planar format to RGB 8+-bit format.
@@ -168,44 +189,16 @@ pub fn yuv400_p16_to_rgb16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let callee = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let callee = p16_dispatch!(
+        yuv400_p16_to_rgbx,
+        [{ YuvSourceChannels::Rgb as u8 }],
+        endianness,
+        bytes_packing
+    );
     callee(
         y_plane, y_stride, rgb, rgb_stride, bit_depth, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert YUV 400 planar format to BGR 8+-bit format.
@@ -240,41 +233,13 @@ pub fn yuv400_p16_to_bgr16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let callee = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let callee = p16_dispatch!(
+        yuv400_p16_to_rgbx,
+        [{ YuvSourceChannels::Bgr as u8 }],
+        endianness,
+        bytes_packing
+    );
     callee(
         y_plane, y_stride, bgr, bgr_stride, bit_depth, width, height, range, matrix,
     )
@@ -312,41 +277,13 @@ pub fn yuv400_p16_to_rgba16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let callee = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let callee = p16_dispatch!(
+        yuv400_p16_to_rgbx,
+        [{ YuvSourceChannels::Rgba as u8 }],
+        endianness,
+        bytes_packing
+    );
     callee(
         y_plane,
         y_stride,
@@ -392,41 +329,13 @@ pub fn yuv400_p16_to_bgra16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let callee = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv400_p16_to_rgbx::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let callee = p16_dispatch!(
+        yuv400_p16_to_rgbx,
+        [{ YuvSourceChannels::Bgra as u8 }],
+        endianness,
+        bytes_packing
+    );
     callee(
         y_plane,
         y_stride,
@@ -26,12 +26,16 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+use crate::y_with_alpha_to_rgb::y_with_alpha_to_rgbx_generic;
+use crate::yuv_error::{check_rgba_destination, check_y8_channel};
+use crate::yuv_planar_generic::Pixel;
+use crate::yuv_support::p16_dispatch;
 use crate::yuv_support::*;
+use crate::YuvError;
 #[cfg(feature = "rayon")]
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 #[cfg(feature = "rayon")]
 use rayon::prelude::{ParallelSlice, ParallelSliceMut};
-use std::slice;
 
 // Chroma subsampling always assumed as 400
 fn yuv400_p16_with_alpha_to_rgbx<
@@ -50,7 +54,7 @@ fn yuv400_p16_with_alpha_to_rgbx<
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
     let max_colors = (1 << bit_depth) - 1;
 
@@ -64,9 +68,12 @@ fn yuv400_p16_with_alpha_to_rgbx<
         channels, 4,
         "YUV400 with alpha cannot be called on target image without alpha"
     );
+    check_y8_channel(y_plane16, y_stride, width, height)?;
+    check_y8_channel(a_plane16, a_stride, width, height)?;
+    check_rgba_destination(rgba16, rgba_stride, width, height, channels)?;
 
     let range = get_yuv_range(bit_depth, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let transform = get_inverse_transform(
         max_colors,
         range.range_y,
@@ -82,54 +89,26 @@ fn yuv400_p16_with_alpha_to_rgbx<
 
     let bias_y = range.bias_y as i32;
 
-    let casted_rgba = unsafe {
-        slice::from_raw_parts_mut(
-            rgba16.as_mut_ptr() as *mut u8,
-            rgba_stride as usize * height as usize,
-        )
-    };
-
-    let casted_y_plane = unsafe {
-        slice::from_raw_parts(
-            y_plane16.as_ptr() as *const u8,
-            y_stride as usize * height as usize,
-        )
-    };
-
-    let casted_a_plane = unsafe {
-        slice::from_raw_parts(
-            a_plane16.as_ptr() as *const u8,
-            a_stride as usize * height as usize,
-        )
-    };
-
     let iter;
     let y_iter;
     let a_iter;
     #[cfg(feature = "rayon")]
     {
-        iter = casted_rgba.par_chunks_exact_mut(rgba_stride as usize);
-        y_iter = casted_y_plane.par_chunks_exact(y_stride as usize);
-        a_iter = casted_a_plane.par_chunks_exact(a_stride as usize);
+        iter = rgba16.par_chunks_mut(rgba_stride as usize / 2);
+        y_iter = y_plane16.par_chunks(y_stride as usize / 2);
+        a_iter = a_plane16.par_chunks(a_stride as usize / 2);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = casted_rgba.chunks_exact_mut(rgba_stride as usize);
-        y_iter = casted_y_plane.chunks_exact(y_stride as usize);
-        a_iter = casted_a_plane.chunks_exact(a_stride as usize);
+        iter = rgba16.chunks_mut(rgba_stride as usize / 2);
+        y_iter = y_plane16.chunks(y_stride as usize / 2);
+        a_iter = a_plane16.chunks(a_stride as usize / 2);
     }
 
-    iter.zip(y_iter)
-        .zip(a_iter)
-        .for_each(|((rgba16, y_plane16), a_plane16)| unsafe {
-            let mut _cx = 0usize;
-
-            let dst_ptr = rgba16.as_mut_ptr() as *mut u16;
-            let y_ptr = y_plane16.as_ptr() as *const u16;
-            let a_ptr = a_plane16.as_ptr() as *const u16;
-
-            for x in _cx..width as usize {
-                let y_value = (y_ptr.add(x).read_unaligned() as i32 - bias_y) * y_coef;
+    iter.take(height as usize).zip(y_iter).zip(a_iter).for_each(
+        |((rgba16, y_plane16), a_plane16)| {
+            for x in 0..width as usize {
+                let y_value = (y_plane16[x] as i32 - bias_y) * y_coef;
 
                 let r = ((y_value + ROUNDING_CONST) >> PRECISION)
                     .min(max_colors as i32)
@@ -137,19 +116,16 @@ fn yuv400_p16_with_alpha_to_rgbx<
 
                 let px = x * channels;
 
-                let rgba_shift = px;
-
-                let dst = dst_ptr.add(rgba_shift);
-                dst.add(destination_channels.get_r_channel_offset())
-                    .write_unaligned(r as u16);
-                dst.add(destination_channels.get_g_channel_offset())
-                    .write_unaligned(r as u16);
-                dst.add(destination_channels.get_b_channel_offset())
-                    .write_unaligned(r as u16);
-                dst.add(destination_channels.get_a_channel_offset())
-                    .write_unaligned(a_ptr.add(x).read_unaligned());
+                let dst = &mut rgba16[px..];
+                dst[destination_channels.get_r_channel_offset()] = r as u16;
+                dst[destination_channels.get_g_channel_offset()] = r as u16;
+                dst[destination_channels.get_b_channel_offset()] = r as u16;
+                dst[destination_channels.get_a_channel_offset()] = a_plane16[x];
             }
-        });
+        },
+    );
+
+    Ok(())
 }
 
 /// Convert YUV 400 planar format with alpha plane to RGBA 8+-bit format.
@@ -186,41 +162,13 @@ pub fn yuv400_p16_with_alpha_to_rgba16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let callee = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv400_p16_with_alpha_to_rgbx::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv400_p16_with_alpha_to_rgbx::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv400_p16_with_alpha_to_rgbx::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv400_p16_with_alpha_to_rgbx::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let callee = p16_dispatch!(
+        yuv400_p16_with_alpha_to_rgbx,
+        [{ YuvSourceChannels::Rgba as u8 }],
+        endianness,
+        bytes_packing
+    );
     callee(
         y_plane,
         y_stride,
@@ -270,41 +218,13 @@ pub fn yuv400_p16_with_alpha_to_bgra16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let callee = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv400_p16_with_alpha_to_rgbx::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv400_p16_with_alpha_to_rgbx::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv400_p16_with_alpha_to_rgbx::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv400_p16_with_alpha_to_rgbx::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let callee = p16_dispatch!(
+        yuv400_p16_with_alpha_to_rgbx,
+        [{ YuvSourceChannels::Bgra as u8 }],
+        endianness,
+        bytes_packing
+    );
     callee(
         y_plane,
         y_stride,
@@ -319,3 +239,263 @@ pub fn yuv400_p16_with_alpha_to_bgra16(
         matrix,
     )
 }
+
+type YWithAlphaP16Converter<D> = fn(
+    &[u16],
+    u32,
+    &[u16],
+    u32,
+    &mut [D],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvRange,
+    YuvStandardMatrix,
+    usize,
+    usize,
+) -> Result<(), YuvError>;
+
+fn y_p16_with_alpha_dispatcher<D: Pixel, const DESTINATION_CHANNELS: u8>(
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> YWithAlphaP16Converter<D> {
+    p16_dispatch!(
+        y_with_alpha_to_rgbx_generic,
+        [u16, D, DESTINATION_CHANNELS],
+        endianness,
+        bytes_packing
+    )
+}
+
+/// Convert 8+-bit monochrome image with alpha plane ( AVIF/HEIF style Y + A ) to RGBA 8+-bit format.
+///
+/// Destination keeps bit depth of the source, limited range alpha is expanded onto full range.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `a_plane` - A slice to load alpha plane data.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+/// * `bit_depth` - Bit depth of source and destination, 10 and 12 are typical for AVIF.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `range` - The range of the Y plane (limited or full).
+/// * `alpha_range` - The range of the alpha plane (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn y_p16_with_alpha_to_rgba16(
+    y_plane: &[u16],
+    y_stride: u32,
+    a_plane: &[u16],
+    a_stride: u32,
+    rgba: &mut [u16],
+    rgba_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    alpha_range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = y_p16_with_alpha_dispatcher::<u16, { YuvSourceChannels::Rgba as u8 }>(
+        endianness,
+        bytes_packing,
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        a_plane,
+        a_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        alpha_range,
+        matrix,
+        bit_depth as usize,
+        bit_depth as usize,
+    )
+}
+
+/// Convert 8+-bit monochrome image with alpha plane ( AVIF/HEIF style Y + A ) to RGBA 8-bit format.
+///
+/// Both planes are scaled down to 8 bits, limited range alpha is expanded onto full 0..255 range.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `a_plane` - A slice to load alpha plane data.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+/// * `bit_depth` - Bit depth of source planes, 10 and 12 are typical for AVIF.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `range` - The range of the Y plane (limited or full).
+/// * `alpha_range` - The range of the alpha plane (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn y_p16_with_alpha_to_rgba(
+    y_plane: &[u16],
+    y_stride: u32,
+    a_plane: &[u16],
+    a_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    alpha_range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = y_p16_with_alpha_dispatcher::<u8, { YuvSourceChannels::Rgba as u8 }>(
+        endianness,
+        bytes_packing,
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        a_plane,
+        a_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        alpha_range,
+        matrix,
+        bit_depth as usize,
+        8,
+    )
+}
+
+/// Convert 8+-bit monochrome image with alpha plane ( AVIF/HEIF style Y + A ) to BGRA 8+-bit format.
+///
+/// Destination keeps bit depth of the source, limited range alpha is expanded onto full range.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `a_plane` - A slice to load alpha plane data.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for BGRA data.
+/// * `bit_depth` - Bit depth of source and destination, 10 and 12 are typical for AVIF.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `range` - The range of the Y plane (limited or full).
+/// * `alpha_range` - The range of the alpha plane (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn y_p16_with_alpha_to_bgra16(
+    y_plane: &[u16],
+    y_stride: u32,
+    a_plane: &[u16],
+    a_stride: u32,
+    bgra: &mut [u16],
+    bgra_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    alpha_range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = y_p16_with_alpha_dispatcher::<u16, { YuvSourceChannels::Bgra as u8 }>(
+        endianness,
+        bytes_packing,
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        a_plane,
+        a_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        alpha_range,
+        matrix,
+        bit_depth as usize,
+        bit_depth as usize,
+    )
+}
+
+/// Convert 8+-bit monochrome image with alpha plane ( AVIF/HEIF style Y + A ) to BGRA 8-bit format.
+///
+/// Both planes are scaled down to 8 bits, limited range alpha is expanded onto full 0..255 range.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `a_plane` - A slice to load alpha plane data.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for BGRA data.
+/// * `bit_depth` - Bit depth of source planes, 10 and 12 are typical for AVIF.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `range` - The range of the Y plane (limited or full).
+/// * `alpha_range` - The range of the alpha plane (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn y_p16_with_alpha_to_bgra(
+    y_plane: &[u16],
+    y_stride: u32,
+    a_plane: &[u16],
+    a_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    alpha_range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = y_p16_with_alpha_dispatcher::<u8, { YuvSourceChannels::Bgra as u8 }>(
+        endianness,
+        bytes_packing,
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        a_plane,
+        a_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        alpha_range,
+        matrix,
+        bit_depth as usize,
+        8,
+    )
+}
@@ -28,15 +28,29 @@
  */
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
-    feature = "nightly_avx512"
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
 ))]
 use crate::avx512bw::avx512_y_to_rgb_row;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 #[allow(unused_imports)]
 use crate::internals::ProcessedOffset;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_y_to_rgb_row;
-#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[cfg(feature = "profiling")]
+use crate::profiling::{dispatch_backend, rayon_splits, ProfileScope};
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    not(feature = "safe-only")
+))]
 use crate::wasm32::wasm_y_to_rgb_row;
 use crate::yuv_error::{check_rgba_destination, check_y8_channel};
 use crate::yuv_support::*;
@@ -63,8 +77,16 @@ fn y_to_rgbx<const DESTINATION_CHANNELS: u8>(
     check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
     check_y8_channel(y_plane, y_stride, width, height)?;
 
+    #[cfg(feature = "profiling")]
+    let _profile = ProfileScope::new(
+        "y_to_rgba",
+        y_plane.len() + rgba.len(),
+        dispatch_backend(true, true),
+        rayon_splits(height),
+    );
+
     let range = get_yuv_range(8, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let transform = get_inverse_transform(255, range.range_y, range.range_uv, kr_kb.kr, kr_kb.kb);
 
     const PRECISION: i32 = 6;
@@ -76,21 +98,22 @@ fn y_to_rgbx<const DESTINATION_CHANNELS: u8>(
 
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly_avx512"
+        feature = "nightly_avx512",
+        not(feature = "safe-only")
     ))]
-    let mut _use_avx512 = std::arch::is_x86_feature_detected!("avx512bw");
+    let mut _use_avx512 = crate::cpu_features::has_avx512bw();
 
     let iter;
     let y_iter;
     #[cfg(feature = "rayon")]
     {
-        iter = rgba.par_chunks_exact_mut(rgba_stride as usize);
-        y_iter = y_plane.par_chunks_exact(y_stride as usize);
+        iter = rgba.par_chunks_mut(rgba_stride as usize);
+        y_iter = y_plane.par_chunks(y_stride as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = rgba.chunks_exact_mut(rgba_stride as usize);
-        y_iter = y_plane.chunks_exact(y_stride as usize);
+        iter = rgba.chunks_mut(rgba_stride as usize);
+        y_iter = y_plane.chunks(y_stride as usize);
     }
 
     iter.zip(y_iter).for_each(|(rgba, y_plane)| {
@@ -98,7 +121,8 @@ fn y_to_rgbx<const DESTINATION_CHANNELS: u8>(
 
         #[cfg(all(
             any(target_arch = "x86", target_arch = "x86_64"),
-            feature = "nightly_avx512"
+            feature = "nightly_avx512",
+            not(feature = "safe-only")
         ))]
         unsafe {
             if _use_avx512 {
@@ -116,7 +140,11 @@ fn y_to_rgbx<const DESTINATION_CHANNELS: u8>(
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             let offset = neon_y_to_rgb_row::<DESTINATION_CHANNELS>(
                 &range,
@@ -131,7 +159,11 @@ fn y_to_rgbx<const DESTINATION_CHANNELS: u8>(
             _cx = offset;
         }
 
-        #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             let offset = wasm_y_to_rgb_row::<DESTINATION_CHANNELS>(
                 &range,
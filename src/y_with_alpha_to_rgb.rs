@@ -27,12 +27,14 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 use crate::yuv_error::{check_rgba_destination, check_y8_channel};
+use crate::yuv_planar_generic::Pixel;
 use crate::yuv_support::*;
 use crate::YuvError;
 #[cfg(feature = "rayon")]
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 #[cfg(feature = "rayon")]
 use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+use std::mem::size_of;
 
 // Chroma subsampling always assumed as 400
 fn y_with_alpha_to_rgbx<const DESTINATION_CHANNELS: u8>(
@@ -63,7 +65,7 @@ fn y_with_alpha_to_rgbx<const DESTINATION_CHANNELS: u8>(
     check_y8_channel(a_plane, a_stride, width, height)?;
 
     let range = get_yuv_range(8, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let transform = get_inverse_transform(255, range.range_y, range.range_uv, kr_kb.kr, kr_kb.kb);
 
     const PRECISION: i32 = 6;
@@ -78,15 +80,15 @@ fn y_with_alpha_to_rgbx<const DESTINATION_CHANNELS: u8>(
     let a_iter;
     #[cfg(feature = "rayon")]
     {
-        iter = rgba.par_chunks_exact_mut(rgba_stride as usize);
-        y_iter = y_plane.par_chunks_exact(y_stride as usize);
-        a_iter = a_plane.par_chunks_exact(a_stride as usize);
+        iter = rgba.par_chunks_mut(rgba_stride as usize);
+        y_iter = y_plane.par_chunks(y_stride as usize);
+        a_iter = a_plane.par_chunks(a_stride as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = rgba.chunks_exact_mut(rgba_stride as usize);
-        y_iter = y_plane.chunks_exact(y_stride as usize);
-        a_iter = a_plane.chunks_exact(a_stride as usize);
+        iter = rgba.chunks_mut(rgba_stride as usize);
+        y_iter = y_plane.chunks(y_stride as usize);
+        a_iter = a_plane.chunks(a_stride as usize);
     }
 
     iter.zip(y_iter)
@@ -205,3 +207,222 @@ pub fn yuv400_with_alpha_to_bgra(
         matrix,
     )
 }
+
+/// Monochrome with alpha ( AVIF/HEIF style Y + A ) to RGBA over any combination of `u8` and `u16`
+/// storage.
+///
+/// Alpha plane has its own range, limited range alpha is expanded the same way as luma,
+/// both planes are rescaled from `src_bit_depth` into `dst_bit_depth`.
+pub(crate) fn y_with_alpha_to_rgbx_generic<
+    S: Pixel,
+    D: Pixel,
+    const DESTINATION_CHANNELS: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+>(
+    y_plane: &[S],
+    y_stride: u32,
+    a_plane: &[S],
+    a_stride: u32,
+    rgba: &mut [D],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    alpha_range: YuvRange,
+    matrix: YuvStandardMatrix,
+    src_bit_depth: usize,
+    dst_bit_depth: usize,
+) -> Result<(), YuvError> {
+    let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = destination_channels.get_channels_count();
+    assert!(
+        destination_channels.has_alpha(),
+        "YUV400 with alpha cannot be called on target image without alpha"
+    );
+
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_y8_channel(a_plane, a_stride, width, height)?;
+
+    const PRECISION: i32 = 12;
+    const ROUNDING_CONST: i32 = 1 << (PRECISION - 1);
+
+    let max_colors = (1i32 << dst_bit_depth) - 1;
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
+
+    let range = get_yuv_range(src_bit_depth as u32, range);
+    let transform = get_inverse_transform(
+        max_colors as u32,
+        range.range_y,
+        range.range_uv,
+        kr_kb.kr,
+        kr_kb.kb,
+    );
+    let y_coef = transform.to_integers(PRECISION as u32).y_coef;
+    let bias_y = range.bias_y as i32;
+
+    let alpha_range = get_yuv_range(src_bit_depth as u32, alpha_range);
+    let alpha_transform = get_inverse_transform(
+        max_colors as u32,
+        alpha_range.range_y,
+        alpha_range.range_uv,
+        kr_kb.kr,
+        kr_kb.kb,
+    );
+    let a_coef = alpha_transform.to_integers(PRECISION as u32).y_coef;
+    let bias_a = alpha_range.bias_y as i32;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba
+            .par_chunks_mut(rgba_stride as usize / size_of::<D>())
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba
+            .chunks_mut(rgba_stride as usize / size_of::<D>())
+            .take(height as usize);
+    }
+
+    iter.enumerate().for_each(|(y, rgba)| {
+        let y_src = &y_plane[y * y_stride as usize / size_of::<S>()..];
+        let a_src = &a_plane[y * a_stride as usize / size_of::<S>()..];
+        for ((&y_vl, &a_vl), rgba) in y_src
+            .iter()
+            .zip(a_src)
+            .zip(rgba.chunks_exact_mut(channels))
+            .take(width as usize)
+        {
+            let y_value =
+                (y_vl.load::<ENDIANNESS, BYTES_POSITION>(src_bit_depth) - bias_y) * y_coef;
+            let a_value =
+                (a_vl.load::<ENDIANNESS, BYTES_POSITION>(src_bit_depth) - bias_a) * a_coef;
+
+            let r = ((y_value + ROUNDING_CONST) >> PRECISION)
+                .min(max_colors)
+                .max(0);
+            let a = ((a_value + ROUNDING_CONST) >> PRECISION)
+                .min(max_colors)
+                .max(0);
+            rgba[destination_channels.get_r_channel_offset()] = D::from_i32(r);
+            rgba[destination_channels.get_g_channel_offset()] = D::from_i32(r);
+            rgba[destination_channels.get_b_channel_offset()] = D::from_i32(r);
+            rgba[destination_channels.get_a_channel_offset()] = D::from_i32(a);
+        }
+    });
+
+    Ok(())
+}
+
+/// Convert monochrome image with alpha plane ( AVIF/HEIF style Y + A ) to RGBA format.
+///
+/// Unlike [yuv400_with_alpha_to_rgba] range of the alpha plane is explicit,
+/// limited range alpha is expanded onto full 0..255 range.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `a_plane` - A slice to load alpha plane data.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `range` - The range of the Y plane (limited or full).
+/// * `alpha_range` - The range of the alpha plane (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn y_with_alpha_to_rgba(
+    y_plane: &[u8],
+    y_stride: u32,
+    a_plane: &[u8],
+    a_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    alpha_range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    y_with_alpha_to_rgbx_generic::<
+        u8,
+        u8,
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::LeastSignificantBytes as u8 },
+    >(
+        y_plane,
+        y_stride,
+        a_plane,
+        a_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        alpha_range,
+        matrix,
+        8,
+        8,
+    )
+}
+
+/// Convert monochrome image with alpha plane ( AVIF/HEIF style Y + A ) to BGRA format.
+///
+/// Unlike [yuv400_with_alpha_to_bgra] range of the alpha plane is explicit,
+/// limited range alpha is expanded onto full 0..255 range.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `a_plane` - A slice to load alpha plane data.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for BGRA data.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `range` - The range of the Y plane (limited or full).
+/// * `alpha_range` - The range of the alpha plane (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn y_with_alpha_to_bgra(
+    y_plane: &[u8],
+    y_stride: u32,
+    a_plane: &[u8],
+    a_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    alpha_range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    y_with_alpha_to_rgbx_generic::<
+        u8,
+        u8,
+        { YuvSourceChannels::Bgra as u8 },
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::LeastSignificantBytes as u8 },
+    >(
+        y_plane,
+        y_stride,
+        a_plane,
+        a_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        alpha_range,
+        matrix,
+        8,
+        8,
+    )
+}
@@ -27,12 +27,21 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_ycgcor_to_rgb_row;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::sse_ycgcor_type_to_rgb_row;
 use crate::ycgcor_support::YCgCoR;
+use crate::yuv_error::{check_chroma_source_channel, check_rgba_destination, check_y8_channel};
 use crate::yuv_support::{get_yuv_range, YuvChromaSample, YuvSourceChannels};
+use crate::YuvError;
 use crate::YuvRange;
 
 fn ycgco_r_type_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8, const R_TYPE: usize>(
@@ -47,10 +56,16 @@ fn ycgco_r_type_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8, cons
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let destination_channels: YuvSourceChannels = DESTINATION_CHANNELS.into();
     let channels = destination_channels.get_channels_count();
+
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_source_channel(cg_plane, cg_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(co_plane, co_stride, width, height, chroma_subsampling)?;
+
     let range = get_yuv_range(8, range);
     let bias_y = range.bias_y as i32;
     let bias_uv = range.bias_uv as i32;
@@ -76,25 +91,31 @@ fn ycgco_r_type_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8, cons
     let range_reduction_uv =
         (max_colors as f32 / range.range_uv as f32 * precision_scale).round() as i32;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_sse = crate::cpu_features::has_sse41();
 
     for y in 0..height as usize {
         let mut _cx = 0usize;
         let mut _uv_x = 0usize;
 
-        let y_ptr = unsafe { (y_plane.as_ptr() as *const u8).add(y_offset) as *mut u16 };
-        let cg_ptr = unsafe { (cg_plane.as_ptr() as *const u8).add(u_offset) as *mut u16 };
-        let co_ptr = unsafe { (co_plane.as_ptr() as *const u8).add(v_offset) as *mut u16 };
+        let y_src = &y_plane[y_offset / 2..];
+        let cg_src = &cg_plane[u_offset / 2..];
+        let co_src = &co_plane[v_offset / 2..];
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
             if _use_sse {
                 let offset = sse_ycgcor_type_to_rgb_row::<DESTINATION_CHANNELS, SAMPLING>(
                     &range,
-                    y_ptr,
-                    cg_ptr,
-                    co_ptr,
+                    y_src.as_ptr(),
+                    cg_src.as_ptr(),
+                    co_src.as_ptr(),
                     rgba,
                     _cx,
                     _uv_x,
@@ -106,13 +127,17 @@ fn ycgco_r_type_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8, cons
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             let offset = neon_ycgcor_to_rgb_row::<DESTINATION_CHANNELS, SAMPLING>(
                 &range,
-                y_ptr,
-                cg_ptr,
-                co_ptr,
+                y_src.as_ptr(),
+                cg_src.as_ptr(),
+                co_src.as_ptr(),
                 rgba,
                 _cx,
                 _uv_x,
@@ -124,18 +149,15 @@ fn ycgco_r_type_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8, cons
         }
 
         for x in (_cx..width as usize).step_by(iterator_step) {
-            let y_value =
-                (unsafe { y_ptr.add(x).read_unaligned() as i32 } - bias_y) * range_reduction_y;
+            let y_value = (y_src[x] as i32 - bias_y) * range_reduction_y;
 
             let cg_pos = _uv_x;
 
-            let cg_value = (unsafe { cg_ptr.add(cg_pos).read_unaligned() } as i32 - bias_uv)
-                * range_reduction_uv;
+            let cg_value = (cg_src[cg_pos] as i32 - bias_uv) * range_reduction_uv;
 
             let v_pos = _uv_x;
 
-            let co_value = (unsafe { co_ptr.add(v_pos).read_unaligned() } as i32 - bias_uv)
-                * range_reduction_uv;
+            let co_value = (co_src[v_pos] as i32 - bias_uv) * range_reduction_uv;
 
             let t = y_value - (cg_value >> 1);
             let g = (((t + cg_value + ROUNDING_CONST).max(0)) >> PRECISION).min(255);
@@ -147,24 +169,11 @@ fn ycgco_r_type_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8, cons
 
             let rgba_shift = rgba_offset + px;
 
-            unsafe {
-                *rgba.get_unchecked_mut(rgba_shift + destination_channels.get_r_channel_offset()) =
-                    r as u8
-            };
-            unsafe {
-                *rgba.get_unchecked_mut(rgba_shift + destination_channels.get_g_channel_offset()) =
-                    g as u8
-            };
-            unsafe {
-                *rgba.get_unchecked_mut(rgba_shift + destination_channels.get_b_channel_offset()) =
-                    b as u8
-            };
+            rgba[rgba_shift + destination_channels.get_r_channel_offset()] = r as u8;
+            rgba[rgba_shift + destination_channels.get_g_channel_offset()] = g as u8;
+            rgba[rgba_shift + destination_channels.get_b_channel_offset()] = b as u8;
             if destination_channels.has_alpha() {
-                unsafe {
-                    *rgba.get_unchecked_mut(
-                        rgba_shift + destination_channels.get_a_channel_offset(),
-                    ) = 255
-                };
+                rgba[rgba_shift + destination_channels.get_a_channel_offset()] = 255;
             }
 
             if chroma_subsampling == YuvChromaSample::YUV420
@@ -172,7 +181,7 @@ fn ycgco_r_type_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8, cons
             {
                 let next_x = x + 1;
                 if next_x < width as usize {
-                    let y_value = unsafe { y_ptr.add(next_x).read_unaligned() } as i32 - bias_y;
+                    let y_value = y_src[next_x] as i32 - bias_y;
 
                     let t = y_value - (cg_value >> 1);
                     let g = (((t + cg_value + ROUNDING_CONST).max(0)) >> PRECISION).min(255);
@@ -184,27 +193,11 @@ fn ycgco_r_type_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8, cons
 
                     let rgba_shift = rgba_offset + next_px;
 
-                    unsafe {
-                        *rgba.get_unchecked_mut(
-                            rgba_shift + destination_channels.get_r_channel_offset(),
-                        ) = r as u8
-                    };
-                    unsafe {
-                        *rgba.get_unchecked_mut(
-                            rgba_shift + destination_channels.get_g_channel_offset(),
-                        ) = g as u8
-                    };
-                    unsafe {
-                        *rgba.get_unchecked_mut(
-                            rgba_shift + destination_channels.get_b_channel_offset(),
-                        ) = b as u8
-                    };
+                    rgba[rgba_shift + destination_channels.get_r_channel_offset()] = r as u8;
+                    rgba[rgba_shift + destination_channels.get_g_channel_offset()] = g as u8;
+                    rgba[rgba_shift + destination_channels.get_b_channel_offset()] = b as u8;
                     if destination_channels.has_alpha() {
-                        unsafe {
-                            *rgba.get_unchecked_mut(
-                                rgba_shift + destination_channels.get_a_channel_offset(),
-                            ) = 255
-                        };
+                        rgba[rgba_shift + destination_channels.get_a_channel_offset()] = 255;
                     }
                 }
             }
@@ -227,6 +220,8 @@ fn ycgco_r_type_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8, cons
             }
         }
     }
+
+    Ok(())
 }
 
 /// Convert YCgCo-Ro 420 planar format to RGB format.
@@ -264,7 +259,7 @@ pub fn ycgcoro420_to_rgb(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     ycgco_r_type_ro_rgbx::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -310,7 +305,7 @@ pub fn ycgcoro420_to_bgr(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     ycgco_r_type_ro_rgbx::<
         { YuvSourceChannels::Bgr as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -356,7 +351,7 @@ pub fn ycgcoro420_to_rgba(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     ycgco_r_type_ro_rgbx::<
         { YuvSourceChannels::Rgba as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -411,7 +406,7 @@ pub fn ycgcoro420_to_bgra(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     ycgco_r_type_ro_rgbx::<
         { YuvSourceChannels::Bgra as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -466,7 +461,7 @@ pub fn ycgcoro422_to_rgb(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     ycgco_r_type_ro_rgbx::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -512,7 +507,7 @@ pub fn ycgcoro422_to_bgr(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     ycgco_r_type_ro_rgbx::<
         { YuvSourceChannels::Bgr as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -558,7 +553,7 @@ pub fn ycgcoro422_to_rgba(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     ycgco_r_type_ro_rgbx::<
         { YuvSourceChannels::Rgba as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -613,7 +608,7 @@ pub fn ycgcoro422_to_bgra(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     ycgco_r_type_ro_rgbx::<
         { YuvSourceChannels::Bgra as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -668,7 +663,7 @@ pub fn ycgcoro444_to_rgba(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     ycgco_r_type_ro_rgbx::<
         { YuvSourceChannels::Rgba as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -723,7 +718,7 @@ pub fn ycgcoro444_to_bgra(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     ycgco_r_type_ro_rgbx::<
         { YuvSourceChannels::Bgra as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -778,7 +773,7 @@ pub fn ycgcoro444_to_rgb(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     ycgco_r_type_ro_rgbx::<
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -824,7 +819,7 @@ pub fn ycgcoro444_to_bgr(
     width: u32,
     height: u32,
     range: YuvRange,
-) {
+) -> Result<(), YuvError> {
     ycgco_r_type_ro_rgbx::<
         { YuvSourceChannels::Bgr as u8 },
         { YuvChromaSample::YUV444 as u8 },
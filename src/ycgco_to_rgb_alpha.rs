@@ -27,18 +27,29 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::avx2_ycgco_to_rgba_alpha;
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
-    feature = "nightly_avx512"
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
 ))]
 use crate::avx512bw::avx512_ycgco_to_rgba_alpha;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_ycgco_to_rgb_alpha_row;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::sse_ycgco_to_rgb_alpha_row;
-use crate::yuv_error::{check_chroma_channel, check_rgba_destination, check_y8_channel};
+use crate::yuv_error::{check_chroma_source_channel, check_rgba_destination, check_y8_channel};
 use crate::yuv_support::*;
 use crate::{YuvError, YuvRange};
 
@@ -65,8 +76,8 @@ fn ycgco_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
     check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
     check_y8_channel(y_plane, y_stride, width, height)?;
     check_y8_channel(a_plane, a_stride, width, height)?;
-    check_chroma_channel(cg_plane, cg_stride, width, height, chroma_subsampling)?;
-    check_chroma_channel(co_plane, co_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(cg_plane, cg_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(co_plane, co_stride, width, height, chroma_subsampling)?;
 
     let range = get_yuv_range(8, range);
     let bias_y = range.bias_y as i32;
@@ -94,15 +105,22 @@ fn ycgco_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
     let range_reduction_uv =
         (max_colors as f32 / range.range_uv as f32 * precision_scale).round() as i32;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_avx2 = std::arch::is_x86_feature_detected!("avx2");
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly_avx512"
+        not(feature = "safe-only")
     ))]
-    let mut _use_avx512 = std::arch::is_x86_feature_detected!("avx512bw");
+    let mut _use_sse = crate::cpu_features::has_sse41();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_avx2 = crate::cpu_features::has_avx2();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly_avx512",
+        not(feature = "safe-only")
+    ))]
+    let mut _use_avx512 = crate::cpu_features::has_avx512bw();
 
     for y in 0..height as usize {
         #[allow(unused_variables)]
@@ -113,7 +131,10 @@ fn ycgco_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
         #[allow(unused_mut)]
         let mut uv_x = 0usize;
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
             #[cfg(feature = "nightly_avx512")]
             if _use_avx512 {
@@ -181,7 +202,11 @@ fn ycgco_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             let processed = neon_ycgco_to_rgb_alpha_row::<DESTINATION_CHANNELS, SAMPLING>(
                 &range,
@@ -205,24 +230,21 @@ fn ycgco_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
         }
 
         for x in (cx..width as usize).step_by(iterator_step) {
-            let y_value = (unsafe { *y_plane.get_unchecked(y_offset + x) } as i32 - bias_y)
-                * range_reduction_y;
+            let y_value = (y_plane[y_offset + x] as i32 - bias_y) * range_reduction_y;
 
             let cg_pos = match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => u_offset + uv_x,
                 YuvChromaSample::YUV444 => u_offset + uv_x,
             };
 
-            let cg_value =
-                (unsafe { *cg_plane.get_unchecked(cg_pos) } as i32 - bias_uv) * range_reduction_uv;
+            let cg_value = (cg_plane[cg_pos] as i32 - bias_uv) * range_reduction_uv;
 
             let v_pos = match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => v_offset + uv_x,
                 YuvChromaSample::YUV444 => v_offset + uv_x,
             };
 
-            let co_value =
-                (unsafe { *co_plane.get_unchecked(v_pos) } as i32 - bias_uv) * range_reduction_uv;
+            let co_value = (co_plane[v_pos] as i32 - bias_uv) * range_reduction_uv;
 
             let t = y_value - cg_value;
 
@@ -236,7 +258,7 @@ fn ycgco_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
                 .min(255)
                 .max(0);
 
-            let a_value = unsafe { *a_plane.get_unchecked(a_offset + x) };
+            let a_value = a_plane[a_offset + x];
             if premultiply_alpha {
                 r = (r * a_value as i32) / 255;
                 g = (g * a_value as i32) / 255;
@@ -247,24 +269,11 @@ fn ycgco_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
 
             let rgba_shift = rgba_offset + px;
 
-            unsafe {
-                *rgba.get_unchecked_mut(rgba_shift + destination_channels.get_r_channel_offset()) =
-                    r as u8
-            };
-            unsafe {
-                *rgba.get_unchecked_mut(rgba_shift + destination_channels.get_g_channel_offset()) =
-                    g as u8
-            };
-            unsafe {
-                *rgba.get_unchecked_mut(rgba_shift + destination_channels.get_b_channel_offset()) =
-                    b as u8
-            };
+            rgba[rgba_shift + destination_channels.get_r_channel_offset()] = r as u8;
+            rgba[rgba_shift + destination_channels.get_g_channel_offset()] = g as u8;
+            rgba[rgba_shift + destination_channels.get_b_channel_offset()] = b as u8;
             if destination_channels.has_alpha() {
-                unsafe {
-                    *rgba.get_unchecked_mut(
-                        rgba_shift + destination_channels.get_a_channel_offset(),
-                    ) = 255
-                };
+                rgba[rgba_shift + destination_channels.get_a_channel_offset()] = 255;
             }
 
             if chroma_subsampling == YuvChromaSample::YUV420
@@ -272,9 +281,7 @@ fn ycgco_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
             {
                 let next_x = x + 1;
                 if next_x < width as usize {
-                    let y_value = (unsafe { *y_plane.get_unchecked(y_offset + next_x) } as i32
-                        - bias_y)
-                        * range_reduction_y;
+                    let y_value = (y_plane[y_offset + next_x] as i32 - bias_y) * range_reduction_y;
 
                     let mut r = ((t + co_value + ROUNDING_CONST) >> PRECISION)
                         .min(255)
@@ -290,34 +297,18 @@ fn ycgco_ro_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
 
                     let rgba_shift = rgba_offset + next_px;
 
-                    let a_value = unsafe { *a_plane.get_unchecked(a_offset + next_x) };
+                    let a_value = a_plane[a_offset + next_x];
                     if premultiply_alpha {
                         r = (r * a_value as i32) / 255;
                         g = (g * a_value as i32) / 255;
                         b = (b * a_value as i32) / 255;
                     }
 
-                    unsafe {
-                        *rgba.get_unchecked_mut(
-                            rgba_shift + destination_channels.get_r_channel_offset(),
-                        ) = r as u8
-                    };
-                    unsafe {
-                        *rgba.get_unchecked_mut(
-                            rgba_shift + destination_channels.get_g_channel_offset(),
-                        ) = g as u8
-                    };
-                    unsafe {
-                        *rgba.get_unchecked_mut(
-                            rgba_shift + destination_channels.get_b_channel_offset(),
-                        ) = b as u8
-                    };
+                    rgba[rgba_shift + destination_channels.get_r_channel_offset()] = r as u8;
+                    rgba[rgba_shift + destination_channels.get_g_channel_offset()] = g as u8;
+                    rgba[rgba_shift + destination_channels.get_b_channel_offset()] = b as u8;
                     if destination_channels.has_alpha() {
-                        unsafe {
-                            *rgba.get_unchecked_mut(
-                                rgba_shift + destination_channels.get_a_channel_offset(),
-                            ) = a_value;
-                        };
+                        rgba[rgba_shift + destination_channels.get_a_channel_offset()] = a_value;
                     }
                 }
             }
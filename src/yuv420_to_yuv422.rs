@@ -0,0 +1,309 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{YuvBiPlanarImage, YuvBiPlanarImageMut, YuvPlanarImage, YuvPlanarImageMut};
+use crate::nv_to_yuv444::{far_index, YuvChromaUpsampling};
+use crate::yuv_support::YuvChromaSample;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+use std::fmt::Debug;
+
+/// Copies `height` rows of `row_len` elements between planes with strides in elements
+fn copy_plane<T: Copy + Send + Sync>(
+    src: &[T],
+    src_stride: usize,
+    dst: &mut [T],
+    dst_stride: usize,
+    row_len: usize,
+    height: usize,
+) {
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst.par_chunks_mut(dst_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst.chunks_mut(dst_stride);
+    }
+    iter.take(height).enumerate().for_each(|(y, dst_row)| {
+        let src_row = &src[y * src_stride..];
+        dst_row[..row_len].copy_from_slice(&src_row[..row_len]);
+    });
+}
+
+/// Doubles chroma rows of a 4:2:0 plane, `row_len` elements of each row are resampled
+/// independently so interleaved chroma is handled the same as planar one.
+///
+/// Linear reconstruction weights the nearest chroma row 3:1 against its neighbour,
+/// as for chroma sited vertically between luma rows.
+fn double_chroma_rows<T: Copy + Send + Sync>(
+    src: &[T],
+    src_stride: usize,
+    dst: &mut [T],
+    dst_stride: usize,
+    row_len: usize,
+    height: usize,
+    upsampling: YuvChromaUpsampling,
+    load: impl Fn(T) -> u32 + Send + Sync,
+    store: impl Fn(u32) -> T + Send + Sync,
+) {
+    let chroma_height = height.div_ceil(2);
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst.par_chunks_mut(dst_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst.chunks_mut(dst_stride);
+    }
+    iter.take(height).enumerate().for_each(|(y, dst_row)| {
+        let near_row = &src[(y / 2) * src_stride..];
+        match upsampling {
+            YuvChromaUpsampling::Nearest => {
+                dst_row[..row_len].copy_from_slice(&near_row[..row_len]);
+            }
+            YuvChromaUpsampling::Bilinear => {
+                let far_row = &src[far_index(y, chroma_height, upsampling) * src_stride..];
+                for ((dst, &near), &far) in dst_row
+                    .iter_mut()
+                    .zip(near_row.iter())
+                    .zip(far_row.iter())
+                    .take(row_len)
+                {
+                    *dst = store((3 * load(near) + load(far) + 2) >> 2);
+                }
+            }
+        }
+    });
+}
+
+fn yuv420_to_yuv422_impl<T: Copy + Debug + Send + Sync>(
+    src: &YuvPlanarImage<T>,
+    dst: &mut YuvPlanarImageMut<T>,
+    upsampling: YuvChromaUpsampling,
+    load: impl Fn(T) -> u32 + Send + Sync,
+    store: impl Fn(u32) -> T + Send + Sync,
+) -> Result<(), YuvError> {
+    if src.size.width == 0 || src.size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    src.check_constraints(YuvChromaSample::YUV420)?;
+    if dst.size != src.size {
+        return Err(YuvError::ImageSizeMismatch(src.size, dst.size));
+    }
+    dst.check_constraints(YuvChromaSample::YUV422)?;
+
+    let width = src.size.width as usize;
+    let height = src.size.height as usize;
+    let chroma_width = width.div_ceil(2);
+    let element_size = std::mem::size_of::<T>();
+
+    copy_plane(
+        src.y_plane,
+        src.y_stride.get() as usize / element_size,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride.get() as usize / element_size,
+        width,
+        height,
+    );
+    double_chroma_rows(
+        src.u_plane,
+        src.u_stride.get() as usize / element_size,
+        dst.u_plane.borrow_mut(),
+        dst.u_stride.get() as usize / element_size,
+        chroma_width,
+        height,
+        upsampling,
+        &load,
+        &store,
+    );
+    double_chroma_rows(
+        src.v_plane,
+        src.v_stride.get() as usize / element_size,
+        dst.v_plane.borrow_mut(),
+        dst.v_stride.get() as usize / element_size,
+        chroma_width,
+        height,
+        upsampling,
+        &load,
+        &store,
+    );
+    Ok(())
+}
+
+fn yuv_nv420_to_nv422_impl<T: Copy + Debug + Send + Sync>(
+    src: &YuvBiPlanarImage<T>,
+    dst: &mut YuvBiPlanarImageMut<T>,
+    upsampling: YuvChromaUpsampling,
+    load: impl Fn(T) -> u32 + Send + Sync,
+    store: impl Fn(u32) -> T + Send + Sync,
+) -> Result<(), YuvError> {
+    if src.size.width == 0 || src.size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    src.check_constraints(YuvChromaSample::YUV420)?;
+    if dst.size != src.size {
+        return Err(YuvError::ImageSizeMismatch(src.size, dst.size));
+    }
+    dst.check_constraints(YuvChromaSample::YUV422)?;
+
+    let width = src.size.width as usize;
+    let height = src.size.height as usize;
+    let element_size = std::mem::size_of::<T>();
+
+    copy_plane(
+        src.y_plane,
+        src.y_stride.get() as usize / element_size,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride.get() as usize / element_size,
+        width,
+        height,
+    );
+    double_chroma_rows(
+        src.uv_plane,
+        src.uv_stride.get() as usize / element_size,
+        dst.uv_plane.borrow_mut(),
+        dst.uv_stride.get() as usize / element_size,
+        width.div_ceil(2) * 2,
+        height,
+        upsampling,
+        load,
+        store,
+    );
+    Ok(())
+}
+
+/// Convert YUV 420 planar format to YUV 422 planar format by doubling chroma rows.
+///
+/// Luma is copied as is, horizontal chroma resolution is kept.
+///
+/// # Arguments
+///
+/// * `planar_image` - Source 4:2:0 planar image.
+/// * `target_image` - Target 4:2:2 planar image of the same size.
+/// * `upsampling` - Vertical chroma reconstruction, [YuvChromaUpsampling::Bilinear] blends
+///   two nearest chroma rows 3:1.
+///
+pub fn yuv420_to_yuv422(
+    planar_image: &YuvPlanarImage<u8>,
+    target_image: &mut YuvPlanarImageMut<u8>,
+    upsampling: YuvChromaUpsampling,
+) -> Result<(), YuvError> {
+    yuv420_to_yuv422_impl(
+        planar_image,
+        target_image,
+        upsampling,
+        |v| v as u32,
+        |v| v as u8,
+    )
+}
+
+/// Convert YUV 420 planar format with 8+ bit pixel format to YUV 422 planar format
+/// of the same bit depth by doubling chroma rows.
+///
+/// Samples are blended as native integers in the least significant bits, so any bit depth
+/// up to 16 is preserved. Luma is copied as is, horizontal chroma resolution is kept.
+///
+/// # Arguments
+///
+/// * `planar_image` - Source 4:2:0 planar image.
+/// * `target_image` - Target 4:2:2 planar image of the same size.
+/// * `upsampling` - Vertical chroma reconstruction, [YuvChromaUpsampling::Bilinear] blends
+///   two nearest chroma rows 3:1.
+///
+pub fn yuv420_p16_to_yuv422_p16(
+    planar_image: &YuvPlanarImage<u16>,
+    target_image: &mut YuvPlanarImageMut<u16>,
+    upsampling: YuvChromaUpsampling,
+) -> Result<(), YuvError> {
+    yuv420_to_yuv422_impl(
+        planar_image,
+        target_image,
+        upsampling,
+        |v| v as u32,
+        |v| v as u16,
+    )
+}
+
+/// Convert YUV NV12 to YUV NV16 by doubling rows of the interleaved chroma plane.
+///
+/// Chroma order is kept, so the same call converts NV21 to NV61.
+/// Luma is copied as is, horizontal chroma resolution is kept.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source NV12 image.
+/// * `target_image` - Target NV16 image of the same size.
+/// * `upsampling` - Vertical chroma reconstruction, [YuvChromaUpsampling::Bilinear] blends
+///   two nearest chroma rows 3:1.
+///
+pub fn yuv_nv12_to_yuv_nv16(
+    bi_planar_image: &YuvBiPlanarImage<u8>,
+    target_image: &mut YuvBiPlanarImageMut<u8>,
+    upsampling: YuvChromaUpsampling,
+) -> Result<(), YuvError> {
+    yuv_nv420_to_nv422_impl(
+        bi_planar_image,
+        target_image,
+        upsampling,
+        |v| v as u32,
+        |v| v as u8,
+    )
+}
+
+/// Convert P010 to P210 by doubling rows of the interleaved chroma plane.
+///
+/// Both keep 10-bit samples in the most significant bits of little endian 16-bit words,
+/// blended chroma is rounded back to 10 bits. Luma is copied as is.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source P010 image.
+/// * `target_image` - Target P210 image of the same size.
+/// * `upsampling` - Vertical chroma reconstruction, [YuvChromaUpsampling::Bilinear] blends
+///   two nearest chroma rows 3:1.
+///
+pub fn yuv_p010_to_yuv_p210(
+    bi_planar_image: &YuvBiPlanarImage<u16>,
+    target_image: &mut YuvBiPlanarImageMut<u16>,
+    upsampling: YuvChromaUpsampling,
+) -> Result<(), YuvError> {
+    yuv_nv420_to_nv422_impl(
+        bi_planar_image,
+        target_image,
+        upsampling,
+        |v| (u16::from_le(v) >> 6) as u32,
+        |v| ((v as u16) << 6).to_le(),
+    )
+}
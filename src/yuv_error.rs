@@ -26,7 +26,12 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+use crate::color_description::{
+    YuvChromaSiting, YuvColorPrimaries, YuvMatrixCoefficients, YuvTransferCharacteristics,
+};
+use crate::images::ImageSize;
 use crate::yuv_support::YuvChromaSample;
+use std::cell::Cell;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 
@@ -44,6 +49,61 @@ pub enum YuvError {
     ZeroBaseSize,
     LumaPlaneSizeMismatch(MismatchedSize),
     LumaPlaneMinimumSizeMismatch(MismatchedSize),
+    PackedFrameSizeMismatch(MismatchedSize),
+    PackedFrameMinimumSizeMismatch(MismatchedSize),
+    ScratchSizeMismatch(MismatchedSize),
+    PixelStrideMismatch(MismatchedSize),
+    AllocationFailure(usize),
+    UnsupportedMatrixCoefficients(YuvMatrixCoefficients),
+    UnsupportedColorPrimaries(YuvColorPrimaries),
+    UnsupportedChromaSiting(YuvChromaSiting),
+    UnsupportedTransferCharacteristics(YuvTransferCharacteristics),
+    /// Levels black point is not below white point
+    LevelsRangeMismatch(u8, u8),
+    /// Gamma is not finite or not positive
+    InvalidGamma(f32),
+    /// Tensor normalization standard deviation is zero or not finite
+    InvalidTensorNormalization,
+    /// Custom range bounds are inverted or do not fit the bit depth
+    InvalidCustomRange(u32),
+    /// Byte plane is not aligned to `u16` or its length or stride is odd
+    PlaneReinterpretationFailure,
+    /// Requested bit depth is not supported by the converter
+    UnsupportedBitDepth(u32),
+    /// Broadcast safe margin leaves no legal codes in the target range
+    InvalidBroadcastMargin(u8),
+    /// Lookup table does not have one entry per code value
+    LutSizeMismatch(MismatchedSize),
+    /// 3D LUT grid is smaller than 2 or larger than 256 points per axis
+    InvalidLut3dSize(usize),
+    /// `.cube` file is malformed at given line
+    CubeParseError(usize),
+    /// Preview downscale factor is zero
+    InvalidPreviewFactor(u32),
+    /// Row stride in bytes cannot hold a single row of visible pixels
+    StrideTooSmall(MismatchedSize),
+    /// Row range start is past its end or range ends below the last image row
+    InvalidRowRange(u32, u32),
+    /// Bit shift moves samples out of the destination type
+    InvalidShift(u32),
+    /// Destination image dimensions differ from the source ones, source size goes first
+    ImageSizeMismatch(ImageSize, ImageSize),
+    /// Crop region of given size placed at `x`, `y` does not fit in the source image
+    CropOutOfBounds(u32, u32, ImageSize),
+    /// Hue rotation angle is not finite
+    InvalidHueAngle(f32),
+    /// Plane data offset in bytes is not a multiple of sample size or lies past the plane end
+    InvalidDataOffset(u32),
+    /// Some plane of the image of given size takes more bytes than a slice can address,
+    /// that is `isize::MAX`, only reachable on 32-bit targets for images below 2^32 pixels
+    ImageTooLarge(ImageSize),
+    /// In-place destination stride exceeds source stride, so written rows would
+    /// overrun source pixels that are not converted yet
+    InPlaceStrideMismatch(MismatchedSize),
+    /// Interlaced frame of given height does not split into two fields of whole luma and chroma rows
+    InvalidFieldHeight(u32),
+    /// [crate::YuvStandardMatrix::Auto] was given to a converter that does not see the whole frame
+    UnresolvedAutoMatrix,
 }
 
 impl Display for YuvError {
@@ -67,17 +127,246 @@ impl Display for YuvError {
                 "Destination must have size at least {} but it is {}",
                 size.expected, size.received
             )),
+            YuvError::PackedFrameSizeMismatch(size) => f.write_fmt(format_args!(
+                "Packed frame have invalid size, it must be {}, but it was {}",
+                size.expected, size.received
+            )),
+            YuvError::PackedFrameMinimumSizeMismatch(size) => f.write_fmt(format_args!(
+                "Packed frame have invalid size, it must be at least {}, but it was {}",
+                size.expected, size.received
+            )),
+            YuvError::ScratchSizeMismatch(size) => f.write_fmt(format_args!(
+                "Scratch arena is too small, it must have at least {} bytes left, but it has {}",
+                size.expected, size.received
+            )),
+            YuvError::PixelStrideMismatch(size) => f.write_fmt(format_args!(
+                "Pixel stride must be at least {} bytes, but it was {}",
+                size.expected, size.received
+            )),
+            YuvError::AllocationFailure(size) => {
+                f.write_fmt(format_args!("Failed to allocate {} bytes of memory", size))
+            }
+            YuvError::UnsupportedMatrixCoefficients(matrix) => f.write_fmt(format_args!(
+                "Matrix coefficients {:?} are not supported by this conversion",
+                matrix
+            )),
+            YuvError::UnsupportedColorPrimaries(primaries) => f.write_fmt(format_args!(
+                "Colour primaries {:?} cannot be used to derive matrix coefficients",
+                primaries
+            )),
+            YuvError::UnsupportedChromaSiting(siting) => f.write_fmt(format_args!(
+                "Chroma siting {:?} is not supported by this conversion",
+                siting
+            )),
+            YuvError::UnsupportedTransferCharacteristics(transfer) => f.write_fmt(format_args!(
+                "Transfer characteristics {:?} are not supported by this conversion",
+                transfer
+            )),
+            YuvError::LevelsRangeMismatch(black, white) => f.write_fmt(format_args!(
+                "Levels black point {} must be less than white point {}",
+                black, white
+            )),
+            YuvError::InvalidGamma(gamma) => f.write_fmt(format_args!(
+                "Gamma must be finite and positive, but {} was received",
+                gamma
+            )),
+            YuvError::InvalidTensorNormalization => f.write_str(
+                "Tensor normalization standard deviation must be finite and non zero",
+            ),
+            YuvError::InvalidCustomRange(bit_depth) => f.write_fmt(format_args!(
+                "Custom range bounds must be ordered and fit {} bit depth",
+                bit_depth
+            )),
+            YuvError::PlaneReinterpretationFailure => f.write_str(
+                "Plane bytes cannot be viewed as 16-bit samples, buffer must be 2-byte aligned with even length and stride",
+            ),
+            YuvError::UnsupportedBitDepth(bit_depth) => f.write_fmt(format_args!(
+                "Bit depth {} is not supported",
+                bit_depth
+            )),
+            YuvError::InvalidBroadcastMargin(margin) => f.write_fmt(format_args!(
+                "Broadcast safe margin {} leaves no legal codes in the target range",
+                margin
+            )),
+            YuvError::LutSizeMismatch(size) => f.write_fmt(format_args!(
+                "Lookup table must have {} entries, but it has {}",
+                size.expected, size.received
+            )),
+            YuvError::InvalidLut3dSize(size) => f.write_fmt(format_args!(
+                "3D LUT must have from 2 to 256 points per axis, but it has {}",
+                size
+            )),
+            YuvError::CubeParseError(line) => {
+                f.write_fmt(format_args!("Malformed cube file at line {}", line))
+            }
+            YuvError::InvalidPreviewFactor(factor) => f.write_fmt(format_args!(
+                "Preview downscale factor must be at least 1, but it was {}",
+                factor
+            )),
+            YuvError::StrideTooSmall(size) => f.write_fmt(format_args!(
+                "Stride must be at least {} bytes to hold a row, but it was {}",
+                size.expected, size.received
+            )),
+            YuvError::InvalidRowRange(start, end) => f.write_fmt(format_args!(
+                "Row range {}..{} must be ordered and fit image height",
+                start, end
+            )),
+            YuvError::InvalidShift(shift) => f.write_fmt(format_args!(
+                "Shift {} is too large for the destination samples",
+                shift
+            )),
+            YuvError::ImageSizeMismatch(source, destination) => f.write_fmt(format_args!(
+                "Destination image is {}x{}, but source image is {}x{}",
+                destination.width, destination.height, source.width, source.height
+            )),
+            YuvError::CropOutOfBounds(x, y, size) => f.write_fmt(format_args!(
+                "Crop region {}x{} at ({}, {}) does not fit in the source image",
+                size.width, size.height, x, y
+            )),
+            YuvError::InvalidHueAngle(degrees) => f.write_fmt(format_args!(
+                "Hue rotation angle must be finite, but {} was received",
+                degrees
+            )),
+            YuvError::InvalidDataOffset(offset) => f.write_fmt(format_args!(
+                "Plane data offset {} must be aligned to sample size and lie inside the plane",
+                offset
+            )),
+            YuvError::ImageTooLarge(size) => f.write_fmt(format_args!(
+                "Image {}x{} does not fit into addressable memory",
+                size.width, size.height
+            )),
+            YuvError::InPlaceStrideMismatch(size) => f.write_fmt(format_args!(
+                "In-place destination stride must be at most source stride {}, but it was {}",
+                size.expected, size.received
+            )),
+            YuvError::InvalidFieldHeight(height) => f.write_fmt(format_args!(
+                "Frame height {} does not split into two fields of whole luma and chroma rows",
+                height
+            )),
+            YuvError::UnresolvedAutoMatrix => f.write_str(
+                "Automatic matrix needs frame dimensions, resolve it with YuvStandardMatrix::resolve",
+            ),
         }
     }
 }
 
 impl Error for YuvError {}
 
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// Controls how buffer sizes are validated against `stride * height`, selected per call
+/// with [with_stride_mode] or [crate::YuvConversionContext::with_stride_mode]
+pub enum YuvStrideMode {
+    /// Every plane must hold exactly `stride * height` elements, padding of the last row included
+    #[default]
+    Strict = 0,
+    /// The last row of a plane may end right after its visible pixels, only `width` pixels
+    /// are processed on that row and its padding is never read or written
+    AutoTail = 1,
+}
+
+thread_local! {
+    /// Mode of conversions running inside [with_stride_mode] on this thread
+    static STRIDE_MODE: Cell<YuvStrideMode> = const { Cell::new(YuvStrideMode::Strict) };
+}
+
+/// Restores mode of the enclosing scope, also when the scoped call unwinds
+struct StrideModeGuard(YuvStrideMode);
+
+impl Drop for StrideModeGuard {
+    fn drop(&mut self) {
+        STRIDE_MODE.with(|x| x.set(self.0));
+    }
+}
+
+/// Runs `f` with buffers of every conversion it starts validated in `mode`.
+///
+/// Mode is scoped to the call and the current thread, conversions outside of `f`,
+/// including ones running concurrently on other threads, keep [YuvStrideMode::Strict].
+///
+/// # Arguments
+///
+/// * `mode` - Validation mode of conversions called by `f`.
+/// * `f` - Conversions to run.
+///
+pub fn with_stride_mode<R>(mode: YuvStrideMode, f: impl FnOnce() -> R) -> R {
+    let _guard = StrideModeGuard(STRIDE_MODE.with(|x| x.replace(mode)));
+    f()
+}
+
+/// Returns buffer validation mode of conversions started from the current scope
+pub fn stride_mode() -> YuvStrideMode {
+    STRIDE_MODE.with(|x| x.get())
+}
+
+/// Checks that `received` bytes hold `rows` rows of `stride` bytes,
+/// row takes at least `row_size` bytes, the last one may be short when [YuvStrideMode::AutoTail] is active
 #[inline]
-pub(crate) fn check_overflow_v2(v0: usize, v1: usize) -> Result<(), YuvError> {
-    let (_, overflow) = v0.overflowing_mul(v1);
-    if overflow {
-        return Err(YuvError::PointerOverflow);
+fn check_plane_size(
+    received: usize,
+    stride: usize,
+    row_size: usize,
+    rows: usize,
+) -> Result<(), MismatchedSize> {
+    let full_size = stride * rows;
+    let fits = match stride_mode() {
+        YuvStrideMode::Strict => received == full_size,
+        YuvStrideMode::AutoTail => {
+            let min_size = if rows == 0 {
+                0
+            } else {
+                full_size - stride + row_size
+            };
+            received >= min_size && received <= full_size
+        }
+    };
+    if !fits {
+        return Err(MismatchedSize {
+            expected: full_size,
+            received,
+        });
+    }
+    Ok(())
+}
+
+/// Checks that a row of `row_size` bytes fits into `stride`
+#[inline]
+pub(crate) fn check_stride(stride: usize, row_size: usize) -> Result<(), YuvError> {
+    if stride < row_size {
+        return Err(YuvError::StrideTooSmall(MismatchedSize {
+            expected: row_size,
+            received: stride,
+        }));
+    }
+    Ok(())
+}
+
+/// Returns bytes taken by `count` items of `item_size` bytes, for an image of `width` x `height`
+#[inline]
+fn image_bytes(count: usize, item_size: usize, width: u32, height: u32) -> Result<usize, YuvError> {
+    count
+        .checked_mul(item_size)
+        .ok_or(YuvError::ImageTooLarge(ImageSize::new(width, height)))
+}
+
+/// Checks that image of `width` x `height` is not empty and `rows` rows of `stride` bytes,
+/// each holding `row_size` visible bytes, fit into a single slice, so every offset inside
+/// the plane is representable by `usize`
+#[inline]
+fn check_addressable(
+    stride: usize,
+    row_size: usize,
+    rows: usize,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    if width == 0 || height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    let full_size = image_bytes(stride, rows, width, height)?;
+    let visible_size = image_bytes(row_size, rows, width, height)?;
+    if full_size.max(visible_size) > isize::MAX as usize {
+        return Err(YuvError::ImageTooLarge(ImageSize::new(width, height)));
     }
     Ok(())
 }
@@ -96,84 +385,306 @@ pub(crate) fn check_overflow_v3(v0: usize, v1: usize, v2: usize) -> Result<(), Y
 }
 
 #[inline]
-pub(crate) fn check_rgba_destination(
+pub(crate) fn check_rgba_destination<V>(
+    arr: &[V],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    channels: usize,
+) -> Result<(), YuvError> {
+    let element_size = std::mem::size_of::<V>();
+    let row_size = image_bytes(width as usize, channels * element_size, width, height)?;
+    check_addressable(
+        rgba_stride as usize,
+        row_size,
+        height as usize,
+        width,
+        height,
+    )?;
+    check_stride(rgba_stride as usize, row_size)?;
+    check_plane_size(
+        std::mem::size_of_val(arr),
+        rgba_stride as usize,
+        row_size,
+        height as usize,
+    )
+    .map_err(YuvError::DestinationSizeMismatch)?;
+    Ok(())
+}
+
+/// Checks interleaved image whose pixels are `pixel_stride` bytes apart,
+/// only the first `channels` bytes of each pixel are read
+#[inline]
+pub(crate) fn check_rgba_pixel_stride(
     arr: &[u8],
     rgba_stride: u32,
+    pixel_stride: u32,
     width: u32,
     height: u32,
     channels: usize,
 ) -> Result<(), YuvError> {
-    check_overflow_v3(width as usize, height as usize, channels)?;
-    if arr.len() != rgba_stride as usize * height as usize {
-        return Err(YuvError::DestinationSizeMismatch(MismatchedSize {
-            expected: rgba_stride as usize * height as usize,
-            received: arr.len(),
-        }));
-    }
-    if (rgba_stride as usize * height as usize) < (width as usize * height as usize * channels) {
-        return Err(YuvError::MinimumDestinationSizeMismatch(MismatchedSize {
-            expected: width as usize * height as usize * channels,
-            received: rgba_stride as usize * height as usize,
+    if (pixel_stride as usize) < channels {
+        return Err(YuvError::PixelStrideMismatch(MismatchedSize {
+            expected: channels,
+            received: pixel_stride as usize,
         }));
     }
+    let row_size = if width == 0 {
+        0
+    } else {
+        image_bytes(width as usize - 1, pixel_stride as usize, width, height)? + channels
+    };
+    check_addressable(
+        rgba_stride as usize,
+        row_size,
+        height as usize,
+        width,
+        height,
+    )?;
+    check_stride(rgba_stride as usize, row_size)?;
+    check_plane_size(arr.len(), rgba_stride as usize, row_size, height as usize)
+        .map_err(YuvError::DestinationSizeMismatch)?;
     Ok(())
 }
 
 #[inline]
-pub(crate) fn check_y8_channel(
-    data: &[u8],
+pub(crate) fn check_y8_channel<V>(
+    data: &[V],
     stride: u32,
     width: u32,
     height: u32,
 ) -> Result<(), YuvError> {
-    check_overflow_v2(stride as usize, height as usize)?;
-    check_overflow_v2(width as usize, height as usize)?;
-    if (stride as usize * height as usize) < (width as usize * height as usize) {
-        return Err(YuvError::LumaPlaneMinimumSizeMismatch(MismatchedSize {
-            expected: width as usize * height as usize,
-            received: stride as usize * height as usize,
-        }));
-    }
-    if stride as usize * height as usize != data.len() {
-        return Err(YuvError::LumaPlaneSizeMismatch(MismatchedSize {
-            expected: stride as usize * height as usize,
-            received: data.len(),
-        }));
-    }
+    let element_size = std::mem::size_of::<V>();
+    let row_size = image_bytes(width as usize, element_size, width, height)?;
+    check_addressable(stride as usize, row_size, height as usize, width, height)?;
+    check_stride(stride as usize, row_size)?;
+    check_plane_size(
+        std::mem::size_of_val(data),
+        stride as usize,
+        row_size,
+        height as usize,
+    )
+    .map_err(YuvError::LumaPlaneSizeMismatch)?;
     Ok(())
 }
 
 #[inline]
-pub(crate) fn check_chroma_channel(
-    data: &[u8],
+pub(crate) fn check_chroma_channel<V>(
+    data: &[V],
     stride: u32,
     image_width: u32,
     image_height: u32,
     sampling: YuvChromaSample,
 ) -> Result<(), YuvError> {
     let chroma_min_width = match sampling {
-        YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => (image_width + 1) / 2,
+        YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => image_width.div_ceil(2),
         YuvChromaSample::YUV444 => image_width,
     };
     let chroma_height = match sampling {
-        YuvChromaSample::YUV420 => (image_height + 1) / 2,
+        YuvChromaSample::YUV420 => image_height.div_ceil(2),
         YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => image_height,
     };
-    check_overflow_v2(stride as usize, chroma_height as usize)?;
-    check_overflow_v2(chroma_min_width as usize, chroma_height as usize)?;
-    if (stride as usize * chroma_height as usize)
-        < (chroma_min_width as usize * chroma_height as usize)
-    {
-        return Err(YuvError::LumaPlaneMinimumSizeMismatch(MismatchedSize {
-            expected: chroma_min_width as usize * chroma_height as usize,
-            received: stride as usize * chroma_height as usize,
-        }));
+    let element_size = std::mem::size_of::<V>();
+    let row_size = image_bytes(
+        chroma_min_width as usize,
+        element_size,
+        image_width,
+        image_height,
+    )?;
+    check_addressable(
+        stride as usize,
+        row_size,
+        chroma_height as usize,
+        image_width,
+        image_height,
+    )?;
+    check_stride(stride as usize, row_size)?;
+    check_plane_size(
+        std::mem::size_of_val(data),
+        stride as usize,
+        row_size,
+        chroma_height as usize,
+    )
+    .map_err(YuvError::LumaPlaneSizeMismatch)?;
+    Ok(())
+}
+
+/// Same as [check_chroma_channel] for planes that are only read, `stride == 0` is accepted
+/// and means a single row replicated for every chroma row
+#[inline]
+pub(crate) fn check_chroma_source_channel<V>(
+    data: &[V],
+    stride: u32,
+    image_width: u32,
+    image_height: u32,
+    sampling: YuvChromaSample,
+) -> Result<(), YuvError> {
+    if stride != 0 {
+        return check_chroma_channel(data, stride, image_width, image_height, sampling);
     }
-    if stride as usize * chroma_height as usize != data.len() {
+    let chroma_min_width = match sampling {
+        YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => image_width.div_ceil(2),
+        YuvChromaSample::YUV444 => image_width,
+    };
+    let row_size = image_bytes(
+        chroma_min_width as usize,
+        std::mem::size_of::<V>(),
+        image_width,
+        image_height,
+    )?;
+    let received = std::mem::size_of_val(data);
+    if received < row_size {
         return Err(YuvError::LumaPlaneSizeMismatch(MismatchedSize {
-            expected: stride as usize * chroma_height as usize,
-            received: data.len(),
+            expected: row_size,
+            received,
         }));
     }
     Ok(())
 }
+
+/// Checks packed 4:2:2 frame ( YUYV and friends ), odd width is rounded up to the last pair
+#[inline]
+pub(crate) fn check_yuv_packed422<V>(
+    data: &[V],
+    stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    let element_size = std::mem::size_of::<V>();
+    let row_size = image_bytes(
+        (width as usize).div_ceil(2),
+        4 * element_size,
+        width,
+        height,
+    )?;
+    check_addressable(stride as usize, row_size, height as usize, width, height)?;
+    check_stride(stride as usize, row_size)?;
+    check_plane_size(
+        std::mem::size_of_val(data),
+        stride as usize,
+        row_size,
+        height as usize,
+    )
+    .map_err(YuvError::PackedFrameSizeMismatch)?;
+    Ok(())
+}
+
+/// Checks bit depth of 8+ bit planes stored in 16-bit words
+#[inline]
+pub(crate) fn check_p16_bit_depth(bit_depth: u32) -> Result<(), YuvError> {
+    if !(8..=16).contains(&bit_depth) {
+        return Err(YuvError::UnsupportedBitDepth(bit_depth));
+    }
+    Ok(())
+}
+
+/// Checks 10-bit packed plane ( MIPI RAW10 ), every 4 samples take 5 bytes and
+/// the last group of a row is padded to full size
+#[inline]
+pub(crate) fn check_raw10_plane(
+    data: &[u8],
+    stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    let row_size = image_bytes((width as usize).div_ceil(4), 5, width, height)?;
+    check_addressable(stride as usize, row_size, height as usize, width, height)?;
+    check_stride(stride as usize, row_size)?;
+    check_plane_size(data.len(), stride as usize, row_size, height as usize)
+        .map_err(YuvError::PackedFrameSizeMismatch)?;
+    Ok(())
+}
+
+/// Checks SMPTE ST 2110 4:2:2 10-bit frame, every pixel pair takes 5 bytes ( pgroup )
+/// and odd width is rounded up to the last pair
+#[inline]
+pub(crate) fn check_pgroup422_frame(
+    data: &[u8],
+    stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    let row_size = image_bytes((width as usize).div_ceil(2), 5, width, height)?;
+    check_addressable(stride as usize, row_size, height as usize, width, height)?;
+    check_stride(stride as usize, row_size)?;
+    check_plane_size(data.len(), stride as usize, row_size, height as usize)
+        .map_err(YuvError::PackedFrameSizeMismatch)?;
+    Ok(())
+}
+
+/// Checks single buffer IMC1-IMC4 frame: luma rows followed by chroma rows of the same stride.
+///
+/// With `split_chroma_rows` each chroma row holds V and U halves starting at half stride ( IMC2, IMC4 ),
+/// otherwise V and U planes follow each other ( IMC1, IMC3 ).
+#[inline]
+pub(crate) fn check_imc_frame(
+    data: &[u8],
+    stride: u32,
+    width: u32,
+    height: u32,
+    split_chroma_rows: bool,
+) -> Result<(), YuvError> {
+    let chroma_width = width.div_ceil(2) as usize;
+    let chroma_height = height.div_ceil(2) as usize;
+    let stride = stride as usize;
+    let (rows, min_row_width, last_row_size) = if split_chroma_rows {
+        (
+            height as usize + chroma_height,
+            stride / 2 + chroma_width,
+            stride / 2 + chroma_width,
+        )
+    } else {
+        (
+            height as usize + chroma_height * 2,
+            width as usize,
+            chroma_width,
+        )
+    };
+    check_addressable(stride, last_row_size, rows, width, height)?;
+    check_stride(stride, min_row_width.max(width as usize))?;
+    check_plane_size(data.len(), stride, last_row_size, rows)
+        .map_err(YuvError::LumaPlaneSizeMismatch)?;
+    Ok(())
+}
+
+/// Checks interleaved chroma plane of NV formats, row holds `UV` pair per chroma sample
+#[inline]
+pub(crate) fn check_interleaved_chroma_channel<V>(
+    data: &[V],
+    stride: u32,
+    image_width: u32,
+    image_height: u32,
+    sampling: YuvChromaSample,
+) -> Result<(), YuvError> {
+    let chroma_min_width = match sampling {
+        YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => image_width.div_ceil(2) as usize,
+        YuvChromaSample::YUV444 => image_width as usize,
+    };
+    let chroma_height = match sampling {
+        YuvChromaSample::YUV420 => image_height.div_ceil(2),
+        YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => image_height,
+    };
+    let element_size = std::mem::size_of::<V>();
+    let row_size = image_bytes(
+        chroma_min_width,
+        2 * element_size,
+        image_width,
+        image_height,
+    )?;
+    check_addressable(
+        stride as usize,
+        row_size,
+        chroma_height as usize,
+        image_width,
+        image_height,
+    )?;
+    check_stride(stride as usize, row_size)?;
+    check_plane_size(
+        std::mem::size_of_val(data),
+        stride as usize,
+        row_size,
+        chroma_height as usize,
+    )
+    .map_err(YuvError::LumaPlaneSizeMismatch)?;
+    Ok(())
+}
@@ -0,0 +1,518 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_with_capacity, YuvPlanarImage};
+use crate::yuv_error::{check_rgba_destination, MismatchedSize};
+use crate::yuv_planar_generic::yuv_planar_to_rgb_generic;
+use crate::yuv_support::{
+    YuvBytesPacking, YuvChromaSample, YuvEndianness, YuvOvershoot, YuvRange, YuvSourceChannels,
+    YuvStandardMatrix,
+};
+use crate::yuv_to_rgba::yuv_to_rgbx_rows;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+/// Per channel 1D LUTs applied right after YUV to RGB conversion, as grading pipelines do.
+///
+/// Every table has one entry per code value of converted RGB, 256 for 8-bit and 1024
+/// for 10-bit output. Channels without table are left unchanged.
+#[derive(Debug, Copy, Clone)]
+pub struct YuvRgbLut<'a, T> {
+    pub r: Option<&'a [T]>,
+    pub g: Option<&'a [T]>,
+    pub b: Option<&'a [T]>,
+}
+
+impl<T> Default for YuvRgbLut<'_, T> {
+    fn default() -> Self {
+        YuvRgbLut {
+            r: None,
+            g: None,
+            b: None,
+        }
+    }
+}
+
+impl<T: Copy> YuvRgbLut<'_, T> {
+    /// Resolves tables into `entries` sized ones, missing channels get identity
+    fn resolve(
+        &self,
+        entries: usize,
+        identity: impl Fn(usize) -> T,
+    ) -> Result<[Vec<T>; 3], YuvError> {
+        let resolve_channel = |table: Option<&[T]>| -> Result<Vec<T>, YuvError> {
+            if let Some(table) = table {
+                if table.len() != entries {
+                    return Err(YuvError::LutSizeMismatch(MismatchedSize {
+                        expected: entries,
+                        received: table.len(),
+                    }));
+                }
+            }
+            let mut resolved = try_with_capacity(entries)?;
+            match table {
+                Some(table) => resolved.extend_from_slice(table),
+                None => resolved.extend((0..entries).map(&identity)),
+            }
+            Ok(resolved)
+        };
+        Ok([
+            resolve_channel(self.r)?,
+            resolve_channel(self.g)?,
+            resolve_channel(self.b)?,
+        ])
+    }
+}
+
+/// Rows converted by SIMD kernels before LUT is applied to them while they are still in cache
+const LUT_BAND_ROWS: usize = 16;
+
+/// Converts planar image in small bands of rows with regular SIMD kernels and hands
+/// visible part of every converted row to `post_row` right after its band is done
+pub(crate) fn yuv_to_rgbx_banded<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    post_row: impl Fn(&mut [u8]) + Send + Sync,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+    let width = image.size.width;
+    let height = image.size.height;
+
+    image.check_constraints(sampling)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    let matrix = matrix.resolve(width, height);
+
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => {
+            yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+        }
+        YuvChromaSample::YUV422 => {
+            yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+        }
+        YuvChromaSample::YUV444 => {
+            yuv_to_rgbx_rows::<DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+        }
+    };
+
+    let band_stride = rgba_stride as usize * LUT_BAND_ROWS;
+    let chroma_band_rows = match sampling {
+        YuvChromaSample::YUV420 => LUT_BAND_ROWS / 2,
+        YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => LUT_BAND_ROWS,
+    };
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba.par_chunks_mut(band_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba.chunks_mut(band_stride);
+    }
+
+    iter.take((height as usize).div_ceil(LUT_BAND_ROWS))
+        .enumerate()
+        .for_each(|(band, rgba_band)| {
+            let start_row = band * LUT_BAND_ROWS;
+            let rows = LUT_BAND_ROWS.min(height as usize - start_row);
+            let chroma_row = band * chroma_band_rows;
+            handler(
+                &image.y_plane[start_row * image.y_stride.get() as usize..],
+                image.y_stride.get(),
+                &image.u_plane[chroma_row * image.u_stride.get() as usize..],
+                image.u_stride.get(),
+                &image.v_plane[chroma_row * image.v_stride.get() as usize..],
+                image.v_stride.get(),
+                rgba_band,
+                rgba_stride,
+                width,
+                rows as u32,
+                range,
+                matrix,
+                255,
+            );
+
+            for rgba_row in rgba_band.chunks_mut(rgba_stride as usize).take(rows) {
+                post_row(&mut rgba_row[..width as usize * channels]);
+            }
+        });
+
+    Ok(())
+}
+
+fn yuv_to_rgbx_with_lut<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    lut: YuvRgbLut<u8>,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+    let [lut_r, lut_g, lut_b] = lut.resolve(256, |i| i as u8)?;
+
+    yuv_to_rgbx_banded::<DESTINATION_CHANNELS>(
+        image,
+        sampling,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+        |rgba_row| {
+            for dst in rgba_row.chunks_exact_mut(channels) {
+                let r_offset = dst_chans.get_r_channel_offset();
+                let g_offset = dst_chans.get_g_channel_offset();
+                let b_offset = dst_chans.get_b_channel_offset();
+                dst[r_offset] = lut_r[dst[r_offset] as usize];
+                dst[g_offset] = lut_g[dst[g_offset] as usize];
+                dst[b_offset] = lut_b[dst[b_offset] as usize];
+            }
+        },
+    )
+}
+
+/// Convert YUV planar format with 8-bit pixel format to RGB format applying per channel 1D LUTs.
+///
+/// Rows are converted in small bands by SIMD kernels and LUTs are applied to each band
+/// right after it, so no extra pass over the whole frame is needed.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `lut` - Per channel 256 entry tables.
+///
+pub fn yuv_to_rgb_with_lut(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgb: &mut [u8],
+    rgb_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    lut: YuvRgbLut<u8>,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_with_lut::<{ YuvSourceChannels::Rgb as u8 }>(
+        image, sampling, rgb, rgb_stride, range, matrix, lut,
+    )
+}
+
+/// Convert YUV planar format with 8-bit pixel format to RGBA format applying per channel 1D LUTs.
+///
+/// Rows are converted in small bands by SIMD kernels and LUTs are applied to each band
+/// right after it, so no extra pass over the whole frame is needed. Alpha is always opaque.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `lut` - Per channel 256 entry tables.
+///
+pub fn yuv_to_rgba_with_lut(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    lut: YuvRgbLut<u8>,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_with_lut::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        sampling,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+        lut,
+    )
+}
+
+/// Convert YUV planar format with 8-bit pixel format to BGRA format applying per channel 1D LUTs.
+///
+/// Rows are converted in small bands by SIMD kernels and LUTs are applied to each band
+/// right after it, so no extra pass over the whole frame is needed. Alpha is always opaque.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `lut` - Per channel 256 entry tables.
+///
+pub fn yuv_to_bgra_with_lut(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    lut: YuvRgbLut<u8>,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_with_lut::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        sampling,
+        bgra,
+        bgra_stride,
+        range,
+        matrix,
+        lut,
+    )
+}
+
+fn yuv_p10_to_rgbx16_with_lut_impl<
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+>(
+    image: &YuvPlanarImage<u16>,
+    rgba: &mut [u16],
+    rgba_stride: u32,
+    dst_chans: YuvSourceChannels,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    luts: &[Vec<u16>; 3],
+) {
+    let channels = dst_chans.get_channels_count();
+    let [lut_r, lut_g, lut_b] = luts;
+    yuv_planar_to_rgb_generic::<u16, u16, SAMPLING, ENDIANNESS, BYTES_POSITION>(
+        image.y_plane,
+        image.y_stride.get(),
+        image.u_plane,
+        image.u_stride.get(),
+        image.v_plane,
+        image.v_stride.get(),
+        rgba,
+        rgba_stride,
+        image.size.width,
+        image.size.height,
+        range,
+        matrix,
+        10,
+        10,
+        YuvOvershoot::Clamp,
+        |dst, x, r, g, b| {
+            let dst = &mut dst[x * channels..];
+            dst[dst_chans.get_r_channel_offset()] = lut_r[r as usize];
+            dst[dst_chans.get_g_channel_offset()] = lut_g[g as usize];
+            dst[dst_chans.get_b_channel_offset()] = lut_b[b as usize];
+            if dst_chans.has_alpha() {
+                dst[dst_chans.get_a_channel_offset()] = 1023;
+            }
+        },
+    );
+}
+
+type YuvP10ToRgbx16WithLut = fn(
+    &YuvPlanarImage<u16>,
+    &mut [u16],
+    u32,
+    YuvSourceChannels,
+    YuvRange,
+    YuvStandardMatrix,
+    &[Vec<u16>; 3],
+);
+
+fn yuv_p10_to_rgbx16_with_lut_dispatcher<const SAMPLING: u8>(
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> YuvP10ToRgbx16WithLut {
+    match (endianness, bytes_packing) {
+        (YuvEndianness::BigEndian, YuvBytesPacking::MostSignificantBytes) => {
+            yuv_p10_to_rgbx16_with_lut_impl::<
+                SAMPLING,
+                { YuvEndianness::BigEndian as u8 },
+                { YuvBytesPacking::MostSignificantBytes as u8 },
+            >
+        }
+        (YuvEndianness::BigEndian, YuvBytesPacking::LeastSignificantBytes) => {
+            yuv_p10_to_rgbx16_with_lut_impl::<
+                SAMPLING,
+                { YuvEndianness::BigEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+            >
+        }
+        (YuvEndianness::LittleEndian, YuvBytesPacking::MostSignificantBytes) => {
+            yuv_p10_to_rgbx16_with_lut_impl::<
+                SAMPLING,
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::MostSignificantBytes as u8 },
+            >
+        }
+        (YuvEndianness::LittleEndian, YuvBytesPacking::LeastSignificantBytes) => {
+            yuv_p10_to_rgbx16_with_lut_impl::<
+                SAMPLING,
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+            >
+        }
+    }
+}
+
+fn yuv_p10_to_rgbx16_with_lut(
+    image: &YuvPlanarImage<u16>,
+    sampling: YuvChromaSample,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+    rgba: &mut [u16],
+    rgba_stride: u32,
+    dst_chans: YuvSourceChannels,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    lut: YuvRgbLut<u16>,
+) -> Result<(), YuvError> {
+    image.check_constraints(sampling)?;
+    check_rgba_destination(
+        rgba,
+        rgba_stride,
+        image.size.width,
+        image.size.height,
+        dst_chans.get_channels_count(),
+    )?;
+    let luts = lut.resolve(1024, |i| i as u16)?;
+
+    let dispatcher = match sampling {
+        YuvChromaSample::YUV420 => yuv_p10_to_rgbx16_with_lut_dispatcher::<
+            { YuvChromaSample::YUV420 as u8 },
+        >(endianness, bytes_packing),
+        YuvChromaSample::YUV422 => yuv_p10_to_rgbx16_with_lut_dispatcher::<
+            { YuvChromaSample::YUV422 as u8 },
+        >(endianness, bytes_packing),
+        YuvChromaSample::YUV444 => yuv_p10_to_rgbx16_with_lut_dispatcher::<
+            { YuvChromaSample::YUV444 as u8 },
+        >(endianness, bytes_packing),
+    };
+    dispatcher(image, rgba, rgba_stride, dst_chans, range, matrix, &luts);
+    Ok(())
+}
+
+/// Convert YUV planar format with 10-bit pixel format to RGB format with 10-bit
+/// values mapped through per channel 1D LUTs.
+///
+/// Every converted value is looked up in the same pass, table entries are stored as is,
+/// so tables may also widen output to any bit depth up to 16.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `endianness` - The endianness of stored bytes.
+/// * `bytes_packing` - Position of significant bytes (most significant or least significant).
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `lut` - Per channel 1024 entry tables.
+///
+pub fn yuv_p10_to_rgb16_with_lut(
+    image: &YuvPlanarImage<u16>,
+    sampling: YuvChromaSample,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+    rgb: &mut [u16],
+    rgb_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    lut: YuvRgbLut<u16>,
+) -> Result<(), YuvError> {
+    yuv_p10_to_rgbx16_with_lut(
+        image,
+        sampling,
+        endianness,
+        bytes_packing,
+        rgb,
+        rgb_stride,
+        YuvSourceChannels::Rgb,
+        range,
+        matrix,
+        lut,
+    )
+}
+
+/// Convert YUV planar format with 10-bit pixel format to RGBA format with 10-bit
+/// values mapped through per channel 1D LUTs.
+///
+/// Every converted value is looked up in the same pass, table entries are stored as is,
+/// so tables may also widen output to any bit depth up to 16. Alpha is always 1023.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `endianness` - The endianness of stored bytes.
+/// * `bytes_packing` - Position of significant bytes (most significant or least significant).
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `lut` - Per channel 1024 entry tables.
+///
+pub fn yuv_p10_to_rgba16_with_lut(
+    image: &YuvPlanarImage<u16>,
+    sampling: YuvChromaSample,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+    rgba: &mut [u16],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    lut: YuvRgbLut<u16>,
+) -> Result<(), YuvError> {
+    yuv_p10_to_rgbx16_with_lut(
+        image,
+        sampling,
+        endianness,
+        bytes_packing,
+        rgba,
+        rgba_stride,
+        YuvSourceChannels::Rgba,
+        range,
+        matrix,
+        lut,
+    )
+}
@@ -26,9 +26,18 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_yuv_nv12_p10_to_rgba_row;
+use crate::yuv_error::{
+    check_interleaved_chroma_channel, check_rgba_destination, check_y8_channel,
+};
+use crate::yuv_support::p16_dispatch;
 use crate::yuv_support::*;
+use crate::YuvError;
 #[cfg(feature = "rayon")]
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 #[cfg(feature = "rayon")]
@@ -48,18 +57,22 @@ fn yuv_nv_p10_to_image_impl<
     bgra: &mut [u8],
     bgra_stride: u32,
     width: u32,
-    _: u32,
+    height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
     let channels = dst_chans.get_channels_count();
     let uv_order: YuvNVOrder = NV_ORDER.into();
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_interleaved_chroma_channel(uv_plane, uv_stride, width, height, chroma_subsampling)?;
+    check_rgba_destination(bgra, bgra_stride, width, height, channels)?;
+
     let endianness: YuvEndianness = ENDIANNESS.into();
     let bytes_position: YuvBytesPacking = BYTES_POSITION.into();
     let range = get_yuv_range(10, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let max_range_p10 = (1u32 << 10u32) - 1u32;
     let transform = get_inverse_transform(
         max_range_p10,
@@ -88,14 +101,14 @@ fn yuv_nv_p10_to_image_impl<
     let iter;
     #[cfg(feature = "rayon")]
     {
-        iter = bgra.par_chunks_exact_mut(bgra_stride as usize);
+        iter = bgra.par_chunks_mut(bgra_stride as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = bgra.chunks_exact_mut(bgra_stride as usize);
+        iter = bgra.chunks_mut(bgra_stride as usize);
     }
 
-    iter.enumerate().for_each(|(y, bgra)| unsafe {
+    iter.enumerate().for_each(|(y, bgra)| {
         let y_offset = y * (y_stride as usize);
         let uv_offset = if chroma_subsampling == YuvChromaSample::YUV420 {
             (y >> 1) * (uv_stride as usize)
@@ -104,18 +117,19 @@ fn yuv_nv_p10_to_image_impl<
         };
         let dst_offset = 0usize;
 
-        let y_src_ptr = y_plane.as_ptr() as *const u8;
-        let uv_src_ptr = uv_plane.as_ptr() as *const u8;
-
         let mut _cx = 0usize;
 
         let mut _ux = 0usize;
 
-        let y_ld_ptr = y_src_ptr.add(y_offset) as *const u16;
-        let uv_ld_ptr = uv_src_ptr.add(uv_offset) as *const u16;
+        let y_src = &y_plane[y_offset / 2..];
+        let uv_src = &uv_plane[uv_offset / 2..];
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
-        {
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
             let offset = neon_yuv_nv12_p10_to_rgba_row::<
                 DESTINATION_CHANNELS,
                 NV_ORDER,
@@ -123,8 +137,8 @@ fn yuv_nv_p10_to_image_impl<
                 ENDIANNESS,
                 BYTES_POSITION,
             >(
-                y_ld_ptr,
-                uv_ld_ptr,
+                y_src.as_ptr(),
+                uv_src.as_ptr(),
                 bgra,
                 dst_offset,
                 width,
@@ -143,17 +157,9 @@ fn yuv_nv_p10_to_image_impl<
             let mut cr_value: i32;
             match endianness {
                 YuvEndianness::BigEndian => {
-                    let mut y_vl = u16::from_be(y_ld_ptr.add(x).read_unaligned()) as i32;
-                    let mut cb_vl = u16::from_be(
-                        uv_ld_ptr
-                            .add(_ux + uv_order.get_u_position())
-                            .read_unaligned(),
-                    ) as i32;
-                    let mut cr_vl = u16::from_be(
-                        uv_ld_ptr
-                            .add(_ux + uv_order.get_v_position())
-                            .read_unaligned(),
-                    ) as i32;
+                    let mut y_vl = u16::from_be(y_src[x]) as i32;
+                    let mut cb_vl = u16::from_be(uv_src[_ux + uv_order.get_u_position()]) as i32;
+                    let mut cr_vl = u16::from_be(uv_src[_ux + uv_order.get_v_position()]) as i32;
                     if bytes_position == YuvBytesPacking::MostSignificantBytes {
                         y_vl >>= 6;
                         cb_vl >>= 6;
@@ -165,17 +171,9 @@ fn yuv_nv_p10_to_image_impl<
                     cr_value = cr_vl;
                 }
                 YuvEndianness::LittleEndian => {
-                    let mut y_vl = u16::from_le(y_ld_ptr.add(x).read_unaligned()) as i32;
-                    let mut cb_vl = u16::from_le(
-                        uv_ld_ptr
-                            .add(_ux + uv_order.get_u_position())
-                            .read_unaligned(),
-                    ) as i32;
-                    let mut cr_vl = u16::from_le(
-                        uv_ld_ptr
-                            .add(_ux + uv_order.get_v_position())
-                            .read_unaligned(),
-                    ) as i32;
+                    let mut y_vl = u16::from_le(y_src[x]) as i32;
+                    let mut cb_vl = u16::from_le(uv_src[_ux + uv_order.get_u_position()]) as i32;
+                    let mut cr_vl = u16::from_le(uv_src[_ux + uv_order.get_v_position()]) as i32;
                     if bytes_position == YuvBytesPacking::MostSignificantBytes {
                         y_vl >>= 6;
                         cb_vl >>= 6;
@@ -188,16 +186,8 @@ fn yuv_nv_p10_to_image_impl<
                 }
             }
 
-            match uv_order {
-                YuvNVOrder::UV => {
-                    cb_value -= bias_uv;
-                    cr_value -= bias_uv;
-                }
-                YuvNVOrder::VU => {
-                    cr_value = cb_value - bias_uv;
-                    cb_value = cr_value - bias_uv;
-                }
-            }
+            cb_value -= bias_uv;
+            cr_value -= bias_uv;
 
             // shift right 8 due to we want to make it 8 bit instead of 10
 
@@ -213,12 +203,12 @@ fn yuv_nv_p10_to_image_impl<
 
             let rgb_offset = dst_offset + px;
 
-            let dst_slice = bgra.get_unchecked_mut(rgb_offset..);
-            *dst_slice.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b as u8;
-            *dst_slice.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g as u8;
-            *dst_slice.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r as u8;
+            let dst_slice = &mut bgra[rgb_offset..];
+            dst_slice[dst_chans.get_b_channel_offset()] = b as u8;
+            dst_slice[dst_chans.get_g_channel_offset()] = g as u8;
+            dst_slice[dst_chans.get_r_channel_offset()] = r as u8;
             if dst_chans.has_alpha() {
-                *bgra.get_unchecked_mut(dst_chans.get_a_channel_offset()) = 255;
+                dst_slice[dst_chans.get_a_channel_offset()] = 255;
             }
 
             if chroma_subsampling == YuvChromaSample::YUV422
@@ -228,16 +218,14 @@ fn yuv_nv_p10_to_image_impl<
                 if next_px < width as usize {
                     let y_value: i32 = match endianness {
                         YuvEndianness::BigEndian => {
-                            let mut y_vl =
-                                u16::from_be(y_ld_ptr.add(next_px).read_unaligned()) as i32;
+                            let mut y_vl = u16::from_be(y_src[next_px]) as i32;
                             if bytes_position == YuvBytesPacking::MostSignificantBytes {
                                 y_vl >>= 6;
                             }
                             (y_vl - bias_y) * y_coef
                         }
                         YuvEndianness::LittleEndian => {
-                            let mut y_vl =
-                                u16::from_le(y_ld_ptr.add(next_px).read_unaligned()) as i32;
+                            let mut y_vl = u16::from_le(y_src[next_px]) as i32;
                             if bytes_position == YuvBytesPacking::MostSignificantBytes {
                                 y_vl >>= 6;
                             }
@@ -256,12 +244,12 @@ fn yuv_nv_p10_to_image_impl<
 
                     let px = next_px * channels;
                     let rgb_offset = dst_offset + px;
-                    let dst_slice = bgra.get_unchecked_mut(rgb_offset..);
-                    *dst_slice.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b as u8;
-                    *dst_slice.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g as u8;
-                    *dst_slice.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r as u8;
+                    let dst_slice = &mut bgra[rgb_offset..];
+                    dst_slice[dst_chans.get_b_channel_offset()] = b as u8;
+                    dst_slice[dst_chans.get_g_channel_offset()] = g as u8;
+                    dst_slice[dst_chans.get_r_channel_offset()] = r as u8;
                     if dst_chans.has_alpha() {
-                        *dst_slice.get_unchecked_mut(dst_chans.get_a_channel_offset()) = 255;
+                        dst_slice[dst_chans.get_a_channel_offset()] = 255;
                     }
                 }
             }
@@ -269,6 +257,8 @@ fn yuv_nv_p10_to_image_impl<
             _ux += 2;
         }
     });
+
+    Ok(())
 }
 
 /// Convert YUV NV12 format with 10-bit pixel format to BGRA format.
@@ -307,49 +297,17 @@ pub fn yuv_nv12_p10_to_bgra(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -361,7 +319,7 @@ pub fn yuv_nv12_p10_to_bgra(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV12 format with 10-bit pixel format to RGBA format.
@@ -400,49 +358,17 @@ pub fn yuv_nv12_p10_to_rgba(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -454,7 +380,7 @@ pub fn yuv_nv12_p10_to_rgba(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV12 format with 10-bit pixel format to BGR format.
@@ -493,52 +419,20 @@ pub fn yuv_nv12_p10_to_bgr(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert YUV NV12 format with 10-bit pixel format to RGB format.
@@ -577,52 +471,20 @@ pub fn yuv_nv12_p10_to_rgb(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert YUV NV16 format with 10-bit pixel format to BGRA format.
@@ -661,49 +523,17 @@ pub fn yuv_nv16_p10_to_bgra(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -715,7 +545,7 @@ pub fn yuv_nv16_p10_to_bgra(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV61 format with 10-bit pixel format to BGRA format.
@@ -754,49 +584,17 @@ pub fn yuv_nv61_p10_to_bgra(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -808,7 +606,7 @@ pub fn yuv_nv61_p10_to_bgra(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV16 format with 10-bit pixel format to BGR format.
@@ -847,49 +645,17 @@ pub fn yuv_nv16_p10_to_bgr(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -901,7 +667,7 @@ pub fn yuv_nv16_p10_to_bgr(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV61 format with 10-bit pixel format to BGR format.
@@ -940,49 +706,17 @@ pub fn yuv_nv61_p10_to_bgr(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -994,7 +728,7 @@ pub fn yuv_nv61_p10_to_bgr(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV16 format with 10-bit pixel format to RGB format.
@@ -1033,49 +767,17 @@ pub fn yuv_nv16_p10_to_rgb(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1087,7 +789,7 @@ pub fn yuv_nv16_p10_to_rgb(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV61 format with 10-bit pixel format to RGB format.
@@ -1126,49 +828,17 @@ pub fn yuv_nv61_p10_to_rgb(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1180,7 +850,7 @@ pub fn yuv_nv61_p10_to_rgb(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV16 format with 10-bit pixel format to RGB format.
@@ -1219,49 +889,17 @@ pub fn yuv_nv16_p10_to_rgba(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1273,7 +911,7 @@ pub fn yuv_nv16_p10_to_rgba(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV61 format with 10-bit pixel format to RGB format.
@@ -1312,49 +950,17 @@ pub fn yuv_nv61_p10_to_rgba(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1366,7 +972,7 @@ pub fn yuv_nv61_p10_to_rgba(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV21 format with 10-bit pixel format to BGR format.
@@ -1405,52 +1011,20 @@ pub fn yuv_nv21_p10_to_bgr(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert YUV NV21 format with 10-bit pixel format to BGRA format.
@@ -1489,49 +1063,17 @@ pub fn yuv_nv21_p10_to_bgra(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1543,7 +1085,7 @@ pub fn yuv_nv21_p10_to_bgra(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV21 format with 10-bit pixel format to RGB format.
@@ -1582,52 +1124,20 @@ pub fn yuv_nv21_p10_to_rgb(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, width, height, range, matrix,
-    );
+    )
 }
 
 /// Convert YUV NV21 format with 10-bit pixel format to RGBA format.
@@ -1666,49 +1176,17 @@ pub fn yuv_nv21_p10_to_rgba(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p10_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1720,5 +1198,493 @@ pub fn yuv_nv21_p10_to_rgba(
         height,
         range,
         matrix,
+    )
+}
+
+/// Convert YUV NV24 format with 10-bit pixel format to BGRA format.
+///
+/// This function takes YUV NV24 data with 10-bit precision.
+/// and converts it to BGRA format with 8-bit precision.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input BGRA data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv24_p10_to_bgra(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV42 format with 10-bit pixel format to BGRA format.
+///
+/// This function takes YUV NV42 data with 10-bit precision.
+/// and converts it to BGRA format with 8-bit precision.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input BGRA data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv42_p10_to_bgra(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV24 format with 10-bit pixel format to BGR format.
+///
+/// This function takes YUV NV24 data with 10-bit precision.
+/// and converts it to BGR format with 8-bit precision.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGR data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGR image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input BGR data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv24_p10_to_bgr(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
     );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV42 format with 10-bit pixel format to BGR format.
+///
+/// This function takes YUV NV42 data with 10-bit precision.
+/// and converts it to BGR format with 8-bit precision.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGR data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGR image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input BGR data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv42_p10_to_bgr(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV24 format with 10-bit pixel format to RGB format.
+///
+/// This function takes YUV NV24 data with 10-bit precision.
+/// and converts it to RGB format with 8-bit precision.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted RGB data.
+/// * `bgra_stride` - The stride (bytes per row) for the RGB image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input RGB data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv24_p10_to_rgb(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV42 format with 10-bit pixel format to RGB format.
+///
+/// This function takes YUV NV42 data with 10-bit precision.
+/// and converts it to RGB format with 8-bit precision.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted RGB data.
+/// * `bgra_stride` - The stride (bytes per row) for the RGB image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input RGB data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv42_p10_to_rgb(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV24 format with 10-bit pixel format to RGB format.
+///
+/// This function takes YUV NV24 data with 10-bit precision.
+/// and converts it to RGBA format with 8-bit precision.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted RGBA data.
+/// * `bgra_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input RGBA data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv24_p10_to_rgba(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV42 format with 10-bit pixel format to RGB format.
+///
+/// This function takes YUV NV42 data with 10-bit precision.
+/// and converts it to RGBA format with 8-bit precision.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted RGBA data.
+/// * `bgra_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input RGBA data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv42_p10_to_rgba(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p10_to_image_impl,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
 }
@@ -26,16 +26,29 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+use crate::images::YuvBiPlanarImage;
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_yuv_nv_p16_to_rgba_row;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::sse_yuv_nv_p16_to_rgba_row;
+use crate::yuv_error::{
+    check_interleaved_chroma_channel, check_p16_bit_depth, check_rgba_destination, check_y8_channel,
+};
+use crate::yuv_planar_generic::yuv_biplanar_to_rgb_generic;
+use crate::yuv_support::p16_dispatch;
 use crate::yuv_support::*;
+use crate::YuvError;
 #[cfg(feature = "rayon")]
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 #[cfg(feature = "rayon")]
 use rayon::prelude::ParallelSliceMut;
-use std::slice;
 
 fn yuv_nv_p16_to_image_impl<
     const DESTINATION_CHANNELS: u8,
@@ -55,15 +68,19 @@ fn yuv_nv_p16_to_image_impl<
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
     let channels = dst_chans.get_channels_count();
     let uv_order: YuvNVOrder = NV_ORDER.into();
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_interleaved_chroma_channel(uv_plane, uv_stride, width, height, chroma_subsampling)?;
+    check_rgba_destination(bgra, bgra_stride, width, height, channels)?;
+
     let endianness: YuvEndianness = ENDIANNESS.into();
     let bytes_position: YuvBytesPacking = BYTES_POSITION.into();
     let range = get_yuv_range(BIT_DEPTH as u32, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let max_range = ((1u32 << (BIT_DEPTH as u32)) - 1u32) as i32;
     let transform = get_inverse_transform(
         max_range as u32,
@@ -84,15 +101,11 @@ fn yuv_nv_p16_to_image_impl<
     let bias_y = range.bias_y as i32;
     let bias_uv = range.bias_uv as i32;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
-
-    let casted_slice = unsafe {
-        slice::from_raw_parts_mut(
-            bgra.as_mut_ptr() as *mut u8,
-            height as usize * bgra_stride as usize,
-        )
-    };
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_sse = crate::cpu_features::has_sse41();
 
     let iterator_step = match chroma_subsampling {
         YuvChromaSample::YUV420 => 2usize,
@@ -103,14 +116,18 @@ fn yuv_nv_p16_to_image_impl<
     let iter;
     #[cfg(feature = "rayon")]
     {
-        iter = casted_slice.par_chunks_exact_mut(bgra_stride as usize);
+        iter = bgra
+            .par_chunks_mut(bgra_stride as usize / 2)
+            .take(height as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = casted_slice.chunks_exact_mut(bgra_stride as usize);
+        iter = bgra
+            .chunks_mut(bgra_stride as usize / 2)
+            .take(height as usize);
     }
 
-    iter.enumerate().for_each(|(y, bgra)| unsafe {
+    iter.enumerate().for_each(|(y, bgra)| {
         let y_offset = y * (y_stride as usize);
         let uv_offset = if chroma_subsampling == YuvChromaSample::YUV420 {
             (y >> 1) * (uv_stride as usize)
@@ -123,15 +140,14 @@ fn yuv_nv_p16_to_image_impl<
 
         let msb_shift = 16 - BIT_DEPTH;
 
-        let y_src_ptr = y_plane.as_ptr() as *const u8;
-        let uv_src_ptr = uv_plane.as_ptr() as *const u8;
+        let y_src = &y_plane[y_offset / 2..];
+        let uv_src = &uv_plane[uv_offset / 2..];
 
-        let y_ld_ptr = y_src_ptr.add(y_offset) as *const u16;
-        let uv_ld_ptr = uv_src_ptr.add(uv_offset) as *const u16;
-        let dst_st_ptr = bgra.as_mut_ptr() as *mut u16;
-
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
             if _use_sse {
                 let processed = sse_yuv_nv_p16_to_rgba_row::<
                     DESTINATION_CHANNELS,
@@ -141,9 +157,9 @@ fn yuv_nv_p16_to_image_impl<
                     BYTES_POSITION,
                     BIT_DEPTH,
                 >(
-                    y_ld_ptr,
-                    uv_ld_ptr,
-                    dst_st_ptr,
+                    y_src.as_ptr(),
+                    uv_src.as_ptr(),
+                    bgra.as_mut_ptr(),
                     width,
                     &range,
                     &i_transform,
@@ -155,8 +171,12 @@ fn yuv_nv_p16_to_image_impl<
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
-        {
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
             let processed = neon_yuv_nv_p16_to_rgba_row::<
                 DESTINATION_CHANNELS,
                 NV_ORDER,
@@ -165,9 +185,9 @@ fn yuv_nv_p16_to_image_impl<
                 BYTES_POSITION,
                 BIT_DEPTH,
             >(
-                y_ld_ptr,
-                uv_ld_ptr,
-                dst_st_ptr,
+                y_src.as_ptr(),
+                uv_src.as_ptr(),
+                bgra.as_mut_ptr(),
                 width,
                 &range,
                 &i_transform,
@@ -184,17 +204,9 @@ fn yuv_nv_p16_to_image_impl<
             let mut cr_value: i32;
             match endianness {
                 YuvEndianness::BigEndian => {
-                    let mut y_vl = u16::from_be(y_ld_ptr.add(x).read_unaligned()) as i32;
-                    let mut cb_vl = u16::from_be(
-                        uv_ld_ptr
-                            .add(_ux + uv_order.get_u_position())
-                            .read_unaligned(),
-                    ) as i32;
-                    let mut cr_vl = u16::from_be(
-                        uv_ld_ptr
-                            .add(_ux + uv_order.get_v_position())
-                            .read_unaligned(),
-                    ) as i32;
+                    let mut y_vl = u16::from_be(y_src[x]) as i32;
+                    let mut cb_vl = u16::from_be(uv_src[_ux + uv_order.get_u_position()]) as i32;
+                    let mut cr_vl = u16::from_be(uv_src[_ux + uv_order.get_v_position()]) as i32;
                     if bytes_position == YuvBytesPacking::MostSignificantBytes {
                         y_vl >>= msb_shift;
                         cb_vl >>= msb_shift;
@@ -206,17 +218,9 @@ fn yuv_nv_p16_to_image_impl<
                     cr_value = cr_vl;
                 }
                 YuvEndianness::LittleEndian => {
-                    let mut y_vl = u16::from_le(y_ld_ptr.add(x).read_unaligned()) as i32;
-                    let mut cb_vl = u16::from_le(
-                        uv_ld_ptr
-                            .add(_ux + uv_order.get_u_position())
-                            .read_unaligned(),
-                    ) as i32;
-                    let mut cr_vl = u16::from_le(
-                        uv_ld_ptr
-                            .add(_ux + uv_order.get_v_position())
-                            .read_unaligned(),
-                    ) as i32;
+                    let mut y_vl = u16::from_le(y_src[x]) as i32;
+                    let mut cb_vl = u16::from_le(uv_src[_ux + uv_order.get_u_position()]) as i32;
+                    let mut cr_vl = u16::from_le(uv_src[_ux + uv_order.get_v_position()]) as i32;
                     if bytes_position == YuvBytesPacking::MostSignificantBytes {
                         y_vl >>= msb_shift;
                         cb_vl >>= msb_shift;
@@ -229,16 +233,8 @@ fn yuv_nv_p16_to_image_impl<
                 }
             }
 
-            match uv_order {
-                YuvNVOrder::UV => {
-                    cb_value -= bias_uv;
-                    cr_value -= bias_uv;
-                }
-                YuvNVOrder::VU => {
-                    cr_value = cb_value - bias_uv;
-                    cb_value = cr_value - bias_uv;
-                }
-            }
+            cb_value -= bias_uv;
+            cr_value -= bias_uv;
 
             // shift right 8 due to we want to make it 8 bit instead of 10
             let r_p16 = (y_value + cr_coef * cr_value + ROUNDING_CONST) >> PRECISION;
@@ -252,20 +248,12 @@ fn yuv_nv_p16_to_image_impl<
 
             let px = x * channels;
 
-            let dst_store = dst_st_ptr.add(px);
-            dst_store
-                .add(dst_chans.get_b_channel_offset())
-                .write_unaligned(b as u16);
-            dst_store
-                .add(dst_chans.get_g_channel_offset())
-                .write_unaligned(g as u16);
-            dst_store
-                .add(dst_chans.get_r_channel_offset())
-                .write_unaligned(r as u16);
+            let dst_store = &mut bgra[px..];
+            dst_store[dst_chans.get_b_channel_offset()] = b as u16;
+            dst_store[dst_chans.get_g_channel_offset()] = g as u16;
+            dst_store[dst_chans.get_r_channel_offset()] = r as u16;
             if dst_chans.has_alpha() {
-                dst_store
-                    .add(dst_chans.get_a_channel_offset())
-                    .write_unaligned(max_range as u16);
+                dst_store[dst_chans.get_a_channel_offset()] = max_range as u16;
             }
 
             if chroma_subsampling == YuvChromaSample::YUV422
@@ -275,16 +263,14 @@ fn yuv_nv_p16_to_image_impl<
                 if next_px < width as usize {
                     let y_value: i32 = match endianness {
                         YuvEndianness::BigEndian => {
-                            let mut y_vl =
-                                u16::from_be(y_ld_ptr.add(next_px).read_unaligned()) as i32;
+                            let mut y_vl = u16::from_be(y_src[next_px]) as i32;
                             if bytes_position == YuvBytesPacking::MostSignificantBytes {
                                 y_vl >>= msb_shift;
                             }
                             (y_vl - bias_y) * y_coef
                         }
                         YuvEndianness::LittleEndian => {
-                            let mut y_vl =
-                                u16::from_le(y_ld_ptr.add(next_px).read_unaligned()) as i32;
+                            let mut y_vl = u16::from_le(y_src[next_px]) as i32;
                             if bytes_position == YuvBytesPacking::MostSignificantBytes {
                                 y_vl >>= msb_shift;
                             }
@@ -303,20 +289,12 @@ fn yuv_nv_p16_to_image_impl<
                     let g = g_p16.clamp(0, max_range);
 
                     let px = next_px * channels;
-                    let dst_store = dst_st_ptr.add(px);
-                    dst_store
-                        .add(dst_chans.get_b_channel_offset())
-                        .write_unaligned(b as u16);
-                    dst_store
-                        .add(dst_chans.get_g_channel_offset())
-                        .write_unaligned(g as u16);
-                    dst_store
-                        .add(dst_chans.get_r_channel_offset())
-                        .write_unaligned(r as u16);
+                    let dst_store = &mut bgra[px..];
+                    dst_store[dst_chans.get_b_channel_offset()] = b as u16;
+                    dst_store[dst_chans.get_g_channel_offset()] = g as u16;
+                    dst_store[dst_chans.get_r_channel_offset()] = r as u16;
                     if dst_chans.has_alpha() {
-                        dst_store
-                            .add(dst_chans.get_a_channel_offset())
-                            .write_unaligned(max_range as u16);
+                        dst_store[dst_chans.get_a_channel_offset()] = max_range as u16;
                     }
                 }
             }
@@ -324,6 +302,8 @@ fn yuv_nv_p16_to_image_impl<
             _ux += 2;
         }
     });
+
+    Ok(())
 }
 
 fn yuv_nv_p16_to_image<
@@ -344,7 +324,7 @@ fn yuv_nv_p16_to_image<
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     if bit_depth == 10 {
         yuv_nv_p16_to_image_impl::<
             DESTINATION_CHANNELS,
@@ -364,7 +344,7 @@ fn yuv_nv_p16_to_image<
             height,
             range,
             matrix,
-        );
+        )
     } else if bit_depth == 12 {
         yuv_nv_p16_to_image_impl::<
             DESTINATION_CHANNELS,
@@ -384,7 +364,7 @@ fn yuv_nv_p16_to_image<
             height,
             range,
             matrix,
-        );
+        )
     } else {
         panic!("Bit depth {} is not implemented", bit_depth);
     }
@@ -428,49 +408,17 @@ pub fn yuv_nv12_to_bgra_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -483,7 +431,7 @@ pub fn yuv_nv12_to_bgra_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV12 format with 10/12-bit pixel format to RGBA format.
@@ -524,49 +472,17 @@ pub fn yuv_nv12_to_rgba_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -579,7 +495,7 @@ pub fn yuv_nv12_to_rgba_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV12 format with 10/12-bit pixel format to BGR format.
@@ -620,53 +536,21 @@ pub fn yuv_nv12_to_bgr_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV12 format with 10/12-bit pixel format to RGB format.
@@ -707,53 +591,21 @@ pub fn yuv_nv12_to_rgb_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV16 format with 10/12-bit pixel format to BGRA format.
@@ -794,49 +646,17 @@ pub fn yuv_nv16_to_bgra_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -849,7 +669,7 @@ pub fn yuv_nv16_to_bgra_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV61 format with 10/12-bit pixel format to BGRA format.
@@ -890,49 +710,17 @@ pub fn yuv_nv61_to_bgra_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -945,7 +733,7 @@ pub fn yuv_nv61_to_bgra_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV16 format with 10/12-bit pixel format to BGR format.
@@ -986,49 +774,17 @@ pub fn yuv_nv16_to_bgr_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1041,7 +797,7 @@ pub fn yuv_nv16_to_bgr_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV61 format with 10/12-bit pixel format to BGR format.
@@ -1082,49 +838,17 @@ pub fn yuv_nv61_to_bgr_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1137,7 +861,7 @@ pub fn yuv_nv61_to_bgr_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV16 format with 10/12-bit pixel format to RGB format.
@@ -1178,49 +902,17 @@ pub fn yuv_nv16_to_rgb_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1233,7 +925,7 @@ pub fn yuv_nv16_to_rgb_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV61 format with 10/12-bit pixel format to RGB format.
@@ -1274,49 +966,17 @@ pub fn yuv_nv61_to_rgb_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1329,7 +989,7 @@ pub fn yuv_nv61_to_rgb_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV16 format with 10/12-bit pixel format to RGB format.
@@ -1370,49 +1030,17 @@ pub fn yuv_nv16_to_rgba_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::UV as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1425,7 +1053,7 @@ pub fn yuv_nv16_to_rgba_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV61 format with 10/12-bit pixel format to RGB format.
@@ -1466,49 +1094,17 @@ pub fn yuv_nv61_to_rgba_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV422 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1521,7 +1117,7 @@ pub fn yuv_nv61_to_rgba_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV21 format with 10/12-bit pixel format to BGR format.
@@ -1562,53 +1158,21 @@ pub fn yuv_nv21_to_bgr_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, bgr, bgr_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV21 format with 10/12-bit pixel format to BGRA format.
@@ -1649,49 +1213,17 @@ pub fn yuv_nv21_to_bgra_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1704,7 +1236,7 @@ pub fn yuv_nv21_to_bgra_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV21 format with 10/12-bit pixel format to RGB format.
@@ -1745,53 +1277,21 @@ pub fn yuv_nv21_to_rgb_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, uv_plane, uv_stride, rgb, rgb_stride, bit_depth, width, height, range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUV NV21 format with 10/12-bit pixel format to RGBA format.
@@ -1832,49 +1332,17 @@ pub fn yuv_nv21_to_rgba_p16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_nv_p16_to_image::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvNVOrder::VU as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV420 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1887,5 +1355,685 @@ pub fn yuv_nv21_to_rgba_p16(
         height,
         range,
         matrix,
+    )
+}
+
+/// Convert YUV NV24 format with 10/12-bit pixel format to BGRA format.
+///
+/// This function takes YUV NV24 data with 10/12-bit precision.
+/// and converts it to BGRA format.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `bit_depth` - Only 10 or 12 bit-depth is supported
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input BGRA data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv24_to_bgra_p16(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u16],
+    bgra_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV42 format with 10/12-bit pixel format to BGRA format.
+///
+/// This function takes YUV NV42 data with 10/12-bit precision.
+/// and converts it to BGRA format.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `bit_depth` - Only 10 or 12 bit-depth is supported
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input BGRA data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv42_to_bgra_p16(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u16],
+    bgra_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Bgra as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
     );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV24 format with 10/12-bit pixel format to BGR format.
+///
+/// This function takes YUV NV24 data with 10/12-bit precision.
+/// and converts it to BGR format.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGR data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGR image data.
+/// * `bit_depth` - Only 10 or 12 bit-depth is supported
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input BGR data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv24_to_bgr_p16(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u16],
+    bgra_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV42 format with 10/12-bit pixel format to BGR format.
+///
+/// This function takes YUV NV42 data with 10/12-bit precision.
+/// and converts it to BGR format.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGR data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGR image data.
+/// * `bit_depth` - Only 10 or 12 bit-depth is supported
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input BGR data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv42_to_bgr_p16(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u16],
+    bgra_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Bgr as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV24 format with 10/12-bit pixel format to RGB format.
+///
+/// This function takes YUV NV24 data with 10/12-bit precision.
+/// and converts it to RGB format.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted RGB data.
+/// * `bgra_stride` - The stride (bytes per row) for the RGB image data.
+/// * `bit_depth` - Only 10 or 12 bit-depth is supported
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input RGB data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv24_to_rgb_p16(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u16],
+    bgra_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV42 format with 10/12-bit pixel format to RGB format.
+///
+/// This function takes YUV NV42 data with 10/12-bit precision.
+/// and converts it to RGB format.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted RGB data.
+/// * `bgra_stride` - The stride (bytes per row) for the RGB image data.
+/// * `bit_depth` - Only 10 or 12 bit-depth is supported
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input RGB data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv42_to_rgb_p16(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u16],
+    bgra_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Rgb as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV24 format with 10/12-bit pixel format to RGB format.
+///
+/// This function takes YUV NV24 data with 10/12-bit precision.
+/// and converts it to RGBA format.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted RGBA data.
+/// * `bgra_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `bit_depth` - Only 10 or 12 bit-depth is supported
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input RGBA data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv24_to_rgba_p16(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u16],
+    bgra_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::UV as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV42 format with 10/12-bit pixel format to RGB format.
+///
+/// This function takes YUV NV42 data with 10/12-bit precision.
+/// and converts it to RGBA format.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 10 bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) with 10 bit depth.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted RGBA data.
+/// * `bgra_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `bit_depth` - Only 10 or 12 bit-depth is supported
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
+///
+/// # Panics
+///
+/// This function panics if the lengths of the planes or the input RGBA data are not valid based
+/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
+///
+pub fn yuv_nv42_to_rgba_p16(
+    y_plane: &[u16],
+    y_stride: u32,
+    uv_plane: &[u16],
+    uv_stride: u32,
+    bgra: &mut [u16],
+    bgra_stride: u32,
+    bit_depth: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_nv_p16_to_image,
+        [
+            { YuvSourceChannels::Rgba as u8 },
+            { YuvNVOrder::VU as u8 },
+            { YuvChromaSample::YUV444 as u8 },
+        ],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        bit_depth,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// NV12 with 8..16 bit samples to RGBA keeping source bit depth in destination
+fn yuv_nv12_p16_to_rgba16_impl<const ENDIANNESS: u8, const BYTES_POSITION: u8>(
+    bi_planar_image: &YuvBiPlanarImage<u16>,
+    rgba16: &mut [u16],
+    rgba16_stride: u32,
+    bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    check_p16_bit_depth(bit_depth)?;
+    bi_planar_image.check_constraints(YuvChromaSample::YUV420)?;
+    let width = bi_planar_image.size.width;
+    let height = bi_planar_image.size.height;
+    check_rgba_destination(rgba16, rgba16_stride, width, height, 4)?;
+
+    let max_colors = ((1u32 << bit_depth) - 1) as u16;
+
+    yuv_biplanar_to_rgb_generic::<
+        u16,
+        u16,
+        { YuvChromaSample::YUV420 as u8 },
+        ENDIANNESS,
+        BYTES_POSITION,
+    >(
+        bi_planar_image.y_plane,
+        bi_planar_image.y_stride.get(),
+        bi_planar_image.uv_plane,
+        bi_planar_image.uv_stride.get(),
+        YuvNVOrder::UV,
+        rgba16,
+        rgba16_stride,
+        width,
+        height,
+        range,
+        matrix,
+        bit_depth as usize,
+        bit_depth as usize,
+        |dst, x, r, g, b| {
+            let dst = &mut dst[x * 4..x * 4 + 4];
+            dst[0] = r as u16;
+            dst[1] = g as u16;
+            dst[2] = b as u16;
+            dst[3] = max_colors;
+        },
+    );
+    Ok(())
+}
+
+/// Convert YUV NV12 format with 8..16 bit pixel format to RGBA with the same bit depth.
+///
+/// Unlike [yuv_nv12_to_rgba_p16] any bit depth from 8 to 16 is accepted and the output keeps full
+/// precision of the source.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source NV12 image with high bit depth samples.
+/// * `rgba16` - A mutable slice to store the converted RGBA data, values are in `0..(1 << bit_depth)`.
+/// * `rgba16_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `bit_depth` - Bit depth of the source and of the destination.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn yuv_nv12_p16_to_rgba16(
+    bi_planar_image: &YuvBiPlanarImage<u16>,
+    rgba16: &mut [u16],
+    rgba16_stride: u32,
+    bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(yuv_nv12_p16_to_rgba16_impl, [], endianness, bytes_packing);
+    dispatcher(
+        bi_planar_image,
+        rgba16,
+        rgba16_stride,
+        bit_depth,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV12 format with 10 bit pixel format to 10 bit RGBA.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source NV12 image with 10 bit samples.
+/// * `rgba16` - A mutable slice to store the converted RGBA data, values are in `0..1024`.
+/// * `rgba16_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn yuv_nv12_p10_to_rgba16(
+    bi_planar_image: &YuvBiPlanarImage<u16>,
+    rgba16: &mut [u16],
+    rgba16_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    yuv_nv12_p16_to_rgba16(
+        bi_planar_image,
+        rgba16,
+        rgba16_stride,
+        10,
+        range,
+        matrix,
+        endianness,
+        bytes_packing,
+    )
+}
+
+/// Convert P010 to 10 bit RGBA.
+///
+/// P010 keeps 10-bit samples in the most significant bits of little endian 16-bit words.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source P010 image.
+/// * `rgba16` - A mutable slice to store the converted RGBA data, values are in `0..1024`.
+/// * `rgba16_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_p010_to_rgba16(
+    bi_planar_image: &YuvBiPlanarImage<u16>,
+    rgba16: &mut [u16],
+    rgba16_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv12_p16_to_rgba16_impl::<
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::MostSignificantBytes as u8 },
+    >(bi_planar_image, rgba16, rgba16_stride, 10, range, matrix)
+}
+
+/// Convert P016 to 16 bit RGBA.
+///
+/// P016 stores full 16-bit little endian samples with Cb before Cr in the interleaved plane.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source P016 image.
+/// * `rgba16` - A mutable slice to store the converted RGBA data.
+/// * `rgba16_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_p016_to_rgba16(
+    bi_planar_image: &YuvBiPlanarImage<u16>,
+    rgba16: &mut [u16],
+    rgba16_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv12_p16_to_rgba16_impl::<
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::MostSignificantBytes as u8 },
+    >(bi_planar_image, rgba16, rgba16_stride, 16, range, matrix)
 }
@@ -27,28 +27,145 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::avx2_yuv_nv_to_rgba_row;
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
-    feature = "nightly_avx512"
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
 ))]
 use crate::avx512bw::avx512_yuv_nv_to_rgba;
 #[allow(unused_imports)]
 use crate::internals::*;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_yuv_nv_to_rgba_row;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "profiling")]
+use crate::profiling::{dispatch_backend, rayon_splits, ProfileScope};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::sse_yuv_nv_to_rgba;
-#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    not(feature = "safe-only")
+))]
 use crate::wasm32::wasm_yuv_nv_to_rgba_row;
+use crate::yuv_error::{
+    check_interleaved_chroma_channel, check_rgba_destination, check_y8_channel,
+};
 use crate::yuv_support::*;
+use crate::YuvError;
 #[cfg(feature = "rayon")]
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 #[cfg(feature = "rayon")]
 use rayon::prelude::ParallelSliceMut;
 
-fn yuv_nv12_to_rgbx<
+const PRECISION: i32 = 6;
+
+/// Same as `get_inverse_transform(255, 219, 224, 0.299, 0.114).to_integers(6)`
+const BT601_TV_INVERSE_TRANSFORM: CbCrInverseTransform<i32> = CbCrInverseTransform {
+    y_coef: 75,
+    cr_coef: 102,
+    cb_coef: 129,
+    g_coeff_1: 52,
+    g_coeff_2: 25,
+};
+
+/// Same as `get_inverse_transform(255, 219, 224, 0.2126, 0.0722).to_integers(6)`
+const BT709_TV_INVERSE_TRANSFORM: CbCrInverseTransform<i32> = CbCrInverseTransform {
+    y_coef: 75,
+    cr_coef: 115,
+    cb_coef: 135,
+    g_coeff_1: 34,
+    g_coeff_2: 14,
+};
+
+const TV_RANGE: YuvChromaRange = get_yuv_range(8, YuvRange::TV);
+
+pub(crate) fn yuv_nv_to_rgbx<
+    const UV_ORDER: u8,
+    const DESTINATION_CHANNELS: u8,
+    const YUV_CHROMA_SAMPLING: u8,
+>(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    #[allow(unused_variables)] height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<UV_ORDER, DESTINATION_CHANNELS, YUV_CHROMA_SAMPLING>(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+        255,
+    )
+}
+
+fn yuv_nv_to_rgbx_constant_alpha<
+    const UV_ORDER: u8,
+    const DESTINATION_CHANNELS: u8,
+    const YUV_CHROMA_SAMPLING: u8,
+>(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    let chroma_range = get_yuv_range(8, range);
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
+    let transform = get_inverse_transform(
+        255,
+        chroma_range.range_y,
+        chroma_range.range_uv,
+        kr_kb.kr,
+        kr_kb.kb,
+    );
+    let inverse_transform = transform.to_integers(PRECISION as u32);
+    yuv_nv_to_rgbx_impl::<UV_ORDER, DESTINATION_CHANNELS, YUV_CHROMA_SAMPLING>(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        &chroma_range,
+        &inverse_transform,
+        alpha,
+    )
+}
+
+/// Converts image which planes were already validated, e.g. into a region of a larger image
+pub(crate) fn yuv_nv_to_rgbx_rows<
     const UV_ORDER: u8,
     const DESTINATION_CHANNELS: u8,
     const YUV_CHROMA_SAMPLING: u8,
@@ -60,20 +177,111 @@ fn yuv_nv12_to_rgbx<
     bgra: &mut [u8],
     bgra_stride: u32,
     width: u32,
-    _: u32,
+    height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
+) {
+    let chroma_range = get_yuv_range(8, range);
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
+    let transform = get_inverse_transform(
+        255,
+        chroma_range.range_y,
+        chroma_range.range_uv,
+        kr_kb.kr,
+        kr_kb.kb,
+    );
+    let inverse_transform = transform.to_integers(PRECISION as u32);
+    yuv_nv_to_rgbx_unchecked::<UV_ORDER, DESTINATION_CHANNELS, YUV_CHROMA_SAMPLING>(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        &chroma_range,
+        &inverse_transform,
+        255,
+    );
+}
+
+fn yuv_nv_to_rgbx_impl<
+    const UV_ORDER: u8,
+    const DESTINATION_CHANNELS: u8,
+    const YUV_CHROMA_SAMPLING: u8,
+>(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: &YuvChromaRange,
+    inverse_transform: &CbCrInverseTransform<i32>,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let chroma_subsampling: YuvChromaSample = YUV_CHROMA_SAMPLING.into();
+
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_interleaved_chroma_channel(uv_plane, uv_stride, width, height, chroma_subsampling)?;
+    check_rgba_destination(
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        dst_chans.get_channels_count(),
+    )?;
+
+    yuv_nv_to_rgbx_unchecked::<UV_ORDER, DESTINATION_CHANNELS, YUV_CHROMA_SAMPLING>(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        inverse_transform,
+        alpha,
+    );
+    Ok(())
+}
+
+fn yuv_nv_to_rgbx_unchecked<
+    const UV_ORDER: u8,
+    const DESTINATION_CHANNELS: u8,
+    const YUV_CHROMA_SAMPLING: u8,
+>(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    #[allow(unused_variables)] height: u32,
+    range: &YuvChromaRange,
+    inverse_transform: &CbCrInverseTransform<i32>,
+    alpha: u8,
 ) {
     let order: YuvNVOrder = UV_ORDER.into();
+
+    #[cfg(feature = "profiling")]
+    let _profile = ProfileScope::new(
+        "yuv_nv_to_rgba",
+        y_plane.len() + uv_plane.len() + bgra.len(),
+        dispatch_backend(true, true),
+        rayon_splits(height),
+    );
     let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
     let chroma_subsampling: YuvChromaSample = YUV_CHROMA_SAMPLING.into();
-    let range = get_yuv_range(8, range);
     let channels = dst_chans.get_channels_count();
-    let kr_kb = matrix.get_kr_kb();
-    let transform = get_inverse_transform(255, range.range_y, range.range_uv, kr_kb.kr, kr_kb.kb);
-    const PRECISION: i32 = 6;
     const ROUNDING_CONST: i32 = 1 << (PRECISION - 1);
-    let inverse_transform = transform.to_integers(PRECISION as u32);
     let cr_coef = inverse_transform.cr_coef;
     let cb_coef = inverse_transform.cb_coef;
     let y_coef = inverse_transform.y_coef;
@@ -91,27 +299,34 @@ fn yuv_nv12_to_rgbx<
         YuvChromaSample::YUV444 => 1usize,
     };
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_avx2 = std::arch::is_x86_feature_detected!("avx2");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly_avx512"
+        not(feature = "safe-only")
+    ))]
+    let mut _use_avx2 = crate::cpu_features::has_avx2();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_sse = crate::cpu_features::has_sse41();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly_avx512",
+        not(feature = "safe-only")
     ))]
-    let mut _use_avx512 = std::arch::is_x86_feature_detected!("avx512bw");
+    let mut _use_avx512 = crate::cpu_features::has_avx512bw();
 
     let iter;
     #[cfg(feature = "rayon")]
     {
-        iter = bgra.par_chunks_exact_mut(bgra_stride as usize);
+        iter = bgra.par_chunks_mut(bgra_stride as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = bgra.chunks_exact_mut(bgra_stride as usize);
+        iter = bgra.chunks_mut(bgra_stride as usize);
     }
 
-    iter.enumerate().for_each(|(y, bgra)| unsafe {
+    iter.enumerate().for_each(|(y, bgra)| {
         let y_offset = y * (y_stride as usize);
         let uv_offset = if chroma_subsampling == YuvChromaSample::YUV420 {
             (y >> 1) * (uv_stride as usize)
@@ -127,14 +342,17 @@ fn yuv_nv12_to_rgbx<
         #[allow(unused_mut)]
         let mut ux = 0usize;
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
             #[cfg(feature = "nightly_avx512")]
             if _use_avx512 {
                 let processed =
                     avx512_yuv_nv_to_rgba::<UV_ORDER, DESTINATION_CHANNELS, YUV_CHROMA_SAMPLING>(
-                        &range,
-                        &inverse_transform,
+                        range,
+                        inverse_transform,
                         y_plane,
                         uv_plane,
                         bgra,
@@ -144,6 +362,7 @@ fn yuv_nv12_to_rgbx<
                         uv_offset,
                         dst_offset,
                         width as usize,
+                        alpha,
                     );
                 cx = processed.cx;
                 ux = processed.ux;
@@ -152,8 +371,8 @@ fn yuv_nv12_to_rgbx<
             if _use_avx2 {
                 let processed =
                     avx2_yuv_nv_to_rgba_row::<UV_ORDER, DESTINATION_CHANNELS, YUV_CHROMA_SAMPLING>(
-                        &range,
-                        &inverse_transform,
+                        range,
+                        inverse_transform,
                         y_plane,
                         uv_plane,
                         bgra,
@@ -163,6 +382,7 @@ fn yuv_nv12_to_rgbx<
                         uv_offset,
                         dst_offset,
                         width as usize,
+                        alpha,
                     );
                 cx = processed.cx;
                 ux = processed.ux;
@@ -171,8 +391,8 @@ fn yuv_nv12_to_rgbx<
             if _use_sse {
                 let processed =
                     sse_yuv_nv_to_rgba::<UV_ORDER, DESTINATION_CHANNELS, YUV_CHROMA_SAMPLING>(
-                        &range,
-                        &inverse_transform,
+                        range,
+                        inverse_transform,
                         y_plane,
                         uv_plane,
                         bgra,
@@ -182,18 +402,23 @@ fn yuv_nv12_to_rgbx<
                         uv_offset,
                         dst_offset,
                         width as usize,
+                        alpha,
                     );
                 cx = processed.cx;
                 ux = processed.ux;
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
-        {
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
             let processed =
                 neon_yuv_nv_to_rgba_row::<UV_ORDER, DESTINATION_CHANNELS, YUV_CHROMA_SAMPLING>(
-                    &range,
-                    &inverse_transform,
+                    range,
+                    inverse_transform,
                     y_plane,
                     uv_plane,
                     bgra,
@@ -203,17 +428,22 @@ fn yuv_nv12_to_rgbx<
                     uv_offset,
                     dst_offset,
                     width as usize,
+                    alpha,
                 );
             cx = processed.cx;
             ux = processed.ux;
         }
 
-        #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
-        {
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
             let processed =
                 wasm_yuv_nv_to_rgba_row::<UV_ORDER, DESTINATION_CHANNELS, YUV_CHROMA_SAMPLING>(
-                    &range,
-                    &inverse_transform,
+                    range,
+                    inverse_transform,
                     y_plane,
                     uv_plane,
                     bgra,
@@ -223,18 +453,17 @@ fn yuv_nv12_to_rgbx<
                     uv_offset,
                     dst_offset,
                     width as usize,
+                    alpha,
                 );
             cx = processed.cx;
             ux = processed.ux;
         }
 
         for x in (cx..width as usize).step_by(iterator_step) {
-            let y_value = (*y_plane.get_unchecked(y_offset + x) as i32 - bias_y) * y_coef;
+            let y_value = (y_plane[y_offset + x] as i32 - bias_y) * y_coef;
             let cb_pos = uv_offset + ux;
-            let cb_value: i32 =
-                *uv_plane.get_unchecked(cb_pos + order.get_u_position()) as i32 - bias_uv;
-            let cr_value: i32 =
-                *uv_plane.get_unchecked(cb_pos + order.get_v_position()) as i32 - bias_uv;
+            let cb_value: i32 = uv_plane[cb_pos + order.get_u_position()] as i32 - bias_uv;
+            let cr_value: i32 = uv_plane[cb_pos + order.get_v_position()] as i32 - bias_uv;
 
             let r = ((y_value + cr_coef * cr_value + ROUNDING_CONST) >> PRECISION)
                 .min(255)
@@ -251,12 +480,12 @@ fn yuv_nv12_to_rgbx<
 
             let dst_shift = dst_offset + px;
 
-            let dst_slice = bgra.get_unchecked_mut(dst_shift..);
-            *dst_slice.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b as u8;
-            *dst_slice.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g as u8;
-            *dst_slice.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r as u8;
+            let dst_slice = &mut bgra[dst_shift..];
+            dst_slice[dst_chans.get_b_channel_offset()] = b as u8;
+            dst_slice[dst_chans.get_g_channel_offset()] = g as u8;
+            dst_slice[dst_chans.get_r_channel_offset()] = r as u8;
             if dst_chans.has_alpha() {
-                *dst_slice.get_unchecked_mut(dst_chans.get_a_channel_offset()) = 255;
+                dst_slice[dst_chans.get_a_channel_offset()] = alpha;
             }
 
             if chroma_subsampling == YuvChromaSample::YUV422
@@ -264,8 +493,7 @@ fn yuv_nv12_to_rgbx<
             {
                 let next_px = x + 1;
                 if next_px < width as usize {
-                    let y_value =
-                        (*y_plane.get_unchecked(y_offset + next_px) as i32 - bias_y) * y_coef;
+                    let y_value = (y_plane[y_offset + next_px] as i32 - bias_y) * y_coef;
 
                     let r = ((y_value + cr_coef * cr_value + ROUNDING_CONST) >> PRECISION)
                         .min(255)
@@ -281,12 +509,12 @@ fn yuv_nv12_to_rgbx<
 
                     let next_px = next_px * channels;
                     let dst_shift = dst_offset + next_px;
-                    let dst_slice = bgra.get_unchecked_mut(dst_shift..);
-                    *dst_slice.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b as u8;
-                    *dst_slice.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g as u8;
-                    *dst_slice.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r as u8;
+                    let dst_slice = &mut bgra[dst_shift..];
+                    dst_slice[dst_chans.get_b_channel_offset()] = b as u8;
+                    dst_slice[dst_chans.get_g_channel_offset()] = g as u8;
+                    dst_slice[dst_chans.get_r_channel_offset()] = r as u8;
                     if dst_chans.has_alpha() {
-                        *dst_slice.get_unchecked_mut(dst_chans.get_a_channel_offset()) = 255;
+                        dst_slice[dst_chans.get_a_channel_offset()] = alpha;
                     }
                 }
             }
@@ -327,8 +555,8 @@ pub fn yuv_nv12_to_bgra(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::UV as u8 },
         { YuvSourceChannels::Bgra as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -377,8 +605,8 @@ pub fn yuv_nv16_to_bgra(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::UV as u8 },
         { YuvSourceChannels::Bgra as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -427,8 +655,8 @@ pub fn yuv_nv61_to_bgra(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::VU as u8 },
         { YuvSourceChannels::Bgra as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -477,8 +705,8 @@ pub fn yuv_nv21_to_bgra(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::VU as u8 },
         { YuvSourceChannels::Bgra as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -527,8 +755,8 @@ pub fn yuv_nv16_to_rgba(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::UV as u8 },
         { YuvSourceChannels::Rgba as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -577,8 +805,8 @@ pub fn yuv_nv61_to_rgba(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::VU as u8 },
         { YuvSourceChannels::Rgba as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -627,8 +855,8 @@ pub fn yuv_nv12_to_rgba(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::UV as u8 },
         { YuvSourceChannels::Rgba as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -677,8 +905,8 @@ pub fn yuv_nv21_to_rgba(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::VU as u8 },
         { YuvSourceChannels::Rgba as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -728,8 +956,8 @@ pub fn yuv_nv12_to_rgb(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::UV as u8 },
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -770,8 +998,8 @@ pub fn yuv_nv12_to_bgr(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::UV as u8 },
         { YuvSourceChannels::Bgr as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -811,8 +1039,8 @@ pub fn yuv_nv16_to_rgb(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::UV as u8 },
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -852,8 +1080,8 @@ pub fn yuv_nv16_to_bgr(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::UV as u8 },
         { YuvSourceChannels::Bgr as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -893,8 +1121,8 @@ pub fn yuv_nv61_to_rgb(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::VU as u8 },
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -934,8 +1162,8 @@ pub fn yuv_nv61_to_bgr(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::VU as u8 },
         { YuvSourceChannels::Bgr as u8 },
         { YuvChromaSample::YUV422 as u8 },
@@ -976,8 +1204,8 @@ pub fn yuv_nv21_to_rgb(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::VU as u8 },
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -1018,8 +1246,8 @@ pub fn yuv_nv21_to_bgr(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::VU as u8 },
         { YuvSourceChannels::Bgr as u8 },
         { YuvChromaSample::YUV420 as u8 },
@@ -1059,8 +1287,8 @@ pub fn yuv_nv42_to_rgba(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::VU as u8 },
         { YuvSourceChannels::Rgba as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -1110,8 +1338,8 @@ pub fn yuv_nv24_to_rgb(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::UV as u8 },
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -1152,8 +1380,8 @@ pub fn yuv_nv24_to_bgr(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::UV as u8 },
         { YuvSourceChannels::Bgr as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -1193,8 +1421,8 @@ pub fn yuv_nv24_to_rgba(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::UV as u8 },
         { YuvSourceChannels::Rgba as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -1234,8 +1462,8 @@ pub fn yuv_nv24_to_bgra(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::UV as u8 },
         { YuvSourceChannels::Bgra as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -1276,8 +1504,8 @@ pub fn yuv_nv42_to_rgb(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::VU as u8 },
         { YuvSourceChannels::Rgb as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -1318,8 +1546,8 @@ pub fn yuv_nv42_to_bgr(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::VU as u8 },
         { YuvSourceChannels::Bgr as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -1359,8 +1587,8 @@ pub fn yuv_nv42_to_bgra(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
-    yuv_nv12_to_rgbx::<
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
         { YuvNVOrder::VU as u8 },
         { YuvSourceChannels::Bgra as u8 },
         { YuvChromaSample::YUV444 as u8 },
@@ -1368,3 +1596,1351 @@ pub fn yuv_nv42_to_bgra(
         y_plane, y_stride, vu_plane, vu_stride, rgb, rgb_stride, width, height, range, matrix,
     )
 }
+
+/// Convert YUV NV12 format with BT.601 limited range to RGBA format.
+///
+/// Same as [yuv_nv12_to_rgba] with `YuvRange::TV` and `YuvStandardMatrix::Bt601`,
+/// but uses precomputed coefficients instead of deriving them on every call,
+/// which is noticeable when converting a lot of small images.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+///
+pub fn yuv_nv12_to_rgba_bt601_tv(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_impl::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        &TV_RANGE,
+        &BT601_TV_INVERSE_TRANSFORM,
+        255,
+    )
+}
+
+/// Convert YUV NV12 format with BT.601 limited range to BGRA format.
+///
+/// Same as [yuv_nv12_to_bgra] with `YuvRange::TV` and `YuvStandardMatrix::Bt601`,
+/// but uses precomputed coefficients instead of deriving them on every call,
+/// which is noticeable when converting a lot of small images.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+///
+pub fn yuv_nv12_to_bgra_bt601_tv(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_impl::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Bgra as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        &TV_RANGE,
+        &BT601_TV_INVERSE_TRANSFORM,
+        255,
+    )
+}
+
+/// Convert YUV NV12 format with BT.709 limited range to RGBA format.
+///
+/// Same as [yuv_nv12_to_rgba] with `YuvRange::TV` and `YuvStandardMatrix::Bt709`,
+/// but uses precomputed coefficients instead of deriving them on every call,
+/// which is noticeable when converting a lot of small images.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+///
+pub fn yuv_nv12_to_rgba_bt709_tv(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_impl::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        &TV_RANGE,
+        &BT709_TV_INVERSE_TRANSFORM,
+        255,
+    )
+}
+
+/// Convert YUV NV12 format with BT.709 limited range to BGRA format.
+///
+/// Same as [yuv_nv12_to_bgra] with `YuvRange::TV` and `YuvStandardMatrix::Bt709`,
+/// but uses precomputed coefficients instead of deriving them on every call,
+/// which is noticeable when converting a lot of small images.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+///
+pub fn yuv_nv12_to_bgra_bt709_tv(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_impl::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Bgra as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        &TV_RANGE,
+        &BT709_TV_INVERSE_TRANSFORM,
+        255,
+    )
+}
+
+/// Convert YUV NV12 format to RGBA format with constant alpha.
+///
+/// Same as [yuv_nv12_to_rgba] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv_nv12_to_rgba_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV NV12 format to BGRA format with constant alpha.
+///
+/// Same as [yuv_nv12_to_bgra] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv_nv12_to_bgra_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Bgra as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV NV21 format to RGBA format with constant alpha.
+///
+/// Same as [yuv_nv21_to_rgba] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv_nv21_to_rgba_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<
+        { YuvNVOrder::VU as u8 },
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV NV21 format to BGRA format with constant alpha.
+///
+/// Same as [yuv_nv21_to_bgra] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv_nv21_to_bgra_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<
+        { YuvNVOrder::VU as u8 },
+        { YuvSourceChannels::Bgra as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV NV16 format to RGBA format with constant alpha.
+///
+/// Same as [yuv_nv16_to_rgba] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv_nv16_to_rgba_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV422 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV NV16 format to BGRA format with constant alpha.
+///
+/// Same as [yuv_nv16_to_bgra] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv_nv16_to_bgra_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Bgra as u8 },
+        { YuvChromaSample::YUV422 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV NV61 format to RGBA format with constant alpha.
+///
+/// Same as [yuv_nv61_to_rgba] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv_nv61_to_rgba_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<
+        { YuvNVOrder::VU as u8 },
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV422 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV NV61 format to BGRA format with constant alpha.
+///
+/// Same as [yuv_nv61_to_bgra] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv_nv61_to_bgra_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<
+        { YuvNVOrder::VU as u8 },
+        { YuvSourceChannels::Bgra as u8 },
+        { YuvChromaSample::YUV422 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV NV24 format to RGBA format with constant alpha.
+///
+/// Same as [yuv_nv24_to_rgba] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv_nv24_to_rgba_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV444 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV NV24 format to BGRA format with constant alpha.
+///
+/// Same as [yuv_nv24_to_bgra] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv_nv24_to_bgra_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Bgra as u8 },
+        { YuvChromaSample::YUV444 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV NV42 format to RGBA format with constant alpha.
+///
+/// Same as [yuv_nv42_to_rgba] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv_nv42_to_rgba_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<
+        { YuvNVOrder::VU as u8 },
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV444 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV NV42 format to BGRA format with constant alpha.
+///
+/// Same as [yuv_nv42_to_bgra] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv_nv42_to_bgra_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_constant_alpha::<
+        { YuvNVOrder::VU as u8 },
+        { YuvSourceChannels::Bgra as u8 },
+        { YuvChromaSample::YUV444 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV NV12 format to RGBX format.
+///
+/// Same as [yuv_nv12_to_rgba] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `rgbx` - A mutable slice to store the converted RGBX data.
+/// * `rgbx_stride` - The stride (bytes per row) for the RGBX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv12_to_rgbx(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    rgbx: &mut [u8],
+    rgbx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Rgbx as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        rgbx,
+        rgbx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV12 format to BGRX format.
+///
+/// Same as [yuv_nv12_to_bgra] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgrx` - A mutable slice to store the converted BGRX data.
+/// * `bgrx_stride` - The stride (bytes per row) for the BGRX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv12_to_bgrx(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgrx: &mut [u8],
+    bgrx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Bgrx as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgrx,
+        bgrx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV21 format to RGBX format.
+///
+/// Same as [yuv_nv21_to_rgba] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A slice to load the VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `rgbx` - A mutable slice to store the converted RGBX data.
+/// * `rgbx_stride` - The stride (bytes per row) for the RGBX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv21_to_rgbx(
+    y_plane: &[u8],
+    y_stride: u32,
+    vu_plane: &[u8],
+    vu_stride: u32,
+    rgbx: &mut [u8],
+    rgbx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
+        { YuvNVOrder::VU as u8 },
+        { YuvSourceChannels::Rgbx as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        rgbx,
+        rgbx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV21 format to BGRX format.
+///
+/// Same as [yuv_nv21_to_bgra] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A slice to load the VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `bgrx` - A mutable slice to store the converted BGRX data.
+/// * `bgrx_stride` - The stride (bytes per row) for the BGRX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv21_to_bgrx(
+    y_plane: &[u8],
+    y_stride: u32,
+    vu_plane: &[u8],
+    vu_stride: u32,
+    bgrx: &mut [u8],
+    bgrx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
+        { YuvNVOrder::VU as u8 },
+        { YuvSourceChannels::Bgrx as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        bgrx,
+        bgrx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV16 format to RGBX format.
+///
+/// Same as [yuv_nv16_to_rgba] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `rgbx` - A mutable slice to store the converted RGBX data.
+/// * `rgbx_stride` - The stride (bytes per row) for the RGBX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv16_to_rgbx(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    rgbx: &mut [u8],
+    rgbx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Rgbx as u8 },
+        { YuvChromaSample::YUV422 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        rgbx,
+        rgbx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV16 format to BGRX format.
+///
+/// Same as [yuv_nv16_to_bgra] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgrx` - A mutable slice to store the converted BGRX data.
+/// * `bgrx_stride` - The stride (bytes per row) for the BGRX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv16_to_bgrx(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgrx: &mut [u8],
+    bgrx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Bgrx as u8 },
+        { YuvChromaSample::YUV422 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgrx,
+        bgrx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV61 format to RGBX format.
+///
+/// Same as [yuv_nv61_to_rgba] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A slice to load the VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `rgbx` - A mutable slice to store the converted RGBX data.
+/// * `rgbx_stride` - The stride (bytes per row) for the RGBX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv61_to_rgbx(
+    y_plane: &[u8],
+    y_stride: u32,
+    vu_plane: &[u8],
+    vu_stride: u32,
+    rgbx: &mut [u8],
+    rgbx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
+        { YuvNVOrder::VU as u8 },
+        { YuvSourceChannels::Rgbx as u8 },
+        { YuvChromaSample::YUV422 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        rgbx,
+        rgbx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV61 format to BGRX format.
+///
+/// Same as [yuv_nv61_to_bgra] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A slice to load the VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `bgrx` - A mutable slice to store the converted BGRX data.
+/// * `bgrx_stride` - The stride (bytes per row) for the BGRX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv61_to_bgrx(
+    y_plane: &[u8],
+    y_stride: u32,
+    vu_plane: &[u8],
+    vu_stride: u32,
+    bgrx: &mut [u8],
+    bgrx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
+        { YuvNVOrder::VU as u8 },
+        { YuvSourceChannels::Bgrx as u8 },
+        { YuvChromaSample::YUV422 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        bgrx,
+        bgrx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV24 format to RGBX format.
+///
+/// Same as [yuv_nv24_to_rgba] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `rgbx` - A mutable slice to store the converted RGBX data.
+/// * `rgbx_stride` - The stride (bytes per row) for the RGBX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv24_to_rgbx(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    rgbx: &mut [u8],
+    rgbx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Rgbx as u8 },
+        { YuvChromaSample::YUV444 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        rgbx,
+        rgbx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV24 format to BGRX format.
+///
+/// Same as [yuv_nv24_to_bgra] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `bgrx` - A mutable slice to store the converted BGRX data.
+/// * `bgrx_stride` - The stride (bytes per row) for the BGRX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv24_to_bgrx(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    bgrx: &mut [u8],
+    bgrx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
+        { YuvNVOrder::UV as u8 },
+        { YuvSourceChannels::Bgrx as u8 },
+        { YuvChromaSample::YUV444 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        bgrx,
+        bgrx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV42 format to RGBX format.
+///
+/// Same as [yuv_nv42_to_rgba] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A slice to load the VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `rgbx` - A mutable slice to store the converted RGBX data.
+/// * `rgbx_stride` - The stride (bytes per row) for the RGBX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv42_to_rgbx(
+    y_plane: &[u8],
+    y_stride: u32,
+    vu_plane: &[u8],
+    vu_stride: u32,
+    rgbx: &mut [u8],
+    rgbx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
+        { YuvNVOrder::VU as u8 },
+        { YuvSourceChannels::Rgbx as u8 },
+        { YuvChromaSample::YUV444 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        rgbx,
+        rgbx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV NV42 format to BGRX format.
+///
+/// Same as [yuv_nv42_to_bgra] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A slice to load the VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `bgrx` - A mutable slice to store the converted BGRX data.
+/// * `bgrx_stride` - The stride (bytes per row) for the BGRX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv_nv42_to_bgrx(
+    y_plane: &[u8],
+    y_stride: u32,
+    vu_plane: &[u8],
+    vu_stride: u32,
+    bgrx: &mut [u8],
+    bgrx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx::<
+        { YuvNVOrder::VU as u8 },
+        { YuvSourceChannels::Bgrx as u8 },
+        { YuvChromaSample::YUV444 as u8 },
+    >(
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        bgrx,
+        bgrx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
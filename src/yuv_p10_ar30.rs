@@ -0,0 +1,647 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride};
+use crate::yuv_error::{check_chroma_source_channel, check_rgba_destination, check_y8_channel};
+use crate::yuv_planar_generic::{yuv_planar_to_rgb30, Pixel};
+use crate::yuv_support::p16_dispatch;
+use crate::yuv_support::{
+    get_inverse_transform, get_yuv_range, Rgb30, Rgb30Padding, YuvBytesPacking, YuvChromaSample,
+    YuvEndianness, YuvRange, YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+const BIT_DEPTH: u32 = 10;
+
+fn yuv_p10_with_alpha_to_ar30_impl<
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+>(
+    y_plane: &[u16],
+    y_stride: u32,
+    u_plane: &[u16],
+    u_stride: u32,
+    v_plane: &[u16],
+    v_stride: u32,
+    a_plane: &[u16],
+    a_stride: u32,
+    ar30: &mut [u32],
+    ar30_stride: u32,
+    a8: &mut [u8],
+    a8_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+
+    check_rgba_destination(ar30, ar30_stride, width, height, 1)?;
+    check_rgba_destination(a8, a8_stride, width, height, 1)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_y8_channel(a_plane, a_stride, width, height)?;
+    check_chroma_source_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
+    let range = get_yuv_range(BIT_DEPTH, range);
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
+    let max_colors = (1i32 << BIT_DEPTH) - 1;
+    const PRECISION: i32 = 12;
+    const ROUNDING_CONST: i32 = 1 << (PRECISION - 1);
+    let transform = get_inverse_transform(
+        max_colors as u32,
+        range.range_y,
+        range.range_uv,
+        kr_kb.kr,
+        kr_kb.kb,
+    );
+    let i_transform = transform.to_integers(PRECISION as u32);
+    let cr_coef = i_transform.cr_coef;
+    let cb_coef = i_transform.cb_coef;
+    let y_coef = i_transform.y_coef;
+    let g_coef_1 = i_transform.g_coeff_1;
+    let g_coef_2 = i_transform.g_coeff_2;
+
+    let bias_y = range.bias_y as i32;
+    let bias_uv = range.bias_uv as i32;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = ar30
+            .par_chunks_mut(ar30_stride as usize / 4)
+            .take(height as usize)
+            .zip(a8.par_chunks_mut(a8_stride as usize));
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = ar30
+            .chunks_mut(ar30_stride as usize / 4)
+            .take(height as usize)
+            .zip(a8.chunks_mut(a8_stride as usize));
+    }
+
+    iter.enumerate().for_each(|(y, (ar30_row, a8_row))| {
+        let y_offset = y * y_stride as usize;
+        let a_offset = y * a_stride as usize;
+        let (u_offset, v_offset) = match chroma_subsampling {
+            YuvChromaSample::YUV420 => ((y >> 1) * u_stride as usize, (y >> 1) * v_stride as usize),
+            YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => {
+                (y * u_stride as usize, y * v_stride as usize)
+            }
+        };
+
+        let y_src = &y_plane[y_offset / 2..];
+        let u_src = &u_plane[u_offset / 2..];
+        let v_src = &v_plane[v_offset / 2..];
+        let a_src = &a_plane[a_offset / 2..];
+
+        for x in 0..width as usize {
+            let cx = match chroma_subsampling {
+                YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => x >> 1,
+                YuvChromaSample::YUV444 => x,
+            };
+
+            let y_value =
+                (y_src[x].load::<ENDIANNESS, BYTES_POSITION>(BIT_DEPTH as usize) - bias_y) * y_coef;
+            let cb_value =
+                u_src[cx].load::<ENDIANNESS, BYTES_POSITION>(BIT_DEPTH as usize) - bias_uv;
+            let cr_value =
+                v_src[cx].load::<ENDIANNESS, BYTES_POSITION>(BIT_DEPTH as usize) - bias_uv;
+            let a_value = a_src[x]
+                .load::<ENDIANNESS, BYTES_POSITION>(BIT_DEPTH as usize)
+                .min(max_colors);
+
+            let r = ((y_value + cr_coef * cr_value + ROUNDING_CONST) >> PRECISION)
+                .min(max_colors)
+                .max(0);
+            let b = ((y_value + cb_coef * cb_value + ROUNDING_CONST) >> PRECISION)
+                .min(max_colors)
+                .max(0);
+            let g = ((y_value - g_coef_1 * cr_value - g_coef_2 * cb_value + ROUNDING_CONST)
+                >> PRECISION)
+                .min(max_colors)
+                .max(0);
+
+            let packed = ((a_value as u32 >> 8) << 30)
+                | ((r as u32) << 20)
+                | ((g as u32) << 10)
+                | (b as u32);
+            ar30_row[x] = packed.to_le();
+            a8_row[x] = (a_value >> 2) as u8;
+        }
+    });
+
+    Ok(())
+}
+
+fn yuv_p10_with_alpha_to_ar30<const SAMPLING: u8>(
+    y_plane: &[u16],
+    y_stride: u32,
+    u_plane: &[u16],
+    u_stride: u32,
+    v_plane: &[u16],
+    v_stride: u32,
+    a_plane: &[u16],
+    a_stride: u32,
+    ar30: &mut [u32],
+    ar30_stride: u32,
+    a8: &mut [u8],
+    a8_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p10_with_alpha_to_ar30_impl,
+        [SAMPLING],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        a_plane,
+        a_stride,
+        ar30,
+        ar30_stride,
+        a8,
+        a8_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 420 planar format with 10-bit pixel format and alpha plane to AR30 and A8.
+///
+/// Color is packed into AR30 (little endian 32-bit words, 10 bits for B, G and R
+/// starting from the least significant bit, 2 bits of alpha in the top).
+/// Alpha is additionally written at 8-bit precision into a separate plane,
+/// both destinations are produced in a single pass.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice containing Y (luminance) with 10-bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) with 10-bit depth.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) with 10-bit depth.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `a_plane` - A slice to load the alpha with 10-bit depth.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `ar30` - A mutable slice to store the converted AR30 data.
+/// * `ar30_stride` - The stride (bytes per row) for AR30 data.
+/// * `a8` - A mutable slice to store the 8-bit alpha plane.
+/// * `a8_stride` - The stride (bytes per row) for the 8-bit alpha plane.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn yuv420_p10_with_alpha_to_ar30(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    u_plane: &[u16],
+    u_stride: RowStride,
+    v_plane: &[u16],
+    v_stride: RowStride,
+    a_plane: &[u16],
+    a_stride: RowStride,
+    ar30: &mut [u32],
+    ar30_stride: RowStride,
+    a8: &mut [u8],
+    a8_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, a_stride, ar30_stride, a8_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        a_stride.get(),
+        ar30_stride.get(),
+        a8_stride.get(),
+    );
+    yuv_p10_with_alpha_to_ar30::<{ YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        a_plane,
+        a_stride,
+        ar30,
+        ar30_stride,
+        a8,
+        a8_stride,
+        width,
+        height,
+        range,
+        matrix,
+        endianness,
+        bytes_packing,
+    )
+}
+
+/// Convert YUV 422 planar format with 10-bit pixel format and alpha plane to AR30 and A8.
+///
+/// Color is packed into AR30 (little endian 32-bit words, 10 bits for B, G and R
+/// starting from the least significant bit, 2 bits of alpha in the top).
+/// Alpha is additionally written at 8-bit precision into a separate plane,
+/// both destinations are produced in a single pass.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice containing Y (luminance) with 10-bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) with 10-bit depth.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) with 10-bit depth.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `a_plane` - A slice to load the alpha with 10-bit depth.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `ar30` - A mutable slice to store the converted AR30 data.
+/// * `ar30_stride` - The stride (bytes per row) for AR30 data.
+/// * `a8` - A mutable slice to store the 8-bit alpha plane.
+/// * `a8_stride` - The stride (bytes per row) for the 8-bit alpha plane.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn yuv422_p10_with_alpha_to_ar30(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    u_plane: &[u16],
+    u_stride: RowStride,
+    v_plane: &[u16],
+    v_stride: RowStride,
+    a_plane: &[u16],
+    a_stride: RowStride,
+    ar30: &mut [u32],
+    ar30_stride: RowStride,
+    a8: &mut [u8],
+    a8_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, a_stride, ar30_stride, a8_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        a_stride.get(),
+        ar30_stride.get(),
+        a8_stride.get(),
+    );
+    yuv_p10_with_alpha_to_ar30::<{ YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        a_plane,
+        a_stride,
+        ar30,
+        ar30_stride,
+        a8,
+        a8_stride,
+        width,
+        height,
+        range,
+        matrix,
+        endianness,
+        bytes_packing,
+    )
+}
+
+/// Convert YUV 444 planar format with 10-bit pixel format and alpha plane to AR30 and A8.
+///
+/// Color is packed into AR30 (little endian 32-bit words, 10 bits for B, G and R
+/// starting from the least significant bit, 2 bits of alpha in the top).
+/// Alpha is additionally written at 8-bit precision into a separate plane,
+/// both destinations are produced in a single pass.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice containing Y (luminance) with 10-bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) with 10-bit depth.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) with 10-bit depth.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `a_plane` - A slice to load the alpha with 10-bit depth.
+/// * `a_stride` - The stride (bytes per row) for the alpha plane.
+/// * `ar30` - A mutable slice to store the converted AR30 data.
+/// * `ar30_stride` - The stride (bytes per row) for AR30 data.
+/// * `a8` - A mutable slice to store the 8-bit alpha plane.
+/// * `a8_stride` - The stride (bytes per row) for the 8-bit alpha plane.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn yuv444_p10_with_alpha_to_ar30(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    u_plane: &[u16],
+    u_stride: RowStride,
+    v_plane: &[u16],
+    v_stride: RowStride,
+    a_plane: &[u16],
+    a_stride: RowStride,
+    ar30: &mut [u32],
+    ar30_stride: RowStride,
+    a8: &mut [u8],
+    a8_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, a_stride, ar30_stride, a8_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        a_stride.get(),
+        ar30_stride.get(),
+        a8_stride.get(),
+    );
+    yuv_p10_with_alpha_to_ar30::<{ YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        a_plane,
+        a_stride,
+        ar30,
+        ar30_stride,
+        a8,
+        a8_stride,
+        width,
+        height,
+        range,
+        matrix,
+        endianness,
+        bytes_packing,
+    )
+}
+
+/// Convert YUV 420 planar format with 10-bit pixel format to AR30.
+///
+/// Color is packed into AR30 (little endian 32-bit words, 10 bits for B, G and R
+/// starting from the least significant bit), alpha bits are set to opaque.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice containing Y (luminance) with 10-bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) with 10-bit depth.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) with 10-bit depth.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `ar30` - A mutable slice to store the converted AR30 data.
+/// * `ar30_stride` - The stride (bytes per row) for AR30 data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn yuv420_p10_to_ar30(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    u_plane: &[u16],
+    u_stride: RowStride,
+    v_plane: &[u16],
+    v_stride: RowStride,
+    ar30: &mut [u32],
+    ar30_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, ar30_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        ar30_stride.get(),
+    );
+    let dispatcher = p16_dispatch!(
+        yuv_planar_to_rgb30,
+        [u16, { YuvChromaSample::YUV420 as u8 }],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        ar30,
+        ar30_stride,
+        width,
+        height,
+        range,
+        matrix,
+        BIT_DEPTH as usize,
+        Rgb30::Ar30,
+        Rgb30Padding::Opaque,
+    )
+}
+
+/// Convert YUV 422 planar format with 10-bit pixel format to AR30.
+///
+/// Color is packed into AR30 (little endian 32-bit words, 10 bits for B, G and R
+/// starting from the least significant bit), alpha bits are set to opaque.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice containing Y (luminance) with 10-bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) with 10-bit depth.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) with 10-bit depth.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `ar30` - A mutable slice to store the converted AR30 data.
+/// * `ar30_stride` - The stride (bytes per row) for AR30 data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn yuv422_p10_to_ar30(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    u_plane: &[u16],
+    u_stride: RowStride,
+    v_plane: &[u16],
+    v_stride: RowStride,
+    ar30: &mut [u32],
+    ar30_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, ar30_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        ar30_stride.get(),
+    );
+    let dispatcher = p16_dispatch!(
+        yuv_planar_to_rgb30,
+        [u16, { YuvChromaSample::YUV422 as u8 }],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        ar30,
+        ar30_stride,
+        width,
+        height,
+        range,
+        matrix,
+        BIT_DEPTH as usize,
+        Rgb30::Ar30,
+        Rgb30Padding::Opaque,
+    )
+}
+
+/// Convert YUV 444 planar format with 10-bit pixel format to AR30.
+///
+/// Color is packed into AR30 (little endian 32-bit words, 10 bits for B, G and R
+/// starting from the least significant bit), alpha bits are set to opaque.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice containing Y (luminance) with 10-bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) with 10-bit depth.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) with 10-bit depth.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `ar30` - A mutable slice to store the converted AR30 data.
+/// * `ar30_stride` - The stride (bytes per row) for AR30 data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn yuv444_p10_to_ar30(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    u_plane: &[u16],
+    u_stride: RowStride,
+    v_plane: &[u16],
+    v_stride: RowStride,
+    ar30: &mut [u32],
+    ar30_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, ar30_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        ar30_stride.get(),
+    );
+    let dispatcher = p16_dispatch!(
+        yuv_planar_to_rgb30,
+        [u16, { YuvChromaSample::YUV444 as u8 }],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        ar30,
+        ar30_stride,
+        width,
+        height,
+        range,
+        matrix,
+        BIT_DEPTH as usize,
+        Rgb30::Ar30,
+        Rgb30Padding::Opaque,
+    )
+}
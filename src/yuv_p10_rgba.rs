@@ -26,17 +26,31 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_yuv_p16_to_rgba_row;
 #[cfg(feature = "rayon")]
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 #[cfg(feature = "rayon")]
 use rayon::prelude::ParallelSliceMut;
 
+use crate::numerics::to_ne;
+use crate::yuv_error::{check_chroma_source_channel, check_rgba_destination, check_y8_channel};
+use crate::yuv_support::p16_dispatch;
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::yuv_support::P16_SIMD_MAX_BIT_DEPTH;
 use crate::yuv_support::{
     get_inverse_transform, get_yuv_range, YuvBytesPacking, YuvChromaSample, YuvEndianness,
     YuvRange, YuvSourceChannels, YuvStandardMatrix,
 };
+use crate::YuvError;
 
 pub(crate) fn yuv_p16_to_image_impl<
     const DESTINATION_CHANNELS: u8,
@@ -53,19 +67,22 @@ pub(crate) fn yuv_p16_to_image_impl<
     rgba: &mut [u8],
     rgba_stride: u32,
     width: u32,
-    _: u32,
+    height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
     bit_depth: usize,
-) {
+) -> Result<(), YuvError> {
     let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
     let channels = dst_chans.get_channels_count();
 
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
-    let endianness: YuvEndianness = ENDIANNESS.into();
-    let bytes_position: YuvBytesPacking = BYTES_POSITION.into();
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_source_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+
     let range = get_yuv_range(bit_depth as u32, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let max_range_p10 = (1u32 << bit_depth as u32) - 1;
     const PRECISION: i32 = 6;
     const ROUNDING_CONST: i32 = 1 << (PRECISION - 1);
@@ -86,7 +103,7 @@ pub(crate) fn yuv_p16_to_image_impl<
     let bias_y = range.bias_y as i32;
     let bias_uv = range.bias_uv as i32;
 
-    let msb_shift = 16 - bit_depth;
+    let msb_shift = 16 - bit_depth as i32;
     let store_shift = PRECISION as usize + (bit_depth.saturating_sub(8));
 
     let dst_offset = 0usize;
@@ -94,14 +111,14 @@ pub(crate) fn yuv_p16_to_image_impl<
     let iter;
     #[cfg(feature = "rayon")]
     {
-        iter = rgba.par_chunks_exact_mut(rgba_stride as usize);
+        iter = rgba.par_chunks_mut(rgba_stride as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = rgba.chunks_exact_mut(rgba_stride as usize);
+        iter = rgba.chunks_mut(rgba_stride as usize);
     }
 
-    iter.enumerate().for_each(|(y, rgba)| unsafe {
+    iter.enumerate().for_each(|(y, rgba)| {
         let y_offset = y * (y_stride as usize);
         let u_offset = if chroma_subsampling == YuvChromaSample::YUV420 {
             (y >> 1) * (u_stride as usize)
@@ -114,75 +131,53 @@ pub(crate) fn yuv_p16_to_image_impl<
             y * (v_stride as usize)
         };
 
-        let y_src_ptr = y_plane.as_ptr() as *const u8;
-        let u_src_ptr = u_plane.as_ptr() as *const u8;
-        let v_src_ptr = v_plane.as_ptr() as *const u8;
-
         let mut x = 0usize;
         let mut cx = 0usize;
 
-        let y_ld_ptr = y_src_ptr.add(y_offset) as *const u16;
-        let u_ld_ptr = u_src_ptr.add(u_offset) as *const u16;
-        let v_ld_ptr = v_src_ptr.add(v_offset) as *const u16;
-
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        let y_src = &y_plane[y_offset / 2..];
+        let u_src = &u_plane[u_offset / 2..];
+        let v_src = &v_plane[v_offset / 2..];
+
+        // Kernel duplicates each chroma sample and needs 6-bit products to fit 16-bit lanes
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        if chroma_subsampling != YuvChromaSample::YUV444
+            && bit_depth as u32 <= P16_SIMD_MAX_BIT_DEPTH
         {
-            let offset = neon_yuv_p16_to_rgba_row::<
-                DESTINATION_CHANNELS,
-                SAMPLING,
-                ENDIANNESS,
-                BYTES_POSITION,
-            >(
-                y_ld_ptr,
-                u_ld_ptr,
-                v_ld_ptr,
-                rgba,
-                dst_offset,
-                width,
-                &range,
-                &i_transform,
-                x,
-                cx,
-                bit_depth,
-            );
+            let offset = unsafe {
+                neon_yuv_p16_to_rgba_row::<
+                    DESTINATION_CHANNELS,
+                    SAMPLING,
+                    ENDIANNESS,
+                    BYTES_POSITION,
+                >(
+                    y_src.as_ptr(),
+                    u_src.as_ptr(),
+                    v_src.as_ptr(),
+                    rgba,
+                    dst_offset,
+                    width,
+                    &range,
+                    &i_transform,
+                    x,
+                    cx,
+                    bit_depth,
+                )
+            };
             x = offset.cx;
             cx = offset.ux;
         }
 
         while x < width as usize {
-            let y_value: i32;
-            let cb_value: i32;
-            let cr_value: i32;
-            match endianness {
-                YuvEndianness::BigEndian => {
-                    let mut y_vl = u16::from_be(y_ld_ptr.add(x).read_unaligned()) as i32;
-                    let mut cb_vl = u16::from_be(u_ld_ptr.add(cx).read_unaligned()) as i32;
-                    let mut cr_vl = u16::from_be(v_ld_ptr.add(cx).read_unaligned()) as i32;
-                    if bytes_position == YuvBytesPacking::MostSignificantBytes {
-                        y_vl >>= msb_shift;
-                        cb_vl >>= msb_shift;
-                        cr_vl >>= msb_shift;
-                    }
-                    y_value = (y_vl - bias_y) * y_coef;
-
-                    cb_value = cb_vl - bias_uv;
-                    cr_value = cr_vl - bias_uv;
-                }
-                YuvEndianness::LittleEndian => {
-                    let mut y_vl = u16::from_le(y_ld_ptr.add(x).read_unaligned()) as i32;
-                    let mut cb_vl = u16::from_le(u_ld_ptr.add(cx).read_unaligned()) as i32;
-                    let mut cr_vl = u16::from_le(v_ld_ptr.add(cx).read_unaligned()) as i32;
-                    if bytes_position == YuvBytesPacking::MostSignificantBytes {
-                        y_vl >>= msb_shift;
-                        cb_vl >>= msb_shift;
-                        cr_vl >>= msb_shift;
-                    }
-                    y_value = (y_vl - bias_y) * y_coef;
-
-                    cb_value = cb_vl - bias_uv;
-                    cr_value = cr_vl - bias_uv;
-                }
-            }
+            let y_vl = to_ne::<ENDIANNESS, BYTES_POSITION>(y_src[x], msb_shift) as i32;
+            let cb_vl = to_ne::<ENDIANNESS, BYTES_POSITION>(u_src[cx], msb_shift) as i32;
+            let cr_vl = to_ne::<ENDIANNESS, BYTES_POSITION>(v_src[cx], msb_shift) as i32;
+            let y_value = (y_vl - bias_y) * y_coef;
+            let cb_value = cb_vl - bias_uv;
+            let cr_value = cr_vl - bias_uv;
 
             let r_u16 = (y_value + cr_coef * cr_value + ROUNDING_CONST) >> store_shift;
             let b_u16 = (y_value + cb_coef * cb_value + ROUNDING_CONST) >> store_shift;
@@ -197,33 +192,19 @@ pub(crate) fn yuv_p16_to_image_impl<
 
             let rgb_offset = dst_offset + px;
 
-            let dst_slice = rgba.get_unchecked_mut(rgb_offset..);
-            *dst_slice.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b as u8;
-            *dst_slice.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g as u8;
-            *dst_slice.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r as u8;
+            let dst_slice = &mut rgba[rgb_offset..];
+            dst_slice[dst_chans.get_b_channel_offset()] = b as u8;
+            dst_slice[dst_chans.get_g_channel_offset()] = g as u8;
+            dst_slice[dst_chans.get_r_channel_offset()] = r as u8;
             if dst_chans.has_alpha() {
-                *dst_slice.get_unchecked_mut(dst_chans.get_a_channel_offset()) = 255;
+                dst_slice[dst_chans.get_a_channel_offset()] = 255;
             }
 
             x += 1;
 
-            if x + 1 < width as usize {
-                let y_value: i32 = match endianness {
-                    YuvEndianness::BigEndian => {
-                        let mut y_vl = u16::from_be(y_ld_ptr.add(x).read_unaligned()) as i32;
-                        if bytes_position == YuvBytesPacking::MostSignificantBytes {
-                            y_vl >>= msb_shift;
-                        }
-                        (y_vl - bias_y) * y_coef
-                    }
-                    YuvEndianness::LittleEndian => {
-                        let mut y_vl = u16::from_le(y_ld_ptr.add(x).read_unaligned()) as i32;
-                        if bytes_position == YuvBytesPacking::MostSignificantBytes {
-                            y_vl >>= msb_shift;
-                        }
-                        (y_vl - bias_y) * y_coef
-                    }
-                };
+            if chroma_subsampling != YuvChromaSample::YUV444 && x < width as usize {
+                let y_vl = to_ne::<ENDIANNESS, BYTES_POSITION>(y_src[x], msb_shift) as i32;
+                let y_value = (y_vl - bias_y) * y_coef;
 
                 let r_u16 = (y_value + cr_coef * cr_value + ROUNDING_CONST) >> store_shift;
                 let b_u16 = (y_value + cb_coef * cb_value + ROUNDING_CONST) >> store_shift;
@@ -236,19 +217,22 @@ pub(crate) fn yuv_p16_to_image_impl<
 
                 let px = x * channels;
                 let rgb_offset = dst_offset + px;
-                let dst_slice = rgba.get_unchecked_mut(rgb_offset..);
-                *dst_slice.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b as u8;
-                *dst_slice.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g as u8;
-                *dst_slice.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r as u8;
+                let dst_slice = &mut rgba[rgb_offset..];
+                dst_slice[dst_chans.get_b_channel_offset()] = b as u8;
+                dst_slice[dst_chans.get_g_channel_offset()] = g as u8;
+                dst_slice[dst_chans.get_r_channel_offset()] = r as u8;
                 if dst_chans.has_alpha() {
-                    *dst_slice.get_unchecked_mut(dst_chans.get_a_channel_offset()) = 255;
+                    dst_slice[dst_chans.get_a_channel_offset()] = 255;
                 }
+
+                x += 1;
             }
 
-            x += 1;
             cx += 1;
         }
     });
+
+    Ok(())
 }
 
 /// Convert YUV 420 planar format with 10-bit pixel format to BGRA format.
@@ -293,45 +277,15 @@ pub fn yuv420_p10_to_bgra(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_impl,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -346,7 +300,7 @@ pub fn yuv420_p10_to_bgra(
         range,
         matrix,
         10,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format with 10-bit pixel format to BGRA format.
@@ -391,49 +345,19 @@ pub fn yuv420_p10_to_bgr(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_impl,
+        [{ YuvSourceChannels::Bgr as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
         range, matrix, 10,
-    );
+    )
 }
 
 /// Convert YUV 422 format with 10-bit pixel format to BGRA format .
@@ -479,45 +403,15 @@ pub fn yuv422_p10_to_bgra(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_impl,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -532,7 +426,7 @@ pub fn yuv422_p10_to_bgra(
         range,
         matrix,
         10,
-    );
+    )
 }
 
 /// Convert YUV 422 format with 10-bit pixel format to BGR format.
@@ -578,49 +472,19 @@ pub fn yuv422_p10_to_bgr(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_impl,
+        [{ YuvSourceChannels::Bgr as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
         range, matrix, 10,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format with 10-bit pixel format to RGBA format.
@@ -665,45 +529,15 @@ pub fn yuv420_p10_to_rgba(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_impl,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -718,7 +552,7 @@ pub fn yuv420_p10_to_rgba(
         range,
         matrix,
         10,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format with 10-bit pixel format to RGB format.
@@ -763,49 +597,19 @@ pub fn yuv420_p10_to_rgb(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_impl,
+        [{ YuvSourceChannels::Rgb as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
         range, matrix, 10,
-    );
+    )
 }
 
 /// Convert YUV 422 format with 10-bit pixel format to RGBA format.
@@ -850,45 +654,15 @@ pub fn yuv422_p10_to_rgba(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_impl,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -903,7 +677,7 @@ pub fn yuv422_p10_to_rgba(
         range,
         matrix,
         10,
-    );
+    )
 }
 
 /// Convert YUV 422 format with 10-bit pixel format to RGB format.
@@ -948,49 +722,19 @@ pub fn yuv422_p10_to_rgb(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_impl,
+        [{ YuvSourceChannels::Rgb as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
         range, matrix, 10,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format with 10-bit pixel format to RGBA format.
@@ -1035,45 +779,15 @@ pub fn yuv444_p10_to_rgba(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_impl,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1088,7 +802,7 @@ pub fn yuv444_p10_to_rgba(
         range,
         matrix,
         10,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format with 10-bit pixel format to RGB format.
@@ -1133,49 +847,19 @@ pub fn yuv444_p10_to_rgb(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_impl,
+        [{ YuvSourceChannels::Rgb as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
         range, matrix, 10,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format with 10-bit pixel format to BGRA format.
@@ -1220,45 +904,15 @@ pub fn yuv444_p10_to_bgra(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_impl,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1273,7 +927,7 @@ pub fn yuv444_p10_to_bgra(
         range,
         matrix,
         10,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format with 10-bit pixel format to BGR format.
@@ -1318,47 +972,17 @@ pub fn yuv444_p10_to_bgr(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_impl,
+        [{ YuvSourceChannels::Bgr as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        }],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
         range, matrix, 10,
-    );
+    )
 }
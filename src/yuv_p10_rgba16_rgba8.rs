@@ -0,0 +1,280 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride};
+use crate::yuv_planar_generic::yuv_planar_to_rgba16_with_rgba8;
+use crate::yuv_support::p16_dispatch;
+use crate::yuv_support::{
+    YuvBytesPacking, YuvChromaSample, YuvEndianness, YuvRange, YuvStandardMatrix,
+};
+use crate::YuvError;
+
+const BIT_DEPTH: usize = 10;
+
+fn yuv_p10_to_rgba16_with_rgba8<const SAMPLING: u8>(
+    y_plane: &[u16],
+    y_stride: u32,
+    u_plane: &[u16],
+    u_stride: u32,
+    v_plane: &[u16],
+    v_stride: u32,
+    rgba16: &mut [u16],
+    rgba16_stride: u32,
+    rgba8: &mut [u8],
+    rgba8_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_planar_to_rgba16_with_rgba8,
+        [u16, SAMPLING],
+        endianness,
+        bytes_packing
+    );
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba16,
+        rgba16_stride,
+        rgba8,
+        rgba8_stride,
+        width,
+        height,
+        range,
+        YuvStandardMatrix::Bt709,
+        BIT_DEPTH,
+    )
+}
+
+/// Convert YUV 420 planar format with 10-bit pixel format BT.709 to RGBA16 and dithered RGBA8.
+///
+/// Both destinations are produced in a single pass: RGBA16 keeps full precision for saving,
+/// RGBA8 is ordered dithered from the same 16-bit values for preview.
+/// Transfer function is left untouched, since BT.709 and sRGB share primaries and white point
+/// both images may be tagged as sRGB. Alpha is opaque in both destinations.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice containing Y (luminance) with 10-bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) with 10-bit depth.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) with 10-bit depth.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba16` - A mutable slice to store the converted RGBA 16-bit data.
+/// * `rgba16_stride` - The stride (bytes per row) for RGBA 16-bit data.
+/// * `rgba8` - A mutable slice to store the dithered RGBA 8-bit data.
+/// * `rgba8_stride` - The stride (bytes per row) for RGBA 8-bit data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn yuv420_p10_to_rgba16_with_rgba8(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    u_plane: &[u16],
+    u_stride: RowStride,
+    v_plane: &[u16],
+    v_stride: RowStride,
+    rgba16: &mut [u16],
+    rgba16_stride: RowStride,
+    rgba8: &mut [u8],
+    rgba8_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgba16_stride, rgba8_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgba16_stride.get(),
+        rgba8_stride.get(),
+    );
+    yuv_p10_to_rgba16_with_rgba8::<{ YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba16,
+        rgba16_stride,
+        rgba8,
+        rgba8_stride,
+        width,
+        height,
+        range,
+        endianness,
+        bytes_packing,
+    )
+}
+
+/// Convert YUV 422 planar format with 10-bit pixel format BT.709 to RGBA16 and dithered RGBA8.
+///
+/// Both destinations are produced in a single pass: RGBA16 keeps full precision for saving,
+/// RGBA8 is ordered dithered from the same 16-bit values for preview.
+/// Transfer function is left untouched, since BT.709 and sRGB share primaries and white point
+/// both images may be tagged as sRGB. Alpha is opaque in both destinations.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice containing Y (luminance) with 10-bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) with 10-bit depth.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) with 10-bit depth.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba16` - A mutable slice to store the converted RGBA 16-bit data.
+/// * `rgba16_stride` - The stride (bytes per row) for RGBA 16-bit data.
+/// * `rgba8` - A mutable slice to store the dithered RGBA 8-bit data.
+/// * `rgba8_stride` - The stride (bytes per row) for RGBA 8-bit data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn yuv422_p10_to_rgba16_with_rgba8(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    u_plane: &[u16],
+    u_stride: RowStride,
+    v_plane: &[u16],
+    v_stride: RowStride,
+    rgba16: &mut [u16],
+    rgba16_stride: RowStride,
+    rgba8: &mut [u8],
+    rgba8_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgba16_stride, rgba8_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgba16_stride.get(),
+        rgba8_stride.get(),
+    );
+    yuv_p10_to_rgba16_with_rgba8::<{ YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba16,
+        rgba16_stride,
+        rgba8,
+        rgba8_stride,
+        width,
+        height,
+        range,
+        endianness,
+        bytes_packing,
+    )
+}
+
+/// Convert YUV 444 planar format with 10-bit pixel format BT.709 to RGBA16 and dithered RGBA8.
+///
+/// Both destinations are produced in a single pass: RGBA16 keeps full precision for saving,
+/// RGBA8 is ordered dithered from the same 16-bit values for preview.
+/// Transfer function is left untouched, since BT.709 and sRGB share primaries and white point
+/// both images may be tagged as sRGB. Alpha is opaque in both destinations.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice containing Y (luminance) with 10-bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) with 10-bit depth.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) with 10-bit depth.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba16` - A mutable slice to store the converted RGBA 16-bit data.
+/// * `rgba16_stride` - The stride (bytes per row) for RGBA 16-bit data.
+/// * `rgba8` - A mutable slice to store the dithered RGBA 8-bit data.
+/// * `rgba8_stride` - The stride (bytes per row) for RGBA 8-bit data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `endianness` - The endianness of stored bytes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant )
+///
+pub fn yuv444_p10_to_rgba16_with_rgba8(
+    y_plane: &[u16],
+    y_stride: RowStride,
+    u_plane: &[u16],
+    u_stride: RowStride,
+    v_plane: &[u16],
+    v_stride: RowStride,
+    rgba16: &mut [u16],
+    rgba16_stride: RowStride,
+    rgba8: &mut [u8],
+    rgba8_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgba16_stride, rgba8_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgba16_stride.get(),
+        rgba8_stride.get(),
+    );
+    yuv_p10_to_rgba16_with_rgba8::<{ YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba16,
+        rgba16_stride,
+        rgba8,
+        rgba8_stride,
+        width,
+        height,
+        range,
+        endianness,
+        bytes_packing,
+    )
+}
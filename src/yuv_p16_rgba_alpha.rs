@@ -26,12 +26,25 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_yuv_p16_to_rgba_alpha_row;
+use crate::yuv_error::{check_chroma_source_channel, check_rgba_destination, check_y8_channel};
+use crate::yuv_support::p16_dispatch;
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::yuv_support::P16_SIMD_MAX_BIT_DEPTH;
 use crate::yuv_support::{
-    get_inverse_transform, get_yuv_range, YuvBytesPacking, YuvChromaSample, YuvEndianness,
-    YuvRange, YuvSourceChannels, YuvStandardMatrix,
+    get_inverse_transform, get_yuv_range, rescale_alpha_depth, YuvBytesPacking, YuvChromaSample,
+    YuvEndianness, YuvRange, YuvSourceChannels, YuvStandardMatrix,
 };
+use crate::YuvError;
 #[cfg(feature = "rayon")]
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 #[cfg(feature = "rayon")]
@@ -54,11 +67,12 @@ pub(crate) fn yuv_p16_to_image_alpha_impl<
     rgba: &mut [u8],
     rgba_stride: u32,
     width: u32,
-    _: u32,
+    height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
     bit_depth: usize,
-) {
+    alpha_bit_depth: usize,
+) -> Result<(), YuvError> {
     let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
     let channels = dst_chans.get_channels_count();
 
@@ -67,10 +81,19 @@ pub(crate) fn yuv_p16_to_image_alpha_impl<
     }
 
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_source_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+    check_y8_channel(a_plane, a_stride, width, height)?;
+    if !(8..=16).contains(&alpha_bit_depth) {
+        return Err(YuvError::UnsupportedBitDepth(alpha_bit_depth as u32));
+    }
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+
     let endianness: YuvEndianness = ENDIANNESS.into();
     let bytes_position: YuvBytesPacking = BYTES_POSITION.into();
     let range = get_yuv_range(bit_depth as u32, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let max_range_p10 = (1u32 << bit_depth as u32) - 1;
     const PRECISION: i32 = 6;
     const ROUNDING_CONST: i32 = 1 << (PRECISION - 1);
@@ -92,6 +115,7 @@ pub(crate) fn yuv_p16_to_image_alpha_impl<
     let bias_uv = range.bias_uv as i32;
 
     let msb_shift = 16 - bit_depth;
+    let alpha_msb_shift = 16 - alpha_bit_depth;
     let a_store_shift = bit_depth.saturating_sub(8);
     let store_shift = PRECISION as usize + a_store_shift;
 
@@ -100,14 +124,14 @@ pub(crate) fn yuv_p16_to_image_alpha_impl<
     let iter;
     #[cfg(feature = "rayon")]
     {
-        iter = rgba.par_chunks_exact_mut(rgba_stride as usize);
+        iter = rgba.par_chunks_mut(rgba_stride as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = rgba.chunks_exact_mut(rgba_stride as usize);
+        iter = rgba.chunks_mut(rgba_stride as usize);
     }
 
-    iter.enumerate().for_each(|(y, rgba)| unsafe {
+    iter.enumerate().for_each(|(y, rgba)| {
         let y_offset = y * (y_stride as usize);
         let a_offset = y * (a_stride as usize);
         let u_offset = if chroma_subsampling == YuvChromaSample::YUV420 {
@@ -121,42 +145,48 @@ pub(crate) fn yuv_p16_to_image_alpha_impl<
             y * (v_stride as usize)
         };
 
-        let y_src_ptr = y_plane.as_ptr() as *const u8;
-        let u_src_ptr = u_plane.as_ptr() as *const u8;
-        let v_src_ptr = v_plane.as_ptr() as *const u8;
-        let a_src_ptr = a_plane.as_ptr() as *const u8;
-
         let mut x = 0usize;
         let mut cx = 0usize;
 
-        let y_ld_ptr = y_src_ptr.add(y_offset) as *const u16;
-        let u_ld_ptr = u_src_ptr.add(u_offset) as *const u16;
-        let v_ld_ptr = v_src_ptr.add(v_offset) as *const u16;
-        let a_ld_ptr = a_src_ptr.add(a_offset) as *const u16;
-
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        let y_src = &y_plane[y_offset / 2..];
+        let u_src = &u_plane[u_offset / 2..];
+        let v_src = &v_plane[v_offset / 2..];
+        let a_src = &a_plane[a_offset / 2..];
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        // NEON kernel stores alpha at color bit depth, duplicates each chroma sample
+        // and needs 6-bit products to fit 16-bit lanes
+        if alpha_bit_depth == bit_depth
+            && chroma_subsampling != YuvChromaSample::YUV444
+            && bit_depth as u32 <= P16_SIMD_MAX_BIT_DEPTH
         {
-            let offset = neon_yuv_p16_to_rgba_alpha_row::<
-                DESTINATION_CHANNELS,
-                SAMPLING,
-                ENDIANNESS,
-                BYTES_POSITION,
-            >(
-                y_ld_ptr,
-                u_ld_ptr,
-                v_ld_ptr,
-                a_ld_ptr,
-                rgba,
-                dst_offset,
-                width,
-                &range,
-                &i_transform,
-                x,
-                cx,
-                bit_depth,
-            );
-            x = offset.cx;
-            cx = offset.ux;
+            unsafe {
+                let offset = neon_yuv_p16_to_rgba_alpha_row::<
+                    DESTINATION_CHANNELS,
+                    SAMPLING,
+                    ENDIANNESS,
+                    BYTES_POSITION,
+                >(
+                    y_src.as_ptr(),
+                    u_src.as_ptr(),
+                    v_src.as_ptr(),
+                    a_src.as_ptr(),
+                    rgba,
+                    dst_offset,
+                    width,
+                    &range,
+                    &i_transform,
+                    x,
+                    cx,
+                    bit_depth,
+                );
+                x = offset.cx;
+                cx = offset.ux;
+            }
         }
 
         while x < width as usize {
@@ -165,9 +195,9 @@ pub(crate) fn yuv_p16_to_image_alpha_impl<
             let cr_value: i32;
             match endianness {
                 YuvEndianness::BigEndian => {
-                    let mut y_vl = u16::from_be(y_ld_ptr.add(x).read_unaligned()) as i32;
-                    let mut cb_vl = u16::from_be(u_ld_ptr.add(cx).read_unaligned()) as i32;
-                    let mut cr_vl = u16::from_be(v_ld_ptr.add(cx).read_unaligned()) as i32;
+                    let mut y_vl = u16::from_be(y_src[x]) as i32;
+                    let mut cb_vl = u16::from_be(u_src[cx]) as i32;
+                    let mut cr_vl = u16::from_be(v_src[cx]) as i32;
                     if bytes_position == YuvBytesPacking::MostSignificantBytes {
                         y_vl >>= msb_shift;
                         cb_vl >>= msb_shift;
@@ -179,9 +209,9 @@ pub(crate) fn yuv_p16_to_image_alpha_impl<
                     cr_value = cr_vl - bias_uv;
                 }
                 YuvEndianness::LittleEndian => {
-                    let mut y_vl = u16::from_le(y_ld_ptr.add(x).read_unaligned()) as i32;
-                    let mut cb_vl = u16::from_le(u_ld_ptr.add(cx).read_unaligned()) as i32;
-                    let mut cr_vl = u16::from_le(v_ld_ptr.add(cx).read_unaligned()) as i32;
+                    let mut y_vl = u16::from_le(y_src[x]) as i32;
+                    let mut cb_vl = u16::from_le(u_src[cx]) as i32;
+                    let mut cr_vl = u16::from_le(v_src[cx]) as i32;
                     if bytes_position == YuvBytesPacking::MostSignificantBytes {
                         y_vl >>= msb_shift;
                         cb_vl >>= msb_shift;
@@ -207,35 +237,35 @@ pub(crate) fn yuv_p16_to_image_alpha_impl<
 
             let rgb_offset = dst_offset + px;
 
-            let dst_slice = rgba.get_unchecked_mut(rgb_offset..);
+            let dst_slice = &mut rgba[rgb_offset..];
 
-            *dst_slice.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b as u8;
-            *dst_slice.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g as u8;
-            *dst_slice.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r as u8;
+            dst_slice[dst_chans.get_b_channel_offset()] = b as u8;
+            dst_slice[dst_chans.get_g_channel_offset()] = g as u8;
+            dst_slice[dst_chans.get_r_channel_offset()] = r as u8;
             let mut a0 = if endianness == YuvEndianness::BigEndian {
-                u16::from_be(a_ld_ptr.add(x).read_unaligned())
+                u16::from_be(a_src[x])
             } else {
-                u16::from_le(a_ld_ptr.add(x).read_unaligned())
+                u16::from_le(a_src[x])
             };
             if bytes_position == YuvBytesPacking::MostSignificantBytes {
-                a0 >>= msb_shift;
+                a0 >>= alpha_msb_shift;
             }
-            a0 >>= a_store_shift;
-            *dst_slice.get_unchecked_mut(dst_chans.get_a_channel_offset()) = a0 as u8;
+            a0 = rescale_alpha_depth(a0, alpha_bit_depth, 8);
+            dst_slice[dst_chans.get_a_channel_offset()] = a0 as u8;
 
             x += 1;
 
-            if x + 1 < width as usize {
+            if chroma_subsampling != YuvChromaSample::YUV444 && x < width as usize {
                 let y_value: i32 = match endianness {
                     YuvEndianness::BigEndian => {
-                        let mut y_vl = u16::from_be(y_ld_ptr.add(x).read_unaligned()) as i32;
+                        let mut y_vl = u16::from_be(y_src[x]) as i32;
                         if bytes_position == YuvBytesPacking::MostSignificantBytes {
                             y_vl >>= msb_shift;
                         }
                         (y_vl - bias_y) * y_coef
                     }
                     YuvEndianness::LittleEndian => {
-                        let mut y_vl = u16::from_le(y_ld_ptr.add(x).read_unaligned()) as i32;
+                        let mut y_vl = u16::from_le(y_src[x]) as i32;
                         if bytes_position == YuvBytesPacking::MostSignificantBytes {
                             y_vl >>= msb_shift;
                         }
@@ -253,28 +283,31 @@ pub(crate) fn yuv_p16_to_image_alpha_impl<
                 let g = g_u16.min(255).max(0);
 
                 let mut a1 = if endianness == YuvEndianness::BigEndian {
-                    u16::from_be(a_ld_ptr.add(x).read_unaligned())
+                    u16::from_be(a_src[x])
                 } else {
-                    u16::from_le(a_ld_ptr.add(x).read_unaligned())
+                    u16::from_le(a_src[x])
                 };
                 if bytes_position == YuvBytesPacking::MostSignificantBytes {
-                    a1 >>= msb_shift;
+                    a1 >>= alpha_msb_shift;
                 }
-                a1 >>= a_store_shift;
+                a1 = rescale_alpha_depth(a1, alpha_bit_depth, 8);
 
                 let px = x * channels;
                 let rgb_offset = dst_offset + px;
-                let dst_slice = rgba.get_unchecked_mut(rgb_offset..);
-                *dst_slice.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b as u8;
-                *dst_slice.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g as u8;
-                *dst_slice.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r as u8;
-                *dst_slice.get_unchecked_mut(dst_chans.get_a_channel_offset()) = a1 as u8;
+                let dst_slice = &mut rgba[rgb_offset..];
+                dst_slice[dst_chans.get_b_channel_offset()] = b as u8;
+                dst_slice[dst_chans.get_g_channel_offset()] = g as u8;
+                dst_slice[dst_chans.get_r_channel_offset()] = r as u8;
+                dst_slice[dst_chans.get_a_channel_offset()] = a1 as u8;
+
+                x += 1;
             }
 
-            x += 1;
             cx += 1;
         }
     });
+
+    Ok(())
 }
 
 /// Convert YUV 420 planar format with 8+ bit pixel format to BGRA format with interleaving alpha.
@@ -325,45 +358,15 @@ pub fn yuv420_p16_with_alpha_to_bgra(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_alpha_impl,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -380,7 +383,8 @@ pub fn yuv420_p16_with_alpha_to_bgra(
         range,
         matrix,
         bit_depth,
-    );
+        bit_depth,
+    )
 }
 
 /// Convert YUV 422 format with 8+ bit pixel format to BGRA format with interleaving alpha.
@@ -432,45 +436,15 @@ pub fn yuv422_p16_with_alpha_to_bgra(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_alpha_impl,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -487,7 +461,8 @@ pub fn yuv422_p16_with_alpha_to_bgra(
         range,
         matrix,
         bit_depth,
-    );
+        bit_depth,
+    )
 }
 
 /// Convert YUV 420 planar format with 8+ bit pixel format to RGBA format with interleaving alpha.
@@ -538,45 +513,15 @@ pub fn yuv420_p16_with_alpha_to_rgba(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_alpha_impl,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -593,7 +538,8 @@ pub fn yuv420_p16_with_alpha_to_rgba(
         range,
         matrix,
         bit_depth,
-    );
+        bit_depth,
+    )
 }
 
 /// Convert YUV 422 format with 8+ bit pixel format to RGBA format with interleaving alpha.
@@ -644,45 +590,15 @@ pub fn yuv422_p16_with_alpha_to_rgba(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_alpha_impl,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -699,7 +615,8 @@ pub fn yuv422_p16_with_alpha_to_rgba(
         range,
         matrix,
         bit_depth,
-    );
+        bit_depth,
+    )
 }
 
 /// Convert YUV 444 planar format with 8+ bit pixel format to RGBA format with interleaving alpha.
@@ -750,45 +667,15 @@ pub fn yuv444_p16_with_alpha_to_rgba(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_alpha_impl,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -805,7 +692,8 @@ pub fn yuv444_p16_with_alpha_to_rgba(
         range,
         matrix,
         bit_depth,
-    );
+        bit_depth,
+    )
 }
 
 /// Convert YUV 444 planar format with 8+ bit pixel format to BGRA format with interleaving alpha.
@@ -856,45 +744,15 @@ pub fn yuv444_p16_with_alpha_to_bgra(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_alpha_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_alpha_impl,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -911,5 +769,234 @@ pub fn yuv444_p16_with_alpha_to_bgra(
         range,
         matrix,
         bit_depth,
-    );
+        bit_depth,
+    )
+}
+
+type AlphaDepthHandler = fn(
+    &[u16],
+    u32,
+    &[u16],
+    u32,
+    &[u16],
+    u32,
+    &[u16],
+    u32,
+    &mut [u8],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+    usize,
+    usize,
+) -> Result<(), YuvError>;
+
+fn select_alpha_depth_handler<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> AlphaDepthHandler {
+    p16_dispatch!(
+        yuv_p16_to_image_alpha_impl,
+        [DESTINATION_CHANNELS, SAMPLING],
+        endianness,
+        bytes_packing
+    )
+}
+
+fn yuv_p16_with_alpha_depth_to_image<const DESTINATION_CHANNELS: u8>(
+    y_plane: &[u16],
+    y_stride: u32,
+    u_plane: &[u16],
+    u_stride: u32,
+    v_plane: &[u16],
+    v_stride: u32,
+    a_plane: &[u16],
+    a_stride: u32,
+    dst: &mut [u8],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    sampling: YuvChromaSample,
+    bit_depth: usize,
+    alpha_bit_depth: usize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    let dispatcher = match sampling {
+        YuvChromaSample::YUV420 => select_alpha_depth_handler::<
+            DESTINATION_CHANNELS,
+            { YuvChromaSample::YUV420 as u8 },
+        >(endianness, bytes_packing),
+        YuvChromaSample::YUV422 => select_alpha_depth_handler::<
+            DESTINATION_CHANNELS,
+            { YuvChromaSample::YUV422 as u8 },
+        >(endianness, bytes_packing),
+        YuvChromaSample::YUV444 => select_alpha_depth_handler::<
+            DESTINATION_CHANNELS,
+            { YuvChromaSample::YUV444 as u8 },
+        >(endianness, bytes_packing),
+    };
+    dispatcher(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        a_plane,
+        a_stride,
+        dst,
+        dst_stride,
+        width,
+        height,
+        range,
+        matrix,
+        bit_depth,
+        alpha_bit_depth,
+    )
+}
+
+/// Convert YUV planar format with 8+ bit pixel format and an alpha plane of its own bit depth to RGBA format with interleaving alpha.
+///
+/// Color planes are decoded at `bit_depth` while alpha is read at `alpha_bit_depth`
+/// and rescaled to 8 bits of the destination, e.g. 8-bit AV1 alpha items paired with 10-bit color.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 8+ bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) with 8+ bit depth.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) with 8+ bit depth.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `a_plane` - A slice to load the alpha with `alpha_bit_depth` precision.
+/// * `a_stride` - The stride (bytes per row) for the Alpha plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `sampling` - Chroma subsampling of the U and V planes.
+/// * `bit_depth` - Bit depth of source YUV planes
+/// * `alpha_bit_depth` - Bit depth of the alpha plane, from 8 to 16.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes, shared by color and alpha planes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ), shared by color and alpha planes
+///
+pub fn yuv_p16_with_alpha_depth_to_rgba(
+    y_plane: &[u16],
+    y_stride: u32,
+    u_plane: &[u16],
+    u_stride: u32,
+    v_plane: &[u16],
+    v_stride: u32,
+    a_plane: &[u16],
+    a_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    sampling: YuvChromaSample,
+    bit_depth: usize,
+    alpha_bit_depth: usize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    yuv_p16_with_alpha_depth_to_image::<{ YuvSourceChannels::Rgba as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        a_plane,
+        a_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        sampling,
+        bit_depth,
+        alpha_bit_depth,
+        range,
+        matrix,
+        endianness,
+        bytes_packing,
+    )
+}
+
+/// Convert YUV planar format with 8+ bit pixel format and an alpha plane of its own bit depth to BGRA format with interleaving alpha.
+///
+/// Color planes are decoded at `bit_depth` while alpha is read at `alpha_bit_depth`
+/// and rescaled to 8 bits of the destination, e.g. 8-bit AV1 alpha items paired with 10-bit color.
+///
+/// # Arguments
+///
+/// * `y_plane` -  A slice containing Y (luminance) with 8+ bit depth.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) with 8+ bit depth.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) with 8+ bit depth.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `a_plane` - A slice to load the alpha with `alpha_bit_depth` precision.
+/// * `a_stride` - The stride (bytes per row) for the Alpha plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for BGRA data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `sampling` - Chroma subsampling of the U and V planes.
+/// * `bit_depth` - Bit depth of source YUV planes
+/// * `alpha_bit_depth` - Bit depth of the alpha plane, from 8 to 16.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `endianness` - The endianness of stored bytes, shared by color and alpha planes
+/// * `bytes_packing` - position of significant bytes ( most significant or least significant ), shared by color and alpha planes
+///
+pub fn yuv_p16_with_alpha_depth_to_bgra(
+    y_plane: &[u16],
+    y_stride: u32,
+    u_plane: &[u16],
+    u_stride: u32,
+    v_plane: &[u16],
+    v_stride: u32,
+    a_plane: &[u16],
+    a_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    sampling: YuvChromaSample,
+    bit_depth: usize,
+    alpha_bit_depth: usize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> Result<(), YuvError> {
+    yuv_p16_with_alpha_depth_to_image::<{ YuvSourceChannels::Bgra as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        a_plane,
+        a_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        sampling,
+        bit_depth,
+        alpha_bit_depth,
+        range,
+        matrix,
+        endianness,
+        bytes_packing,
+    )
 }
@@ -26,17 +26,31 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_yuv_p16_to_rgba16_row;
+use crate::yuv_error::{
+    check_chroma_source_channel, check_p16_bit_depth, check_rgba_destination, check_y8_channel,
+};
+use crate::yuv_support::p16_dispatch;
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::yuv_support::P16_SIMD_MAX_BIT_DEPTH;
 use crate::yuv_support::{
-    get_inverse_transform, get_yuv_range, YuvBytesPacking, YuvChromaSample, YuvEndianness,
-    YuvRange, YuvSourceChannels, YuvStandardMatrix,
+    get_inverse_transform, get_yuv_range, p16_inverse_precision, YuvBytesPacking, YuvChromaSample,
+    YuvEndianness, YuvRange, YuvSourceChannels, YuvStandardMatrix,
 };
+use crate::YuvError;
 #[cfg(feature = "rayon")]
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 #[cfg(feature = "rayon")]
 use rayon::prelude::ParallelSliceMut;
-use std::slice;
 
 pub(crate) fn yuv_p16_to_image_p16_impl<
     const DESTINATION_CHANNELS: u8,
@@ -57,18 +71,24 @@ pub(crate) fn yuv_p16_to_image_p16_impl<
     range: YuvRange,
     matrix: YuvStandardMatrix,
     bit_depth: usize,
-) {
+) -> Result<(), YuvError> {
     let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
     let channels = dst_chans.get_channels_count();
 
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_source_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+    check_rgba_destination(rgba16, rgba_stride, width, height, channels)?;
+
     let endianness: YuvEndianness = ENDIANNESS.into();
     let bytes_position: YuvBytesPacking = BYTES_POSITION.into();
+    check_p16_bit_depth(bit_depth as u32)?;
     let range = get_yuv_range(bit_depth as u32, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let max_range_p16 = (1u32 << bit_depth as u32) - 1;
-    const PRECISION: i32 = 6;
-    const ROUNDING_CONST: i32 = 1 << (PRECISION - 1);
+    let precision = p16_inverse_precision(bit_depth as u32);
+    let rounding_const: i32 = 1 << (precision - 1);
     let transform = get_inverse_transform(
         max_range_p16,
         range.range_y,
@@ -76,7 +96,7 @@ pub(crate) fn yuv_p16_to_image_p16_impl<
         kr_kb.kr,
         kr_kb.kb,
     );
-    let i_transform = transform.to_integers(PRECISION as u32);
+    let i_transform = transform.to_integers(precision);
     let cr_coef = i_transform.cr_coef;
     let cb_coef = i_transform.cb_coef;
     let y_coef = i_transform.y_coef;
@@ -87,28 +107,25 @@ pub(crate) fn yuv_p16_to_image_p16_impl<
     let bias_uv = range.bias_uv as i32;
 
     let msb_shift = 16 - bit_depth;
-    let store_shift = PRECISION as usize;
+    let store_shift = precision as usize;
 
     let dst_offset = 0usize;
 
-    let rgba_safe_align = unsafe {
-        slice::from_raw_parts_mut(
-            rgba16.as_mut_ptr() as *mut u8,
-            height as usize * rgba_stride as usize,
-        )
-    };
-
     let iter;
     #[cfg(feature = "rayon")]
     {
-        iter = rgba_safe_align.par_chunks_exact_mut(rgba_stride as usize);
+        iter = rgba16
+            .par_chunks_mut(rgba_stride as usize / 2)
+            .take(height as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = rgba_safe_align.chunks_exact_mut(rgba_stride as usize);
+        iter = rgba16
+            .chunks_mut(rgba_stride as usize / 2)
+            .take(height as usize);
     }
 
-    iter.enumerate().for_each(|(y, rgba16)| unsafe {
+    iter.enumerate().for_each(|(y, rgba16)| {
         let y_offset = y * (y_stride as usize);
         let u_offset = if chroma_subsampling == YuvChromaSample::YUV420 {
             (y >> 1) * (u_stride as usize)
@@ -121,39 +138,42 @@ pub(crate) fn yuv_p16_to_image_p16_impl<
             y * (v_stride as usize)
         };
 
-        let y_src_ptr = y_plane.as_ptr() as *const u8;
-        let u_src_ptr = u_plane.as_ptr() as *const u8;
-        let v_src_ptr = v_plane.as_ptr() as *const u8;
-
         let mut x = 0usize;
         let mut cx = 0usize;
 
-        let y_ld_ptr = y_src_ptr.add(y_offset) as *const u16;
-        let u_ld_ptr = u_src_ptr.add(u_offset) as *const u16;
-        let v_ld_ptr = v_src_ptr.add(v_offset) as *const u16;
-
-        let dst = rgba16.as_mut_ptr() as *mut u16;
+        let y_src = &y_plane[y_offset / 2..];
+        let u_src = &u_plane[u_offset / 2..];
+        let v_src = &v_plane[v_offset / 2..];
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        // Kernel widens chroma pairwise and keeps 6-bit products in 16-bit lanes
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        if chroma_subsampling != YuvChromaSample::YUV444
+            && bit_depth as u32 <= P16_SIMD_MAX_BIT_DEPTH
         {
-            let offset = neon_yuv_p16_to_rgba16_row::<
-                DESTINATION_CHANNELS,
-                SAMPLING,
-                ENDIANNESS,
-                BYTES_POSITION,
-            >(
-                y_ld_ptr,
-                u_ld_ptr,
-                v_ld_ptr,
-                dst,
-                dst_offset,
-                width,
-                &range,
-                &i_transform,
-                x,
-                cx,
-                bit_depth,
-            );
+            let offset = unsafe {
+                neon_yuv_p16_to_rgba16_row::<
+                    DESTINATION_CHANNELS,
+                    SAMPLING,
+                    ENDIANNESS,
+                    BYTES_POSITION,
+                >(
+                    y_src.as_ptr(),
+                    u_src.as_ptr(),
+                    v_src.as_ptr(),
+                    rgba16.as_mut_ptr(),
+                    dst_offset,
+                    width,
+                    &range,
+                    &i_transform,
+                    x,
+                    cx,
+                    bit_depth,
+                )
+            };
             x = offset.cx;
             cx = offset.ux;
         }
@@ -164,9 +184,9 @@ pub(crate) fn yuv_p16_to_image_p16_impl<
             let cr_value: i32;
             match endianness {
                 YuvEndianness::BigEndian => {
-                    let mut y_vl = u16::from_be(y_ld_ptr.add(x).read_unaligned()) as i32;
-                    let mut cb_vl = u16::from_be(u_ld_ptr.add(cx).read_unaligned()) as i32;
-                    let mut cr_vl = u16::from_be(v_ld_ptr.add(cx).read_unaligned()) as i32;
+                    let mut y_vl = u16::from_be(y_src[x]) as i32;
+                    let mut cb_vl = u16::from_be(u_src[cx]) as i32;
+                    let mut cr_vl = u16::from_be(v_src[cx]) as i32;
                     if bytes_position == YuvBytesPacking::MostSignificantBytes {
                         y_vl >>= msb_shift;
                         cb_vl >>= msb_shift;
@@ -178,9 +198,9 @@ pub(crate) fn yuv_p16_to_image_p16_impl<
                     cr_value = cr_vl - bias_uv;
                 }
                 YuvEndianness::LittleEndian => {
-                    let mut y_vl = u16::from_le(y_ld_ptr.add(x).read_unaligned()) as i32;
-                    let mut cb_vl = u16::from_le(u_ld_ptr.add(cx).read_unaligned()) as i32;
-                    let mut cr_vl = u16::from_le(v_ld_ptr.add(cx).read_unaligned()) as i32;
+                    let mut y_vl = u16::from_le(y_src[x]) as i32;
+                    let mut cb_vl = u16::from_le(u_src[cx]) as i32;
+                    let mut cr_vl = u16::from_le(v_src[cx]) as i32;
                     if bytes_position == YuvBytesPacking::MostSignificantBytes {
                         y_vl >>= msb_shift;
                         cb_vl >>= msb_shift;
@@ -193,9 +213,9 @@ pub(crate) fn yuv_p16_to_image_p16_impl<
                 }
             }
 
-            let r_u16 = (y_value + cr_coef * cr_value + ROUNDING_CONST) >> store_shift;
-            let b_u16 = (y_value + cb_coef * cb_value + ROUNDING_CONST) >> store_shift;
-            let g_u16 = (y_value - g_coef_1 * cr_value - g_coef_2 * cb_value + ROUNDING_CONST)
+            let r_u16 = (y_value + cr_coef * cr_value + rounding_const) >> store_shift;
+            let b_u16 = (y_value + cb_coef * cb_value + rounding_const) >> store_shift;
+            let g_u16 = (y_value - g_coef_1 * cr_value - g_coef_2 * cb_value + rounding_const)
                 >> store_shift;
 
             let r = r_u16.min(max_range_p16 as i32).max(0);
@@ -206,35 +226,27 @@ pub(crate) fn yuv_p16_to_image_p16_impl<
 
             let rgb_offset = dst_offset + px;
 
-            let dst_slice = dst.add(rgb_offset);
-            dst_slice
-                .add(dst_chans.get_b_channel_offset())
-                .write_unaligned(b as u16);
-            dst_slice
-                .add(dst_chans.get_g_channel_offset())
-                .write_unaligned(g as u16);
-            dst_slice
-                .add(dst_chans.get_r_channel_offset())
-                .write_unaligned(r as u16);
+            let dst_slice = &mut rgba16[rgb_offset..];
+            dst_slice[dst_chans.get_b_channel_offset()] = b as u16;
+            dst_slice[dst_chans.get_g_channel_offset()] = g as u16;
+            dst_slice[dst_chans.get_r_channel_offset()] = r as u16;
             if dst_chans.has_alpha() {
-                dst_slice
-                    .add(dst_chans.get_a_channel_offset())
-                    .write_unaligned(max_range_p16 as u16);
+                dst_slice[dst_chans.get_a_channel_offset()] = max_range_p16 as u16;
             }
 
             x += 1;
 
-            if x + 1 < width as usize {
+            if chroma_subsampling != YuvChromaSample::YUV444 && x < width as usize {
                 let y_value: i32 = match endianness {
                     YuvEndianness::BigEndian => {
-                        let mut y_vl = u16::from_be(y_ld_ptr.add(x).read_unaligned()) as i32;
+                        let mut y_vl = u16::from_be(y_src[x]) as i32;
                         if bytes_position == YuvBytesPacking::MostSignificantBytes {
                             y_vl >>= msb_shift;
                         }
                         (y_vl - bias_y) * y_coef
                     }
                     YuvEndianness::LittleEndian => {
-                        let mut y_vl = u16::from_le(y_ld_ptr.add(x).read_unaligned()) as i32;
+                        let mut y_vl = u16::from_le(y_src[x]) as i32;
                         if bytes_position == YuvBytesPacking::MostSignificantBytes {
                             y_vl >>= msb_shift;
                         }
@@ -242,9 +254,9 @@ pub(crate) fn yuv_p16_to_image_p16_impl<
                     }
                 };
 
-                let r_u16 = (y_value + cr_coef * cr_value + ROUNDING_CONST) >> store_shift;
-                let b_u16 = (y_value + cb_coef * cb_value + ROUNDING_CONST) >> store_shift;
-                let g_u16 = (y_value - g_coef_1 * cr_value - g_coef_2 * cb_value + ROUNDING_CONST)
+                let r_u16 = (y_value + cr_coef * cr_value + rounding_const) >> store_shift;
+                let b_u16 = (y_value + cb_coef * cb_value + rounding_const) >> store_shift;
+                let g_u16 = (y_value - g_coef_1 * cr_value - g_coef_2 * cb_value + rounding_const)
                     >> store_shift;
 
                 let r = r_u16.min(max_range_p16 as i32).max(0);
@@ -253,27 +265,22 @@ pub(crate) fn yuv_p16_to_image_p16_impl<
 
                 let px = x * channels;
                 let rgb_offset = dst_offset + px;
-                let dst_slice = dst.add(rgb_offset);
-                dst_slice
-                    .add(dst_chans.get_b_channel_offset())
-                    .write_unaligned(b as u16);
-                dst_slice
-                    .add(dst_chans.get_g_channel_offset())
-                    .write_unaligned(g as u16);
-                dst_slice
-                    .add(dst_chans.get_r_channel_offset())
-                    .write_unaligned(r as u16);
+                let dst_slice = &mut rgba16[rgb_offset..];
+                dst_slice[dst_chans.get_b_channel_offset()] = b as u16;
+                dst_slice[dst_chans.get_g_channel_offset()] = g as u16;
+                dst_slice[dst_chans.get_r_channel_offset()] = r as u16;
                 if dst_chans.has_alpha() {
-                    dst_slice
-                        .add(dst_chans.get_a_channel_offset())
-                        .write_unaligned(max_range_p16 as u16);
+                    dst_slice[dst_chans.get_a_channel_offset()] = max_range_p16 as u16;
                 }
+
+                x += 1;
             }
 
-            x += 1;
             cx += 1;
         }
     });
+
+    Ok(())
 }
 
 /// Convert YUV 420 planar format with 8+ bit pixel format to BGRA 8+ bit-depth format.
@@ -297,7 +304,7 @@ pub(crate) fn yuv_p16_to_image_p16_impl<
 /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
 /// * `endianness` - The endianness of stored bytes
 /// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
-/// * `bit_depth` - Bit depth of source YUV planes
+/// * `bit_depth` - Bit depth of source YUV planes, from 8 to 16.
 ///
 /// # Panics
 ///
@@ -320,45 +327,15 @@ pub fn yuv420_p16_to_bgra16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_p16_impl,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -373,7 +350,7 @@ pub fn yuv420_p16_to_bgra16(
         range,
         matrix,
         bit_depth,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format with 8+ bit pixel format to BGR 8+ bit-depth format.
@@ -397,7 +374,7 @@ pub fn yuv420_p16_to_bgra16(
 /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
 /// * `endianness` - The endianness of stored bytes
 /// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
-/// * `bit_depth` - Bit depth of source YUV planes
+/// * `bit_depth` - Bit depth of source YUV planes, from 8 to 16.
 ///
 /// # Panics
 ///
@@ -420,49 +397,19 @@ pub fn yuv420_p16_to_bgr16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_p16_impl,
+        [{ YuvSourceChannels::Bgr as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
         range, matrix, bit_depth,
-    );
+    )
 }
 
 /// Convert YUV 422 format with 8+ bit pixel format to BGRA format 8+ bit-depth format.
@@ -486,7 +433,7 @@ pub fn yuv420_p16_to_bgr16(
 /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
 /// * `endianness` - The endianness of stored bytes
 /// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
-/// * `bit_depth` - Bit depth of source YUV planes
+/// * `bit_depth` - Bit depth of source YUV planes, from 8 to 16.
 ///
 ///
 /// # Panics
@@ -510,45 +457,15 @@ pub fn yuv422_p16_to_bgra16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_p16_impl,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -563,7 +480,7 @@ pub fn yuv422_p16_to_bgra16(
         range,
         matrix,
         bit_depth,
-    );
+    )
 }
 
 /// Convert YUV 422 format with 8+ bit pixel format to BGR format 8+ bit-depth format.
@@ -587,7 +504,7 @@ pub fn yuv422_p16_to_bgra16(
 /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
 /// * `endianness` - The endianness of stored bytes
 /// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
-/// * `bit_depth` - Bit depth of source YUV planes
+/// * `bit_depth` - Bit depth of source YUV planes, from 8 to 16.
 ///
 ///
 /// # Panics
@@ -611,49 +528,19 @@ pub fn yuv422_p16_to_bgr16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_p16_impl,
+        [{ YuvSourceChannels::Bgr as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
         range, matrix, bit_depth,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format with 8+ bit pixel format to RGBA format 8+ bit-depth format.
@@ -677,7 +564,7 @@ pub fn yuv422_p16_to_bgr16(
 /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
 /// * `endianness` - The endianness of stored bytes
 /// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
-/// * `bit_depth` - Bit depth of source YUV planes
+/// * `bit_depth` - Bit depth of source YUV planes, from 8 to 16.
 ///
 /// # Panics
 ///
@@ -700,45 +587,15 @@ pub fn yuv420_p16_to_rgba16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_p16_impl,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -753,7 +610,7 @@ pub fn yuv420_p16_to_rgba16(
         range,
         matrix,
         bit_depth,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format with 8+ bit pixel format to RGB format 8+ bit-depth format.
@@ -777,7 +634,7 @@ pub fn yuv420_p16_to_rgba16(
 /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
 /// * `endianness` - The endianness of stored bytes
 /// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
-/// * `bit_depth` - Bit depth of source YUV planes
+/// * `bit_depth` - Bit depth of source YUV planes, from 8 to 16.
 ///
 /// # Panics
 ///
@@ -800,49 +657,19 @@ pub fn yuv420_p16_to_rgb16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV420 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_p16_impl,
+        [{ YuvSourceChannels::Rgb as u8 }, {
+            YuvChromaSample::YUV420 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
         range, matrix, bit_depth,
-    );
+    )
 }
 
 /// Convert YUV 422 format with 8+ bit pixel format to RGBA format 8+ bit-depth format.
@@ -866,7 +693,7 @@ pub fn yuv420_p16_to_rgb16(
 /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
 /// * `endianness` - The endianness of stored bytes
 /// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
-/// * `bit_depth` - Bit depth of source YUV planes
+/// * `bit_depth` - Bit depth of source YUV planes, from 8 to 16.
 ///
 /// # Panics
 ///
@@ -889,45 +716,15 @@ pub fn yuv422_p16_to_rgba16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_p16_impl,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -942,7 +739,7 @@ pub fn yuv422_p16_to_rgba16(
         range,
         matrix,
         bit_depth,
-    );
+    )
 }
 
 /// Convert YUV 422 format with 8+ bit pixel format to RGB format 8+ bit-depth format.
@@ -966,7 +763,7 @@ pub fn yuv422_p16_to_rgba16(
 /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
 /// * `endianness` - The endianness of stored bytes
 /// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
-/// * `bit_depth` - Bit depth of source YUV planes
+/// * `bit_depth` - Bit depth of source YUV planes, from 8 to 16.
 ///
 /// # Panics
 ///
@@ -989,49 +786,19 @@ pub fn yuv422_p16_to_rgb16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV422 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_p16_impl,
+        [{ YuvSourceChannels::Rgb as u8 }, {
+            YuvChromaSample::YUV422 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
         range, matrix, bit_depth,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format with 8+ bit pixel format to RGBA format 8+ bit-depth format.
@@ -1055,7 +822,7 @@ pub fn yuv422_p16_to_rgb16(
 /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
 /// * `endianness` - The endianness of stored bytes
 /// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
-/// * `bit_depth` - Bit depth of source YUV planes
+/// * `bit_depth` - Bit depth of source YUV planes, from 8 to 16.
 ///
 /// # Panics
 ///
@@ -1078,45 +845,15 @@ pub fn yuv444_p16_to_rgba16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgba as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_p16_impl,
+        [{ YuvSourceChannels::Rgba as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1131,7 +868,7 @@ pub fn yuv444_p16_to_rgba16(
         range,
         matrix,
         bit_depth,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format with 8+ bit pixel format to RGB format 8+ bit-depth format.
@@ -1155,7 +892,7 @@ pub fn yuv444_p16_to_rgba16(
 /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
 /// * `endianness` - The endianness of stored bytes
 /// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
-/// * `bit_depth` - Bit depth of source YUV planes
+/// * `bit_depth` - Bit depth of source YUV planes, from 8 to 16.
 ///
 /// # Panics
 ///
@@ -1178,49 +915,19 @@ pub fn yuv444_p16_to_rgb16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Rgb as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_p16_impl,
+        [{ YuvSourceChannels::Rgb as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
         range, matrix, bit_depth,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format with 8+ bit pixel format to BGRA format 8+ bit-depth format.
@@ -1244,7 +951,7 @@ pub fn yuv444_p16_to_rgb16(
 /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
 /// * `endianness` - The endianness of stored bytes
 /// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
-/// * `bit_depth` - Bit depth of source YUV planes
+/// * `bit_depth` - Bit depth of source YUV planes, from 8 to 16.
 ///
 /// # Panics
 ///
@@ -1267,45 +974,15 @@ pub fn yuv444_p16_to_bgra16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgra as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_p16_impl,
+        [{ YuvSourceChannels::Bgra as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane,
         y_stride,
@@ -1320,7 +997,7 @@ pub fn yuv444_p16_to_bgra16(
         range,
         matrix,
         bit_depth,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format with 8+ bit pixel format to BGR format 8+ bit-depth format.
@@ -1344,7 +1021,7 @@ pub fn yuv444_p16_to_bgra16(
 /// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
 /// * `endianness` - The endianness of stored bytes
 /// * `bytes_packing` - position of significant bytes ( most significant or least significant ) if it in most significant it should be stated as per Apple *kCVPixelFormatType_422YpCbCr10BiPlanarFullRange/kCVPixelFormatType_422YpCbCr10BiPlanarVideoRange*
-/// * `bit_depth` - Bit depth of source YUV planes
+/// * `bit_depth` - Bit depth of source YUV planes, from 8 to 16.
 ///
 /// # Panics
 ///
@@ -1367,47 +1044,17 @@ pub fn yuv444_p16_to_bgr16(
     matrix: YuvStandardMatrix,
     endianness: YuvEndianness,
     bytes_packing: YuvBytesPacking,
-) {
-    let dispatcher = match endianness {
-        YuvEndianness::BigEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::BigEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-        YuvEndianness::LittleEndian => match bytes_packing {
-            YuvBytesPacking::MostSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::MostSignificantBytes as u8 },
-                >
-            }
-            YuvBytesPacking::LeastSignificantBytes => {
-                yuv_p16_to_image_p16_impl::<
-                    { YuvSourceChannels::Bgr as u8 },
-                    { YuvChromaSample::YUV444 as u8 },
-                    { YuvEndianness::LittleEndian as u8 },
-                    { YuvBytesPacking::LeastSignificantBytes as u8 },
-                >
-            }
-        },
-    };
+) -> Result<(), YuvError> {
+    let dispatcher = p16_dispatch!(
+        yuv_p16_to_image_p16_impl,
+        [{ YuvSourceChannels::Bgr as u8 }, {
+            YuvChromaSample::YUV444 as u8
+        },],
+        endianness,
+        bytes_packing
+    );
     dispatcher(
         y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
         range, matrix, bit_depth,
-    );
+    )
 }
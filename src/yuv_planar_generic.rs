@@ -0,0 +1,699 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::batch_tensor::TensorLayout;
+use crate::yuv_error::{
+    check_chroma_source_channel, check_rgba_destination, check_y8_channel, MismatchedSize,
+};
+use crate::yuv_support::{
+    get_inverse_transform, get_yuv_range, Rgb30, Rgb30Padding, YuvBytesPacking, YuvChromaSample,
+    YuvEndianness, YuvNVOrder, YuvOvershoot, YuvRange, YuvSourceChannels, YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+use std::mem::size_of;
+
+/// Storage type of a single plane sample, either `u8` or `u16`
+pub(crate) trait Pixel: Copy + Send + Sync {
+    /// Decodes a stored sample and returns it as value in `bit_depth` range.
+    fn load<const ENDIANNESS: u8, const BYTES_POSITION: u8>(self, bit_depth: usize) -> i32;
+
+    /// Narrows an already clamped value into storage type.
+    fn from_i32(v: i32) -> Self;
+}
+
+impl Pixel for u8 {
+    #[inline(always)]
+    fn load<const ENDIANNESS: u8, const BYTES_POSITION: u8>(self, _: usize) -> i32 {
+        self as i32
+    }
+
+    #[inline(always)]
+    fn from_i32(v: i32) -> Self {
+        v as u8
+    }
+}
+
+impl Pixel for u16 {
+    #[inline(always)]
+    fn load<const ENDIANNESS: u8, const BYTES_POSITION: u8>(self, bit_depth: usize) -> i32 {
+        let endianness: YuvEndianness = ENDIANNESS.into();
+        let bytes_position: YuvBytesPacking = BYTES_POSITION.into();
+        let mut v = match endianness {
+            YuvEndianness::BigEndian => u16::from_be(self),
+            YuvEndianness::LittleEndian => u16::from_le(self),
+        };
+        if bytes_position == YuvBytesPacking::MostSignificantBytes {
+            v >>= 16 - bit_depth;
+        }
+        v as i32
+    }
+
+    #[inline(always)]
+    fn from_i32(v: i32) -> Self {
+        v as u16
+    }
+}
+
+/// Fixed point inverse transform shared by generic planar walkers.
+///
+/// Transform is done with 12 bits of fractional precision so any source and destination
+/// bit depth up to 16 shares the same rounding behaviour.
+///
+/// When overshoot is preserved coefficients are scaled down and `offset` lifts nominal black
+/// so the whole source code range maps linearly onto destination range.
+pub(crate) struct PlanarRgbTransform {
+    cr_coef: i32,
+    cb_coef: i32,
+    y_coef: i32,
+    g_coef_1: i32,
+    g_coef_2: i32,
+    bias_y: i32,
+    bias_uv: i32,
+    offset: i32,
+    max_colors: i32,
+}
+
+impl PlanarRgbTransform {
+    const PRECISION: i32 = 12;
+    const ROUNDING_CONST: i32 = 1 << (Self::PRECISION - 1);
+
+    pub(crate) fn new(
+        range: YuvRange,
+        matrix: YuvStandardMatrix,
+        src_bit_depth: usize,
+        dst_bit_depth: usize,
+        overshoot: YuvOvershoot,
+    ) -> PlanarRgbTransform {
+        let range = get_yuv_range(src_bit_depth as u32, range);
+        let kr_kb = matrix.get_kr_kb();
+        let max_colors = (1i32 << dst_bit_depth) - 1;
+        let mut transform = get_inverse_transform(
+            max_colors as u32,
+            range.range_y,
+            range.range_uv,
+            kr_kb.kr,
+            kr_kb.kb,
+        );
+        let mut offset = 0i32;
+        if overshoot == YuvOvershoot::PreserveOvershoot {
+            let full_code = ((1u32 << src_bit_depth) - 1) as f32;
+            let scale = range.range_y as f32 / full_code;
+            transform.y_coef *= scale;
+            transform.cr_coef *= scale;
+            transform.cb_coef *= scale;
+            transform.g_coeff_1 *= scale;
+            transform.g_coeff_2 *= scale;
+            offset = (range.bias_y as f32 * max_colors as f32 / full_code
+                * (1 << Self::PRECISION) as f32)
+                .round() as i32;
+        }
+        let i_transform = transform.to_integers(Self::PRECISION as u32);
+        PlanarRgbTransform {
+            cr_coef: i_transform.cr_coef,
+            cb_coef: i_transform.cb_coef,
+            y_coef: i_transform.y_coef,
+            g_coef_1: i_transform.g_coeff_1,
+            g_coef_2: i_transform.g_coeff_2,
+            bias_y: range.bias_y as i32,
+            bias_uv: range.bias_uv as i32,
+            offset,
+            max_colors,
+        }
+    }
+
+    /// Converts a single decoded Y, Cb, Cr triplet into clamped R, G, B.
+    #[inline(always)]
+    pub(crate) fn to_rgb(&self, y: i32, cb: i32, cr: i32) -> (i32, i32, i32) {
+        let y_value = (y - self.bias_y) * self.y_coef + self.offset;
+        let cb_value = cb - self.bias_uv;
+        let cr_value = cr - self.bias_uv;
+
+        let r = ((y_value + self.cr_coef * cr_value + Self::ROUNDING_CONST) >> Self::PRECISION)
+            .min(self.max_colors)
+            .max(0);
+        let b = ((y_value + self.cb_coef * cb_value + Self::ROUNDING_CONST) >> Self::PRECISION)
+            .min(self.max_colors)
+            .max(0);
+        let g = ((y_value - self.g_coef_1 * cr_value - self.g_coef_2 * cb_value
+            + Self::ROUNDING_CONST)
+            >> Self::PRECISION)
+            .min(self.max_colors)
+            .max(0);
+        (r, g, b)
+    }
+
+    /// Converts row `y` of the planar image, `store` receives pixel index and R, G, B values.
+    #[inline(always)]
+    fn convert_row<S: Pixel, const SAMPLING: u8, const ENDIANNESS: u8, const BYTES_POSITION: u8>(
+        &self,
+        y: usize,
+        y_plane: &[S],
+        y_stride: u32,
+        u_plane: &[S],
+        u_stride: u32,
+        v_plane: &[S],
+        v_stride: u32,
+        width: u32,
+        src_bit_depth: usize,
+        mut store: impl FnMut(usize, i32, i32, i32),
+    ) {
+        let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+        let y_offset = y * y_stride as usize;
+        let (u_offset, v_offset) = match chroma_subsampling {
+            YuvChromaSample::YUV420 => ((y >> 1) * u_stride as usize, (y >> 1) * v_stride as usize),
+            YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => {
+                (y * u_stride as usize, y * v_stride as usize)
+            }
+        };
+
+        let y_src = &y_plane[y_offset / size_of::<S>()..];
+        let u_src = &u_plane[u_offset / size_of::<S>()..];
+        let v_src = &v_plane[v_offset / size_of::<S>()..];
+
+        for (x, &y_vl) in y_src.iter().take(width as usize).enumerate() {
+            let cx = match chroma_subsampling {
+                YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => x >> 1,
+                YuvChromaSample::YUV444 => x,
+            };
+
+            let (r, g, b) = self.to_rgb(
+                y_vl.load::<ENDIANNESS, BYTES_POSITION>(src_bit_depth),
+                u_src[cx].load::<ENDIANNESS, BYTES_POSITION>(src_bit_depth),
+                v_src[cx].load::<ENDIANNESS, BYTES_POSITION>(src_bit_depth),
+            );
+
+            store(x, r, g, b);
+        }
+    }
+}
+
+/// Walks planar YUV image and hands out every pixel converted to RGB at `dst_bit_depth`.
+///
+/// `store` receives destination row, pixel index and R, G, B values.
+pub(crate) fn yuv_planar_to_rgb_generic<
+    S: Pixel,
+    D: Copy + Send + Sync,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+>(
+    y_plane: &[S],
+    y_stride: u32,
+    u_plane: &[S],
+    u_stride: u32,
+    v_plane: &[S],
+    v_stride: u32,
+    dst: &mut [D],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    src_bit_depth: usize,
+    dst_bit_depth: usize,
+    overshoot: YuvOvershoot,
+    store: impl Fn(&mut [D], usize, i32, i32, i32) + Send + Sync,
+) {
+    let transform = PlanarRgbTransform::new(
+        range,
+        matrix.resolve(width, height),
+        src_bit_depth,
+        dst_bit_depth,
+        overshoot,
+    );
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride as usize / size_of::<D>())
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride as usize / size_of::<D>())
+            .take(height as usize);
+    }
+
+    iter.enumerate().for_each(|(y, dst_row)| {
+        transform.convert_row::<S, SAMPLING, ENDIANNESS, BYTES_POSITION>(
+            y,
+            y_plane,
+            y_stride,
+            u_plane,
+            u_stride,
+            v_plane,
+            v_stride,
+            width,
+            src_bit_depth,
+            |x, r, g, b| store(dst_row, x, r, g, b),
+        );
+    });
+}
+
+/// Same as [yuv_planar_to_rgb_generic] for bi-planar images with interleaved chroma plane.
+///
+/// `store` receives destination row, pixel index and R, G, B values.
+pub(crate) fn yuv_biplanar_to_rgb_generic<
+    S: Pixel,
+    D: Copy + Send + Sync,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+>(
+    y_plane: &[S],
+    y_stride: u32,
+    uv_plane: &[S],
+    uv_stride: u32,
+    order: YuvNVOrder,
+    dst: &mut [D],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    src_bit_depth: usize,
+    dst_bit_depth: usize,
+    store: impl Fn(&mut [D], usize, i32, i32, i32) + Send + Sync,
+) {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    let transform = PlanarRgbTransform::new(
+        range,
+        matrix.resolve(width, height),
+        src_bit_depth,
+        dst_bit_depth,
+        YuvOvershoot::Clamp,
+    );
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride as usize / size_of::<D>())
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride as usize / size_of::<D>())
+            .take(height as usize);
+    }
+
+    iter.enumerate().for_each(|(y, dst_row)| {
+        let uv_y = match chroma_subsampling {
+            YuvChromaSample::YUV420 => y >> 1,
+            YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => y,
+        };
+        let y_src = &y_plane[y * y_stride as usize / size_of::<S>()..];
+        let uv_src = &uv_plane[uv_y * uv_stride as usize / size_of::<S>()..];
+
+        for (x, &y_vl) in y_src.iter().take(width as usize).enumerate() {
+            let cx = match chroma_subsampling {
+                YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => x >> 1,
+                YuvChromaSample::YUV444 => x,
+            };
+            let uv = &uv_src[cx * 2..cx * 2 + 2];
+
+            let (r, g, b) = transform.to_rgb(
+                y_vl.load::<ENDIANNESS, BYTES_POSITION>(src_bit_depth),
+                uv[order.get_u_position()].load::<ENDIANNESS, BYTES_POSITION>(src_bit_depth),
+                uv[order.get_v_position()].load::<ENDIANNESS, BYTES_POSITION>(src_bit_depth),
+            );
+
+            store(dst_row, x, r, g, b);
+        }
+    });
+}
+
+/// Generic planar YUV to interleaved RGB(A) conversion over any combination of `u8` and `u16`
+/// storage. Alpha, if requested, is always opaque.
+pub(crate) fn yuv_planar_to_rgbx<
+    S: Pixel,
+    D: Pixel,
+    const DESTINATION_CHANNELS: u8,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+>(
+    y_plane: &[S],
+    y_stride: u32,
+    u_plane: &[S],
+    u_stride: u32,
+    v_plane: &[S],
+    v_stride: u32,
+    rgba: &mut [D],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    src_bit_depth: usize,
+    dst_bit_depth: usize,
+    overshoot: YuvOvershoot,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_source_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
+    let max_colors = (1i32 << dst_bit_depth) - 1;
+
+    yuv_planar_to_rgb_generic::<S, D, SAMPLING, ENDIANNESS, BYTES_POSITION>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        src_bit_depth,
+        dst_bit_depth,
+        overshoot,
+        |dst, x, r, g, b| {
+            let dst = &mut dst[x * channels..];
+            dst[dst_chans.get_r_channel_offset()] = D::from_i32(r);
+            dst[dst_chans.get_g_channel_offset()] = D::from_i32(g);
+            dst[dst_chans.get_b_channel_offset()] = D::from_i32(b);
+            if dst_chans.has_alpha() {
+                dst[dst_chans.get_a_channel_offset()] = D::from_i32(max_colors);
+            }
+        },
+    );
+
+    Ok(())
+}
+
+/// Generic planar YUV to 30-bit packed RGB conversion, top two bits are filled as `padding` says.
+pub(crate) fn yuv_planar_to_rgb30<
+    S: Pixel,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+>(
+    y_plane: &[S],
+    y_stride: u32,
+    u_plane: &[S],
+    u_stride: u32,
+    v_plane: &[S],
+    v_stride: u32,
+    rgb30: &mut [u32],
+    rgb30_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    src_bit_depth: usize,
+    layout: Rgb30,
+    padding: Rgb30Padding,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+
+    check_rgba_destination(rgb30, rgb30_stride, width, height, 1)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_source_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
+    yuv_planar_to_rgb_generic::<S, u32, SAMPLING, ENDIANNESS, BYTES_POSITION>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgb30,
+        rgb30_stride,
+        width,
+        height,
+        range,
+        matrix,
+        src_bit_depth,
+        10,
+        YuvOvershoot::Clamp,
+        |dst, x, r, g, b| {
+            dst[x] = layout.pack(r as u32, g as u32, b as u32, padding).to_le();
+        },
+    );
+
+    Ok(())
+}
+
+/// 8x8 Bayer matrix used for ordered dithering
+pub(crate) const BAYER_8X8: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+/// Generic planar YUV to RGBA16 conversion that also writes an ordered dithered RGBA8 copy
+/// of the same pixels in a single pass. Alpha is always opaque.
+pub(crate) fn yuv_planar_to_rgba16_with_rgba8<
+    S: Pixel,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+>(
+    y_plane: &[S],
+    y_stride: u32,
+    u_plane: &[S],
+    u_stride: u32,
+    v_plane: &[S],
+    v_stride: u32,
+    rgba16: &mut [u16],
+    rgba16_stride: u32,
+    rgba8: &mut [u8],
+    rgba8_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    src_bit_depth: usize,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    let channels = YuvSourceChannels::Rgba.get_channels_count();
+
+    check_rgba_destination(rgba16, rgba16_stride, width, height, channels)?;
+    check_rgba_destination(rgba8, rgba8_stride, width, height, channels)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_source_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
+    let transform = PlanarRgbTransform::new(
+        range,
+        matrix.resolve(width, height),
+        src_bit_depth,
+        16,
+        YuvOvershoot::Clamp,
+    );
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba16
+            .par_chunks_mut(rgba16_stride as usize / 2)
+            .take(height as usize)
+            .zip(rgba8.par_chunks_mut(rgba8_stride as usize));
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba16
+            .chunks_mut(rgba16_stride as usize / 2)
+            .take(height as usize)
+            .zip(rgba8.chunks_mut(rgba8_stride as usize));
+    }
+
+    iter.enumerate().for_each(|(y, (rgba16_row, rgba8_row))| {
+        // Thresholds are centered in each of 64 buckets so the mean matches plain rounding
+        let thresholds = BAYER_8X8[y & 7].map(|v| (v as u32 * 2 + 1) * 65535 / 128);
+        transform.convert_row::<S, SAMPLING, ENDIANNESS, BYTES_POSITION>(
+            y,
+            y_plane,
+            y_stride,
+            u_plane,
+            u_stride,
+            v_plane,
+            v_stride,
+            width,
+            src_bit_depth,
+            |x, r, g, b| {
+                let dst16 = &mut rgba16_row[x * channels..x * channels + channels];
+                dst16[0] = r as u16;
+                dst16[1] = g as u16;
+                dst16[2] = b as u16;
+                dst16[3] = u16::MAX;
+
+                let threshold = thresholds[x & 7];
+                let dst8 = &mut rgba8_row[x * channels..x * channels + channels];
+                dst8[0] = ((r as u32 * 255 + threshold) / 65535) as u8;
+                dst8[1] = ((g as u32 * 255 + threshold) / 65535) as u8;
+                dst8[2] = ((b as u32 * 255 + threshold) / 65535) as u8;
+                dst8[3] = u8::MAX;
+            },
+        );
+    });
+
+    Ok(())
+}
+
+/// Generic planar YUV to RGB float conversion, planar or interleaved per `layout`.
+///
+/// Every channel value at `bit_depth` is mapped through its own table of `2^bit_depth`
+/// entries, so normalization costs a single load per sample.
+pub(crate) fn yuv_planar_to_rgb_f32<
+    S: Pixel,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+>(
+    y_plane: &[S],
+    y_stride: u32,
+    u_plane: &[S],
+    u_stride: u32,
+    v_plane: &[S],
+    v_stride: u32,
+    dst: &mut [f32],
+    layout: TensorLayout,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    bit_depth: usize,
+    luts: &[Vec<f32>; 3],
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_source_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+    let plane_size = width as usize * height as usize;
+    if dst.len() != plane_size * 3 {
+        return Err(YuvError::DestinationSizeMismatch(MismatchedSize {
+            expected: plane_size * 3,
+            received: dst.len(),
+        }));
+    }
+
+    let transform = PlanarRgbTransform::new(
+        range,
+        matrix.resolve(width, height),
+        bit_depth,
+        bit_depth,
+        YuvOvershoot::Clamp,
+    );
+    let [r_lut, g_lut, b_lut] = luts;
+    match layout {
+        TensorLayout::Chw => {
+            let (r_plane, gb_planes) = dst.split_at_mut(plane_size);
+            let (g_plane, b_plane) = gb_planes.split_at_mut(plane_size);
+
+            let iter;
+            #[cfg(feature = "rayon")]
+            {
+                iter = r_plane
+                    .par_chunks_mut(width as usize)
+                    .zip(g_plane.par_chunks_mut(width as usize))
+                    .zip(b_plane.par_chunks_mut(width as usize));
+            }
+            #[cfg(not(feature = "rayon"))]
+            {
+                iter = r_plane
+                    .chunks_mut(width as usize)
+                    .zip(g_plane.chunks_mut(width as usize))
+                    .zip(b_plane.chunks_mut(width as usize));
+            }
+            iter.enumerate().for_each(|(y, ((r_row, g_row), b_row))| {
+                transform.convert_row::<S, SAMPLING, ENDIANNESS, BYTES_POSITION>(
+                    y,
+                    y_plane,
+                    y_stride,
+                    u_plane,
+                    u_stride,
+                    v_plane,
+                    v_stride,
+                    width,
+                    bit_depth,
+                    |x, r, g, b| {
+                        r_row[x] = r_lut[r as usize];
+                        g_row[x] = g_lut[g as usize];
+                        b_row[x] = b_lut[b as usize];
+                    },
+                );
+            });
+        }
+        TensorLayout::Hwc => {
+            let iter;
+            #[cfg(feature = "rayon")]
+            {
+                iter = dst.par_chunks_mut(width as usize * 3);
+            }
+            #[cfg(not(feature = "rayon"))]
+            {
+                iter = dst.chunks_mut(width as usize * 3);
+            }
+            iter.enumerate().for_each(|(y, row)| {
+                transform.convert_row::<S, SAMPLING, ENDIANNESS, BYTES_POSITION>(
+                    y,
+                    y_plane,
+                    y_stride,
+                    u_plane,
+                    u_stride,
+                    v_plane,
+                    v_stride,
+                    width,
+                    bit_depth,
+                    |x, r, g, b| {
+                        let dst = &mut row[x * 3..x * 3 + 3];
+                        dst[0] = r_lut[r as usize];
+                        dst[1] = g_lut[g as usize];
+                        dst[2] = b_lut[b as usize];
+                    },
+                );
+            });
+        }
+    }
+
+    Ok(())
+}
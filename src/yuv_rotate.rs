@@ -0,0 +1,405 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{try_vec, YuvBiPlanarImage, YuvPlanarImage};
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_nv_to_rgba::yuv_nv_to_rgbx;
+use crate::yuv_support::{
+    YuvChromaSample, YuvNVOrder, YuvRange, YuvSourceChannels, YuvStandardMatrix,
+};
+use crate::yuv_to_rgba::yuv_to_rgbx;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
+/// Quarter turn applied to an image while it is converted
+pub enum YuvRotation {
+    /// Top row of the source becomes the right column of the destination
+    Clockwise90,
+    /// Top row of the source becomes the left column of the destination
+    CounterClockwise90,
+}
+
+/// Source rows converted at once, every destination row then receives a run of this many pixels
+const ROTATION_STRIP_HEIGHT: usize = 16;
+/// Destination rows written by one task, adjacent rows read adjacent pixels of the strip
+const ROTATION_ROW_BLOCK: usize = 64;
+
+/// Rows `start..start + rows` of a plane, the slice is exactly what
+/// a plane of `rows` rows is expected to hold, stride `0` keeps its single row
+#[inline]
+fn plane_rows(plane: &[u8], stride: usize, start: usize, rows: usize) -> &[u8] {
+    if stride == 0 {
+        return plane;
+    }
+    let begin = (start * stride).min(plane.len());
+    let end = ((start + rows) * stride).min(plane.len());
+    &plane[begin..end]
+}
+
+#[inline]
+pub(crate) fn chroma_rows(sampling: YuvChromaSample, start: usize, rows: usize) -> (usize, usize) {
+    match sampling {
+        YuvChromaSample::YUV420 => (start / 2, rows.div_ceil(2)),
+        YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => (start, rows),
+    }
+}
+
+/// Converts the source strip by strip into a small interleaved buffer that stays in cache,
+/// then scatters each strip as short contiguous runs into the rotated destination.
+fn rotated_rgbx<const DESTINATION_CHANNELS: u8>(
+    width: usize,
+    height: usize,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    rotation: YuvRotation,
+    convert_strip: impl Fn(usize, usize, &mut [u8]) -> Result<(), YuvError>,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+
+    // Destination is `height` pixels wide and `width` rows tall
+    check_rgba_destination(rgba, rgba_stride, height as u32, width as u32, channels)?;
+
+    let strip_stride = width * channels;
+    let mut strip = try_vec(0u8, strip_stride * ROTATION_STRIP_HEIGHT)?;
+    let rgba_stride = rgba_stride as usize;
+
+    for start in (0..height).step_by(ROTATION_STRIP_HEIGHT) {
+        let rows = ROTATION_STRIP_HEIGHT.min(height - start);
+        let strip = &mut strip[..strip_stride * rows];
+        convert_strip(start, rows, strip)?;
+        let strip: &[u8] = strip;
+
+        let block_iter;
+        #[cfg(feature = "rayon")]
+        {
+            block_iter = rgba
+                .par_chunks_mut(rgba_stride * ROTATION_ROW_BLOCK)
+                .take(width.div_ceil(ROTATION_ROW_BLOCK))
+                .enumerate();
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            block_iter = rgba
+                .chunks_mut(rgba_stride * ROTATION_ROW_BLOCK)
+                .take(width.div_ceil(ROTATION_ROW_BLOCK))
+                .enumerate();
+        }
+        block_iter.for_each(|(block, dst_block)| {
+            let first_row = block * ROTATION_ROW_BLOCK;
+            for (row, dst_row) in dst_block
+                .chunks_mut(rgba_stride)
+                .take(width - first_row)
+                .enumerate()
+            {
+                let dst_y = first_row + row;
+                match rotation {
+                    YuvRotation::Clockwise90 => {
+                        // Source column `dst_y`, source row `y` lands at column `height - 1 - y`
+                        let src = &strip[dst_y * channels..];
+                        let run_start = (height - start - rows) * channels;
+                        let run = &mut dst_row[run_start..run_start + rows * channels];
+                        for (dst, src) in run
+                            .chunks_exact_mut(channels)
+                            .rev()
+                            .zip(src.chunks(strip_stride))
+                        {
+                            dst.copy_from_slice(&src[..channels]);
+                        }
+                    }
+                    YuvRotation::CounterClockwise90 => {
+                        // Source column `width - 1 - dst_y`, source row `y` lands at column `y`
+                        let src = &strip[(width - 1 - dst_y) * channels..];
+                        let run_start = start * channels;
+                        let run = &mut dst_row[run_start..run_start + rows * channels];
+                        for (dst, src) in
+                            run.chunks_exact_mut(channels).zip(src.chunks(strip_stride))
+                        {
+                            dst.copy_from_slice(&src[..channels]);
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    Ok(())
+}
+
+fn yuv_to_rgbx_rotated<const DESTINATION_CHANNELS: u8>(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    rotation: YuvRotation,
+) -> Result<(), YuvError> {
+    image.check_constraints(sampling)?;
+    let matrix = matrix.resolve(image.size.width, image.size.height);
+
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => {
+            yuv_to_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+        }
+        YuvChromaSample::YUV422 => {
+            yuv_to_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+        }
+        YuvChromaSample::YUV444 => {
+            yuv_to_rgbx::<DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+        }
+    };
+
+    let width = image.size.width as usize;
+    let y_stride = image.y_stride.get() as usize;
+    let u_stride = image.u_stride.get() as usize;
+    let v_stride = image.v_stride.get() as usize;
+    let channels = YuvSourceChannels::from(DESTINATION_CHANNELS).get_channels_count();
+
+    rotated_rgbx::<DESTINATION_CHANNELS>(
+        width,
+        image.size.height as usize,
+        rgba,
+        rgba_stride,
+        rotation,
+        |start, rows, strip| {
+            let (chroma_start, chroma_rows) = chroma_rows(sampling, start, rows);
+            handler(
+                plane_rows(image.y_plane, y_stride, start, rows),
+                y_stride as u32,
+                plane_rows(image.u_plane, u_stride, chroma_start, chroma_rows),
+                u_stride as u32,
+                plane_rows(image.v_plane, v_stride, chroma_start, chroma_rows),
+                v_stride as u32,
+                strip,
+                (width * channels) as u32,
+                width as u32,
+                rows as u32,
+                range,
+                matrix,
+            )
+        },
+    )
+}
+
+fn yuv_nv_to_rgbx_rotated<const UV_ORDER: u8, const DESTINATION_CHANNELS: u8>(
+    image: &YuvBiPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    rotation: YuvRotation,
+) -> Result<(), YuvError> {
+    image.check_constraints(sampling)?;
+    let matrix = matrix.resolve(image.size.width, image.size.height);
+
+    let handler = match sampling {
+        YuvChromaSample::YUV420 => {
+            yuv_nv_to_rgbx::<UV_ORDER, DESTINATION_CHANNELS, { YuvChromaSample::YUV420 as u8 }>
+        }
+        YuvChromaSample::YUV422 => {
+            yuv_nv_to_rgbx::<UV_ORDER, DESTINATION_CHANNELS, { YuvChromaSample::YUV422 as u8 }>
+        }
+        YuvChromaSample::YUV444 => {
+            yuv_nv_to_rgbx::<UV_ORDER, DESTINATION_CHANNELS, { YuvChromaSample::YUV444 as u8 }>
+        }
+    };
+
+    let width = image.size.width as usize;
+    let y_stride = image.y_stride.get() as usize;
+    let uv_stride = image.uv_stride.get() as usize;
+    let channels = YuvSourceChannels::from(DESTINATION_CHANNELS).get_channels_count();
+
+    rotated_rgbx::<DESTINATION_CHANNELS>(
+        width,
+        image.size.height as usize,
+        rgba,
+        rgba_stride,
+        rotation,
+        |start, rows, strip| {
+            let (chroma_start, chroma_rows) = chroma_rows(sampling, start, rows);
+            handler(
+                plane_rows(image.y_plane, y_stride, start, rows),
+                y_stride as u32,
+                plane_rows(image.uv_plane, uv_stride, chroma_start, chroma_rows),
+                uv_stride as u32,
+                strip,
+                (width * channels) as u32,
+                width as u32,
+                rows as u32,
+                range,
+                matrix,
+            )
+        },
+    )
+}
+
+/// Convert planar YUV image to RGBA rotated by a quarter turn.
+///
+/// Conversion and rotation are done in a single pass, strips of the source are converted
+/// in cache and written to the destination as short column runs, so no full size
+/// intermediate RGBA frame is needed. Destination is `image.size.height` pixels wide
+/// and `image.size.width` rows tall.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `rgba` - A mutable slice to store the rotated RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `rotation` - Direction of the quarter turn.
+///
+pub fn yuv_to_rgba_rotated(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    rotation: YuvRotation,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_rotated::<{ YuvSourceChannels::Rgba as u8 }>(
+        image,
+        sampling,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+        rotation,
+    )
+}
+
+/// Convert planar YUV image to BGRA rotated by a quarter turn.
+///
+/// Conversion and rotation are done in a single pass, strips of the source are converted
+/// in cache and written to the destination as short column runs, so no full size
+/// intermediate BGRA frame is needed. Destination is `image.size.height` pixels wide
+/// and `image.size.width` rows tall.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image.
+/// * `sampling` - Chroma subsampling of the image.
+/// * `bgra` - A mutable slice to store the rotated BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `rotation` - Direction of the quarter turn.
+///
+pub fn yuv_to_bgra_rotated(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    rotation: YuvRotation,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_rotated::<{ YuvSourceChannels::Bgra as u8 }>(
+        image,
+        sampling,
+        bgra,
+        bgra_stride,
+        range,
+        matrix,
+        rotation,
+    )
+}
+
+/// Convert YUV NV12 image to RGBA rotated by a quarter turn.
+///
+/// Conversion and rotation are done in a single pass, see [yuv_to_rgba_rotated].
+/// Destination is `image.size.height` pixels wide and `image.size.width` rows tall.
+///
+/// # Arguments
+///
+/// * `image` - Source NV12 image.
+/// * `rgba` - A mutable slice to store the rotated RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `rotation` - Direction of the quarter turn.
+///
+pub fn yuv_nv12_to_rgba_rotated(
+    image: &YuvBiPlanarImage<u8>,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    rotation: YuvRotation,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_rotated::<{ YuvNVOrder::UV as u8 }, { YuvSourceChannels::Rgba as u8 }>(
+        image,
+        YuvChromaSample::YUV420,
+        rgba,
+        rgba_stride,
+        range,
+        matrix,
+        rotation,
+    )
+}
+
+/// Convert YUV NV12 image to BGRA rotated by a quarter turn.
+///
+/// Conversion and rotation are done in a single pass, see [yuv_to_bgra_rotated].
+/// Destination is `image.size.height` pixels wide and `image.size.width` rows tall.
+///
+/// # Arguments
+///
+/// * `image` - Source NV12 image.
+/// * `bgra` - A mutable slice to store the rotated BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `rotation` - Direction of the quarter turn.
+///
+pub fn yuv_nv12_to_bgra_rotated(
+    image: &YuvBiPlanarImage<u8>,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    rotation: YuvRotation,
+) -> Result<(), YuvError> {
+    yuv_nv_to_rgbx_rotated::<{ YuvNVOrder::UV as u8 }, { YuvSourceChannels::Bgra as u8 }>(
+        image,
+        YuvChromaSample::YUV420,
+        bgra,
+        bgra_stride,
+        range,
+        matrix,
+        rotation,
+    )
+}
@@ -26,6 +26,7 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+use crate::YuvError;
 
 #[derive(Debug, Copy, Clone)]
 pub struct CbCrInverseTransform<T> {
@@ -171,6 +172,121 @@ pub enum YuvRange {
     TV,
     /// Full range Y ∈ [0, 2^bit_depth - 1], UV ∈ [-1 << (depth - 1), -1 << (depth - 1) + 2^bit_depth - 1]
     Full,
+    /// Explicit code value bounds at the bit depth of processed data, neutral chroma is
+    /// the middle of UV bounds. Use [YuvRange::custom] to validate bounds against bit depth,
+    /// otherwise out of range bounds are clamped to the bit depth when converting.
+    Custom {
+        min_y: u16,
+        max_y: u16,
+        min_uv: u16,
+        max_uv: u16,
+    },
+}
+
+impl YuvRange {
+    /// Creates custom range checking that every bound fits `bit_depth`
+    /// and minimums are below maximums.
+    pub fn custom(
+        min_y: u16,
+        max_y: u16,
+        min_uv: u16,
+        max_uv: u16,
+        bit_depth: u32,
+    ) -> Result<YuvRange, YuvError> {
+        let max_code = ((1u32 << bit_depth.clamp(1, 16)) - 1) as u16;
+        if min_y >= max_y || min_uv >= max_uv || max_y > max_code || max_uv > max_code {
+            return Err(YuvError::InvalidCustomRange(bit_depth));
+        }
+        Ok(YuvRange::Custom {
+            min_y,
+            max_y,
+            min_uv,
+            max_uv,
+        })
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+/// Declares how limited range values outside of nominal black and white are handled
+/// when expanding into high bit-depth RGB.
+///
+/// Has no effect on full range content.
+pub enum YuvOvershoot {
+    /// Nominal black and white are mapped onto 0 and maximum of destination,
+    /// super-blacks and super-whites are clamped
+    #[default]
+    Clamp,
+    /// Whole code range of the source is mapped linearly onto destination range,
+    /// so nominal black and white land inside of it and super-blacks and super-whites are preserved
+    PreserveOvershoot,
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+/// Broadcast legal clamping of forward conversion, as required for EBU R103 delivery.
+///
+/// When enabled Y, Cb and Cr are clamped into nominal range of the target [YuvRange]
+/// shrunk by `margin` codes from both ends, so for limited range 8-bit content Y stays
+/// in `16 + margin..=235 - margin` and chroma in `16 + margin..=240 - margin`.
+/// Any non zero margin on full range also keeps SDI timing reference codes out of the signal.
+pub struct YuvBroadcastSafe {
+    /// Enables clamping into legal range and counting of clipped samples
+    pub broadcast_safe: bool,
+    /// Codes kept between legal limits and the outermost emitted values
+    pub margin: u8,
+}
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+/// Component order of 30-bit RGB packed into little endian 32-bit words,
+/// named after DRM fourcc codes
+pub enum Rgb30 {
+    /// `AR30`/`XR30` (`ARGB2101010`/`XRGB2101010`), blue in the least significant bits
+    #[default]
+    Ar30 = 0,
+    /// `AB30`/`XB30` (`ABGR2101010`/`XBGR2101010`), red in the least significant bits
+    Ab30 = 1,
+    /// `RA30`/`RX30` (`RGBA1010102`/`RGBX1010102`), red in the most significant bits,
+    /// padding bits in the two least significant bits
+    Ra30 = 2,
+}
+
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+/// Value written into the two most significant bits of 30-bit packed RGB
+/// when the destination carries no alpha
+pub enum Rgb30Padding {
+    /// Bits are set, pixels read as opaque when the buffer is treated as `AR30`/`AB30`
+    #[default]
+    Opaque = 0,
+    /// Bits are cleared, as some `XR30`/`XB30` consumers expect
+    Zero = 1,
+}
+
+impl Rgb30 {
+    /// Packs 10-bit R, G, B into a single word with given padding bits
+    #[inline(always)]
+    pub(crate) const fn pack(self, r: u32, g: u32, b: u32, padding: Rgb30Padding) -> u32 {
+        let top = match padding {
+            Rgb30Padding::Opaque => 3u32 << 30,
+            Rgb30Padding::Zero => 0,
+        };
+        match self {
+            Rgb30::Ar30 => top | (r << 20) | (g << 10) | b,
+            Rgb30::Ab30 => top | (b << 20) | (g << 10) | r,
+            Rgb30::Ra30 => (r << 22) | (g << 12) | (b << 2) | (top >> 30),
+        }
+    }
+
+    /// Unpacks a single word into 10-bit R, G, B and 2-bit padding ( alpha )
+    #[inline(always)]
+    pub(crate) const fn unpack(self, v: u32) -> [u32; 4] {
+        match self {
+            Rgb30::Ar30 => [(v >> 20) & 0x3ff, (v >> 10) & 0x3ff, v & 0x3ff, v >> 30],
+            Rgb30::Ab30 => [v & 0x3ff, (v >> 10) & 0x3ff, (v >> 20) & 0x3ff, v >> 30],
+            Rgb30::Ra30 => [v >> 22, (v >> 12) & 0x3ff, (v >> 2) & 0x3ff, v & 0x3],
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
@@ -182,6 +298,58 @@ pub struct YuvChromaRange {
     pub range: YuvRange,
 }
 
+impl YuvChromaRange {
+    /// Lowest chroma code value encoders produce
+    pub const fn min_uv(&self) -> u32 {
+        match self.range {
+            YuvRange::Custom { min_uv, .. } => min_uv as u32,
+            YuvRange::TV | YuvRange::Full => self.bias_y,
+        }
+    }
+
+    /// Highest chroma code value encoders produce
+    pub const fn max_uv(&self) -> u32 {
+        match self.range {
+            YuvRange::Custom { max_uv, .. } => max_uv as u32,
+            YuvRange::TV | YuvRange::Full => self.bias_y + self.range_uv,
+        }
+    }
+}
+
+/// Clamps custom range bound pair into `[0, max_code]` keeping at least one code between them
+const fn sanitize_bounds(min: u16, max: u16, max_code: u32) -> (u32, u32) {
+    let max = if max as u32 > max_code {
+        max_code
+    } else if max == 0 {
+        1
+    } else {
+        max as u32
+    };
+    let min = if min as u32 >= max {
+        max - 1
+    } else {
+        min as u32
+    };
+    (min, max)
+}
+
+/// Deepest samples SIMD kernels decode, their 6-bit transform products must fit 16-bit lanes
+#[allow(dead_code)]
+pub(crate) const P16_SIMD_MAX_BIT_DEPTH: u32 = 12;
+
+/// Fractional bits of integer inverse transform decoding 8+ bit planes into 8+ bit RGB.
+///
+/// Up to [P16_SIMD_MAX_BIT_DEPTH] it matches SIMD kernels, deeper samples are decoded by
+/// scalar code with 13 bits: coefficient error stays below one code at 16 bits while
+/// `(y - bias) * y_coef + cr * cr_coef` of any 16-bit TV or full range sample fits `i32`.
+pub(crate) const fn p16_inverse_precision(bit_depth: u32) -> u32 {
+    if bit_depth > P16_SIMD_MAX_BIT_DEPTH {
+        13
+    } else {
+        6
+    }
+}
+
 pub const fn get_yuv_range(depth: u32, range: YuvRange) -> YuvChromaRange {
     match range {
         YuvRange::TV => YuvChromaRange {
@@ -198,6 +366,28 @@ pub const fn get_yuv_range(depth: u32, range: YuvRange) -> YuvChromaRange {
             range_y: (1 << depth) - 1,
             range,
         },
+        YuvRange::Custom {
+            min_y,
+            max_y,
+            min_uv,
+            max_uv,
+        } => {
+            let max_code = (1u32 << depth) - 1;
+            let (min_y, max_y) = sanitize_bounds(min_y, max_y, max_code);
+            let (min_uv, max_uv) = sanitize_bounds(min_uv, max_uv, max_code);
+            YuvChromaRange {
+                bias_y: min_y,
+                bias_uv: (min_uv + max_uv).div_ceil(2),
+                range_y: max_y - min_y,
+                range_uv: max_uv - min_uv,
+                range: YuvRange::Custom {
+                    min_y: min_y as u16,
+                    max_y: max_y as u16,
+                    min_uv: min_uv as u16,
+                    max_uv: max_uv as u16,
+                },
+            }
+        }
     }
 }
 
@@ -215,8 +405,20 @@ pub enum YuvStandardMatrix {
     /// Custom parameters first goes for kr, second for kb.
     /// Methods will *panic* if 1.0f32 - kr - kb == 0
     Custom(f32, f32),
+    /// Picks matrix from frame dimensions as players do for untagged streams:
+    /// Bt.601 up to 1024x576, Bt.2020 from 3840 wide or 2160 tall, Bt.709 in between.
+    /// Conversions resolve it against the full frame, see [YuvStandardMatrix::resolve].
+    /// Untagged content is only a guess, prefer explicit matrix whenever it is signalled.
+    Auto,
 }
 
+/// Largest SD frame, PAL 1024x576 with square pixels
+const AUTO_SD_MAX_WIDTH: u32 = 1024;
+const AUTO_SD_MAX_HEIGHT: u32 = 576;
+/// Smallest UHD frame
+const AUTO_UHD_MIN_WIDTH: u32 = 3840;
+const AUTO_UHD_MIN_HEIGHT: u32 = 2160;
+
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
 pub struct YuvBias {
     pub kr: f32,
@@ -224,6 +426,27 @@ pub struct YuvBias {
 }
 
 impl YuvStandardMatrix {
+    /// Returns matrix actually used for a `width` x `height` frame,
+    /// [YuvStandardMatrix::Auto] is replaced by the guess for this size, others are returned as is.
+    ///
+    /// Useful to log or report the decision made for untagged content.
+    pub const fn resolve(self, width: u32, height: u32) -> YuvStandardMatrix {
+        match self {
+            YuvStandardMatrix::Auto => {
+                if width >= AUTO_UHD_MIN_WIDTH || height >= AUTO_UHD_MIN_HEIGHT {
+                    YuvStandardMatrix::Bt2020
+                } else if width > AUTO_SD_MAX_WIDTH || height > AUTO_SD_MAX_HEIGHT {
+                    YuvStandardMatrix::Bt709
+                } else {
+                    YuvStandardMatrix::Bt601
+                }
+            }
+            _ => self,
+        }
+    }
+
+    /// Returns luma coefficients of the matrix,
+    /// unresolved [YuvStandardMatrix::Auto] is taken as HD Bt.709.
     pub const fn get_kr_kb(self) -> YuvBias {
         match self {
             YuvStandardMatrix::Bt601 => YuvBias {
@@ -247,12 +470,14 @@ impl YuvStandardMatrix {
                 kb: 0.0713f32,
             },
             YuvStandardMatrix::Custom(kr, kb) => YuvBias { kr, kb },
+            YuvStandardMatrix::Auto => YuvStandardMatrix::Bt709.get_kr_kb(),
         }
     }
 }
 
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+/// Order of chroma samples in interleaved plane of bi-planar formats
 pub enum YuvNVOrder {
     UV = 0,
     VU = 1,
@@ -290,6 +515,11 @@ impl From<u8> for YuvNVOrder {
 
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// Chroma subsampling of YUV images.
+///
+/// Horizontally subsampled chroma rows hold `(width + 1) / 2` samples. When width is odd,
+/// forward conversions compute the last chroma sample from the last pixel alone, as if it were
+/// replicated past the edge, so pixels beyond `width` and row padding are never read.
 pub enum YuvChromaSample {
     YUV420 = 0,
     YUV422 = 1,
@@ -358,6 +588,69 @@ impl From<u8> for YuvBytesPacking {
     }
 }
 
+/// Picks instantiation of a high bit depth handler for runtime endianness and bytes packing.
+///
+/// Handler is given with its leading generic arguments only, endianness and bytes packing
+/// are appended as the last two const parameters, e.g.
+/// `p16_dispatch!(handler, [{ YuvSourceChannels::Rgba as u8 }], endianness, bytes_packing)`.
+/// Match is exhaustive over both enums, so a new variant fails to build until every
+/// dispatcher handles it.
+macro_rules! p16_dispatch {
+    ($handler:ident, [$($param:tt),* $(,)?], $endianness:expr, $bytes_packing:expr $(,)?) => {
+        match ($endianness, $bytes_packing) {
+            (
+                $crate::yuv_support::YuvEndianness::BigEndian,
+                $crate::yuv_support::YuvBytesPacking::MostSignificantBytes,
+            ) => $handler::<
+                $($param,)*
+                { $crate::yuv_support::YuvEndianness::BigEndian as u8 },
+                { $crate::yuv_support::YuvBytesPacking::MostSignificantBytes as u8 },
+            >,
+            (
+                $crate::yuv_support::YuvEndianness::BigEndian,
+                $crate::yuv_support::YuvBytesPacking::LeastSignificantBytes,
+            ) => $handler::<
+                $($param,)*
+                { $crate::yuv_support::YuvEndianness::BigEndian as u8 },
+                { $crate::yuv_support::YuvBytesPacking::LeastSignificantBytes as u8 },
+            >,
+            (
+                $crate::yuv_support::YuvEndianness::LittleEndian,
+                $crate::yuv_support::YuvBytesPacking::MostSignificantBytes,
+            ) => $handler::<
+                $($param,)*
+                { $crate::yuv_support::YuvEndianness::LittleEndian as u8 },
+                { $crate::yuv_support::YuvBytesPacking::MostSignificantBytes as u8 },
+            >,
+            (
+                $crate::yuv_support::YuvEndianness::LittleEndian,
+                $crate::yuv_support::YuvBytesPacking::LeastSignificantBytes,
+            ) => $handler::<
+                $($param,)*
+                { $crate::yuv_support::YuvEndianness::LittleEndian as u8 },
+                { $crate::yuv_support::YuvBytesPacking::LeastSignificantBytes as u8 },
+            >,
+        }
+    };
+}
+
+pub(crate) use p16_dispatch;
+
+/// Rescales alpha sample from `src_depth` to `dst_depth` bits,
+/// truncating when narrowing and replicating high bits when widening so opaque stays opaque.
+/// Both depths are expected to be in 8..=16.
+#[inline(always)]
+pub(crate) const fn rescale_alpha_depth(a: u16, src_depth: usize, dst_depth: usize) -> u16 {
+    if src_depth > dst_depth {
+        a >> (src_depth - dst_depth)
+    } else if src_depth < dst_depth {
+        let shift = dst_depth - src_depth;
+        (((a as u32) << shift) | ((a as u32) >> (src_depth - shift))) as u16
+    } else {
+        a
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum YuvSourceChannels {
@@ -365,6 +658,10 @@ pub enum YuvSourceChannels {
     Rgba = 1,
     Bgra = 2,
     Bgr = 3,
+    /// 4 bytes per pixel laid out as RGBX, decoders leave the padding byte untouched
+    Rgbx = 4,
+    /// 4 bytes per pixel laid out as BGRX, decoders leave the padding byte untouched
+    Bgrx = 5,
 }
 
 impl From<u8> for YuvSourceChannels {
@@ -375,6 +672,8 @@ impl From<u8> for YuvSourceChannels {
             1 => YuvSourceChannels::Rgba,
             2 => YuvSourceChannels::Bgra,
             3 => YuvSourceChannels::Bgr,
+            4 => YuvSourceChannels::Rgbx,
+            5 => YuvSourceChannels::Bgrx,
             _ => {
                 panic!("Unknown value")
             }
@@ -387,14 +686,20 @@ impl YuvSourceChannels {
     pub const fn get_channels_count(&self) -> usize {
         match self {
             YuvSourceChannels::Rgb | YuvSourceChannels::Bgr => 3,
-            YuvSourceChannels::Rgba | YuvSourceChannels::Bgra => 4,
+            YuvSourceChannels::Rgba
+            | YuvSourceChannels::Bgra
+            | YuvSourceChannels::Rgbx
+            | YuvSourceChannels::Bgrx => 4,
         }
     }
 
     #[inline(always)]
     pub const fn has_alpha(&self) -> bool {
         match self {
-            YuvSourceChannels::Rgb | YuvSourceChannels::Bgr => false,
+            YuvSourceChannels::Rgb
+            | YuvSourceChannels::Bgr
+            | YuvSourceChannels::Rgbx
+            | YuvSourceChannels::Bgrx => false,
             YuvSourceChannels::Rgba | YuvSourceChannels::Bgra => true,
         }
     }
@@ -408,6 +713,8 @@ impl YuvSourceChannels {
             YuvSourceChannels::Rgba => 0,
             YuvSourceChannels::Bgra => 2,
             YuvSourceChannels::Bgr => 2,
+            YuvSourceChannels::Rgbx => 0,
+            YuvSourceChannels::Bgrx => 2,
         }
     }
 
@@ -416,6 +723,7 @@ impl YuvSourceChannels {
         match self {
             YuvSourceChannels::Rgb | YuvSourceChannels::Bgr => 1,
             YuvSourceChannels::Rgba | YuvSourceChannels::Bgra => 1,
+            YuvSourceChannels::Rgbx | YuvSourceChannels::Bgrx => 1,
         }
     }
 
@@ -426,13 +734,18 @@ impl YuvSourceChannels {
             YuvSourceChannels::Rgba => 2,
             YuvSourceChannels::Bgra => 0,
             YuvSourceChannels::Bgr => 0,
+            YuvSourceChannels::Rgbx => 2,
+            YuvSourceChannels::Bgrx => 0,
         }
     }
     #[inline(always)]
     pub const fn get_a_channel_offset(&self) -> usize {
         match self {
             YuvSourceChannels::Rgb | YuvSourceChannels::Bgr => 0,
-            YuvSourceChannels::Rgba | YuvSourceChannels::Bgra => 3,
+            YuvSourceChannels::Rgba
+            | YuvSourceChannels::Bgra
+            | YuvSourceChannels::Rgbx
+            | YuvSourceChannels::Bgrx => 3,
         }
     }
 }
@@ -0,0 +1,179 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{RowStride, YuvBiPlanarImage, YuvGrayImageMut, YuvPlanarImage};
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::neon::neon_expand_luma_row;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::sse::sse_expand_luma_row;
+use crate::yuv_support::{get_yuv_range, YuvChromaSample, YuvRange};
+use crate::{ImageSize, YuvError};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+/// Writes luma plane into gray image, limited range is expanded to full range
+fn luma_to_gray8(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    size: ImageSize,
+    gray: &mut YuvGrayImageMut<u8>,
+    range: YuvRange,
+) -> Result<(), YuvError> {
+    if gray.size != size {
+        return Err(YuvError::ImageSizeMismatch(size, gray.size));
+    }
+    gray.check_constraints()?;
+
+    let width = size.width as usize;
+    let height = size.height as usize;
+    let y_stride = y_stride.get() as usize;
+    let gray_stride = gray.y_stride.get() as usize;
+
+    let chroma_range = get_yuv_range(8, range);
+    let bias = chroma_range.bias_y as i16;
+    // Q14 so samples above nominal white still fit 16 bits after doubling in SIMD kernels
+    let scale = ((255 << 14) as f32 / chroma_range.range_y as f32).round() as i16;
+    let full_range = chroma_range.bias_y == 0 && chroma_range.range_y == 255;
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = gray.y_plane.borrow_mut().par_chunks_mut(gray_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = gray.y_plane.borrow_mut().chunks_mut(gray_stride);
+    }
+    iter.take(height).enumerate().for_each(|(y, dst)| {
+        let src = &y_plane[y * y_stride..][..width];
+        let dst = &mut dst[..width];
+        if full_range {
+            dst.copy_from_slice(src);
+            return;
+        }
+
+        let mut _cx = 0usize;
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            if _use_sse {
+                _cx = sse_expand_luma_row(src, dst, width, bias, scale);
+            }
+        }
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            _cx = neon_expand_luma_row(src, dst, width, bias, scale);
+        }
+
+        for (dst, &src) in dst.iter_mut().zip(src.iter()).skip(_cx) {
+            let value = ((src as i32 - bias as i32) * 2 * scale as i32 + (1 << 14)) >> 15;
+            *dst = value.clamp(0, 255) as u8;
+        }
+    });
+
+    Ok(())
+}
+
+/// Extracts 8-bit full range grayscale from YUV NV12 or NV21 image, chroma is not read.
+///
+/// Limited range luma is expanded to full range, full range luma is copied as is.
+///
+/// # Arguments
+///
+/// * `bi_planar_image` - Source NV12 or NV21 image.
+/// * `gray` - Target gray image of the same size.
+/// * `range` - Range of the source luma, see [YuvRange] for more info.
+///
+pub fn nv12_to_gray8(
+    bi_planar_image: &YuvBiPlanarImage<u8>,
+    gray: &mut YuvGrayImageMut<u8>,
+    range: YuvRange,
+) -> Result<(), YuvError> {
+    if bi_planar_image.size.width == 0 || bi_planar_image.size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    bi_planar_image.check_constraints(YuvChromaSample::YUV420)?;
+    luma_to_gray8(
+        bi_planar_image.y_plane,
+        bi_planar_image.y_stride,
+        bi_planar_image.size,
+        gray,
+        range,
+    )
+}
+
+/// Extracts 8-bit full range grayscale from YUV 420 planar image, chroma is not read.
+///
+/// Limited range luma is expanded to full range, full range luma is copied as is.
+///
+/// # Arguments
+///
+/// * `planar_image` - Source 4:2:0 planar image.
+/// * `gray` - Target gray image of the same size.
+/// * `range` - Range of the source luma, see [YuvRange] for more info.
+///
+pub fn yuv420_to_gray8(
+    planar_image: &YuvPlanarImage<u8>,
+    gray: &mut YuvGrayImageMut<u8>,
+    range: YuvRange,
+) -> Result<(), YuvError> {
+    if planar_image.size.width == 0 || planar_image.size.height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    planar_image.check_constraints(YuvChromaSample::YUV420)?;
+    luma_to_gray8(
+        planar_image.y_plane,
+        planar_image.y_stride,
+        planar_image.size,
+        gray,
+        range,
+    )
+}
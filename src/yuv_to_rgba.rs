@@ -26,20 +26,43 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::avx2_yuv_to_rgba_row;
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
-    feature = "nightly_avx512"
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
 ))]
 use crate::avx512bw::avx512_yuv_to_rgba;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
+))]
+use crate::internals::is_plane_aligned;
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_yuv_to_rgba_row;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "profiling")]
+use crate::profiling::{dispatch_backend, rayon_splits, ProfileScope};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::sse_yuv_to_rgba_row;
-#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    not(feature = "safe-only")
+))]
 use crate::wasm32::wasm_yuv_to_rgba_row;
-use crate::yuv_error::{check_chroma_channel, check_rgba_destination, check_y8_channel};
+use crate::yuv_error::{check_chroma_source_channel, check_rgba_destination, check_y8_channel};
 #[allow(unused_imports)]
 use crate::yuv_support::*;
 use crate::YuvError;
@@ -48,7 +71,38 @@ use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 #[cfg(feature = "rayon")]
 use rayon::prelude::ParallelSliceMut;
 
-fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
+pub(crate) fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_constant_alpha::<DESTINATION_CHANNELS, SAMPLING>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        255,
+    )
+}
+
+fn yuv_to_rgbx_constant_alpha<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
     y_plane: &[u8],
     y_stride: u32,
     u_plane: &[u8],
@@ -61,6 +115,7 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
+    alpha: u8,
 ) -> Result<(), YuvError> {
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
     let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
@@ -68,11 +123,58 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
 
     check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
     check_y8_channel(y_plane, y_stride, width, height)?;
-    check_chroma_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
-    check_chroma_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
+    yuv_to_rgbx_rows::<DESTINATION_CHANNELS, SAMPLING>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    );
+    Ok(())
+}
+
+/// Converts planes that are already validated, last row of `rgba` may end right after
+/// its visible pixels, so it also serves a region inside a larger destination
+pub(crate) fn yuv_to_rgbx_rows<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+
+    #[cfg(feature = "profiling")]
+    let _profile = ProfileScope::new(
+        "yuv_to_rgba",
+        y_plane.len() + u_plane.len() + v_plane.len() + rgba.len(),
+        dispatch_backend(true, true),
+        rayon_splits(height),
+    );
 
     let range = get_yuv_range(8, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let transform = get_inverse_transform(255, range.range_y, range.range_uv, kr_kb.kr, kr_kb.kb);
     const PRECISION: i32 = 6;
     const ROUNDING_CONST: i32 = 1 << (PRECISION - 1);
@@ -86,15 +188,32 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
     let bias_y = range.bias_y as i32;
     let bias_uv = range.bias_uv as i32;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_avx2 = std::arch::is_x86_feature_detected!("avx2");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly_avx512"
+        not(feature = "safe-only")
+    ))]
+    let mut _use_avx2 = crate::cpu_features::has_avx2();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_sse = crate::cpu_features::has_sse41();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly_avx512",
+        not(feature = "safe-only")
+    ))]
+    let mut _use_avx512 = crate::cpu_features::has_avx512bw();
+    // Well allocated buffers take aligned loads and stores, decided once per call
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly_avx512",
+        not(feature = "safe-only")
     ))]
-    let mut _use_avx512 = std::arch::is_x86_feature_detected!("avx512bw");
+    let _avx512_aligned = is_plane_aligned(y_plane, y_stride)
+        && is_plane_aligned(u_plane, u_stride)
+        && is_plane_aligned(v_plane, v_stride)
+        && is_plane_aligned(rgba, rgba_stride);
 
     let iterator_step = match chroma_subsampling {
         YuvChromaSample::YUV420 => 2usize,
@@ -105,14 +224,16 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
     let iter;
     #[cfg(feature = "rayon")]
     {
-        iter = rgba.par_chunks_exact_mut(rgba_stride as usize);
+        iter = rgba
+            .par_chunks_mut(rgba_stride as usize)
+            .take(height as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = rgba.chunks_exact_mut(rgba_stride as usize);
+        iter = rgba.chunks_mut(rgba_stride as usize).take(height as usize);
     }
 
-    iter.enumerate().for_each(|(y, rgba)| unsafe {
+    iter.enumerate().for_each(|(y, rgba)| {
         let y_offset = y * (y_stride as usize);
         let u_offset = if chroma_subsampling == YuvChromaSample::YUV420 {
             (y >> 1) * (u_stride as usize)
@@ -134,11 +255,19 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
         #[allow(unused_mut)]
         let mut uv_x = 0usize;
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
             #[cfg(feature = "nightly_avx512")]
             if _use_avx512 {
-                let processed = avx512_yuv_to_rgba::<DESTINATION_CHANNELS, SAMPLING>(
+                let handler = if _avx512_aligned {
+                    avx512_yuv_to_rgba::<DESTINATION_CHANNELS, SAMPLING, true>
+                } else {
+                    avx512_yuv_to_rgba::<DESTINATION_CHANNELS, SAMPLING, false>
+                };
+                let processed = handler(
                     &range,
                     &inverse_transform,
                     y_plane,
@@ -152,6 +281,7 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
                     v_offset,
                     rgba_offset,
                     width as usize,
+                    alpha,
                 );
                 cx = processed.cx;
                 uv_x = processed.ux;
@@ -172,6 +302,7 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
                     v_offset,
                     rgba_offset,
                     width as usize,
+                    alpha,
                 );
                 cx = processed.cx;
                 uv_x = processed.ux;
@@ -191,14 +322,19 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
                     v_offset,
                     rgba_offset,
                     width as usize,
+                    alpha,
                 );
                 cx = processed.cx;
                 uv_x = processed.ux;
             }
         }
 
-        #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
-        {
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
             let processed = wasm_yuv_to_rgba_row::<DESTINATION_CHANNELS, SAMPLING>(
                 &range,
                 &inverse_transform,
@@ -213,13 +349,18 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
                 v_offset,
                 rgba_offset,
                 width as usize,
+                alpha,
             );
             cx = processed.cx;
             uv_x = processed.ux;
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
-        {
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
             let processed = neon_yuv_to_rgba_row::<DESTINATION_CHANNELS, SAMPLING>(
                 &range,
                 &inverse_transform,
@@ -234,27 +375,28 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
                 v_offset,
                 rgba_offset,
                 width as usize,
+                alpha,
             );
             cx = processed.cx;
             uv_x = processed.ux;
         }
 
         for x in (cx..width as usize).step_by(iterator_step) {
-            let y_value = (*y_plane.get_unchecked(y_offset + x) as i32 - bias_y) * y_coef;
+            let y_value = (y_plane[y_offset + x] as i32 - bias_y) * y_coef;
 
             let u_pos = match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => u_offset + uv_x,
                 YuvChromaSample::YUV444 => u_offset + uv_x,
             };
 
-            let cb_value = *u_plane.get_unchecked(u_pos) as i32 - bias_uv;
+            let cb_value = u_plane[u_pos] as i32 - bias_uv;
 
             let v_pos = match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => v_offset + uv_x,
                 YuvChromaSample::YUV444 => v_offset + uv_x,
             };
 
-            let cr_value = *v_plane.get_unchecked(v_pos) as i32 - bias_uv;
+            let cr_value = v_plane[v_pos] as i32 - bias_uv;
 
             let r = ((y_value + cr_coef * cr_value + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
             let b = ((y_value + cb_coef * cb_value + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
@@ -266,12 +408,12 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
 
             let rgba_shift = rgba_offset + px;
 
-            let dst = rgba.get_unchecked_mut(rgba_shift..);
-            *dst.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r as u8;
-            *dst.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g as u8;
-            *dst.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b as u8;
+            let dst = &mut rgba[rgba_shift..];
+            dst[dst_chans.get_r_channel_offset()] = r as u8;
+            dst[dst_chans.get_g_channel_offset()] = g as u8;
+            dst[dst_chans.get_b_channel_offset()] = b as u8;
             if dst_chans.has_alpha() {
-                *dst.get_unchecked_mut(dst_chans.get_a_channel_offset()) = 255;
+                dst[dst_chans.get_a_channel_offset()] = alpha;
             }
 
             if chroma_subsampling == YuvChromaSample::YUV420
@@ -279,8 +421,7 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
             {
                 let next_x = x + 1;
                 if next_x < width as usize {
-                    let y_value =
-                        (*y_plane.get_unchecked(y_offset + next_x) as i32 - bias_y) * y_coef;
+                    let y_value = (y_plane[y_offset + next_x] as i32 - bias_y) * y_coef;
 
                     let r = ((y_value + cr_coef * cr_value + ROUNDING_CONST) >> PRECISION)
                         .clamp(0, 255);
@@ -295,12 +436,12 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
 
                     let rgba_shift = rgba_offset + next_px;
 
-                    let dst = rgba.get_unchecked_mut(rgba_shift..);
-                    *dst.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r as u8;
-                    *dst.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g as u8;
-                    *dst.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b as u8;
+                    let dst = &mut rgba[rgba_shift..];
+                    dst[dst_chans.get_r_channel_offset()] = r as u8;
+                    dst[dst_chans.get_g_channel_offset()] = g as u8;
+                    dst[dst_chans.get_b_channel_offset()] = b as u8;
                     if dst_chans.has_alpha() {
-                        *dst.get_unchecked_mut(dst_chans.get_a_channel_offset()) = 255;
+                        dst[dst_chans.get_a_channel_offset()] = alpha;
                     }
                 }
             }
@@ -308,8 +449,6 @@ fn yuv_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
             uv_x += 1;
         }
     });
-
-    Ok(())
 }
 
 /// Convert YUV 420 planar format to RGB format.
@@ -905,3 +1044,1161 @@ pub fn yuv444_to_bgr(
         range, matrix,
     )
 }
+
+/// Convert YV12 planar format to RGB format.
+///
+/// YV12 is YUV 420 planar format where V plane is stored before U plane.
+/// This function takes YV12 data with 8-bit precision,
+/// and converts it to RGB format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data, the second plane in YV12.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data, the third plane in YV12.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yv12_to_rgb(
+    y_plane: &[u8],
+    y_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    rgb: &mut [u8],
+    rgb_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert YV12 planar format to BGR format.
+///
+/// YV12 is YUV 420 planar format where V plane is stored before U plane.
+/// This function takes YV12 data with 8-bit precision,
+/// and converts it to BGR format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data, the second plane in YV12.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data, the third plane in YV12.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `bgr` - A mutable slice to store the converted BGR data.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yv12_to_bgr(
+    y_plane: &[u8],
+    y_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    bgr: &mut [u8],
+    bgr_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert YV12 planar format to RGBA format.
+///
+/// YV12 is YUV 420 planar format where V plane is stored before U plane.
+/// This function takes YV12 data with 8-bit precision,
+/// and converts it to RGBA format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data, the second plane in YV12.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data, the third plane in YV12.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yv12_to_rgba(
+    y_plane: &[u8],
+    y_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YV12 planar format to BGRA format.
+///
+/// YV12 is YUV 420 planar format where V plane is stored before U plane.
+/// This function takes YV12 data with 8-bit precision,
+/// and converts it to BGRA format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data, the second plane in YV12.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data, the third plane in YV12.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yv12_to_bgra(
+    y_plane: &[u8],
+    y_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YV16 planar format to RGB format.
+///
+/// YV16 is YUV 422 planar format where V plane is stored before U plane.
+/// This function takes YV16 data with 8-bit precision,
+/// and converts it to RGB format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data, the second plane in YV16.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data, the third plane in YV16.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yv16_to_rgb(
+    y_plane: &[u8],
+    y_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    rgb: &mut [u8],
+    rgb_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert YV16 planar format to BGR format.
+///
+/// YV16 is YUV 422 planar format where V plane is stored before U plane.
+/// This function takes YV16 data with 8-bit precision,
+/// and converts it to BGR format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data, the second plane in YV16.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data, the third plane in YV16.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `bgr` - A mutable slice to store the converted BGR data.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yv16_to_bgr(
+    y_plane: &[u8],
+    y_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    bgr: &mut [u8],
+    bgr_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert YV16 planar format to RGBA format.
+///
+/// YV16 is YUV 422 planar format where V plane is stored before U plane.
+/// This function takes YV16 data with 8-bit precision,
+/// and converts it to RGBA format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data, the second plane in YV16.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data, the third plane in YV16.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yv16_to_rgba(
+    y_plane: &[u8],
+    y_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YV16 planar format to BGRA format.
+///
+/// YV16 is YUV 422 planar format where V plane is stored before U plane.
+/// This function takes YV16 data with 8-bit precision,
+/// and converts it to BGRA format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data, the second plane in YV16.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data, the third plane in YV16.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yv16_to_bgra(
+    y_plane: &[u8],
+    y_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YV24 planar format to RGB format.
+///
+/// YV24 is YUV 444 planar format where V plane is stored before U plane.
+/// This function takes YV24 data with 8-bit precision,
+/// and converts it to RGB format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data, the second plane in YV24.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data, the third plane in YV24.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yv24_to_rgb(
+    y_plane: &[u8],
+    y_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    rgb: &mut [u8],
+    rgb_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert YV24 planar format to BGR format.
+///
+/// YV24 is YUV 444 planar format where V plane is stored before U plane.
+/// This function takes YV24 data with 8-bit precision,
+/// and converts it to BGR format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data, the second plane in YV24.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data, the third plane in YV24.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `bgr` - A mutable slice to store the converted BGR data.
+/// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yv24_to_bgr(
+    y_plane: &[u8],
+    y_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    bgr: &mut [u8],
+    bgr_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert YV24 planar format to RGBA format.
+///
+/// YV24 is YUV 444 planar format where V plane is stored before U plane.
+/// This function takes YV24 data with 8-bit precision,
+/// and converts it to RGBA format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data, the second plane in YV24.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data, the third plane in YV24.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yv24_to_rgba(
+    y_plane: &[u8],
+    y_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YV24 planar format to BGRA format.
+///
+/// YV24 is YUV 444 planar format where V plane is stored before U plane.
+/// This function takes YV24 data with 8-bit precision,
+/// and converts it to BGRA format with 8-bit per channel precision.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data, the second plane in YV24.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data, the third plane in YV24.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yv24_to_bgra(
+    y_plane: &[u8],
+    y_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 420 planar format to RGBA format with constant alpha.
+///
+/// Same as [yuv420_to_rgba] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv420_to_rgba_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_constant_alpha::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV 420 planar format to BGRA format with constant alpha.
+///
+/// Same as [yuv420_to_bgra] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv420_to_bgra_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_constant_alpha::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV 422 planar format to RGBA format with constant alpha.
+///
+/// Same as [yuv422_to_rgba] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv422_to_rgba_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_constant_alpha::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV 422 planar format to BGRA format with constant alpha.
+///
+/// Same as [yuv422_to_bgra] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv422_to_bgra_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_constant_alpha::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV 444 planar format to RGBA format with constant alpha.
+///
+/// Same as [yuv444_to_rgba] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv444_to_rgba_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_constant_alpha::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV 444 planar format to BGRA format with constant alpha.
+///
+/// Same as [yuv444_to_bgra] but alpha channel is filled with the provided value instead of 255.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `alpha` - Value to write into alpha channel.
+///
+pub fn yuv444_to_bgra_with_constant_alpha(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    bgra: &mut [u8],
+    bgra_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    alpha: u8,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx_constant_alpha::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+        alpha,
+    )
+}
+
+/// Convert YUV 420 planar format to RGBX format.
+///
+/// Same as [yuv420_to_rgba] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgbx` - A mutable slice to store the converted RGBX data.
+/// * `rgbx_stride` - The stride (bytes per row) for the RGBX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv420_to_rgbx(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    rgbx: &mut [u8],
+    rgbx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Rgbx as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgbx,
+        rgbx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 420 planar format to BGRX format.
+///
+/// Same as [yuv420_to_bgra] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgrx` - A mutable slice to store the converted BGRX data.
+/// * `bgrx_stride` - The stride (bytes per row) for the BGRX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv420_to_bgrx(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    bgrx: &mut [u8],
+    bgrx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Bgrx as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgrx,
+        bgrx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 422 planar format to RGBX format.
+///
+/// Same as [yuv422_to_rgba] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgbx` - A mutable slice to store the converted RGBX data.
+/// * `rgbx_stride` - The stride (bytes per row) for the RGBX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv422_to_rgbx(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    rgbx: &mut [u8],
+    rgbx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Rgbx as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgbx,
+        rgbx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 422 planar format to BGRX format.
+///
+/// Same as [yuv422_to_bgra] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgrx` - A mutable slice to store the converted BGRX data.
+/// * `bgrx_stride` - The stride (bytes per row) for the BGRX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv422_to_bgrx(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    bgrx: &mut [u8],
+    bgrx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Bgrx as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgrx,
+        bgrx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 444 planar format to RGBX format.
+///
+/// Same as [yuv444_to_rgba] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgbx` - A mutable slice to store the converted RGBX data.
+/// * `rgbx_stride` - The stride (bytes per row) for the RGBX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv444_to_rgbx(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    rgbx: &mut [u8],
+    rgbx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Rgbx as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgbx,
+        rgbx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 444 planar format to BGRX format.
+///
+/// Same as [yuv444_to_bgra] but the 4th byte of every destination pixel is left untouched.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgrx` - A mutable slice to store the converted BGRX data.
+/// * `bgrx_stride` - The stride (bytes per row) for the BGRX image data.
+/// * `width` - The width of the YUV image.
+/// * `height` - The height of the YUV image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv444_to_bgrx(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    bgrx: &mut [u8],
+    bgrx_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    yuv_to_rgbx::<{ YuvSourceChannels::Bgrx as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgrx,
+        bgrx_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
@@ -0,0 +1,633 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride, YuvPlanarImage};
+use crate::yuv_planar_generic::{yuv_planar_to_rgbx, Pixel};
+use crate::yuv_support::{
+    YuvBytesPacking, YuvChromaSample, YuvEndianness, YuvOvershoot, YuvRange, YuvSourceChannels,
+    YuvStandardMatrix,
+};
+use crate::YuvError;
+
+/// Convert YUV 420 planar format with 8-bit pixel format to RGBA format with 8+ bit-depth.
+///
+/// Values are expanded directly from 8-bit YUV into the requested destination bit depth
+/// so no precision is lost on the 8-bit intermediate.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `bit_depth` - Bit depth of destination RGBA data, up to 16.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv420_to_rgba16(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    rgba: &mut [u16],
+    rgba_stride: RowStride,
+    bit_depth: usize,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgba_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgba_stride.get(),
+    );
+    yuv_planar_to_rgbx::<
+        u8,
+        u16,
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::LeastSignificantBytes as u8 },
+    >(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        8,
+        bit_depth,
+        YuvOvershoot::Clamp,
+    )
+}
+
+/// Convert YUV 420 planar format with 8-bit pixel format to RGB format with 8+ bit-depth.
+///
+/// Values are expanded directly from 8-bit YUV into the requested destination bit depth
+/// so no precision is lost on the 8-bit intermediate.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `bit_depth` - Bit depth of destination RGB data, up to 16.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv420_to_rgb16(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    rgb: &mut [u16],
+    rgb_stride: RowStride,
+    bit_depth: usize,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgb_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgb_stride.get(),
+    );
+    yuv_planar_to_rgbx::<
+        u8,
+        u16,
+        { YuvSourceChannels::Rgb as u8 },
+        { YuvChromaSample::YUV420 as u8 },
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::LeastSignificantBytes as u8 },
+    >(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgb,
+        rgb_stride,
+        width,
+        height,
+        range,
+        matrix,
+        8,
+        bit_depth,
+        YuvOvershoot::Clamp,
+    )
+}
+
+/// Convert YUV 422 planar format with 8-bit pixel format to RGBA format with 8+ bit-depth.
+///
+/// Values are expanded directly from 8-bit YUV into the requested destination bit depth
+/// so no precision is lost on the 8-bit intermediate.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `bit_depth` - Bit depth of destination RGBA data, up to 16.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv422_to_rgba16(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    rgba: &mut [u16],
+    rgba_stride: RowStride,
+    bit_depth: usize,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgba_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgba_stride.get(),
+    );
+    yuv_planar_to_rgbx::<
+        u8,
+        u16,
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV422 as u8 },
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::LeastSignificantBytes as u8 },
+    >(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        8,
+        bit_depth,
+        YuvOvershoot::Clamp,
+    )
+}
+
+/// Convert YUV 422 planar format with 8-bit pixel format to RGB format with 8+ bit-depth.
+///
+/// Values are expanded directly from 8-bit YUV into the requested destination bit depth
+/// so no precision is lost on the 8-bit intermediate.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `bit_depth` - Bit depth of destination RGB data, up to 16.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv422_to_rgb16(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    rgb: &mut [u16],
+    rgb_stride: RowStride,
+    bit_depth: usize,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgb_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgb_stride.get(),
+    );
+    yuv_planar_to_rgbx::<
+        u8,
+        u16,
+        { YuvSourceChannels::Rgb as u8 },
+        { YuvChromaSample::YUV422 as u8 },
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::LeastSignificantBytes as u8 },
+    >(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgb,
+        rgb_stride,
+        width,
+        height,
+        range,
+        matrix,
+        8,
+        bit_depth,
+        YuvOvershoot::Clamp,
+    )
+}
+
+/// Convert YUV 444 planar format with 8-bit pixel format to RGBA format with 8+ bit-depth.
+///
+/// Values are expanded directly from 8-bit YUV into the requested destination bit depth
+/// so no precision is lost on the 8-bit intermediate.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `bit_depth` - Bit depth of destination RGBA data, up to 16.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv444_to_rgba16(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    rgba: &mut [u16],
+    rgba_stride: RowStride,
+    bit_depth: usize,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgba_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgba_stride.get(),
+    );
+    yuv_planar_to_rgbx::<
+        u8,
+        u16,
+        { YuvSourceChannels::Rgba as u8 },
+        { YuvChromaSample::YUV444 as u8 },
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::LeastSignificantBytes as u8 },
+    >(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+        8,
+        bit_depth,
+        YuvOvershoot::Clamp,
+    )
+}
+
+/// Convert YUV 444 planar format with 8-bit pixel format to RGB format with 8+ bit-depth.
+///
+/// Values are expanded directly from 8-bit YUV into the requested destination bit depth
+/// so no precision is lost on the 8-bit intermediate.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
+/// * `bit_depth` - Bit depth of destination RGB data, up to 16.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv444_to_rgb16(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    rgb: &mut [u16],
+    rgb_stride: RowStride,
+    bit_depth: usize,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgb_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgb_stride.get(),
+    );
+    yuv_planar_to_rgbx::<
+        u8,
+        u16,
+        { YuvSourceChannels::Rgb as u8 },
+        { YuvChromaSample::YUV444 as u8 },
+        { YuvEndianness::LittleEndian as u8 },
+        { YuvBytesPacking::LeastSignificantBytes as u8 },
+    >(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgb,
+        rgb_stride,
+        width,
+        height,
+        range,
+        matrix,
+        8,
+        bit_depth,
+        YuvOvershoot::Clamp,
+    )
+}
+
+type PlanarToRgba16<S> = fn(
+    &[S],
+    u32,
+    &[S],
+    u32,
+    &[S],
+    u32,
+    &mut [u16],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+    usize,
+    usize,
+    YuvOvershoot,
+) -> Result<(), YuvError>;
+
+fn planar_to_rgba16_dispatcher<S: Pixel, const SAMPLING: u8>(
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> PlanarToRgba16<S> {
+    match (endianness, bytes_packing) {
+        (YuvEndianness::BigEndian, YuvBytesPacking::MostSignificantBytes) => {
+            yuv_planar_to_rgbx::<
+                S,
+                u16,
+                { YuvSourceChannels::Rgba as u8 },
+                SAMPLING,
+                { YuvEndianness::BigEndian as u8 },
+                { YuvBytesPacking::MostSignificantBytes as u8 },
+            >
+        }
+        (YuvEndianness::BigEndian, YuvBytesPacking::LeastSignificantBytes) => {
+            yuv_planar_to_rgbx::<
+                S,
+                u16,
+                { YuvSourceChannels::Rgba as u8 },
+                SAMPLING,
+                { YuvEndianness::BigEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+            >
+        }
+        (YuvEndianness::LittleEndian, YuvBytesPacking::MostSignificantBytes) => {
+            yuv_planar_to_rgbx::<
+                S,
+                u16,
+                { YuvSourceChannels::Rgba as u8 },
+                SAMPLING,
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::MostSignificantBytes as u8 },
+            >
+        }
+        (YuvEndianness::LittleEndian, YuvBytesPacking::LeastSignificantBytes) => {
+            yuv_planar_to_rgbx::<
+                S,
+                u16,
+                { YuvSourceChannels::Rgba as u8 },
+                SAMPLING,
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+            >
+        }
+    }
+}
+
+fn planar_to_rgba16_sampled_dispatcher<S: Pixel>(
+    sampling: YuvChromaSample,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+) -> PlanarToRgba16<S> {
+    match sampling {
+        YuvChromaSample::YUV420 => planar_to_rgba16_dispatcher::<
+            S,
+            { YuvChromaSample::YUV420 as u8 },
+        >(endianness, bytes_packing),
+        YuvChromaSample::YUV422 => planar_to_rgba16_dispatcher::<
+            S,
+            { YuvChromaSample::YUV422 as u8 },
+        >(endianness, bytes_packing),
+        YuvChromaSample::YUV444 => planar_to_rgba16_dispatcher::<
+            S,
+            { YuvChromaSample::YUV444 as u8 },
+        >(endianness, bytes_packing),
+    }
+}
+
+/// Convert planar YUV with 8-bit pixel format to RGBA format with 8+ bit-depth,
+/// optionally preserving super-blacks and super-whites of limited range content.
+///
+/// With [YuvOvershoot::PreserveOvershoot] whole 8-bit code range is mapped linearly onto
+/// destination range, so nominal black (16) and white (235) land inside of it instead of
+/// clamping everything outside of them. Full range content is not affected.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `bit_depth` - Bit depth of destination RGBA data, up to 16.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `overshoot` - How values outside of nominal range are handled.
+///
+pub fn yuv_to_rgba16_with_overshoot(
+    image: &YuvPlanarImage<u8>,
+    sampling: YuvChromaSample,
+    rgba: &mut [u16],
+    rgba_stride: RowStride,
+    bit_depth: usize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    overshoot: YuvOvershoot,
+) -> Result<(), YuvError> {
+    let rgba_stride = rgba_stride.get();
+    let dispatcher = planar_to_rgba16_sampled_dispatcher::<u8>(
+        sampling,
+        YuvEndianness::LittleEndian,
+        YuvBytesPacking::LeastSignificantBytes,
+    );
+    dispatcher(
+        image.y_plane,
+        image.y_stride.get(),
+        image.u_plane,
+        image.u_stride.get(),
+        image.v_plane,
+        image.v_stride.get(),
+        rgba,
+        rgba_stride,
+        image.size.width,
+        image.size.height,
+        range,
+        matrix,
+        8,
+        bit_depth,
+        overshoot,
+    )
+}
+
+/// Convert planar YUV with 8+ bit pixel format to RGBA format with 8+ bit-depth,
+/// optionally preserving super-blacks and super-whites of limited range content.
+///
+/// With [YuvOvershoot::PreserveOvershoot] whole source code range is mapped linearly onto
+/// destination range, e.g. for 10-bit source nominal black (64) and white (940) land inside of it
+/// instead of clamping everything outside of them. Full range content is not affected.
+///
+/// # Arguments
+///
+/// * `image` - Source planar YUV image.
+/// * `sampling` - Chroma subsampling of the source image.
+/// * `src_bit_depth` - Bit depth of source YUV planes.
+/// * `endianness` - The endianness of stored bytes.
+/// * `bytes_packing` - Position of significant bytes (most significant or least significant).
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
+/// * `dst_bit_depth` - Bit depth of destination RGBA data, up to 16.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `overshoot` - How values outside of nominal range are handled.
+///
+pub fn yuv_p16_to_rgba16_with_overshoot(
+    image: &YuvPlanarImage<u16>,
+    sampling: YuvChromaSample,
+    src_bit_depth: usize,
+    endianness: YuvEndianness,
+    bytes_packing: YuvBytesPacking,
+    rgba: &mut [u16],
+    rgba_stride: RowStride,
+    dst_bit_depth: usize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    overshoot: YuvOvershoot,
+) -> Result<(), YuvError> {
+    let rgba_stride = rgba_stride.get();
+    let dispatcher =
+        planar_to_rgba16_sampled_dispatcher::<u16>(sampling, endianness, bytes_packing);
+    dispatcher(
+        image.y_plane,
+        image.y_stride.get(),
+        image.u_plane,
+        image.u_stride.get(),
+        image.v_plane,
+        image.v_stride.get(),
+        rgba,
+        rgba_stride,
+        image.size.width,
+        image.size.height,
+        range,
+        matrix,
+        src_bit_depth,
+        dst_bit_depth,
+        overshoot,
+    )
+}
@@ -26,18 +26,29 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::avx2_yuv_to_rgba_alpha;
 #[cfg(all(
     any(target_arch = "x86", target_arch = "x86_64"),
-    feature = "nightly_avx512"
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
 ))]
 use crate::avx512bw::avx512_yuv_to_rgba_alpha;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::neon_yuv_to_rgba_alpha;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::sse_yuv_to_rgba_alpha_row;
-use crate::yuv_error::{check_chroma_channel, check_rgba_destination, check_y8_channel};
+use crate::yuv_error::{check_chroma_source_channel, check_rgba_destination, check_y8_channel};
 #[allow(unused_imports)]
 use crate::yuv_support::*;
 use crate::{YuvError, YuvRange, YuvStandardMatrix};
@@ -74,11 +85,11 @@ fn yuv_with_alpha_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
     check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
     check_y8_channel(y_plane, y_stride, width, height)?;
     check_y8_channel(a_plane, a_stride, width, height)?;
-    check_chroma_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
-    check_chroma_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
 
     let range = get_yuv_range(8, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let transform = get_inverse_transform(255, range.range_y, range.range_uv, kr_kb.kr, kr_kb.kb);
     const PRECISION: i32 = 6;
     const ROUNDING_CONST: i32 = 1 << (PRECISION - 1);
@@ -99,24 +110,31 @@ fn yuv_with_alpha_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
         YuvChromaSample::YUV444 => 1usize,
     };
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_avx2 = std::arch::is_x86_feature_detected!("avx2");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
     #[cfg(all(
         any(target_arch = "x86", target_arch = "x86_64"),
-        feature = "nightly_avx512"
+        not(feature = "safe-only")
     ))]
-    let mut _use_avx512 = std::arch::is_x86_feature_detected!("avx512bw");
+    let mut _use_avx2 = crate::cpu_features::has_avx2();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_sse = crate::cpu_features::has_sse41();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly_avx512",
+        not(feature = "safe-only")
+    ))]
+    let mut _use_avx512 = crate::cpu_features::has_avx512bw();
 
     let iter;
     #[cfg(feature = "rayon")]
     {
-        iter = rgba.par_chunks_exact_mut(rgba_stride as usize);
+        iter = rgba.par_chunks_mut(rgba_stride as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = rgba.chunks_exact_mut(rgba_stride as usize);
+        iter = rgba.chunks_mut(rgba_stride as usize);
     }
 
     iter.enumerate().for_each(|(y, rgba)| {
@@ -142,7 +160,10 @@ fn yuv_with_alpha_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
         #[allow(unused_mut)]
         let mut uv_x = 0usize;
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
             #[cfg(feature = "nightly_avx512")]
             {
@@ -215,7 +236,11 @@ fn yuv_with_alpha_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         unsafe {
             let processed = neon_yuv_to_rgba_alpha::<DESTINATION_CHANNELS, SAMPLING>(
                 &range,
@@ -240,22 +265,21 @@ fn yuv_with_alpha_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
         }
 
         for x in (cx..width as usize).step_by(iterator_step) {
-            let y_value =
-                (unsafe { *y_plane.get_unchecked(y_offset + x) } as i32 - bias_y) * y_coef;
+            let y_value = (y_plane[y_offset + x] as i32 - bias_y) * y_coef;
 
             let u_pos = match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => u_offset + uv_x,
                 YuvChromaSample::YUV444 => u_offset + uv_x,
             };
 
-            let cb_value = unsafe { *u_plane.get_unchecked(u_pos) } as i32 - bias_uv;
+            let cb_value = u_plane[u_pos] as i32 - bias_uv;
 
             let v_pos = match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => v_offset + uv_x,
                 YuvChromaSample::YUV444 => v_offset + uv_x,
             };
 
-            let cr_value = unsafe { *v_plane.get_unchecked(v_pos) } as i32 - bias_uv;
+            let cr_value = v_plane[v_pos] as i32 - bias_uv;
 
             let mut r = ((y_value + cr_coef * cr_value + ROUNDING_CONST) >> PRECISION)
                 .min(255)
@@ -272,29 +296,25 @@ fn yuv_with_alpha_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
 
             let rgba_shift = rgba_offset + px;
 
-            let a_value = unsafe { *a_plane.get_unchecked(a_offset + x) };
+            let a_value = a_plane[a_offset + x];
             if premultiply_alpha {
                 r = (r * a_value as i32) / 255;
                 g = (g * a_value as i32) / 255;
                 b = (b * a_value as i32) / 255;
             }
 
-            unsafe {
-                let dst = rgba.get_unchecked_mut(rgba_shift..);
-                *dst.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r as u8;
-                *dst.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g as u8;
-                *dst.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b as u8;
-                *dst.get_unchecked_mut(dst_chans.get_a_channel_offset()) = a_value;
-            }
+            let dst = &mut rgba[rgba_shift..];
+            dst[dst_chans.get_r_channel_offset()] = r as u8;
+            dst[dst_chans.get_g_channel_offset()] = g as u8;
+            dst[dst_chans.get_b_channel_offset()] = b as u8;
+            dst[dst_chans.get_a_channel_offset()] = a_value;
 
             if chroma_subsampling == YuvChromaSample::YUV420
                 || chroma_subsampling == YuvChromaSample::YUV422
             {
                 let next_x = x + 1;
                 if x + 1 < width as usize {
-                    let y_value = (unsafe { *y_plane.get_unchecked(y_offset + x + 1) } as i32
-                        - bias_y)
-                        * y_coef;
+                    let y_value = (y_plane[y_offset + x + 1] as i32 - bias_y) * y_coef;
 
                     let mut r = ((y_value + cr_coef * cr_value + ROUNDING_CONST) >> PRECISION)
                         .min(255)
@@ -312,20 +332,18 @@ fn yuv_with_alpha_to_rgbx<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
 
                     let rgba_shift = rgba_offset + next_px;
 
-                    let a_value = unsafe { *a_plane.get_unchecked(a_offset + next_x) };
+                    let a_value = a_plane[a_offset + next_x];
                     if premultiply_alpha {
                         r = (r * a_value as i32) / 255;
                         g = (g * a_value as i32) / 255;
                         b = (b * a_value as i32) / 255;
                     }
 
-                    unsafe {
-                        let dst = rgba.get_unchecked_mut(rgba_shift..);
-                        *dst.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r as u8;
-                        *dst.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g as u8;
-                        *dst.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b as u8;
-                        *dst.get_unchecked_mut(dst_chans.get_a_channel_offset()) = a_value;
-                    }
+                    let dst = &mut rgba[rgba_shift..];
+                    dst[dst_chans.get_r_channel_offset()] = r as u8;
+                    dst[dst_chans.get_g_channel_offset()] = g as u8;
+                    dst[dst_chans.get_b_channel_offset()] = b as u8;
+                    dst[dst_chans.get_a_channel_offset()] = a_value;
                 }
             }
 
@@ -0,0 +1,774 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride};
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
+use crate::neon::neon_yuv_to_rgba_f32_row;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
+use crate::sse::sse_yuv_to_rgba_f32_row;
+use crate::yuv_error::{check_chroma_source_channel, check_rgba_destination, check_y8_channel};
+use crate::yuv_support::{
+    get_inverse_transform, get_yuv_range, CbCrInverseTransform, YuvChromaSample, YuvRange,
+    YuvSourceChannels, YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+fn yuv_to_rgbx_f32<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    rgba: &mut [f32],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_source_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
+    let range = get_yuv_range(8, range);
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
+    let transform = get_inverse_transform(255, range.range_y, range.range_uv, kr_kb.kr, kr_kb.kb);
+    // Coefficients produce values in 0..=255, scale them once so results land in [0, 1]
+    let transform = CbCrInverseTransform::new(
+        transform.y_coef / 255.,
+        transform.cr_coef / 255.,
+        transform.cb_coef / 255.,
+        transform.g_coeff_1 / 255.,
+        transform.g_coeff_2 / 255.,
+    );
+    let bias_y = range.bias_y as f32;
+    let bias_uv = range.bias_uv as f32;
+
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let _use_sse = crate::cpu_features::has_sse41();
+
+    let width = width as usize;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba
+            .par_chunks_mut(rgba_stride as usize / 4)
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba
+            .chunks_mut(rgba_stride as usize / 4)
+            .take(height as usize);
+    }
+
+    iter.enumerate().for_each(|(y, rgba)| {
+        let chroma_y = match chroma_subsampling {
+            YuvChromaSample::YUV420 => y >> 1,
+            YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => y,
+        };
+        let y_src = &y_plane[y * y_stride as usize..];
+        let u_src = &u_plane[chroma_y * u_stride as usize..];
+        let v_src = &v_plane[chroma_y * v_stride as usize..];
+
+        #[allow(unused_mut)]
+        let mut cx = 0usize;
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            if _use_sse {
+                cx = sse_yuv_to_rgba_f32_row::<DESTINATION_CHANNELS, SAMPLING>(
+                    &range, &transform, y_src, u_src, v_src, rgba, width,
+                );
+            }
+        }
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            cx = neon_yuv_to_rgba_f32_row::<DESTINATION_CHANNELS, SAMPLING>(
+                &range, &transform, y_src, u_src, v_src, rgba, width,
+            );
+        }
+
+        for (x, dst) in rgba
+            .chunks_exact_mut(channels)
+            .enumerate()
+            .take(width)
+            .skip(cx)
+        {
+            let ux = match chroma_subsampling {
+                YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => x >> 1,
+                YuvChromaSample::YUV444 => x,
+            };
+            let y_value = (y_src[x] as f32 - bias_y) * transform.y_coef;
+            let cb_value = u_src[ux] as f32 - bias_uv;
+            let cr_value = v_src[ux] as f32 - bias_uv;
+
+            let r = y_value + cr_value * transform.cr_coef;
+            let b = y_value + cb_value * transform.cb_coef;
+            let g = y_value - cr_value * transform.g_coeff_1 - cb_value * transform.g_coeff_2;
+
+            dst[dst_chans.get_r_channel_offset()] = r.max(0.).min(1.);
+            dst[dst_chans.get_g_channel_offset()] = g.max(0.).min(1.);
+            dst[dst_chans.get_b_channel_offset()] = b.max(0.).min(1.);
+            if dst_chans.has_alpha() {
+                dst[dst_chans.get_a_channel_offset()] = 1.;
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Convert YUV 420 planar format to RGBA format with normalized 32-bit float per channel.
+///
+/// This function takes YUV 420 planar format data with 8-bit precision,
+/// and converts it to RGBA format with values in `[0, 1]`, alpha is set to `1.0`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv420_to_rgba_f32(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    rgba: &mut [f32],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgba_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgba_stride.get(),
+    );
+    yuv_to_rgbx_f32::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 420 planar format to RGB format with normalized 32-bit float per channel.
+///
+/// This function takes YUV 420 planar format data with 8-bit precision,
+/// and converts it to RGB format with values in `[0, 1]`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for RGB data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv420_to_rgb_f32(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    rgb: &mut [f32],
+    rgb_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgb_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgb_stride.get(),
+    );
+    yuv_to_rgbx_f32::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert YUV 420 planar format to BGRA format with normalized 32-bit float per channel.
+///
+/// This function takes YUV 420 planar format data with 8-bit precision,
+/// and converts it to BGRA format with values in `[0, 1]`, alpha is set to `1.0`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for BGRA data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv420_to_bgra_f32(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    bgra: &mut [f32],
+    bgra_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, bgra_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        bgra_stride.get(),
+    );
+    yuv_to_rgbx_f32::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 420 planar format to BGR format with normalized 32-bit float per channel.
+///
+/// This function takes YUV 420 planar format data with 8-bit precision,
+/// and converts it to BGR format with values in `[0, 1]`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgr` - A mutable slice to store the converted BGR data.
+/// * `bgr_stride` - The stride (bytes per row) for BGR data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv420_to_bgr_f32(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    bgr: &mut [f32],
+    bgr_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, bgr_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        bgr_stride.get(),
+    );
+    yuv_to_rgbx_f32::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV420 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert YUV 422 planar format to RGBA format with normalized 32-bit float per channel.
+///
+/// This function takes YUV 422 planar format data with 8-bit precision,
+/// and converts it to RGBA format with values in `[0, 1]`, alpha is set to `1.0`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv422_to_rgba_f32(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    rgba: &mut [f32],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgba_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgba_stride.get(),
+    );
+    yuv_to_rgbx_f32::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 422 planar format to RGB format with normalized 32-bit float per channel.
+///
+/// This function takes YUV 422 planar format data with 8-bit precision,
+/// and converts it to RGB format with values in `[0, 1]`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for RGB data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv422_to_rgb_f32(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    rgb: &mut [f32],
+    rgb_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgb_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgb_stride.get(),
+    );
+    yuv_to_rgbx_f32::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert YUV 422 planar format to BGRA format with normalized 32-bit float per channel.
+///
+/// This function takes YUV 422 planar format data with 8-bit precision,
+/// and converts it to BGRA format with values in `[0, 1]`, alpha is set to `1.0`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for BGRA data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv422_to_bgra_f32(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    bgra: &mut [f32],
+    bgra_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, bgra_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        bgra_stride.get(),
+    );
+    yuv_to_rgbx_f32::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 422 planar format to BGR format with normalized 32-bit float per channel.
+///
+/// This function takes YUV 422 planar format data with 8-bit precision,
+/// and converts it to BGR format with values in `[0, 1]`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgr` - A mutable slice to store the converted BGR data.
+/// * `bgr_stride` - The stride (bytes per row) for BGR data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv422_to_bgr_f32(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    bgr: &mut [f32],
+    bgr_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, bgr_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        bgr_stride.get(),
+    );
+    yuv_to_rgbx_f32::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV422 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert YUV 444 planar format to RGBA format with normalized 32-bit float per channel.
+///
+/// This function takes YUV 444 planar format data with 8-bit precision,
+/// and converts it to RGBA format with values in `[0, 1]`, alpha is set to `1.0`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgba` - A mutable slice to store the converted RGBA data.
+/// * `rgba_stride` - The stride (bytes per row) for RGBA data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv444_to_rgba_f32(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    rgba: &mut [f32],
+    rgba_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgba_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgba_stride.get(),
+    );
+    yuv_to_rgbx_f32::<{ YuvSourceChannels::Rgba as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        rgba,
+        rgba_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 444 planar format to RGB format with normalized 32-bit float per channel.
+///
+/// This function takes YUV 444 planar format data with 8-bit precision,
+/// and converts it to RGB format with values in `[0, 1]`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `rgb` - A mutable slice to store the converted RGB data.
+/// * `rgb_stride` - The stride (bytes per row) for RGB data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv444_to_rgb_f32(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    rgb: &mut [f32],
+    rgb_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, rgb_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        rgb_stride.get(),
+    );
+    yuv_to_rgbx_f32::<{ YuvSourceChannels::Rgb as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, rgb, rgb_stride, width, height,
+        range, matrix,
+    )
+}
+
+/// Convert YUV 444 planar format to BGRA format with normalized 32-bit float per channel.
+///
+/// This function takes YUV 444 planar format data with 8-bit precision,
+/// and converts it to BGRA format with values in `[0, 1]`, alpha is set to `1.0`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgra` - A mutable slice to store the converted BGRA data.
+/// * `bgra_stride` - The stride (bytes per row) for BGRA data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv444_to_bgra_f32(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    bgra: &mut [f32],
+    bgra_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, bgra_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        bgra_stride.get(),
+    );
+    yuv_to_rgbx_f32::<{ YuvSourceChannels::Bgra as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane,
+        y_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        bgra,
+        bgra_stride,
+        width,
+        height,
+        range,
+        matrix,
+    )
+}
+
+/// Convert YUV 444 planar format to BGR format with normalized 32-bit float per channel.
+///
+/// This function takes YUV 444 planar format data with 8-bit precision,
+/// and converts it to BGR format with values in `[0, 1]`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `bgr` - A mutable slice to store the converted BGR data.
+/// * `bgr_stride` - The stride (bytes per row) for BGR data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+pub fn yuv444_to_bgr_f32(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    bgr: &mut [f32],
+    bgr_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, bgr_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        bgr_stride.get(),
+    );
+    yuv_to_rgbx_f32::<{ YuvSourceChannels::Bgr as u8 }, { YuvChromaSample::YUV444 as u8 }>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, bgr, bgr_stride, width, height,
+        range, matrix,
+    )
+}
@@ -0,0 +1,454 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::color_description::{YuvColorPrimaries, YuvTransferCharacteristics};
+use crate::images::{ImageSize, RowStride};
+use crate::luma_transfer::LumaTransfer;
+use crate::yuv_error::{check_chroma_source_channel, check_rgba_destination, check_y8_channel};
+use crate::yuv_support::{
+    get_inverse_transform, get_yuv_range, YuvChromaSample, YuvRange, YuvStandardMatrix,
+};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+const LAB_EPSILON: f32 = 216. / 24389.;
+const LAB_KAPPA: f32 = 24389. / 27.;
+
+#[inline(always)]
+fn lab_f(t: f32) -> f32 {
+    if t > LAB_EPSILON {
+        t.cbrt()
+    } else {
+        (LAB_KAPPA * t + 16.) / 116.
+    }
+}
+
+/// Decodes YUV into non-linear RGB, linearizes it with `transfer` and
+/// projects onto CIE XYZ, or CIE L*a*b* when `LAB` is set, in a single pass.
+fn yuv_to_cie<const SAMPLING: u8, const LAB: bool>(
+    y_plane: &[u8],
+    y_stride: u32,
+    u_plane: &[u8],
+    u_stride: u32,
+    v_plane: &[u8],
+    v_stride: u32,
+    dst: &mut [f32],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    transfer: YuvTransferCharacteristics,
+    primaries: YuvColorPrimaries,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSample = SAMPLING.into();
+
+    check_rgba_destination(dst, dst_stride, width, height, 3)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_source_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_source_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
+    let transfer = LumaTransfer::new(transfer)?;
+    let (to_xyz, white) = primaries
+        .rgb_to_xyz()
+        .ok_or(YuvError::UnsupportedColorPrimaries(primaries))?;
+
+    let range = get_yuv_range(8, range);
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
+    let transform = get_inverse_transform(255, range.range_y, range.range_uv, kr_kb.kr, kr_kb.kb);
+    let y_coef = transform.y_coef / 255.;
+    let cr_coef = transform.cr_coef / 255.;
+    let cb_coef = transform.cb_coef / 255.;
+    let g_coeff_1 = transform.g_coeff_1 / 255.;
+    let g_coeff_2 = transform.g_coeff_2 / 255.;
+    let bias_y = range.bias_y as f32;
+    let bias_uv = range.bias_uv as f32;
+
+    let width = width as usize;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride as usize / 4)
+            .take(height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride as usize / 4)
+            .take(height as usize);
+    }
+
+    iter.enumerate().for_each(|(y, dst)| {
+        let chroma_y = match chroma_subsampling {
+            YuvChromaSample::YUV420 => y >> 1,
+            YuvChromaSample::YUV422 | YuvChromaSample::YUV444 => y,
+        };
+        let y_src = &y_plane[y * y_stride as usize..];
+        let u_src = &u_plane[chroma_y * u_stride as usize..];
+        let v_src = &v_plane[chroma_y * v_stride as usize..];
+
+        for (x, dst) in dst.chunks_exact_mut(3).enumerate().take(width) {
+            let ux = match chroma_subsampling {
+                YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => x >> 1,
+                YuvChromaSample::YUV444 => x,
+            };
+            let y_value = (y_src[x] as f32 - bias_y) * y_coef;
+            let cb_value = u_src[ux] as f32 - bias_uv;
+            let cr_value = v_src[ux] as f32 - bias_uv;
+
+            let r = transfer.linearize((y_value + cr_value * cr_coef).max(0.).min(1.));
+            let g = transfer.linearize(
+                (y_value - cr_value * g_coeff_1 - cb_value * g_coeff_2)
+                    .max(0.)
+                    .min(1.),
+            );
+            let b = transfer.linearize((y_value + cb_value * cb_coef).max(0.).min(1.));
+
+            let cx = to_xyz[0][0] * r + to_xyz[0][1] * g + to_xyz[0][2] * b;
+            let cy = to_xyz[1][0] * r + to_xyz[1][1] * g + to_xyz[1][2] * b;
+            let cz = to_xyz[2][0] * r + to_xyz[2][1] * g + to_xyz[2][2] * b;
+
+            if LAB {
+                let fx = lab_f(cx / white[0]);
+                let fy = lab_f(cy / white[1]);
+                let fz = lab_f(cz / white[2]);
+                dst[0] = 116. * fy - 16.;
+                dst[1] = 500. * (fx - fy);
+                dst[2] = 200. * (fy - fz);
+            } else {
+                dst[0] = cx;
+                dst[1] = cy;
+                dst[2] = cz;
+            }
+        }
+    });
+
+    Ok(())
+}
+
+/// Convert YUV 420 planar format to CIE XYZ with 32-bit float per channel.
+///
+/// Samples are decoded into RGB, brought to linear light with `transfer` and projected
+/// onto XYZ according to `primaries`. Linear light is normalized so that the transfer
+/// peak has `Y = 1`, the white point of `primaries` is used as is, without adaptation.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `xyz` - A mutable slice to store interleaved X, Y, Z values.
+/// * `xyz_stride` - The stride (bytes per row) for XYZ data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `transfer` - Transfer characteristics the samples are encoded with.
+/// * `primaries` - Colour primaries of the RGB decoded from the samples.
+///
+pub fn yuv420_to_xyz(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    xyz: &mut [f32],
+    xyz_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    transfer: YuvTransferCharacteristics,
+    primaries: YuvColorPrimaries,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, xyz_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        xyz_stride.get(),
+    );
+    yuv_to_cie::<{ YuvChromaSample::YUV420 as u8 }, false>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, xyz, xyz_stride, width, height,
+        range, matrix, transfer, primaries,
+    )
+}
+
+/// Convert YUV 420 planar format to CIE L*a*b* with 32-bit float per channel.
+///
+/// Same as [yuv420_to_xyz] followed by L*a*b* relative to the white point of `primaries`,
+/// `L*` is in `[0, 100]`. Output is ready for colour difference metrics such as CIEDE2000.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `lab` - A mutable slice to store interleaved L*, a*, b* values.
+/// * `lab_stride` - The stride (bytes per row) for L*a*b* data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `transfer` - Transfer characteristics the samples are encoded with.
+/// * `primaries` - Colour primaries of the RGB decoded from the samples.
+///
+pub fn yuv420_to_lab(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    lab: &mut [f32],
+    lab_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    transfer: YuvTransferCharacteristics,
+    primaries: YuvColorPrimaries,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, lab_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        lab_stride.get(),
+    );
+    yuv_to_cie::<{ YuvChromaSample::YUV420 as u8 }, true>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, lab, lab_stride, width, height,
+        range, matrix, transfer, primaries,
+    )
+}
+
+/// Convert YUV 422 planar format to CIE XYZ with 32-bit float per channel.
+///
+/// Samples are decoded into RGB, brought to linear light with `transfer` and projected
+/// onto XYZ according to `primaries`. Linear light is normalized so that the transfer
+/// peak has `Y = 1`, the white point of `primaries` is used as is, without adaptation.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `xyz` - A mutable slice to store interleaved X, Y, Z values.
+/// * `xyz_stride` - The stride (bytes per row) for XYZ data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `transfer` - Transfer characteristics the samples are encoded with.
+/// * `primaries` - Colour primaries of the RGB decoded from the samples.
+///
+pub fn yuv422_to_xyz(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    xyz: &mut [f32],
+    xyz_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    transfer: YuvTransferCharacteristics,
+    primaries: YuvColorPrimaries,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, xyz_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        xyz_stride.get(),
+    );
+    yuv_to_cie::<{ YuvChromaSample::YUV422 as u8 }, false>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, xyz, xyz_stride, width, height,
+        range, matrix, transfer, primaries,
+    )
+}
+
+/// Convert YUV 422 planar format to CIE L*a*b* with 32-bit float per channel.
+///
+/// Same as [yuv422_to_xyz] followed by L*a*b* relative to the white point of `primaries`,
+/// `L*` is in `[0, 100]`. Output is ready for colour difference metrics such as CIEDE2000.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `lab` - A mutable slice to store interleaved L*, a*, b* values.
+/// * `lab_stride` - The stride (bytes per row) for L*a*b* data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `transfer` - Transfer characteristics the samples are encoded with.
+/// * `primaries` - Colour primaries of the RGB decoded from the samples.
+///
+pub fn yuv422_to_lab(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    lab: &mut [f32],
+    lab_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    transfer: YuvTransferCharacteristics,
+    primaries: YuvColorPrimaries,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, lab_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        lab_stride.get(),
+    );
+    yuv_to_cie::<{ YuvChromaSample::YUV422 as u8 }, true>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, lab, lab_stride, width, height,
+        range, matrix, transfer, primaries,
+    )
+}
+
+/// Convert YUV 444 planar format to CIE XYZ with 32-bit float per channel.
+///
+/// Samples are decoded into RGB, brought to linear light with `transfer` and projected
+/// onto XYZ according to `primaries`. Linear light is normalized so that the transfer
+/// peak has `Y = 1`, the white point of `primaries` is used as is, without adaptation.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `xyz` - A mutable slice to store interleaved X, Y, Z values.
+/// * `xyz_stride` - The stride (bytes per row) for XYZ data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `transfer` - Transfer characteristics the samples are encoded with.
+/// * `primaries` - Colour primaries of the RGB decoded from the samples.
+///
+pub fn yuv444_to_xyz(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    xyz: &mut [f32],
+    xyz_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    transfer: YuvTransferCharacteristics,
+    primaries: YuvColorPrimaries,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, xyz_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        xyz_stride.get(),
+    );
+    yuv_to_cie::<{ YuvChromaSample::YUV444 as u8 }, false>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, xyz, xyz_stride, width, height,
+        range, matrix, transfer, primaries,
+    )
+}
+
+/// Convert YUV 444 planar format to CIE L*a*b* with 32-bit float per channel.
+///
+/// Same as [yuv444_to_xyz] followed by L*a*b* relative to the white point of `primaries`,
+/// `L*` is in `[0, 100]`. Output is ready for colour difference metrics such as CIEDE2000.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `u_plane` - A slice to load the U (chrominance) plane data.
+/// * `u_stride` - The stride (bytes per row) for the U plane.
+/// * `v_plane` - A slice to load the V (chrominance) plane data.
+/// * `v_stride` - The stride (bytes per row) for the V plane.
+/// * `lab` - A mutable slice to store interleaved L*, a*, b* values.
+/// * `lab_stride` - The stride (bytes per row) for L*a*b* data.
+/// * `size` - The size of the image.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+/// * `transfer` - Transfer characteristics the samples are encoded with.
+/// * `primaries` - Colour primaries of the RGB decoded from the samples.
+///
+pub fn yuv444_to_lab(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    u_plane: &[u8],
+    u_stride: RowStride,
+    v_plane: &[u8],
+    v_stride: RowStride,
+    lab: &mut [f32],
+    lab_stride: RowStride,
+    size: ImageSize,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    transfer: YuvTransferCharacteristics,
+    primaries: YuvColorPrimaries,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, u_stride, v_stride, lab_stride) = (
+        y_stride.get(),
+        u_stride.get(),
+        v_stride.get(),
+        lab_stride.get(),
+    );
+    yuv_to_cie::<{ YuvChromaSample::YUV444 as u8 }, true>(
+        y_plane, y_stride, u_plane, u_stride, v_plane, v_stride, lab, lab_stride, width, height,
+        range, matrix, transfer, primaries,
+    )
+}
@@ -26,13 +26,27 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::yuv_to_yuy2_avx2_row;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::yuv_to_yuy2_neon_impl;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "profiling")]
+use crate::profiling::{dispatch_backend, rayon_splits, ProfileScope};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::yuv_to_yuy2_sse_impl;
+use crate::yuv_error::{check_chroma_channel, check_y8_channel, check_yuv_packed422};
 use crate::yuv_support::{YuvChromaSample, Yuy2Description};
+use crate::YuvError;
 #[cfg(feature = "rayon")]
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 #[cfg(feature = "rayon")]
@@ -53,7 +67,7 @@ impl YuvToYuy2Navigation {
     }
 }
 
-fn yuv_to_yuy2_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
+pub(crate) fn yuv_to_yuy2_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
     y_plane: &[u8],
     y_stride: u32,
     u_plane: &[u8],
@@ -63,29 +77,48 @@ fn yuv_to_yuy2_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
     yuy2_store: &mut [u8],
     yuy2_stride: u32,
     width: u32,
-    _: u32,
-) {
+    height: u32,
+) -> Result<(), YuvError> {
     let yuy2_target: Yuy2Description = YUY2_TARGET.into();
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
 
+    check_yuv_packed422(yuy2_store, yuy2_stride, width, height)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
+    #[cfg(feature = "profiling")]
+    let _profile = ProfileScope::new(
+        "yuv_to_yuy2",
+        y_plane.len() + u_plane.len() + v_plane.len() + yuy2_store.len(),
+        dispatch_backend(false, false),
+        rayon_splits(height),
+    );
+
     let yuy_offset = 0usize;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = is_x86_feature_detected!("sse4.1");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_avx2 = is_x86_feature_detected!("avx2");
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_sse = crate::cpu_features::has_sse41();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_avx2 = crate::cpu_features::has_avx2();
 
     let iter;
     #[cfg(feature = "rayon")]
     {
-        iter = yuy2_store.par_chunks_exact_mut(yuy2_stride as usize);
+        iter = yuy2_store.par_chunks_mut(yuy2_stride as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        iter = yuy2_store.chunks_exact_mut(yuy2_stride as usize);
+        iter = yuy2_store.chunks_mut(yuy2_stride as usize);
     }
 
-    iter.enumerate().for_each(|(y, yuy2_store)| unsafe {
+    iter.enumerate().for_each(|(y, yuy2_store)| {
         let y_offset = y * (y_stride as usize);
         let u_offset = if chroma_subsampling == YuvChromaSample::YUV420 {
             (y >> 1) * (u_stride as usize)
@@ -102,8 +135,11 @@ fn yuv_to_yuy2_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
         let mut _uv_x = 0usize;
         let mut _yuy2_x = 0usize;
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-        {
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        unsafe {
             if _use_avx2 {
                 let processed = yuv_to_yuy2_avx2_row::<SAMPLING, YUY2_TARGET>(
                     y_plane,
@@ -140,7 +176,11 @@ fn yuv_to_yuy2_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
             }
         }
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         {
             let processed = yuv_to_yuy2_neon_impl::<SAMPLING, YUY2_TARGET>(
                 y_plane,
@@ -167,28 +207,22 @@ fn yuv_to_yuy2_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
             let (u_value, v_value);
 
             if chroma_subsampling == YuvChromaSample::YUV444 {
-                u_value = (((*u_plane.get_unchecked(u_pos) as u32
-                    + *u_plane.get_unchecked(u_pos + 1) as u32)
-                    + 1)
-                    >> 1) as u8;
-                v_value = (((*v_plane.get_unchecked(v_pos) as u32
-                    + *v_plane.get_unchecked(v_pos + 1) as u32)
-                    + 1)
-                    >> 1) as u8;
+                u_value = (((u_plane[u_pos] as u32 + u_plane[u_pos + 1] as u32) + 1) >> 1) as u8;
+                v_value = (((v_plane[v_pos] as u32 + v_plane[v_pos + 1] as u32) + 1) >> 1) as u8;
             } else {
-                u_value = *u_plane.get_unchecked(u_pos);
-                v_value = *v_plane.get_unchecked(v_pos);
+                u_value = u_plane[u_pos];
+                v_value = v_plane[v_pos];
             }
 
-            let first_y_value = *y_plane.get_unchecked(y_pos);
-            let second_y_value = *y_plane.get_unchecked(y_pos + 1);
+            let first_y_value = y_plane[y_pos];
+            let second_y_value = y_plane[y_pos + 1];
 
             let dst_offset = yuy_offset + x * 4;
-            let dst_store = yuy2_store.get_unchecked_mut(dst_offset..);
-            *dst_store.get_unchecked_mut(yuy2_target.get_first_y_position()) = first_y_value;
-            *dst_store.get_unchecked_mut(yuy2_target.get_u_position()) = u_value;
-            *dst_store.get_unchecked_mut(yuy2_target.get_second_y_position()) = second_y_value;
-            *dst_store.get_unchecked_mut(yuy2_target.get_v_position()) = v_value;
+            let dst_store = &mut yuy2_store[dst_offset..];
+            dst_store[yuy2_target.get_first_y_position()] = first_y_value;
+            dst_store[yuy2_target.get_u_position()] = u_value;
+            dst_store[yuy2_target.get_second_y_position()] = second_y_value;
+            dst_store[yuy2_target.get_v_position()] = v_value;
 
             _uv_x += match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => 1,
@@ -202,26 +236,27 @@ fn yuv_to_yuy2_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
             let v_pos = v_offset + _uv_x;
             let y_pos = y_offset + _cx;
 
-            let u_value = *u_plane.get_unchecked(u_pos);
-            let v_value = *v_plane.get_unchecked(v_pos);
+            let u_value = u_plane[u_pos];
+            let v_value = v_plane[v_pos];
 
-            let first_y_value = *y_plane.get_unchecked(y_pos);
+            let first_y_value = y_plane[y_pos];
 
             let dst_offset = yuy_offset + ((width as usize - 1) / 2) * 4;
-            let dst_store = yuy2_store.get_unchecked_mut(dst_offset..);
-            *dst_store.get_unchecked_mut(yuy2_target.get_first_y_position()) = first_y_value;
-            *dst_store.get_unchecked_mut(yuy2_target.get_u_position()) = u_value;
-            *dst_store.get_unchecked_mut(yuy2_target.get_second_y_position()) = 0;
-            *dst_store.get_unchecked_mut(yuy2_target.get_v_position()) = v_value;
+            let dst_store = &mut yuy2_store[dst_offset..];
+            dst_store[yuy2_target.get_first_y_position()] = first_y_value;
+            dst_store[yuy2_target.get_u_position()] = u_value;
+            dst_store[yuy2_target.get_second_y_position()] = first_y_value;
+            dst_store[yuy2_target.get_v_position()] = v_value;
         }
     });
+
+    Ok(())
 }
 
 /// Convert YUV 444 planar format to YUYV ( YUV Packed ) format.
 ///
 /// This function takes YUV 444 planar format data with 8-bit precision,
 /// and converts it to YUYV format with 8-bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -236,11 +271,12 @@ fn yuv_to_yuy2_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
 /// * `yuy2_store` - A mutable slice to store the converted YUYV data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function panics if the lengths of the planes or the input YUYV data are not valid based
-/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
+/// Returns [YuvError::StrideTooSmall] when a stride cannot hold a row of the image,
+/// [YuvError::PackedFrameSizeMismatch] when the YUYV data or [YuvError::LumaPlaneSizeMismatch]
+/// when a Y, U or V plane does not match width, height and stride,
+/// and [YuvError::ImageTooLarge] when a plane does not fit into addressable memory.
 ///
 pub fn yuv444_to_yuyv422(
     y_plane: &[u8],
@@ -253,7 +289,7 @@ pub fn yuv444_to_yuyv422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::YUYV as usize }>(
         y_plane,
         y_stride,
@@ -265,14 +301,13 @@ pub fn yuv444_to_yuyv422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 422 planar format to YUYV ( YUV Packed ) format.
 ///
 /// This function takes YUV 422 planar format data with 8-bit precision,
 /// and converts it to YUYV format with 8-bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -287,11 +322,12 @@ pub fn yuv444_to_yuyv422(
 /// * `yuy2_store` - A mutable slice to store the converted YUYV data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function panics if the lengths of the planes or the input YUYV data are not valid based
-/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
+/// Returns [YuvError::StrideTooSmall] when a stride cannot hold a row of the image,
+/// [YuvError::PackedFrameSizeMismatch] when the YUYV data or [YuvError::LumaPlaneSizeMismatch]
+/// when a Y, U or V plane does not match width, height and stride,
+/// and [YuvError::ImageTooLarge] when a plane does not fit into addressable memory.
 ///
 pub fn yuv422_to_yuyv422(
     y_plane: &[u8],
@@ -304,7 +340,7 @@ pub fn yuv422_to_yuyv422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::YUYV as usize }>(
         y_plane,
         y_stride,
@@ -316,14 +352,13 @@ pub fn yuv422_to_yuyv422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format to YUYV ( YUV Packed ) format.
 ///
 /// This function takes YUV 420 planar format data with 8-bit precision,
 /// and converts it to YUYV format with 8-bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -338,11 +373,12 @@ pub fn yuv422_to_yuyv422(
 /// * `yuy2_store` - A mutable slice to store the converted YUYV data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function panics if the lengths of the planes or the input YUYV data are not valid based
-/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
+/// Returns [YuvError::StrideTooSmall] when a stride cannot hold a row of the image,
+/// [YuvError::PackedFrameSizeMismatch] when the YUYV data or [YuvError::LumaPlaneSizeMismatch]
+/// when a Y, U or V plane does not match width, height and stride,
+/// and [YuvError::ImageTooLarge] when a plane does not fit into addressable memory.
 ///
 pub fn yuv420_to_yuyv422(
     y_plane: &[u8],
@@ -355,7 +391,7 @@ pub fn yuv420_to_yuyv422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::YUYV as usize }>(
         y_plane,
         y_stride,
@@ -367,14 +403,13 @@ pub fn yuv420_to_yuyv422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format to YVYU ( YUV Packed ) format.
 ///
 /// This function takes YUV 444 planar format data with 8-bit precision,
 /// and converts it to YVYU format with 8-bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -389,11 +424,12 @@ pub fn yuv420_to_yuyv422(
 /// * `yuy2_store` - A mutable slice to store the converted YVYU data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function panics if the lengths of the planes or the input YVYU data are not valid based
-/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
+/// Returns [YuvError::StrideTooSmall] when a stride cannot hold a row of the image,
+/// [YuvError::PackedFrameSizeMismatch] when the YVYU data or [YuvError::LumaPlaneSizeMismatch]
+/// when a Y, U or V plane does not match width, height and stride,
+/// and [YuvError::ImageTooLarge] when a plane does not fit into addressable memory.
 ///
 pub fn yuv444_to_yvyu422(
     y_plane: &[u8],
@@ -406,7 +442,7 @@ pub fn yuv444_to_yvyu422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::YVYU as usize }>(
         y_plane,
         y_stride,
@@ -418,14 +454,13 @@ pub fn yuv444_to_yvyu422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 422 planar format to YVYU ( YUV Packed ) format.
 ///
 /// This function takes YUV 422 planar format data with 8-bit precision,
 /// and converts it to YVYU format with 8-bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -440,11 +475,12 @@ pub fn yuv444_to_yvyu422(
 /// * `yuy2_store` - A mutable slice to store the converted YVYU data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function panics if the lengths of the planes or the input YVYU data are not valid based
-/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
+/// Returns [YuvError::StrideTooSmall] when a stride cannot hold a row of the image,
+/// [YuvError::PackedFrameSizeMismatch] when the YVYU data or [YuvError::LumaPlaneSizeMismatch]
+/// when a Y, U or V plane does not match width, height and stride,
+/// and [YuvError::ImageTooLarge] when a plane does not fit into addressable memory.
 ///
 pub fn yuv422_to_yvyu422(
     y_plane: &[u8],
@@ -457,7 +493,7 @@ pub fn yuv422_to_yvyu422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::YVYU as usize }>(
         y_plane,
         y_stride,
@@ -469,14 +505,13 @@ pub fn yuv422_to_yvyu422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format to YVYU ( YUV Packed ) format.
 ///
 /// This function takes YUV 420 planar format data with 8-bit precision,
 /// and converts it to YVYU format with 8-bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -491,11 +526,12 @@ pub fn yuv422_to_yvyu422(
 /// * `yuy2_store` - A mutable slice to store the converted YVYU data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function panics if the lengths of the planes or the input YVYU data are not valid based
-/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
+/// Returns [YuvError::StrideTooSmall] when a stride cannot hold a row of the image,
+/// [YuvError::PackedFrameSizeMismatch] when the YVYU data or [YuvError::LumaPlaneSizeMismatch]
+/// when a Y, U or V plane does not match width, height and stride,
+/// and [YuvError::ImageTooLarge] when a plane does not fit into addressable memory.
 ///
 pub fn yuv420_to_yvyu422(
     y_plane: &[u8],
@@ -508,7 +544,7 @@ pub fn yuv420_to_yvyu422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::YVYU as usize }>(
         y_plane,
         y_stride,
@@ -520,14 +556,13 @@ pub fn yuv420_to_yvyu422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format to VYUY ( YUV Packed ) format.
 ///
 /// This function takes YUV 444 planar format data with 8-bit precision,
 /// and converts it to VYUY format with 8-bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -542,11 +577,12 @@ pub fn yuv420_to_yvyu422(
 /// * `yuy2_store` - A mutable slice to store the converted VYUY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function panics if the lengths of the planes or the input VYUY data are not valid based
-/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
+/// Returns [YuvError::StrideTooSmall] when a stride cannot hold a row of the image,
+/// [YuvError::PackedFrameSizeMismatch] when the VYUY data or [YuvError::LumaPlaneSizeMismatch]
+/// when a Y, U or V plane does not match width, height and stride,
+/// and [YuvError::ImageTooLarge] when a plane does not fit into addressable memory.
 ///
 pub fn yuv444_to_vyuy422(
     y_plane: &[u8],
@@ -559,7 +595,7 @@ pub fn yuv444_to_vyuy422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::VYUY as usize }>(
         y_plane,
         y_stride,
@@ -571,14 +607,13 @@ pub fn yuv444_to_vyuy422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 422 planar format to VYUY ( YUV Packed ) format.
 ///
 /// This function takes YUV 422 planar format data with 8-bit precision,
 /// and converts it to VYUY format with 8-bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -593,11 +628,12 @@ pub fn yuv444_to_vyuy422(
 /// * `yuy2_store` - A mutable slice to store the converted VYUY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function panics if the lengths of the planes or the input VYUY data are not valid based
-/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
+/// Returns [YuvError::StrideTooSmall] when a stride cannot hold a row of the image,
+/// [YuvError::PackedFrameSizeMismatch] when the VYUY data or [YuvError::LumaPlaneSizeMismatch]
+/// when a Y, U or V plane does not match width, height and stride,
+/// and [YuvError::ImageTooLarge] when a plane does not fit into addressable memory.
 ///
 pub fn yuv422_to_vyuy422(
     y_plane: &[u8],
@@ -610,7 +646,7 @@ pub fn yuv422_to_vyuy422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::VYUY as usize }>(
         y_plane,
         y_stride,
@@ -622,14 +658,13 @@ pub fn yuv422_to_vyuy422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format to VYUY ( YUV Packed ) format.
 ///
 /// This function takes YUV 420 planar format data with 8-bit precision,
 /// and converts it to VYUY format with 8-bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -644,11 +679,12 @@ pub fn yuv422_to_vyuy422(
 /// * `yuy2_store` - A mutable slice to store the converted VYUY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function panics if the lengths of the planes or the input VYUY data are not valid based
-/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
+/// Returns [YuvError::StrideTooSmall] when a stride cannot hold a row of the image,
+/// [YuvError::PackedFrameSizeMismatch] when the VYUY data or [YuvError::LumaPlaneSizeMismatch]
+/// when a Y, U or V plane does not match width, height and stride,
+/// and [YuvError::ImageTooLarge] when a plane does not fit into addressable memory.
 ///
 pub fn yuv420_to_vyuy422(
     y_plane: &[u8],
@@ -661,7 +697,7 @@ pub fn yuv420_to_vyuy422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::VYUY as usize }>(
         y_plane,
         y_stride,
@@ -673,14 +709,13 @@ pub fn yuv420_to_vyuy422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format to UYVY ( YUV Packed ) format.
 ///
 /// This function takes YUV 444 planar format data with 8-bit precision,
 /// and converts it to UYVY format with 8-bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -695,11 +730,12 @@ pub fn yuv420_to_vyuy422(
 /// * `yuy2_store` - A mutable slice to store the converted UYVY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function panics if the lengths of the planes or the input UYVY data are not valid based
-/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
+/// Returns [YuvError::StrideTooSmall] when a stride cannot hold a row of the image,
+/// [YuvError::PackedFrameSizeMismatch] when the UYVY data or [YuvError::LumaPlaneSizeMismatch]
+/// when a Y, U or V plane does not match width, height and stride,
+/// and [YuvError::ImageTooLarge] when a plane does not fit into addressable memory.
 ///
 pub fn yuv444_to_uyvy422(
     y_plane: &[u8],
@@ -712,7 +748,7 @@ pub fn yuv444_to_uyvy422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::UYVY as usize }>(
         y_plane,
         y_stride,
@@ -724,14 +760,13 @@ pub fn yuv444_to_uyvy422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 422 planar format to UYVY ( YUV Packed ) format.
 ///
 /// This function takes YUV 422 planar format data with 8-bit precision,
 /// and converts it to UYVY format with 8-bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -746,11 +781,12 @@ pub fn yuv444_to_uyvy422(
 /// * `yuy2_store` - A mutable slice to store the converted UYVY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function panics if the lengths of the planes or the input UYVY data are not valid based
-/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
+/// Returns [YuvError::StrideTooSmall] when a stride cannot hold a row of the image,
+/// [YuvError::PackedFrameSizeMismatch] when the UYVY data or [YuvError::LumaPlaneSizeMismatch]
+/// when a Y, U or V plane does not match width, height and stride,
+/// and [YuvError::ImageTooLarge] when a plane does not fit into addressable memory.
 ///
 pub fn yuv422_to_uyvy422(
     y_plane: &[u8],
@@ -763,7 +799,7 @@ pub fn yuv422_to_uyvy422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::UYVY as usize }>(
         y_plane,
         y_stride,
@@ -775,14 +811,13 @@ pub fn yuv422_to_uyvy422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format to UYVY ( YUV Packed ) format.
 ///
 /// This function takes YUV 420 planar format data with 8-bit precision,
 /// and converts it to UYVY format with 8-bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -797,11 +832,12 @@ pub fn yuv422_to_uyvy422(
 /// * `yuy2_store` - A mutable slice to store the converted UYVY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
 ///
-/// # Panics
+/// # Errors
 ///
-/// This function panics if the lengths of the planes or the input UYVY data are not valid based
-/// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
+/// Returns [YuvError::StrideTooSmall] when a stride cannot hold a row of the image,
+/// [YuvError::PackedFrameSizeMismatch] when the UYVY data or [YuvError::LumaPlaneSizeMismatch]
+/// when a Y, U or V plane does not match width, height and stride,
+/// and [YuvError::ImageTooLarge] when a plane does not fit into addressable memory.
 ///
 pub fn yuv420_to_uyvy422(
     y_plane: &[u8],
@@ -814,7 +850,7 @@ pub fn yuv420_to_uyvy422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::UYVY as usize }>(
         y_plane,
         y_stride,
@@ -826,5 +862,5 @@ pub fn yuv420_to_uyvy422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
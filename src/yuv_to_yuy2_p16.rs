@@ -26,7 +26,9 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+use crate::yuv_error::{check_chroma_channel, check_y8_channel, check_yuv_packed422};
 use crate::yuv_support::{YuvChromaSample, Yuy2Description};
+use crate::YuvError;
 
 fn yuv_to_yuy2_impl_p16<const SAMPLING: u8, const YUY2_TARGET: usize>(
     y_plane: &[u16],
@@ -39,10 +41,15 @@ fn yuv_to_yuy2_impl_p16<const SAMPLING: u8, const YUY2_TARGET: usize>(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     let yuy2_target: Yuy2Description = YUY2_TARGET.into();
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
 
+    check_yuv_packed422(yuy2_store, yuy2_stride, width, height)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
     let mut y_offset = 0usize;
     let mut u_offset = 0usize;
     let mut v_offset = 0usize;
@@ -54,53 +61,33 @@ fn yuv_to_yuy2_impl_p16<const SAMPLING: u8, const YUY2_TARGET: usize>(
         let mut _yuy2_x = 0usize;
 
         for x in _yuy2_x..width as usize / 2 {
-            unsafe {
-                let u_pos = _uv_x;
-                let v_pos = _uv_x;
-                let y_pos = _cx;
+            let u_pos = _uv_x;
+            let v_pos = _uv_x;
+            let y_pos = _cx;
 
-                let y_src_ptr =
-                    ((y_plane.as_ptr() as *const u8).add(y_offset) as *const u16).add(y_pos);
-                let u_src_ptr =
-                    ((u_plane.as_ptr() as *const u8).add(u_offset) as *const u16).add(u_pos);
-                let v_src_ptr =
-                    ((v_plane.as_ptr() as *const u8).add(v_offset) as *const u16).add(v_pos);
+            let y_src = &y_plane[y_offset / 2 + y_pos..];
+            let u_src = &u_plane[u_offset / 2 + u_pos..];
+            let v_src = &v_plane[v_offset / 2 + v_pos..];
 
-                let (u_value, v_value);
+            let (u_value, v_value);
 
-                if chroma_subsampling == YuvChromaSample::YUV444 {
-                    u_value = (((u_src_ptr.read_unaligned() as u32
-                        + u_src_ptr.add(1).read_unaligned() as u32)
-                        + 1)
-                        >> 1) as u16;
-                    v_value = (((v_src_ptr.read_unaligned() as u32
-                        + v_src_ptr.add(1).read_unaligned() as u32)
-                        + 1)
-                        >> 1) as u16;
-                } else {
-                    u_value = u_src_ptr.read_unaligned();
-                    v_value = v_src_ptr.read_unaligned();
-                }
+            if chroma_subsampling == YuvChromaSample::YUV444 {
+                u_value = (((u_src[0] as u32 + u_src[1] as u32) + 1) >> 1) as u16;
+                v_value = (((v_src[0] as u32 + v_src[1] as u32) + 1) >> 1) as u16;
+            } else {
+                u_value = u_src[0];
+                v_value = v_src[0];
+            }
 
-                let first_y_value = y_src_ptr.read_unaligned();
-                let second_y_value = y_src_ptr.add(1).read_unaligned();
+            let first_y_value = y_src[0];
+            let second_y_value = y_src[1];
 
-                let dst_ptr =
-                    ((yuy2_store.as_ptr() as *mut u8).add(yuy_offset) as *mut u16).add(x * 4);
+            let dst = &mut yuy2_store[yuy_offset / 2 + x * 4..];
 
-                dst_ptr
-                    .add(yuy2_target.get_first_y_position())
-                    .write_unaligned(first_y_value);
-                dst_ptr
-                    .add(yuy2_target.get_u_position())
-                    .write_unaligned(u_value);
-                dst_ptr
-                    .add(yuy2_target.get_second_y_position())
-                    .write_unaligned(second_y_value);
-                dst_ptr
-                    .add(yuy2_target.get_v_position())
-                    .write_unaligned(v_value);
-            }
+            dst[yuy2_target.get_first_y_position()] = first_y_value;
+            dst[yuy2_target.get_u_position()] = u_value;
+            dst[yuy2_target.get_second_y_position()] = second_y_value;
+            dst[yuy2_target.get_v_position()] = v_value;
 
             _uv_x += match chroma_subsampling {
                 YuvChromaSample::YUV420 | YuvChromaSample::YUV422 => 1,
@@ -110,38 +97,24 @@ fn yuv_to_yuy2_impl_p16<const SAMPLING: u8, const YUY2_TARGET: usize>(
         }
 
         if width & 1 == 1 {
-            unsafe {
-                let u_pos = _uv_x;
-                let v_pos = _uv_x;
-                let y_pos = _cx;
+            let u_pos = _uv_x;
+            let v_pos = _uv_x;
+            let y_pos = _cx;
 
-                let y_src_ptr =
-                    ((y_plane.as_ptr() as *const u8).add(y_offset) as *const u16).add(y_pos);
-                let u_src_ptr =
-                    ((u_plane.as_ptr() as *const u8).add(u_offset) as *const u16).add(u_pos);
-                let v_src_ptr =
-                    ((v_plane.as_ptr() as *const u8).add(v_offset) as *const u16).add(v_pos);
+            let y_src = &y_plane[y_offset / 2 + y_pos..];
+            let u_src = &u_plane[u_offset / 2 + u_pos..];
+            let v_src = &v_plane[v_offset / 2 + v_pos..];
 
-                let u_value = u_src_ptr.read_unaligned();
-                let v_value = v_src_ptr.read_unaligned();
+            let u_value = u_src[0];
+            let v_value = v_src[0];
 
-                let first_y_value = y_src_ptr.read_unaligned();
+            let first_y_value = y_src[0];
 
-                let dst_ptr = ((yuy2_store.as_ptr() as *mut u8).add(yuy_offset) as *mut u16)
-                    .add(((width as usize - 1) / 2) * 4);
-                dst_ptr
-                    .add(yuy2_target.get_first_y_position())
-                    .write_unaligned(first_y_value);
-                dst_ptr
-                    .add(yuy2_target.get_u_position())
-                    .write_unaligned(u_value);
-                dst_ptr
-                    .add(yuy2_target.get_second_y_position())
-                    .write_unaligned(0);
-                dst_ptr
-                    .add(yuy2_target.get_v_position())
-                    .write_unaligned(v_value);
-            }
+            let dst = &mut yuy2_store[yuy_offset / 2 + ((width as usize - 1) / 2) * 4..];
+            dst[yuy2_target.get_first_y_position()] = first_y_value;
+            dst[yuy2_target.get_u_position()] = u_value;
+            dst[yuy2_target.get_second_y_position()] = first_y_value;
+            dst[yuy2_target.get_v_position()] = v_value;
         }
 
         y_offset += y_stride as usize;
@@ -159,13 +132,14 @@ fn yuv_to_yuy2_impl_p16<const SAMPLING: u8, const YUY2_TARGET: usize>(
             }
         }
     }
+
+    Ok(())
 }
 
 /// Convert YUV 444 planar format to YUYV ( YUV Packed ) format.
 ///
 /// This function takes YUV 444 planar format data with 8-16 bit precision,
 /// and converts it to YUYV format with 8-16 bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -184,7 +158,6 @@ fn yuv_to_yuy2_impl_p16<const SAMPLING: u8, const YUY2_TARGET: usize>(
 ///
 /// This function panics if the lengths of the planes or the input YUYV data are not valid based
 /// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
 ///
 pub fn yuv444_to_yuyv422_p16(
     y_plane: &[u16],
@@ -197,7 +170,7 @@ pub fn yuv444_to_yuyv422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl_p16::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::YUYV as usize }>(
         y_plane,
         y_stride,
@@ -209,14 +182,13 @@ pub fn yuv444_to_yuyv422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 422 planar format to YUYV ( YUV Packed ) format.
 ///
 /// This function takes YUV 422 planar format data with 8-16 bit precision,
 /// and converts it to YUYV format with 8-16 bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -235,7 +207,6 @@ pub fn yuv444_to_yuyv422_p16(
 ///
 /// This function panics if the lengths of the planes or the input YUYV data are not valid based
 /// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
 ///
 pub fn yuv422_to_yuyv422_p16(
     y_plane: &[u16],
@@ -248,7 +219,7 @@ pub fn yuv422_to_yuyv422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl_p16::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::YUYV as usize }>(
         y_plane,
         y_stride,
@@ -260,14 +231,13 @@ pub fn yuv422_to_yuyv422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format to YUYV ( YUV Packed ) format.
 ///
 /// This function takes YUV 420 planar format data with 8-16 bit precision,
 /// and converts it to YUYV format with 8-16 bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -286,7 +256,6 @@ pub fn yuv422_to_yuyv422_p16(
 ///
 /// This function panics if the lengths of the planes or the input YUYV data are not valid based
 /// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
 ///
 pub fn yuv420_to_yuyv422_p16(
     y_plane: &[u16],
@@ -299,7 +268,7 @@ pub fn yuv420_to_yuyv422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl_p16::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::YUYV as usize }>(
         y_plane,
         y_stride,
@@ -311,14 +280,13 @@ pub fn yuv420_to_yuyv422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format to YVYU ( YUV Packed ) format.
 ///
 /// This function takes YUV 444 planar format data with 8-16 bit precision,
 /// and converts it to YVYU format with 8-16 bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -337,7 +305,6 @@ pub fn yuv420_to_yuyv422_p16(
 ///
 /// This function panics if the lengths of the planes or the input YVYU data are not valid based
 /// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
 ///
 pub fn yuv444_to_yvyu422_p16(
     y_plane: &[u16],
@@ -350,7 +317,7 @@ pub fn yuv444_to_yvyu422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl_p16::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::YVYU as usize }>(
         y_plane,
         y_stride,
@@ -362,14 +329,13 @@ pub fn yuv444_to_yvyu422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 422 planar format to YVYU ( YUV Packed ) format.
 ///
 /// This function takes YUV 422 planar format data with 8-16 bit precision,
 /// and converts it to YVYU format with 8-16 bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -388,7 +354,6 @@ pub fn yuv444_to_yvyu422_p16(
 ///
 /// This function panics if the lengths of the planes or the input YVYU data are not valid based
 /// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
 ///
 pub fn yuv422_to_yvyu422_p16(
     y_plane: &[u16],
@@ -401,7 +366,7 @@ pub fn yuv422_to_yvyu422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl_p16::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::YVYU as usize }>(
         y_plane,
         y_stride,
@@ -413,14 +378,13 @@ pub fn yuv422_to_yvyu422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format to YVYU ( YUV Packed ) format.
 ///
 /// This function takes YUV 420 planar format data with 8-16 bit precision,
 /// and converts it to YVYU format with 8-16 bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -439,7 +403,6 @@ pub fn yuv422_to_yvyu422_p16(
 ///
 /// This function panics if the lengths of the planes or the input YVYU data are not valid based
 /// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
 ///
 pub fn yuv420_to_yvyu422_p16(
     y_plane: &[u16],
@@ -452,7 +415,7 @@ pub fn yuv420_to_yvyu422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl_p16::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::YVYU as usize }>(
         y_plane,
         y_stride,
@@ -464,14 +427,13 @@ pub fn yuv420_to_yvyu422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format to VYUY ( YUV Packed ) format.
 ///
 /// This function takes YUV 444 planar format data with 8-16 bit precision,
 /// and converts it to VYUY format with 8-16 bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -490,7 +452,6 @@ pub fn yuv420_to_yvyu422_p16(
 ///
 /// This function panics if the lengths of the planes or the input VYUY data are not valid based
 /// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
 ///
 pub fn yuv444_to_vyuy422_p16(
     y_plane: &[u16],
@@ -503,7 +464,7 @@ pub fn yuv444_to_vyuy422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl_p16::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::VYUY as usize }>(
         y_plane,
         y_stride,
@@ -515,14 +476,13 @@ pub fn yuv444_to_vyuy422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 422 planar format to VYUY ( YUV Packed ) format.
 ///
 /// This function takes YUV 422 planar format data with 8-16 bit precision,
 /// and converts it to VYUY format with 8-16 bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -541,7 +501,6 @@ pub fn yuv444_to_vyuy422_p16(
 ///
 /// This function panics if the lengths of the planes or the input VYUY data are not valid based
 /// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
 ///
 pub fn yuv422_to_vyuy422_p16(
     y_plane: &[u16],
@@ -554,7 +513,7 @@ pub fn yuv422_to_vyuy422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl_p16::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::VYUY as usize }>(
         y_plane,
         y_stride,
@@ -566,14 +525,13 @@ pub fn yuv422_to_vyuy422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format to VYUY ( YUV Packed ) format.
 ///
 /// This function takes YUV 420 planar format data with 8-16 bit precision,
 /// and converts it to VYUY format with 8-16 bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -592,7 +550,6 @@ pub fn yuv422_to_vyuy422_p16(
 ///
 /// This function panics if the lengths of the planes or the input VYUY data are not valid based
 /// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
 ///
 pub fn yuv420_to_vyuy422_p16(
     y_plane: &[u16],
@@ -605,7 +562,7 @@ pub fn yuv420_to_vyuy422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl_p16::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::VYUY as usize }>(
         y_plane,
         y_stride,
@@ -617,14 +574,13 @@ pub fn yuv420_to_vyuy422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 444 planar format to UYVY ( YUV Packed ) format.
 ///
 /// This function takes YUV 444 planar format data with 8-16 bit precision,
 /// and converts it to UYVY format with 8-16 bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -643,7 +599,6 @@ pub fn yuv420_to_vyuy422_p16(
 ///
 /// This function panics if the lengths of the planes or the input UYVY data are not valid based
 /// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
 ///
 pub fn yuv444_to_uyvy422_p16(
     y_plane: &[u16],
@@ -656,7 +611,7 @@ pub fn yuv444_to_uyvy422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl_p16::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::UYVY as usize }>(
         y_plane,
         y_stride,
@@ -668,14 +623,13 @@ pub fn yuv444_to_uyvy422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 422 planar format to UYVY ( YUV Packed ) format.
 ///
 /// This function takes YUV 422 planar format data with 8-16 bit precision,
 /// and converts it to UYVY format with 8-16 bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -694,7 +648,6 @@ pub fn yuv444_to_uyvy422_p16(
 ///
 /// This function panics if the lengths of the planes or the input UYVY data are not valid based
 /// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
 ///
 pub fn yuv422_to_uyvy422_p16(
     y_plane: &[u16],
@@ -707,7 +660,7 @@ pub fn yuv422_to_uyvy422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl_p16::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::UYVY as usize }>(
         y_plane,
         y_stride,
@@ -719,14 +672,13 @@ pub fn yuv422_to_uyvy422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUV 420 planar format to UYVY ( YUV Packed ) format.
 ///
 /// This function takes YUV 420 planar format data with 8-16 bit precision,
 /// and converts it to UYVY format with 8-16 bit per channel precision.
-/// Do not forget about odd alignment, use (width + 1) for buffers.
 ///
 /// # Arguments
 ///
@@ -745,7 +697,6 @@ pub fn yuv422_to_uyvy422_p16(
 ///
 /// This function panics if the lengths of the planes or the input UYVY data are not valid based
 /// on the specified width, height, and strides, or if invalid YUV range or matrix is provided.
-/// Panic will be received if buffer doesn't expand with (width + 1) size for odd width
 ///
 pub fn yuv420_to_uyvy422_p16(
     y_plane: &[u16],
@@ -758,7 +709,7 @@ pub fn yuv420_to_uyvy422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuv_to_yuy2_impl_p16::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::UYVY as usize }>(
         y_plane,
         y_stride,
@@ -770,5 +721,5 @@ pub fn yuv420_to_uyvy422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
@@ -0,0 +1,876 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{ImageSize, RowStride};
+use crate::yuv_error::{check_interleaved_chroma_channel, check_y8_channel, check_yuv_packed422};
+use crate::yuv_support::{YuvChromaSample, YuvNVOrder, Yuy2Description};
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+/// Vertical chroma resampling used when repacking between 4:2:2 and 4:2:0
+pub enum YuvChromaVerticalFilter {
+    /// Averages both rows of a pair when dropping a chroma row,
+    /// and interpolates odd rows from neighbouring chroma rows when restoring them
+    #[default]
+    Average,
+    /// Keeps the top row of a pair when dropping a chroma row,
+    /// and repeats each chroma row twice when restoring them
+    Duplicate,
+}
+
+#[inline(always)]
+fn avg_u8(a: u8, b: u8) -> u8 {
+    ((a as u16 + b as u16 + 1) >> 1) as u8
+}
+
+fn yuy2_to_yuv_nv_impl<const UV_ORDER: u8, const YUY2_TARGET: usize>(
+    yuy2_store: &[u8],
+    yuy2_stride: u32,
+    y_plane: &mut [u8],
+    y_stride: u32,
+    uv_plane: &mut [u8],
+    uv_stride: u32,
+    width: u32,
+    height: u32,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let yuy2_source: Yuy2Description = YUY2_TARGET.into();
+    let order: YuvNVOrder = UV_ORDER.into();
+
+    check_yuv_packed422(yuy2_store, yuy2_stride, width, height)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_interleaved_chroma_channel(uv_plane, uv_stride, width, height, YuvChromaSample::YUV420)?;
+
+    let width = width as usize;
+    let y_stride = y_stride as usize;
+    let yuy2_stride = yuy2_stride as usize;
+
+    let y_iter;
+    let yuy2_iter;
+    let uv_iter;
+    #[cfg(feature = "rayon")]
+    {
+        y_iter = y_plane.par_chunks_mut(y_stride * 2);
+        yuy2_iter = yuy2_store.par_chunks(yuy2_stride * 2);
+        uv_iter = uv_plane.par_chunks_mut(uv_stride as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        y_iter = y_plane.chunks_mut(y_stride * 2);
+        yuy2_iter = yuy2_store.chunks(yuy2_stride * 2);
+        uv_iter = uv_plane.chunks_mut(uv_stride as usize);
+    }
+
+    y_iter
+        .zip(yuy2_iter)
+        .zip(uv_iter)
+        .for_each(|((y_rows, yuy2_rows), uv_row)| {
+            for (y_row, yuy2_row) in y_rows
+                .chunks_mut(y_stride)
+                .zip(yuy2_rows.chunks(yuy2_stride))
+            {
+                for (y_dst, yuy2) in y_row
+                    .chunks_exact_mut(2)
+                    .zip(yuy2_row.chunks_exact(4))
+                    .take(width / 2)
+                {
+                    y_dst[0] = yuy2[yuy2_source.get_first_y_position()];
+                    y_dst[1] = yuy2[yuy2_source.get_second_y_position()];
+                }
+                if width & 1 == 1 {
+                    let yuy2 = &yuy2_row[((width - 1) / 2) * 4..];
+                    y_row[width - 1] = yuy2[yuy2_source.get_first_y_position()];
+                }
+            }
+
+            let top = &yuy2_rows[..yuy2_stride.min(yuy2_rows.len())];
+            let bottom =
+                if yuy2_rows.len() > yuy2_stride && filter == YuvChromaVerticalFilter::Average {
+                    &yuy2_rows[yuy2_stride..]
+                } else {
+                    top
+                };
+
+            for ((uv_dst, top), bottom) in uv_row
+                .chunks_exact_mut(2)
+                .zip(top.chunks_exact(4))
+                .zip(bottom.chunks_exact(4))
+                .take(width.div_ceil(2))
+            {
+                uv_dst[order.get_u_position()] = avg_u8(
+                    top[yuy2_source.get_u_position()],
+                    bottom[yuy2_source.get_u_position()],
+                );
+                uv_dst[order.get_v_position()] = avg_u8(
+                    top[yuy2_source.get_v_position()],
+                    bottom[yuy2_source.get_v_position()],
+                );
+            }
+        });
+
+    Ok(())
+}
+
+fn yuv_nv_to_yuy2_impl<const UV_ORDER: u8, const YUY2_TARGET: usize>(
+    y_plane: &[u8],
+    y_stride: u32,
+    uv_plane: &[u8],
+    uv_stride: u32,
+    yuy2_store: &mut [u8],
+    yuy2_stride: u32,
+    width: u32,
+    height: u32,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let yuy2_target: Yuy2Description = YUY2_TARGET.into();
+    let order: YuvNVOrder = UV_ORDER.into();
+
+    check_yuv_packed422(yuy2_store, yuy2_stride, width, height)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_interleaved_chroma_channel(uv_plane, uv_stride, width, height, YuvChromaSample::YUV420)?;
+
+    let width = width as usize;
+    let y_stride = y_stride as usize;
+    let uv_stride = uv_stride as usize;
+    let chroma_height = (height as usize).div_ceil(2);
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = yuy2_store.par_chunks_mut(yuy2_stride as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = yuy2_store.chunks_mut(yuy2_stride as usize);
+    }
+
+    iter.enumerate().for_each(|(y, yuy2_row)| {
+        let y_row = &y_plane[y * y_stride..];
+        let chroma_y = y >> 1;
+        let top = &uv_plane[chroma_y * uv_stride..];
+        // Odd rows sit between two chroma rows, the last one has no neighbour below
+        let bottom = if y & 1 == 1
+            && chroma_y + 1 < chroma_height
+            && filter == YuvChromaVerticalFilter::Average
+        {
+            &uv_plane[(chroma_y + 1) * uv_stride..]
+        } else {
+            top
+        };
+
+        for (((yuy2, y_src), top), bottom) in yuy2_row
+            .chunks_exact_mut(4)
+            .zip(y_row.chunks_exact(2))
+            .zip(top.chunks_exact(2))
+            .zip(bottom.chunks_exact(2))
+            .take(width / 2)
+        {
+            yuy2[yuy2_target.get_first_y_position()] = y_src[0];
+            yuy2[yuy2_target.get_second_y_position()] = y_src[1];
+            yuy2[yuy2_target.get_u_position()] =
+                avg_u8(top[order.get_u_position()], bottom[order.get_u_position()]);
+            yuy2[yuy2_target.get_v_position()] =
+                avg_u8(top[order.get_v_position()], bottom[order.get_v_position()]);
+        }
+
+        if width & 1 == 1 {
+            let cx = (width - 1) / 2;
+            let yuy2 = &mut yuy2_row[cx * 4..cx * 4 + 4];
+            let top = &top[cx * 2..];
+            let bottom = &bottom[cx * 2..];
+            yuy2[yuy2_target.get_first_y_position()] = y_row[width - 1];
+            yuy2[yuy2_target.get_second_y_position()] = y_row[width - 1];
+            yuy2[yuy2_target.get_u_position()] =
+                avg_u8(top[order.get_u_position()], bottom[order.get_u_position()]);
+            yuy2[yuy2_target.get_v_position()] =
+                avg_u8(top[order.get_v_position()], bottom[order.get_v_position()]);
+        }
+    });
+
+    Ok(())
+}
+
+/// Convert YUYV ( YUV Packed 4:2:2 ) format to YUV NV12 bi-planar format.
+///
+/// Luma is copied as is, every two chroma rows of the packed source are folded into one
+/// row of the interleaved `UV` plane according to `filter`.
+///
+/// # Arguments
+///
+/// * `yuy2_store` - A slice to load the YUYV data.
+/// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A mutable slice to store the interleaved UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `size` - The size of the image.
+/// * `filter` - How two chroma rows are folded into one.
+///
+pub fn yuyv422_to_yuv_nv12(
+    yuy2_store: &[u8],
+    yuy2_stride: RowStride,
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    uv_plane: &mut [u8],
+    uv_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (yuy2_stride, y_stride, uv_stride) = (yuy2_stride.get(), y_stride.get(), uv_stride.get());
+    yuy2_to_yuv_nv_impl::<{ YuvNVOrder::UV as u8 }, { Yuy2Description::YUYV as usize }>(
+        yuy2_store,
+        yuy2_stride,
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert YUYV ( YUV Packed 4:2:2 ) format to YUV NV21 bi-planar format.
+///
+/// Luma is copied as is, every two chroma rows of the packed source are folded into one
+/// row of the interleaved `VU` plane according to `filter`.
+///
+/// # Arguments
+///
+/// * `yuy2_store` - A slice to load the YUYV data.
+/// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A mutable slice to store the interleaved VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `size` - The size of the image.
+/// * `filter` - How two chroma rows are folded into one.
+///
+pub fn yuyv422_to_yuv_nv21(
+    yuy2_store: &[u8],
+    yuy2_stride: RowStride,
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    vu_plane: &mut [u8],
+    vu_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (yuy2_stride, y_stride, vu_stride) = (yuy2_stride.get(), y_stride.get(), vu_stride.get());
+    yuy2_to_yuv_nv_impl::<{ YuvNVOrder::VU as u8 }, { Yuy2Description::YUYV as usize }>(
+        yuy2_store,
+        yuy2_stride,
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert UYVY ( YUV Packed 4:2:2 ) format to YUV NV12 bi-planar format.
+///
+/// Luma is copied as is, every two chroma rows of the packed source are folded into one
+/// row of the interleaved `UV` plane according to `filter`.
+///
+/// # Arguments
+///
+/// * `yuy2_store` - A slice to load the UYVY data.
+/// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A mutable slice to store the interleaved UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `size` - The size of the image.
+/// * `filter` - How two chroma rows are folded into one.
+///
+pub fn uyvy422_to_yuv_nv12(
+    yuy2_store: &[u8],
+    yuy2_stride: RowStride,
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    uv_plane: &mut [u8],
+    uv_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (yuy2_stride, y_stride, uv_stride) = (yuy2_stride.get(), y_stride.get(), uv_stride.get());
+    yuy2_to_yuv_nv_impl::<{ YuvNVOrder::UV as u8 }, { Yuy2Description::UYVY as usize }>(
+        yuy2_store,
+        yuy2_stride,
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert UYVY ( YUV Packed 4:2:2 ) format to YUV NV21 bi-planar format.
+///
+/// Luma is copied as is, every two chroma rows of the packed source are folded into one
+/// row of the interleaved `VU` plane according to `filter`.
+///
+/// # Arguments
+///
+/// * `yuy2_store` - A slice to load the UYVY data.
+/// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A mutable slice to store the interleaved VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `size` - The size of the image.
+/// * `filter` - How two chroma rows are folded into one.
+///
+pub fn uyvy422_to_yuv_nv21(
+    yuy2_store: &[u8],
+    yuy2_stride: RowStride,
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    vu_plane: &mut [u8],
+    vu_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (yuy2_stride, y_stride, vu_stride) = (yuy2_stride.get(), y_stride.get(), vu_stride.get());
+    yuy2_to_yuv_nv_impl::<{ YuvNVOrder::VU as u8 }, { Yuy2Description::UYVY as usize }>(
+        yuy2_store,
+        yuy2_stride,
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert YVYU ( YUV Packed 4:2:2 ) format to YUV NV12 bi-planar format.
+///
+/// Luma is copied as is, every two chroma rows of the packed source are folded into one
+/// row of the interleaved `UV` plane according to `filter`.
+///
+/// # Arguments
+///
+/// * `yuy2_store` - A slice to load the YVYU data.
+/// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A mutable slice to store the interleaved UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `size` - The size of the image.
+/// * `filter` - How two chroma rows are folded into one.
+///
+pub fn yvyu422_to_yuv_nv12(
+    yuy2_store: &[u8],
+    yuy2_stride: RowStride,
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    uv_plane: &mut [u8],
+    uv_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (yuy2_stride, y_stride, uv_stride) = (yuy2_stride.get(), y_stride.get(), uv_stride.get());
+    yuy2_to_yuv_nv_impl::<{ YuvNVOrder::UV as u8 }, { Yuy2Description::YVYU as usize }>(
+        yuy2_store,
+        yuy2_stride,
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert YVYU ( YUV Packed 4:2:2 ) format to YUV NV21 bi-planar format.
+///
+/// Luma is copied as is, every two chroma rows of the packed source are folded into one
+/// row of the interleaved `VU` plane according to `filter`.
+///
+/// # Arguments
+///
+/// * `yuy2_store` - A slice to load the YVYU data.
+/// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A mutable slice to store the interleaved VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `size` - The size of the image.
+/// * `filter` - How two chroma rows are folded into one.
+///
+pub fn yvyu422_to_yuv_nv21(
+    yuy2_store: &[u8],
+    yuy2_stride: RowStride,
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    vu_plane: &mut [u8],
+    vu_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (yuy2_stride, y_stride, vu_stride) = (yuy2_stride.get(), y_stride.get(), vu_stride.get());
+    yuy2_to_yuv_nv_impl::<{ YuvNVOrder::VU as u8 }, { Yuy2Description::YVYU as usize }>(
+        yuy2_store,
+        yuy2_stride,
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert VYUY ( YUV Packed 4:2:2 ) format to YUV NV12 bi-planar format.
+///
+/// Luma is copied as is, every two chroma rows of the packed source are folded into one
+/// row of the interleaved `UV` plane according to `filter`.
+///
+/// # Arguments
+///
+/// * `yuy2_store` - A slice to load the VYUY data.
+/// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A mutable slice to store the interleaved UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `size` - The size of the image.
+/// * `filter` - How two chroma rows are folded into one.
+///
+pub fn vyuy422_to_yuv_nv12(
+    yuy2_store: &[u8],
+    yuy2_stride: RowStride,
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    uv_plane: &mut [u8],
+    uv_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (yuy2_stride, y_stride, uv_stride) = (yuy2_stride.get(), y_stride.get(), uv_stride.get());
+    yuy2_to_yuv_nv_impl::<{ YuvNVOrder::UV as u8 }, { Yuy2Description::VYUY as usize }>(
+        yuy2_store,
+        yuy2_stride,
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert VYUY ( YUV Packed 4:2:2 ) format to YUV NV21 bi-planar format.
+///
+/// Luma is copied as is, every two chroma rows of the packed source are folded into one
+/// row of the interleaved `VU` plane according to `filter`.
+///
+/// # Arguments
+///
+/// * `yuy2_store` - A slice to load the VYUY data.
+/// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
+/// * `y_plane` - A mutable slice to store the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A mutable slice to store the interleaved VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `size` - The size of the image.
+/// * `filter` - How two chroma rows are folded into one.
+///
+pub fn vyuy422_to_yuv_nv21(
+    yuy2_store: &[u8],
+    yuy2_stride: RowStride,
+    y_plane: &mut [u8],
+    y_stride: RowStride,
+    vu_plane: &mut [u8],
+    vu_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (yuy2_stride, y_stride, vu_stride) = (yuy2_stride.get(), y_stride.get(), vu_stride.get());
+    yuy2_to_yuv_nv_impl::<{ YuvNVOrder::VU as u8 }, { Yuy2Description::VYUY as usize }>(
+        yuy2_store,
+        yuy2_stride,
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert YUV NV12 bi-planar format to YUYV ( YUV Packed 4:2:2 ) format.
+///
+/// Luma is copied as is, every row of the interleaved `UV` plane is expanded to two rows
+/// of the packed destination according to `filter`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the interleaved UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `yuy2_store` - A mutable slice to store the converted YUYV data.
+/// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
+/// * `size` - The size of the image.
+/// * `filter` - How one chroma row is expanded into two.
+///
+pub fn yuv_nv12_to_yuyv422(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    uv_plane: &[u8],
+    uv_stride: RowStride,
+    yuy2_store: &mut [u8],
+    yuy2_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, uv_stride, yuy2_stride) = (y_stride.get(), uv_stride.get(), yuy2_stride.get());
+    yuv_nv_to_yuy2_impl::<{ YuvNVOrder::UV as u8 }, { Yuy2Description::YUYV as usize }>(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        yuy2_store,
+        yuy2_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert YUV NV21 bi-planar format to YUYV ( YUV Packed 4:2:2 ) format.
+///
+/// Luma is copied as is, every row of the interleaved `VU` plane is expanded to two rows
+/// of the packed destination according to `filter`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A slice to load the interleaved VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `yuy2_store` - A mutable slice to store the converted YUYV data.
+/// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
+/// * `size` - The size of the image.
+/// * `filter` - How one chroma row is expanded into two.
+///
+pub fn yuv_nv21_to_yuyv422(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    vu_plane: &[u8],
+    vu_stride: RowStride,
+    yuy2_store: &mut [u8],
+    yuy2_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, vu_stride, yuy2_stride) = (y_stride.get(), vu_stride.get(), yuy2_stride.get());
+    yuv_nv_to_yuy2_impl::<{ YuvNVOrder::VU as u8 }, { Yuy2Description::YUYV as usize }>(
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        yuy2_store,
+        yuy2_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert YUV NV12 bi-planar format to UYVY ( YUV Packed 4:2:2 ) format.
+///
+/// Luma is copied as is, every row of the interleaved `UV` plane is expanded to two rows
+/// of the packed destination according to `filter`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the interleaved UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `yuy2_store` - A mutable slice to store the converted UYVY data.
+/// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
+/// * `size` - The size of the image.
+/// * `filter` - How one chroma row is expanded into two.
+///
+pub fn yuv_nv12_to_uyvy422(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    uv_plane: &[u8],
+    uv_stride: RowStride,
+    yuy2_store: &mut [u8],
+    yuy2_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, uv_stride, yuy2_stride) = (y_stride.get(), uv_stride.get(), yuy2_stride.get());
+    yuv_nv_to_yuy2_impl::<{ YuvNVOrder::UV as u8 }, { Yuy2Description::UYVY as usize }>(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        yuy2_store,
+        yuy2_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert YUV NV21 bi-planar format to UYVY ( YUV Packed 4:2:2 ) format.
+///
+/// Luma is copied as is, every row of the interleaved `VU` plane is expanded to two rows
+/// of the packed destination according to `filter`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A slice to load the interleaved VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `yuy2_store` - A mutable slice to store the converted UYVY data.
+/// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
+/// * `size` - The size of the image.
+/// * `filter` - How one chroma row is expanded into two.
+///
+pub fn yuv_nv21_to_uyvy422(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    vu_plane: &[u8],
+    vu_stride: RowStride,
+    yuy2_store: &mut [u8],
+    yuy2_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, vu_stride, yuy2_stride) = (y_stride.get(), vu_stride.get(), yuy2_stride.get());
+    yuv_nv_to_yuy2_impl::<{ YuvNVOrder::VU as u8 }, { Yuy2Description::UYVY as usize }>(
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        yuy2_store,
+        yuy2_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert YUV NV12 bi-planar format to YVYU ( YUV Packed 4:2:2 ) format.
+///
+/// Luma is copied as is, every row of the interleaved `UV` plane is expanded to two rows
+/// of the packed destination according to `filter`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the interleaved UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `yuy2_store` - A mutable slice to store the converted YVYU data.
+/// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
+/// * `size` - The size of the image.
+/// * `filter` - How one chroma row is expanded into two.
+///
+pub fn yuv_nv12_to_yvyu422(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    uv_plane: &[u8],
+    uv_stride: RowStride,
+    yuy2_store: &mut [u8],
+    yuy2_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, uv_stride, yuy2_stride) = (y_stride.get(), uv_stride.get(), yuy2_stride.get());
+    yuv_nv_to_yuy2_impl::<{ YuvNVOrder::UV as u8 }, { Yuy2Description::YVYU as usize }>(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        yuy2_store,
+        yuy2_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert YUV NV21 bi-planar format to YVYU ( YUV Packed 4:2:2 ) format.
+///
+/// Luma is copied as is, every row of the interleaved `VU` plane is expanded to two rows
+/// of the packed destination according to `filter`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A slice to load the interleaved VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `yuy2_store` - A mutable slice to store the converted YVYU data.
+/// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
+/// * `size` - The size of the image.
+/// * `filter` - How one chroma row is expanded into two.
+///
+pub fn yuv_nv21_to_yvyu422(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    vu_plane: &[u8],
+    vu_stride: RowStride,
+    yuy2_store: &mut [u8],
+    yuy2_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, vu_stride, yuy2_stride) = (y_stride.get(), vu_stride.get(), yuy2_stride.get());
+    yuv_nv_to_yuy2_impl::<{ YuvNVOrder::VU as u8 }, { Yuy2Description::YVYU as usize }>(
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        yuy2_store,
+        yuy2_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert YUV NV12 bi-planar format to VYUY ( YUV Packed 4:2:2 ) format.
+///
+/// Luma is copied as is, every row of the interleaved `UV` plane is expanded to two rows
+/// of the packed destination according to `filter`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `uv_plane` - A slice to load the interleaved UV (chrominance) plane data.
+/// * `uv_stride` - The stride (bytes per row) for the UV plane.
+/// * `yuy2_store` - A mutable slice to store the converted VYUY data.
+/// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
+/// * `size` - The size of the image.
+/// * `filter` - How one chroma row is expanded into two.
+///
+pub fn yuv_nv12_to_vyuy422(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    uv_plane: &[u8],
+    uv_stride: RowStride,
+    yuy2_store: &mut [u8],
+    yuy2_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, uv_stride, yuy2_stride) = (y_stride.get(), uv_stride.get(), yuy2_stride.get());
+    yuv_nv_to_yuy2_impl::<{ YuvNVOrder::UV as u8 }, { Yuy2Description::VYUY as usize }>(
+        y_plane,
+        y_stride,
+        uv_plane,
+        uv_stride,
+        yuy2_store,
+        yuy2_stride,
+        width,
+        height,
+        filter,
+    )
+}
+
+/// Convert YUV NV21 bi-planar format to VYUY ( YUV Packed 4:2:2 ) format.
+///
+/// Luma is copied as is, every row of the interleaved `VU` plane is expanded to two rows
+/// of the packed destination according to `filter`.
+///
+/// # Arguments
+///
+/// * `y_plane` - A slice to load the Y (luminance) plane data.
+/// * `y_stride` - The stride (bytes per row) for the Y plane.
+/// * `vu_plane` - A slice to load the interleaved VU (chrominance) plane data.
+/// * `vu_stride` - The stride (bytes per row) for the VU plane.
+/// * `yuy2_store` - A mutable slice to store the converted VYUY data.
+/// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
+/// * `size` - The size of the image.
+/// * `filter` - How one chroma row is expanded into two.
+///
+pub fn yuv_nv21_to_vyuy422(
+    y_plane: &[u8],
+    y_stride: RowStride,
+    vu_plane: &[u8],
+    vu_stride: RowStride,
+    yuy2_store: &mut [u8],
+    yuy2_stride: RowStride,
+    size: ImageSize,
+    filter: YuvChromaVerticalFilter,
+) -> Result<(), YuvError> {
+    let (width, height) = (size.width, size.height);
+    let (y_stride, vu_stride, yuy2_stride) = (y_stride.get(), vu_stride.get(), yuy2_stride.get());
+    yuv_nv_to_yuy2_impl::<{ YuvNVOrder::VU as u8 }, { Yuy2Description::VYUY as usize }>(
+        y_plane,
+        y_stride,
+        vu_plane,
+        vu_stride,
+        yuy2_store,
+        yuy2_stride,
+        width,
+        height,
+        filter,
+    )
+}
@@ -26,40 +26,76 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::yuy2_to_rgb_avx;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
+))]
+use crate::avx512bw::yuy2_to_rgb_avx512;
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::yuy2_to_rgb_neon;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "profiling")]
+use crate::profiling::{dispatch_backend, rayon_splits, ProfileScope};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::yuy2_to_rgb_sse;
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    not(feature = "safe-only")
+))]
+use crate::wasm32::yuy2_to_rgb_wasm;
+use crate::yuv_error::{check_rgba_destination, check_yuv_packed422};
 use crate::yuv_support::{
     get_inverse_transform, get_yuv_range, YuvSourceChannels, Yuy2Description,
 };
 #[allow(unused_imports)]
 use crate::yuv_to_yuy2::YuvToYuy2Navigation;
-use crate::{YuvRange, YuvStandardMatrix};
+use crate::{YuvError, YuvRange, YuvStandardMatrix};
 #[cfg(feature = "rayon")]
 use rayon::iter::{IndexedParallelIterator, ParallelIterator};
 #[cfg(feature = "rayon")]
 use rayon::prelude::{ParallelSlice, ParallelSliceMut};
 
-fn yuy2_to_rgb_impl<const DESTINATION_CHANNELS: u8, const YUY2_SOURCE: usize>(
+pub(crate) fn yuy2_to_rgb_impl<const DESTINATION_CHANNELS: u8, const YUY2_SOURCE: usize>(
     yuy2_store: &[u8],
     yuy2_stride: u32,
     rgb_store: &mut [u8],
     rgb_stride: u32,
     width: u32,
-    _: u32,
+    height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     let yuy2_source: Yuy2Description = YUY2_SOURCE.into();
 
     let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
     let channels = dst_chans.get_channels_count();
 
+    check_yuv_packed422(yuy2_store, yuy2_stride, width, height)?;
+    check_rgba_destination(rgb_store, rgb_stride, width, height, channels)?;
+
+    #[cfg(feature = "profiling")]
+    let _profile = ProfileScope::new(
+        "yuy2_to_rgba",
+        yuy2_store.len() + rgb_store.len(),
+        dispatch_backend(true, true),
+        rayon_splits(height),
+    );
+
     let range = get_yuv_range(8, range);
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let transform = get_inverse_transform(255, range.range_y, range.range_uv, kr_kb.kr, kr_kb.kb);
     const PRECISION: i32 = 6;
     const ROUNDING_CONST: i32 = 1 << (PRECISION - 1);
@@ -72,68 +108,79 @@ fn yuy2_to_rgb_impl<const DESTINATION_CHANNELS: u8, const YUY2_SOURCE: usize>(
     let bias_y = range.bias_y as i32;
     let bias_uv = range.bias_uv as i32;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_avx = std::arch::is_x86_feature_detected!("avx2");
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_sse = crate::cpu_features::has_sse41();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_avx = crate::cpu_features::has_avx2();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly_avx512",
+        not(feature = "safe-only")
+    ))]
+    let mut _use_avx512 = crate::cpu_features::has_avx512bw();
 
     let rgb_iter;
     let yuy2_iter;
     #[cfg(feature = "rayon")]
     {
-        rgb_iter = rgb_store.par_chunks_exact_mut(rgb_stride as usize);
-        yuy2_iter = yuy2_store.par_chunks_exact(yuy2_stride as usize);
+        rgb_iter = rgb_store.par_chunks_mut(rgb_stride as usize);
+        yuy2_iter = yuy2_store.par_chunks(yuy2_stride as usize);
     }
     #[cfg(not(feature = "rayon"))]
     {
-        rgb_iter = rgb_store.chunks_exact_mut(rgb_stride as usize);
-        yuy2_iter = yuy2_store.chunks_exact(yuy2_stride as usize);
+        rgb_iter = rgb_store.chunks_mut(rgb_stride as usize);
+        yuy2_iter = yuy2_store.chunks(yuy2_stride as usize);
     }
 
-    rgb_iter
-        .zip(yuy2_iter)
-        .for_each(|(rgb_store, yuy2_store)| unsafe {
-            let rgb_offset = 0usize;
-            let yuy_offset = 0usize;
-
-            let mut _cx = 0usize;
-            let mut _yuy2_x = 0usize;
-
-            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-            {
-                if _use_avx {
-                    let processed = yuy2_to_rgb_avx::<DESTINATION_CHANNELS, YUY2_SOURCE>(
-                        &range,
-                        &inverse_transform,
-                        yuy2_store,
-                        yuy_offset,
-                        rgb_store,
-                        rgb_offset,
-                        width,
-                        YuvToYuy2Navigation::new(_cx, 0, _yuy2_x),
-                    );
-                    _cx = processed.cx;
-                    _yuy2_x = processed.x;
-                }
-                if _use_sse {
-                    let processed = yuy2_to_rgb_sse::<DESTINATION_CHANNELS, YUY2_SOURCE>(
-                        &range,
-                        &inverse_transform,
-                        yuy2_store,
-                        yuy_offset,
-                        rgb_store,
-                        rgb_offset,
-                        width,
-                        YuvToYuy2Navigation::new(_cx, 0, _yuy2_x),
-                    );
-                    _cx = processed.cx;
-                    _yuy2_x = processed.x;
-                }
+    rgb_iter.zip(yuy2_iter).for_each(|(rgb_store, yuy2_store)| {
+        let rgb_offset = 0usize;
+        let yuy_offset = 0usize;
+
+        let mut _cx = 0usize;
+        let mut _yuy2_x = 0usize;
+
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
+        {
+            #[cfg(feature = "nightly_avx512")]
+            if _use_avx512 {
+                let processed = yuy2_to_rgb_avx512::<DESTINATION_CHANNELS, YUY2_SOURCE>(
+                    &range,
+                    &inverse_transform,
+                    yuy2_store,
+                    yuy_offset,
+                    rgb_store,
+                    rgb_offset,
+                    width,
+                    YuvToYuy2Navigation::new(_cx, 0, _yuy2_x),
+                );
+                _cx = processed.cx;
+                _yuy2_x = processed.x;
             }
-
-            #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
-            {
-                let processed = yuy2_to_rgb_neon::<DESTINATION_CHANNELS, YUY2_SOURCE>(
+            if _use_avx {
+                let processed = yuy2_to_rgb_avx::<DESTINATION_CHANNELS, YUY2_SOURCE>(
+                    &range,
+                    &inverse_transform,
+                    yuy2_store,
+                    yuy_offset,
+                    rgb_store,
+                    rgb_offset,
+                    width,
+                    YuvToYuy2Navigation::new(_cx, 0, _yuy2_x),
+                );
+                _cx = processed.cx;
+                _yuy2_x = processed.x;
+            }
+            if _use_sse {
+                let processed = yuy2_to_rgb_sse::<DESTINATION_CHANNELS, YUY2_SOURCE>(
                     &range,
                     &inverse_transform,
                     yuy2_store,
@@ -146,81 +193,123 @@ fn yuy2_to_rgb_impl<const DESTINATION_CHANNELS: u8, const YUY2_SOURCE: usize>(
                 _cx = processed.cx;
                 _yuy2_x = processed.x;
             }
+        }
+
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
+        {
+            let processed = yuy2_to_rgb_neon::<DESTINATION_CHANNELS, YUY2_SOURCE>(
+                &range,
+                &inverse_transform,
+                yuy2_store,
+                yuy_offset,
+                rgb_store,
+                rgb_offset,
+                width,
+                YuvToYuy2Navigation::new(_cx, 0, _yuy2_x),
+            );
+            _cx = processed.cx;
+            _yuy2_x = processed.x;
+        }
+
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            not(feature = "safe-only")
+        ))]
+        {
+            let processed = yuy2_to_rgb_wasm::<DESTINATION_CHANNELS, YUY2_SOURCE>(
+                &range,
+                &inverse_transform,
+                yuy2_store,
+                yuy_offset,
+                rgb_store,
+                rgb_offset,
+                width,
+                YuvToYuy2Navigation::new(_cx, 0, _yuy2_x),
+            );
+            _cx = processed.cx;
+            _yuy2_x = processed.x;
+        }
+
+        let max_iter = width as usize / 2;
+        for x in _yuy2_x..max_iter {
+            let rgb_pos = rgb_offset + _cx * channels;
+            let yuy2_offset = yuy_offset + x * 4;
+
+            let yuy2_plane_shifted = &yuy2_store[yuy2_offset..];
+
+            let first_y = yuy2_plane_shifted[yuy2_source.get_first_y_position()];
+            let second_y = yuy2_plane_shifted[yuy2_source.get_second_y_position()];
+            let u_value = yuy2_plane_shifted[yuy2_source.get_u_position()];
+            let v_value = yuy2_plane_shifted[yuy2_source.get_v_position()];
+
+            let cb = u_value as i32 - bias_uv;
+            let cr = v_value as i32 - bias_uv;
+            let f_y = (first_y as i32 - bias_y) * y_coef;
+            let s_y = (second_y as i32 - bias_y) * y_coef;
+
+            let dst0 = &mut rgb_store[rgb_pos..];
+            let r0 = ((f_y + cr_coef * cr + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
+            let b0 = ((f_y + cb_coef * cb + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
+            let g0 =
+                ((f_y - g_coef_1 * cr - g_coef_2 * cb + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
+            dst0[dst_chans.get_r_channel_offset()] = r0 as u8;
+            dst0[dst_chans.get_g_channel_offset()] = g0 as u8;
+            dst0[dst_chans.get_b_channel_offset()] = b0 as u8;
+
+            if dst_chans.has_alpha() {
+                dst0[dst_chans.get_a_channel_offset()] = 255;
+            }
 
-            let max_iter = width as usize / 2;
-            for x in _yuy2_x..max_iter {
-                let rgb_pos = rgb_offset + _cx * channels;
-                let yuy2_offset = yuy_offset + x * 4;
-
-                let yuy2_plane_shifted = yuy2_store.get_unchecked(yuy2_offset..);
-
-                let first_y = *yuy2_plane_shifted.get_unchecked(yuy2_source.get_first_y_position());
-                let second_y =
-                    *yuy2_plane_shifted.get_unchecked(yuy2_source.get_second_y_position());
-                let u_value = *yuy2_plane_shifted.get_unchecked(yuy2_source.get_u_position());
-                let v_value = *yuy2_plane_shifted.get_unchecked(yuy2_source.get_v_position());
-
-                let cb = u_value as i32 - bias_uv;
-                let cr = v_value as i32 - bias_uv;
-                let f_y = (first_y as i32 - bias_y) * y_coef;
-                let s_y = (second_y as i32 - bias_y) * y_coef;
-
-                let dst0 = rgb_store.get_unchecked_mut(rgb_pos..);
-                let r0 = ((f_y + cr_coef * cr + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
-                let b0 = ((f_y + cb_coef * cb + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
-                let g0 = ((f_y - g_coef_1 * cr - g_coef_2 * cb + ROUNDING_CONST) >> PRECISION)
-                    .clamp(0, 255);
-                *dst0.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r0 as u8;
-                *dst0.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g0 as u8;
-                *dst0.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b0 as u8;
-
-                if dst_chans.has_alpha() {
-                    *dst0.get_unchecked_mut(dst_chans.get_a_channel_offset()) = 255;
-                }
-
-                let dst1 = dst0.get_unchecked_mut(channels..);
-
-                let r1 = ((s_y + cr_coef * cr + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
-                let b1 = ((s_y + cb_coef * cb + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
-                let g1 = ((s_y - g_coef_1 * cr - g_coef_2 * cb + ROUNDING_CONST) >> PRECISION)
-                    .clamp(0, 255);
-                *dst1.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r1 as u8;
-                *dst1.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g1 as u8;
-                *dst1.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b1 as u8;
-                if dst_chans.has_alpha() {
-                    *dst1.get_unchecked_mut(dst_chans.get_a_channel_offset()) = 255;
-                }
-
-                _cx += 2;
+            let dst1 = &mut dst0[channels..];
+
+            let r1 = ((s_y + cr_coef * cr + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
+            let b1 = ((s_y + cb_coef * cb + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
+            let g1 =
+                ((s_y - g_coef_1 * cr - g_coef_2 * cb + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
+            dst1[dst_chans.get_r_channel_offset()] = r1 as u8;
+            dst1[dst_chans.get_g_channel_offset()] = g1 as u8;
+            dst1[dst_chans.get_b_channel_offset()] = b1 as u8;
+            if dst_chans.has_alpha() {
+                dst1[dst_chans.get_a_channel_offset()] = 255;
             }
 
-            if width & 1 == 1 {
-                let rgb_pos = rgb_offset + (width as usize - 1) * channels;
-                let yuy2_offset = yuy_offset + ((width as usize - 1) / 2) * 4;
-
-                let yuy2_plane_shifted = yuy2_store.get_unchecked(yuy2_offset..);
-
-                let first_y = *yuy2_plane_shifted.get_unchecked(yuy2_source.get_first_y_position());
-                let u_value = *yuy2_plane_shifted.get_unchecked(yuy2_source.get_u_position());
-                let v_value = *yuy2_plane_shifted.get_unchecked(yuy2_source.get_v_position());
-
-                let cb = u_value as i32 - bias_uv;
-                let cr = v_value as i32 - bias_uv;
-                let f_y = (first_y as i32 - bias_y) * y_coef;
-
-                let dst0 = rgb_store.get_unchecked_mut(rgb_pos..);
-                let r0 = ((f_y + cr_coef * cr + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
-                let b0 = ((f_y + cb_coef * cb + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
-                let g0 = ((f_y - g_coef_1 * cr - g_coef_2 * cb + ROUNDING_CONST) >> PRECISION)
-                    .clamp(0, 255);
-                *dst0.get_unchecked_mut(dst_chans.get_r_channel_offset()) = r0 as u8;
-                *dst0.get_unchecked_mut(dst_chans.get_g_channel_offset()) = g0 as u8;
-                *dst0.get_unchecked_mut(dst_chans.get_b_channel_offset()) = b0 as u8;
-                if dst_chans.has_alpha() {
-                    *dst0.get_unchecked_mut(dst_chans.get_a_channel_offset()) = 255;
-                }
+            _cx += 2;
+        }
+
+        if width & 1 == 1 {
+            let rgb_pos = rgb_offset + (width as usize - 1) * channels;
+            let yuy2_offset = yuy_offset + ((width as usize - 1) / 2) * 4;
+
+            let yuy2_plane_shifted = &yuy2_store[yuy2_offset..];
+
+            let first_y = yuy2_plane_shifted[yuy2_source.get_first_y_position()];
+            let u_value = yuy2_plane_shifted[yuy2_source.get_u_position()];
+            let v_value = yuy2_plane_shifted[yuy2_source.get_v_position()];
+
+            let cb = u_value as i32 - bias_uv;
+            let cr = v_value as i32 - bias_uv;
+            let f_y = (first_y as i32 - bias_y) * y_coef;
+
+            let dst0 = &mut rgb_store[rgb_pos..];
+            let r0 = ((f_y + cr_coef * cr + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
+            let b0 = ((f_y + cb_coef * cb + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
+            let g0 =
+                ((f_y - g_coef_1 * cr - g_coef_2 * cb + ROUNDING_CONST) >> PRECISION).clamp(0, 255);
+            dst0[dst_chans.get_r_channel_offset()] = r0 as u8;
+            dst0[dst_chans.get_g_channel_offset()] = g0 as u8;
+            dst0[dst_chans.get_b_channel_offset()] = b0 as u8;
+            if dst_chans.has_alpha() {
+                dst0[dst_chans.get_a_channel_offset()] = 255;
             }
-        });
+        }
+    });
+
+    Ok(())
 }
 
 /// Convert YUYV (YUV Packed) format to RGB image.
@@ -230,7 +319,7 @@ fn yuy2_to_rgb_impl<const DESTINATION_CHANNELS: u8, const YUY2_SOURCE: usize>(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YUYV data.
+/// * `yuy2_store` - A slice to load packed YUYV data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
 /// * `rgb` - A mutable slice to store the converted RGB data.
 /// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
@@ -253,7 +342,7 @@ pub fn yuyv422_to_rgb(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Rgb as u8 }, { Yuy2Description::YUYV as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -263,7 +352,7 @@ pub fn yuyv422_to_rgb(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUYV (YUV Packed) format to RGBA image.
@@ -273,7 +362,7 @@ pub fn yuyv422_to_rgb(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YUYV data.
+/// * `yuy2_store` - A slice to load packed YUYV data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
 /// * `rgba` - A mutable slice to store the converted RGBA data.
 /// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
@@ -296,7 +385,7 @@ pub fn yuyv422_to_rgba(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Rgba as u8 }, { Yuy2Description::YUYV as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -306,7 +395,7 @@ pub fn yuyv422_to_rgba(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUYV (YUV Packed) format to BGR image.
@@ -316,7 +405,7 @@ pub fn yuyv422_to_rgba(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YUYV data.
+/// * `yuy2_store` - A slice to load packed YUYV data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
 /// * `bgr` - A mutable slice to store the converted BGR data.
 /// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
@@ -339,7 +428,7 @@ pub fn yuyv422_to_bgr(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Bgr as u8 }, { Yuy2Description::YUYV as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -349,7 +438,7 @@ pub fn yuyv422_to_bgr(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUYV (YUV Packed) format to BGR image.
@@ -359,7 +448,7 @@ pub fn yuyv422_to_bgr(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YUYV data.
+/// * `yuy2_store` - A slice to load packed YUYV data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
 /// * `bgra` - A mutable slice to store the converted BGRA data.
 /// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
@@ -382,7 +471,7 @@ pub fn yuyv422_to_bgra(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Bgra as u8 }, { Yuy2Description::YUYV as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -392,7 +481,7 @@ pub fn yuyv422_to_bgra(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) format to RGB image.
@@ -402,7 +491,7 @@ pub fn yuyv422_to_bgra(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted UYVY data.
+/// * `yuy2_store` - A slice to load packed UYVY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
 /// * `rgb` - A mutable slice to store the converted RGB data.
 /// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
@@ -425,7 +514,7 @@ pub fn uyvy422_to_rgb(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Rgb as u8 }, { Yuy2Description::UYVY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -435,7 +524,7 @@ pub fn uyvy422_to_rgb(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) format to RGBA image.
@@ -445,7 +534,7 @@ pub fn uyvy422_to_rgb(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted UYVY data.
+/// * `yuy2_store` - A slice to load packed UYVY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
 /// * `rgba` - A mutable slice to store the converted RGBA data.
 /// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
@@ -468,7 +557,7 @@ pub fn uyvy422_to_rgba(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Rgba as u8 }, { Yuy2Description::UYVY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -478,7 +567,7 @@ pub fn uyvy422_to_rgba(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) format to BGR image.
@@ -488,7 +577,7 @@ pub fn uyvy422_to_rgba(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted UYVY data.
+/// * `yuy2_store` - A slice to load packed UYVY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
 /// * `bgr` - A mutable slice to store the converted BGR data.
 /// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
@@ -511,7 +600,7 @@ pub fn uyvy422_to_bgr(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Bgr as u8 }, { Yuy2Description::UYVY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -521,7 +610,7 @@ pub fn uyvy422_to_bgr(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) format to BGRA image.
@@ -531,7 +620,7 @@ pub fn uyvy422_to_bgr(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted UYVY data.
+/// * `yuy2_store` - A slice to load packed UYVY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
 /// * `bgra` - A mutable slice to store the converted BGRA data.
 /// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
@@ -554,7 +643,7 @@ pub fn uyvy422_to_bgra(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Bgra as u8 }, { Yuy2Description::UYVY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -564,7 +653,7 @@ pub fn uyvy422_to_bgra(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YVYU ( YUV Packed ) format to RGB image.
@@ -574,7 +663,7 @@ pub fn uyvy422_to_bgra(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YVYU data.
+/// * `yuy2_store` - A slice to load packed YVYU data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
 /// * `rgb` - A mutable slice to store the converted RGB data.
 /// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
@@ -597,7 +686,7 @@ pub fn yvyu422_to_rgb(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Rgb as u8 }, { Yuy2Description::YVYU as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -607,7 +696,7 @@ pub fn yvyu422_to_rgb(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) format to RGBA image.
@@ -617,7 +706,7 @@ pub fn yvyu422_to_rgb(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YVYU data.
+/// * `yuy2_store` - A slice to load packed YVYU data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
 /// * `rgba` - A mutable slice to store the converted RGBA data.
 /// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
@@ -640,7 +729,7 @@ pub fn yvyu422_to_rgba(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Rgba as u8 }, { Yuy2Description::YVYU as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -650,7 +739,7 @@ pub fn yvyu422_to_rgba(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) format to BGR image.
@@ -660,7 +749,7 @@ pub fn yvyu422_to_rgba(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YVYU data.
+/// * `yuy2_store` - A slice to load packed YVYU data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
 /// * `bgr` - A mutable slice to store the converted BGR data.
 /// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
@@ -683,7 +772,7 @@ pub fn yvyu422_to_bgr(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Bgr as u8 }, { Yuy2Description::YVYU as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -693,7 +782,7 @@ pub fn yvyu422_to_bgr(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) format to BGRA image.
@@ -703,7 +792,7 @@ pub fn yvyu422_to_bgr(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YVYU data.
+/// * `yuy2_store` - A slice to load packed YVYU data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
 /// * `bgra` - A mutable slice to store the converted BGRA data.
 /// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
@@ -726,7 +815,7 @@ pub fn yvyu422_to_bgra(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Bgra as u8 }, { Yuy2Description::YVYU as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -736,7 +825,7 @@ pub fn yvyu422_to_bgra(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) format to RGB image.
@@ -746,7 +835,7 @@ pub fn yvyu422_to_bgra(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted VYUY data.
+/// * `yuy2_store` - A slice to load packed VYUY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
 /// * `rgb` - A mutable slice to store the converted RGB data.
 /// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
@@ -769,7 +858,7 @@ pub fn vyuy422_to_rgb(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Rgb as u8 }, { Yuy2Description::VYUY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -779,7 +868,7 @@ pub fn vyuy422_to_rgb(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) format to RGBA image.
@@ -789,7 +878,7 @@ pub fn vyuy422_to_rgb(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted VYUY data.
+/// * `yuy2_store` - A slice to load packed VYUY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
 /// * `rgba` - A mutable slice to store the converted RGBA data.
 /// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
@@ -812,7 +901,7 @@ pub fn vyuy422_to_rgba(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Rgba as u8 }, { Yuy2Description::VYUY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -822,7 +911,7 @@ pub fn vyuy422_to_rgba(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) format to BGR image.
@@ -832,7 +921,7 @@ pub fn vyuy422_to_rgba(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted VYUY data.
+/// * `yuy2_store` - A slice to load packed VYUY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
 /// * `bgr` - A mutable slice to store the converted BGR data.
 /// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
@@ -855,7 +944,7 @@ pub fn vyuy422_to_bgr(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Bgr as u8 }, { Yuy2Description::VYUY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -865,7 +954,7 @@ pub fn vyuy422_to_bgr(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) format to BGRA image.
@@ -875,7 +964,7 @@ pub fn vyuy422_to_bgr(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted VYUY data.
+/// * `yuy2_store` - A slice to load packed VYUY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
 /// * `bgra` - A mutable slice to store the converted BGRA data.
 /// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
@@ -898,7 +987,7 @@ pub fn vyuy422_to_bgra(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl::<{ YuvSourceChannels::Bgra as u8 }, { Yuy2Description::VYUY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -908,5 +997,5 @@ pub fn vyuy422_to_bgra(
         height,
         range,
         matrix,
-    );
+    )
 }
@@ -26,12 +26,17 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+use crate::yuv_error::{check_rgba_destination, check_yuv_packed422};
 use crate::yuv_support::{
     get_inverse_transform, get_yuv_range, YuvSourceChannels, Yuy2Description,
 };
-use crate::{YuvRange, YuvStandardMatrix};
+use crate::{YuvError, YuvRange, YuvStandardMatrix};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
 
-fn yuy2_to_rgb_impl_p16<const DESTINATION_CHANNELS: u8, const YUY2_SOURCE: usize>(
+pub(crate) fn yuy2_to_rgb_impl_p16<const DESTINATION_CHANNELS: u8, const YUY2_SOURCE: usize>(
     yuy2_store: &[u16],
     yuy2_stride: u32,
     rgb_store: &mut [u16],
@@ -41,14 +46,18 @@ fn yuy2_to_rgb_impl_p16<const DESTINATION_CHANNELS: u8, const YUY2_SOURCE: usize
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     let yuy2_source: Yuy2Description = YUY2_SOURCE.into();
     const PRECISION: i32 = 6;
     let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
     let channels = dst_chans.get_channels_count();
+
+    check_yuv_packed422(yuy2_store, yuy2_stride, width, height)?;
+    check_rgba_destination(rgb_store, rgb_stride, width, height, channels)?;
+
     let range = get_yuv_range(bit_depth, range);
     let max_colors = (1 << bit_depth) - 1;
-    let kr_kb = matrix.get_kr_kb();
+    let kr_kb = matrix.resolve(width, height).get_kr_kb();
     let transform = get_inverse_transform(
         max_colors as u32,
         range.range_y,
@@ -66,125 +75,93 @@ fn yuy2_to_rgb_impl_p16<const DESTINATION_CHANNELS: u8, const YUY2_SOURCE: usize
     let bias_y = range.bias_y as i32;
     let bias_uv = range.bias_uv as i32;
 
-    let mut rgb_offset = 0usize;
-    let mut yuy_offset = 0usize;
-
-    for _ in 0..height as usize {
-        let mut _cx = 0usize;
-        let mut _yuy2_x = 0usize;
-
-        let max_iter = width as usize / 2;
-        for x in _yuy2_x..max_iter {
-            unsafe {
-                let rgb_pos = _cx * channels;
-                let yuy2_offset = x * 4;
-
-                let src_ptr = ((yuy2_store.as_ptr() as *const u8).add(yuy_offset) as *const u16)
-                    .add(yuy2_offset);
-
-                let dst_ptr =
-                    ((rgb_store.as_mut_ptr() as *mut u8).add(rgb_offset) as *mut u16).add(rgb_pos);
-
-                let first_y = src_ptr
-                    .add(yuy2_source.get_first_y_position())
-                    .read_unaligned();
-                let second_y = src_ptr
-                    .add(yuy2_source.get_second_y_position())
-                    .read_unaligned();
-                let u_value = src_ptr.add(yuy2_source.get_u_position()).read_unaligned();
-                let v_value = src_ptr.add(yuy2_source.get_v_position()).read_unaligned();
-
-                let cb = u_value as i32 - bias_uv;
-                let cr = v_value as i32 - bias_uv;
-                let f_y = (first_y as i32 - bias_y) * y_coef;
-                let s_y = (second_y as i32 - bias_y) * y_coef;
-
-                let r0 = ((f_y + cr_coef * cr + ROUNDING_CONST) >> PRECISION).clamp(0, max_colors);
-                let b0 = ((f_y + cb_coef * cb + ROUNDING_CONST) >> PRECISION).clamp(0, max_colors);
-                let g0 = ((f_y - g_coef_1 * cr - g_coef_2 * cb + ROUNDING_CONST) >> PRECISION)
-                    .clamp(0, max_colors);
-                dst_ptr
-                    .add(dst_chans.get_r_channel_offset())
-                    .write_unaligned(r0 as u16);
-                dst_ptr
-                    .add(dst_chans.get_g_channel_offset())
-                    .write_unaligned(g0 as u16);
-                dst_ptr
-                    .add(dst_chans.get_b_channel_offset())
-                    .write_unaligned(b0 as u16);
-
-                if dst_chans.has_alpha() {
-                    dst_ptr
-                        .add(dst_chans.get_a_channel_offset())
-                        .write_unaligned(max_colors as u16);
-                }
-
-                let dst1 = dst_ptr.add(channels);
-
-                let r1 = ((s_y + cr_coef * cr + ROUNDING_CONST) >> PRECISION).clamp(0, max_colors);
-                let b1 = ((s_y + cb_coef * cb + ROUNDING_CONST) >> PRECISION).clamp(0, max_colors);
-                let g1 = ((s_y - g_coef_1 * cr - g_coef_2 * cb + ROUNDING_CONST) >> PRECISION)
-                    .clamp(0, max_colors);
-                dst1.add(dst_chans.get_r_channel_offset())
-                    .write_unaligned(r1 as u16);
-                dst1.add(dst_chans.get_g_channel_offset())
-                    .write_unaligned(g1 as u16);
-                dst1.add(dst_chans.get_b_channel_offset())
-                    .write_unaligned(b1 as u16);
-                if dst_chans.has_alpha() {
-                    dst1.add(dst_chans.get_a_channel_offset())
-                        .write_unaligned(max_colors as u16);
-                }
+    let width = width as usize;
+
+    let rgb_iter;
+    let yuy2_iter;
+    #[cfg(feature = "rayon")]
+    {
+        rgb_iter = rgb_store.par_chunks_mut(rgb_stride as usize / 2);
+        yuy2_iter = yuy2_store.par_chunks(yuy2_stride as usize / 2);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        rgb_iter = rgb_store.chunks_mut(rgb_stride as usize / 2);
+        yuy2_iter = yuy2_store.chunks(yuy2_stride as usize / 2);
+    }
+
+    rgb_iter.zip(yuy2_iter).for_each(|(rgb_row, yuy2_row)| {
+        for (dst, src) in rgb_row
+            .chunks_exact_mut(channels * 2)
+            .zip(yuy2_row.chunks_exact(4))
+            .take(width / 2)
+        {
+            let first_y = src[yuy2_source.get_first_y_position()];
+            let second_y = src[yuy2_source.get_second_y_position()];
+            let u_value = src[yuy2_source.get_u_position()];
+            let v_value = src[yuy2_source.get_v_position()];
+
+            let cb = u_value as i32 - bias_uv;
+            let cr = v_value as i32 - bias_uv;
+            let f_y = (first_y as i32 - bias_y) * y_coef;
+            let s_y = (second_y as i32 - bias_y) * y_coef;
+
+            let r0 = ((f_y + cr_coef * cr + ROUNDING_CONST) >> PRECISION).clamp(0, max_colors);
+            let b0 = ((f_y + cb_coef * cb + ROUNDING_CONST) >> PRECISION).clamp(0, max_colors);
+            let g0 = ((f_y - g_coef_1 * cr - g_coef_2 * cb + ROUNDING_CONST) >> PRECISION)
+                .clamp(0, max_colors);
+            dst[dst_chans.get_r_channel_offset()] = r0 as u16;
+            dst[dst_chans.get_g_channel_offset()] = g0 as u16;
+            dst[dst_chans.get_b_channel_offset()] = b0 as u16;
+
+            if dst_chans.has_alpha() {
+                dst[dst_chans.get_a_channel_offset()] = max_colors as u16;
             }
 
-            _cx += 2;
+            let dst1 = &mut dst[channels..];
+
+            let r1 = ((s_y + cr_coef * cr + ROUNDING_CONST) >> PRECISION).clamp(0, max_colors);
+            let b1 = ((s_y + cb_coef * cb + ROUNDING_CONST) >> PRECISION).clamp(0, max_colors);
+            let g1 = ((s_y - g_coef_1 * cr - g_coef_2 * cb + ROUNDING_CONST) >> PRECISION)
+                .clamp(0, max_colors);
+            dst1[dst_chans.get_r_channel_offset()] = r1 as u16;
+            dst1[dst_chans.get_g_channel_offset()] = g1 as u16;
+            dst1[dst_chans.get_b_channel_offset()] = b1 as u16;
+            if dst_chans.has_alpha() {
+                dst1[dst_chans.get_a_channel_offset()] = max_colors as u16;
+            }
         }
 
         if width & 1 == 1 {
-            unsafe {
-                let rgb_pos = (width as usize - 1) * channels;
-                let yuy2_offset = ((width as usize - 1) / 2) * 4;
-
-                let src_ptr = ((yuy2_store.as_ptr() as *const u8).add(yuy_offset) as *const u16)
-                    .add(yuy2_offset);
-
-                let dst_ptr =
-                    ((rgb_store.as_mut_ptr() as *mut u8).add(rgb_offset) as *mut u16).add(rgb_pos);
-
-                let first_y = src_ptr
-                    .add(yuy2_source.get_first_y_position())
-                    .read_unaligned();
-                let u_value = src_ptr.add(yuy2_source.get_u_position()).read_unaligned();
-                let v_value = src_ptr.add(yuy2_source.get_v_position()).read_unaligned();
-
-                let cb = u_value as i32 - bias_uv;
-                let cr = v_value as i32 - bias_uv;
-                let f_y = (first_y as i32 - bias_y) * y_coef;
-
-                let r0 = ((f_y + cr_coef * cr + ROUNDING_CONST) >> PRECISION).clamp(0, max_colors);
-                let b0 = ((f_y + cb_coef * cb + ROUNDING_CONST) >> PRECISION).clamp(0, max_colors);
-                let g0 = ((f_y - g_coef_1 * cr - g_coef_2 * cb + ROUNDING_CONST) >> PRECISION)
-                    .clamp(0, max_colors);
-                dst_ptr
-                    .add(dst_chans.get_r_channel_offset())
-                    .write_unaligned(r0 as u16);
-                dst_ptr
-                    .add(dst_chans.get_g_channel_offset())
-                    .write_unaligned(g0 as u16);
-                dst_ptr
-                    .add(dst_chans.get_b_channel_offset())
-                    .write_unaligned(b0 as u16);
-                if dst_chans.has_alpha() {
-                    dst_ptr
-                        .add(dst_chans.get_a_channel_offset())
-                        .write_unaligned(max_colors as u16);
-                }
+            let rgb_pos = (width - 1) * channels;
+            let yuy2_offset = ((width - 1) / 2) * 4;
+
+            let src = &yuy2_row[yuy2_offset..];
+
+            let dst = &mut rgb_row[rgb_pos..];
+
+            let first_y = src[yuy2_source.get_first_y_position()];
+            let u_value = src[yuy2_source.get_u_position()];
+            let v_value = src[yuy2_source.get_v_position()];
+
+            let cb = u_value as i32 - bias_uv;
+            let cr = v_value as i32 - bias_uv;
+            let f_y = (first_y as i32 - bias_y) * y_coef;
+
+            let r0 = ((f_y + cr_coef * cr + ROUNDING_CONST) >> PRECISION).clamp(0, max_colors);
+            let b0 = ((f_y + cb_coef * cb + ROUNDING_CONST) >> PRECISION).clamp(0, max_colors);
+            let g0 = ((f_y - g_coef_1 * cr - g_coef_2 * cb + ROUNDING_CONST) >> PRECISION)
+                .clamp(0, max_colors);
+            dst[dst_chans.get_r_channel_offset()] = r0 as u16;
+            dst[dst_chans.get_g_channel_offset()] = g0 as u16;
+            dst[dst_chans.get_b_channel_offset()] = b0 as u16;
+            if dst_chans.has_alpha() {
+                dst[dst_chans.get_a_channel_offset()] = max_colors as u16;
             }
         }
+    });
 
-        rgb_offset += rgb_stride as usize;
-        yuy_offset += yuy2_stride as usize;
-    }
+    Ok(())
 }
 
 /// Convert YUYV (YUV Packed) 8+ bit depth format to RGB image.
@@ -194,7 +171,7 @@ fn yuy2_to_rgb_impl_p16<const DESTINATION_CHANNELS: u8, const YUY2_SOURCE: usize
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YUYV data.
+/// * `yuy2_store` - A slice to load packed YUYV data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
 /// * `rgb` - A mutable slice to store the converted RGB data.
 /// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
@@ -219,7 +196,7 @@ pub fn yuyv422_to_rgb_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Rgb as u8 }, { Yuy2Description::YUYV as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -230,7 +207,7 @@ pub fn yuyv422_to_rgb_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUYV (YUV Packed) 8+ bit depth format to RGBA image.
@@ -240,7 +217,7 @@ pub fn yuyv422_to_rgb_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YUYV data.
+/// * `yuy2_store` - A slice to load packed YUYV data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
 /// * `rgba` - A mutable slice to store the converted RGBA data.
 /// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
@@ -265,7 +242,7 @@ pub fn yuyv422_to_rgba_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Rgba as u8 }, { Yuy2Description::YUYV as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -276,7 +253,7 @@ pub fn yuyv422_to_rgba_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUYV (YUV Packed) 8+ bit depth format to BGR image.
@@ -286,7 +263,7 @@ pub fn yuyv422_to_rgba_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YUYV data.
+/// * `yuy2_store` - A slice to load packed YUYV data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
 /// * `bgr` - A mutable slice to store the converted BGR data.
 /// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
@@ -311,7 +288,7 @@ pub fn yuyv422_to_bgr_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Bgr as u8 }, { Yuy2Description::YUYV as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -322,7 +299,7 @@ pub fn yuyv422_to_bgr_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YUYV (YUV Packed) 8+ bit depth format to BGR image.
@@ -332,7 +309,7 @@ pub fn yuyv422_to_bgr_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YUYV data.
+/// * `yuy2_store` - A slice to load packed YUYV data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YUYV plane.
 /// * `bgra` - A mutable slice to store the converted BGRA data.
 /// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
@@ -357,7 +334,7 @@ pub fn yuyv422_to_bgra_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Bgra as u8 }, { Yuy2Description::YUYV as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -368,7 +345,7 @@ pub fn yuyv422_to_bgra_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) 8+ bit depth format to RGB image.
@@ -378,7 +355,7 @@ pub fn yuyv422_to_bgra_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted UYVY data.
+/// * `yuy2_store` - A slice to load packed UYVY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
 /// * `rgb` - A mutable slice to store the converted RGB data.
 /// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
@@ -403,7 +380,7 @@ pub fn uyvy422_to_rgb_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Rgb as u8 }, { Yuy2Description::UYVY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -414,7 +391,7 @@ pub fn uyvy422_to_rgb_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) 8+ bit depth format to RGBA image.
@@ -424,7 +401,7 @@ pub fn uyvy422_to_rgb_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted UYVY data.
+/// * `yuy2_store` - A slice to load packed UYVY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
 /// * `rgba` - A mutable slice to store the converted RGBA data.
 /// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
@@ -449,7 +426,7 @@ pub fn uyvy422_to_rgba_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Rgba as u8 }, { Yuy2Description::UYVY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -460,7 +437,7 @@ pub fn uyvy422_to_rgba_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) 8+ bit depth format to BGR image.
@@ -470,7 +447,7 @@ pub fn uyvy422_to_rgba_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted UYVY data.
+/// * `yuy2_store` - A slice to load packed UYVY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
 /// * `bgr` - A mutable slice to store the converted BGR data.
 /// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
@@ -495,7 +472,7 @@ pub fn uyvy422_to_bgr_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Bgr as u8 }, { Yuy2Description::UYVY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -506,7 +483,7 @@ pub fn uyvy422_to_bgr_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) 8+ bit depth format to BGRA image.
@@ -516,7 +493,7 @@ pub fn uyvy422_to_bgr_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted UYVY data.
+/// * `yuy2_store` - A slice to load packed UYVY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the UYVY plane.
 /// * `bgra` - A mutable slice to store the converted BGRA data.
 /// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
@@ -541,7 +518,7 @@ pub fn uyvy422_to_bgra_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Bgra as u8 }, { Yuy2Description::UYVY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -552,7 +529,7 @@ pub fn uyvy422_to_bgra_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YVYU ( YUV Packed ) 8+ bit depth format to RGB image.
@@ -562,7 +539,7 @@ pub fn uyvy422_to_bgra_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YVYU data.
+/// * `yuy2_store` - A slice to load packed YVYU data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
 /// * `rgb` - A mutable slice to store the converted RGB data.
 /// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
@@ -587,7 +564,7 @@ pub fn yvyu422_to_rgb_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Rgb as u8 }, { Yuy2Description::YVYU as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -598,7 +575,7 @@ pub fn yvyu422_to_rgb_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) 8+ bit depth format to RGBA image.
@@ -608,7 +585,7 @@ pub fn yvyu422_to_rgb_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YVYU data.
+/// * `yuy2_store` - A slice to load packed YVYU data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
 /// * `rgba` - A mutable slice to store the converted RGBA data.
 /// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
@@ -633,7 +610,7 @@ pub fn yvyu422_to_rgba_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Rgba as u8 }, { Yuy2Description::YVYU as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -644,7 +621,7 @@ pub fn yvyu422_to_rgba_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) 8+ bit depth format to BGR image.
@@ -654,7 +631,7 @@ pub fn yvyu422_to_rgba_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YVYU data.
+/// * `yuy2_store` - A slice to load packed YVYU data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
 /// * `bgr` - A mutable slice to store the converted BGR data.
 /// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
@@ -679,7 +656,7 @@ pub fn yvyu422_to_bgr_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Bgr as u8 }, { Yuy2Description::YVYU as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -690,7 +667,7 @@ pub fn yvyu422_to_bgr_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) 8+ bit depth format to BGRA image.
@@ -700,7 +677,7 @@ pub fn yvyu422_to_bgr_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted YVYU data.
+/// * `yuy2_store` - A slice to load packed YVYU data.
 /// * `yuy2_stride` - The stride (bytes per row) for the YVYU plane.
 /// * `bgra` - A mutable slice to store the converted BGRA data.
 /// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
@@ -725,7 +702,7 @@ pub fn yvyu422_to_bgra_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Bgra as u8 }, { Yuy2Description::YVYU as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -736,7 +713,7 @@ pub fn yvyu422_to_bgra_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) 8+ bit depth format to RGB image.
@@ -746,7 +723,7 @@ pub fn yvyu422_to_bgra_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted VYUY data.
+/// * `yuy2_store` - A slice to load packed VYUY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
 /// * `rgb` - A mutable slice to store the converted RGB data.
 /// * `rgb_stride` - The stride (bytes per row) for the RGB image data.
@@ -771,7 +748,7 @@ pub fn vyuy422_to_rgb_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Rgb as u8 }, { Yuy2Description::VYUY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -782,7 +759,7 @@ pub fn vyuy422_to_rgb_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) 8+ bit depth format to RGBA image.
@@ -792,7 +769,7 @@ pub fn vyuy422_to_rgb_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted VYUY data.
+/// * `yuy2_store` - A slice to load packed VYUY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
 /// * `rgba` - A mutable slice to store the converted RGBA data.
 /// * `rgba_stride` - The stride (bytes per row) for the RGBA image data.
@@ -817,7 +794,7 @@ pub fn vyuy422_to_rgba_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Rgba as u8 }, { Yuy2Description::VYUY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -828,7 +805,7 @@ pub fn vyuy422_to_rgba_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) 8+ bit depth format to BGR image.
@@ -838,7 +815,7 @@ pub fn vyuy422_to_rgba_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted VYUY data.
+/// * `yuy2_store` - A slice to load packed VYUY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
 /// * `bgr` - A mutable slice to store the converted BGR data.
 /// * `bgr_stride` - The stride (bytes per row) for the BGR image data.
@@ -863,7 +840,7 @@ pub fn vyuy422_to_bgr_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Bgr as u8 }, { Yuy2Description::VYUY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -874,7 +851,7 @@ pub fn vyuy422_to_bgr_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) 8+ bit depth format to BGRA image.
@@ -884,7 +861,7 @@ pub fn vyuy422_to_bgr_p16(
 ///
 /// # Arguments
 ///
-/// * `yuy2_store` - A slice to store the converted VYUY data.
+/// * `yuy2_store` - A slice to load packed VYUY data.
 /// * `yuy2_stride` - The stride (bytes per row) for the VYUY plane.
 /// * `bgra` - A mutable slice to store the converted BGRA data.
 /// * `bgra_stride` - The stride (bytes per row) for the BGRA image data.
@@ -909,7 +886,7 @@ pub fn vyuy422_to_bgra_p16(
     height: u32,
     range: YuvRange,
     matrix: YuvStandardMatrix,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_rgb_impl_p16::<{ YuvSourceChannels::Bgra as u8 }, { Yuy2Description::VYUY as usize }>(
         yuy2_store,
         yuy2_stride,
@@ -920,5 +897,5 @@ pub fn vyuy422_to_bgra_p16(
         height,
         range,
         matrix,
-    );
+    )
 }
@@ -26,17 +26,43 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::avx2::yuy2_to_yuv_avx;
-#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "nightly_avx512",
+    not(feature = "safe-only")
+))]
+use crate::avx512bw::yuy2_to_yuv_avx512;
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "safe-only")
+))]
 use crate::neon::yuy2_to_yuv_neon_impl;
-#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[cfg(feature = "profiling")]
+use crate::profiling::{dispatch_backend, ProfileScope};
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    not(feature = "safe-only")
+))]
 use crate::sse::yuy2_to_yuv_sse_impl;
+#[cfg(all(
+    target_arch = "wasm32",
+    target_feature = "simd128",
+    not(feature = "safe-only")
+))]
+use crate::wasm32::yuy2_to_yuv_wasm;
+use crate::yuv_error::{check_chroma_channel, check_y8_channel, check_yuv_packed422};
 use crate::yuv_support::{YuvChromaSample, Yuy2Description};
 #[allow(unused_imports)]
 use crate::yuv_to_yuy2::YuvToYuy2Navigation;
+use crate::YuvError;
 
-fn yuy2_to_yuv_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
+pub(crate) fn yuy2_to_yuv_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
     y_plane: &mut [u8],
     y_stride: u32,
     u_plane: &mut [u8],
@@ -47,26 +73,55 @@ fn yuy2_to_yuv_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     let yuy2_target: Yuy2Description = YUY2_TARGET.into();
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
 
+    check_yuv_packed422(yuy2_store, yuy2_stride, width, height)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
+    #[cfg(feature = "profiling")]
+    let _profile = ProfileScope::new(
+        "yuy2_to_yuv",
+        y_plane.len() + u_plane.len() + v_plane.len() + yuy2_store.len(),
+        dispatch_backend(true, true),
+        0,
+    );
+
     let mut y_offset = 0usize;
     let mut u_offset = 0usize;
     let mut v_offset = 0usize;
     let mut yuy_offset = 0usize;
 
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_sse = std::arch::is_x86_feature_detected!("sse4.1");
-    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
-    let mut _use_avx2 = std::arch::is_x86_feature_detected!("avx2");
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_sse = crate::cpu_features::has_sse41();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        not(feature = "safe-only")
+    ))]
+    let mut _use_avx2 = crate::cpu_features::has_avx2();
+    #[cfg(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly_avx512",
+        not(feature = "safe-only")
+    ))]
+    let mut _use_avx512 = crate::cpu_features::has_avx512bw();
 
     for y in 0..height as usize {
         let mut _cx = 0usize;
         let mut _uv_x = 0usize;
         let mut _yuy2_x = 0usize;
 
-        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        #[cfg(all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            not(feature = "safe-only")
+        ))]
         {
             let processed = yuy2_to_yuv_neon_impl::<SAMPLING, YUY2_TARGET>(
                 y_plane,
@@ -85,8 +140,29 @@ fn yuy2_to_yuv_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
             _yuy2_x = processed.x;
         }
 
-        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[cfg(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(feature = "safe-only")
+        ))]
         unsafe {
+            #[cfg(feature = "nightly_avx512")]
+            if _use_avx512 {
+                let processed = yuy2_to_yuv_avx512::<SAMPLING, YUY2_TARGET>(
+                    y_plane,
+                    y_offset,
+                    u_plane,
+                    u_offset,
+                    v_plane,
+                    v_offset,
+                    yuy2_store,
+                    yuy_offset,
+                    width,
+                    YuvToYuy2Navigation::new(_cx, _uv_x, _yuy2_x),
+                );
+                _cx = processed.cx;
+                _uv_x = processed.uv_x;
+                _yuy2_x = processed.x;
+            }
             if _use_avx2 {
                 let processed = yuy2_to_yuv_avx::<SAMPLING, YUY2_TARGET>(
                     y_plane,
@@ -123,32 +199,49 @@ fn yuy2_to_yuv_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
             }
         }
 
+        #[cfg(all(
+            target_arch = "wasm32",
+            target_feature = "simd128",
+            not(feature = "safe-only")
+        ))]
+        unsafe {
+            let processed = yuy2_to_yuv_wasm::<SAMPLING, YUY2_TARGET>(
+                y_plane,
+                y_offset,
+                u_plane,
+                u_offset,
+                v_plane,
+                v_offset,
+                yuy2_store,
+                yuy_offset,
+                width,
+                YuvToYuy2Navigation::new(_cx, _uv_x, _yuy2_x),
+            );
+            _cx = processed.cx;
+            _uv_x = processed.uv_x;
+            _yuy2_x = processed.x;
+        }
+
         for x in _yuy2_x..width as usize / 2 {
             let u_pos = u_offset + _uv_x;
             let v_pos = v_offset + _uv_x;
             let y_pos = y_offset + _cx;
             let yuy2_offset = yuy_offset + x * 4;
 
-            let yuy2_plane_shifted = unsafe { yuy2_store.get_unchecked(yuy2_offset..) };
-
-            let first_y_position =
-                unsafe { *yuy2_plane_shifted.get_unchecked(yuy2_target.get_first_y_position()) };
-            let second_y_position =
-                unsafe { *yuy2_plane_shifted.get_unchecked(yuy2_target.get_second_y_position()) };
-            let u_value =
-                unsafe { *yuy2_plane_shifted.get_unchecked(yuy2_target.get_u_position()) };
-            let v_value =
-                unsafe { *yuy2_plane_shifted.get_unchecked(yuy2_target.get_v_position()) };
-
-            unsafe {
-                *y_plane.get_unchecked_mut(y_pos) = first_y_position;
-                *y_plane.get_unchecked_mut(y_pos + 1) = second_y_position;
-                *u_plane.get_unchecked_mut(u_pos) = u_value;
-                *v_plane.get_unchecked_mut(v_pos) = v_value;
-                if chroma_subsampling == YuvChromaSample::YUV444 {
-                    *u_plane.get_unchecked_mut(u_pos + 1) = u_value;
-                    *v_plane.get_unchecked_mut(v_pos + 1) = v_value;
-                }
+            let yuy2_plane_shifted = &yuy2_store[yuy2_offset..];
+
+            let first_y_position = yuy2_plane_shifted[yuy2_target.get_first_y_position()];
+            let second_y_position = yuy2_plane_shifted[yuy2_target.get_second_y_position()];
+            let u_value = yuy2_plane_shifted[yuy2_target.get_u_position()];
+            let v_value = yuy2_plane_shifted[yuy2_target.get_v_position()];
+
+            y_plane[y_pos] = first_y_position;
+            y_plane[y_pos + 1] = second_y_position;
+            u_plane[u_pos] = u_value;
+            v_plane[v_pos] = v_value;
+            if chroma_subsampling == YuvChromaSample::YUV444 {
+                u_plane[u_pos + 1] = u_value;
+                v_plane[v_pos + 1] = v_value;
             }
 
             _uv_x += match chroma_subsampling {
@@ -164,20 +257,15 @@ fn yuy2_to_yuv_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
             let y_pos = y_offset + _cx;
             let yuy2_offset = yuy_offset + ((width as usize - 1) / 2) * 4;
 
-            let yuy2_plane_shifted = unsafe { yuy2_store.get_unchecked(yuy2_offset..) };
+            let yuy2_plane_shifted = &yuy2_store[yuy2_offset..];
 
-            let first_y_position =
-                unsafe { *yuy2_plane_shifted.get_unchecked(yuy2_target.get_first_y_position()) };
-            let u_value =
-                unsafe { *yuy2_plane_shifted.get_unchecked(yuy2_target.get_u_position()) };
-            let v_value =
-                unsafe { *yuy2_plane_shifted.get_unchecked(yuy2_target.get_v_position()) };
+            let first_y_position = yuy2_plane_shifted[yuy2_target.get_first_y_position()];
+            let u_value = yuy2_plane_shifted[yuy2_target.get_u_position()];
+            let v_value = yuy2_plane_shifted[yuy2_target.get_v_position()];
 
-            unsafe {
-                *y_plane.get_unchecked_mut(y_pos) = first_y_position;
-                *u_plane.get_unchecked_mut(u_pos) = u_value;
-                *v_plane.get_unchecked_mut(v_pos) = v_value;
-            }
+            y_plane[y_pos] = first_y_position;
+            u_plane[u_pos] = u_value;
+            v_plane[v_pos] = v_value;
         }
 
         y_offset += y_stride as usize;
@@ -195,6 +283,8 @@ fn yuy2_to_yuv_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
             }
         }
     }
+
+    Ok(())
 }
 
 /// Convert YUYV (YUV Packed) format to YUV 444 planar format.
@@ -231,7 +321,7 @@ pub fn yuyv422_to_yuv444(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::YUYV as usize }>(
         y_plane,
         y_stride,
@@ -243,7 +333,7 @@ pub fn yuyv422_to_yuv444(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUYV (YUV Packed) format to YUV 420 planar format.
@@ -280,7 +370,7 @@ pub fn yuyv422_to_yuv420(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::YUYV as usize }>(
         y_plane,
         y_stride,
@@ -292,7 +382,7 @@ pub fn yuyv422_to_yuv420(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) format to YUV 422 planar format.
@@ -329,7 +419,7 @@ pub fn yuyv422_to_yuv422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::YUYV as usize }>(
         y_plane,
         y_stride,
@@ -341,7 +431,7 @@ pub fn yuyv422_to_yuv422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) format to YUV 444 planar format.
@@ -378,7 +468,7 @@ pub fn yvyu422_to_yuv444(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::YVYU as usize }>(
         y_plane,
         y_stride,
@@ -390,7 +480,7 @@ pub fn yvyu422_to_yuv444(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) format to YUV 420 planar format.
@@ -427,7 +517,7 @@ pub fn yvyu422_to_yuv420(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::YVYU as usize }>(
         y_plane,
         y_stride,
@@ -439,7 +529,7 @@ pub fn yvyu422_to_yuv420(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) format to YUV 422 planar format.
@@ -476,7 +566,7 @@ pub fn yvyu422_to_yuv422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::YVYU as usize }>(
         y_plane,
         y_stride,
@@ -488,7 +578,7 @@ pub fn yvyu422_to_yuv422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) format to YUV 444 planar format.
@@ -525,7 +615,7 @@ pub fn vyuy422_to_yuv444(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::VYUY as usize }>(
         y_plane,
         y_stride,
@@ -537,7 +627,7 @@ pub fn vyuy422_to_yuv444(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) format to YUV 420 planar format.
@@ -574,7 +664,7 @@ pub fn vyuy422_to_yuv420(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::VYUY as usize }>(
         y_plane,
         y_stride,
@@ -586,7 +676,7 @@ pub fn vyuy422_to_yuv420(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) format to YUV 422 planar format.
@@ -623,7 +713,7 @@ pub fn vyuy422_to_yuv422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::VYUY as usize }>(
         y_plane,
         y_stride,
@@ -635,7 +725,7 @@ pub fn vyuy422_to_yuv422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) format to YUV 444 planar format.
@@ -672,7 +762,7 @@ pub fn uyvy422_to_yuv444(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::UYVY as usize }>(
         y_plane,
         y_stride,
@@ -684,7 +774,7 @@ pub fn uyvy422_to_yuv444(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) format to YUV 420 planar format.
@@ -721,7 +811,7 @@ pub fn uyvy422_to_yuv420(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::UYVY as usize }>(
         y_plane,
         y_stride,
@@ -733,7 +823,7 @@ pub fn uyvy422_to_yuv420(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) format to YUV 422 planar format.
@@ -770,7 +860,7 @@ pub fn uyvy422_to_yuv422(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::UYVY as usize }>(
         y_plane,
         y_stride,
@@ -782,5 +872,5 @@ pub fn uyvy422_to_yuv422(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
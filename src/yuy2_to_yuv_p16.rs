@@ -26,7 +26,9 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
+use crate::yuv_error::{check_chroma_channel, check_y8_channel, check_yuv_packed422};
 use crate::yuv_support::{YuvChromaSample, Yuy2Description};
+use crate::YuvError;
 
 fn yuy2_to_yuv_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
     y_plane: &mut [u16],
@@ -39,10 +41,15 @@ fn yuy2_to_yuv_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     let yuy2_target: Yuy2Description = YUY2_TARGET.into();
     let chroma_subsampling: YuvChromaSample = SAMPLING.into();
 
+    check_yuv_packed422(yuy2_store, yuy2_stride, width, height)?;
+    check_y8_channel(y_plane, y_stride, width, height)?;
+    check_chroma_channel(u_plane, u_stride, width, height, chroma_subsampling)?;
+    check_chroma_channel(v_plane, v_stride, width, height, chroma_subsampling)?;
+
     let mut y_offset = 0usize;
     let mut u_offset = 0usize;
     let mut v_offset = 0usize;
@@ -54,38 +61,28 @@ fn yuy2_to_yuv_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
         let mut _yuy2_x = 0usize;
 
         for x in _yuy2_x..width as usize / 2 {
-            unsafe {
-                let u_pos = _uv_x;
-                let v_pos = _uv_x;
-                let y_pos = _cx;
+            let u_pos = _uv_x;
+            let v_pos = _uv_x;
+            let y_pos = _cx;
 
-                let mut y_dst_ptr = (y_plane.as_mut_ptr() as *mut u8).add(y_offset) as *mut u16;
-                y_dst_ptr = y_dst_ptr.add(y_pos);
-                let mut u_dst_ptr = (u_plane.as_mut_ptr() as *mut u8).add(u_offset) as *mut u16;
-                u_dst_ptr = u_dst_ptr.add(u_pos);
-                let mut v_dst_ptr = (v_plane.as_mut_ptr() as *mut u8).add(v_offset) as *mut u16;
-                v_dst_ptr = v_dst_ptr.add(v_pos);
+            let y_dst = &mut y_plane[y_offset / 2 + y_pos..];
+            let u_dst = &mut u_plane[u_offset / 2 + u_pos..];
+            let v_dst = &mut v_plane[v_offset / 2 + v_pos..];
 
-                let mut yuy2_ptr = (yuy2_store.as_ptr() as *const u8).add(yuy_offset) as *const u16;
-                yuy2_ptr = yuy2_ptr.add(x * 4);
+            let yuy2_src = &yuy2_store[yuy_offset / 2 + x * 4..];
 
-                let first_y_position = yuy2_ptr
-                    .add(yuy2_target.get_first_y_position())
-                    .read_unaligned();
-                let second_y_position = yuy2_ptr
-                    .add(yuy2_target.get_second_y_position())
-                    .read_unaligned();
-                let u_value = u_dst_ptr.add(yuy2_target.get_u_position()).read_unaligned();
-                let v_value = v_dst_ptr.add(yuy2_target.get_v_position()).read_unaligned();
+            let first_y_position = yuy2_src[yuy2_target.get_first_y_position()];
+            let second_y_position = yuy2_src[yuy2_target.get_second_y_position()];
+            let u_value = yuy2_src[yuy2_target.get_u_position()];
+            let v_value = yuy2_src[yuy2_target.get_v_position()];
 
-                y_dst_ptr.write_unaligned(first_y_position);
-                y_dst_ptr.add(1).write_unaligned(second_y_position);
-                u_dst_ptr.write_unaligned(u_value);
-                v_dst_ptr.write_unaligned(v_value);
-                if chroma_subsampling == YuvChromaSample::YUV444 {
-                    u_dst_ptr.add(1).write_unaligned(u_value);
-                    v_dst_ptr.add(1).write_unaligned(v_value);
-                }
+            y_dst[0] = first_y_position;
+            y_dst[1] = second_y_position;
+            u_dst[0] = u_value;
+            v_dst[0] = v_value;
+            if chroma_subsampling == YuvChromaSample::YUV444 {
+                u_dst[1] = u_value;
+                v_dst[1] = v_value;
             }
 
             _uv_x += match chroma_subsampling {
@@ -96,32 +93,24 @@ fn yuy2_to_yuv_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
         }
 
         if width & 1 == 1 {
-            unsafe {
-                let u_pos = _uv_x;
-                let v_pos = _uv_x;
-                let y_pos = _cx;
-                let yuy2_x = ((width as usize - 1) / 2) * 4;
+            let u_pos = _uv_x;
+            let v_pos = _uv_x;
+            let y_pos = _cx;
+            let yuy2_x = ((width as usize - 1) / 2) * 4;
 
-                let mut y_dst_ptr = (y_plane.as_mut_ptr() as *mut u8).add(y_offset) as *mut u16;
-                y_dst_ptr = y_dst_ptr.add(y_pos);
-                let mut u_dst_ptr = (u_plane.as_mut_ptr() as *mut u8).add(u_offset) as *mut u16;
-                u_dst_ptr = u_dst_ptr.add(u_pos);
-                let mut v_dst_ptr = (v_plane.as_mut_ptr() as *mut u8).add(v_offset) as *mut u16;
-                v_dst_ptr = v_dst_ptr.add(v_pos);
+            let y_dst = &mut y_plane[y_offset / 2 + y_pos..];
+            let u_dst = &mut u_plane[u_offset / 2 + u_pos..];
+            let v_dst = &mut v_plane[v_offset / 2 + v_pos..];
 
-                let mut yuy2_ptr = (yuy2_store.as_ptr() as *const u8).add(yuy_offset) as *const u16;
-                yuy2_ptr = yuy2_ptr.add(yuy2_x);
+            let yuy2_src = &yuy2_store[yuy_offset / 2 + yuy2_x..];
 
-                let first_y_position = yuy2_ptr
-                    .add(yuy2_target.get_first_y_position())
-                    .read_unaligned();
-                let u_value = yuy2_ptr.add(yuy2_target.get_u_position()).read_unaligned();
-                let v_value = yuy2_ptr.add(yuy2_target.get_v_position()).read_unaligned();
+            let first_y_position = yuy2_src[yuy2_target.get_first_y_position()];
+            let u_value = yuy2_src[yuy2_target.get_u_position()];
+            let v_value = yuy2_src[yuy2_target.get_v_position()];
 
-                y_dst_ptr.write_unaligned(first_y_position);
-                u_dst_ptr.write_unaligned(u_value);
-                v_dst_ptr.write_unaligned(v_value);
-            }
+            y_dst[0] = first_y_position;
+            u_dst[0] = u_value;
+            v_dst[0] = v_value;
         }
 
         y_offset += y_stride as usize;
@@ -139,6 +128,8 @@ fn yuy2_to_yuv_impl<const SAMPLING: u8, const YUY2_TARGET: usize>(
             }
         }
     }
+
+    Ok(())
 }
 
 /// Convert YUYV (YUV Packed) format to YUV 444 planar format.
@@ -175,7 +166,7 @@ pub fn yuyv422_to_yuv444_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::YUYV as usize }>(
         y_plane,
         y_stride,
@@ -187,7 +178,7 @@ pub fn yuyv422_to_yuv444_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YUYV (YUV Packed) format to YUV 420 planar format.
@@ -224,7 +215,7 @@ pub fn yuyv422_to_yuv420_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::YUYV as usize }>(
         y_plane,
         y_stride,
@@ -236,7 +227,7 @@ pub fn yuyv422_to_yuv420_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) format to YUV 422 planar format.
@@ -273,7 +264,7 @@ pub fn yuyv422_to_yuv422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::YUYV as usize }>(
         y_plane,
         y_stride,
@@ -285,7 +276,7 @@ pub fn yuyv422_to_yuv422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) format to YUV 444 planar format.
@@ -322,7 +313,7 @@ pub fn yvyu422_to_yuv444_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::YVYU as usize }>(
         y_plane,
         y_stride,
@@ -334,7 +325,7 @@ pub fn yvyu422_to_yuv444_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) format to YUV 420 planar format.
@@ -371,7 +362,7 @@ pub fn yvyu422_to_yuv420_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::YVYU as usize }>(
         y_plane,
         y_stride,
@@ -383,7 +374,7 @@ pub fn yvyu422_to_yuv420_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert YVYU (YUV Packed) format to YUV 422 planar format.
@@ -420,7 +411,7 @@ pub fn yvyu422_to_yuv422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::YVYU as usize }>(
         y_plane,
         y_stride,
@@ -432,7 +423,7 @@ pub fn yvyu422_to_yuv422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) format to YUV 444 planar format.
@@ -469,7 +460,7 @@ pub fn vyuy422_to_yuv444_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::VYUY as usize }>(
         y_plane,
         y_stride,
@@ -481,7 +472,7 @@ pub fn vyuy422_to_yuv444_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) format to YUV 420 planar format.
@@ -518,7 +509,7 @@ pub fn vyuy422_to_yuv420_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::VYUY as usize }>(
         y_plane,
         y_stride,
@@ -530,7 +521,7 @@ pub fn vyuy422_to_yuv420_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert VYUY (YUV Packed) format to YUV 422 planar format.
@@ -567,7 +558,7 @@ pub fn vyuy422_to_yuv422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::VYUY as usize }>(
         y_plane,
         y_stride,
@@ -579,7 +570,7 @@ pub fn vyuy422_to_yuv422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) format to YUV 444 planar format.
@@ -616,7 +607,7 @@ pub fn uyvy422_to_yuv444_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV444 as u8 }, { Yuy2Description::UYVY as usize }>(
         y_plane,
         y_stride,
@@ -628,7 +619,7 @@ pub fn uyvy422_to_yuv444_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) format to YUV 420 planar format.
@@ -665,7 +656,7 @@ pub fn uyvy422_to_yuv420_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV420 as u8 }, { Yuy2Description::UYVY as usize }>(
         y_plane,
         y_stride,
@@ -677,7 +668,7 @@ pub fn uyvy422_to_yuv420_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
 
 /// Convert UYVY (YUV Packed) format to YUV 422 planar format.
@@ -714,7 +705,7 @@ pub fn uyvy422_to_yuv422_p16(
     yuy2_stride: u32,
     width: u32,
     height: u32,
-) {
+) -> Result<(), YuvError> {
     yuy2_to_yuv_impl::<{ YuvChromaSample::YUV422 as u8 }, { Yuy2Description::UYVY as usize }>(
         y_plane,
         y_stride,
@@ -726,5 +717,5 @@ pub fn uyvy422_to_yuv422_p16(
         yuy2_stride,
         width,
         height,
-    );
+    )
 }
@@ -0,0 +1,676 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 11/2024. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::color_description::YuvChromaSiting;
+use crate::images::ImageSize;
+use crate::yuv_error::check_yuv_packed422;
+use crate::yuv_support::Yuy2Description;
+use crate::YuvError;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+/// Source window and orientation of a packed 4:2:2 remap.
+#[derive(Copy, Clone)]
+struct Yuy2Window {
+    x: usize,
+    y: usize,
+    mirror: bool,
+    upside_down: bool,
+}
+
+/// Chroma is stored once per luma pair, so moving the first pixel of a pair to
+/// a source position that is not the one chroma is sited on means resampling chroma.
+///
+/// Position is returned in half chroma samples: even values hit a stored sample,
+/// odd values fall halfway between two neighbours.
+#[inline(always)]
+fn chroma_source_position(
+    pair: usize,
+    dst_width: usize,
+    window: Yuy2Window,
+    centered: bool,
+) -> isize {
+    if window.mirror {
+        // Mirrored pair k covers source pixels x + w - 1 - 2k and x + w - 2 - 2k,
+        // co-sited chroma follows the first one, centered chroma sits between them
+        window.x as isize + dst_width as isize - 1 - 2 * pair as isize - centered as isize
+    } else {
+        (window.x + 2 * pair) as isize
+    }
+}
+
+#[inline(always)]
+fn sample_chroma(src_row: &[u8], position: isize, last_pair: isize, offset: usize) -> u8 {
+    let pair0 = (position.div_euclid(2)).clamp(0, last_pair) as usize;
+    if position & 1 == 0 {
+        src_row[pair0 * 4 + offset]
+    } else {
+        let pair1 = (position.div_euclid(2) + 1).clamp(0, last_pair) as usize;
+        ((src_row[pair0 * 4 + offset] as u16 + src_row[pair1 * 4 + offset] as u16 + 1) >> 1) as u8
+    }
+}
+
+fn yuy2_remap_impl<const YUY2_TARGET: usize>(
+    src: &[u8],
+    src_stride: u32,
+    src_width: u32,
+    src_height: u32,
+    dst: &mut [u8],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    window: Yuy2Window,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    let yuy2_source: Yuy2Description = YUY2_TARGET.into();
+    if width == 0 || height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    check_yuv_packed422(src, src_stride, src_width, src_height)?;
+    check_yuv_packed422(dst, dst_stride, width, height)?;
+
+    let centered = matches!(
+        siting,
+        YuvChromaSiting::Center | YuvChromaSiting::Top | YuvChromaSiting::Bottom
+    );
+    let src_width = src_width as usize;
+    let src_stride = src_stride as usize;
+    let width = width as usize;
+    let height = height as usize;
+    let last_pair = (src_width.div_ceil(2) - 1) as isize;
+    let first_y = yuy2_source.get_first_y_position();
+    let second_y = yuy2_source.get_second_y_position();
+    let u_pos = yuy2_source.get_u_position();
+    let v_pos = yuy2_source.get_v_position();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst.par_chunks_mut(dst_stride as usize).take(height);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst.chunks_mut(dst_stride as usize).take(height);
+    }
+
+    iter.enumerate().for_each(|(y, dst_row)| {
+        let src_y = if window.upside_down {
+            window.y + height - 1 - y
+        } else {
+            window.y + y
+        };
+        let src_row = &src[src_y * src_stride..];
+
+        let luma = |x: usize| -> u8 {
+            let sx = if window.mirror {
+                window.x + width - 1 - x
+            } else {
+                window.x + x
+            };
+            let offset = if sx & 1 == 0 { first_y } else { second_y };
+            src_row[(sx / 2) * 4 + offset]
+        };
+
+        for (pair, dst) in dst_row
+            .chunks_exact_mut(4)
+            .take(width.div_ceil(2))
+            .enumerate()
+        {
+            let y0 = luma(pair * 2);
+            dst[first_y] = y0;
+            // Padding luma of an odd width row repeats the last visible one
+            dst[second_y] = if pair * 2 + 1 < width {
+                luma(pair * 2 + 1)
+            } else {
+                y0
+            };
+            let position = chroma_source_position(pair, width, window, centered);
+            dst[u_pos] = sample_chroma(src_row, position, last_pair, u_pos);
+            dst[v_pos] = sample_chroma(src_row, position, last_pair, v_pos);
+        }
+    });
+
+    Ok(())
+}
+
+fn yuy2_crop_impl<const YUY2_TARGET: usize>(
+    src: &[u8],
+    src_stride: u32,
+    src_size: ImageSize,
+    dst: &mut [u8],
+    dst_stride: u32,
+    x: u32,
+    y: u32,
+    crop_size: ImageSize,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    let fits = |origin: u32, extent: u32, bound: u32| {
+        origin
+            .checked_add(extent)
+            .map(|end| end <= bound)
+            .unwrap_or(false)
+    };
+    if !fits(x, crop_size.width, src_size.width) || !fits(y, crop_size.height, src_size.height) {
+        return Err(YuvError::CropOutOfBounds(x, y, crop_size));
+    }
+    yuy2_remap_impl::<YUY2_TARGET>(
+        src,
+        src_stride,
+        src_size.width,
+        src_size.height,
+        dst,
+        dst_stride,
+        crop_size.width,
+        crop_size.height,
+        Yuy2Window {
+            x: x as usize,
+            y: y as usize,
+            mirror: false,
+            upside_down: false,
+        },
+        siting,
+    )
+}
+
+/// Rotates YUYV ( YUV Packed 4:2:2 ) image by 180 degrees.
+///
+/// Luma pairs are reversed together with the row order, chroma is re-phased
+/// according to `siting` so it stays aligned with the first pixel of each pair.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the YUYV data.
+/// * `src_stride` - The stride (bytes per row) for the source YUYV data.
+/// * `dst` - A mutable slice to store the transformed YUYV data.
+/// * `dst_stride` - The stride (bytes per row) for the destination YUYV data.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `siting` - Horizontal chroma sample location of the image.
+///
+pub fn yuyv422_rotate180(
+    src: &[u8],
+    src_stride: u32,
+    dst: &mut [u8],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    yuy2_remap_impl::<{ Yuy2Description::YUYV as usize }>(
+        src,
+        src_stride,
+        width,
+        height,
+        dst,
+        dst_stride,
+        width,
+        height,
+        Yuy2Window {
+            x: 0,
+            y: 0,
+            mirror: true,
+            upside_down: true,
+        },
+        siting,
+    )
+}
+
+/// Mirrors YUYV ( YUV Packed 4:2:2 ) image horizontally.
+///
+/// Luma pairs are reversed, chroma is re-phased according to `siting`
+/// so it stays aligned with the first pixel of each pair.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the YUYV data.
+/// * `src_stride` - The stride (bytes per row) for the source YUYV data.
+/// * `dst` - A mutable slice to store the transformed YUYV data.
+/// * `dst_stride` - The stride (bytes per row) for the destination YUYV data.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `siting` - Horizontal chroma sample location of the image.
+///
+pub fn yuyv422_flip_horizontal(
+    src: &[u8],
+    src_stride: u32,
+    dst: &mut [u8],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    yuy2_remap_impl::<{ Yuy2Description::YUYV as usize }>(
+        src,
+        src_stride,
+        width,
+        height,
+        dst,
+        dst_stride,
+        width,
+        height,
+        Yuy2Window {
+            x: 0,
+            y: 0,
+            mirror: true,
+            upside_down: false,
+        },
+        siting,
+    )
+}
+
+/// Crops YUYV ( YUV Packed 4:2:2 ) image.
+///
+/// Region may start at an odd column, in that case chroma is re-phased according to `siting`
+/// so it stays aligned with the first pixel of each output pair.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the YUYV data.
+/// * `src_stride` - The stride (bytes per row) for the source YUYV data.
+/// * `src_size` - Size of the source image.
+/// * `dst` - A mutable slice to store the cropped YUYV data.
+/// * `dst_stride` - The stride (bytes per row) for the destination YUYV data.
+/// * `x` - First column of the crop region.
+/// * `y` - First row of the crop region.
+/// * `crop_size` - Size of the crop region and of the destination image.
+/// * `siting` - Horizontal chroma sample location of the image.
+///
+pub fn yuyv422_crop(
+    src: &[u8],
+    src_stride: u32,
+    src_size: ImageSize,
+    dst: &mut [u8],
+    dst_stride: u32,
+    x: u32,
+    y: u32,
+    crop_size: ImageSize,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    yuy2_crop_impl::<{ Yuy2Description::YUYV as usize }>(
+        src, src_stride, src_size, dst, dst_stride, x, y, crop_size, siting,
+    )
+}
+
+/// Rotates UYVY ( YUV Packed 4:2:2 ) image by 180 degrees.
+///
+/// Luma pairs are reversed together with the row order, chroma is re-phased
+/// according to `siting` so it stays aligned with the first pixel of each pair.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the UYVY data.
+/// * `src_stride` - The stride (bytes per row) for the source UYVY data.
+/// * `dst` - A mutable slice to store the transformed UYVY data.
+/// * `dst_stride` - The stride (bytes per row) for the destination UYVY data.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `siting` - Horizontal chroma sample location of the image.
+///
+pub fn uyvy422_rotate180(
+    src: &[u8],
+    src_stride: u32,
+    dst: &mut [u8],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    yuy2_remap_impl::<{ Yuy2Description::UYVY as usize }>(
+        src,
+        src_stride,
+        width,
+        height,
+        dst,
+        dst_stride,
+        width,
+        height,
+        Yuy2Window {
+            x: 0,
+            y: 0,
+            mirror: true,
+            upside_down: true,
+        },
+        siting,
+    )
+}
+
+/// Mirrors UYVY ( YUV Packed 4:2:2 ) image horizontally.
+///
+/// Luma pairs are reversed, chroma is re-phased according to `siting`
+/// so it stays aligned with the first pixel of each pair.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the UYVY data.
+/// * `src_stride` - The stride (bytes per row) for the source UYVY data.
+/// * `dst` - A mutable slice to store the transformed UYVY data.
+/// * `dst_stride` - The stride (bytes per row) for the destination UYVY data.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `siting` - Horizontal chroma sample location of the image.
+///
+pub fn uyvy422_flip_horizontal(
+    src: &[u8],
+    src_stride: u32,
+    dst: &mut [u8],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    yuy2_remap_impl::<{ Yuy2Description::UYVY as usize }>(
+        src,
+        src_stride,
+        width,
+        height,
+        dst,
+        dst_stride,
+        width,
+        height,
+        Yuy2Window {
+            x: 0,
+            y: 0,
+            mirror: true,
+            upside_down: false,
+        },
+        siting,
+    )
+}
+
+/// Crops UYVY ( YUV Packed 4:2:2 ) image.
+///
+/// Region may start at an odd column, in that case chroma is re-phased according to `siting`
+/// so it stays aligned with the first pixel of each output pair.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the UYVY data.
+/// * `src_stride` - The stride (bytes per row) for the source UYVY data.
+/// * `src_size` - Size of the source image.
+/// * `dst` - A mutable slice to store the cropped UYVY data.
+/// * `dst_stride` - The stride (bytes per row) for the destination UYVY data.
+/// * `x` - First column of the crop region.
+/// * `y` - First row of the crop region.
+/// * `crop_size` - Size of the crop region and of the destination image.
+/// * `siting` - Horizontal chroma sample location of the image.
+///
+pub fn uyvy422_crop(
+    src: &[u8],
+    src_stride: u32,
+    src_size: ImageSize,
+    dst: &mut [u8],
+    dst_stride: u32,
+    x: u32,
+    y: u32,
+    crop_size: ImageSize,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    yuy2_crop_impl::<{ Yuy2Description::UYVY as usize }>(
+        src, src_stride, src_size, dst, dst_stride, x, y, crop_size, siting,
+    )
+}
+
+/// Rotates YVYU ( YUV Packed 4:2:2 ) image by 180 degrees.
+///
+/// Luma pairs are reversed together with the row order, chroma is re-phased
+/// according to `siting` so it stays aligned with the first pixel of each pair.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the YVYU data.
+/// * `src_stride` - The stride (bytes per row) for the source YVYU data.
+/// * `dst` - A mutable slice to store the transformed YVYU data.
+/// * `dst_stride` - The stride (bytes per row) for the destination YVYU data.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `siting` - Horizontal chroma sample location of the image.
+///
+pub fn yvyu422_rotate180(
+    src: &[u8],
+    src_stride: u32,
+    dst: &mut [u8],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    yuy2_remap_impl::<{ Yuy2Description::YVYU as usize }>(
+        src,
+        src_stride,
+        width,
+        height,
+        dst,
+        dst_stride,
+        width,
+        height,
+        Yuy2Window {
+            x: 0,
+            y: 0,
+            mirror: true,
+            upside_down: true,
+        },
+        siting,
+    )
+}
+
+/// Mirrors YVYU ( YUV Packed 4:2:2 ) image horizontally.
+///
+/// Luma pairs are reversed, chroma is re-phased according to `siting`
+/// so it stays aligned with the first pixel of each pair.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the YVYU data.
+/// * `src_stride` - The stride (bytes per row) for the source YVYU data.
+/// * `dst` - A mutable slice to store the transformed YVYU data.
+/// * `dst_stride` - The stride (bytes per row) for the destination YVYU data.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `siting` - Horizontal chroma sample location of the image.
+///
+pub fn yvyu422_flip_horizontal(
+    src: &[u8],
+    src_stride: u32,
+    dst: &mut [u8],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    yuy2_remap_impl::<{ Yuy2Description::YVYU as usize }>(
+        src,
+        src_stride,
+        width,
+        height,
+        dst,
+        dst_stride,
+        width,
+        height,
+        Yuy2Window {
+            x: 0,
+            y: 0,
+            mirror: true,
+            upside_down: false,
+        },
+        siting,
+    )
+}
+
+/// Crops YVYU ( YUV Packed 4:2:2 ) image.
+///
+/// Region may start at an odd column, in that case chroma is re-phased according to `siting`
+/// so it stays aligned with the first pixel of each output pair.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the YVYU data.
+/// * `src_stride` - The stride (bytes per row) for the source YVYU data.
+/// * `src_size` - Size of the source image.
+/// * `dst` - A mutable slice to store the cropped YVYU data.
+/// * `dst_stride` - The stride (bytes per row) for the destination YVYU data.
+/// * `x` - First column of the crop region.
+/// * `y` - First row of the crop region.
+/// * `crop_size` - Size of the crop region and of the destination image.
+/// * `siting` - Horizontal chroma sample location of the image.
+///
+pub fn yvyu422_crop(
+    src: &[u8],
+    src_stride: u32,
+    src_size: ImageSize,
+    dst: &mut [u8],
+    dst_stride: u32,
+    x: u32,
+    y: u32,
+    crop_size: ImageSize,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    yuy2_crop_impl::<{ Yuy2Description::YVYU as usize }>(
+        src, src_stride, src_size, dst, dst_stride, x, y, crop_size, siting,
+    )
+}
+
+/// Rotates VYUY ( YUV Packed 4:2:2 ) image by 180 degrees.
+///
+/// Luma pairs are reversed together with the row order, chroma is re-phased
+/// according to `siting` so it stays aligned with the first pixel of each pair.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the VYUY data.
+/// * `src_stride` - The stride (bytes per row) for the source VYUY data.
+/// * `dst` - A mutable slice to store the transformed VYUY data.
+/// * `dst_stride` - The stride (bytes per row) for the destination VYUY data.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `siting` - Horizontal chroma sample location of the image.
+///
+pub fn vyuy422_rotate180(
+    src: &[u8],
+    src_stride: u32,
+    dst: &mut [u8],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    yuy2_remap_impl::<{ Yuy2Description::VYUY as usize }>(
+        src,
+        src_stride,
+        width,
+        height,
+        dst,
+        dst_stride,
+        width,
+        height,
+        Yuy2Window {
+            x: 0,
+            y: 0,
+            mirror: true,
+            upside_down: true,
+        },
+        siting,
+    )
+}
+
+/// Mirrors VYUY ( YUV Packed 4:2:2 ) image horizontally.
+///
+/// Luma pairs are reversed, chroma is re-phased according to `siting`
+/// so it stays aligned with the first pixel of each pair.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the VYUY data.
+/// * `src_stride` - The stride (bytes per row) for the source VYUY data.
+/// * `dst` - A mutable slice to store the transformed VYUY data.
+/// * `dst_stride` - The stride (bytes per row) for the destination VYUY data.
+/// * `width` - The width of the image.
+/// * `height` - The height of the image.
+/// * `siting` - Horizontal chroma sample location of the image.
+///
+pub fn vyuy422_flip_horizontal(
+    src: &[u8],
+    src_stride: u32,
+    dst: &mut [u8],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    yuy2_remap_impl::<{ Yuy2Description::VYUY as usize }>(
+        src,
+        src_stride,
+        width,
+        height,
+        dst,
+        dst_stride,
+        width,
+        height,
+        Yuy2Window {
+            x: 0,
+            y: 0,
+            mirror: true,
+            upside_down: false,
+        },
+        siting,
+    )
+}
+
+/// Crops VYUY ( YUV Packed 4:2:2 ) image.
+///
+/// Region may start at an odd column, in that case chroma is re-phased according to `siting`
+/// so it stays aligned with the first pixel of each output pair.
+///
+/// # Arguments
+///
+/// * `src` - A slice to load the VYUY data.
+/// * `src_stride` - The stride (bytes per row) for the source VYUY data.
+/// * `src_size` - Size of the source image.
+/// * `dst` - A mutable slice to store the cropped VYUY data.
+/// * `dst_stride` - The stride (bytes per row) for the destination VYUY data.
+/// * `x` - First column of the crop region.
+/// * `y` - First row of the crop region.
+/// * `crop_size` - Size of the crop region and of the destination image.
+/// * `siting` - Horizontal chroma sample location of the image.
+///
+pub fn vyuy422_crop(
+    src: &[u8],
+    src_stride: u32,
+    src_size: ImageSize,
+    dst: &mut [u8],
+    dst_stride: u32,
+    x: u32,
+    y: u32,
+    crop_size: ImageSize,
+    siting: YuvChromaSiting,
+) -> Result<(), YuvError> {
+    yuy2_crop_impl::<{ Yuy2Description::VYUY as usize }>(
+        src, src_stride, src_size, dst, dst_stride, x, y, crop_size, siting,
+    )
+}
@@ -0,0 +1,313 @@
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use yuvutils_rs::{
+    rgb_to_yuv_nv12, rgba_to_yuv420, rgba_to_yuv420_p16, yuv400_to_rgba, yuv420_p16_to_rgba16,
+    yuv420_to_rgba, yuv420_to_rgba_f32, yuv420_to_yuyv422, yuv422_to_rgb, yuv444_to_bgra,
+    yuv_nv12_to_rgba, yuv_nv12_to_rgba_p16, yuyv422_to_rgba, ImageSize, RowStride, YuvBytesPacking,
+    YuvChromaSample, YuvConversionContext, YuvEndianness, YuvError, YuvFramePool,
+    YuvFramePoolLayout, YuvRange, YuvStandardMatrix,
+};
+
+/// Sizes passed to a converter, every plane of it gets `stride`
+#[derive(Debug, Copy, Clone)]
+struct Case {
+    width: u32,
+    height: u32,
+    stride: u32,
+}
+
+/// Buffers are small, every case must be rejected before plane sizes are looked at
+const BUFFER: usize = 64;
+
+type Converter = fn(Case) -> Result<(), YuvError>;
+
+const CONVERTERS: [(&str, Converter); 13] = [
+    ("yuv420_to_rgba", |c| {
+        let p = vec![0u8; BUFFER];
+        let mut rgba = vec![0u8; BUFFER];
+        yuv420_to_rgba(
+            &p,
+            c.stride,
+            &p,
+            c.stride,
+            &p,
+            c.stride,
+            &mut rgba,
+            c.stride,
+            c.width,
+            c.height,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )
+    }),
+    ("yuv422_to_rgb", |c| {
+        let p = vec![0u8; BUFFER];
+        let mut rgb = vec![0u8; BUFFER];
+        yuv422_to_rgb(
+            &p,
+            c.stride,
+            &p,
+            c.stride,
+            &p,
+            c.stride,
+            &mut rgb,
+            c.stride,
+            c.width,
+            c.height,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt601,
+        )
+    }),
+    ("yuv444_to_bgra", |c| {
+        let p = vec![0u8; BUFFER];
+        let mut bgra = vec![0u8; BUFFER];
+        yuv444_to_bgra(
+            &p,
+            c.stride,
+            &p,
+            c.stride,
+            &p,
+            c.stride,
+            &mut bgra,
+            c.stride,
+            c.width,
+            c.height,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt2020,
+        )
+    }),
+    ("yuv_nv12_to_rgba", |c| {
+        let p = vec![0u8; BUFFER];
+        let mut rgba = vec![0u8; BUFFER];
+        yuv_nv12_to_rgba(
+            &p,
+            c.stride,
+            &p,
+            c.stride,
+            &mut rgba,
+            c.stride,
+            c.width,
+            c.height,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )
+    }),
+    ("yuv_nv12_to_rgba_p16", |c| {
+        let p = vec![0u16; BUFFER];
+        let mut rgba = vec![0u16; BUFFER];
+        yuv_nv12_to_rgba_p16(
+            &p,
+            c.stride,
+            &p,
+            c.stride,
+            &mut rgba,
+            c.stride,
+            10,
+            c.width,
+            c.height,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+            YuvEndianness::LittleEndian,
+            YuvBytesPacking::LeastSignificantBytes,
+        )
+    }),
+    ("yuv420_p16_to_rgba16", |c| {
+        let p = vec![0u16; BUFFER];
+        let mut rgba = vec![0u16; BUFFER];
+        yuv420_p16_to_rgba16(
+            &p,
+            c.stride,
+            &p,
+            c.stride,
+            &p,
+            c.stride,
+            &mut rgba,
+            c.stride,
+            12,
+            c.width,
+            c.height,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+            YuvEndianness::BigEndian,
+            YuvBytesPacking::LeastSignificantBytes,
+        )
+    }),
+    ("yuv420_to_rgba_f32", |c| {
+        let p = vec![0u8; BUFFER];
+        let mut rgba = vec![0f32; BUFFER];
+        yuv420_to_rgba_f32(
+            &p,
+            RowStride::new(c.stride),
+            &p,
+            RowStride::new(c.stride),
+            &p,
+            RowStride::new(c.stride),
+            &mut rgba,
+            RowStride::new(c.stride),
+            ImageSize::new(c.width, c.height),
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )
+    }),
+    ("yuv400_to_rgba", |c| {
+        let p = vec![0u8; BUFFER];
+        let mut rgba = vec![0u8; BUFFER];
+        yuv400_to_rgba(
+            &p,
+            c.stride,
+            &mut rgba,
+            c.stride,
+            c.width,
+            c.height,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )
+    }),
+    ("yuyv422_to_rgba", |c| {
+        let p = vec![0u8; BUFFER];
+        let mut rgba = vec![0u8; BUFFER];
+        yuyv422_to_rgba(
+            &p,
+            c.stride,
+            &mut rgba,
+            c.stride,
+            c.width,
+            c.height,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )
+    }),
+    ("yuv420_to_yuyv422", |c| {
+        let p = vec![0u8; BUFFER];
+        let mut yuy2 = vec![0u8; BUFFER];
+        yuv420_to_yuyv422(
+            &p, c.stride, &p, c.stride, &p, c.stride, &mut yuy2, c.stride, c.width, c.height,
+        )
+    }),
+    ("rgba_to_yuv420", |c| {
+        let (mut y, mut u, mut v) = (vec![0u8; BUFFER], vec![0u8; BUFFER], vec![0u8; BUFFER]);
+        let rgba = vec![0u8; BUFFER];
+        rgba_to_yuv420(
+            &mut y,
+            c.stride,
+            &mut u,
+            c.stride,
+            &mut v,
+            c.stride,
+            &rgba,
+            c.stride,
+            c.width,
+            c.height,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )
+    }),
+    ("rgb_to_yuv_nv12", |c| {
+        let (mut y, mut uv) = (vec![0u8; BUFFER], vec![0u8; BUFFER]);
+        let rgb = vec![0u8; BUFFER];
+        rgb_to_yuv_nv12(
+            &mut y,
+            c.stride,
+            &mut uv,
+            c.stride,
+            &rgb,
+            c.stride,
+            c.width,
+            c.height,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt601,
+        )
+    }),
+    ("rgba_to_yuv420_p16", |c| {
+        let (mut y, mut u, mut v) = (vec![0u16; BUFFER], vec![0u16; BUFFER], vec![0u16; BUFFER]);
+        let rgba = vec![0u16; BUFFER];
+        rgba_to_yuv420_p16(
+            &mut y,
+            c.stride,
+            &mut u,
+            c.stride,
+            &mut v,
+            c.stride,
+            &rgba,
+            c.stride,
+            10,
+            c.width,
+            c.height,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+            YuvEndianness::LittleEndian,
+            YuvBytesPacking::LeastSignificantBytes,
+        )
+    }),
+];
+
+fn check(case: Case, expected: fn(&YuvError) -> bool) {
+    for (name, convert) in CONVERTERS {
+        let result = catch_unwind(AssertUnwindSafe(|| convert(case)))
+            .unwrap_or_else(|_| panic!("{name} panicked on {case:?}"));
+        match result {
+            Err(e) if expected(&e) => {}
+            other => panic!("{name} returned {other:?} on {case:?}"),
+        }
+    }
+}
+
+#[test]
+fn zero_sizes() {
+    for (width, height, stride) in [(0, 4, 0), (0, 4, 64), (16, 0, 0), (16, 0, 64), (0, 0, 0)] {
+        check(
+            Case {
+                width,
+                height,
+                stride,
+            },
+            |e| matches!(e, YuvError::ZeroBaseSize),
+        );
+    }
+}
+
+#[test]
+fn overflowing_stride_and_height() {
+    for (width, height) in [(16, u32::MAX), (u32::MAX, u32::MAX)] {
+        check(
+            Case {
+                width,
+                height,
+                stride: u32::MAX,
+            },
+            |e| matches!(e, YuvError::ImageTooLarge(_)),
+        );
+    }
+}
+
+#[test]
+fn stride_shorter_than_row() {
+    for (width, height) in [(16, 2), (17, 3), (9, 1)] {
+        check(
+            Case {
+                width,
+                height,
+                stride: 4,
+            },
+            |e| matches!(e, YuvError::StrideTooSmall(_)),
+        );
+    }
+}
+
+#[test]
+fn unallocatable_frame_pool_capacity() {
+    let context = YuvConversionContext::new(
+        YuvChromaSample::YUV420,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt709,
+    );
+    let pool = catch_unwind(|| {
+        YuvFramePool::new(
+            context,
+            ImageSize::new(2, 2),
+            YuvFramePoolLayout::Rgba,
+            usize::MAX,
+        )
+    })
+    .expect("frame pool panicked");
+    assert!(matches!(pool, Err(YuvError::AllocationFailure(_))));
+}
@@ -0,0 +1,187 @@
+use yuvutils_rs::{
+    jpeg_blocks_size, rgba_rows_to_yuv420_rows, rgba_to_yuv420, rgba_to_yuv_jpeg_blocks, ImageSize,
+    RowStride, YuvChromaSample, YuvConversionContext, YuvError, YuvPlanarImage, YuvRange,
+    YuvStandardMatrix,
+};
+
+/// HD by height alone, any strip or row pair of it would look SD
+const WIDTH: u32 = 1024;
+const HEIGHT: u32 = 768;
+
+fn rgba() -> Vec<u8> {
+    (0..(WIDTH * HEIGHT * 4) as usize)
+        .map(|i| (i * 37 % 251) as u8)
+        .collect()
+}
+
+struct Yuv420 {
+    y: Vec<u8>,
+    u: Vec<u8>,
+    v: Vec<u8>,
+}
+
+fn encode(matrix: YuvStandardMatrix) -> Yuv420 {
+    let chroma = (WIDTH / 2 * HEIGHT / 2) as usize;
+    let mut yuv = Yuv420 {
+        y: vec![0; (WIDTH * HEIGHT) as usize],
+        u: vec![0; chroma],
+        v: vec![0; chroma],
+    };
+    rgba_to_yuv420(
+        &mut yuv.y,
+        WIDTH,
+        &mut yuv.u,
+        WIDTH / 2,
+        &mut yuv.v,
+        WIDTH / 2,
+        &rgba(),
+        WIDTH * 4,
+        WIDTH,
+        HEIGHT,
+        YuvRange::TV,
+        matrix,
+    )
+    .unwrap();
+    yuv
+}
+
+#[test]
+fn resolve_thresholds() {
+    use YuvStandardMatrix::*;
+    let cases = [
+        (1, 1, Bt601),
+        (720, 576, Bt601),
+        (1024, 576, Bt601),
+        (1025, 576, Bt709),
+        (1024, 577, Bt709),
+        (1024, 768, Bt709),
+        (1920, 1080, Bt709),
+        (3839, 2159, Bt709),
+        (3840, 1, Bt2020),
+        (1, 2160, Bt2020),
+        (7680, 4320, Bt2020),
+    ];
+    for (width, height, expected) in cases {
+        assert_eq!(Auto.resolve(width, height), expected, "{width}x{height}");
+    }
+    for explicit in [Bt601, Bt709, Bt2020, Smpte240, Bt470_6] {
+        assert_eq!(explicit.resolve(1, 1), explicit);
+        assert_eq!(explicit.resolve(7680, 4320), explicit);
+    }
+}
+
+#[test]
+fn frame_encoding_resolves_against_frame() {
+    let auto = encode(YuvStandardMatrix::Auto);
+    let hd = encode(YuvStandardMatrix::Bt709);
+    assert_ne!(hd.u, encode(YuvStandardMatrix::Bt601).u);
+    assert_eq!(auto.y, hd.y);
+    assert_eq!(auto.u, hd.u);
+    assert_eq!(auto.v, hd.v);
+}
+
+#[test]
+fn row_encoding_rejects_auto() {
+    let rgba = rgba();
+    let row = (WIDTH * 4) as usize;
+    let mut y = vec![0; WIDTH as usize * 2];
+    let (y0, y1) = y.split_at_mut(WIDTH as usize);
+    let mut u = vec![0; WIDTH as usize / 2];
+    let mut v = vec![0; WIDTH as usize / 2];
+    let result = rgba_rows_to_yuv420_rows(
+        &rgba[..row],
+        &rgba[row..row * 2],
+        y0,
+        y1,
+        &mut u,
+        &mut v,
+        WIDTH,
+        YuvRange::TV,
+        YuvStandardMatrix::Auto,
+    );
+    assert!(matches!(result, Err(YuvError::UnresolvedAutoMatrix)));
+}
+
+#[test]
+fn row_encoding_matches_frame_once_resolved() {
+    let rgba = rgba();
+    let frame = encode(YuvStandardMatrix::Auto);
+    let matrix = YuvStandardMatrix::Auto.resolve(WIDTH, HEIGHT);
+    let row = (WIDTH * 4) as usize;
+    let width = WIDTH as usize;
+    for pair in (0..HEIGHT as usize / 2).step_by(97) {
+        let mut y = vec![0; width * 2];
+        let (y0, y1) = y.split_at_mut(width);
+        let mut u = vec![0; width / 2];
+        let mut v = vec![0; width / 2];
+        rgba_rows_to_yuv420_rows(
+            &rgba[pair * 2 * row..][..row],
+            &rgba[(pair * 2 + 1) * row..][..row],
+            y0,
+            y1,
+            &mut u,
+            &mut v,
+            WIDTH,
+            YuvRange::TV,
+            matrix,
+        )
+        .unwrap();
+        assert_eq!(y, frame.y[pair * 2 * width..][..width * 2], "pair {pair}");
+        assert_eq!(u, frame.u[pair * width / 2..][..width / 2], "pair {pair}");
+        assert_eq!(v, frame.v[pair * width / 2..][..width / 2], "pair {pair}");
+    }
+}
+
+#[test]
+fn jpeg_blocks_resolve_against_frame() {
+    let rgba = rgba();
+    let blocks = |matrix| {
+        let mut blocks =
+            vec![0; jpeg_blocks_size(ImageSize::new(WIDTH, HEIGHT), YuvChromaSample::YUV420)];
+        rgba_to_yuv_jpeg_blocks(
+            &mut blocks,
+            &rgba,
+            RowStride::new(WIDTH * 4),
+            ImageSize::new(WIDTH, HEIGHT),
+            YuvChromaSample::YUV420,
+            YuvRange::TV,
+            matrix,
+        )
+        .unwrap();
+        blocks
+    };
+    assert_eq!(
+        blocks(YuvStandardMatrix::Auto),
+        blocks(YuvStandardMatrix::Bt709)
+    );
+}
+
+#[test]
+fn row_decoding_resolves_against_frame() {
+    let frame = encode(YuvStandardMatrix::Bt709);
+    let image = YuvPlanarImage {
+        y_plane: &frame.y,
+        y_stride: RowStride::new(WIDTH),
+        u_plane: &frame.u,
+        u_stride: RowStride::new(WIDTH / 2),
+        v_plane: &frame.v,
+        v_stride: RowStride::new(WIDTH / 2),
+        size: ImageSize::new(WIDTH, HEIGHT),
+    };
+    let decode = |matrix, rows: Option<std::ops::Range<u32>>| {
+        let context = YuvConversionContext::new(YuvChromaSample::YUV420, YuvRange::TV, matrix);
+        let mut rgba = vec![0; (WIDTH * HEIGHT * 4) as usize];
+        match rows {
+            Some(rows) => context.yuv_to_rgba_rows(&image, &mut rgba, WIDTH * 4, rows),
+            None => context.yuv_to_rgba(&image, &mut rgba, WIDTH * 4),
+        }
+        .unwrap();
+        rgba
+    };
+    let whole = decode(YuvStandardMatrix::Bt709, None);
+    let rows = 100..116;
+    let band = decode(YuvStandardMatrix::Auto, Some(rows.clone()));
+    let range = (rows.start * WIDTH * 4) as usize..(rows.end * WIDTH * 4) as usize;
+    assert_eq!(band[range.clone()], whole[range]);
+    assert_eq!(decode(YuvStandardMatrix::Auto, None), whole);
+}
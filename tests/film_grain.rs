@@ -0,0 +1,88 @@
+use yuvutils_rs::{
+    yuv_to_rgba_with_grain, ImageSize, RowStride, YuvChromaSample, YuvConversionContext,
+    YuvGrainPlane, YuvPlanarImage, YuvRange, YuvStandardMatrix,
+};
+
+const WIDTH: u32 = 29;
+/// Heights below, at and across the band size, with a short last band
+const HEIGHTS: [u32; 5] = [1, 15, 16, 17, 53];
+const SAMPLINGS: [YuvChromaSample; 3] = [
+    YuvChromaSample::YUV420,
+    YuvChromaSample::YUV422,
+    YuvChromaSample::YUV444,
+];
+
+fn grain(plane: YuvGrainPlane, row: usize, samples: &mut [u8]) {
+    let offset = match plane {
+        YuvGrainPlane::Y => 3,
+        YuvGrainPlane::U => 5,
+        YuvGrainPlane::V => 11,
+    };
+    for (x, v) in samples.iter_mut().enumerate() {
+        *v = v.wrapping_add((row * offset + x) as u8 % 17);
+    }
+}
+
+/// Grain applied in bands with reused buffers must match grain applied to the whole planes
+#[test]
+fn grain_matches_pre_grained_frame() {
+    for sampling in SAMPLINGS {
+        for height in HEIGHTS {
+            let chroma_width = match sampling {
+                YuvChromaSample::YUV444 => WIDTH,
+                _ => WIDTH.div_ceil(2),
+            };
+            let chroma_height = match sampling {
+                YuvChromaSample::YUV420 => height.div_ceil(2),
+                _ => height,
+            };
+            let y: Vec<u8> = (0..WIDTH * height)
+                .map(|i| (16 + i * 7 % 200) as u8)
+                .collect();
+            let chroma = (chroma_width * chroma_height) as usize;
+            let u: Vec<u8> = (0..chroma).map(|i| (16 + i * 13 % 200) as u8).collect();
+            let v: Vec<u8> = (0..chroma).map(|i| (16 + i * 29 % 200) as u8).collect();
+            let image = |y, u, v| YuvPlanarImage {
+                y_plane: y,
+                y_stride: RowStride::new(WIDTH),
+                u_plane: u,
+                u_stride: RowStride::new(chroma_width),
+                v_plane: v,
+                v_stride: RowStride::new(chroma_width),
+                size: ImageSize::new(WIDTH, height),
+            };
+
+            let stride = WIDTH * 4;
+            let mut grained = vec![0u8; (stride * height) as usize];
+            yuv_to_rgba_with_grain(
+                &image(&y, &u, &v),
+                sampling,
+                &mut grained,
+                stride,
+                YuvRange::TV,
+                YuvStandardMatrix::Bt709,
+                grain,
+            )
+            .unwrap();
+
+            let (mut gy, mut gu, mut gv) = (y.clone(), u.clone(), v.clone());
+            for (row, samples) in gy.chunks_exact_mut(WIDTH as usize).enumerate() {
+                grain(YuvGrainPlane::Y, row, samples);
+            }
+            for (row, (su, sv)) in gu
+                .chunks_exact_mut(chroma_width as usize)
+                .zip(gv.chunks_exact_mut(chroma_width as usize))
+                .enumerate()
+            {
+                grain(YuvGrainPlane::U, row, su);
+                grain(YuvGrainPlane::V, row, sv);
+            }
+            let mut expected = vec![0u8; (stride * height) as usize];
+            YuvConversionContext::new(sampling, YuvRange::TV, YuvStandardMatrix::Bt709)
+                .yuv_to_rgba(&image(&gy, &gu, &gv), &mut expected, stride)
+                .unwrap();
+
+            assert_eq!(grained, expected, "{sampling:?} height {height}");
+        }
+    }
+}
@@ -0,0 +1,122 @@
+#![cfg(target_pointer_width = "64")]
+//! Planes whose `stride * height` is around 2^31 and 2^32 bytes. Strides are huge and images
+//! are narrow, so only a page or two per row is ever touched while offsets of the last rows
+//! do not fit `i32` or `u32`.
+
+use std::alloc::{alloc_zeroed, Layout};
+use yuvutils_rs::{yuv400_to_rgba, YuvRange, YuvStandardMatrix};
+
+const WIDTH: u32 = 48;
+const HEIGHT: u32 = 4096;
+
+/// Strides putting `stride * HEIGHT` just below and just above 2^31 and `u32::MAX`
+const STRIDES: [u32; 4] = [(1 << 19) - 1, (1 << 19) + 1, (1 << 20) - 1, (1 << 20) + 1];
+
+/// Zeroed buffer whose pages are committed only once written, `None` when the system
+/// refuses to reserve that much address space
+fn zeroed(len: usize) -> Option<Vec<u8>> {
+    let layout = Layout::array::<u8>(len).ok()?;
+    // SAFETY: layout is not zero sized, pointer is checked for null and owns `len`
+    // initialized bytes allocated by the global allocator with alignment of `u8`
+    unsafe {
+        let ptr = alloc_zeroed(layout);
+        if ptr.is_null() {
+            return None;
+        }
+        Some(Vec::from_raw_parts(ptr, len, len))
+    }
+}
+
+/// Luma of row `row`, every row differs from its neighbours
+fn luma(row: usize) -> u8 {
+    16 + (row * 37 % 220) as u8
+}
+
+/// RGBA of every luma code, converted with small offsets only
+fn gray_lut() -> Vec<[u8; 4]> {
+    let y = (0..=255u8).collect::<Vec<_>>();
+    let mut rgba = vec![0u8; 256 * 4];
+    yuv400_to_rgba(
+        &y,
+        256,
+        &mut rgba,
+        256 * 4,
+        256,
+        1,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt709,
+    )
+    .unwrap();
+    rgba.chunks_exact(4)
+        .map(|x| [x[0], x[1], x[2], x[3]])
+        .collect()
+}
+
+#[test]
+fn source_plane_beyond_32_bit_offsets() {
+    let lut = gray_lut();
+    for stride in STRIDES {
+        let len = stride as usize * HEIGHT as usize;
+        let Some(mut y_plane) = zeroed(len) else {
+            eprintln!("skipping stride {stride}, {len} bytes cannot be reserved");
+            continue;
+        };
+        for (row, y) in y_plane.chunks_mut(stride as usize).enumerate() {
+            y[..WIDTH as usize].fill(luma(row));
+        }
+        let mut rgba = vec![0u8; (WIDTH * 4 * HEIGHT) as usize];
+        yuv400_to_rgba(
+            &y_plane,
+            stride,
+            &mut rgba,
+            WIDTH * 4,
+            WIDTH,
+            HEIGHT,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        for (row, rgba) in rgba.chunks_exact((WIDTH * 4) as usize).enumerate() {
+            let expected = lut[luma(row) as usize];
+            assert!(
+                rgba.chunks_exact(4).all(|x| x == expected),
+                "row {row} of plane with stride {stride} was read from a wrong offset"
+            );
+        }
+    }
+}
+
+#[test]
+fn destination_beyond_32_bit_offsets() {
+    let lut = gray_lut();
+    let y_plane = (0..HEIGHT as usize)
+        .flat_map(|row| std::iter::repeat_n(luma(row), WIDTH as usize))
+        .collect::<Vec<_>>();
+    for stride in STRIDES {
+        let len = stride as usize * HEIGHT as usize;
+        let Some(mut rgba) = zeroed(len) else {
+            eprintln!("skipping stride {stride}, {len} bytes cannot be reserved");
+            continue;
+        };
+        yuv400_to_rgba(
+            &y_plane,
+            WIDTH,
+            &mut rgba,
+            stride,
+            WIDTH,
+            HEIGHT,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        for (row, rgba) in rgba.chunks(stride as usize).enumerate() {
+            let expected = lut[luma(row) as usize];
+            assert!(
+                rgba[..(WIDTH * 4) as usize]
+                    .chunks_exact(4)
+                    .all(|x| x == expected),
+                "row {row} with stride {stride} was written to a wrong offset"
+            );
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use yuvutils_rs::{
+    rgba_to_yuv_nv12_p16, rgba_to_yuv_nv21_p16, yuv_nv12_p10_to_rgba, yuv_nv12_to_rgba_p16,
+    yuv_nv21_p10_to_rgba, yuv_nv21_to_rgba_p16, YuvBytesPacking, YuvEndianness, YuvError, YuvRange,
+    YuvStandardMatrix,
+};
+
+const BIT_DEPTH: u32 = 10;
+const WIDTHS: [u32; 3] = [1, 32, 33];
+const HEIGHT: u32 = 5;
+/// Codes a decoded pixel may be off by, for 10-bit and 8-bit RGB, encoder keeps 8 fractional
+/// bits and 10-bit decoders keep 6
+const TOLERANCE_10: i32 = 8;
+const TOLERANCE_8: i32 = 1;
+const LAYOUTS: [(&str, YuvEndianness, YuvBytesPacking); 4] = [
+    (
+        "little endian lsb",
+        YuvEndianness::LittleEndian,
+        YuvBytesPacking::LeastSignificantBytes,
+    ),
+    (
+        "little endian msb",
+        YuvEndianness::LittleEndian,
+        YuvBytesPacking::MostSignificantBytes,
+    ),
+    (
+        "big endian lsb",
+        YuvEndianness::BigEndian,
+        YuvBytesPacking::LeastSignificantBytes,
+    ),
+    (
+        "big endian msb",
+        YuvEndianness::BigEndian,
+        YuvBytesPacking::MostSignificantBytes,
+    ),
+];
+
+type Encoder = fn(
+    &mut [u16],
+    u32,
+    &mut [u16],
+    u32,
+    &[u16],
+    u32,
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+    YuvEndianness,
+    YuvBytesPacking,
+) -> Result<(), YuvError>;
+
+type Decoder16 = fn(
+    &[u16],
+    u32,
+    &[u16],
+    u32,
+    &mut [u16],
+    u32,
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+    YuvEndianness,
+    YuvBytesPacking,
+) -> Result<(), YuvError>;
+
+type Decoder8 = fn(
+    &[u16],
+    u32,
+    &[u16],
+    u32,
+    &mut [u8],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+    YuvEndianness,
+    YuvBytesPacking,
+) -> Result<(), YuvError>;
+
+/// 10-bit RGBA made of 2x2 blocks of one colour, so 4:2:0 chroma loses nothing
+fn rgba16(width: u32) -> Vec<u16> {
+    let mut seed = 0x1b87_3593u32;
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        (96 + seed % 832) as u16
+    };
+    let blocks_x = width.div_ceil(2) as usize;
+    let blocks = (0..blocks_x * HEIGHT.div_ceil(2) as usize)
+        .map(|_| [next(), next(), next()])
+        .collect::<Vec<_>>();
+    let mut rgba = Vec::with_capacity((width * HEIGHT * 4) as usize);
+    for y in 0..HEIGHT as usize {
+        for x in 0..width as usize {
+            let [r, g, b] = blocks[y / 2 * blocks_x + x / 2];
+            rgba.extend_from_slice(&[r, g, b, 1023]);
+        }
+    }
+    rgba
+}
+
+/// Y and UV planes as encoded, strides are in bytes
+struct Encoded {
+    y: Vec<u16>,
+    uv: Vec<u16>,
+    width: u32,
+}
+
+impl Encoded {
+    fn uv_stride(&self) -> u32 {
+        self.width.div_ceil(2) * 2 * 2
+    }
+}
+
+fn encode(
+    encoder: Encoder,
+    rgba: &[u16],
+    width: u32,
+    endianness: YuvEndianness,
+    packing: YuvBytesPacking,
+) -> Encoded {
+    let mut encoded = Encoded {
+        y: vec![0; (width * HEIGHT) as usize],
+        uv: vec![0; (width.div_ceil(2) * 2 * HEIGHT.div_ceil(2)) as usize],
+        width,
+    };
+    let uv_stride = encoded.uv_stride();
+    encoder(
+        &mut encoded.y,
+        width * 2,
+        &mut encoded.uv,
+        uv_stride,
+        rgba,
+        width * 4 * 2,
+        BIT_DEPTH,
+        width,
+        HEIGHT,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt709,
+        endianness,
+        packing,
+    )
+    .unwrap();
+    encoded
+}
+
+/// Samples of `plane` as stored with `endianness` and `packing`, back to native 10-bit codes
+fn native(plane: &[u16], endianness: YuvEndianness, packing: YuvBytesPacking) -> Vec<u16> {
+    plane
+        .iter()
+        .map(|&x| {
+            let x = match endianness {
+                YuvEndianness::BigEndian => u16::from_be(x),
+                YuvEndianness::LittleEndian => u16::from_le(x),
+            };
+            match packing {
+                YuvBytesPacking::MostSignificantBytes => x >> (16 - BIT_DEPTH),
+                YuvBytesPacking::LeastSignificantBytes => x,
+            }
+        })
+        .collect()
+}
+
+/// Compares RGB of `decoded` against source scaled down by `shift` bits
+fn assert_close(decoded: &[i32], rgba: &[u16], shift: u32, tolerance: i32, what: &str) {
+    for (x, (d, s)) in decoded
+        .chunks_exact(4)
+        .zip(rgba.chunks_exact(4))
+        .enumerate()
+    {
+        for c in 0..3 {
+            let expected = (s[c] as i32 + (1 << shift >> 1)) >> shift;
+            assert!(
+                (d[c] - expected).abs() <= tolerance,
+                "{what}: pixel {x} decoded to {:?}, expected {:?}",
+                &d[..3],
+                &s[..3]
+            );
+        }
+    }
+}
+
+fn round_trip(encoder: Encoder, decoder16: Decoder16, decoder8: Decoder8, name: &str) {
+    for width in WIDTHS {
+        let rgba = rgba16(width);
+        let reference = encode(
+            encoder,
+            &rgba,
+            width,
+            YuvEndianness::LittleEndian,
+            YuvBytesPacking::LeastSignificantBytes,
+        );
+        for (layout, endianness, packing) in LAYOUTS {
+            let what = format!("{name} {layout} width {width}");
+            let encoded = encode(encoder, &rgba, width, endianness, packing);
+            assert_eq!(
+                native(&encoded.y, endianness, packing),
+                reference.y,
+                "{what}: Y plane"
+            );
+            assert_eq!(
+                native(&encoded.uv, endianness, packing),
+                reference.uv,
+                "{what}: UV plane"
+            );
+
+            let mut rgba16 = vec![0u16; (width * HEIGHT * 4) as usize];
+            decoder16(
+                &encoded.y,
+                width * 2,
+                &encoded.uv,
+                encoded.uv_stride(),
+                &mut rgba16,
+                width * 4 * 2,
+                BIT_DEPTH,
+                width,
+                HEIGHT,
+                YuvRange::TV,
+                YuvStandardMatrix::Bt709,
+                endianness,
+                packing,
+            )
+            .unwrap();
+            let decoded = rgba16.iter().map(|&x| x as i32).collect::<Vec<_>>();
+            assert_close(
+                &decoded,
+                &rgba,
+                0,
+                TOLERANCE_10,
+                &format!("{what} to rgba16"),
+            );
+
+            let mut rgba8 = vec![0u8; (width * HEIGHT * 4) as usize];
+            decoder8(
+                &encoded.y,
+                width * 2,
+                &encoded.uv,
+                encoded.uv_stride(),
+                &mut rgba8,
+                width * 4,
+                width,
+                HEIGHT,
+                YuvRange::TV,
+                YuvStandardMatrix::Bt709,
+                endianness,
+                packing,
+            )
+            .unwrap();
+            let decoded = rgba8.iter().map(|&x| x as i32).collect::<Vec<_>>();
+            assert_close(&decoded, &rgba, 2, TOLERANCE_8, &format!("{what} to rgba8"));
+        }
+    }
+}
+
+#[test]
+fn nv12_p10_round_trip() {
+    round_trip(
+        rgba_to_yuv_nv12_p16,
+        yuv_nv12_to_rgba_p16,
+        yuv_nv12_p10_to_rgba,
+        "nv12",
+    );
+}
+
+#[test]
+fn nv21_p10_round_trip() {
+    round_trip(
+        rgba_to_yuv_nv21_p16,
+        yuv_nv21_to_rgba_p16,
+        yuv_nv21_p10_to_rgba,
+        "nv21",
+    );
+}
+
+#[test]
+fn nv21_p10_swaps_chroma_of_nv12() {
+    let width = 33;
+    let rgba = rgba16(width);
+    for (layout, endianness, packing) in LAYOUTS {
+        let nv12 = encode(rgba_to_yuv_nv12_p16, &rgba, width, endianness, packing);
+        let nv21 = encode(rgba_to_yuv_nv21_p16, &rgba, width, endianness, packing);
+        assert_eq!(nv12.y, nv21.y, "{layout}");
+        for (uv, vu) in nv12.uv.chunks_exact(2).zip(nv21.uv.chunks_exact(2)) {
+            assert_eq!([uv[1], uv[0]], vu, "{layout}");
+        }
+    }
+}
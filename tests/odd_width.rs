@@ -0,0 +1,386 @@
+use std::sync::Mutex;
+use yuvutils_rs::{
+    rgb_to_yuv422, rgba16_to_p010, rgba16_to_yuv422_p16, rgba_to_sharp_yuv420, rgba_to_ycgco420,
+    rgba_to_ycgcoro422, rgba_to_yuv420, rgba_to_yuv420_p16, rgba_to_yuv_nv12, rgba_to_yuv_nv12_p16,
+    set_backend_limit, BufferStoreMut, ImageSize, RowStride, SharpYuvGammaTransfer,
+    YuvBiPlanarImageMut, YuvBytesPacking, YuvDispatchBackend, YuvEndianness, YuvRange,
+    YuvStandardMatrix,
+};
+
+/// Backend limit is process wide, tests of this file pin it one at a time
+static BACKEND: Mutex<()> = Mutex::new(());
+
+const BACKENDS: [YuvDispatchBackend; 3] = [
+    YuvDispatchBackend::Sse41,
+    YuvDispatchBackend::Avx2,
+    YuvDispatchBackend::Avx512Bw,
+];
+
+const HEIGHT: u32 = 2;
+/// Extra bytes past visible pixels of every source row
+const PADDING: usize = 32;
+
+/// Source rows of `width` pixels, visible samples are a gradient and `padding` fills the rest
+fn source(width: u32, channels: usize, padding: u8) -> (Vec<u8>, u32) {
+    let stride = width as usize * channels + PADDING;
+    let mut rgba = vec![padding; stride * HEIGHT as usize];
+    for (y, row) in rgba.chunks_exact_mut(stride).enumerate() {
+        for (x, v) in row[..width as usize * channels].iter_mut().enumerate() {
+            *v = (x * 37 + y * 91 + 11) as u8;
+        }
+    }
+    (rgba, stride as u32)
+}
+
+/// Source of `width + 1` pixels whose last column repeats column `width - 1`
+fn replicated(width: u32, channels: usize) -> (Vec<u8>, u32) {
+    let (mut rgba, stride) = source(width + 1, channels, 0);
+    for row in rgba.chunks_exact_mut(stride as usize) {
+        let last = (width as usize - 1) * channels;
+        row.copy_within(last..last + channels, last + channels);
+    }
+    (rgba, stride)
+}
+
+/// Converts rows of `width` pixels at the given stride, returns the last chroma sample of
+/// every chroma row
+trait LastChroma: Fn(&[u8], u32, u32) -> Vec<u16> {}
+
+impl<F: Fn(&[u8], u32, u32) -> Vec<u16>> LastChroma for F {}
+
+/// Runs `convert` with dispatch capped at `limit` over zeroed and filled row padding
+fn last_chroma(
+    limit: YuvDispatchBackend,
+    width: u32,
+    channels: usize,
+    convert: &impl LastChroma,
+) -> Vec<u16> {
+    set_backend_limit(Some(limit));
+    let (rgba, stride) = source(width, channels, 0);
+    let chroma = convert(&rgba, stride, width);
+    let (rgba, stride) = source(width, channels, 0xff);
+    let chroma_from_padded = convert(&rgba, stride, width);
+    set_backend_limit(None);
+    assert_eq!(
+        chroma, chroma_from_padded,
+        "{limit:?} read row padding at width {width}"
+    );
+    chroma
+}
+
+/// Checks every backend against scalar for widths 1..=65, and that the last chroma sample
+/// of odd widths is the one of the last pixel replicated past the edge
+fn check_backends(channels: usize, convert: impl LastChroma) {
+    let _guard = BACKEND.lock().unwrap_or_else(|e| e.into_inner());
+    for width in 1..=65 {
+        let scalar = last_chroma(YuvDispatchBackend::Scalar, width, channels, &convert);
+        for backend in BACKENDS {
+            let simd = last_chroma(backend, width, channels, &convert);
+            assert_eq!(scalar, simd, "{backend:?} differs at width {width}");
+        }
+        if width % 2 == 1 {
+            let (rgba, stride) = replicated(width, channels);
+            set_backend_limit(Some(YuvDispatchBackend::Scalar));
+            let expected = convert(&rgba, stride, width + 1);
+            set_backend_limit(None);
+            assert_eq!(
+                scalar, expected,
+                "last chroma sample at width {width} is not the replicated edge"
+            );
+        }
+    }
+}
+
+/// 10-bit source with the same gradient
+fn widen(rgba: &[u8]) -> Vec<u16> {
+    rgba.iter().map(|&x| (x as u16) << 2).collect()
+}
+
+#[test]
+fn rgba_to_yuv420_odd_widths() {
+    check_backends(4, |rgba, rgba_stride, width| {
+        let chroma_width = width.div_ceil(2);
+        let mut y = vec![0u8; (width * HEIGHT) as usize];
+        let mut u = vec![0u8; (chroma_width * HEIGHT / 2) as usize];
+        let mut v = vec![0u8; (chroma_width * HEIGHT / 2) as usize];
+        rgba_to_yuv420(
+            &mut y,
+            width,
+            &mut u,
+            chroma_width,
+            &mut v,
+            chroma_width,
+            rgba,
+            rgba_stride,
+            width,
+            HEIGHT,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        vec![
+            u[chroma_width as usize - 1] as u16,
+            v[chroma_width as usize - 1] as u16,
+        ]
+    });
+}
+
+#[test]
+fn rgb_to_yuv422_odd_widths() {
+    check_backends(3, |rgb, rgb_stride, width| {
+        let chroma_width = width.div_ceil(2) as usize;
+        let mut y = vec![0u8; (width * HEIGHT) as usize];
+        let mut u = vec![0u8; chroma_width * HEIGHT as usize];
+        let mut v = vec![0u8; chroma_width * HEIGHT as usize];
+        rgb_to_yuv422(
+            &mut y,
+            width,
+            &mut u,
+            chroma_width as u32,
+            &mut v,
+            chroma_width as u32,
+            rgb,
+            rgb_stride,
+            width,
+            HEIGHT,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt601,
+        )
+        .unwrap();
+        (0..HEIGHT as usize)
+            .flat_map(|row| {
+                let last = row * chroma_width + chroma_width - 1;
+                [u[last] as u16, v[last] as u16]
+            })
+            .collect()
+    });
+}
+
+#[test]
+fn rgba_to_nv12_odd_widths() {
+    check_backends(4, |rgba, rgba_stride, width| {
+        let uv_stride = width.div_ceil(2) * 2;
+        let mut y = vec![0u8; (width * HEIGHT) as usize];
+        let mut uv = vec![0u8; (uv_stride * HEIGHT / 2) as usize];
+        rgba_to_yuv_nv12(
+            &mut y,
+            width,
+            &mut uv,
+            uv_stride,
+            rgba,
+            rgba_stride,
+            width,
+            HEIGHT,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        uv[uv.len() - 2..].iter().map(|&x| x as u16).collect()
+    });
+}
+
+#[test]
+fn rgba_to_yuv420_p16_odd_widths() {
+    check_backends(4, |rgba, rgba_stride, width| {
+        let rgba = widen(rgba);
+        let chroma_width = width.div_ceil(2);
+        let mut y = vec![0u16; (width * HEIGHT) as usize];
+        let mut u = vec![0u16; (chroma_width * HEIGHT / 2) as usize];
+        let mut v = vec![0u16; (chroma_width * HEIGHT / 2) as usize];
+        rgba_to_yuv420_p16(
+            &mut y,
+            width * 2,
+            &mut u,
+            chroma_width * 2,
+            &mut v,
+            chroma_width * 2,
+            &rgba,
+            rgba_stride * 2,
+            10,
+            width,
+            HEIGHT,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt2020,
+            YuvEndianness::LittleEndian,
+            YuvBytesPacking::LeastSignificantBytes,
+        )
+        .unwrap();
+        vec![u[chroma_width as usize - 1], v[chroma_width as usize - 1]]
+    });
+}
+
+#[test]
+fn rgba16_to_yuv422_p16_odd_widths() {
+    check_backends(4, |rgba, rgba_stride, width| {
+        let rgba = widen(rgba);
+        let chroma_width = width.div_ceil(2) as usize;
+        let mut y = vec![0u16; (width * HEIGHT) as usize];
+        let mut u = vec![0u16; chroma_width * HEIGHT as usize];
+        let mut v = vec![0u16; chroma_width * HEIGHT as usize];
+        rgba16_to_yuv422_p16(
+            &mut y,
+            width * 2,
+            &mut u,
+            chroma_width as u32 * 2,
+            &mut v,
+            chroma_width as u32 * 2,
+            &rgba,
+            rgba_stride * 2,
+            10,
+            width,
+            HEIGHT,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+            YuvEndianness::BigEndian,
+            YuvBytesPacking::LeastSignificantBytes,
+        )
+        .unwrap();
+        (0..HEIGHT as usize)
+            .flat_map(|row| {
+                let last = row * chroma_width + chroma_width - 1;
+                [u[last], v[last]]
+            })
+            .collect()
+    });
+}
+
+#[test]
+fn rgba_to_nv12_p16_odd_widths() {
+    check_backends(4, |rgba, rgba_stride, width| {
+        let rgba = widen(rgba);
+        let uv_stride = width.div_ceil(2) * 2;
+        let mut y = vec![0u16; (width * HEIGHT) as usize];
+        let mut uv = vec![0u16; (uv_stride * HEIGHT / 2) as usize];
+        rgba_to_yuv_nv12_p16(
+            &mut y,
+            width * 2,
+            &mut uv,
+            uv_stride * 2,
+            &rgba,
+            rgba_stride * 2,
+            12,
+            width,
+            HEIGHT,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt601,
+            YuvEndianness::LittleEndian,
+            YuvBytesPacking::MostSignificantBytes,
+        )
+        .unwrap();
+        uv[uv.len() - 2..].to_vec()
+    });
+}
+
+#[test]
+fn rgba16_to_p010_odd_widths() {
+    check_backends(4, |rgba, rgba_stride, width| {
+        let rgba = widen(rgba);
+        let uv_stride = width.div_ceil(2) * 2;
+        let mut image = YuvBiPlanarImageMut {
+            y_plane: BufferStoreMut::Owned(vec![0u16; (width * HEIGHT) as usize]),
+            y_stride: RowStride::new(width * 2),
+            uv_plane: BufferStoreMut::Owned(vec![0u16; (uv_stride * HEIGHT / 2) as usize]),
+            uv_stride: RowStride::new(uv_stride * 2),
+            size: ImageSize::new(width, HEIGHT),
+        };
+        rgba16_to_p010(
+            &mut image,
+            &rgba,
+            rgba_stride * 2,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt2020,
+            YuvBytesPacking::MostSignificantBytes,
+        )
+        .unwrap();
+        let uv = image.uv_plane.borrow();
+        uv[uv.len() - 2..].to_vec()
+    });
+}
+
+#[test]
+fn rgba_to_ycgco420_odd_widths() {
+    check_backends(4, |rgba, rgba_stride, width| {
+        let chroma_width = width.div_ceil(2);
+        let mut y = vec![0u8; (width * HEIGHT) as usize];
+        let mut cg = vec![0u8; (chroma_width * HEIGHT / 2) as usize];
+        let mut co = vec![0u8; (chroma_width * HEIGHT / 2) as usize];
+        rgba_to_ycgco420(
+            &mut y,
+            width,
+            &mut cg,
+            chroma_width,
+            &mut co,
+            chroma_width,
+            rgba,
+            rgba_stride,
+            width,
+            HEIGHT,
+            YuvRange::TV,
+        )
+        .unwrap();
+        vec![
+            cg[chroma_width as usize - 1] as u16,
+            co[chroma_width as usize - 1] as u16,
+        ]
+    });
+}
+
+#[test]
+fn rgba_to_ycgcoro422_odd_widths() {
+    check_backends(4, |rgba, rgba_stride, width| {
+        let chroma_width = width.div_ceil(2) as usize;
+        let mut y = vec![0u16; (width * HEIGHT) as usize];
+        let mut cg = vec![0u16; chroma_width * HEIGHT as usize];
+        let mut co = vec![0u16; chroma_width * HEIGHT as usize];
+        rgba_to_ycgcoro422(
+            &mut y,
+            width * 2,
+            &mut cg,
+            chroma_width as u32 * 2,
+            &mut co,
+            chroma_width as u32 * 2,
+            rgba,
+            rgba_stride,
+            width,
+            HEIGHT,
+            YuvRange::Full,
+        )
+        .unwrap();
+        (0..HEIGHT as usize)
+            .flat_map(|row| {
+                let last = row * chroma_width + chroma_width - 1;
+                [cg[last], co[last]]
+            })
+            .collect()
+    });
+}
+
+#[test]
+fn rgba_to_sharp_yuv420_odd_widths() {
+    check_backends(4, |rgba, rgba_stride, width| {
+        let chroma_width = width.div_ceil(2);
+        let mut y = vec![0u8; (width * HEIGHT) as usize];
+        let mut u = vec![0u8; (chroma_width * HEIGHT / 2) as usize];
+        let mut v = vec![0u8; (chroma_width * HEIGHT / 2) as usize];
+        rgba_to_sharp_yuv420(
+            &mut y,
+            width,
+            &mut u,
+            chroma_width,
+            &mut v,
+            chroma_width,
+            rgba,
+            rgba_stride,
+            width,
+            HEIGHT,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+            SharpYuvGammaTransfer::Srgb,
+        )
+        .unwrap();
+        vec![
+            u[chroma_width as usize - 1] as u16,
+            v[chroma_width as usize - 1] as u16,
+        ]
+    });
+}
@@ -0,0 +1,413 @@
+use yuvutils_rs::{
+    yuv422_p10_to_rgba, yuv422_p16_to_rgba, yuv422_p16_with_alpha_to_rgba,
+    yuv422_p16_with_alpha_to_rgba16, yuv444_p10_to_rgba, yuv444_p16_to_rgba,
+    yuv444_p16_with_alpha_to_rgba, yuv444_p16_with_alpha_to_rgba16, YuvBytesPacking,
+    YuvChromaSample, YuvEndianness, YuvError, YuvRange, YuvStandardMatrix,
+};
+
+const HEIGHT: u32 = 3;
+const KR: f32 = 0.2126;
+const KB: f32 = 0.0722;
+
+/// Planes of a test image in native order, alpha plane has color bit depth
+struct Planes {
+    y: Vec<u16>,
+    u: Vec<u16>,
+    v: Vec<u16>,
+    a: Vec<u16>,
+    width: u32,
+    chroma_width: u32,
+    sampling: YuvChromaSample,
+    bit_depth: u32,
+    range: YuvRange,
+}
+
+impl Planes {
+    fn new(width: u32, sampling: YuvChromaSample, bit_depth: u32, range: YuvRange) -> Planes {
+        let chroma_width = match sampling {
+            YuvChromaSample::YUV444 => width,
+            _ => width.div_ceil(2),
+        };
+        let max = (1u32 << bit_depth) - 1;
+        let (min_y, max_y, min_uv, max_uv) = match range {
+            YuvRange::TV => (
+                16 << (bit_depth - 8),
+                235 << (bit_depth - 8),
+                16 << (bit_depth - 8),
+                240 << (bit_depth - 8),
+            ),
+            _ => (0, max, 0, max),
+        };
+        let mut seed = 0x2545_f491u32;
+        let mut next = |min: u32, max: u32| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            (min + seed % (max - min + 1)) as u16
+        };
+        let luma = (width * HEIGHT) as usize;
+        let chroma = (chroma_width * HEIGHT) as usize;
+        Planes {
+            y: (0..luma).map(|_| next(min_y, max_y)).collect(),
+            u: (0..chroma).map(|_| next(min_uv, max_uv)).collect(),
+            v: (0..chroma).map(|_| next(min_uv, max_uv)).collect(),
+            a: (0..luma).map(|_| next(0, max)).collect(),
+            width,
+            chroma_width,
+            sampling,
+            bit_depth,
+            range,
+        }
+    }
+
+    /// Planes as stored with `endianness`, samples are least significant bits
+    fn stored(&self, endianness: YuvEndianness) -> [Vec<u16>; 4] {
+        let store = |plane: &Vec<u16>| {
+            plane
+                .iter()
+                .map(|&x| match endianness {
+                    YuvEndianness::BigEndian => x.to_be(),
+                    YuvEndianness::LittleEndian => x.to_le(),
+                })
+                .collect()
+        };
+        [
+            store(&self.y),
+            store(&self.u),
+            store(&self.v),
+            store(&self.a),
+        ]
+    }
+
+    /// Float RGBA of a pixel, every channel scaled to `0..=max`
+    fn reference(&self, x: usize, row: usize, max: f32) -> [f32; 4] {
+        let depth_max = ((1u32 << self.bit_depth) - 1) as f32;
+        let scale = (1u32 << (self.bit_depth - 8)) as f32;
+        let (bias_y, range_y, range_uv) = match self.range {
+            YuvRange::TV => (16. * scale, 219. * scale, 224. * scale),
+            _ => (0., depth_max, depth_max),
+        };
+        let bias_uv = (1u32 << (self.bit_depth - 1)) as f32;
+        let cx = match self.sampling {
+            YuvChromaSample::YUV444 => x,
+            _ => x / 2,
+        };
+        let chroma = row * self.chroma_width as usize + cx;
+        let luma = row * self.width as usize + x;
+        let y = (self.y[luma] as f32 - bias_y) / range_y;
+        let cb = (self.u[chroma] as f32 - bias_uv) / range_uv;
+        let cr = (self.v[chroma] as f32 - bias_uv) / range_uv;
+        let kg = 1. - KR - KB;
+        let r = y + 2. * (1. - KR) * cr;
+        let b = y + 2. * (1. - KB) * cb;
+        let g = y - 2. * KB * (1. - KB) / kg * cb - 2. * KR * (1. - KR) / kg * cr;
+        let a = self.a[luma] as f32 / depth_max;
+        [r, g, b, a].map(|c| (c * max).clamp(0., max))
+    }
+
+    /// Asserts every pixel of `rgba` is within `tolerance` levels of the reference
+    fn check(&self, rgba: &[u32], max: u32, alpha: bool, tolerance: f32, name: &str) {
+        for row in 0..HEIGHT as usize {
+            for x in 0..self.width as usize {
+                let expected = self.reference(x, row, max as f32);
+                let start = (row * self.width as usize + x) * 4;
+                let pixel = &rgba[start..start + 4];
+                let channels = if alpha { 4 } else { 3 };
+                for c in 0..channels {
+                    let diff = (pixel[c] as f32 - expected[c]).abs();
+                    assert!(
+                        diff <= tolerance,
+                        "{name} {:?} {} bit {:?} width {}: pixel ({x}, {row}) is {pixel:?}, expected {expected:?}",
+                        self.sampling,
+                        self.bit_depth,
+                        self.range,
+                        self.width
+                    );
+                }
+                if !alpha {
+                    assert_eq!(pixel[3], max, "{name} did not write opaque alpha");
+                }
+            }
+        }
+    }
+}
+
+type Decoder8 = fn(&Planes, &[Vec<u16>; 4], &mut [u8], YuvEndianness) -> Result<(), YuvError>;
+type Decoder16 = fn(&Planes, &[Vec<u16>; 4], &mut [u16], YuvEndianness) -> Result<(), YuvError>;
+
+const WIDTHS: [u32; 6] = [1, 2, 7, 8, 17, 32];
+const ENDIANNESS: [YuvEndianness; 2] = [YuvEndianness::LittleEndian, YuvEndianness::BigEndian];
+const RANGES: [YuvRange; 2] = [YuvRange::TV, YuvRange::Full];
+
+fn check_u8(name: &str, sampling: YuvChromaSample, depths: &[u32], alpha: bool, decode: Decoder8) {
+    for &bit_depth in depths {
+        for range in RANGES {
+            for width in WIDTHS {
+                let planes = Planes::new(width, sampling, bit_depth, range);
+                for endianness in ENDIANNESS {
+                    let stored = planes.stored(endianness);
+                    let mut rgba = vec![0u8; (width * HEIGHT * 4) as usize];
+                    decode(&planes, &stored, &mut rgba, endianness).unwrap();
+                    let rgba = rgba.iter().map(|&x| x as u32).collect::<Vec<_>>();
+                    planes.check(&rgba, 255, alpha, 2.5, name);
+                }
+            }
+        }
+    }
+}
+
+fn check_u16(name: &str, sampling: YuvChromaSample, decode: Decoder16) {
+    for bit_depth in [10, 12, 16] {
+        for range in RANGES {
+            for width in WIDTHS {
+                let planes = Planes::new(width, sampling, bit_depth, range);
+                for endianness in ENDIANNESS {
+                    let stored = planes.stored(endianness);
+                    let mut rgba = vec![0u16; (width * HEIGHT * 4) as usize];
+                    decode(&planes, &stored, &mut rgba, endianness).unwrap();
+                    let rgba = rgba.iter().map(|&x| x as u32).collect::<Vec<_>>();
+                    let max = (1u32 << bit_depth) - 1;
+                    // Kernels up to 12 bits keep 6 bits of coefficient precision
+                    let tolerance = if bit_depth > 12 {
+                        3.
+                    } else {
+                        max as f32 * 0.006 + 1.
+                    };
+                    planes.check(&rgba, max, true, tolerance, name);
+                }
+            }
+        }
+    }
+}
+
+fn strides(planes: &Planes) -> (u32, u32) {
+    (planes.width * 2, planes.chroma_width * 2)
+}
+
+#[test]
+fn p10_to_rgba() {
+    check_u8(
+        "yuv422_p10_to_rgba",
+        YuvChromaSample::YUV422,
+        &[10],
+        false,
+        |p, s, rgba, e| {
+            let (y_stride, uv_stride) = strides(p);
+            yuv422_p10_to_rgba(
+                &s[0],
+                y_stride,
+                &s[1],
+                uv_stride,
+                &s[2],
+                uv_stride,
+                rgba,
+                p.width * 4,
+                p.width,
+                HEIGHT,
+                p.range,
+                YuvStandardMatrix::Bt709,
+                e,
+                YuvBytesPacking::LeastSignificantBytes,
+            )
+        },
+    );
+    check_u8(
+        "yuv444_p10_to_rgba",
+        YuvChromaSample::YUV444,
+        &[10],
+        false,
+        |p, s, rgba, e| {
+            let (y_stride, uv_stride) = strides(p);
+            yuv444_p10_to_rgba(
+                &s[0],
+                y_stride,
+                &s[1],
+                uv_stride,
+                &s[2],
+                uv_stride,
+                rgba,
+                p.width * 4,
+                p.width,
+                HEIGHT,
+                p.range,
+                YuvStandardMatrix::Bt709,
+                e,
+                YuvBytesPacking::LeastSignificantBytes,
+            )
+        },
+    );
+}
+
+#[test]
+fn p16_to_rgba() {
+    let depths = [10, 12, 16];
+    check_u8(
+        "yuv422_p16_to_rgba",
+        YuvChromaSample::YUV422,
+        &depths,
+        false,
+        |p, s, rgba, e| {
+            let (y_stride, uv_stride) = strides(p);
+            yuv422_p16_to_rgba(
+                &s[0],
+                y_stride,
+                &s[1],
+                uv_stride,
+                &s[2],
+                uv_stride,
+                rgba,
+                p.width * 4,
+                p.bit_depth as usize,
+                p.width,
+                HEIGHT,
+                p.range,
+                YuvStandardMatrix::Bt709,
+                e,
+                YuvBytesPacking::LeastSignificantBytes,
+            )
+        },
+    );
+    check_u8(
+        "yuv444_p16_to_rgba",
+        YuvChromaSample::YUV444,
+        &depths,
+        false,
+        |p, s, rgba, e| {
+            let (y_stride, uv_stride) = strides(p);
+            yuv444_p16_to_rgba(
+                &s[0],
+                y_stride,
+                &s[1],
+                uv_stride,
+                &s[2],
+                uv_stride,
+                rgba,
+                p.width * 4,
+                p.bit_depth as usize,
+                p.width,
+                HEIGHT,
+                p.range,
+                YuvStandardMatrix::Bt709,
+                e,
+                YuvBytesPacking::LeastSignificantBytes,
+            )
+        },
+    );
+}
+
+#[test]
+fn p16_with_alpha_to_rgba() {
+    let depths = [10, 12, 16];
+    check_u8(
+        "yuv422_p16_with_alpha_to_rgba",
+        YuvChromaSample::YUV422,
+        &depths,
+        true,
+        |p, s, rgba, e| {
+            let (y_stride, uv_stride) = strides(p);
+            yuv422_p16_with_alpha_to_rgba(
+                &s[0],
+                y_stride,
+                &s[1],
+                uv_stride,
+                &s[2],
+                uv_stride,
+                &s[3],
+                y_stride,
+                rgba,
+                p.width * 4,
+                p.bit_depth as usize,
+                p.width,
+                HEIGHT,
+                p.range,
+                YuvStandardMatrix::Bt709,
+                e,
+                YuvBytesPacking::LeastSignificantBytes,
+            )
+        },
+    );
+    check_u8(
+        "yuv444_p16_with_alpha_to_rgba",
+        YuvChromaSample::YUV444,
+        &depths,
+        true,
+        |p, s, rgba, e| {
+            let (y_stride, uv_stride) = strides(p);
+            yuv444_p16_with_alpha_to_rgba(
+                &s[0],
+                y_stride,
+                &s[1],
+                uv_stride,
+                &s[2],
+                uv_stride,
+                &s[3],
+                y_stride,
+                rgba,
+                p.width * 4,
+                p.bit_depth as usize,
+                p.width,
+                HEIGHT,
+                p.range,
+                YuvStandardMatrix::Bt709,
+                e,
+                YuvBytesPacking::LeastSignificantBytes,
+            )
+        },
+    );
+}
+
+#[test]
+fn p16_with_alpha_to_rgba16() {
+    check_u16(
+        "yuv422_p16_with_alpha_to_rgba16",
+        YuvChromaSample::YUV422,
+        |p, s, rgba, e| {
+            let (y_stride, uv_stride) = strides(p);
+            yuv422_p16_with_alpha_to_rgba16(
+                &s[0],
+                y_stride,
+                &s[1],
+                uv_stride,
+                &s[2],
+                uv_stride,
+                &s[3],
+                y_stride,
+                rgba,
+                p.width * 8,
+                p.bit_depth as usize,
+                p.width,
+                HEIGHT,
+                p.range,
+                YuvStandardMatrix::Bt709,
+                e,
+                YuvBytesPacking::LeastSignificantBytes,
+            )
+        },
+    );
+    check_u16(
+        "yuv444_p16_with_alpha_to_rgba16",
+        YuvChromaSample::YUV444,
+        |p, s, rgba, e| {
+            let (y_stride, uv_stride) = strides(p);
+            yuv444_p16_with_alpha_to_rgba16(
+                &s[0],
+                y_stride,
+                &s[1],
+                uv_stride,
+                &s[2],
+                uv_stride,
+                &s[3],
+                y_stride,
+                rgba,
+                p.width * 8,
+                p.bit_depth as usize,
+                p.width,
+                HEIGHT,
+                p.range,
+                YuvStandardMatrix::Bt709,
+                e,
+                YuvBytesPacking::LeastSignificantBytes,
+            )
+        },
+    );
+}
@@ -0,0 +1,433 @@
+use yuvutils_rs::{
+    yuv400_p16_to_rgb16, yuv420_p16_to_rgb16, yuv422_p16_to_rgb16, yuv444_p16_to_rgb16,
+    YuvBytesPacking, YuvChromaSample, YuvEndianness, YuvError, YuvRange, YuvStandardMatrix,
+};
+
+/// Bound of 16-bit decoding documented in README, in codes of `0..=65535`
+const TOLERANCE: f64 = 5.;
+
+const MATRICES: [YuvStandardMatrix; 5] = [
+    YuvStandardMatrix::Bt601,
+    YuvStandardMatrix::Bt709,
+    YuvStandardMatrix::Bt2020,
+    YuvStandardMatrix::Smpte240,
+    YuvStandardMatrix::Bt470_6,
+];
+
+/// Exact RGB of a 16-bit sample clamped to `0..=65535`
+fn reference(y: u16, u: u16, v: u16, range: YuvRange, matrix: YuvStandardMatrix) -> [f64; 3] {
+    let bias = matrix.get_kr_kb();
+    let (kr, kb) = (bias.kr as f64, bias.kb as f64);
+    let kg = 1. - kr - kb;
+    let (bias_y, range_y, range_uv) = match range {
+        YuvRange::Full => (0., 65535., 65535.),
+        _ => (16. * 256., 219. * 256., 224. * 256.),
+    };
+    let y = (y as f64 - bias_y) / range_y;
+    let cb = (u as f64 - 32768.) / range_uv;
+    let cr = (v as f64 - 32768.) / range_uv;
+    let r = y + 2. * (1. - kr) * cr;
+    let b = y + 2. * (1. - kb) * cb;
+    let g = y - 2. * kb * (1. - kb) / kg * cb - 2. * kr * (1. - kr) / kg * cr;
+    [r, g, b].map(|c| (c * 65535.).clamp(0., 65535.))
+}
+
+/// Decodes 4:4:4 planes of one row, samples are given in native order
+fn decode444(
+    y: &[u16],
+    u: &[u16],
+    v: &[u16],
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    endianness: YuvEndianness,
+) -> Vec<u16> {
+    let store = |plane: &[u16]| {
+        plane
+            .iter()
+            .map(|&x| match endianness {
+                YuvEndianness::BigEndian => x.to_be(),
+                YuvEndianness::LittleEndian => x.to_le(),
+            })
+            .collect::<Vec<_>>()
+    };
+    let width = y.len() as u32;
+    let mut rgb = vec![0u16; y.len() * 3];
+    yuv444_p16_to_rgb16(
+        &store(y),
+        width * 2,
+        &store(u),
+        width * 2,
+        &store(v),
+        width * 2,
+        &mut rgb,
+        width * 6,
+        16,
+        width,
+        1,
+        range,
+        matrix,
+        endianness,
+        YuvBytesPacking::LeastSignificantBytes,
+    )
+    .unwrap();
+    rgb
+}
+
+fn assert_accurate(
+    y: &[u16],
+    u: &[u16],
+    v: &[u16],
+    rgb: &[u16],
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) {
+    for (x, pixel) in rgb.chunks_exact(3).enumerate() {
+        let expected = reference(y[x], u[x], v[x], range, matrix);
+        for (c, &value) in pixel.iter().enumerate() {
+            assert!(
+                (value as f64 - expected[c]).abs() <= TOLERANCE,
+                "{matrix:?} {range:?} yuv ({}, {}, {}) decoded to {pixel:?}, expected {expected:?}",
+                y[x],
+                u[x],
+                v[x]
+            );
+        }
+    }
+}
+
+#[test]
+fn extreme_codes_do_not_overflow() {
+    let codes = [0u16, 1, 4096, 32767, 32768, 60160, 61440, 65534, 65535];
+    let mut y = Vec::new();
+    let mut u = Vec::new();
+    let mut v = Vec::new();
+    for &cy in &codes {
+        for &cu in &codes {
+            for &cv in &codes {
+                y.push(cy);
+                u.push(cu);
+                v.push(cv);
+            }
+        }
+    }
+    for range in [YuvRange::Full, YuvRange::TV] {
+        for matrix in MATRICES {
+            for endianness in [YuvEndianness::LittleEndian, YuvEndianness::BigEndian] {
+                let rgb = decode444(&y, &u, &v, range, matrix, endianness);
+                assert_accurate(&y, &u, &v, &rgb, range, matrix);
+            }
+        }
+    }
+}
+
+#[test]
+fn full_range_sweep_is_accurate() {
+    let mut seed = 0x9e37_79b9u32;
+    let mut next = || {
+        seed ^= seed << 13;
+        seed ^= seed >> 17;
+        seed ^= seed << 5;
+        seed as u16
+    };
+    let y = (0..4099).map(|_| next()).collect::<Vec<_>>();
+    let u = (0..4099).map(|_| next()).collect::<Vec<_>>();
+    let v = (0..4099).map(|_| next()).collect::<Vec<_>>();
+    for matrix in MATRICES {
+        let rgb = decode444(
+            &y,
+            &u,
+            &v,
+            YuvRange::Full,
+            matrix,
+            YuvEndianness::LittleEndian,
+        );
+        assert_accurate(&y, &u, &v, &rgb, YuvRange::Full, matrix);
+    }
+}
+
+#[test]
+fn subsampled_full_range_is_accurate() {
+    const WIDTH: usize = 33;
+    let y = (0..WIDTH * 2)
+        .map(|i| (i * 2011 % 65536) as u16)
+        .collect::<Vec<_>>();
+    let chroma_width = WIDTH.div_ceil(2);
+    let u = (0..chroma_width * 2)
+        .map(|i| (65535 - i * 3989 % 65536) as u16)
+        .collect::<Vec<_>>();
+    let v = (0..chroma_width * 2)
+        .map(|i| (i * 7919 % 65536) as u16)
+        .collect::<Vec<_>>();
+    let matrix = YuvStandardMatrix::Bt2020;
+    for sampling in [YuvChromaSample::YUV420, YuvChromaSample::YUV422] {
+        let chroma_rows = match sampling {
+            YuvChromaSample::YUV420 => 1,
+            _ => 2,
+        };
+        let mut rgb = vec![0u16; WIDTH * 2 * 3];
+        let decode = match sampling {
+            YuvChromaSample::YUV420 => yuv420_p16_to_rgb16,
+            _ => yuv422_p16_to_rgb16,
+        };
+        decode(
+            &y,
+            WIDTH as u32 * 2,
+            &u[..chroma_width * chroma_rows],
+            chroma_width as u32 * 2,
+            &v[..chroma_width * chroma_rows],
+            chroma_width as u32 * 2,
+            &mut rgb,
+            WIDTH as u32 * 6,
+            16,
+            WIDTH as u32,
+            2,
+            YuvRange::Full,
+            matrix,
+            YuvEndianness::LittleEndian,
+            YuvBytesPacking::LeastSignificantBytes,
+        )
+        .unwrap();
+        for row in 0..2 {
+            for x in 0..WIDTH {
+                let chroma_row = if chroma_rows == 1 { 0 } else { row };
+                let chroma = chroma_row * chroma_width + x / 2;
+                let expected = reference(
+                    y[row * WIDTH + x],
+                    u[chroma],
+                    v[chroma],
+                    YuvRange::Full,
+                    matrix,
+                );
+                let pixel = &rgb[(row * WIDTH + x) * 3..][..3];
+                for c in 0..3 {
+                    assert!(
+                        (pixel[c] as f64 - expected[c]).abs() <= TOLERANCE,
+                        "{sampling:?} pixel ({x}, {row}) is {pixel:?}, expected {expected:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn full_range_gray_is_copied_exactly() {
+    let y = [0u16, 1, 255, 256, 32767, 32768, 65279, 65534, 65535];
+    let mut rgb = vec![0u16; y.len() * 3];
+    yuv400_p16_to_rgb16(
+        &y,
+        y.len() as u32 * 2,
+        &mut rgb,
+        y.len() as u32 * 6,
+        16,
+        y.len() as u32,
+        1,
+        YuvRange::Full,
+        YuvStandardMatrix::Bt709,
+        YuvEndianness::LittleEndian,
+        YuvBytesPacking::LeastSignificantBytes,
+    )
+    .unwrap();
+    for (pixel, &y) in rgb.chunks_exact(3).zip(&y) {
+        assert_eq!(pixel, [y, y, y]);
+    }
+}
+
+#[test]
+fn unsupported_bit_depths_are_rejected() {
+    let y = [0u16; 4];
+    let mut rgb = [0u16; 12];
+    for bit_depth in [7, 17] {
+        let result = yuv444_p16_to_rgb16(
+            &y,
+            8,
+            &y,
+            8,
+            &y,
+            8,
+            &mut rgb,
+            24,
+            bit_depth,
+            4,
+            1,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+            YuvEndianness::LittleEndian,
+            YuvBytesPacking::LeastSignificantBytes,
+        );
+        assert!(matches!(result, Err(YuvError::UnsupportedBitDepth(_))));
+    }
+}
+
+#[test]
+fn yuv444_uses_chroma_of_every_pixel() {
+    const WIDTH: usize = 7;
+    let y = (0..WIDTH)
+        .map(|i| (100 + i * 97) as u16)
+        .collect::<Vec<_>>();
+    let u = (0..WIDTH)
+        .map(|i| (64 + i * 131) as u16)
+        .collect::<Vec<_>>();
+    let v = (0..WIDTH)
+        .map(|i| (960 - i * 113) as u16)
+        .collect::<Vec<_>>();
+    let decode = |y: &[u16], u: &[u16], v: &[u16]| {
+        let width = y.len() as u32;
+        let mut rgb = vec![0u16; y.len() * 3];
+        yuv444_p16_to_rgb16(
+            y,
+            width * 2,
+            u,
+            width * 2,
+            v,
+            width * 2,
+            &mut rgb,
+            width * 6,
+            10,
+            width,
+            1,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+            YuvEndianness::LittleEndian,
+            YuvBytesPacking::LeastSignificantBytes,
+        )
+        .unwrap();
+        rgb
+    };
+    let row = decode(&y, &u, &v);
+    for x in 0..WIDTH {
+        let alone = decode(&y[x..x + 1], &u[x..x + 1], &v[x..x + 1]);
+        assert_eq!(row[x * 3..][..3], alone, "pixel {x}");
+    }
+}
+
+#[test]
+fn even_width_subsampled_rows_keep_last_pixel() {
+    const WIDTH: usize = 6;
+    const CHROMA_WIDTH: usize = WIDTH / 2;
+    const UNTOUCHED: u16 = 0xdead;
+    let y = (0..WIDTH * 2)
+        .map(|i| (100 + i * 71) as u16)
+        .collect::<Vec<_>>();
+    let u = (0..CHROMA_WIDTH * 2)
+        .map(|i| (64 + i * 149) as u16)
+        .collect::<Vec<_>>();
+    let v = (0..CHROMA_WIDTH * 2)
+        .map(|i| (960 - i * 127) as u16)
+        .collect::<Vec<_>>();
+    let decode444 = |y: u16, u: u16, v: u16| {
+        let mut rgb = [0u16; 3];
+        yuv444_p16_to_rgb16(
+            &[y],
+            2,
+            &[u],
+            2,
+            &[v],
+            2,
+            &mut rgb,
+            6,
+            10,
+            1,
+            1,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+            YuvEndianness::LittleEndian,
+            YuvBytesPacking::LeastSignificantBytes,
+        )
+        .unwrap();
+        rgb
+    };
+    for sampling in [YuvChromaSample::YUV420, YuvChromaSample::YUV422] {
+        let chroma_rows = match sampling {
+            YuvChromaSample::YUV420 => 1,
+            _ => 2,
+        };
+        let decode = match sampling {
+            YuvChromaSample::YUV420 => yuv420_p16_to_rgb16,
+            _ => yuv422_p16_to_rgb16,
+        };
+        let mut rgb = vec![UNTOUCHED; WIDTH * 2 * 3];
+        decode(
+            &y,
+            WIDTH as u32 * 2,
+            &u[..CHROMA_WIDTH * chroma_rows],
+            CHROMA_WIDTH as u32 * 2,
+            &v[..CHROMA_WIDTH * chroma_rows],
+            CHROMA_WIDTH as u32 * 2,
+            &mut rgb,
+            WIDTH as u32 * 6,
+            10,
+            WIDTH as u32,
+            2,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+            YuvEndianness::LittleEndian,
+            YuvBytesPacking::LeastSignificantBytes,
+        )
+        .unwrap();
+        for row in 0..2 {
+            for x in 0..WIDTH {
+                let chroma_row = if chroma_rows == 1 { 0 } else { row };
+                let chroma = chroma_row * CHROMA_WIDTH + x / 2;
+                let expected = decode444(y[row * WIDTH + x], u[chroma], v[chroma]);
+                assert_eq!(
+                    rgb[(row * WIDTH + x) * 3..][..3],
+                    expected,
+                    "{sampling:?} pixel ({x}, {row})"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn gray_honours_byte_order() {
+    let y = [64u16, 65, 200, 511, 512, 777, 940, 1023];
+    let decode = |endianness: YuvEndianness, packing: YuvBytesPacking| {
+        let stored = y
+            .iter()
+            .map(|&x| {
+                let x = match packing {
+                    YuvBytesPacking::MostSignificantBytes => x << 6,
+                    YuvBytesPacking::LeastSignificantBytes => x,
+                };
+                match endianness {
+                    YuvEndianness::BigEndian => x.to_be(),
+                    YuvEndianness::LittleEndian => x.to_le(),
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut rgb = vec![0u16; y.len() * 3];
+        yuv400_p16_to_rgb16(
+            &stored,
+            y.len() as u32 * 2,
+            &mut rgb,
+            y.len() as u32 * 6,
+            10,
+            y.len() as u32,
+            1,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+            endianness,
+            packing,
+        )
+        .unwrap();
+        rgb
+    };
+    let reference = decode(
+        YuvEndianness::LittleEndian,
+        YuvBytesPacking::LeastSignificantBytes,
+    );
+    assert!(reference.iter().any(|&c| c != 0));
+    for (order, endianness) in [
+        ("big endian", YuvEndianness::BigEndian),
+        ("little endian", YuvEndianness::LittleEndian),
+    ] {
+        for (position, packing) in [
+            ("msb", YuvBytesPacking::MostSignificantBytes),
+            ("lsb", YuvBytesPacking::LeastSignificantBytes),
+        ] {
+            assert_eq!(decode(endianness, packing), reference, "{order} {position}");
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use std::sync::Mutex;
+use yuvutils_rs::{
+    set_backend_limit, yuv420_to_bgra, yuv420_to_bgrx, yuv420_to_rgba, yuv420_to_rgbx,
+    yuv_nv12_to_bgra, yuv_nv12_to_bgrx, yuv_nv12_to_rgba, yuv_nv12_to_rgbx, YuvDispatchBackend,
+    YuvError, YuvRange, YuvStandardMatrix,
+};
+
+/// Backend limit is process wide, tests of this file pin it one at a time
+static BACKEND: Mutex<()> = Mutex::new(());
+
+const BACKENDS: [YuvDispatchBackend; 4] = [
+    YuvDispatchBackend::Scalar,
+    YuvDispatchBackend::Sse41,
+    YuvDispatchBackend::Avx2,
+    YuvDispatchBackend::Avx512Bw,
+];
+
+const HEIGHT: u32 = 2;
+/// Byte the destination starts filled with, padding bytes must keep it
+const FILL: u8 = 0xa5;
+
+type Planar = fn(
+    &[u8],
+    u32,
+    &[u8],
+    u32,
+    &[u8],
+    u32,
+    &mut [u8],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+) -> Result<(), YuvError>;
+
+type BiPlanar = fn(
+    &[u8],
+    u32,
+    &[u8],
+    u32,
+    &mut [u8],
+    u32,
+    u32,
+    u32,
+    YuvRange,
+    YuvStandardMatrix,
+) -> Result<(), YuvError>;
+
+fn plane(len: usize, seed: usize) -> Vec<u8> {
+    (0..len).map(|i| (16 + (i * seed) % 220) as u8).collect()
+}
+
+/// Converts with `rgbx` and `rgba` for every backend and width, the RGBX destination must
+/// match RGBA in colour bytes while keeping the 4th byte and the row tail untouched
+fn check(convert: impl Fn(u32, &mut [u8], u32, bool)) {
+    let _guard = BACKEND.lock().unwrap_or_else(|e| e.into_inner());
+    for backend in BACKENDS {
+        set_backend_limit(Some(backend));
+        for width in 1..=140u32 {
+            let stride = width * 4 + 12;
+            let mut rgba = vec![0u8; (stride * HEIGHT) as usize];
+            convert(width, &mut rgba, stride, false);
+            let mut rgbx = vec![FILL; (stride * HEIGHT) as usize];
+            convert(width, &mut rgbx, stride, true);
+            for (y, (x_row, a_row)) in rgbx
+                .chunks_exact(stride as usize)
+                .zip(rgba.chunks_exact(stride as usize))
+                .enumerate()
+            {
+                let visible = width as usize * 4;
+                for (x, (xp, ap)) in x_row[..visible]
+                    .chunks_exact(4)
+                    .zip(a_row[..visible].chunks_exact(4))
+                    .enumerate()
+                {
+                    assert_eq!(xp[..3], ap[..3], "{backend:?} width {width} at {x}x{y}");
+                    assert_eq!(xp[3], FILL, "{backend:?} width {width} at {x}x{y}");
+                }
+                assert!(
+                    x_row[visible..].iter().all(|&v| v == FILL),
+                    "{backend:?} width {width} row {y} wrote past its pixels"
+                );
+            }
+        }
+    }
+    set_backend_limit(None);
+}
+
+fn check_planar(rgba: Planar, rgbx: Planar) {
+    check(|width, dst, stride, x| {
+        let chroma_stride = width.div_ceil(2);
+        let y = plane((width * HEIGHT) as usize, 7);
+        let u = plane((chroma_stride * HEIGHT.div_ceil(2)) as usize, 13);
+        let v = plane((chroma_stride * HEIGHT.div_ceil(2)) as usize, 29);
+        let convert = if x { rgbx } else { rgba };
+        convert(
+            &y,
+            width,
+            &u,
+            chroma_stride,
+            &v,
+            chroma_stride,
+            dst,
+            stride,
+            width,
+            HEIGHT,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+    });
+}
+
+fn check_bi_planar(rgba: BiPlanar, rgbx: BiPlanar) {
+    check(|width, dst, stride, x| {
+        let uv_stride = width.div_ceil(2) * 2;
+        let y = plane((width * HEIGHT) as usize, 7);
+        let uv = plane((uv_stride * HEIGHT.div_ceil(2)) as usize, 13);
+        let convert = if x { rgbx } else { rgba };
+        convert(
+            &y,
+            width,
+            &uv,
+            uv_stride,
+            dst,
+            stride,
+            width,
+            HEIGHT,
+            YuvRange::TV,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+    });
+}
+
+#[test]
+fn yuv420_rgbx_keeps_padding_byte() {
+    check_planar(yuv420_to_rgba, yuv420_to_rgbx);
+}
+
+#[test]
+fn yuv420_bgrx_keeps_padding_byte() {
+    check_planar(yuv420_to_bgra, yuv420_to_bgrx);
+}
+
+#[test]
+fn nv12_rgbx_keeps_padding_byte() {
+    check_bi_planar(yuv_nv12_to_rgba, yuv_nv12_to_rgbx);
+}
+
+#[test]
+fn nv12_bgrx_keeps_padding_byte() {
+    check_bi_planar(yuv_nv12_to_bgra, yuv_nv12_to_bgrx);
+}
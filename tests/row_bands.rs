@@ -0,0 +1,203 @@
+use yuvutils_rs::{
+    ImageSize, RowStride, YuvChromaSample, YuvConversionContext, YuvFramePoolLayout,
+    YuvPlanarImage, YuvRange, YuvRowBand, YuvStandardMatrix,
+};
+
+const WIDTH: u32 = 33;
+const HEIGHTS: [u32; 5] = [1, 3, 17, 45, 63];
+const BAND_ROWS: [u32; 6] = [0, 1, 3, 5, 7, 101];
+const LAYOUTS: [YuvFramePoolLayout; 3] = [
+    YuvFramePoolLayout::Rgb,
+    YuvFramePoolLayout::Rgba,
+    YuvFramePoolLayout::Bgra,
+];
+
+/// Planes of a 4:2:0 frame holding only legal TV range codes
+struct Planes {
+    y: Vec<u8>,
+    u: Vec<u8>,
+    v: Vec<u8>,
+    size: ImageSize,
+}
+
+impl Planes {
+    fn new(width: u32, height: u32) -> Planes {
+        let chroma = (width.div_ceil(2) * height.div_ceil(2)) as usize;
+        Planes {
+            y: (0..(width * height) as usize)
+                .map(|i| (16 + i * 7 % 220) as u8)
+                .collect(),
+            u: (0..chroma).map(|i| (16 + i * 13 % 225) as u8).collect(),
+            v: (0..chroma).map(|i| (16 + i * 29 % 225) as u8).collect(),
+            size: ImageSize::new(width, height),
+        }
+    }
+
+    fn image(&self) -> YuvPlanarImage<'_, u8> {
+        YuvPlanarImage {
+            y_plane: &self.y,
+            y_stride: RowStride::new(self.size.width),
+            u_plane: &self.u,
+            u_stride: RowStride::new(self.size.width.div_ceil(2)),
+            v_plane: &self.v,
+            v_stride: RowStride::new(self.size.width.div_ceil(2)),
+            size: self.size,
+        }
+    }
+}
+
+fn context() -> YuvConversionContext {
+    YuvConversionContext::new(
+        YuvChromaSample::YUV420,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt709,
+    )
+}
+
+/// Destination stride with padding, so bands must honour it
+fn stride(layout: YuvFramePoolLayout) -> u32 {
+    match layout {
+        YuvFramePoolLayout::Rgb => WIDTH * 3 + 5,
+        _ => WIDTH * 4 + 8,
+    }
+}
+
+/// Whole frame converted in a single call
+fn whole_frame(image: &YuvPlanarImage<u8>, layout: YuvFramePoolLayout) -> Vec<u8> {
+    let stride = stride(layout);
+    let mut dst = vec![0u8; (stride * image.size.height) as usize];
+    let context = context();
+    match layout {
+        YuvFramePoolLayout::Rgb => context.yuv_to_rgb(image, &mut dst, stride),
+        YuvFramePoolLayout::Rgba => context.yuv_to_rgba(image, &mut dst, stride),
+        YuvFramePoolLayout::Bgra => context.yuv_to_bgra(image, &mut dst, stride),
+    }
+    .unwrap();
+    dst
+}
+
+/// Copies bands into a frame, checking they cover every row once with even starts
+fn join<'a>(
+    bands: impl Iterator<Item = YuvRowBand<'a>>,
+    height: u32,
+    band_rows: u32,
+    stride: u32,
+) -> Vec<u8> {
+    let band_rows = band_rows.clamp(2, height.max(2)).next_multiple_of(2);
+    let mut frame = vec![0u8; (stride * height) as usize];
+    let mut covered = vec![false; height as usize];
+    for band in bands {
+        let rows = band.rows();
+        assert_eq!(rows.start % band_rows, 0, "band {rows:?} starts off grid");
+        assert_eq!(rows.end, (rows.start + band_rows).min(height));
+        assert_eq!(band.data().len(), rows.len() * stride as usize);
+        for row in rows.clone() {
+            assert!(!covered[row as usize], "row {row} was handed out twice");
+            covered[row as usize] = true;
+        }
+        frame[(rows.start * stride) as usize..][..band.data().len()].copy_from_slice(band.data());
+    }
+    assert!(covered.iter().all(|&x| x), "not every row was handed out");
+    frame
+}
+
+fn assert_same_pixels(joined: &[u8], reference: &[u8], layout: YuvFramePoolLayout, what: &str) {
+    let stride = stride(layout) as usize;
+    let row_len = WIDTH as usize * layout.bytes_per_pixel();
+    for (row, (a, b)) in joined
+        .chunks(stride)
+        .zip(reference.chunks(stride))
+        .enumerate()
+    {
+        assert_eq!(a[..row_len], b[..row_len], "{what}: row {row} differs");
+    }
+}
+
+#[test]
+fn iterator_bands_match_whole_frame() {
+    for height in HEIGHTS {
+        let planes = Planes::new(WIDTH, height);
+        let image = planes.image();
+        for layout in LAYOUTS {
+            let reference = whole_frame(&image, layout);
+            let stride = stride(layout);
+            for band_rows in BAND_ROWS {
+                let mut dst = vec![0u8; (stride * height) as usize];
+                let bands = context()
+                    .convert_many_rows_into_iter(&image, layout, &mut dst, stride, band_rows)
+                    .unwrap();
+                let joined = join(bands, height, band_rows, stride);
+                let what = format!("{layout:?} height {height} band_rows {band_rows}");
+                assert_same_pixels(&joined, &reference, layout, &what);
+                assert_same_pixels(&dst, &reference, layout, &what);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn channel_bands_match_whole_frame() {
+    for height in HEIGHTS {
+        let planes = Planes::new(WIDTH, height);
+        let image = planes.image();
+        for layout in LAYOUTS {
+            let reference = whole_frame(&image, layout);
+            let stride = stride(layout);
+            for band_rows in BAND_ROWS {
+                for capacity in [0, 1, 4] {
+                    let mut dst = vec![0u8; (stride * height) as usize];
+                    let joined = context()
+                        .convert_many_rows_into_channel(
+                            &image,
+                            layout,
+                            &mut dst,
+                            stride,
+                            band_rows,
+                            capacity,
+                            |bands| join(bands, height, band_rows, stride),
+                        )
+                        .unwrap();
+                    let what = format!(
+                        "{layout:?} height {height} band_rows {band_rows} capacity {capacity}"
+                    );
+                    assert_same_pixels(&joined, &reference, layout, &what);
+                    assert_same_pixels(&dst, &reference, layout, &what);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn dropping_channel_stops_workers() {
+    const HEIGHT: u32 = 1024;
+    const BAND_ROWS: u32 = 2;
+    let planes = Planes::new(WIDTH, HEIGHT);
+    let image = planes.image();
+    let stride = WIDTH * 4;
+    // Alpha of converted pixels is opaque, so zeroed rows were never converted
+    let mut dst = vec![0u8; (stride * HEIGHT) as usize];
+    let first = context()
+        .convert_many_rows_into_channel(
+            &image,
+            YuvFramePoolLayout::Rgba,
+            &mut dst,
+            stride,
+            BAND_ROWS,
+            1,
+            |mut bands| bands.next().map(|band| band.rows()),
+        )
+        .unwrap();
+    assert!(first.is_some());
+    let converted = dst
+        .chunks_exact((stride * BAND_ROWS) as usize)
+        .filter(|band| band[3] == 255)
+        .count();
+    let total = (HEIGHT / BAND_ROWS) as usize;
+    assert!(
+        converted < total / 2,
+        "{converted} of {total} bands were converted after the receiver was dropped"
+    );
+}
@@ -0,0 +1,325 @@
+use std::panic::catch_unwind;
+use std::thread;
+use yuvutils_rs::{
+    jpeg_blocks_size, rgba64_le_to_yuv_p16, rgba_to_sharp_yuv420, rgba_to_sharp_yuv422,
+    rgba_to_yuv_jpeg_blocks, stride_mode, with_stride_mode, yuv420_to_rgba, BufferStoreMut,
+    ImageSize, RowStride, SharpYuvGammaTransfer, YuvBytesPacking, YuvChromaSample,
+    YuvConversionContext, YuvEndianness, YuvError, YuvPlanarImage, YuvPlanarImageMut, YuvRange,
+    YuvStandardMatrix, YuvStrideMode,
+};
+
+const WIDTH: u32 = 13;
+const HEIGHTS: [u32; 2] = [5, 6];
+/// Row padding of every plane, in elements
+const PADDING: u32 = 7;
+
+/// Plane of `rows` rows of `stride` elements, the last one cut right after `row_len` elements
+/// when `short` is set
+fn plane<T: Copy>(
+    stride: u32,
+    row_len: u32,
+    rows: u32,
+    short: bool,
+    f: impl Fn(usize) -> T,
+) -> Vec<T> {
+    let len = if short {
+        ((rows - 1) * stride + row_len) as usize
+    } else {
+        (rows * stride) as usize
+    };
+    (0..len).map(f).collect()
+}
+
+/// Visible part of every row
+fn visible<T: Copy>(plane: &[T], stride: u32, row_len: u32) -> Vec<T> {
+    plane
+        .chunks(stride as usize)
+        .flat_map(|row| row[..row_len as usize].iter().copied())
+        .collect()
+}
+
+fn rgba_code(i: usize) -> u8 {
+    (i * 37 % 251) as u8
+}
+
+/// Runs `convert` on full and on short buffers in both modes, short buffers have to be
+/// rejected by strict mode and give the same visible output as full ones in auto tail mode
+fn check_modes<T: PartialEq + std::fmt::Debug>(
+    name: &str,
+    convert: impl Fn(bool) -> Result<T, YuvError>,
+) {
+    let reference = convert(false).unwrap_or_else(|e| panic!("{name} failed on full buffers: {e}"));
+    match convert(true) {
+        Err(YuvError::DestinationSizeMismatch(_) | YuvError::LumaPlaneSizeMismatch(_)) => {}
+        other => panic!("{name} accepted a short last row in strict mode: {other:?}"),
+    }
+    for short in [false, true] {
+        let output = with_stride_mode(YuvStrideMode::AutoTail, || convert(short))
+            .unwrap_or_else(|e| panic!("{name} rejected buffers in auto tail mode: {e}"));
+        assert_eq!(output, reference, "{name} differs with short = {short}");
+    }
+}
+
+#[test]
+fn sharp_yuv_with_short_last_rows() {
+    for height in HEIGHTS {
+        for sampling in [YuvChromaSample::YUV420, YuvChromaSample::YUV422] {
+            let chroma_width = WIDTH.div_ceil(2);
+            let chroma_height = match sampling {
+                YuvChromaSample::YUV420 => height.div_ceil(2),
+                _ => height,
+            };
+            let rgba_stride = WIDTH * 4 + PADDING;
+            let y_stride = WIDTH + PADDING;
+            let chroma_stride = chroma_width + PADDING;
+            check_modes(
+                &format!("{sampling:?} sharp yuv, height {height}"),
+                |short| {
+                    let rgba = plane(rgba_stride, WIDTH * 4, height, short, rgba_code);
+                    let mut y = plane(y_stride, WIDTH, height, short, |_| 0u8);
+                    let mut u = plane(chroma_stride, chroma_width, chroma_height, short, |_| 0u8);
+                    let mut v = plane(chroma_stride, chroma_width, chroma_height, short, |_| 0u8);
+                    let convert = match sampling {
+                        YuvChromaSample::YUV420 => rgba_to_sharp_yuv420,
+                        _ => rgba_to_sharp_yuv422,
+                    };
+                    convert(
+                        &mut y,
+                        y_stride,
+                        &mut u,
+                        chroma_stride,
+                        &mut v,
+                        chroma_stride,
+                        &rgba,
+                        rgba_stride,
+                        WIDTH,
+                        height,
+                        YuvRange::TV,
+                        YuvStandardMatrix::Bt709,
+                        SharpYuvGammaTransfer::Srgb,
+                    )?;
+                    Ok((
+                        visible(&y, y_stride, WIDTH),
+                        visible(&u, chroma_stride, chroma_width),
+                        visible(&v, chroma_stride, chroma_width),
+                    ))
+                },
+            );
+        }
+    }
+}
+
+#[test]
+fn rgba64_with_short_last_row() {
+    for height in HEIGHTS {
+        // Strides are in bytes, planes hold `u16`
+        let rgba_stride = (WIDTH * 4 + PADDING) * 2;
+        let size = ImageSize::new(WIDTH, height);
+        check_modes(&format!("rgba64, height {height}"), |short| {
+            let rgba = plane(rgba_stride / 2, WIDTH * 4, height, short, |i| {
+                (i * 4099 % 65536) as u16
+            });
+            let mut image = YuvPlanarImageMut::<u16>::alloc(size, YuvChromaSample::YUV420);
+            rgba64_le_to_yuv_p16(
+                &rgba,
+                rgba_stride,
+                &mut image,
+                YuvChromaSample::YUV420,
+                10,
+                YuvRange::TV,
+                YuvStandardMatrix::Bt2020,
+                YuvEndianness::LittleEndian,
+                YuvBytesPacking::LeastSignificantBytes,
+            )?;
+            Ok((
+                image.y_plane.borrow().to_vec(),
+                image.u_plane.borrow().to_vec(),
+                image.v_plane.borrow().to_vec(),
+            ))
+        });
+    }
+}
+
+#[test]
+fn rgba64_destination_with_short_last_rows() {
+    let height = 5;
+    let y_stride = WIDTH + PADDING;
+    let chroma_width = WIDTH.div_ceil(2);
+    let chroma_stride = chroma_width + PADDING;
+    let rgba = (0..(WIDTH * 4 * height) as usize)
+        .map(|i| (i * 4099 % 65536) as u16)
+        .collect::<Vec<_>>();
+    check_modes("rgba64 into short planes", |short| {
+        let mut image = YuvPlanarImageMut {
+            y_plane: BufferStoreMut::Owned(plane(y_stride, WIDTH, height, short, |_| 0u16)),
+            y_stride: RowStride::new(y_stride * 2),
+            u_plane: BufferStoreMut::Owned(plane(chroma_stride, chroma_width, 3, short, |_| 0)),
+            u_stride: RowStride::new(chroma_stride * 2),
+            v_plane: BufferStoreMut::Owned(plane(chroma_stride, chroma_width, 3, short, |_| 0)),
+            v_stride: RowStride::new(chroma_stride * 2),
+            size: ImageSize::new(WIDTH, height),
+        };
+        rgba64_le_to_yuv_p16(
+            &rgba,
+            WIDTH * 8,
+            &mut image,
+            YuvChromaSample::YUV420,
+            12,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+            YuvEndianness::LittleEndian,
+            YuvBytesPacking::LeastSignificantBytes,
+        )?;
+        Ok((
+            visible(image.y_plane.borrow(), y_stride, WIDTH),
+            visible(image.u_plane.borrow(), chroma_stride, chroma_width),
+            visible(image.v_plane.borrow(), chroma_stride, chroma_width),
+        ))
+    });
+}
+
+#[test]
+fn jpeg_blocks_with_short_last_row() {
+    // Heights end inside the last MCU row, which is converted on its own worker with rayon
+    for height in [5, 21] {
+        let rgba_stride = WIDTH * 4 + PADDING;
+        check_modes(&format!("jpeg blocks, height {height}"), |short| {
+            let rgba = plane(rgba_stride, WIDTH * 4, height, short, rgba_code);
+            let mut blocks =
+                vec![0u8; jpeg_blocks_size(ImageSize::new(WIDTH, height), YuvChromaSample::YUV420)];
+            rgba_to_yuv_jpeg_blocks(
+                &mut blocks,
+                &rgba,
+                RowStride::new(rgba_stride),
+                ImageSize::new(WIDTH, height),
+                YuvChromaSample::YUV420,
+                YuvRange::Full,
+                YuvStandardMatrix::Bt601,
+            )?;
+            Ok(blocks)
+        });
+    }
+}
+
+/// Planes of a 4:2:0 frame with padded rows
+struct Planes {
+    y: Vec<u8>,
+    u: Vec<u8>,
+    v: Vec<u8>,
+}
+
+const Y_STRIDE: u32 = WIDTH + PADDING;
+const CHROMA_STRIDE: u32 = WIDTH.div_ceil(2) + PADDING;
+
+impl Planes {
+    fn new(height: u32) -> Planes {
+        let chroma_width = WIDTH.div_ceil(2);
+        let chroma_height = height.div_ceil(2);
+        Planes {
+            y: plane(Y_STRIDE, WIDTH, height, false, |i| (16 + i * 7 % 220) as u8),
+            u: plane(CHROMA_STRIDE, chroma_width, chroma_height, false, |i| {
+                (16 + i * 13 % 225) as u8
+            }),
+            v: plane(CHROMA_STRIDE, chroma_width, chroma_height, false, |i| {
+                (16 + i * 29 % 225) as u8
+            }),
+        }
+    }
+
+    fn image(&self, height: u32) -> YuvPlanarImage<'_, u8> {
+        YuvPlanarImage {
+            y_plane: &self.y,
+            y_stride: RowStride::new(Y_STRIDE),
+            u_plane: &self.u,
+            u_stride: RowStride::new(CHROMA_STRIDE),
+            v_plane: &self.v,
+            v_stride: RowStride::new(CHROMA_STRIDE),
+            size: ImageSize::new(WIDTH, height),
+        }
+    }
+}
+
+#[test]
+fn decoding_into_short_last_row() {
+    for height in HEIGHTS {
+        let planes = Planes::new(height);
+        let rgba_stride = WIDTH * 4 + PADDING;
+        check_modes(&format!("yuv420_to_rgba, height {height}"), |short| {
+            let mut rgba = plane(rgba_stride, WIDTH * 4, height, short, |_| 0u8);
+            yuv420_to_rgba(
+                &planes.y,
+                Y_STRIDE,
+                &planes.u,
+                CHROMA_STRIDE,
+                &planes.v,
+                CHROMA_STRIDE,
+                &mut rgba,
+                rgba_stride,
+                WIDTH,
+                height,
+                YuvRange::TV,
+                YuvStandardMatrix::Bt709,
+            )?;
+            Ok(visible(&rgba, rgba_stride, WIDTH * 4))
+        });
+    }
+}
+
+#[test]
+fn context_carries_its_own_mode() {
+    let height = 5;
+    let planes = Planes::new(height);
+    let image = planes.image(height);
+    let rgba_stride = WIDTH * 4 + PADDING;
+    let context = YuvConversionContext::new(
+        YuvChromaSample::YUV420,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt709,
+    );
+    let mut full = plane(rgba_stride, WIDTH * 4, height, false, |_| 0u8);
+    context.yuv_to_rgba(&image, &mut full, rgba_stride).unwrap();
+
+    let mut short = plane(rgba_stride, WIDTH * 4, height, true, |_| 0u8);
+    assert!(matches!(
+        context.yuv_to_rgba(&image, &mut short, rgba_stride),
+        Err(YuvError::DestinationSizeMismatch(_))
+    ));
+    // Scope of the caller does not override the mode of the context
+    assert!(
+        with_stride_mode(YuvStrideMode::AutoTail, || context.yuv_to_rgba(
+            &image,
+            &mut short,
+            rgba_stride
+        ))
+        .is_err()
+    );
+    context
+        .with_stride_mode(YuvStrideMode::AutoTail)
+        .yuv_to_rgba(&image, &mut short, rgba_stride)
+        .unwrap();
+    assert_eq!(
+        visible(&short, rgba_stride, WIDTH * 4),
+        visible(&full, rgba_stride, WIDTH * 4)
+    );
+}
+
+#[test]
+fn mode_is_scoped_to_the_call_and_thread() {
+    assert_eq!(stride_mode(), YuvStrideMode::Strict);
+    with_stride_mode(YuvStrideMode::AutoTail, || {
+        assert_eq!(stride_mode(), YuvStrideMode::AutoTail);
+        let other = thread::spawn(stride_mode).join().unwrap();
+        assert_eq!(other, YuvStrideMode::Strict);
+        with_stride_mode(YuvStrideMode::Strict, || {
+            assert_eq!(stride_mode(), YuvStrideMode::Strict);
+        });
+        assert_eq!(stride_mode(), YuvStrideMode::AutoTail);
+    });
+    assert_eq!(stride_mode(), YuvStrideMode::Strict);
+
+    let unwound = catch_unwind(|| {
+        with_stride_mode(YuvStrideMode::AutoTail, || panic!("conversion failed"));
+    });
+    assert!(unwound.is_err());
+    assert_eq!(stride_mode(), YuvStrideMode::Strict);
+}
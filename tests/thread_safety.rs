@@ -0,0 +1,150 @@
+use std::sync::Mutex;
+use std::thread;
+use yuvutils_rs::{
+    verify_backends, ImageSize, RowStride, YuvChromaSample, YuvConversionContext, YuvFramePool,
+    YuvFramePoolLayout, YuvPlanarImage, YuvRange, YuvStandardMatrix,
+};
+
+const WIDTH: u32 = 67;
+const HEIGHT: u32 = 45;
+const THREADS: usize = 8;
+const ROUNDS: usize = 16;
+
+/// Tests of this file that probe backends must not overlap with ones expecting exact output
+static DISPATCH: Mutex<()> = Mutex::new(());
+
+/// Planes of a frame holding only legal TV range codes
+struct Planes {
+    y: Vec<u8>,
+    u: Vec<u8>,
+    v: Vec<u8>,
+}
+
+impl Planes {
+    fn new() -> Planes {
+        let chroma = (WIDTH.div_ceil(2) * HEIGHT.div_ceil(2)) as usize;
+        Planes {
+            y: (0..(WIDTH * HEIGHT) as usize)
+                .map(|i| (16 + i * 7 % 220) as u8)
+                .collect(),
+            u: (0..chroma).map(|i| (16 + i * 13 % 225) as u8).collect(),
+            v: (0..chroma).map(|i| (16 + i * 29 % 225) as u8).collect(),
+        }
+    }
+
+    fn image(&self) -> YuvPlanarImage<'_, u8> {
+        YuvPlanarImage {
+            y_plane: &self.y,
+            y_stride: RowStride::new(WIDTH),
+            u_plane: &self.u,
+            u_stride: RowStride::new(WIDTH.div_ceil(2)),
+            v_plane: &self.v,
+            v_stride: RowStride::new(WIDTH.div_ceil(2)),
+            size: ImageSize::new(WIDTH, HEIGHT),
+        }
+    }
+}
+
+fn context() -> YuvConversionContext {
+    YuvConversionContext::new(
+        YuvChromaSample::YUV420,
+        YuvRange::TV,
+        YuvStandardMatrix::Bt709,
+    )
+}
+
+fn convert(context: &YuvConversionContext, image: &YuvPlanarImage<u8>) -> Vec<u8> {
+    let mut rgba = vec![0u8; (WIDTH * HEIGHT * 4) as usize];
+    context.yuv_to_rgba(image, &mut rgba, WIDTH * 4).unwrap();
+    rgba
+}
+
+#[test]
+fn context_and_pool_are_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<YuvConversionContext>();
+    assert_send_sync::<YuvFramePool>();
+}
+
+#[test]
+fn shared_context_from_many_threads() {
+    let _guard = DISPATCH.lock().unwrap_or_else(|e| e.into_inner());
+    let planes = Planes::new();
+    let image = planes.image();
+    let context = context();
+    let reference = convert(&context, &image);
+    thread::scope(|s| {
+        for _ in 0..THREADS {
+            s.spawn(|| {
+                for _ in 0..ROUNDS {
+                    assert_eq!(convert(&context, &image), reference);
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn shared_pool_from_many_threads() {
+    let _guard = DISPATCH.lock().unwrap_or_else(|e| e.into_inner());
+    let planes = Planes::new();
+    let image = planes.image();
+    let reference = convert(&context(), &image);
+    let pool =
+        YuvFramePool::new(context(), image.size, YuvFramePoolLayout::Rgba, THREADS / 2).unwrap();
+    let pool = Mutex::new(pool);
+    let sequences = Mutex::new(Vec::new());
+    thread::scope(|s| {
+        for _ in 0..THREADS {
+            s.spawn(|| {
+                let mut converted = 0;
+                while converted < ROUNDS {
+                    let frame = pool.lock().unwrap().convert(&image).unwrap();
+                    let Some(frame) = frame else {
+                        thread::yield_now();
+                        continue;
+                    };
+                    assert_eq!(frame.data(), &reference[..]);
+                    sequences.lock().unwrap().push(frame.sequence());
+                    pool.lock().unwrap().recycle(frame);
+                    converted += 1;
+                }
+            });
+        }
+    });
+    let mut sequences = sequences.into_inner().unwrap();
+    sequences.sort_unstable();
+    assert_eq!(
+        sequences,
+        (0..(THREADS * ROUNDS) as u64).collect::<Vec<_>>()
+    );
+    assert_eq!(pool.into_inner().unwrap().available(), THREADS / 2);
+}
+
+#[test]
+fn verify_backends_while_converting() {
+    let _guard = DISPATCH.lock().unwrap_or_else(|e| e.into_inner());
+    let planes = Planes::new();
+    let image = planes.image();
+    let context = context();
+    let reference = convert(&context, &image);
+    thread::scope(|s| {
+        let probes = (0..2).map(|_| s.spawn(verify_backends)).collect::<Vec<_>>();
+        for _ in 0..THREADS {
+            s.spawn(|| {
+                for _ in 0..ROUNDS {
+                    // Dispatch may fall back to another backend while probing is running
+                    let rgba = convert(&context, &image);
+                    assert!(rgba
+                        .iter()
+                        .zip(&reference)
+                        .all(|(a, b)| a.abs_diff(*b) <= 2));
+                }
+            });
+        }
+        for probe in probes {
+            assert_eq!(probe.join().unwrap(), vec![]);
+        }
+    });
+    assert_eq!(convert(&context, &image), reference);
+}
@@ -0,0 +1,31 @@
+use yuvutils_rs::{query_expected_throughput, YuvFormatPair};
+
+const PAIRS: [YuvFormatPair; 15] = [
+    YuvFormatPair::RgbaToYuv420,
+    YuvFormatPair::RgbaToYuv422,
+    YuvFormatPair::RgbaToYuv444,
+    YuvFormatPair::Yuv420ToRgba,
+    YuvFormatPair::Yuv422ToRgba,
+    YuvFormatPair::Yuv444ToRgba,
+    YuvFormatPair::RgbaToYuvNv12,
+    YuvFormatPair::YuvNv12ToRgba,
+    YuvFormatPair::RgbaToYuv400,
+    YuvFormatPair::Yuv400ToRgba,
+    YuvFormatPair::Yuv420ToYuyv422,
+    YuvFormatPair::Yuyv422ToYuv420,
+    YuvFormatPair::Yuyv422ToRgba,
+    YuvFormatPair::Yuv420ToYuvNv12,
+    YuvFormatPair::YuvNv12ToYuv420,
+];
+
+#[test]
+fn every_pair_has_expected_throughput_on_dispatched_backend() {
+    for pair in PAIRS {
+        let throughput = query_expected_throughput(pair);
+        assert!(
+            throughput.is_some_and(|x| x.0 > 0.),
+            "{pair:?} on {:?} has no expected throughput",
+            pair.backend()
+        );
+    }
+}
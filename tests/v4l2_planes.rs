@@ -0,0 +1,182 @@
+use yuvutils_rs::{
+    ImageSize, RowStride, YuvBiPlanarImage, YuvChromaSample, YuvError, YuvField, YuvFieldLayout,
+    YuvPlanarImage, YuvPlanarImageMut,
+};
+
+const WIDTH: u32 = 6;
+const HEIGHT: u32 = 8;
+const STRIDE: u32 = 8;
+const CHROMA_STRIDE: u32 = 4;
+
+/// Plane padded the way drivers do, every row is filled with its index
+fn plane(data_offset: usize, stride: u32, rows: u32, padding: usize) -> Vec<u8> {
+    let mut plane = vec![0xffu8; data_offset];
+    for row in 0..rows {
+        plane.extend(std::iter::repeat_n(row as u8, stride as usize));
+    }
+    plane.extend(std::iter::repeat_n(0xffu8, padding));
+    plane
+}
+
+#[test]
+fn oversized_planes_are_accepted() {
+    let y = plane(64, STRIDE, HEIGHT, 4096);
+    let u = plane(0, CHROMA_STRIDE, HEIGHT / 2, 100);
+    let v = plane(32, CHROMA_STRIDE, HEIGHT / 2, 1);
+    let image = YuvPlanarImage::from_v4l2_planes(
+        &y,
+        64,
+        RowStride::new(STRIDE),
+        &u,
+        0,
+        RowStride::new(CHROMA_STRIDE),
+        &v,
+        32,
+        RowStride::new(CHROMA_STRIDE),
+        ImageSize::new(WIDTH, HEIGHT),
+        YuvChromaSample::YUV420,
+    )
+    .unwrap();
+    assert_eq!(image.y_plane.len(), (STRIDE * HEIGHT) as usize);
+    assert_eq!(image.y_plane[0], 0);
+    assert_eq!(image.v_plane.len(), (CHROMA_STRIDE * HEIGHT / 2) as usize);
+
+    let mut y = y;
+    let mut u = u;
+    let mut v = v;
+    let image = YuvPlanarImageMut::from_v4l2_planes_mut(
+        &mut y,
+        64,
+        RowStride::new(STRIDE),
+        &mut u,
+        0,
+        RowStride::new(CHROMA_STRIDE),
+        &mut v,
+        32,
+        RowStride::new(CHROMA_STRIDE),
+        ImageSize::new(WIDTH, HEIGHT),
+        YuvChromaSample::YUV420,
+    )
+    .unwrap();
+    assert_eq!(image.y_plane.borrow().len(), (STRIDE * HEIGHT) as usize);
+}
+
+#[test]
+fn short_planes_are_rejected() {
+    let y = plane(0, STRIDE, HEIGHT - 1, 0);
+    let uv = plane(0, STRIDE, HEIGHT / 2, 0);
+    let result = YuvBiPlanarImage::from_v4l2_planes(
+        &y,
+        0,
+        RowStride::new(STRIDE),
+        &uv,
+        0,
+        RowStride::new(STRIDE),
+        ImageSize::new(WIDTH, HEIGHT),
+        YuvChromaSample::YUV420,
+    );
+    assert!(matches!(result, Err(YuvError::LumaPlaneSizeMismatch(_))));
+
+    let result = YuvBiPlanarImage::from_v4l2_planes(
+        &uv,
+        (uv.len() + 1) as u32,
+        RowStride::new(STRIDE),
+        &uv,
+        0,
+        RowStride::new(STRIDE),
+        ImageSize::new(WIDTH, HEIGHT),
+        YuvChromaSample::YUV420,
+    );
+    assert!(matches!(result, Err(YuvError::InvalidDataOffset(_))));
+}
+
+fn field_rows(image: &YuvBiPlanarImage<u8>) -> (Vec<u8>, Vec<u8>) {
+    let y_stride = image.y_stride.get() as usize;
+    let uv_stride = image.uv_stride.get() as usize;
+    let y = (0..image.size.height as usize)
+        .map(|row| image.y_plane[row * y_stride])
+        .collect();
+    let uv = (0..image.size.height.div_ceil(2) as usize)
+        .map(|row| image.uv_plane[row * uv_stride])
+        .collect();
+    (y, uv)
+}
+
+#[test]
+fn fields_of_interlaced_frame() {
+    let y = plane(0, STRIDE, HEIGHT, 512);
+    let uv = plane(0, STRIDE, HEIGHT / 2, 512);
+    let frame = YuvBiPlanarImage::from_v4l2_planes(
+        &y,
+        0,
+        RowStride::new(STRIDE),
+        &uv,
+        0,
+        RowStride::new(STRIDE),
+        ImageSize::new(WIDTH, HEIGHT),
+        YuvChromaSample::YUV420,
+    )
+    .unwrap();
+
+    let top = frame
+        .field(
+            YuvField::Top,
+            YuvFieldLayout::Interlaced,
+            YuvChromaSample::YUV420,
+        )
+        .unwrap();
+    assert_eq!(top.size, ImageSize::new(WIDTH, HEIGHT / 2));
+    assert_eq!(top.y_stride.get(), STRIDE * 2);
+    assert_eq!(field_rows(&top), (vec![0, 2, 4, 6], vec![0, 2]));
+
+    let cases = [
+        (
+            YuvField::Top,
+            YuvFieldLayout::SequentialTopBottom,
+            (vec![0, 1, 2, 3], vec![0, 1]),
+        ),
+        (
+            YuvField::Bottom,
+            YuvFieldLayout::SequentialTopBottom,
+            (vec![4, 5, 6, 7], vec![2, 3]),
+        ),
+        (
+            YuvField::Top,
+            YuvFieldLayout::SequentialBottomTop,
+            (vec![4, 5, 6, 7], vec![2, 3]),
+        ),
+        (
+            YuvField::Bottom,
+            YuvFieldLayout::SequentialBottomTop,
+            (vec![0, 1, 2, 3], vec![0, 1]),
+        ),
+    ];
+    for (field, layout, rows) in cases {
+        let image = frame.field(field, layout, YuvChromaSample::YUV420).unwrap();
+        assert_eq!(image.y_stride.get(), STRIDE);
+        assert_eq!(field_rows(&image), rows, "{field:?} {layout:?}");
+    }
+}
+
+#[test]
+fn odd_chroma_rows_do_not_split_into_fields() {
+    let y = plane(0, STRIDE, 6, 0);
+    let uv = plane(0, STRIDE, 3, 0);
+    let frame = YuvBiPlanarImage::from_v4l2_planes(
+        &y,
+        0,
+        RowStride::new(STRIDE),
+        &uv,
+        0,
+        RowStride::new(STRIDE),
+        ImageSize::new(WIDTH, 6),
+        YuvChromaSample::YUV420,
+    )
+    .unwrap();
+    let result = frame.field(
+        YuvField::Top,
+        YuvFieldLayout::SequentialTopBottom,
+        YuvChromaSample::YUV420,
+    );
+    assert!(matches!(result, Err(YuvError::InvalidFieldHeight(6))));
+}
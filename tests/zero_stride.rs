@@ -0,0 +1,451 @@
+use std::fmt::Debug;
+use yuvutils_rs::{
+    build_yuv_pyramid, chroma_key_yuv_to_rgba, decimate_yuv, decimate_yuv_p16,
+    yuv420_p16_to_yuv422_p16, yuv420_to_gray8, yuv420_to_rgb_f32_chw, yuv420_to_yuv422,
+    yuv420_to_yuv_nv12, yuv_p10_to_rgb30, yuv_p10_to_rgba16_with_lut,
+    yuv_p16_to_rgba16_with_overshoot, yuv_p16_to_rgba64_le, yuv_planar_to_yuv400,
+    yuv_to_rgba16_with_overshoot, yuv_to_rgba_at_offset, yuv_to_rgba_fastest,
+    yuv_to_rgba_horizontal_scale, yuv_to_rgba_rotated, yuv_to_rgba_texture,
+    yuv_to_rgba_with_description, yuv_to_rgba_with_grain, yuv_to_rgba_with_lut,
+    yuv_to_rgba_with_lut3d, yuv_to_rgba_with_storage_order, BufferStoreMut, ColorDescription,
+    HorizontalScale, ImageSize, Lut3d, Lut3dInterpolation, Rgb30, Rgb30Padding, RowStride,
+    TensorNormalization, YuvBiPlanarImageMut, YuvBytesPacking, YuvChromaSample,
+    YuvChromaUpsampling, YuvColor, YuvConversionContext, YuvDecimation, YuvEndianness, YuvField,
+    YuvFieldLayout, YuvFramePoolLayout, YuvGrayImageMut, YuvOvershoot, YuvPlanarImage,
+    YuvPlanarImageMut, YuvPyramidFilter, YuvRange, YuvRgbLut, YuvRotation, YuvStandardMatrix,
+    YuvStorageOrder, YuvTextureOptions,
+};
+
+const SIZE: ImageSize = ImageSize::new(37, 12);
+const SAMPLINGS: [YuvChromaSample; 3] = [
+    YuvChromaSample::YUV420,
+    YuvChromaSample::YUV422,
+    YuvChromaSample::YUV444,
+];
+const RANGE: YuvRange = YuvRange::TV;
+const MATRIX: YuvStandardMatrix = YuvStandardMatrix::Bt709;
+
+fn chroma_size(sampling: YuvChromaSample) -> (usize, usize) {
+    let (width, height) = (SIZE.width as usize, SIZE.height as usize);
+    match sampling {
+        YuvChromaSample::YUV420 => (width.div_ceil(2), height.div_ceil(2)),
+        YuvChromaSample::YUV422 => (width.div_ceil(2), height),
+        YuvChromaSample::YUV444 => (width, height),
+    }
+}
+
+/// Luma and one chroma row per plane, chroma is constant down every column
+struct Planes<T> {
+    y: Vec<T>,
+    u_row: Vec<T>,
+    v_row: Vec<T>,
+    u: Vec<T>,
+    v: Vec<T>,
+}
+
+impl<T: Copy + Debug> Planes<T> {
+    fn new(sampling: YuvChromaSample, sample: impl Fn(usize) -> T) -> Planes<T> {
+        let (chroma_width, chroma_height) = chroma_size(sampling);
+        let u_row: Vec<T> = (0..chroma_width).map(|x| sample(x * 13 + 5)).collect();
+        let v_row: Vec<T> = (0..chroma_width).map(|x| sample(x * 29 + 3)).collect();
+        Planes {
+            y: (0..(SIZE.width * SIZE.height) as usize)
+                .map(|i| sample(i * 7))
+                .collect(),
+            u: u_row.repeat(chroma_height),
+            v: v_row.repeat(chroma_height),
+            u_row,
+            v_row,
+        }
+    }
+
+    /// Image with every chroma row stored when `expanded`, or a single row at stride `0`
+    fn image(&self, expanded: bool) -> YuvPlanarImage<'_, T> {
+        let element = size_of::<T>() as u32;
+        let chroma_stride = if expanded {
+            self.u_row.len() as u32 * element
+        } else {
+            0
+        };
+        YuvPlanarImage {
+            y_plane: &self.y,
+            y_stride: RowStride::new(SIZE.width * element),
+            u_plane: if expanded { &self.u } else { &self.u_row },
+            u_stride: RowStride::new(chroma_stride),
+            v_plane: if expanded { &self.v } else { &self.v_row },
+            v_stride: RowStride::new(chroma_stride),
+            size: SIZE,
+        }
+    }
+}
+
+fn planes8(sampling: YuvChromaSample) -> Planes<u8> {
+    Planes::new(sampling, |i| (16 + i % 220) as u8)
+}
+
+fn planes10(sampling: YuvChromaSample) -> Planes<u16> {
+    Planes::new(sampling, |i| (64 + i * 3 % 876) as u16)
+}
+
+/// Runs `convert` on the stride `0` image and on its expanded twin, outputs must match
+fn check<T: Copy + Debug, O: PartialEq + Debug>(
+    what: &str,
+    planes: impl Fn(YuvChromaSample) -> Planes<T>,
+    samplings: &[YuvChromaSample],
+    convert: impl Fn(&YuvPlanarImage<T>, YuvChromaSample) -> O,
+) {
+    for &sampling in samplings {
+        let planes = planes(sampling);
+        let zero = planes.image(false);
+        zero.check_constraints(sampling).unwrap();
+        assert_eq!(
+            convert(&zero, sampling),
+            convert(&planes.image(true), sampling),
+            "{what} {sampling:?}"
+        );
+    }
+}
+
+fn rgba() -> Vec<u8> {
+    vec![0; (SIZE.width * SIZE.height * 4) as usize]
+}
+
+fn rgba16() -> Vec<u16> {
+    vec![0; (SIZE.width * SIZE.height * 4) as usize]
+}
+
+fn planes_of<T: Copy + Debug>(image: &YuvPlanarImageMut<T>) -> [Vec<T>; 3] {
+    [
+        image.y_plane.borrow().to_vec(),
+        image.u_plane.borrow().to_vec(),
+        image.v_plane.borrow().to_vec(),
+    ]
+}
+
+#[test]
+fn mutable_planes_reject_zero_stride() {
+    let mut image = YuvPlanarImageMut::<u8>::alloc(SIZE, YuvChromaSample::YUV420);
+    image.u_stride = RowStride::new(0);
+    image.u_plane = BufferStoreMut::Owned(vec![0; SIZE.width.div_ceil(2) as usize]);
+    assert!(image.check_constraints(YuvChromaSample::YUV420).is_err());
+}
+
+#[test]
+fn context_conversions() {
+    check("context", planes8, &SAMPLINGS, |image, sampling| {
+        let context = YuvConversionContext::new(sampling, RANGE, MATRIX);
+        let stride = SIZE.width * 4;
+        let mut whole = rgba();
+        context.yuv_to_rgba(image, &mut whole, stride).unwrap();
+        let mut rows = rgba();
+        context
+            .yuv_to_rgba_rows(image, &mut rows, stride, 4..SIZE.height)
+            .unwrap();
+        let mut bands = rgba();
+        context
+            .convert_many_rows_into_iter(image, YuvFramePoolLayout::Rgba, &mut bands, stride, 4)
+            .unwrap()
+            .for_each(drop);
+        [whole, rows, bands]
+    });
+}
+
+#[test]
+fn rgba_variants() {
+    let stride = SIZE.width * 4;
+    check("at offset", planes8, &SAMPLINGS, |image, sampling| {
+        let mut rgba = vec![0; (stride * 2 * SIZE.height * 2) as usize];
+        yuv_to_rgba_at_offset(
+            image,
+            sampling,
+            &mut rgba,
+            RowStride::new(stride * 2),
+            3,
+            5,
+            RANGE,
+            MATRIX,
+        )
+        .unwrap();
+        rgba
+    });
+    check("chroma key", planes8, &SAMPLINGS, |image, sampling| {
+        let mut rgba = rgba();
+        let key = YuvColor::new(120, 100, 140);
+        chroma_key_yuv_to_rgba(
+            image, sampling, &mut rgba, stride, RANGE, MATRIX, key, 40, 20, true,
+        )
+        .unwrap();
+        rgba
+    });
+    check("description", planes8, &SAMPLINGS, |image, sampling| {
+        let mut rgba = rgba();
+        let description = ColorDescription::from_codes(1, false, 1, 1, 0);
+        yuv_to_rgba_with_description(image, sampling, &mut rgba, stride, description).unwrap();
+        rgba
+    });
+    check("fastest", planes8, &SAMPLINGS, |image, sampling| {
+        let mut rgba = rgba();
+        yuv_to_rgba_fastest(image, sampling, &mut rgba, stride, RANGE, MATRIX).unwrap();
+        rgba
+    });
+    check("grain", planes8, &SAMPLINGS, |image, sampling| {
+        let mut rgba = rgba();
+        yuv_to_rgba_with_grain(
+            image,
+            sampling,
+            &mut rgba,
+            stride,
+            RANGE,
+            MATRIX,
+            |_, y, row| row.iter_mut().for_each(|v| *v = v.wrapping_add(y as u8)),
+        )
+        .unwrap();
+        rgba
+    });
+    check(
+        "horizontal scale",
+        planes8,
+        &SAMPLINGS,
+        |image, sampling| {
+            let mut rgba = vec![0; (SIZE.width * 2 * 4 * SIZE.height) as usize];
+            let scale = HorizontalScale::new(2, 1);
+            yuv_to_rgba_horizontal_scale(
+                image,
+                sampling,
+                scale,
+                &mut rgba,
+                stride * 2,
+                RANGE,
+                MATRIX,
+            )
+            .unwrap();
+            rgba
+        },
+    );
+    check("lut", planes8, &SAMPLINGS, |image, sampling| {
+        let mut rgba = rgba();
+        let table: Vec<u8> = (0..=255u8).rev().collect();
+        let lut = YuvRgbLut {
+            g: Some(&table),
+            ..Default::default()
+        };
+        yuv_to_rgba_with_lut(image, sampling, &mut rgba, stride, RANGE, MATRIX, lut).unwrap();
+        rgba
+    });
+    check("lut3d", planes8, &SAMPLINGS, |image, sampling| {
+        let mut rgba = rgba();
+        let identity = [0., 1.]
+            .iter()
+            .flat_map(|&b| [0., 1.].map(move |g| [0., 1.].map(move |r| [r, g, b])))
+            .flatten()
+            .collect::<Vec<_>>();
+        let lut = Lut3d::new(2, &identity).unwrap();
+        let interpolation = Lut3dInterpolation::Trilinear;
+        yuv_to_rgba_with_lut3d(
+            image,
+            sampling,
+            &mut rgba,
+            RowStride::new(stride),
+            RANGE,
+            MATRIX,
+            &lut,
+            interpolation,
+        )
+        .unwrap();
+        rgba
+    });
+    check("rotated", planes8, &SAMPLINGS, |image, sampling| {
+        let mut rgba = rgba();
+        let rotation = YuvRotation::Clockwise90;
+        yuv_to_rgba_rotated(
+            image,
+            sampling,
+            &mut rgba,
+            SIZE.height * 4,
+            RANGE,
+            MATRIX,
+            rotation,
+        )
+        .unwrap();
+        rgba
+    });
+    check("storage order", planes8, &SAMPLINGS, |image, sampling| {
+        let mut rgba = rgba();
+        let order = YuvStorageOrder::RowMajor;
+        yuv_to_rgba_with_storage_order(image, order, sampling, &mut rgba, stride, RANGE, MATRIX)
+            .unwrap();
+        rgba
+    });
+    check("texture", planes8, &SAMPLINGS, |image, sampling| {
+        let mut rgba = rgba();
+        let options = YuvTextureOptions {
+            flip_y: true,
+            ..Default::default()
+        };
+        yuv_to_rgba_texture(
+            image, None, sampling, &mut rgba, stride, RANGE, MATRIX, options,
+        )
+        .unwrap();
+        rgba
+    });
+    check("rgba16", planes8, &SAMPLINGS, |image, sampling| {
+        let mut rgba = rgba16();
+        let overshoot = YuvOvershoot::Clamp;
+        yuv_to_rgba16_with_overshoot(
+            image,
+            sampling,
+            &mut rgba,
+            RowStride::new(stride * 2),
+            10,
+            RANGE,
+            MATRIX,
+            overshoot,
+        )
+        .unwrap();
+        rgba
+    });
+    check("tensor", planes8, &SAMPLINGS[..1], |image, _| {
+        let mut tensor = vec![0f32; (SIZE.width * SIZE.height * 3) as usize];
+        let normalization = TensorNormalization::default();
+        yuv420_to_rgb_f32_chw(image, &mut tensor, normalization, RANGE, MATRIX).unwrap();
+        tensor
+    });
+}
+
+#[test]
+fn high_bit_depth_variants() {
+    const LE: YuvEndianness = YuvEndianness::LittleEndian;
+    const LSB: YuvBytesPacking = YuvBytesPacking::LeastSignificantBytes;
+    let stride = SIZE.width * 4 * 2;
+    check(
+        "rgba16 overshoot",
+        planes10,
+        &SAMPLINGS,
+        |image, sampling| {
+            let mut rgba = rgba16();
+            let overshoot = YuvOvershoot::Clamp;
+            yuv_p16_to_rgba16_with_overshoot(
+                image,
+                sampling,
+                10,
+                LE,
+                LSB,
+                &mut rgba,
+                RowStride::new(stride),
+                10,
+                RANGE,
+                MATRIX,
+                overshoot,
+            )
+            .unwrap();
+            rgba
+        },
+    );
+    check("rgba64", planes10, &SAMPLINGS, |image, sampling| {
+        let mut rgba = rgba16();
+        yuv_p16_to_rgba64_le(
+            image, sampling, 10, LE, LSB, &mut rgba, stride, RANGE, MATRIX,
+        )
+        .unwrap();
+        rgba
+    });
+    check("rgba16 lut", planes10, &SAMPLINGS, |image, sampling| {
+        let mut rgba = rgba16();
+        let table: Vec<u16> = (0..1024u16).rev().collect();
+        let lut = YuvRgbLut {
+            r: Some(&table),
+            ..Default::default()
+        };
+        yuv_p10_to_rgba16_with_lut(
+            image, sampling, LE, LSB, &mut rgba, stride, RANGE, MATRIX, lut,
+        )
+        .unwrap();
+        rgba
+    });
+    check("rgb30", planes10, &SAMPLINGS, |image, sampling| {
+        let mut rgb30 = vec![0u32; (SIZE.width * SIZE.height) as usize];
+        yuv_p10_to_rgb30(
+            image,
+            sampling,
+            &mut rgb30,
+            RowStride::new(SIZE.width * 4),
+            RANGE,
+            MATRIX,
+            LE,
+            LSB,
+            Rgb30::Ar30,
+            Rgb30Padding::Opaque,
+        )
+        .unwrap();
+        rgb30
+    });
+}
+
+#[test]
+fn planar_to_planar() {
+    check("decimate", planes8, &SAMPLINGS, |image, sampling| {
+        let size = ImageSize::new(SIZE.width.div_ceil(2), SIZE.height.div_ceil(2));
+        let mut target = YuvPlanarImageMut::alloc(size, sampling);
+        decimate_yuv(image, &mut target, sampling, YuvDecimation::Half).unwrap();
+        planes_of(&target)
+    });
+    check("decimate p16", planes10, &SAMPLINGS, |image, sampling| {
+        let size = ImageSize::new(SIZE.width.div_ceil(2), SIZE.height.div_ceil(2));
+        let mut target = YuvPlanarImageMut::alloc(size, sampling);
+        decimate_yuv_p16(image, &mut target, sampling, YuvDecimation::Half, 10).unwrap();
+        planes_of(&target)
+    });
+    check("pyramid", planes8, &SAMPLINGS, |image, sampling| {
+        build_yuv_pyramid(image, sampling, 3, YuvPyramidFilter::Gaussian)
+            .unwrap()
+            .iter()
+            .map(planes_of)
+            .collect::<Vec<_>>()
+    });
+    check("422", planes8, &SAMPLINGS[..1], |image, _| {
+        let mut target = YuvPlanarImageMut::alloc(SIZE, YuvChromaSample::YUV422);
+        yuv420_to_yuv422(image, &mut target, YuvChromaUpsampling::Bilinear).unwrap();
+        planes_of(&target)
+    });
+    check("422 p16", planes10, &SAMPLINGS[..1], |image, _| {
+        let mut target = YuvPlanarImageMut::alloc(SIZE, YuvChromaSample::YUV422);
+        yuv420_p16_to_yuv422_p16(image, &mut target, YuvChromaUpsampling::Bilinear).unwrap();
+        planes_of(&target)
+    });
+    check("nv12", planes8, &SAMPLINGS[..1], |image, _| {
+        let mut target = YuvBiPlanarImageMut::alloc(SIZE, YuvChromaSample::YUV420);
+        yuv420_to_yuv_nv12(image, &mut target).unwrap();
+        [
+            target.y_plane.borrow().to_vec(),
+            target.uv_plane.borrow().to_vec(),
+        ]
+    });
+    check("gray", planes8, &SAMPLINGS, |image, sampling| {
+        let mut gray = YuvGrayImageMut::alloc(SIZE);
+        yuv_planar_to_yuv400(image, &mut gray, sampling).unwrap();
+        gray.y_plane.borrow().to_vec()
+    });
+    check("gray8", planes8, &SAMPLINGS[..1], |image, _| {
+        let mut gray = YuvGrayImageMut::alloc(SIZE);
+        yuv420_to_gray8(image, &mut gray, RANGE).unwrap();
+        gray.y_plane.borrow().to_vec()
+    });
+    check("pixels", planes8, &SAMPLINGS, |image, sampling| {
+        image.pixels(sampling).unwrap().collect::<Vec<_>>()
+    });
+    check("field", planes8, &SAMPLINGS, |image, sampling| {
+        let field = image
+            .field(
+                YuvField::Bottom,
+                YuvFieldLayout::SequentialTopBottom,
+                sampling,
+            )
+            .unwrap();
+        let context = YuvConversionContext::new(sampling, RANGE, MATRIX);
+        let mut rgba = vec![0; (SIZE.width * 4 * field.size.height) as usize];
+        context
+            .yuv_to_rgba(&field, &mut rgba, SIZE.width * 4)
+            .unwrap();
+        rgba
+    });
+}